@@ -0,0 +1,106 @@
+#include "tfhe.h"
+
+#include <assert.h>
+#include <inttypes.h>
+#include <stdio.h>
+#include <stdlib.h>
+
+int handle_test(const ClientKey *client_key) {
+  int ok;
+  FheUint8 *lhs = NULL;
+  FheUint8 *rhs = NULL;
+  FheUint8 *result = NULL;
+  TfheHandle *lhs_handle = NULL;
+  TfheHandle *lhs_handle_clone = NULL;
+  const FheUint8 *lhs_ref = NULL;
+  const FheBool *wrong_type_ref = NULL;
+  size_t ref_count = 0;
+
+  uint8_t lhs_clear = 123;
+  uint8_t rhs_clear = 14;
+
+  ok = fhe_uint8_try_encrypt_with_client_key_u8(lhs_clear, client_key, &lhs);
+  assert(ok == 0);
+
+  ok = fhe_uint8_try_encrypt_with_client_key_u8(rhs_clear, client_key, &rhs);
+  assert(ok == 0);
+
+  // lhs is now owned by the handle
+  ok = fhe_uint8_into_handle(lhs, &lhs_handle);
+  assert(ok == 0);
+  lhs = NULL;
+
+  ok = tfhe_handle_clone(lhs_handle, &lhs_handle_clone);
+  assert(ok == 0);
+
+  ok = tfhe_handle_ref_count(lhs_handle_clone, &ref_count);
+  assert(ok == 0);
+  assert(ref_count == 2);
+
+  // Releasing one handle does not destroy the object
+  ok = tfhe_handle_release(lhs_handle);
+  assert(ok == 0);
+  lhs_handle = NULL;
+
+  ok = tfhe_handle_ref_count(lhs_handle_clone, &ref_count);
+  assert(ok == 0);
+  assert(ref_count == 1);
+
+  // The handle does not reference a FheBool
+  ok = tfhe_handle_get_fhe_bool(lhs_handle_clone, &wrong_type_ref);
+  assert(ok != 0);
+
+  ok = tfhe_handle_get_fhe_uint8(lhs_handle_clone, &lhs_ref);
+  assert(ok == 0);
+
+  ok = fhe_uint8_add(lhs_ref, rhs, &result);
+  assert(ok == 0);
+
+  uint8_t clear;
+  ok = fhe_uint8_decrypt(result, client_key, &clear);
+  assert(ok == 0);
+
+  assert(clear == (uint8_t)(lhs_clear + rhs_clear));
+
+  // Destroys the object as this is the last handle
+  ok = tfhe_handle_release(lhs_handle_clone);
+  assert(ok == 0);
+
+  // Releasing a null handle is a no-op
+  ok = tfhe_handle_release(NULL);
+  assert(ok == 0);
+
+  fhe_uint8_destroy(rhs);
+  fhe_uint8_destroy(result);
+
+  return ok;
+}
+
+int main(void) {
+  int ok = 0;
+
+  ConfigBuilder *builder;
+  Config *config;
+
+  ok = config_builder_default(&builder);
+  assert(ok == 0);
+  ok = config_builder_build(builder, &config);
+  assert(ok == 0);
+
+  ClientKey *client_key = NULL;
+  ServerKey *server_key = NULL;
+
+  ok = generate_keys(config, &client_key, &server_key);
+  assert(ok == 0);
+
+  ok = set_server_key(server_key);
+  assert(ok == 0);
+
+  ok = handle_test(client_key);
+  assert(ok == 0);
+
+  client_key_destroy(client_key);
+  server_key_destroy(server_key);
+
+  return EXIT_SUCCESS;
+}
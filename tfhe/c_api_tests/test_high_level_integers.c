@@ -416,6 +416,54 @@ int uint8_safe_serialization(const ClientKey *client_key, const ServerKey *serve
   return ok;
 }
 
+int uint8_safe_deserialization_conformant_with_config(const ClientKey *client_key,
+                                                     DynamicBufferView config_view) {
+  int ok;
+  FheUint8 *lhs = NULL;
+  FheUint8 *deserialized_lhs = NULL;
+  Config *deserialized_config = NULL;
+  FheUint8ConformanceParams *conformance_params = NULL;
+  DynamicBuffer value_buffer = {.pointer = NULL, .length = 0, .destructor = NULL};
+  DynamicBufferView deser_view = {.pointer = NULL, .length = 0};
+
+  const uint64_t max_serialization_size = UINT64_C(1) << UINT64_C(20);
+
+  uint8_t lhs_clear = 123;
+
+  ok = config_safe_deserialize(config_view, max_serialization_size, &deserialized_config);
+  assert(ok == 0);
+
+  ok = fhe_uint8_conformance_params_from_config(deserialized_config, &conformance_params);
+  assert(ok == 0);
+
+  ok = fhe_uint8_try_encrypt_with_client_key_u8(lhs_clear, client_key, &lhs);
+  assert(ok == 0);
+
+  ok = fhe_uint8_safe_serialize(lhs, &value_buffer, max_serialization_size);
+  assert(ok == 0);
+
+  deser_view.pointer = value_buffer.pointer;
+  deser_view.length = value_buffer.length;
+  ok = fhe_uint8_safe_deserialize_conformant_with_params(deser_view, max_serialization_size,
+                                                         conformance_params, &deserialized_lhs);
+  assert(ok == 0);
+
+  uint8_t clear;
+  ok = fhe_uint8_decrypt(deserialized_lhs, client_key, &clear);
+  assert(ok == 0);
+
+  assert(clear == lhs_clear);
+
+  if (value_buffer.pointer != NULL) {
+    destroy_dynamic_buffer(&value_buffer);
+  }
+  fhe_uint8_conformance_params_destroy(conformance_params);
+  config_destroy(deserialized_config);
+  fhe_uint8_destroy(lhs);
+  fhe_uint8_destroy(deserialized_lhs);
+  return ok;
+}
+
 int uint8_serialization(const ClientKey *client_key) {
   int ok;
   FheUint8 *lhs = NULL;
@@ -598,6 +646,13 @@ int main(void) {
     ok = config_builder_build(builder, &config);
     assert(ok == 0);
 
+    // Keep a serialized copy of the config, as generating the keys consumes it
+    DynamicBuffer config_buffer = {.pointer = NULL, .length = 0, .destructor = NULL};
+    ok = config_safe_serialize(config, &config_buffer, UINT64_C(1) << UINT64_C(20));
+    assert(ok == 0);
+    DynamicBufferView config_view = {.pointer = config_buffer.pointer,
+                                     .length = config_buffer.length};
+
     ClientKey *client_key = NULL;
     ServerKey *server_key = NULL;
     PublicKey *public_key = NULL;
@@ -610,6 +665,9 @@ int main(void) {
     assert(ok == 0);
     ok = uint8_safe_serialization(client_key, server_key);
     assert(ok == 0);
+    ok = uint8_safe_deserialization_conformant_with_config(client_key, config_view);
+    assert(ok == 0);
+    destroy_dynamic_buffer(&config_buffer);
     ok = uint8_compressed(client_key);
     assert(ok == 0);
 
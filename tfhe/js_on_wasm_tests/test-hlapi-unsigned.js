@@ -8,8 +8,10 @@ const {
     TfheCompressedPublicKey,
     TfheCompressedServerKey,
     TfheConfigBuilder,
+    TfheConfig,
     CompressedFheUint8,
     FheUint8,
+    FheUint8ConformanceParams,
     FheUint32,
     CompressedFheUint128,
     FheUint128,
@@ -137,6 +139,30 @@ test('hlapi_compressed_public_client_uint8_big', (t) => {
     assert.deepStrictEqual(safe_decrypted, clear);
 });
 
+test('hlapi_safe_deserialize_conformant_with_config_uint8_big', (t) => {
+    let config = TfheConfigBuilder.default()
+        .build();
+
+    let clientKey = TfheClientKey.generate(config);
+
+    let serialized_config = config.safe_serialize(BigInt(10000000));
+    let deserialized_config = TfheConfig.safe_deserialize(serialized_config, BigInt(10000000));
+    let conformance_params = FheUint8ConformanceParams.from_config(deserialized_config);
+
+    let clear = 73;
+    let encrypted = FheUint8.encrypt_with_client_key(clear, clientKey);
+    let safe_serialized = encrypted.safe_serialize(BigInt(10000000));
+    let safe_deserialized = FheUint8.safe_deserialize_conformant(
+        safe_serialized, BigInt(10000000), conformance_params);
+    assert.deepStrictEqual(safe_deserialized.decrypt(clientKey), clear);
+
+    let compressed_encrypted = CompressedFheUint8.encrypt_with_client_key(clear, clientKey);
+    let compressed_safe_serialized = compressed_encrypted.safe_serialize(BigInt(10000000));
+    let compressed_safe_deserialized = CompressedFheUint8.safe_deserialize_conformant(
+        compressed_safe_serialized, BigInt(10000000), conformance_params);
+    assert.deepStrictEqual(compressed_safe_deserialized.decompress().decrypt(clientKey), clear);
+});
+
 test('hlapi_public_key_encrypt_decrypt_uint32_small', (t) => {
     let config = TfheConfigBuilder.default_with_small_encryption().build();
 
@@ -2,7 +2,11 @@
 //!
 //! This module implements the ciphertext structure containing an encryption of a Boolean message.
 
+use crate::boolean::parameters::BooleanParameters;
+use crate::conformance::ParameterSetConformant;
 use crate::core_crypto::entities::*;
+use crate::named::Named;
+use crate::safe_serialization::{SerializedSizeBound, OBJECT_METADATA_SIZE_BOUND};
 use serde::{Deserialize, Serialize};
 use tfhe_versionable::Versionize;
 
@@ -18,6 +22,31 @@ pub enum Ciphertext {
     Trivial(bool),
 }
 
+impl Named for Ciphertext {
+    const NAME: &'static str = "boolean::Ciphertext";
+}
+
+impl ParameterSetConformant for Ciphertext {
+    type ParameterSet = BooleanParameters;
+
+    fn is_conformant(&self, parameter_set: &BooleanParameters) -> bool {
+        match self {
+            Self::Encrypted(ct) => {
+                ct.is_conformant(&parameter_set.lwe_ciphertext_conformance_params())
+            }
+            Self::Trivial(_) => true,
+        }
+    }
+}
+
+impl SerializedSizeBound for Ciphertext {
+    fn serialized_size_bound(parameter_set: &BooleanParameters) -> u64 {
+        let lwe_size = parameter_set.encryption_lwe_dimension().to_lwe_size().0 as u64;
+
+        4 * lwe_size + OBJECT_METADATA_SIZE_BOUND
+    }
+}
+
 /// A structure containing a compressed ciphertext, meant to encrypt a Boolean message.
 ///
 /// It has to be decompressed before evaluating a Boolean circuit.
@@ -27,6 +56,20 @@ pub struct CompressedCiphertext {
     pub(crate) ciphertext: SeededLweCiphertext<u32>,
 }
 
+impl Named for CompressedCiphertext {
+    const NAME: &'static str = "boolean::CompressedCiphertext";
+}
+
+impl ParameterSetConformant for CompressedCiphertext {
+    type ParameterSet = BooleanParameters;
+
+    fn is_conformant(&self, parameter_set: &BooleanParameters) -> bool {
+        let Self { ciphertext } = self;
+
+        ciphertext.is_conformant(&parameter_set.lwe_ciphertext_conformance_params())
+    }
+}
+
 impl CompressedCiphertext {
     pub fn decompress(&self) -> Ciphertext {
         Ciphertext::Encrypted(self.ciphertext.decompress_into_lwe_ciphertext())
@@ -6,12 +6,15 @@
 use crate::boolean::ciphertext::{Ciphertext, CompressedCiphertext};
 use crate::boolean::engine::{BooleanEngine, WithThreadLocalEngine};
 use crate::boolean::parameters::{BooleanParameters, DynamicDistribution, EncryptionKeyChoice};
+use crate::conformance::ParameterSetConformant;
 use crate::core_crypto::entities::*;
+use crate::named::Named;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Debug, Formatter};
 use tfhe_versionable::Versionize;
 
 use super::backward_compatibility::client_key::ClientKeyVersions;
+use zeroize::Zeroize;
 
 /// A structure containing the client key, which must be kept secret.
 ///
@@ -29,6 +32,37 @@ pub struct ClientKey {
     pub(crate) parameters: BooleanParameters,
 }
 
+impl Named for ClientKey {
+    const NAME: &'static str = "boolean::ClientKey";
+}
+
+impl ParameterSetConformant for ClientKey {
+    type ParameterSet = BooleanParameters;
+
+    fn is_conformant(&self, parameter_set: &BooleanParameters) -> bool {
+        let Self {
+            lwe_secret_key,
+            glwe_secret_key,
+            parameters,
+        } = self;
+
+        lwe_secret_key.lwe_dimension() == parameter_set.lwe_dimension
+            && glwe_secret_key.glwe_dimension() == parameter_set.glwe_dimension
+            && glwe_secret_key.polynomial_size() == parameter_set.polynomial_size
+            && parameters == parameter_set
+    }
+}
+
+/// Overwrites the secret keys with zeros, so that they do not remain in memory.
+///
+/// Wrap the key in [`zeroize::Zeroizing`] to have it zeroized when dropped.
+impl Zeroize for ClientKey {
+    fn zeroize(&mut self) {
+        self.lwe_secret_key.zeroize();
+        self.glwe_secret_key.zeroize();
+    }
+}
+
 impl PartialEq for ClientKey {
     fn eq(&self, other: &Self) -> bool {
         self.parameters == other.parameters
@@ -2,14 +2,20 @@ use crate::boolean::backward_compatibility::server_key::{
     CompressedServerKeyVersions, ServerKeyVersions,
 };
 use crate::boolean::ciphertext::Ciphertext;
+use crate::boolean::parameters::BooleanParameters;
 use crate::boolean::{ClientKey, PLAINTEXT_TRUE};
+use crate::conformance::ParameterSetConformant;
 use crate::core_crypto::algorithms::*;
 use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
 use crate::core_crypto::commons::generators::{DeterministicSeeder, EncryptionRandomGenerator};
 use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, Seeder};
+use crate::core_crypto::commons::numeric::UnsignedInteger;
 use crate::core_crypto::commons::parameters::{CiphertextModulus, PBSOrder};
 use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::BootstrapKeyConformanceParams;
 use crate::core_crypto::fft_impl::fft64::math::fft::Fft;
+use crate::named::Named;
+use crate::safe_serialization::{SerializedSizeBound, OBJECT_METADATA_SIZE_BOUND};
 use serde::{Deserialize, Serialize};
 use tfhe_versionable::Versionize;
 
@@ -177,6 +183,78 @@ impl ServerKey {
     }
 }
 
+impl Named for ServerKey {
+    const NAME: &'static str = "boolean::ServerKey";
+}
+
+fn bootstrap_key_conformance_params<Scalar: UnsignedInteger>(
+    parameter_set: &BooleanParameters,
+) -> BootstrapKeyConformanceParams<Scalar> {
+    BootstrapKeyConformanceParams {
+        decomp_base_log: parameter_set.pbs_base_log,
+        decomp_level_count: parameter_set.pbs_level,
+        input_lwe_dimension: parameter_set.lwe_dimension,
+        output_glwe_size: parameter_set.glwe_dimension.to_glwe_size(),
+        polynomial_size: parameter_set.polynomial_size,
+        ciphertext_modulus: CiphertextModulus::new_native(),
+    }
+}
+
+fn keyswitch_key_conformance_params(
+    parameter_set: &BooleanParameters,
+) -> KeyswitchKeyConformanceParams<u32> {
+    // The keyswitching key goes from the big key to the small key whatever the PBS order
+    KeyswitchKeyConformanceParams {
+        decomp_base_log: parameter_set.ks_base_log,
+        decomp_level_count: parameter_set.ks_level,
+        output_lwe_size: parameter_set.lwe_dimension.to_lwe_size(),
+        input_lwe_dimension: parameter_set
+            .glwe_dimension
+            .to_equivalent_lwe_dimension(parameter_set.polynomial_size),
+        ciphertext_modulus: CiphertextModulus::new_native(),
+    }
+}
+
+impl ParameterSetConformant for ServerKey {
+    type ParameterSet = BooleanParameters;
+
+    fn is_conformant(&self, parameter_set: &BooleanParameters) -> bool {
+        let Self {
+            bootstrapping_key,
+            key_switching_key,
+            pbs_order,
+        } = self;
+
+        bootstrapping_key.is_conformant(&bootstrap_key_conformance_params(parameter_set))
+            && key_switching_key.is_conformant(&keyswitch_key_conformance_params(parameter_set))
+            && *pbs_order == PBSOrder::from(parameter_set.encryption_key_choice)
+    }
+}
+
+impl SerializedSizeBound for ServerKey {
+    fn serialized_size_bound(parameter_set: &BooleanParameters) -> u64 {
+        let big_lwe_dimension = parameter_set
+            .glwe_dimension
+            .to_equivalent_lwe_dimension(parameter_set.polynomial_size)
+            .0 as u64;
+        let small_lwe_size = parameter_set.lwe_dimension.to_lwe_size().0 as u64;
+
+        let ksk_size = 4 * big_lwe_dimension * parameter_set.ks_level.0 as u64 * small_lwe_size;
+
+        // Each GGSW has level * glwe_size^2 polynomials, of polynomial_size / 2 complex
+        // coefficients of 16 bytes in the Fourier domain
+        let glwe_size = parameter_set.glwe_dimension.to_glwe_size().0 as u64;
+        let bsk_size = 16
+            * parameter_set.lwe_dimension.0 as u64
+            * parameter_set.pbs_level.0 as u64
+            * glwe_size
+            * glwe_size
+            * (parameter_set.polynomial_size.0 as u64 / 2);
+
+        ksk_size + bsk_size + OBJECT_METADATA_SIZE_BOUND
+    }
+}
+
 /// A structure containing the compressed server public key.
 ///
 /// This server key data lives on the CPU.
@@ -195,6 +273,46 @@ pub struct CompressedServerKey {
     pub(crate) pbs_order: PBSOrder,
 }
 
+impl Named for CompressedServerKey {
+    const NAME: &'static str = "boolean::CompressedServerKey";
+}
+
+impl ParameterSetConformant for CompressedServerKey {
+    type ParameterSet = BooleanParameters;
+
+    fn is_conformant(&self, parameter_set: &BooleanParameters) -> bool {
+        let Self {
+            bootstrapping_key,
+            key_switching_key,
+            pbs_order,
+        } = self;
+
+        bootstrapping_key.is_conformant(&bootstrap_key_conformance_params(parameter_set))
+            && key_switching_key.is_conformant(&keyswitch_key_conformance_params(parameter_set))
+            && *pbs_order == PBSOrder::from(parameter_set.encryption_key_choice)
+    }
+}
+
+impl SerializedSizeBound for CompressedServerKey {
+    fn serialized_size_bound(parameter_set: &BooleanParameters) -> u64 {
+        // Only the bodies of the keys are stored, the masks are regenerated from seeds
+        let big_lwe_dimension = parameter_set
+            .glwe_dimension
+            .to_equivalent_lwe_dimension(parameter_set.polynomial_size)
+            .0 as u64;
+        let ksk_size = 4 * big_lwe_dimension * parameter_set.ks_level.0 as u64;
+
+        // Each GGSW has level * glwe_size body polynomials of 4 bytes coefficients
+        let bsk_size = 4
+            * parameter_set.lwe_dimension.0 as u64
+            * parameter_set.pbs_level.0 as u64
+            * parameter_set.glwe_dimension.to_glwe_size().0 as u64
+            * parameter_set.polynomial_size.0 as u64;
+
+        ksk_size + bsk_size + OBJECT_METADATA_SIZE_BOUND
+    }
+}
+
 impl CompressedServerKey {
     /// Decompress a [`CompressedServerKey`] into a [`ServerKey`].
     pub fn decompress(&self) -> ServerKey {
@@ -76,9 +76,6 @@ impl WithThreadLocalEngine for BooleanEngine {
     }
 }
 
-// We have q = 2^32 so log2q = 32
-const LOG2_Q_32: usize = 32;
-
 impl BooleanEngine {
     pub fn create_client_key(&mut self, parameters: BooleanParameters) -> ClientKey {
         // generate the lwe secret key
@@ -112,10 +109,7 @@ impl BooleanEngine {
     pub fn create_public_key(&mut self, client_key: &ClientKey) -> PublicKey {
         let (lwe_sk, encryption_noise) = client_key.encryption_key_and_noise();
 
-        // Formula is (n + 1) * log2(q) + 128
-        let zero_encryption_count = LwePublicKeyZeroEncryptionCount(
-            lwe_sk.lwe_dimension().to_lwe_size().0 * LOG2_Q_32 + 128,
-        );
+        let zero_encryption_count = client_key.parameters.public_key_zero_encryption_count();
 
         #[cfg(not(feature = "__wasm_api"))]
         let lwe_public_key: LwePublicKeyOwned<u32> = par_allocate_and_generate_new_lwe_public_key(
@@ -144,10 +138,7 @@ impl BooleanEngine {
     pub fn create_compressed_public_key(&mut self, client_key: &ClientKey) -> CompressedPublicKey {
         let (lwe_sk, encryption_noise) = client_key.encryption_key_and_noise();
 
-        // Formula is (n + 1) * log2(q) + 128
-        let zero_encryption_count = LwePublicKeyZeroEncryptionCount(
-            lwe_sk.lwe_dimension().to_lwe_size().0 * LOG2_Q_32 + 128,
-        );
+        let zero_encryption_count = client_key.parameters.public_key_zero_encryption_count();
 
         #[cfg(not(feature = "__wasm_api"))]
         let compressed_lwe_public_key = par_allocate_and_generate_new_seeded_lwe_public_key(
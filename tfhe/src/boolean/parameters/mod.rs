@@ -27,6 +27,8 @@ pub use crate::core_crypto::commons::parameters::{
 };
 pub use params::*;
 
+use crate::core_crypto::commons::parameters::{CiphertextModulus, LwePublicKeyZeroEncryptionCount};
+use crate::core_crypto::entities::{LweCiphertextParameters, MsDecompressionType};
 use serde::{Deserialize, Serialize};
 use tfhe_versionable::Versionize;
 
@@ -97,6 +99,32 @@ impl BooleanParameters {
             encryption_key_choice,
         }
     }
+
+    /// Returns the dimension of the LWE ciphertexts encrypted with these parameters
+    pub(crate) fn encryption_lwe_dimension(&self) -> LweDimension {
+        match self.encryption_key_choice {
+            EncryptionKeyChoice::Big => self
+                .glwe_dimension
+                .to_equivalent_lwe_dimension(self.polynomial_size),
+            EncryptionKeyChoice::Small => self.lwe_dimension,
+        }
+    }
+
+    /// Returns the number of encryptions of zero in the public keys generated with these
+    /// parameters
+    pub(crate) fn public_key_zero_encryption_count(&self) -> LwePublicKeyZeroEncryptionCount {
+        // We have q = 2^32 so log2q = 32, the formula is (n + 1) * log2(q) + 128
+        LwePublicKeyZeroEncryptionCount(self.encryption_lwe_dimension().to_lwe_size().0 * 32 + 128)
+    }
+
+    /// Returns the expected properties of the LWE ciphertexts encrypted with these parameters
+    pub(crate) fn lwe_ciphertext_conformance_params(&self) -> LweCiphertextParameters<u32> {
+        LweCiphertextParameters {
+            lwe_dim: self.encryption_lwe_dimension(),
+            ct_modulus: CiphertextModulus::new_native(),
+            ms_decompression_method: MsDecompressionType::ClassicPbs,
+        }
+    }
 }
 
 /// A set of cryptographic parameters for homomorphic Boolean key switching.
@@ -1,7 +1,10 @@
 use crate::boolean::backward_compatibility::public_key::CompressedPublicKeyVersions;
 use crate::boolean::engine::{BooleanEngine, WithThreadLocalEngine};
 use crate::boolean::prelude::{BooleanParameters, Ciphertext, ClientKey};
-use crate::core_crypto::prelude::SeededLwePublicKeyOwned;
+use crate::conformance::ParameterSetConformant;
+use crate::core_crypto::prelude::{CiphertextModulus, SeededLwePublicKeyOwned};
+use crate::named::Named;
+use crate::safe_serialization::{SerializedSizeBound, OBJECT_METADATA_SIZE_BOUND};
 use serde::{Deserialize, Serialize};
 use tfhe_versionable::Versionize;
 
@@ -13,6 +16,37 @@ pub struct CompressedPublicKey {
     pub parameters: BooleanParameters,
 }
 
+impl Named for CompressedPublicKey {
+    const NAME: &'static str = "boolean::CompressedPublicKey";
+}
+
+impl ParameterSetConformant for CompressedPublicKey {
+    type ParameterSet = BooleanParameters;
+
+    fn is_conformant(&self, parameter_set: &BooleanParameters) -> bool {
+        let Self {
+            compressed_lwe_public_key,
+            parameters,
+        } = self;
+
+        // Only the bodies of the encryptions of zero are stored
+        compressed_lwe_public_key.lwe_size()
+            == parameter_set.encryption_lwe_dimension().to_lwe_size()
+            && compressed_lwe_public_key.as_ref().len()
+                == parameter_set.public_key_zero_encryption_count().0
+            && compressed_lwe_public_key.ciphertext_modulus() == CiphertextModulus::new_native()
+            && parameters == parameter_set
+    }
+}
+
+impl SerializedSizeBound for CompressedPublicKey {
+    fn serialized_size_bound(parameter_set: &BooleanParameters) -> u64 {
+        let zero_encryption_count = parameter_set.public_key_zero_encryption_count().0 as u64;
+
+        4 * zero_encryption_count + OBJECT_METADATA_SIZE_BOUND
+    }
+}
+
 impl CompressedPublicKey {
     /// Generates a new public key that is compressed
     ///
@@ -6,7 +6,11 @@ use crate::boolean::ciphertext::Ciphertext;
 use crate::boolean::client_key::ClientKey;
 use crate::boolean::engine::{BooleanEngine, WithThreadLocalEngine};
 use crate::boolean::parameters::BooleanParameters;
+use crate::conformance::ParameterSetConformant;
+use crate::core_crypto::commons::parameters::CiphertextModulus;
 use crate::core_crypto::entities::*;
+use crate::named::Named;
+use crate::safe_serialization::{SerializedSizeBound, OBJECT_METADATA_SIZE_BOUND};
 use serde::{Deserialize, Serialize};
 use tfhe_versionable::Versionize;
 
@@ -18,6 +22,38 @@ pub struct PublicKey {
     pub(crate) parameters: BooleanParameters,
 }
 
+impl Named for PublicKey {
+    const NAME: &'static str = "boolean::PublicKey";
+}
+
+impl ParameterSetConformant for PublicKey {
+    type ParameterSet = BooleanParameters;
+
+    fn is_conformant(&self, parameter_set: &BooleanParameters) -> bool {
+        let Self {
+            lwe_public_key,
+            parameters,
+        } = self;
+
+        let lwe_size = parameter_set.encryption_lwe_dimension().to_lwe_size();
+        let zero_encryption_count = parameter_set.public_key_zero_encryption_count();
+
+        lwe_public_key.lwe_size() == lwe_size
+            && lwe_public_key.as_ref().len() == zero_encryption_count.0 * lwe_size.0
+            && lwe_public_key.ciphertext_modulus() == CiphertextModulus::new_native()
+            && parameters == parameter_set
+    }
+}
+
+impl SerializedSizeBound for PublicKey {
+    fn serialized_size_bound(parameter_set: &BooleanParameters) -> u64 {
+        let lwe_size = parameter_set.encryption_lwe_dimension().to_lwe_size().0 as u64;
+        let zero_encryption_count = parameter_set.public_key_zero_encryption_count().0 as u64;
+
+        4 * zero_encryption_count * lwe_size + OBJECT_METADATA_SIZE_BOUND
+    }
+}
+
 impl PublicKey {
     /// Encrypt a Boolean message using the public key.
     ///
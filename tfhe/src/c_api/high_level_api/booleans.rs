@@ -6,9 +6,11 @@ pub struct FheBool(pub(in crate::c_api) crate::high_level_api::FheBool);
 
 impl_destroy_on_type!(FheBool);
 impl_clone_on_type!(FheBool);
+impl_handle_on_type!(FheBool);
 impl_serialize_deserialize_on_type!(FheBool);
 impl_safe_serialize_on_type!(FheBool);
 impl_safe_deserialize_conformant_on_type!(FheBool, FheBoolConformanceParams);
+impl_conformance_params_on_type!(FheBoolConformanceParams);
 
 impl_binary_fn_on_type!(FheBool => bitand, bitor, bitxor);
 impl_binary_assign_fn_on_type!(FheBool => bitand_assign,  bitor_assign, bitxor_assign);
@@ -44,6 +46,7 @@ pub struct CompressedFheBool(crate::high_level_api::CompressedFheBool);
 
 impl_destroy_on_type!(CompressedFheBool);
 impl_clone_on_type!(CompressedFheBool);
+impl_handle_on_type!(CompressedFheBool);
 impl_serialize_deserialize_on_type!(CompressedFheBool);
 impl_safe_serialize_on_type!(CompressedFheBool);
 impl_safe_deserialize_conformant_on_type!(CompressedFheBool, FheBoolConformanceParams);
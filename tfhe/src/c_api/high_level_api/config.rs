@@ -8,6 +8,8 @@ pub struct Config(pub(in crate::c_api) crate::high_level_api::Config);
 
 impl_destroy_on_type!(ConfigBuilder);
 impl_destroy_on_type!(Config);
+impl_safe_serialize_on_type!(Config);
+impl_safe_deserialize_on_type!(Config);
 
 #[no_mangle]
 pub unsafe extern "C" fn config_builder_default(result: *mut *mut ConfigBuilder) -> c_int {
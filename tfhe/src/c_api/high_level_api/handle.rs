@@ -0,0 +1,74 @@
+//! Reference counted handles
+//!
+//! Objects of the C API are owned by a single pointer, which must be destroyed exactly once with
+//! the `_destroy` function of its type. This is hard to guarantee for bindings of garbage
+//! collected languages (Go, Java, ...) where finalizers run in any order, on any thread, and
+//! possibly while another object still uses the one being finalized.
+//!
+//! A [TfheHandle] is an alternative to this ownership model: an object is moved into a handle,
+//! handles can be cloned and released independently of each other, and the object is destroyed
+//! when the last handle referencing it is released. The reference counting is atomic, so handles
+//! can be released concurrently from multiple threads.
+use super::super::utils::{catch_panic, check_ptr_is_non_null_and_aligned, get_ref_checked};
+use std::any::Any;
+use std::ffi::c_int;
+use std::sync::Arc;
+
+/// A reference counted handle to an object of the C API
+pub struct TfheHandle(pub(in crate::c_api) Arc<dyn Any + Send + Sync>);
+
+impl TfheHandle {
+    pub(in crate::c_api) fn new<T: Any + Send + Sync>(object: T) -> Self {
+        Self(Arc::new(object))
+    }
+
+    pub(in crate::c_api) fn get<T: Any>(&self) -> Option<&T> {
+        self.0.downcast_ref::<T>()
+    }
+}
+
+/// Creates a new handle referencing the same object as `handle`
+///
+/// Both handles have to be released, the object is destroyed when the last one is.
+#[no_mangle]
+pub unsafe extern "C" fn tfhe_handle_clone(
+    handle: *const TfheHandle,
+    result: *mut *mut TfheHandle,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let handle = get_ref_checked(handle).unwrap();
+
+        *result = Box::into_raw(Box::new(TfheHandle(Arc::clone(&handle.0))));
+    })
+}
+
+/// Releases a handle, the object it references is destroyed if it was the last handle
+/// referencing it
+///
+/// handle can be null (no-op in that case)
+#[no_mangle]
+pub unsafe extern "C" fn tfhe_handle_release(handle: *mut TfheHandle) -> c_int {
+    catch_panic(|| {
+        if !handle.is_null() {
+            check_ptr_is_non_null_and_aligned(handle).unwrap();
+            drop(Box::from_raw(handle));
+        }
+    })
+}
+
+/// Returns the number of handles referencing the same object as `handle` (including itself)
+#[no_mangle]
+pub unsafe extern "C" fn tfhe_handle_ref_count(
+    handle: *const TfheHandle,
+    result: *mut usize,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let handle = get_ref_checked(handle).unwrap();
+
+        *result = Arc::strong_count(&handle.0);
+    })
+}
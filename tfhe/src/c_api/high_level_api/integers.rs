@@ -304,9 +304,13 @@ macro_rules! create_integer_wrapper_type {
 
         impl_clone_on_type!($name);
 
+        impl_handle_on_type!($name);
+
         impl_safe_serialize_on_type!($name);
 
         ::paste::paste! {
+            impl_conformance_params_on_type!([<$name ConformanceParams>]);
+
             impl_safe_deserialize_conformant_on_type!($name, [<$name ConformanceParams>]);
         }
 
@@ -320,6 +324,8 @@ macro_rules! create_integer_wrapper_type {
 
             impl_clone_on_type!([<Compressed $name>]);
 
+            impl_handle_on_type!([<Compressed $name>]);
+
             impl_try_encrypt_with_client_key_on_type!([<Compressed $name>]{crate::high_level_api::[<Compressed $name>]}, $clear_scalar_type);
 
             impl_serialize_deserialize_on_type!([<Compressed $name>]);
@@ -513,6 +519,26 @@ macro_rules! impl_oprf_for_uint {
                 })
             }
         }
+
+        ::paste::paste! {
+            #[no_mangle]
+            pub unsafe extern "C" fn [<generate_oblivious_pseudo_random_in_range_ $name:snake>](
+                out_result: *mut *mut $name,
+                seed_low_bytes: u64,
+                seed_high_bytes: u64,
+                excluded_upper_bound: u64,
+            ) -> c_int {
+
+                $crate::c_api::utils::catch_panic(|| {
+                    let seed_low_bytes: u128 = seed_low_bytes.into();
+                    let seed_high_bytes: u128 = seed_high_bytes.into();
+                    let seed = crate::Seed((seed_high_bytes << 64) | seed_low_bytes);
+
+                    let result = crate::FheUint::generate_oblivious_pseudo_random_in_range(seed, excluded_upper_bound);
+                    *out_result = Box::into_raw(Box::new($name(result)));
+                })
+            }
+        }
     };
 }
 
@@ -27,6 +27,11 @@ impl_destroy_on_type!(CompressedServerKey);
 #[cfg(feature = "gpu")]
 impl_destroy_on_type!(CudaServerKey);
 
+impl_handle_on_type!(ClientKey);
+impl_handle_on_type!(PublicKey);
+impl_handle_on_type!(CompactPublicKey);
+impl_handle_on_type!(ServerKey);
+
 impl_serialize_deserialize_on_type!(ClientKey);
 impl_serialize_deserialize_on_type!(PublicKey);
 impl_serialize_deserialize_on_type!(CompactPublicKey);
@@ -4,6 +4,7 @@ pub mod booleans;
 mod compact_list;
 pub mod compressed_ciphertext_list;
 pub mod config;
+pub mod handle;
 pub mod i128;
 pub mod i256;
 pub mod integers;
@@ -52,6 +53,17 @@ pub enum FheTypes {
     Type_FheInt128,
     Type_FheInt160,
     Type_FheInt256,
+    Type_FheInt512,
+    Type_FheInt1024,
+    Type_FheInt2048,
+    Type_FheUint24,
+    Type_FheUint40,
+    Type_FheUint48,
+    Type_FheUint72,
+    Type_FheInt24,
+    Type_FheInt40,
+    Type_FheInt48,
+    Type_FheInt72,
 }
 
 impl From<crate::FheTypes> for FheTypes {
@@ -87,6 +99,17 @@ impl From<crate::FheTypes> for FheTypes {
             crate::FheTypes::Int128 => Self::Type_FheInt128,
             crate::FheTypes::Int160 => Self::Type_FheInt160,
             crate::FheTypes::Int256 => Self::Type_FheInt256,
+            crate::FheTypes::Int512 => Self::Type_FheInt512,
+            crate::FheTypes::Int1024 => Self::Type_FheInt1024,
+            crate::FheTypes::Int2048 => Self::Type_FheInt2048,
+            crate::FheTypes::Uint24 => Self::Type_FheUint24,
+            crate::FheTypes::Uint40 => Self::Type_FheUint40,
+            crate::FheTypes::Uint48 => Self::Type_FheUint48,
+            crate::FheTypes::Uint72 => Self::Type_FheUint72,
+            crate::FheTypes::Int24 => Self::Type_FheInt24,
+            crate::FheTypes::Int40 => Self::Type_FheInt40,
+            crate::FheTypes::Int48 => Self::Type_FheInt48,
+            crate::FheTypes::Int72 => Self::Type_FheInt72,
         }
     }
 }
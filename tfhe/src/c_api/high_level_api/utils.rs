@@ -222,6 +222,58 @@ macro_rules! impl_clone_on_type {
 
 pub(crate) use impl_clone_on_type;
 
+macro_rules! impl_handle_on_type {
+    ($wrapper_type:ty) => {
+        ::paste::paste! {
+            #[no_mangle]
+            #[doc = "Moves the object into a new reference counted handle.\n\n"]
+            #[doc = "The object is owned by the handle afterward, `ptr` must not be used nor "]
+            #[doc = "destroyed anymore."]
+            pub unsafe extern "C" fn [<$wrapper_type:snake _into_handle>](
+                ptr: *mut $wrapper_type,
+                result: *mut *mut $crate::c_api::high_level_api::handle::TfheHandle,
+            ) -> ::std::os::raw::c_int {
+                $crate::c_api::utils::catch_panic(|| {
+                    $crate::c_api::utils::check_ptr_is_non_null_and_aligned(result).unwrap();
+                    $crate::c_api::utils::check_ptr_is_non_null_and_aligned(ptr).unwrap();
+
+                    let object = *Box::from_raw(ptr);
+                    let handle = $crate::c_api::high_level_api::handle::TfheHandle::new(object);
+
+                    *result = Box::into_raw(Box::new(handle));
+                })
+            }
+
+            #[no_mangle]
+            #[doc = "Gets a pointer to the object referenced by the handle.\n\n"]
+            #[doc = "The pointer is valid as long as a handle referencing the object exists, "]
+            #[doc = "it must not be destroyed. Fails if the handle references an object of "]
+            #[doc = "another type."]
+            pub unsafe extern "C" fn [<tfhe_handle_get_ $wrapper_type:snake>](
+                handle: *const $crate::c_api::high_level_api::handle::TfheHandle,
+                result: *mut *const $wrapper_type,
+            ) -> ::std::os::raw::c_int {
+                $crate::c_api::utils::catch_panic(|| {
+                    $crate::c_api::utils::check_ptr_is_non_null_and_aligned(result).unwrap();
+
+                    let handle = $crate::c_api::utils::get_ref_checked(handle).unwrap();
+
+                    let object = handle.get::<$wrapper_type>().unwrap_or_else(|| {
+                        panic!(
+                            "The handle does not reference a {}",
+                            stringify!($wrapper_type)
+                        )
+                    });
+
+                    *result = ::std::ptr::from_ref(object);
+                })
+            }
+        }
+    };
+}
+
+pub(crate) use impl_handle_on_type;
+
 macro_rules! impl_serialize_deserialize_on_type {
     ($wrapper_type:ty) => {
         ::paste::paste! {
@@ -348,12 +400,94 @@ macro_rules! impl_safe_deserialize_conformant_on_type {
                 })
             }
 
+            #[no_mangle]
+            /// Deserializes safely, and checks that the resulting ciphertext
+            /// is in compliance with the given conformance parameters.
+            ///
+            /// This function can only deserialize types which have been serialized
+            /// by a `safe_serialize` function.
+            ///
+            /// - `serialized_size_limit`: size limit (in number of byte) of the serialized object
+            ///    (to avoid out of memory attacks)
+            /// - `conformance_params`: parameters used in the conformance check
+            /// - `result`: pointer where resulting deserialized object needs to be stored.
+            ///    * cannot be NULL
+            ///    * (*result) will point the deserialized object on success, else NULL
+            pub unsafe extern "C" fn [<$wrapper_type:snake _safe_deserialize_conformant_with_params>](
+                buffer_view: crate::c_api::buffer::DynamicBufferView,
+                serialized_size_limit: u64,
+                conformance_params: *const $conformance_param_type,
+                result: *mut *mut $wrapper_type,
+            ) -> ::std::os::raw::c_int {
+                crate::c_api::utils::catch_panic(|| {
+                    crate::c_api::utils::check_ptr_is_non_null_and_aligned(result).unwrap();
+
+                    let params = crate::c_api::utils::get_ref_checked(conformance_params).unwrap();
+
+                    let buffer_view: &[u8] = buffer_view.as_slice();
+
+                    // First fill the result with a null ptr so that if we fail and the return code is not
+                    // checked, then any access to the result pointer will segfault (mimics malloc on failure)
+                    *result = std::ptr::null_mut();
+
+                    let inner = $crate::safe_serialization::DeserializationConfig::new(
+                        serialized_size_limit)
+                    .deserialize_from(buffer_view, &params.0)
+                    .unwrap();
+
+                    let heap_allocated_object = Box::new($wrapper_type(inner));
+
+                    *result = Box::into_raw(heap_allocated_object);
+                })
+            }
         }
     };
 }
 
 pub(crate) use impl_safe_deserialize_conformant_on_type;
 
+/// Defines the C wrapper of a conformance parameter type,
+/// which can be created from a [Config](crate::c_api::high_level_api::config::Config)
+///
+/// This allows checking the conformance of ciphertexts without a server key,
+/// e.g. using a config obtained with `config_safe_deserialize`.
+macro_rules! impl_conformance_params_on_type {
+    ($conformance_param_type:ident) => {
+        pub struct $conformance_param_type(
+            pub(in $crate::c_api) $crate::high_level_api::$conformance_param_type,
+        );
+
+        impl_destroy_on_type!($conformance_param_type);
+
+        ::paste::paste! {
+            #[no_mangle]
+            /// Creates the conformance parameters of ciphertexts encrypted
+            /// with keys generated from the `config`.
+            ///
+            /// - `result`: pointer where the conformance parameters need to be stored.
+            ///    * cannot be NULL
+            pub unsafe extern "C" fn [<$conformance_param_type:snake _from_config>](
+                config: *const crate::c_api::high_level_api::config::Config,
+                result: *mut *mut $conformance_param_type,
+            ) -> ::std::os::raw::c_int {
+                crate::c_api::utils::catch_panic(|| {
+                    crate::c_api::utils::check_ptr_is_non_null_and_aligned(result).unwrap();
+
+                    let config = crate::c_api::utils::get_ref_checked(config).unwrap();
+
+                    let params = $crate::high_level_api::$conformance_param_type::from(
+                        config.0.inner.block_parameters,
+                    );
+
+                    *result = Box::into_raw(Box::new($conformance_param_type(params)));
+                })
+            }
+        }
+    };
+}
+
+pub(crate) use impl_conformance_params_on_type;
+
 macro_rules! impl_safe_deserialize_on_type {
     ($wrapper_type:ty) => {
         ::paste::paste! {
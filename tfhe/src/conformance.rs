@@ -12,6 +12,7 @@ pub trait ParameterSetConformant {
 /// - bigger or equal to `min_inclusive_group_count`
 /// - smaller of equal to `max_inclusive_group_count`
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
 pub struct ListSizeConstraint {
     min_inclusive_group_count: usize,
     max_inclusive_group_count: usize,
@@ -0,0 +1,255 @@
+//! Module containing primitives pertaining to the application of [`GLWE automorphism
+//! keys`](`GlweAutomorphismKey`).
+
+use crate::core_crypto::algorithms::polynomial_algorithms::{
+    polynomial_wrapping_automorphism, polynomial_wrapping_sub_mul_assign,
+};
+use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::{
+    GlweAutomorphismKey, GlweCiphertext, Polynomial, PolynomialListOwned,
+};
+
+/// Apply the automorphism $X \mapsto X^{k}$ of a [`GLWE automorphism key`](`GlweAutomorphismKey`)
+/// to the plaintext of an input [`GLWE ciphertext`](`GlweCiphertext`) and write the result in an
+/// output [`GLWE ciphertext`](`GlweCiphertext`) encrypted under the same secret key.
+///
+/// The output decrypts to the input plaintext polynomial with each coefficient of degree $i$
+/// moved to degree $i \cdot k$, see
+/// [`polynomial_wrapping_automorphism`](`crate::core_crypto::algorithms::polynomial_algorithms::polynomial_wrapping_automorphism`).
+///
+/// ```rust
+/// use tfhe::core_crypto::algorithms::polynomial_algorithms::polynomial_wrapping_automorphism;
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweAutomorphismKey creation
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(2048);
+/// let decomp_base_log = DecompositionBaseLog(23);
+/// let decomp_level_count = DecompositionLevelCount(1);
+/// let automorphism_exponent = 5;
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let automorphism_key = allocate_and_generate_new_glwe_automorphism_key(
+///     &glwe_secret_key,
+///     automorphism_exponent,
+///     decomp_base_log,
+///     decomp_level_count,
+///     glwe_noise_distribution,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Create the plaintext list
+/// let mut input_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+/// input_plaintext_list
+///     .iter_mut()
+///     .enumerate()
+///     .for_each(|(idx, dst)| *dst.0 = (idx as u64 % 16) << 60);
+///
+/// // Create a new GlweCiphertext
+/// let mut input_glwe = GlweCiphertext::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// encrypt_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &mut input_glwe,
+///     &input_plaintext_list,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut output_glwe = GlweCiphertext::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// apply_glwe_automorphism(&automorphism_key, &input_glwe, &mut output_glwe);
+///
+/// let mut decrypted_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+///
+/// decrypt_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &output_glwe,
+///     &mut decrypted_plaintext_list,
+/// );
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// decrypted_plaintext_list
+///     .iter_mut()
+///     .for_each(|x| *x.0 = decomposer.closest_representable(*x.0) >> 60);
+///
+/// // Compute the expected result in the clear
+/// let mut expected = Polynomial::new(0u64, polynomial_size);
+/// polynomial_wrapping_automorphism(
+///     &mut expected,
+///     &input_plaintext_list.as_polynomial(),
+///     automorphism_exponent,
+/// );
+/// expected.iter_mut().for_each(|x| *x >>= 60);
+///
+/// // Check we recovered the expected message
+/// assert_eq!(decrypted_plaintext_list.as_ref(), expected.as_ref());
+/// ```
+pub fn apply_glwe_automorphism<Scalar, KeyCont, InputCont, OutputCont>(
+    automorphism_key: &GlweAutomorphismKey<KeyCont>,
+    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert!(
+        automorphism_key.glwe_size() == input_glwe_ciphertext.glwe_size(),
+        "Mismatched input GlweSize. \
+        GlweAutomorphismKey GlweSize: {:?}, input GlweCiphertext GlweSize {:?}.",
+        automorphism_key.glwe_size(),
+        input_glwe_ciphertext.glwe_size()
+    );
+    assert!(
+        automorphism_key.glwe_size() == output_glwe_ciphertext.glwe_size(),
+        "Mismatched output GlweSize. \
+        GlweAutomorphismKey GlweSize: {:?}, output GlweCiphertext GlweSize {:?}.",
+        automorphism_key.glwe_size(),
+        output_glwe_ciphertext.glwe_size()
+    );
+    assert!(
+        automorphism_key.polynomial_size() == input_glwe_ciphertext.polynomial_size(),
+        "Mismatched input PolynomialSize. \
+        GlweAutomorphismKey PolynomialSize: {:?}, input GlweCiphertext PolynomialSize {:?}.",
+        automorphism_key.polynomial_size(),
+        input_glwe_ciphertext.polynomial_size()
+    );
+    assert!(
+        automorphism_key.polynomial_size() == output_glwe_ciphertext.polynomial_size(),
+        "Mismatched output PolynomialSize. \
+        GlweAutomorphismKey PolynomialSize: {:?}, output GlweCiphertext PolynomialSize {:?}.",
+        automorphism_key.polynomial_size(),
+        output_glwe_ciphertext.polynomial_size()
+    );
+    assert!(
+        automorphism_key.ciphertext_modulus() == input_glwe_ciphertext.ciphertext_modulus(),
+        "Mismatched CiphertextModulus. \
+        GlweAutomorphismKey CiphertextModulus: {:?}, \
+        input GlweCiphertext CiphertextModulus {:?}.",
+        automorphism_key.ciphertext_modulus(),
+        input_glwe_ciphertext.ciphertext_modulus()
+    );
+    assert!(
+        automorphism_key.ciphertext_modulus() == output_glwe_ciphertext.ciphertext_modulus(),
+        "Mismatched CiphertextModulus. \
+        GlweAutomorphismKey CiphertextModulus: {:?}, \
+        output GlweCiphertext CiphertextModulus {:?}.",
+        automorphism_key.ciphertext_modulus(),
+        output_glwe_ciphertext.ciphertext_modulus()
+    );
+    assert!(
+        input_glwe_ciphertext
+            .ciphertext_modulus()
+            .is_compatible_with_native_modulus(),
+        "This operation currently only supports power of 2 moduli"
+    );
+
+    let polynomial_size = automorphism_key.polynomial_size();
+    let decomp_level_count = automorphism_key.decomposition_level_count();
+    let automorphism_exponent = automorphism_key.automorphism_exponent();
+
+    // The output starts as a trivial encryption of the transformed body, the transformed mask is
+    // then keyswitched back to the original secret key
+    output_glwe_ciphertext.as_mut().fill(Scalar::ZERO);
+    polynomial_wrapping_automorphism(
+        &mut output_glwe_ciphertext.get_mut_body().as_mut_polynomial(),
+        &input_glwe_ciphertext.get_body().as_polynomial(),
+        automorphism_exponent,
+    );
+
+    // We instantiate a decomposer
+    let decomposer = SignedDecomposer::new(
+        automorphism_key.decomposition_base_log(),
+        decomp_level_count,
+    );
+
+    let mut transformed_mask_polynomial = Polynomial::new(Scalar::ZERO, polynomial_size);
+    // The decomposition of the transformed mask polynomial, one polynomial per level
+    let mut decomposed_polynomials = PolynomialListOwned::new(
+        Scalar::ZERO,
+        polynomial_size,
+        PolynomialCount(decomp_level_count.0),
+    );
+
+    for (automorphism_key_block, mask_polynomial) in automorphism_key
+        .iter()
+        .zip(input_glwe_ciphertext.get_mask().as_polynomial_list().iter())
+    {
+        polynomial_wrapping_automorphism(
+            &mut transformed_mask_polynomial,
+            &mask_polynomial,
+            automorphism_exponent,
+        );
+
+        // We decompose each coefficient, the terms are stored in the order of the levels of the
+        // key
+        for (coefficient_index, &coefficient) in
+            transformed_mask_polynomial.as_ref().iter().enumerate()
+        {
+            let rounded = decomposer.closest_representable(coefficient);
+            for (mut decomposed_polynomial, term) in decomposed_polynomials
+                .iter_mut()
+                .zip(decomposer.decompose(rounded))
+            {
+                decomposed_polynomial[coefficient_index] = term.value();
+            }
+        }
+
+        // Loop over the number of levels:
+        // We compute the multiplication of a ciphertext from the automorphism key with a piece of
+        // the decomposition and subtract it to the output
+        for (level_key_cipher, decomposed_polynomial) in automorphism_key_block
+            .iter()
+            .zip(decomposed_polynomials.iter())
+        {
+            for (mut output_polynomial, key_polynomial) in output_glwe_ciphertext
+                .as_mut_polynomial_list()
+                .iter_mut()
+                .zip(level_key_cipher.as_polynomial_list().iter())
+            {
+                polynomial_wrapping_sub_mul_assign(
+                    &mut output_polynomial,
+                    &key_polynomial,
+                    &decomposed_polynomial,
+                );
+            }
+        }
+    }
+}
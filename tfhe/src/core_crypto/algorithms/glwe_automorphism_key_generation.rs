@@ -0,0 +1,189 @@
+//! Module containing primitives pertaining to [`GLWE automorphism keys
+//! generation`](`GlweAutomorphismKey`).
+
+use crate::core_crypto::algorithms::encrypt_glwe_ciphertext_list;
+use crate::core_crypto::algorithms::polynomial_algorithms::polynomial_wrapping_automorphism;
+use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
+use crate::core_crypto::commons::math::decomposition::{DecompositionLevel, DecompositionTerm};
+use crate::core_crypto::commons::math::random::{Distribution, Uniform};
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::{
+    GlweAutomorphismKey, GlweAutomorphismKeyOwned, GlweSecretKey, PlaintextListOwned, Polynomial,
+};
+
+/// Fill a [`GLWE automorphism key`](`GlweAutomorphismKey`) with an actual automorphism key
+/// constructed from a [`GLWE secret key`](`GlweSecretKey`), for the automorphism exponent stored
+/// in the key.
+///
+/// ```rust
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweAutomorphismKey creation
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(2048);
+/// let decomp_base_log = DecompositionBaseLog(23);
+/// let decomp_level_count = DecompositionLevelCount(1);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let mut automorphism_key = GlweAutomorphismKey::new(
+///     0u64,
+///     decomp_base_log,
+///     decomp_level_count,
+///     glwe_dimension,
+///     polynomial_size,
+///     5,
+///     ciphertext_modulus,
+/// );
+///
+/// generate_glwe_automorphism_key(
+///     &glwe_secret_key,
+///     &mut automorphism_key,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// assert!(!automorphism_key.as_ref().iter().all(|&x| x == 0));
+/// ```
+pub fn generate_glwe_automorphism_key<Scalar, NoiseDistribution, KeyCont, AutKeyCont, Gen>(
+    glwe_sk: &GlweSecretKey<KeyCont>,
+    automorphism_key: &mut GlweAutomorphismKey<AutKeyCont>,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    AutKeyCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert!(
+        automorphism_key.glwe_dimension() == glwe_sk.glwe_dimension(),
+        "The destination GlweAutomorphismKey GlweDimension is not equal \
+    to the GlweSecretKey GlweDimension. Destination: {:?}, secret key: {:?}",
+        automorphism_key.glwe_dimension(),
+        glwe_sk.glwe_dimension()
+    );
+    assert!(
+        automorphism_key.polynomial_size() == glwe_sk.polynomial_size(),
+        "The destination GlweAutomorphismKey PolynomialSize is not equal \
+    to the GlweSecretKey PolynomialSize. Destination: {:?}, secret key: {:?}",
+        automorphism_key.polynomial_size(),
+        glwe_sk.polynomial_size()
+    );
+
+    let decomp_base_log = automorphism_key.decomposition_base_log();
+    let decomp_level_count = automorphism_key.decomposition_level_count();
+    let polynomial_size = automorphism_key.polynomial_size();
+    let automorphism_exponent = automorphism_key.automorphism_exponent();
+    let ciphertext_modulus = automorphism_key.ciphertext_modulus();
+    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
+
+    // The transformed key polynomial will be stored in this buffer
+    let mut transformed_key_polynomial = Polynomial::new(Scalar::ZERO, polynomial_size);
+
+    // The plaintexts used to encrypt a key polynomial will be stored in this buffer
+    let mut decomposition_plaintexts_buffer = PlaintextListOwned::new(
+        Scalar::ZERO,
+        PlaintextCount(decomp_level_count.0 * polynomial_size.0),
+    );
+
+    // Iterate over the key polynomials and the destination automorphism_key memory
+    for (key_polynomial, mut automorphism_key_block) in glwe_sk
+        .as_polynomial_list()
+        .iter()
+        .zip(automorphism_key.iter_mut())
+    {
+        polynomial_wrapping_automorphism(
+            &mut transformed_key_polynomial,
+            &key_polynomial,
+            automorphism_exponent,
+        );
+
+        // We fill the buffer with the powers of the transformed key polynomial
+        for (level, mut messages) in (1..=decomp_level_count.0)
+            .rev()
+            .map(DecompositionLevel)
+            .zip(decomposition_plaintexts_buffer.chunks_exact_mut(polynomial_size.0))
+        {
+            for (message, &key_coefficient) in messages
+                .iter_mut()
+                .zip(transformed_key_polynomial.as_ref().iter())
+            {
+                // Here we take the decomposition term from the native torus, bring it to the torus
+                // we are working with by dividing by the scaling factor and the encryption will
+                // take care of mapping that back to the native torus
+                *message.0 = DecompositionTerm::new(level, decomp_base_log, key_coefficient)
+                    .to_recomposition_summand()
+                    .wrapping_div(ciphertext_modulus.get_power_of_two_scaling_to_native_torus());
+            }
+        }
+
+        encrypt_glwe_ciphertext_list(
+            glwe_sk,
+            &mut automorphism_key_block,
+            &decomposition_plaintexts_buffer,
+            noise_distribution,
+            generator,
+        );
+    }
+}
+
+/// Allocate a new [`GLWE automorphism key`](`GlweAutomorphismKey`) and fill it with an actual
+/// automorphism key constructed from a [`GLWE secret key`](`GlweSecretKey`) for the automorphism
+/// $X \mapsto X^{k}$, $k$ being the given exponent.
+///
+/// See [`apply_glwe_automorphism`](`super::apply_glwe_automorphism`) for usage.
+pub fn allocate_and_generate_new_glwe_automorphism_key<Scalar, NoiseDistribution, KeyCont, Gen>(
+    glwe_sk: &GlweSecretKey<KeyCont>,
+    automorphism_exponent: usize,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_distribution: NoiseDistribution,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> GlweAutomorphismKeyOwned<Scalar>
+where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    let mut new_automorphism_key = GlweAutomorphismKeyOwned::new(
+        Scalar::ZERO,
+        decomp_base_log,
+        decomp_level_count,
+        glwe_sk.glwe_dimension(),
+        glwe_sk.polynomial_size(),
+        automorphism_exponent,
+        ciphertext_modulus,
+    );
+
+    generate_glwe_automorphism_key(
+        glwe_sk,
+        &mut new_automorphism_key,
+        noise_distribution,
+        generator,
+    );
+
+    new_automorphism_key
+}
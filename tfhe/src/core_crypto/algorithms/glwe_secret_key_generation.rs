@@ -1,6 +1,9 @@
 //! Module containing primitives pertaining to the generation of
 //! [`GLWE secret keys`](`GlweSecretKey`).
 
+use crate::core_crypto::algorithms::lwe_secret_key_generation::{
+    binary_coefficients_to_scalars, ternary_coefficients_to_scalars,
+};
 use crate::core_crypto::commons::generators::SecretRandomGenerator;
 use crate::core_crypto::commons::math::random::{RandomGenerable, UniformBinary};
 use crate::core_crypto::commons::parameters::*;
@@ -68,3 +71,85 @@ pub fn generate_binary_glwe_secret_key<Scalar, InCont, Gen>(
 {
     generator.fill_slice_with_random_uniform_binary(glwe_secret_key.as_mut());
 }
+
+/// Allocate a new [`GLWE secret key`](`GlweSecretKey`) from externally provided binary
+/// coefficients.
+///
+/// The coefficients are laid out polynomial after polynomial, the length of `bits` must therefore
+/// be a non-zero multiple of `polynomial_size`. Each coefficient must be either 0 or 1, otherwise
+/// an error is returned.
+///
+/// See
+/// [`allocate_lwe_secret_key_from_binary_coefficients`](`super::allocate_lwe_secret_key_from_binary_coefficients`)
+/// for the intended use case.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let polynomial_size = PolynomialSize(4);
+/// // Secret bits obtained from an external source
+/// let bits = vec![0u8, 1, 1, 0, 1, 0, 0, 1];
+///
+/// let glwe_secret_key: GlweSecretKeyOwned<u64> =
+///     allocate_glwe_secret_key_from_binary_coefficients(&bits, polynomial_size).unwrap();
+///
+/// assert_eq!(glwe_secret_key.glwe_dimension(), GlweDimension(2));
+/// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+///
+/// // The length of the material must be a multiple of the polynomial size
+/// assert!(
+///     allocate_glwe_secret_key_from_binary_coefficients::<u64>(&bits[..6], polynomial_size)
+///         .is_err()
+/// );
+/// ```
+pub fn allocate_glwe_secret_key_from_binary_coefficients<Scalar>(
+    bits: &[u8],
+    polynomial_size: PolynomialSize,
+) -> Result<GlweSecretKeyOwned<Scalar>, crate::Error>
+where
+    Scalar: UnsignedInteger,
+{
+    check_glwe_secret_key_material_len(bits.len(), polynomial_size)?;
+    let coefficients = binary_coefficients_to_scalars(bits)?;
+    Ok(GlweSecretKeyOwned::from_container(
+        coefficients,
+        polynomial_size,
+    ))
+}
+
+/// Allocate a new [`GLWE secret key`](`GlweSecretKey`) from externally provided ternary
+/// coefficients.
+///
+/// Each coefficient must be either -1, 0 or 1, otherwise an error is returned. See
+/// [`allocate_glwe_secret_key_from_binary_coefficients`] for the layout of the coefficients and
+/// [`allocate_lwe_secret_key_from_ternary_coefficients`](`super::allocate_lwe_secret_key_from_ternary_coefficients`)
+/// for the encoding of -1.
+pub fn allocate_glwe_secret_key_from_ternary_coefficients<Scalar>(
+    coefficients: &[i8],
+    polynomial_size: PolynomialSize,
+) -> Result<GlweSecretKeyOwned<Scalar>, crate::Error>
+where
+    Scalar: UnsignedInteger,
+{
+    check_glwe_secret_key_material_len(coefficients.len(), polynomial_size)?;
+    let coefficients = ternary_coefficients_to_scalars(coefficients)?;
+    Ok(GlweSecretKeyOwned::from_container(
+        coefficients,
+        polynomial_size,
+    ))
+}
+
+fn check_glwe_secret_key_material_len(
+    material_len: usize,
+    polynomial_size: PolynomialSize,
+) -> Result<(), crate::Error> {
+    if polynomial_size.0 == 0 || material_len % polynomial_size.0 != 0 {
+        return Err(crate::Error::new(format!(
+            "Invalid secret key material length {material_len}, \
+            expected a multiple of the polynomial size {polynomial_size:?}"
+        )));
+    }
+    Ok(())
+}
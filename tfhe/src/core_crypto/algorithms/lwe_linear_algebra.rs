@@ -2,9 +2,11 @@
 //! like addition, multiplication, etc.
 
 use crate::core_crypto::algorithms::slice_algorithms::*;
-use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulusKind;
+use crate::core_crypto::commons::ciphertext_modulus::{CiphertextModulus, CiphertextModulusKind};
+use crate::core_crypto::commons::parameters::{LweCiphertextCount, LweSize};
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
+use rayon::prelude::*;
 
 /// Add the right-hand side [`LWE ciphertext`](`LweCiphertext`) to the left-hand side [`LWE
 /// ciphertext`](`LweCiphertext`) updating it in-place.
@@ -824,3 +826,416 @@ pub fn lwe_ciphertext_sub<Scalar, OutputCont, LhsCont, RhsCont>(
     output.as_mut().copy_from_slice(lhs.as_ref());
     lwe_ciphertext_sub_assign(output, rhs);
 }
+
+/// Return the modulus to reduce the results of the batched operations with, `None` if the native
+/// wrapping arithmetic can be used.
+fn batch_custom_modulus<Scalar: UnsignedInteger>(
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) -> Option<Scalar> {
+    if ciphertext_modulus.is_compatible_with_native_modulus() {
+        None
+    } else {
+        Some(ciphertext_modulus.get_custom_modulus().cast_into())
+    }
+}
+
+fn check_batch_lists<Scalar, LhsCont, RhsCont>(
+    lhs: &LweCiphertextList<LhsCont>,
+    rhs: &LweCiphertextList<RhsCont>,
+) where
+    Scalar: UnsignedInteger,
+    LhsCont: Container<Element = Scalar>,
+    RhsCont: Container<Element = Scalar>,
+{
+    assert_eq!(
+        lhs.ciphertext_modulus(),
+        rhs.ciphertext_modulus(),
+        "Mismatched moduli between lhs ({:?}) and rhs ({:?}) LweCiphertextList",
+        lhs.ciphertext_modulus(),
+        rhs.ciphertext_modulus()
+    );
+    assert_eq!(
+        lhs.lwe_size(),
+        rhs.lwe_size(),
+        "Mismatched LweSize between lhs ({:?}) and rhs ({:?}) LweCiphertextList",
+        lhs.lwe_size(),
+        rhs.lwe_size()
+    );
+    assert_eq!(
+        lhs.lwe_ciphertext_count(),
+        rhs.lwe_ciphertext_count(),
+        "Mismatched LweCiphertextCount between lhs ({:?}) and rhs ({:?}) LweCiphertextList",
+        lhs.lwe_ciphertext_count(),
+        rhs.lwe_ciphertext_count()
+    );
+}
+
+/// Add `rhs` to `lhs` element-wise, with the SIMD instructions available on the current CPU.
+fn slice_batch_add_assign<Scalar: UnsignedInteger>(
+    lhs: &mut [Scalar],
+    rhs: &[Scalar],
+    custom_modulus: Option<Scalar>,
+) {
+    struct Impl<'a, Scalar> {
+        lhs: &'a mut [Scalar],
+        rhs: &'a [Scalar],
+        custom_modulus: Option<Scalar>,
+    }
+
+    impl<Scalar: UnsignedInteger> pulp::NullaryFnOnce for Impl<'_, Scalar> {
+        type Output = ();
+
+        #[inline(always)]
+        fn call(self) -> Self::Output {
+            let Self {
+                lhs,
+                rhs,
+                custom_modulus,
+            } = self;
+
+            match custom_modulus {
+                None => {
+                    for (lhs, &rhs) in lhs.iter_mut().zip(rhs.iter()) {
+                        *lhs = (*lhs).wrapping_add(rhs);
+                    }
+                }
+                Some(modulus) => {
+                    for (lhs, &rhs) in lhs.iter_mut().zip(rhs.iter()) {
+                        *lhs = (*lhs).wrapping_add_custom_mod(rhs, modulus);
+                    }
+                }
+            }
+        }
+    }
+
+    pulp::Arch::new().dispatch(Impl {
+        lhs,
+        rhs,
+        custom_modulus,
+    });
+}
+
+/// Add each ciphertext of `rhs` multiplied by its cleartext to the ciphertext of `lhs` at the same
+/// index, with the SIMD instructions available on the current CPU.
+fn slice_batch_cleartext_mul_add_assign<Scalar: UnsignedInteger>(
+    lhs: &mut [Scalar],
+    rhs: &[Scalar],
+    cleartexts: &[Cleartext<Scalar>],
+    lwe_size: LweSize,
+    custom_modulus: Option<Scalar>,
+) {
+    struct Impl<'a, Scalar> {
+        lhs: &'a mut [Scalar],
+        rhs: &'a [Scalar],
+        cleartexts: &'a [Cleartext<Scalar>],
+        lwe_size: LweSize,
+        custom_modulus: Option<Scalar>,
+    }
+
+    impl<Scalar: UnsignedInteger> pulp::NullaryFnOnce for Impl<'_, Scalar> {
+        type Output = ();
+
+        #[inline(always)]
+        fn call(self) -> Self::Output {
+            let Self {
+                lhs,
+                rhs,
+                cleartexts,
+                lwe_size,
+                custom_modulus,
+            } = self;
+
+            for ((lhs, rhs), &Cleartext(cleartext)) in lhs
+                .chunks_exact_mut(lwe_size.0)
+                .zip(rhs.chunks_exact(lwe_size.0))
+                .zip(cleartexts.iter())
+            {
+                match custom_modulus {
+                    None => {
+                        for (lhs, &rhs) in lhs.iter_mut().zip(rhs.iter()) {
+                            *lhs = (*lhs).wrapping_add(rhs.wrapping_mul(cleartext));
+                        }
+                    }
+                    Some(modulus) => {
+                        for (lhs, &rhs) in lhs.iter_mut().zip(rhs.iter()) {
+                            *lhs = (*lhs).wrapping_add_custom_mod(
+                                rhs.wrapping_mul_custom_mod(cleartext, modulus),
+                                modulus,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    pulp::Arch::new().dispatch(Impl {
+        lhs,
+        rhs,
+        cleartexts,
+        lwe_size,
+        custom_modulus,
+    });
+}
+
+/// Number of ciphertexts processed by each task of the multithreaded batched operations, so that
+/// each thread of the current rayon thread pool gets one contiguous chunk of work.
+fn batch_chunk_ciphertext_count(lwe_ciphertext_count: LweCiphertextCount) -> usize {
+    lwe_ciphertext_count
+        .0
+        .div_ceil(rayon::current_num_threads())
+        .max(1)
+}
+
+/// Add each [`LWE ciphertext`](`LweCiphertext`) of the right-hand side [`LWE ciphertext
+/// list`](`LweCiphertextList`) to the ciphertext with the same index in the left-hand side [`LWE
+/// ciphertext list`](`LweCiphertextList`) updating it in-place.
+///
+/// As the ciphertexts of a list are contiguous in memory, the whole batch is processed in a single
+/// pass using the SIMD instructions available on the current CPU, instead of one call to
+/// [`lwe_ciphertext_add_assign`] per ciphertext.
+///
+/// See [`par_lwe_ciphertext_add_assign_batch`] for a multithreaded version.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertextList creation
+/// let lwe_dimension = LweDimension(742);
+/// let lwe_ciphertext_count = LweCiphertextCount(4);
+/// let lwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.000007069849454709433), 0.0);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the LweSecretKey
+/// let lwe_secret_key =
+///     allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+///
+/// // Create the plaintext list
+/// let msg = 3u64;
+/// let plaintext_list = PlaintextList::new(msg << 60, PlaintextCount(lwe_ciphertext_count.0));
+///
+/// // Create a new LweCiphertextList
+/// let mut lhs = LweCiphertextList::new(
+///     0u64,
+///     lwe_dimension.to_lwe_size(),
+///     lwe_ciphertext_count,
+///     ciphertext_modulus,
+/// );
+///
+/// encrypt_lwe_ciphertext_list(
+///     &lwe_secret_key,
+///     &mut lhs,
+///     &plaintext_list,
+///     lwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// let rhs = lhs.clone();
+///
+/// lwe_ciphertext_add_assign_batch(&mut lhs, &rhs);
+///
+/// let mut decrypted_plaintext_list = PlaintextList::new(0u64, PlaintextCount(lhs.entity_count()));
+///
+/// decrypt_lwe_ciphertext_list(&lwe_secret_key, &lhs, &mut decrypted_plaintext_list);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// decrypted_plaintext_list
+///     .iter_mut()
+///     .for_each(|x| *x.0 = decomposer.closest_representable(*x.0) >> 60);
+///
+/// // Check we recovered the expected result
+/// assert!(decrypted_plaintext_list.iter().all(|x| *x.0 == msg + msg));
+/// ```
+pub fn lwe_ciphertext_add_assign_batch<Scalar, LhsCont, RhsCont>(
+    lhs: &mut LweCiphertextList<LhsCont>,
+    rhs: &LweCiphertextList<RhsCont>,
+) where
+    Scalar: UnsignedInteger,
+    LhsCont: ContainerMut<Element = Scalar>,
+    RhsCont: Container<Element = Scalar>,
+{
+    check_batch_lists(lhs, rhs);
+    let custom_modulus = batch_custom_modulus(rhs.ciphertext_modulus());
+
+    slice_batch_add_assign(lhs.as_mut(), rhs.as_ref(), custom_modulus);
+}
+
+/// Parallel variant of [`lwe_ciphertext_add_assign_batch`].
+///
+/// The lists are split in as many contiguous chunks of ciphertexts as there are threads in the
+/// current rayon thread pool, each chunk being processed using SIMD instructions.
+pub fn par_lwe_ciphertext_add_assign_batch<Scalar, LhsCont, RhsCont>(
+    lhs: &mut LweCiphertextList<LhsCont>,
+    rhs: &LweCiphertextList<RhsCont>,
+) where
+    Scalar: UnsignedInteger,
+    LhsCont: ContainerMut<Element = Scalar>,
+    RhsCont: Container<Element = Scalar>,
+{
+    check_batch_lists(lhs, rhs);
+    let custom_modulus = batch_custom_modulus(rhs.ciphertext_modulus());
+    let chunk_size = batch_chunk_ciphertext_count(rhs.lwe_ciphertext_count()) * rhs.lwe_size().0;
+
+    lhs.as_mut()
+        .par_chunks_mut(chunk_size)
+        .zip(rhs.as_ref().par_chunks(chunk_size))
+        .for_each(|(lhs, rhs)| slice_batch_add_assign(lhs, rhs, custom_modulus));
+}
+
+/// Multiply each [`LWE ciphertext`](`LweCiphertext`) of the right-hand side [`LWE ciphertext
+/// list`](`LweCiphertextList`) by the cleartext with the same index and add the result to the
+/// ciphertext with the same index in the left-hand side [`LWE ciphertext
+/// list`](`LweCiphertextList`) updating it in-place.
+///
+/// This is the batched version of computing `lhs[i] += rhs[i] * cleartexts[i]` for all `i`, the
+/// whole batch being processed in a single pass using the SIMD instructions available on the
+/// current CPU.
+///
+/// See [`par_lwe_ciphertext_cleartext_mul_add_assign_batch`] for a multithreaded version.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertextList creation
+/// let lwe_dimension = LweDimension(742);
+/// let lwe_ciphertext_count = LweCiphertextCount(4);
+/// let lwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.000007069849454709433), 0.0);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the LweSecretKey
+/// let lwe_secret_key =
+///     allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+///
+/// // Create the plaintext list
+/// let msg = 1u64;
+/// let plaintext_list = PlaintextList::new(msg << 60, PlaintextCount(lwe_ciphertext_count.0));
+///
+/// // Create a new LweCiphertextList
+/// let mut lhs = LweCiphertextList::new(
+///     0u64,
+///     lwe_dimension.to_lwe_size(),
+///     lwe_ciphertext_count,
+///     ciphertext_modulus,
+/// );
+///
+/// encrypt_lwe_ciphertext_list(
+///     &lwe_secret_key,
+///     &mut lhs,
+///     &plaintext_list,
+///     lwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// let rhs = lhs.clone();
+/// let cleartexts = [Cleartext(0u64), Cleartext(1), Cleartext(2), Cleartext(3)];
+///
+/// lwe_ciphertext_cleartext_mul_add_assign_batch(&mut lhs, &rhs, &cleartexts);
+///
+/// let mut decrypted_plaintext_list = PlaintextList::new(0u64, PlaintextCount(lhs.entity_count()));
+///
+/// decrypt_lwe_ciphertext_list(&lwe_secret_key, &lhs, &mut decrypted_plaintext_list);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// decrypted_plaintext_list
+///     .iter_mut()
+///     .for_each(|x| *x.0 = decomposer.closest_representable(*x.0) >> 60);
+///
+/// // Check we recovered the expected result
+/// for (decrypted, cleartext) in decrypted_plaintext_list.iter().zip(cleartexts.iter()) {
+///     assert_eq!(*decrypted.0, msg + msg * cleartext.0);
+/// }
+/// ```
+pub fn lwe_ciphertext_cleartext_mul_add_assign_batch<Scalar, LhsCont, RhsCont>(
+    lhs: &mut LweCiphertextList<LhsCont>,
+    rhs: &LweCiphertextList<RhsCont>,
+    cleartexts: &[Cleartext<Scalar>],
+) where
+    Scalar: UnsignedInteger,
+    LhsCont: ContainerMut<Element = Scalar>,
+    RhsCont: Container<Element = Scalar>,
+{
+    check_batch_lists(lhs, rhs);
+    assert_eq!(
+        cleartexts.len(),
+        rhs.lwe_ciphertext_count().0,
+        "Mismatched number of cleartexts ({}) and LweCiphertextCount ({:?})",
+        cleartexts.len(),
+        rhs.lwe_ciphertext_count()
+    );
+    let custom_modulus = batch_custom_modulus(rhs.ciphertext_modulus());
+
+    slice_batch_cleartext_mul_add_assign(
+        lhs.as_mut(),
+        rhs.as_ref(),
+        cleartexts,
+        rhs.lwe_size(),
+        custom_modulus,
+    );
+}
+
+/// Parallel variant of [`lwe_ciphertext_cleartext_mul_add_assign_batch`].
+///
+/// The lists are split in as many contiguous chunks of ciphertexts as there are threads in the
+/// current rayon thread pool, each chunk being processed using SIMD instructions.
+pub fn par_lwe_ciphertext_cleartext_mul_add_assign_batch<Scalar, LhsCont, RhsCont>(
+    lhs: &mut LweCiphertextList<LhsCont>,
+    rhs: &LweCiphertextList<RhsCont>,
+    cleartexts: &[Cleartext<Scalar>],
+) where
+    Scalar: UnsignedInteger,
+    LhsCont: ContainerMut<Element = Scalar>,
+    RhsCont: Container<Element = Scalar>,
+{
+    check_batch_lists(lhs, rhs);
+    assert_eq!(
+        cleartexts.len(),
+        rhs.lwe_ciphertext_count().0,
+        "Mismatched number of cleartexts ({}) and LweCiphertextCount ({:?})",
+        cleartexts.len(),
+        rhs.lwe_ciphertext_count()
+    );
+    let custom_modulus = batch_custom_modulus(rhs.ciphertext_modulus());
+    let lwe_size = rhs.lwe_size();
+    let chunk_ciphertext_count = batch_chunk_ciphertext_count(rhs.lwe_ciphertext_count());
+    let chunk_size = chunk_ciphertext_count * lwe_size.0;
+
+    lhs.as_mut()
+        .par_chunks_mut(chunk_size)
+        .zip(rhs.as_ref().par_chunks(chunk_size))
+        .zip(cleartexts.par_chunks(chunk_ciphertext_count))
+        .for_each(|((lhs, rhs), cleartexts)| {
+            slice_batch_cleartext_mul_add_assign(lhs, rhs, cleartexts, lwe_size, custom_modulus);
+        });
+}
@@ -63,3 +63,129 @@ pub fn generate_binary_lwe_secret_key<Scalar, InCont, Gen>(
 {
     generator.fill_slice_with_random_uniform_binary(lwe_secret_key.as_mut());
 }
+
+/// Allocate a new [`LWE secret key`](`LweSecretKey`) from externally provided binary
+/// coefficients.
+///
+/// This is meant for settings where the secret material cannot be sampled by this library, e.g.
+/// when it originates from a hardware security module. All other keys (keyswitching keys,
+/// bootstrapping keys, public keys, etc.) can then be generated from the returned secret key with
+/// the usual generation primitives.
+///
+/// Each coefficient in `bits` must be either 0 or 1, otherwise an error is returned.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // Secret bits obtained from an external source
+/// let bits = vec![0u8, 1, 1, 0, 1, 0, 0, 1];
+///
+/// let lwe_secret_key: LweSecretKeyOwned<u64> =
+///     allocate_lwe_secret_key_from_binary_coefficients(&bits).unwrap();
+///
+/// assert_eq!(lwe_secret_key.lwe_dimension(), LweDimension(bits.len()));
+/// assert!(lwe_secret_key
+///     .as_ref()
+///     .iter()
+///     .zip(bits.iter())
+///     .all(|(&coef, &bit)| coef == bit as u64));
+///
+/// // Values other than 0 or 1 are rejected
+/// let invalid_bits = vec![0u8, 1, 2];
+/// assert!(allocate_lwe_secret_key_from_binary_coefficients::<u64>(&invalid_bits).is_err());
+/// ```
+pub fn allocate_lwe_secret_key_from_binary_coefficients<Scalar>(
+    bits: &[u8],
+) -> Result<LweSecretKeyOwned<Scalar>, crate::Error>
+where
+    Scalar: UnsignedInteger,
+{
+    let coefficients = binary_coefficients_to_scalars(bits)?;
+    Ok(LweSecretKeyOwned::from_container(coefficients))
+}
+
+/// Allocate a new [`LWE secret key`](`LweSecretKey`) from externally provided ternary
+/// coefficients.
+///
+/// Each coefficient in `coefficients` must be either -1, 0 or 1, otherwise an error is returned.
+/// The -1 coefficients are stored as `Scalar::MAX`, i.e. the representation of -1 modulo
+/// $2^{bits}$, which matches the encoding used by the rest of the library.
+///
+/// See [`allocate_lwe_secret_key_from_binary_coefficients`] for the intended use case.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let coefficients = vec![-1i8, 0, 1, 1, 0, -1];
+///
+/// let lwe_secret_key: LweSecretKeyOwned<u64> =
+///     allocate_lwe_secret_key_from_ternary_coefficients(&coefficients).unwrap();
+///
+/// assert_eq!(lwe_secret_key.as_ref(), &[u64::MAX, 0, 1, 1, 0, u64::MAX]);
+///
+/// let invalid_coefficients = vec![-2i8, 0, 1];
+/// assert!(
+///     allocate_lwe_secret_key_from_ternary_coefficients::<u64>(&invalid_coefficients).is_err()
+/// );
+/// ```
+pub fn allocate_lwe_secret_key_from_ternary_coefficients<Scalar>(
+    coefficients: &[i8],
+) -> Result<LweSecretKeyOwned<Scalar>, crate::Error>
+where
+    Scalar: UnsignedInteger,
+{
+    let coefficients = ternary_coefficients_to_scalars(coefficients)?;
+    Ok(LweSecretKeyOwned::from_container(coefficients))
+}
+
+/// Validate externally provided binary secret key material and convert it to `Scalar`s.
+pub(crate) fn binary_coefficients_to_scalars<Scalar: UnsignedInteger>(
+    bits: &[u8],
+) -> Result<Vec<Scalar>, crate::Error> {
+    if bits.is_empty() {
+        return Err(crate::Error::new(
+            "Got empty secret key material".to_string(),
+        ));
+    }
+
+    bits.iter()
+        .enumerate()
+        .map(|(index, &bit)| match bit {
+            0 => Ok(Scalar::ZERO),
+            1 => Ok(Scalar::ONE),
+            _ => Err(crate::Error::new(format!(
+                "Invalid binary secret key coefficient at index {index}: \
+                expected 0 or 1, got {bit}"
+            ))),
+        })
+        .collect()
+}
+
+/// Validate externally provided ternary secret key material and convert it to `Scalar`s.
+pub(crate) fn ternary_coefficients_to_scalars<Scalar: UnsignedInteger>(
+    coefficients: &[i8],
+) -> Result<Vec<Scalar>, crate::Error> {
+    if coefficients.is_empty() {
+        return Err(crate::Error::new(
+            "Got empty secret key material".to_string(),
+        ));
+    }
+
+    coefficients
+        .iter()
+        .enumerate()
+        .map(|(index, &coef)| match coef {
+            -1 => Ok(Scalar::ZERO.wrapping_sub(Scalar::ONE)),
+            0 => Ok(Scalar::ZERO),
+            1 => Ok(Scalar::ONE),
+            _ => Err(crate::Error::new(format!(
+                "Invalid ternary secret key coefficient at index {index}: \
+                expected -1, 0 or 1, got {coef}"
+            ))),
+        })
+        .collect()
+}
@@ -4,6 +4,8 @@
 
 pub mod ggsw_conversion;
 pub mod ggsw_encryption;
+pub mod glwe_automorphism;
+pub mod glwe_automorphism_key_generation;
 pub mod glwe_encryption;
 pub mod glwe_linear_algebra;
 pub mod glwe_sample_extraction;
@@ -52,6 +54,8 @@ pub(crate) mod test;
 // They can still be used via `use crate::core_crypto::algorithms::slice_algorithms::*;`
 pub use ggsw_conversion::*;
 pub use ggsw_encryption::*;
+pub use glwe_automorphism::*;
+pub use glwe_automorphism_key_generation::*;
 pub use glwe_encryption::*;
 pub use glwe_linear_algebra::*;
 pub use glwe_sample_extraction::*;
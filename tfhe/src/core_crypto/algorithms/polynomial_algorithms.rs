@@ -598,6 +598,64 @@ pub fn polynomial_wrapping_monic_monomial_mul<Scalar, OutputCont, InputCont>(
     }
 }
 
+/// Apply the automorphism $X \mapsto X^{k}$ (mod $(X^{N}+1)$) to the input polynomial and write
+/// the result in the output polynomial, $k$ being the given exponent.
+///
+/// The coefficient of degree $i$ of the input is moved to degree $i \cdot k \bmod 2N$, and negated
+/// when that degree is greater than or equal to $N$ as $X^{N} = -1$.
+///
+/// # Note
+///
+/// Computations wrap around (similar to computing modulo $2^{n\_{bits}}$) when exceeding the
+/// unsigned integer capacity.
+///
+/// # Panics
+///
+/// Panics if the exponent is even, as the map is then not an automorphism of the ring.
+///
+/// # Examples
+///
+/// ```rust
+/// use tfhe::core_crypto::algorithms::polynomial_algorithms::*;
+/// use tfhe::core_crypto::entities::*;
+/// let input = Polynomial::from_container(vec![1u8, 2, 3, 4]);
+/// let mut output = Polynomial::from_container(vec![0, 0, 0, 0]);
+/// polynomial_wrapping_automorphism(&mut output, &input, 3);
+/// assert_eq!(output.as_ref(), &[1, 4, 253, 2]);
+/// ```
+pub fn polynomial_wrapping_automorphism<Scalar, OutputCont, InputCont>(
+    output: &mut Polynomial<OutputCont>,
+    input: &Polynomial<InputCont>,
+    automorphism_exponent: usize,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+{
+    assert!(
+        output.polynomial_size() == input.polynomial_size(),
+        "Output polynomial size {:?} is not the same as input polynomial size {:?}.",
+        output.polynomial_size(),
+        input.polynomial_size(),
+    );
+    assert!(
+        automorphism_exponent % 2 == 1,
+        "The automorphism exponent must be odd, got {automorphism_exponent}."
+    );
+
+    let polynomial_size = output.polynomial_size().0;
+    let exponent = automorphism_exponent % (2 * polynomial_size);
+
+    for (degree, &coefficient) in input.iter().enumerate() {
+        let output_degree = (degree * exponent) % (2 * polynomial_size);
+        if output_degree < polynomial_size {
+            output[output_degree] = coefficient;
+        } else {
+            output[output_degree - polynomial_size] = coefficient.wrapping_neg();
+        }
+    }
+}
+
 /// Multiply (mod $(X^{N}+1)$), the input polynomial with a monic monomial of a given degree i.e.
 /// $X^{degree}$, then subtract the input from the result and assign to the output.
 ///
@@ -0,0 +1,110 @@
+use super::*;
+use crate::core_crypto::algorithms::polynomial_algorithms::polynomial_wrapping_automorphism;
+
+#[cfg(not(tarpaulin))]
+const NB_TESTS: usize = 10;
+#[cfg(tarpaulin)]
+const NB_TESTS: usize = 1;
+
+fn glwe_encrypt_automorphism_decrypt_custom_mod<Scalar: UnsignedTorus>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_noise_distribution = params.glwe_noise_distribution;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+
+    // Exponents used by trace packing are of the form 2^i + 1, we also check the conjugation
+    for automorphism_exponent in [3, 5, 2 * polynomial_size.0 - 1] {
+        let automorphism_key = allocate_and_generate_new_glwe_automorphism_key(
+            &glwe_sk,
+            automorphism_exponent,
+            params.pbs_base_log,
+            params.pbs_level,
+            glwe_noise_distribution,
+            ciphertext_modulus,
+            &mut rsc.encryption_random_generator,
+        );
+
+        assert!(check_encrypted_content_respects_mod(
+            &automorphism_key,
+            ciphertext_modulus
+        ));
+
+        for _ in 0..NB_TESTS {
+            let mut plaintext_list =
+                PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+            plaintext_list
+                .iter_mut()
+                .enumerate()
+                .for_each(|(idx, dst)| *dst.0 = Scalar::cast_from(idx) % msg_modulus * delta);
+
+            let mut glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            encrypt_glwe_ciphertext(
+                &glwe_sk,
+                &mut glwe,
+                &plaintext_list,
+                glwe_noise_distribution,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let mut output_glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            apply_glwe_automorphism(&automorphism_key, &glwe, &mut output_glwe);
+
+            assert!(check_encrypted_content_respects_mod(
+                &output_glwe,
+                ciphertext_modulus
+            ));
+
+            let mut output_plaintext_list =
+                PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+
+            decrypt_glwe_ciphertext(&glwe_sk, &output_glwe, &mut output_plaintext_list);
+
+            let decoded: Vec<_> = output_plaintext_list
+                .iter()
+                .map(|x| round_decode(*x.0, delta) % msg_modulus)
+                .collect();
+
+            let mut expected = Polynomial::new(Scalar::ZERO, polynomial_size);
+            polynomial_wrapping_automorphism(
+                &mut expected,
+                &plaintext_list.as_polynomial(),
+                automorphism_exponent,
+            );
+            let expected: Vec<_> = expected
+                .iter()
+                .map(|&x| round_decode(x, delta) % msg_modulus)
+                .collect();
+
+            assert_eq!(decoded, expected);
+        }
+    }
+}
+
+create_parametrized_test!(glwe_encrypt_automorphism_decrypt_custom_mod);
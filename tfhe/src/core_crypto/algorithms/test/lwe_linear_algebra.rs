@@ -73,6 +73,7 @@ fn lwe_encrypt_add_assign_decrypt_custom_mod<Scalar: UnsignedTorus>(
 }
 
 create_parametrized_test_with_non_native_parameters!(lwe_encrypt_add_assign_decrypt_custom_mod);
+create_parametrized_test_with_u128_parameters!(lwe_encrypt_add_assign_decrypt_custom_mod);
 
 fn lwe_encrypt_add_decrypt_custom_mod<Scalar: UnsignedTorus>(params: ClassicTestParams<Scalar>) {
     let lwe_dimension = params.lwe_dimension;
@@ -140,6 +141,7 @@ fn lwe_encrypt_add_decrypt_custom_mod<Scalar: UnsignedTorus>(params: ClassicTest
 }
 
 create_parametrized_test_with_non_native_parameters!(lwe_encrypt_add_decrypt_custom_mod);
+create_parametrized_test_with_u128_parameters!(lwe_encrypt_add_decrypt_custom_mod);
 
 fn lwe_encrypt_plaintext_add_assign_decrypt_custom_mod<Scalar: UnsignedTorus>(
     params: ClassicTestParams<Scalar>,
@@ -209,6 +211,7 @@ fn lwe_encrypt_plaintext_add_assign_decrypt_custom_mod<Scalar: UnsignedTorus>(
 create_parametrized_test_with_non_native_parameters!(
     lwe_encrypt_plaintext_add_assign_decrypt_custom_mod
 );
+create_parametrized_test_with_u128_parameters!(lwe_encrypt_plaintext_add_assign_decrypt_custom_mod);
 
 fn lwe_encrypt_plaintext_sub_assign_decrypt_custom_mod<Scalar: UnsignedTorus>(
     params: ClassicTestParams<Scalar>,
@@ -278,6 +281,7 @@ fn lwe_encrypt_plaintext_sub_assign_decrypt_custom_mod<Scalar: UnsignedTorus>(
 create_parametrized_test_with_non_native_parameters!(
     lwe_encrypt_plaintext_sub_assign_decrypt_custom_mod
 );
+create_parametrized_test_with_u128_parameters!(lwe_encrypt_plaintext_sub_assign_decrypt_custom_mod);
 
 fn lwe_encrypt_opposite_assign_decrypt_custom_mod<Scalar: UnsignedTorus>(
     params: ClassicTestParams<Scalar>,
@@ -347,6 +351,7 @@ fn lwe_encrypt_opposite_assign_decrypt_custom_mod<Scalar: UnsignedTorus>(
 create_parametrized_test_with_non_native_parameters!(
     lwe_encrypt_opposite_assign_decrypt_custom_mod
 );
+create_parametrized_test_with_u128_parameters!(lwe_encrypt_opposite_assign_decrypt_custom_mod);
 
 fn lwe_encrypt_ciphertext_cleartext_mul_assign_decrypt_custom_mod<Scalar: UnsignedTorus>(
     params: ClassicTestParams<Scalar>,
@@ -417,6 +422,9 @@ fn lwe_encrypt_ciphertext_cleartext_mul_assign_decrypt_custom_mod<Scalar: Unsign
 create_parametrized_test_with_non_native_parameters!(
     lwe_encrypt_ciphertext_cleartext_mul_assign_decrypt_custom_mod
 );
+create_parametrized_test_with_u128_parameters!(
+    lwe_encrypt_ciphertext_cleartext_mul_assign_decrypt_custom_mod
+);
 
 fn lwe_encrypt_cleartext_mul_decrypt_custom_mod<Scalar: UnsignedTorus>(
     params: ClassicTestParams<Scalar>,
@@ -487,6 +495,7 @@ fn lwe_encrypt_cleartext_mul_decrypt_custom_mod<Scalar: UnsignedTorus>(
 }
 
 create_parametrized_test_with_non_native_parameters!(lwe_encrypt_cleartext_mul_decrypt_custom_mod);
+create_parametrized_test_with_u128_parameters!(lwe_encrypt_cleartext_mul_decrypt_custom_mod);
 
 fn lwe_encrypt_sub_assign_decrypt_custom_mod<Scalar: UnsignedTorus>(
     params: ClassicTestParams<Scalar>,
@@ -576,6 +585,7 @@ fn lwe_encrypt_sub_assign_decrypt_custom_mod<Scalar: UnsignedTorus>(
 }
 
 create_parametrized_test_with_non_native_parameters!(lwe_encrypt_sub_assign_decrypt_custom_mod);
+create_parametrized_test_with_u128_parameters!(lwe_encrypt_sub_assign_decrypt_custom_mod);
 
 fn lwe_encrypt_sub_decrypt_custom_mod<Scalar: UnsignedTorus>(params: ClassicTestParams<Scalar>) {
     let lwe_dimension = params.lwe_dimension;
@@ -665,3 +675,95 @@ fn lwe_encrypt_sub_decrypt_custom_mod<Scalar: UnsignedTorus>(params: ClassicTest
 }
 
 create_parametrized_test_with_non_native_parameters!(lwe_encrypt_sub_decrypt_custom_mod);
+create_parametrized_test_with_u128_parameters!(lwe_encrypt_sub_decrypt_custom_mod);
+
+fn lwe_list_encrypt_batch_linear_algebra_decrypt_custom_mod<Scalar: UnsignedTorus>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let lwe_dimension = params.lwe_dimension;
+    let lwe_noise_distribution = params.lwe_noise_distribution;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+    // More ciphertexts than threads in most setups so that the parallel versions split the lists
+    let lwe_ciphertext_count = LweCiphertextCount(rayon::current_num_threads() + 3);
+
+    for _ in 0..NB_TESTS {
+        let lwe_sk = allocate_and_generate_new_binary_lwe_secret_key(
+            lwe_dimension,
+            &mut rsc.secret_random_generator,
+        );
+
+        let msgs = (0..lwe_ciphertext_count.0)
+            .map(|i| Scalar::cast_from(i) % msg_modulus)
+            .collect::<Vec<_>>();
+        let cleartexts = (0..lwe_ciphertext_count.0)
+            .map(|i| Cleartext(Scalar::cast_from(i % 3)))
+            .collect::<Vec<_>>();
+
+        let plaintext_list =
+            PlaintextList::from_container(msgs.iter().map(|&msg| msg * delta).collect::<Vec<_>>());
+
+        let mut list = LweCiphertextList::new(
+            Scalar::ZERO,
+            lwe_dimension.to_lwe_size(),
+            lwe_ciphertext_count,
+            ciphertext_modulus,
+        );
+
+        encrypt_lwe_ciphertext_list(
+            &lwe_sk,
+            &mut list,
+            &plaintext_list,
+            lwe_noise_distribution,
+            &mut rsc.encryption_random_generator,
+        );
+
+        let check_decrypted = |list: &LweCiphertextListOwned<Scalar>, expected: &[Scalar]| {
+            assert!(list
+                .iter()
+                .all(|ct| check_encrypted_content_respects_mod(&ct, ciphertext_modulus)));
+
+            for (ct, &expected) in list.iter().zip(expected.iter()) {
+                let decrypted = decrypt_lwe_ciphertext(&lwe_sk, &ct);
+                let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+                assert_eq!(expected % msg_modulus, decoded);
+            }
+        };
+
+        let expected_add = msgs.iter().map(|&msg| msg + msg).collect::<Vec<_>>();
+        let expected_mul_add = msgs
+            .iter()
+            .zip(cleartexts.iter())
+            .map(|(&msg, cleartext)| msg + msg * cleartext.0)
+            .collect::<Vec<_>>();
+
+        let mut res = list.clone();
+        lwe_ciphertext_add_assign_batch(&mut res, &list);
+        check_decrypted(&res, &expected_add);
+
+        let mut res = list.clone();
+        par_lwe_ciphertext_add_assign_batch(&mut res, &list);
+        check_decrypted(&res, &expected_add);
+
+        let mut res = list.clone();
+        lwe_ciphertext_cleartext_mul_add_assign_batch(&mut res, &list, &cleartexts);
+        check_decrypted(&res, &expected_mul_add);
+
+        let mut res = list.clone();
+        par_lwe_ciphertext_cleartext_mul_add_assign_batch(&mut res, &list, &cleartexts);
+        check_decrypted(&res, &expected_mul_add);
+    }
+}
+
+create_parametrized_test_with_non_native_parameters!(
+    lwe_list_encrypt_batch_linear_algebra_decrypt_custom_mod
+);
+create_parametrized_test_with_u128_parameters!(
+    lwe_list_encrypt_batch_linear_algebra_decrypt_custom_mod
+);
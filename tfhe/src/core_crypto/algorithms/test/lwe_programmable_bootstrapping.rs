@@ -372,57 +372,6 @@ where
 
 create_parametrized_test!(lwe_encrypt_pbs_many_lut_decrypt_custom_mod);
 
-// DISCLAIMER: all parameters here are not guaranteed to be secure or yield correct computations
-pub const TEST_PARAMS_4_BITS_NATIVE_U128: ClassicTestParams<u128> = ClassicTestParams {
-    lwe_dimension: LweDimension(742),
-    glwe_dimension: GlweDimension(1),
-    polynomial_size: PolynomialSize(2048),
-    lwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
-        4.9982771e-11,
-    )),
-    glwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
-        8.6457178e-32,
-    )),
-    pbs_base_log: DecompositionBaseLog(23),
-    pbs_level: DecompositionLevelCount(1),
-    ks_level: DecompositionLevelCount(5),
-    ks_base_log: DecompositionBaseLog(3),
-    pfks_level: DecompositionLevelCount(1),
-    pfks_base_log: DecompositionBaseLog(23),
-    pfks_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
-        0.00000000000000029403601535432533,
-    )),
-    cbs_level: DecompositionLevelCount(0),
-    cbs_base_log: DecompositionBaseLog(0),
-    message_modulus_log: MessageModulusLog(4),
-    ciphertext_modulus: CiphertextModulus::new_native(),
-};
-
-pub const TEST_PARAMS_3_BITS_127_U128: ClassicTestParams<u128> = ClassicTestParams {
-    lwe_dimension: LweDimension(742),
-    glwe_dimension: GlweDimension(1),
-    polynomial_size: PolynomialSize(2048),
-    lwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
-        4.9982771e-11,
-    )),
-    glwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
-        8.6457178e-32,
-    )),
-    pbs_base_log: DecompositionBaseLog(23),
-    pbs_level: DecompositionLevelCount(1),
-    ks_level: DecompositionLevelCount(5),
-    ks_base_log: DecompositionBaseLog(3),
-    pfks_level: DecompositionLevelCount(1),
-    pfks_base_log: DecompositionBaseLog(23),
-    pfks_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
-        0.00000000000000029403601535432533,
-    )),
-    cbs_level: DecompositionLevelCount(0),
-    cbs_base_log: DecompositionBaseLog(0),
-    message_modulus_log: MessageModulusLog(3),
-    ciphertext_modulus: CiphertextModulus::new(1 << 127),
-};
-
 fn lwe_encrypt_pbs_f128_decrypt_custom_mod<Scalar>(params: ClassicTestParams<Scalar>)
 where
     Scalar: UnsignedTorus
@@ -8,6 +8,7 @@ pub(crate) use crate::core_crypto::prelude::*;
 pub(crate) use std::fmt::Debug;
 
 mod ggsw_encryption;
+mod glwe_automorphism;
 mod glwe_encryption;
 mod glwe_linear_algebra;
 mod glwe_sample_extraction;
@@ -128,6 +129,56 @@ pub const TEST_PARAMS_3_BITS_SOLINAS_U64: ClassicTestParams<u64> = ClassicTestPa
     ciphertext_modulus: CiphertextModulus::new((1 << 64) - (1 << 32) + 1),
 };
 
+pub const TEST_PARAMS_4_BITS_NATIVE_U128: ClassicTestParams<u128> = ClassicTestParams {
+    lwe_dimension: LweDimension(742),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(2048),
+    lwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+        4.9982771e-11,
+    )),
+    glwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+        8.6457178e-32,
+    )),
+    pbs_base_log: DecompositionBaseLog(23),
+    pbs_level: DecompositionLevelCount(1),
+    ks_level: DecompositionLevelCount(5),
+    ks_base_log: DecompositionBaseLog(3),
+    pfks_level: DecompositionLevelCount(1),
+    pfks_base_log: DecompositionBaseLog(23),
+    pfks_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+        0.00000000000000029403601535432533,
+    )),
+    cbs_level: DecompositionLevelCount(0),
+    cbs_base_log: DecompositionBaseLog(0),
+    message_modulus_log: MessageModulusLog(4),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+};
+
+pub const TEST_PARAMS_3_BITS_127_U128: ClassicTestParams<u128> = ClassicTestParams {
+    lwe_dimension: LweDimension(742),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(2048),
+    lwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+        4.9982771e-11,
+    )),
+    glwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+        8.6457178e-32,
+    )),
+    pbs_base_log: DecompositionBaseLog(23),
+    pbs_level: DecompositionLevelCount(1),
+    ks_level: DecompositionLevelCount(5),
+    ks_base_log: DecompositionBaseLog(3),
+    pfks_level: DecompositionLevelCount(1),
+    pfks_base_log: DecompositionBaseLog(23),
+    pfks_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+        0.00000000000000029403601535432533,
+    )),
+    cbs_level: DecompositionLevelCount(0),
+    cbs_base_log: DecompositionBaseLog(0),
+    message_modulus_log: MessageModulusLog(3),
+    ciphertext_modulus: CiphertextModulus::new(1 << 127),
+};
+
 pub const DUMMY_NATIVE_U32: ClassicTestParams<u32> = ClassicTestParams {
     lwe_dimension: LweDimension(742),
     glwe_dimension: GlweDimension(1),
@@ -492,4 +543,16 @@ macro_rules! create_parametrized_test_with_non_native_parameters {
     };
 }
 
-pub(crate) use {create_parametrized_test, create_parametrized_test_with_non_native_parameters};
+// Macro to generate tests for the parameter sets using u128 ciphertext words
+macro_rules! create_parametrized_test_with_u128_parameters {
+    ($name:ident) => {
+        create_parametrized_test!($name {
+            TEST_PARAMS_4_BITS_NATIVE_U128,
+            TEST_PARAMS_3_BITS_127_U128
+        });
+    };
+}
+
+pub(crate) use create_parametrized_test;
+pub(crate) use create_parametrized_test_with_non_native_parameters;
+pub(crate) use create_parametrized_test_with_u128_parameters;
@@ -0,0 +1,11 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::core_crypto::prelude::{Container, GlweAutomorphismKey, UnsignedInteger};
+
+#[derive(VersionsDispatch)]
+pub enum GlweAutomorphismKeyVersions<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    V0(GlweAutomorphismKey<C>),
+}
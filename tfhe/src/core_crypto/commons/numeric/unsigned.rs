@@ -185,7 +185,19 @@ macro_rules! implement {
                         .wrapping_rem(custom_modulus_u128)
                         .cast_into()
                 } else {
-                    todo!("wrapping_mul_custom_mod is not yet implemented for types wider than u64")
+                    // There is no wider native type to compute the product in, so it is
+                    // computed with a double-and-add, each addition being reduced
+                    let mut lhs = self.wrapping_rem(custom_modulus);
+                    let mut rhs = other.wrapping_rem(custom_modulus);
+                    let mut result = Self::ZERO;
+                    while rhs != Self::ZERO {
+                        if rhs & Self::ONE == Self::ONE {
+                            result = result.wrapping_add_custom_mod(lhs, custom_modulus);
+                        }
+                        lhs = lhs.wrapping_add_custom_mod(lhs, custom_modulus);
+                        rhs >>= 1;
+                    }
+                    result
                 }
             }
             #[inline]
@@ -284,6 +296,28 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_uint128_wrapping_mul_custom_mod() {
+        // (2^64 - 1) * 3 mod (2^64 + 1) computed in u128 without overflow
+        let modulus = (1u128 << 64) + 1;
+        assert_eq!(
+            u64::MAX as u128 * 3 % modulus,
+            (u64::MAX as u128).wrapping_mul_custom_mod(3, modulus)
+        );
+
+        // Products that overflow u128
+        let modulus = u128::MAX - 158; // largest prime below 2^128
+        let a = u128::MAX - 1000;
+        let b = u128::MAX - 2000;
+        // a = -842 and b = -1842 mod modulus
+        assert_eq!(
+            a.wrapping_mul_custom_mod(b, modulus),
+            842u128 * 1842 % modulus
+        );
+        assert_eq!(a.wrapping_mul_custom_mod(0, modulus), 0);
+        assert_eq!(a.wrapping_mul_custom_mod(1, modulus), a);
+    }
+
     #[test]
     fn test_uint128_binary_rep() {
         let a: u128 = 124_282_366_920_938_463_463_374_121_543_098_288_434;
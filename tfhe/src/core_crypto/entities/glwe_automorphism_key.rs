@@ -0,0 +1,456 @@
+//! Module containing the definition of the [`GlweAutomorphismKey`].
+
+use tfhe_versionable::Versionize;
+
+use crate::conformance::ParameterSetConformant;
+use crate::core_crypto::backward_compatibility::entities::glwe_automorphism_key::GlweAutomorphismKeyVersions;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::glwe_ciphertext::glwe_ciphertext_size;
+use crate::core_crypto::entities::glwe_ciphertext_list::{
+    GlweCiphertextListCreationMetadata, GlweCiphertextListMutView, GlweCiphertextListView,
+};
+
+/// An automorphism key, also called Galois key, allowing to apply the automorphism
+/// $\tau\_{k}: X \mapsto X^{k}$ to the plaintext of [`a GLWE ciphertext`](super::GlweCiphertext)
+/// without changing the secret key it is encrypted under.
+///
+/// Applying the automorphism to the polynomials of a GLWE ciphertext yields a ciphertext encrypted
+/// under the secret key transformed by $\tau\_{k}$. The key contains, for each polynomial $S\_{i}$
+/// of the [`GLWE secret key`](super::GlweSecretKey), encryptions of $\tau\_{k}(S\_{i})$ scaled by
+/// each decomposition level, under the original secret key, which are used to keyswitch back to the
+/// original key.
+///
+/// The exponent $k$ must be odd for the map to be an automorphism of the ring
+/// $\mathbb{Z}\_{q}[X]/(X^{N}+1)$.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(GlweAutomorphismKeyVersions)]
+pub struct GlweAutomorphismKey<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    data: C,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    automorphism_exponent: usize,
+    ciphertext_modulus: CiphertextModulus<C::Element>,
+}
+
+impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for GlweAutomorphismKey<C> {
+    fn as_ref(&self) -> &[T] {
+        self.data.as_ref()
+    }
+}
+
+impl<T: UnsignedInteger, C: ContainerMut<Element = T>> AsMut<[T]> for GlweAutomorphismKey<C> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.data.as_mut()
+    }
+}
+
+/// Return the number of elements in an encryption of a polynomial of the input
+/// [`super::GlweSecretKey`] for a [`GlweAutomorphismKey`] given a [`DecompositionLevelCount`],
+/// [`GlweSize`] and [`PolynomialSize`].
+pub fn glwe_automorphism_key_input_key_element_encrypted_size(
+    decomp_level_count: DecompositionLevelCount,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+) -> usize {
+    // One ciphertext per level encrypted under the key
+    decomp_level_count.0 * glwe_ciphertext_size(glwe_size, polynomial_size)
+}
+
+/// Return the number of elements in a [`GlweAutomorphismKey`] given a
+/// [`DecompositionLevelCount`], [`GlweSize`] and [`PolynomialSize`].
+pub fn glwe_automorphism_key_size(
+    decomp_level_count: DecompositionLevelCount,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+) -> usize {
+    // One block per polynomial of the secret key
+    glwe_size.to_glwe_dimension().0
+        * glwe_automorphism_key_input_key_element_encrypted_size(
+            decomp_level_count,
+            glwe_size,
+            polynomial_size,
+        )
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> GlweAutomorphismKey<C> {
+    /// Create a [`GlweAutomorphismKey`] from an existing container.
+    ///
+    /// # Note
+    ///
+    /// This function only wraps a container in the appropriate type. If you want to generate a
+    /// [`GlweAutomorphismKey`] you need to call
+    /// [`crate::core_crypto::algorithms::generate_glwe_automorphism_key`] using this key as
+    /// output.
+    ///
+    /// This docstring exhibits [`GlweAutomorphismKey`] primitives usage.
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    /// // computations
+    /// // Define parameters for GlweAutomorphismKey creation
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(1024);
+    /// let decomp_base_log = DecompositionBaseLog(4);
+    /// let decomp_level_count = DecompositionLevelCount(5);
+    /// let automorphism_exponent = 3;
+    /// let ciphertext_modulus = CiphertextModulus::new_native();
+    ///
+    /// // Create a new GlweAutomorphismKey
+    /// let automorphism_key = GlweAutomorphismKey::new(
+    ///     0u64,
+    ///     decomp_base_log,
+    ///     decomp_level_count,
+    ///     glwe_dimension,
+    ///     polynomial_size,
+    ///     automorphism_exponent,
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// assert_eq!(automorphism_key.decomposition_base_log(), decomp_base_log);
+    /// assert_eq!(
+    ///     automorphism_key.decomposition_level_count(),
+    ///     decomp_level_count
+    /// );
+    /// assert_eq!(automorphism_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(automorphism_key.glwe_size(), glwe_dimension.to_glwe_size());
+    /// assert_eq!(automorphism_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     automorphism_key.automorphism_exponent(),
+    ///     automorphism_exponent
+    /// );
+    /// assert_eq!(automorphism_key.ciphertext_modulus(), ciphertext_modulus);
+    ///
+    /// // Demonstrate how to recover the allocated container
+    /// let underlying_container: Vec<u64> = automorphism_key.into_container();
+    ///
+    /// // Recreate a key using from_container
+    /// let automorphism_key = GlweAutomorphismKey::from_container(
+    ///     underlying_container,
+    ///     decomp_base_log,
+    ///     decomp_level_count,
+    ///     glwe_dimension.to_glwe_size(),
+    ///     polynomial_size,
+    ///     automorphism_exponent,
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// assert_eq!(automorphism_key.decomposition_base_log(), decomp_base_log);
+    /// assert_eq!(
+    ///     automorphism_key.decomposition_level_count(),
+    ///     decomp_level_count
+    /// );
+    /// assert_eq!(automorphism_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(automorphism_key.glwe_size(), glwe_dimension.to_glwe_size());
+    /// assert_eq!(automorphism_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     automorphism_key.automorphism_exponent(),
+    ///     automorphism_exponent
+    /// );
+    /// assert_eq!(automorphism_key.ciphertext_modulus(), ciphertext_modulus);
+    /// ```
+    pub fn from_container(
+        container: C,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        automorphism_exponent: usize,
+        ciphertext_modulus: CiphertextModulus<C::Element>,
+    ) -> Self {
+        assert!(
+            container.container_len() > 0,
+            "Got an empty container to create a GlweAutomorphismKey"
+        );
+        assert!(
+            container.container_len()
+                == glwe_automorphism_key_size(decomp_level_count, glwe_size, polynomial_size),
+            "The provided container length is not valid. \
+        It needs to be equal to: {}. Got container length: {} and decomp_level_count: \
+        {decomp_level_count:?}, glwe_size: {glwe_size:?}, polynomial_size: \
+        {polynomial_size:?}.",
+            glwe_automorphism_key_size(decomp_level_count, glwe_size, polynomial_size),
+            container.container_len()
+        );
+        assert!(
+            automorphism_exponent % 2 == 1,
+            "The automorphism exponent must be odd, got {automorphism_exponent}."
+        );
+
+        Self {
+            data: container,
+            decomp_base_log,
+            decomp_level_count,
+            glwe_size,
+            polynomial_size,
+            automorphism_exponent,
+            ciphertext_modulus,
+        }
+    }
+
+    /// Return the [`DecompositionBaseLog`] of the [`GlweAutomorphismKey`].
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Return the [`DecompositionLevelCount`] of the [`GlweAutomorphismKey`].
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Return the [`GlweDimension`] of the [`GlweAutomorphismKey`].
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_size.to_glwe_dimension()
+    }
+
+    /// Return the [`GlweSize`] of the [`GlweAutomorphismKey`].
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    /// Return the [`PolynomialSize`] of the [`GlweAutomorphismKey`].
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    /// Return the exponent $k$ of the automorphism $X \mapsto X^{k}$ of the
+    /// [`GlweAutomorphismKey`].
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn automorphism_exponent(&self) -> usize {
+        self.automorphism_exponent
+    }
+
+    /// Return the number of elements in an encryption of a polynomial of the input
+    /// [`super::GlweSecretKey`] of the current [`GlweAutomorphismKey`].
+    pub fn input_key_element_encrypted_size(&self) -> usize {
+        glwe_automorphism_key_input_key_element_encrypted_size(
+            self.decomp_level_count,
+            self.glwe_size,
+            self.polynomial_size,
+        )
+    }
+
+    /// Return the [`CiphertextModulus`] of the [`GlweAutomorphismKey`].
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus<C::Element> {
+        self.ciphertext_modulus
+    }
+
+    /// Return a view of the [`GlweAutomorphismKey`]. This is useful if an algorithm takes a view
+    /// by value.
+    pub fn as_view(&self) -> GlweAutomorphismKeyView<'_, Scalar> {
+        GlweAutomorphismKey::from_container(
+            self.as_ref(),
+            self.decomp_base_log,
+            self.decomp_level_count,
+            self.glwe_size,
+            self.polynomial_size,
+            self.automorphism_exponent,
+            self.ciphertext_modulus,
+        )
+    }
+
+    /// Consume the entity and return its underlying container.
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn into_container(self) -> C {
+        self.data
+    }
+
+    pub fn as_glwe_ciphertext_list(&self) -> GlweCiphertextListView<'_, Scalar> {
+        GlweCiphertextListView::from_container(
+            self.as_ref(),
+            self.glwe_size(),
+            self.polynomial_size(),
+            self.ciphertext_modulus(),
+        )
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> GlweAutomorphismKey<C> {
+    /// Mutable variant of [`GlweAutomorphismKey::as_view`].
+    pub fn as_mut_view(&mut self) -> GlweAutomorphismKeyMutView<'_, Scalar> {
+        let decomp_base_log = self.decomp_base_log;
+        let decomp_level_count = self.decomp_level_count;
+        let glwe_size = self.glwe_size;
+        let polynomial_size = self.polynomial_size;
+        let automorphism_exponent = self.automorphism_exponent;
+        let ciphertext_modulus = self.ciphertext_modulus;
+        GlweAutomorphismKey::from_container(
+            self.as_mut(),
+            decomp_base_log,
+            decomp_level_count,
+            glwe_size,
+            polynomial_size,
+            automorphism_exponent,
+            ciphertext_modulus,
+        )
+    }
+
+    pub fn as_mut_glwe_ciphertext_list(&mut self) -> GlweCiphertextListMutView<'_, Scalar> {
+        let glwe_size = self.glwe_size();
+        let polynomial_size = self.polynomial_size();
+        let ciphertext_modulus = self.ciphertext_modulus();
+        GlweCiphertextListMutView::from_container(
+            self.as_mut(),
+            glwe_size,
+            polynomial_size,
+            ciphertext_modulus,
+        )
+    }
+}
+
+/// A [`GlweAutomorphismKey`] owning the memory for its own storage.
+pub type GlweAutomorphismKeyOwned<Scalar> = GlweAutomorphismKey<Vec<Scalar>>;
+/// A [`GlweAutomorphismKey`] immutably borrowing memory for its own storage.
+pub type GlweAutomorphismKeyView<'data, Scalar> = GlweAutomorphismKey<&'data [Scalar]>;
+/// A [`GlweAutomorphismKey`] mutably borrowing memory for its own storage.
+pub type GlweAutomorphismKeyMutView<'data, Scalar> = GlweAutomorphismKey<&'data mut [Scalar]>;
+
+impl<Scalar: UnsignedInteger> GlweAutomorphismKeyOwned<Scalar> {
+    /// Allocate memory and create a new owned [`GlweAutomorphismKey`].
+    ///
+    /// # Note
+    ///
+    /// This function allocates a vector of the appropriate size and wraps it in the appropriate
+    /// type. If you want to generate a [`GlweAutomorphismKey`] you need to call
+    /// [`crate::core_crypto::algorithms::generate_glwe_automorphism_key`] using this key as
+    /// output.
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn new(
+        fill_with: Scalar,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        automorphism_exponent: usize,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        Self::from_container(
+            vec![
+                fill_with;
+                glwe_automorphism_key_size(
+                    decomp_level_count,
+                    glwe_dimension.to_glwe_size(),
+                    polynomial_size
+                )
+            ],
+            decomp_base_log,
+            decomp_level_count,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            automorphism_exponent,
+            ciphertext_modulus,
+        )
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> ContiguousEntityContainer
+    for GlweAutomorphismKey<C>
+{
+    type Element = C::Element;
+
+    type EntityViewMetadata = GlweCiphertextListCreationMetadata<Self::Element>;
+
+    type EntityView<'this> = GlweCiphertextListView<'this, Self::Element>
+    where
+        Self: 'this;
+
+    type SelfViewMetadata = ();
+
+    // At the moment it does not make sense to return "sub" automorphism keys. So we use a dummy
+    // placeholder type here.
+    type SelfView<'this> = DummyCreateFrom
+    where
+        Self: 'this;
+
+    fn get_entity_view_creation_metadata(&self) -> Self::EntityViewMetadata {
+        GlweCiphertextListCreationMetadata {
+            glwe_size: self.glwe_size(),
+            polynomial_size: self.polynomial_size(),
+            ciphertext_modulus: self.ciphertext_modulus(),
+        }
+    }
+
+    fn get_entity_view_pod_size(&self) -> usize {
+        self.input_key_element_encrypted_size()
+    }
+
+    /// Unimplemented for [`GlweAutomorphismKey`]. At the moment it does not make sense to
+    /// return "sub" automorphism keys.
+    fn get_self_view_creation_metadata(&self) -> Self::SelfViewMetadata {
+        unimplemented!(
+            "This function is not supported for GlweAutomorphismKey. \
+        At the moment it does not make sense to return 'sub' automorphism keys."
+        )
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> ContiguousEntityContainerMut
+    for GlweAutomorphismKey<C>
+{
+    type EntityMutView<'this> = GlweCiphertextListMutView<'this, Self::Element>
+    where
+        Self: 'this;
+
+    // At the moment it does not make sense to return "sub" automorphism keys. So we use a dummy
+    // placeholder type here.
+    type SelfMutView<'this> = DummyCreateFrom
+    where
+        Self: 'this;
+}
+
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
+pub struct GlweAutomorphismKeyConformanceParams {
+    pub decomp_base_log: DecompositionBaseLog,
+    pub decomp_level_count: DecompositionLevelCount,
+    pub glwe_size: GlweSize,
+    pub polynomial_size: PolynomialSize,
+    pub automorphism_exponent: usize,
+    pub ciphertext_modulus: CiphertextModulus<u64>,
+}
+
+impl<C: Container<Element = u64>> ParameterSetConformant for GlweAutomorphismKey<C> {
+    type ParameterSet = GlweAutomorphismKeyConformanceParams;
+
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        let Self {
+            data,
+            decomp_base_log,
+            decomp_level_count,
+            glwe_size,
+            polynomial_size,
+            automorphism_exponent,
+            ciphertext_modulus,
+        } = self;
+
+        data.container_len()
+            == glwe_automorphism_key_size(*decomp_level_count, *glwe_size, *polynomial_size)
+            && *decomp_base_log == parameter_set.decomp_base_log
+            && *decomp_level_count == parameter_set.decomp_level_count
+            && *glwe_size == parameter_set.glwe_size
+            && *polynomial_size == parameter_set.polynomial_size
+            && *automorphism_exponent == parameter_set.automorphism_exponent
+            && *ciphertext_modulus == parameter_set.ciphertext_modulus
+    }
+}
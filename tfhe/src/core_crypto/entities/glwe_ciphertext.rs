@@ -627,6 +627,7 @@ impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> CreateFrom<C> for
 /// Can be used on a server to check if client inputs are well formed
 /// before running a computation on them
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
 pub struct GlweCiphertextConformanceParameters<T: UnsignedInteger> {
     pub glwe_dim: GlweDimension,
     pub polynomial_size: PolynomialSize,
@@ -40,6 +40,16 @@ impl<T, C: ContainerMut<Element = T>> AsMut<[T]> for GlweSecretKey<C> {
     }
 }
 
+/// Overwrites the coefficients of the key with zeros, so that the secret does not remain in memory.
+impl<C: ContainerMut> zeroize::Zeroize for GlweSecretKey<C>
+where
+    C::Element: zeroize::DefaultIsZeroes,
+{
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(self.data.as_mut());
+    }
+}
+
 impl<Scalar, C: Container<Element = Scalar>> GlweSecretKey<C> {
     /// Create a [`GlweSecretKey`] from an existing container.
     ///
@@ -742,6 +742,7 @@ pub type LweCiphertextMutView<'data, Scalar> = LweCiphertext<&'data mut [Scalar]
 /// Can be used on a server to check if client inputs are well formed
 /// before running a computation on them
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
 pub struct LweCiphertextParameters<T: UnsignedInteger> {
     pub lwe_dim: LweDimension,
     pub ct_modulus: CiphertextModulus<T>,
@@ -749,6 +750,7 @@ pub struct LweCiphertextParameters<T: UnsignedInteger> {
 }
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
 pub enum MsDecompressionType {
     ClassicPbs,
     MultiBitPbs(LweBskGroupingFactor),
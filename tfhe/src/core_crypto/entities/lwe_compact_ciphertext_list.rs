@@ -334,6 +334,7 @@ pub type LweCompactCiphertextListMutView<'data, Scalar> =
 /// Can be used on a server to check if client inputs are well formed
 /// before running a computation on them
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
 pub struct LweCiphertextListParameters<T: UnsignedInteger> {
     pub lwe_dim: LweDimension,
     pub lwe_ciphertext_count_constraint: ListSizeConstraint,
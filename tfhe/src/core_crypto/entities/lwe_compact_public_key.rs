@@ -186,6 +186,7 @@ impl<Scalar: UnsignedInteger> LweCompactPublicKeyOwned<Scalar> {
 }
 
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
 pub struct LweCompactPublicKeyEncryptionParameters<Scalar: UnsignedInteger> {
     pub encryption_lwe_dimension: LweDimension,
     pub ciphertext_modulus: CiphertextModulus<Scalar>,
@@ -431,16 +431,19 @@ impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> ContiguousEntit
         Self: 'this;
 }
 
-pub struct KeyswitchKeyConformanceParams {
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
+pub struct KeyswitchKeyConformanceParams<Scalar: UnsignedInteger = u64> {
     pub decomp_base_log: DecompositionBaseLog,
     pub decomp_level_count: DecompositionLevelCount,
     pub output_lwe_size: LweSize,
     pub input_lwe_dimension: LweDimension,
-    pub ciphertext_modulus: CiphertextModulus<u64>,
+    pub ciphertext_modulus: CiphertextModulus<Scalar>,
 }
 
-impl<C: Container<Element = u64>> ParameterSetConformant for LweKeyswitchKey<C> {
-    type ParameterSet = KeyswitchKeyConformanceParams;
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> ParameterSetConformant
+    for LweKeyswitchKey<C>
+{
+    type ParameterSet = KeyswitchKeyConformanceParams<Scalar>;
 
     fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
         let Self {
@@ -692,6 +692,7 @@ impl FourierLweMultiBitBootstrapKeyOwned {
     }
 }
 
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
 pub struct MultiBitBootstrapKeyConformanceParams {
     pub decomp_base_log: DecompositionBaseLog,
     pub decomp_level_count: DecompositionLevelCount,
@@ -398,6 +398,7 @@ impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> ContiguousEntit
         Self: 'this;
 }
 
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
 pub struct PackingKeyswitchConformanceParams {
     pub decomp_base_log: DecompositionBaseLog,
     pub decomp_level_count: DecompositionLevelCount,
@@ -37,6 +37,16 @@ impl<T, C: ContainerMut<Element = T>> AsMut<[T]> for LweSecretKey<C> {
     }
 }
 
+/// Overwrites the coefficients of the key with zeros, so that the secret does not remain in memory.
+impl<C: ContainerMut> zeroize::Zeroize for LweSecretKey<C>
+where
+    C::Element: zeroize::DefaultIsZeroes,
+{
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(self.data.as_mut());
+    }
+}
+
 impl<Scalar, C: Container<Element = Scalar>> LweSecretKey<C> {
     /// Create an [`LweSecretKey`] from an existing container.
     ///
@@ -9,6 +9,14 @@ use crate::core_crypto::commons::traits::*;
 #[derive(Debug, Copy, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
 #[versionize(PlaintextVersions)]
 pub struct Plaintext<T: Numeric>(pub T);
+
+/// Overwrites the plaintext with zero, so that the decrypted value does not remain in memory.
+impl<T: Numeric + zeroize::DefaultIsZeroes> zeroize::Zeroize for Plaintext<T> {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.0);
+    }
+}
+
 /// An immutable reference to a plaintext (encoded) value.
 ///
 /// Can be converted to a plaintext via a call to `into`
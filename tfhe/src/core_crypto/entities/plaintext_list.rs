@@ -26,6 +26,17 @@ impl<Scalar, C: ContainerMut<Element = Scalar>> AsMut<[Scalar]> for PlaintextLis
     }
 }
 
+/// Overwrites the plaintexts of the list with zeros, so that decrypted values do not remain in
+/// memory.
+impl<C: ContainerMut> zeroize::Zeroize for PlaintextList<C>
+where
+    C::Element: zeroize::DefaultIsZeroes,
+{
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(self.data.as_mut());
+    }
+}
+
 impl<Scalar, C: Container<Element = Scalar>> PlaintextList<C> {
     /// Create a [`PlaintextList`] from an existing container.
     ///
@@ -136,13 +147,15 @@ impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> ContiguousEntityCo
 
     type EntityViewMetadata = ();
 
-    type EntityView<'this> = PlaintextRef<'this, Self::Element>
+    type EntityView<'this>
+        = PlaintextRef<'this, Self::Element>
     where
         Self: 'this;
 
     type SelfViewMetadata = ();
 
-    type SelfView<'this> = PlaintextList<&'this [Self::Element]>
+    type SelfView<'this>
+        = PlaintextList<&'this [Self::Element]>
     where
         Self: 'this;
 
@@ -158,11 +171,13 @@ impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> ContiguousEntityCo
 impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> ContiguousEntityContainerMut
     for PlaintextList<C>
 {
-    type EntityMutView<'this>= PlaintextRefMut<'this, Self::Element>
+    type EntityMutView<'this>
+        = PlaintextRefMut<'this, Self::Element>
     where
         Self: 'this;
 
-    type SelfMutView<'this>= PlaintextList<&'this mut [Self::Element]>
+    type SelfMutView<'this>
+        = PlaintextList<&'this mut [Self::Element]>
     where
         Self: 'this;
 }
@@ -468,13 +468,14 @@ impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> ContiguousEntit
         Self: 'this;
 }
 
-pub struct GgswCiphertextListConformanceParameters {
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
+pub struct GgswCiphertextListConformanceParameters<Scalar: UnsignedInteger = u64> {
     pub len: usize,
     pub glwe_size: GlweSize,
     pub polynomial_size: PolynomialSize,
     pub decomp_base_log: DecompositionBaseLog,
     pub decomp_level_count: DecompositionLevelCount,
-    pub ciphertext_modulus: CiphertextModulus<u64>,
+    pub ciphertext_modulus: CiphertextModulus<Scalar>,
 }
 
 impl TryFrom<&MultiBitBootstrapKeyConformanceParams> for GgswCiphertextListConformanceParameters {
@@ -498,8 +499,10 @@ impl TryFrom<&MultiBitBootstrapKeyConformanceParams> for GgswCiphertextListConfo
     }
 }
 
-impl From<&BootstrapKeyConformanceParams> for GgswCiphertextListConformanceParameters {
-    fn from(value: &BootstrapKeyConformanceParams) -> Self {
+impl<Scalar: UnsignedInteger> From<&BootstrapKeyConformanceParams<Scalar>>
+    for GgswCiphertextListConformanceParameters<Scalar>
+{
+    fn from(value: &BootstrapKeyConformanceParams<Scalar>) -> Self {
         Self {
             len: value.input_lwe_dimension.0,
             glwe_size: value.output_glwe_size,
@@ -511,8 +514,10 @@ impl From<&BootstrapKeyConformanceParams> for GgswCiphertextListConformanceParam
     }
 }
 
-impl<C: Container<Element = u64>> ParameterSetConformant for SeededGgswCiphertextList<C> {
-    type ParameterSet = GgswCiphertextListConformanceParameters;
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> ParameterSetConformant
+    for SeededGgswCiphertextList<C>
+{
+    type ParameterSet = GgswCiphertextListConformanceParameters<Scalar>;
 
     fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
         let Self {
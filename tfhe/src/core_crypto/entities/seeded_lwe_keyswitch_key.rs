@@ -421,8 +421,10 @@ impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> ContiguousEntit
         Self: 'this;
 }
 
-impl<C: Container<Element = u64>> ParameterSetConformant for SeededLweKeyswitchKey<C> {
-    type ParameterSet = KeyswitchKeyConformanceParams;
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> ParameterSetConformant
+    for SeededLweKeyswitchKey<C>
+{
+    type ParameterSet = KeyswitchKeyConformanceParams<Scalar>;
 
     fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
         let Self {
@@ -9,7 +9,7 @@ use crate::core_crypto::backward_compatibility::fft_impl::{
 };
 use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
 use crate::core_crypto::commons::math::torus::UnsignedTorus;
-use crate::core_crypto::commons::numeric::CastInto;
+use crate::core_crypto::commons::numeric::{CastInto, UnsignedInteger};
 use crate::core_crypto::commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, MonomialDegree,
     PolynomialSize,
@@ -531,13 +531,14 @@ where
     }
 }
 
-pub struct BootstrapKeyConformanceParams {
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
+pub struct BootstrapKeyConformanceParams<Scalar: UnsignedInteger = u64> {
     pub decomp_base_log: DecompositionBaseLog,
     pub decomp_level_count: DecompositionLevelCount,
     pub input_lwe_dimension: LweDimension,
     pub output_glwe_size: GlweSize,
     pub polynomial_size: PolynomialSize,
-    pub ciphertext_modulus: CiphertextModulus<u64>,
+    pub ciphertext_modulus: CiphertextModulus<Scalar>,
 }
 
 impl<C: Container<Element = c64>> ParameterSetConformant for FourierLweBootstrapKey<C> {
@@ -1,9 +1,19 @@
+use crate::core_crypto::commons::generators::MaskRandomGenerator;
+use crate::core_crypto::commons::math::random::Uniform;
+use crate::core_crypto::gpu::entities::DECOMPRESSION_CHUNK_ELEMENT_COUNT;
 use crate::core_crypto::gpu::vec::CudaVec;
-use crate::core_crypto::gpu::{convert_lwe_programmable_bootstrap_key_async, CudaStreams};
+use crate::core_crypto::gpu::{
+    convert_lwe_programmable_bootstrap_key_async,
+    convert_lwe_programmable_bootstrap_key_chunk_async, CudaStreams,
+};
 use crate::core_crypto::prelude::{
-    lwe_bootstrap_key_size, Container, DecompositionBaseLog, DecompositionLevelCount,
-    GlweDimension, LweBootstrapKey, LweDimension, PolynomialSize, UnsignedInteger,
+    decompress_seeded_ggsw_ciphertext_with_existing_generator, ggsw_ciphertext_size,
+    lwe_bootstrap_key_size, ActivatedRandomGenerator, Container, ContiguousEntityContainer,
+    ContiguousEntityContainerMut, DecompositionBaseLog, DecompositionLevelCount,
+    GgswCiphertextList, GlweDimension, LweBootstrapKey, LweDimension, PolynomialSize,
+    SeededLweBootstrapKey, UnsignedInteger,
 };
+use rayon::prelude::*;
 
 /// A structure representing a vector of GLWE ciphertexts with 64 bits of precision on the GPU.
 #[derive(Debug)]
@@ -71,6 +81,101 @@ impl CudaLweBootstrapKey {
         }
     }
 
+    /// Decompress a [`SeededLweBootstrapKey`] on the host by chunks and upload it to the GPU.
+    ///
+    /// The masks of the key are regenerated on the CPU a few GGSW ciphertexts at a time, each
+    /// chunk being converted and copied to the GPU before the next one is decompressed. This
+    /// does as much work on the CPU as decompressing the whole key before calling
+    /// [`Self::from_lwe_bootstrap_key`], but the decompressed key is never fully held in host
+    /// memory.
+    pub fn from_seeded_lwe_bootstrap_key_with_chunked_decompression<InputBskCont>(
+        bsk: &SeededLweBootstrapKey<InputBskCont>,
+        streams: &CudaStreams,
+    ) -> Self
+    where
+        InputBskCont: Container<Element = u64>,
+    {
+        let input_lwe_dimension = bsk.input_lwe_dimension();
+        let polynomial_size = bsk.polynomial_size();
+        let decomp_level_count = bsk.decomposition_level_count();
+        let decomp_base_log = bsk.decomposition_base_log();
+        let glwe_size = bsk.glwe_size();
+        let glwe_dimension = glwe_size.to_glwe_dimension();
+        let ciphertext_modulus = bsk.ciphertext_modulus();
+
+        // Allocate memory
+        let mut d_vec = CudaVec::<f64>::new_multi_gpu(
+            lwe_bootstrap_key_size(
+                input_lwe_dimension,
+                glwe_size,
+                polynomial_size,
+                decomp_level_count,
+            ),
+            streams,
+        );
+
+        let ggsw_size = ggsw_ciphertext_size(glwe_size, polynomial_size, decomp_level_count);
+        let ggsw_per_chunk = (DECOMPRESSION_CHUNK_ELEMENT_COUNT / ggsw_size).max(1);
+        let mut buffer = vec![0u64; ggsw_per_chunk * ggsw_size];
+
+        // Forking logic must match the one of the BSK decompression, each GGSW ciphertext has its
+        // own generator
+        let mut generator =
+            MaskRandomGenerator::<ActivatedRandomGenerator>::new(bsk.compression_seed().seed);
+        let mut gen_iter = generator
+            .try_fork_from_config(bsk.decompression_fork_config(Uniform))
+            .unwrap();
+
+        for (chunk_index, input_chunk) in bsk.chunks(ggsw_per_chunk).enumerate() {
+            let ggsw_count = input_chunk.ggsw_ciphertext_count().0;
+            let generators = gen_iter.by_ref().take(ggsw_count).collect::<Vec<_>>();
+
+            let mut output_chunk = GgswCiphertextList::from_container(
+                &mut buffer[..ggsw_count * ggsw_size],
+                glwe_size,
+                polynomial_size,
+                decomp_base_log,
+                decomp_level_count,
+                ciphertext_modulus,
+            );
+            output_chunk
+                .par_iter_mut()
+                .zip(input_chunk.par_iter())
+                .zip(generators.into_par_iter())
+                .for_each(|((mut output_ggsw, input_ggsw), mut loop_generator)| {
+                    decompress_seeded_ggsw_ciphertext_with_existing_generator(
+                        &mut output_ggsw,
+                        &input_ggsw,
+                        &mut loop_generator,
+                    );
+                });
+
+            // Copy to the GPU, the buffer is reused for the next chunk once the copy is done
+            unsafe {
+                convert_lwe_programmable_bootstrap_key_chunk_async(
+                    streams,
+                    &mut d_vec,
+                    chunk_index * ggsw_per_chunk * ggsw_size,
+                    output_chunk.as_ref(),
+                    LweDimension(ggsw_count),
+                    glwe_dimension,
+                    decomp_level_count,
+                    polynomial_size,
+                );
+            }
+            streams.synchronize();
+        }
+
+        Self {
+            d_vec,
+            input_lwe_dimension,
+            glwe_dimension,
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+        }
+    }
+
     pub(crate) fn input_lwe_dimension(&self) -> LweDimension {
         self.input_lwe_dimension
     }
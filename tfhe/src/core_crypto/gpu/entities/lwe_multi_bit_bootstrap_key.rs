@@ -1,12 +1,19 @@
+use crate::core_crypto::commons::generators::MaskRandomGenerator;
+use crate::core_crypto::commons::math::random::Uniform;
+use crate::core_crypto::gpu::entities::DECOMPRESSION_CHUNK_ELEMENT_COUNT;
 use crate::core_crypto::gpu::vec::CudaVec;
 use crate::core_crypto::gpu::{
-    convert_lwe_multi_bit_programmable_bootstrap_key_async, CudaStreams,
+    convert_lwe_multi_bit_programmable_bootstrap_key_async,
+    convert_lwe_multi_bit_programmable_bootstrap_key_chunk_async, CudaStreams,
 };
 use crate::core_crypto::prelude::{
-    lwe_multi_bit_bootstrap_key_size, Container, DecompositionBaseLog, DecompositionLevelCount,
-    GlweDimension, LweBskGroupingFactor, LweDimension, LweMultiBitBootstrapKey, PolynomialSize,
-    UnsignedInteger,
+    ggsw_ciphertext_size, lwe_multi_bit_bootstrap_key_size,
+    par_decompress_seeded_ggsw_ciphertext_list_with_existing_generator, ActivatedRandomGenerator,
+    Container, ContiguousEntityContainer, ContiguousEntityContainerMut, DecompositionBaseLog,
+    DecompositionLevelCount, GgswCiphertextList, GlweDimension, LweBskGroupingFactor, LweDimension,
+    LweMultiBitBootstrapKey, PolynomialSize, SeededLweMultiBitBootstrapKey, UnsignedInteger,
 };
+use rayon::prelude::*;
 
 /// A structure representing a vector of GLWE ciphertexts with 64 bits of precision on the GPU.
 #[derive(Debug)]
@@ -79,6 +86,111 @@ impl CudaLweMultiBitBootstrapKey {
         }
     }
 
+    /// Decompress a [`SeededLweMultiBitBootstrapKey`] on the host by chunks and upload it to the
+    /// GPU.
+    ///
+    /// The masks of the key are regenerated on the CPU a few GGSW groups at a time, each chunk
+    /// being converted and copied to the GPU before the next one is decompressed. This does as
+    /// much work on the CPU as decompressing the whole key before calling
+    /// [`Self::from_lwe_multi_bit_bootstrap_key`], but the decompressed key is never fully held in
+    /// host memory.
+    pub fn from_seeded_lwe_multi_bit_bootstrap_key_with_chunked_decompression<InputBskCont>(
+        bsk: &SeededLweMultiBitBootstrapKey<InputBskCont>,
+        streams: &CudaStreams,
+    ) -> Self
+    where
+        InputBskCont: Container<Element = u64>,
+    {
+        let input_lwe_dimension = bsk.input_lwe_dimension();
+        let polynomial_size = bsk.polynomial_size();
+        let decomp_level_count = bsk.decomposition_level_count();
+        let decomp_base_log = bsk.decomposition_base_log();
+        let glwe_size = bsk.glwe_size();
+        let glwe_dimension = glwe_size.to_glwe_dimension();
+        let grouping_factor = bsk.grouping_factor();
+        let ciphertext_modulus = bsk.ciphertext_modulus();
+
+        // Allocate memory
+        let mut d_vec = CudaVec::<u64>::new_multi_gpu(
+            lwe_multi_bit_bootstrap_key_size(
+                input_lwe_dimension,
+                glwe_size,
+                polynomial_size,
+                decomp_level_count,
+                grouping_factor,
+            )
+            .unwrap(),
+            streams,
+        );
+
+        let ggsw_per_group = grouping_factor.ggsw_per_multi_bit_element().0;
+        let group_size =
+            ggsw_per_group * ggsw_ciphertext_size(glwe_size, polynomial_size, decomp_level_count);
+        let group_per_chunk = (DECOMPRESSION_CHUNK_ELEMENT_COUNT / group_size).max(1);
+        let mut buffer = vec![0u64; group_per_chunk * group_size];
+
+        // Forking logic must match the one of the multi bit BSK decompression, each GGSW group
+        // has its own generator which is then forked for each GGSW of the group
+        let mut generator =
+            MaskRandomGenerator::<ActivatedRandomGenerator>::new(bsk.compression_seed().seed);
+        let mut gen_iter = generator
+            .try_fork_from_config(bsk.decompression_fork_config(Uniform))
+            .unwrap();
+
+        for (chunk_index, input_chunk) in bsk.chunks(group_per_chunk * ggsw_per_group).enumerate() {
+            let group_count = input_chunk.ggsw_ciphertext_count().0 / ggsw_per_group;
+            let generators = gen_iter.by_ref().take(group_count).collect::<Vec<_>>();
+
+            let mut output_chunk = GgswCiphertextList::from_container(
+                &mut buffer[..group_count * group_size],
+                glwe_size,
+                polynomial_size,
+                decomp_base_log,
+                decomp_level_count,
+                ciphertext_modulus,
+            );
+            output_chunk
+                .par_chunks_exact_mut(ggsw_per_group)
+                .zip(input_chunk.par_chunks_exact(ggsw_per_group))
+                .zip(generators.into_par_iter())
+                .for_each(
+                    |((mut output_ggsw_group, input_ggsw_group), mut loop_generator)| {
+                        par_decompress_seeded_ggsw_ciphertext_list_with_existing_generator(
+                            &mut output_ggsw_group,
+                            &input_ggsw_group,
+                            &mut loop_generator,
+                        );
+                    },
+                );
+
+            // Copy to the GPU, the buffer is reused for the next chunk once the copy is done
+            unsafe {
+                convert_lwe_multi_bit_programmable_bootstrap_key_chunk_async(
+                    streams,
+                    &mut d_vec,
+                    chunk_index * group_per_chunk * group_size,
+                    output_chunk.as_ref(),
+                    LweDimension(group_count * grouping_factor.0),
+                    glwe_dimension,
+                    decomp_level_count,
+                    polynomial_size,
+                    grouping_factor,
+                );
+            }
+            streams.synchronize();
+        }
+
+        Self {
+            d_vec,
+            input_lwe_dimension,
+            glwe_dimension,
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+            grouping_factor,
+        }
+    }
+
     pub(crate) fn input_lwe_dimension(&self) -> LweDimension {
         self.input_lwe_dimension
     }
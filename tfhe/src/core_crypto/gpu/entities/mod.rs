@@ -4,3 +4,7 @@ pub mod lwe_ciphertext_list;
 pub mod lwe_keyswitch_key;
 pub mod lwe_multi_bit_bootstrap_key;
 pub mod lwe_packing_keyswitch_key;
+
+/// Maximum number of elements of a decompressed key held in host memory at once when a compressed
+/// key is uploaded to the GPU (64 MiB of 64 bits elements).
+pub(crate) const DECOMPRESSION_CHUNK_ELEMENT_COUNT: usize = 1 << 23;
@@ -347,6 +347,44 @@ pub unsafe fn convert_lwe_programmable_bootstrap_key_async<T: UnsignedInteger>(
     }
 }
 
+/// Convert a chunk of a programmable bootstrap key
+///
+/// `src` holds `input_lwe_dim` consecutive GGSW ciphertexts of the key, they are converted and
+/// written in `dest` starting at the element at `dest_offset`.
+///
+/// # Safety
+///
+/// [CudaStreams::synchronize] __must__ be called as soon as synchronization is
+/// required
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn convert_lwe_programmable_bootstrap_key_chunk_async<T: UnsignedInteger>(
+    streams: &CudaStreams,
+    dest: &mut CudaVec<f64>,
+    dest_offset: usize,
+    src: &[T],
+    input_lwe_dim: LweDimension,
+    glwe_dim: GlweDimension,
+    l_gadget: DecompositionLevelCount,
+    polynomial_size: PolynomialSize,
+) {
+    assert!(dest_offset + src.len() <= dest.len());
+    for &gpu_index in streams.gpu_indexes.iter() {
+        cuda_convert_lwe_programmable_bootstrap_key_64(
+            streams.ptr[gpu_index as usize],
+            streams.gpu_indexes[gpu_index as usize],
+            dest.get_mut_c_ptr(gpu_index)
+                .cast::<f64>()
+                .add(dest_offset)
+                .cast(),
+            src.as_ptr().cast(),
+            input_lwe_dim.0 as u32,
+            glwe_dim.0 as u32,
+            l_gadget.0 as u32,
+            polynomial_size.0 as u32,
+        );
+    }
+}
+
 /// Convert multi-bit programmable bootstrap key
 ///
 /// # Safety
@@ -381,6 +419,48 @@ pub unsafe fn convert_lwe_multi_bit_programmable_bootstrap_key_async<T: Unsigned
     }
 }
 
+/// Convert a chunk of a multi-bit programmable bootstrap key
+///
+/// `src` holds the GGSW groups of `input_lwe_dim` consecutive input LWE coefficients (a multiple
+/// of the grouping factor), they are converted and written in `dest` starting at the element at
+/// `dest_offset`.
+///
+/// # Safety
+///
+/// [CudaStreams::synchronize] __must__ be called as soon as synchronization is
+/// required
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn convert_lwe_multi_bit_programmable_bootstrap_key_chunk_async<T: UnsignedInteger>(
+    streams: &CudaStreams,
+    dest: &mut CudaVec<u64>,
+    dest_offset: usize,
+    src: &[T],
+    input_lwe_dim: LweDimension,
+    glwe_dim: GlweDimension,
+    l_gadget: DecompositionLevelCount,
+    polynomial_size: PolynomialSize,
+    grouping_factor: LweBskGroupingFactor,
+) {
+    assert!(dest_offset + src.len() <= dest.len());
+    assert_eq!(input_lwe_dim.0 % grouping_factor.0, 0);
+    for &gpu_index in streams.gpu_indexes.iter() {
+        cuda_convert_lwe_multi_bit_programmable_bootstrap_key_64(
+            streams.ptr[gpu_index as usize],
+            streams.gpu_indexes[gpu_index as usize],
+            dest.as_mut_c_ptr(gpu_index)
+                .cast::<u64>()
+                .add(dest_offset)
+                .cast(),
+            src.as_ptr().cast(),
+            input_lwe_dim.0 as u32,
+            glwe_dim.0 as u32,
+            l_gadget.0 as u32,
+            polynomial_size.0 as u32,
+            grouping_factor.0 as u32,
+        );
+    }
+}
+
 /// # Safety
 ///
 /// [CudaStreams::synchronize] __must__ be called as soon as synchronization is
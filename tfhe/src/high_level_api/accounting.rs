@@ -0,0 +1,165 @@
+//! Per-[Tag] accounting of the homomorphic operations performed with the server keys.
+//!
+//! As each [ServerKey](crate::ServerKey) carries the [Tag] of the [ClientKey](crate::ClientKey)
+//! it was generated from, a service computing for multiple tenants can use this accounting to
+//! know how much work was done on behalf of each of them.
+//!
+//! The accounting is disabled by default, see [enable_tag_statistics].
+use crate::high_level_api::keys::InternalServerKey;
+use crate::Tag;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+static IS_ENABLED: AtomicBool = AtomicBool::new(false);
+static STATISTICS: Mutex<Option<HashMap<Tag, TagStatistics>>> = Mutex::new(None);
+
+thread_local! {
+    // Number of operations running on the thread, as operations may be implemented using other
+    // operations. Only the outermost one must be accounted for (and checked against the memory
+    // usage cap)
+    static NESTING_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Statistics accumulated for one [Tag]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TagStatistics {
+    operation_count: u64,
+    #[cfg(feature = "pbs-stats")]
+    pbs_count: u64,
+}
+
+impl TagStatistics {
+    /// Number of operations performed with a server key having the tag
+    pub fn operation_count(&self) -> u64 {
+        self.operation_count
+    }
+
+    /// Number of PBS performed by the operations done with a server key having the tag
+    ///
+    /// The PBS are counted using the global counter of the `pbs-stats` feature (see
+    /// [get_pbs_count](crate::get_pbs_count)), the count is thus exact only if operations for
+    /// different tags are not executed concurrently.
+    #[cfg(feature = "pbs-stats")]
+    pub fn pbs_count(&self) -> u64 {
+        self.pbs_count
+    }
+}
+
+/// Starts accumulating statistics for the operations performed from now on
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{
+///     enable_tag_statistics, set_server_key, take_tag_statistics, ClientKey, ConfigBuilder,
+///     FheUint8, ServerKey,
+/// };
+///
+/// let mut client_key = ClientKey::generate(ConfigBuilder::default());
+/// client_key.tag_mut().set_u64(1234);
+/// let server_key = ServerKey::new(&client_key);
+/// set_server_key(server_key);
+///
+/// enable_tag_statistics();
+///
+/// let a = FheUint8::encrypt(1u8, &client_key);
+/// let b = FheUint8::encrypt(2u8, &client_key);
+/// let _c = &a + &b;
+/// let _d = &a * &b;
+///
+/// let statistics = take_tag_statistics();
+/// assert_eq!(statistics[client_key.tag()].operation_count(), 2);
+/// ```
+pub fn enable_tag_statistics() {
+    IS_ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Stops accumulating statistics, the already accumulated statistics are kept
+pub fn disable_tag_statistics() {
+    IS_ENABLED.store(false, Ordering::Relaxed);
+}
+
+/// Returns a snapshot of the statistics accumulated so far, for each tag
+pub fn tag_statistics_snapshot() -> HashMap<Tag, TagStatistics> {
+    STATISTICS.lock().unwrap().clone().unwrap_or_default()
+}
+
+/// Returns the statistics accumulated so far, for each tag, and resets them
+///
+/// This is done atomically, so that no operation is lost between the snapshot and the reset
+pub fn take_tag_statistics() -> HashMap<Tag, TagStatistics> {
+    STATISTICS.lock().unwrap().take().unwrap_or_default()
+}
+
+/// Resets the accumulated statistics
+pub fn reset_tag_statistics() {
+    let _ = take_tag_statistics();
+}
+
+/// Returns the nesting depth of the current thread
+///
+/// Work spawned on other threads (e.g. by [fhe_join](crate::fhe_join)) must run with the depth of
+/// the thread that spawned it, otherwise operations run inside an already accounted operation
+/// would be accounted again by the worker threads.
+pub(in crate::high_level_api) fn current_nesting_depth() -> usize {
+    NESTING_DEPTH.with(Cell::get)
+}
+
+/// Sets the nesting depth of the current thread, returns the previous one
+pub(in crate::high_level_api) fn replace_nesting_depth(depth: usize) -> usize {
+    NESTING_DEPTH.with(|current| current.replace(depth))
+}
+
+/// Runs `func` and accounts it as one operation for the tag of the `key`
+#[inline]
+pub(in crate::high_level_api) fn account_operation<T, F>(key: &InternalServerKey, func: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    // Restores the depth even if `func` panics, otherwise the thread would stop
+    // accounting operations
+    struct RestoreDepth(usize);
+
+    impl Drop for RestoreDepth {
+        fn drop(&mut self) {
+            NESTING_DEPTH.with(|depth| depth.set(self.0));
+        }
+    }
+
+    let depth = NESTING_DEPTH.with(|depth| depth.replace(depth.get() + 1));
+    let restore_depth = RestoreDepth(depth);
+    if depth != 0 || !IS_ENABLED.load(Ordering::Relaxed) {
+        return func();
+    }
+
+    #[cfg(feature = "pbs-stats")]
+    let pbs_count_before = crate::get_pbs_count();
+
+    let result = func();
+
+    #[cfg(feature = "pbs-stats")]
+    let pbs_count = crate::get_pbs_count().saturating_sub(pbs_count_before);
+    drop(restore_depth);
+
+    let tag = match key {
+        InternalServerKey::Cpu(cpu_key) => &cpu_key.tag,
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(cuda_key) => &cuda_key.tag,
+    };
+
+    let mut statistics = STATISTICS.lock().unwrap();
+    let tag_statistics = statistics
+        .get_or_insert_with(HashMap::new)
+        .entry(tag.clone())
+        .or_default();
+    tag_statistics.operation_count += 1;
+    #[cfg(feature = "pbs-stats")]
+    {
+        tag_statistics.pbs_count += pbs_count;
+    }
+
+    result
+}
@@ -0,0 +1,654 @@
+//! Algorithms on slices of encrypted values.
+//!
+//! These are implemented inside the crate so that the independent operations they are made of
+//! run in parallel, which is not possible from the outside as the server key is set per thread.
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::{FheIntId, FheUintId};
+use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::policy::OperationKind;
+use crate::integer::block_decomposition::DecomposableInto;
+use crate::{FheBool, FheInt, FheUint, FheUint32};
+
+/// Sorts the encrypted values in ascending order
+///
+/// The values are sorted by a bitonic sorting network, whose layers of compare-and-swap
+/// operations are computed in parallel. As the values are encrypted, the cost does not depend on
+/// their order: sorting `n` values always takes `O(n * log2(n)^2)` comparisons.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::algorithms::fhe_sort;
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let mut values = [513u16, 2, 40000, 2, 77].map(|v| FheUint16::encrypt(v, &client_key));
+///
+/// fhe_sort(&mut values);
+///
+/// let decrypted = values
+///     .iter()
+///     .map(|v| v.decrypt(&client_key))
+///     .collect::<Vec<u16>>();
+/// assert_eq!(decrypted, vec![2, 2, 77, 513, 40000]);
+/// ```
+pub fn fhe_sort<Id>(values: &mut [FheUint<Id>])
+where
+    Id: FheUintId,
+{
+    global_state::with_internal_keys(|key| match key {
+        InternalServerKey::Cpu(cpu_key) => {
+            let mut cts = values
+                .iter()
+                .map(|value| value.ciphertext.on_cpu().into_owned())
+                .collect::<Vec<_>>();
+            cpu_key.pbs_key().sort_parallelized(&mut cts);
+            for (value, ct) in values.iter_mut().zip(cts) {
+                *value = FheUint::new(ct, cpu_key.tag.clone());
+            }
+        }
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(_) => {
+            panic!("Cuda devices do not support fhe_sort yet");
+        }
+    })
+}
+
+/// Sorts the encrypted keys in ascending order, and applies the same permutation to the values
+///
+/// The sort is not stable: values associated to equal keys may be reordered.
+///
+/// See [fhe_sort] for the sorting algorithm.
+///
+/// # Panics
+///
+/// Panics if there are not as many keys as values
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::algorithms::fhe_sort_by_key;
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint8};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// // Scores, and the ids of the players that made them
+/// let mut scores = [1200u16, 80, 640].map(|v| FheUint16::encrypt(v, &client_key));
+/// let mut ids = [1u8, 2, 3].map(|v| FheUint8::encrypt(v, &client_key));
+///
+/// fhe_sort_by_key(&mut scores, &mut ids);
+///
+/// let decrypted = ids
+///     .iter()
+///     .map(|v| v.decrypt(&client_key))
+///     .collect::<Vec<u8>>();
+/// assert_eq!(decrypted, vec![2, 3, 1]);
+/// ```
+pub fn fhe_sort_by_key<KeyId, ValueId>(keys: &mut [FheUint<KeyId>], values: &mut [FheUint<ValueId>])
+where
+    KeyId: FheUintId,
+    ValueId: FheUintId,
+{
+    assert_eq!(
+        keys.len(),
+        values.len(),
+        "There must be as many keys as values"
+    );
+
+    global_state::with_internal_keys(|key| match key {
+        InternalServerKey::Cpu(cpu_key) => {
+            let mut key_cts = keys
+                .iter()
+                .map(|sort_key| sort_key.ciphertext.on_cpu().into_owned())
+                .collect::<Vec<_>>();
+            let mut value_cts = values
+                .iter()
+                .map(|value| value.ciphertext.on_cpu().into_owned())
+                .collect::<Vec<_>>();
+            cpu_key
+                .pbs_key()
+                .sort_by_key_parallelized(&mut key_cts, &mut value_cts);
+            for (sort_key, ct) in keys.iter_mut().zip(key_cts) {
+                *sort_key = FheUint::new(ct, cpu_key.tag.clone());
+            }
+            for (value, ct) in values.iter_mut().zip(value_cts) {
+                *value = FheUint::new(ct, cpu_key.tag.clone());
+            }
+        }
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(_) => {
+            panic!("Cuda devices do not support fhe_sort_by_key yet");
+        }
+    })
+}
+
+/// Returns the number of bits that differ between two encrypted values
+///
+/// This is the same as [FheUint::hamming_distance], the xor of the values and the count of its
+/// ones are fused so that each pair of blocks only needs one PBS.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::algorithms::fhe_hamming_distance;
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let a = FheUint8::encrypt(0b1010_1010u8, &client_key);
+/// let b = FheUint8::encrypt(0b0110_1011u8, &client_key);
+///
+/// let distance = fhe_hamming_distance(&a, &b);
+/// let decrypted: u32 = distance.decrypt(&client_key);
+/// assert_eq!(decrypted, 3);
+/// ```
+pub fn fhe_hamming_distance<Id>(lhs: &FheUint<Id>, rhs: &FheUint<Id>) -> FheUint32
+where
+    Id: FheUintId,
+{
+    lhs.hamming_distance(rhs)
+}
+
+/// Returns the sum of the encrypted values
+///
+/// The values are added using a tree of additions in which the carries are only propagated once
+/// at the end, this is the same as summing an iterator of references to the values.
+///
+/// The sum wraps around if it does not fit in the type, the sum of an empty slice is zero.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::algorithms::fhe_sum;
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let values = [513u16, 2, 40000, 77].map(|v| FheUint16::encrypt(v, &client_key));
+///
+/// let sum = fhe_sum(&values);
+/// let decrypted: u16 = sum.decrypt(&client_key);
+/// assert_eq!(decrypted, 513 + 2 + 40000 + 77);
+/// ```
+pub fn fhe_sum<Id>(values: &[FheUint<Id>]) -> FheUint<Id>
+where
+    Id: FheUintId,
+{
+    values.iter().sum()
+}
+
+/// Returns an encryption of `true` if all the encrypted booleans are `true`
+///
+/// Instead of a sequential fold of `&` operations, the booleans are combined by a tree whose
+/// nodes reduce as many booleans as a block can hold with a single PBS, each level of the tree
+/// being computed in parallel. The depth of the computation is thus logarithmic.
+///
+/// The result for an empty slice is `true`.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::algorithms::{fhe_all, fhe_any};
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let values = [true, false, true].map(|v| FheBool::encrypt(v, &client_key));
+///
+/// let all = fhe_all(&values);
+/// let any = fhe_any(&values);
+/// assert!(!all.decrypt(&client_key));
+/// assert!(any.decrypt(&client_key));
+/// ```
+pub fn fhe_all(values: &[FheBool]) -> FheBool {
+    let _permit = match values.first() {
+        Some(first) => first.enforce_operation_policy(OperationKind::BitAnd),
+        None => global_state::permit_unrestricted_operation(),
+    };
+    global_state::with_internal_keys(|key| match key {
+        InternalServerKey::Cpu(cpu_key) => {
+            let blocks = values
+                .iter()
+                .map(|value| value.ciphertext.on_cpu().into_owned())
+                .collect::<Vec<_>>();
+            FheBool::new(
+                cpu_key.pbs_key().all_true_parallelized(&blocks),
+                cpu_key.tag.clone(),
+            )
+        }
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(_) => {
+            panic!("Cuda devices do not support fhe_all yet");
+        }
+    })
+}
+
+/// Returns an encryption of `true` if at least one of the encrypted booleans is `true`
+///
+/// See [fhe_all] for the algorithm.
+///
+/// The result for an empty slice is `false`.
+pub fn fhe_any(values: &[FheBool]) -> FheBool {
+    let _permit = match values.first() {
+        Some(first) => first.enforce_operation_policy(OperationKind::BitOr),
+        None => global_state::permit_unrestricted_operation(),
+    };
+    global_state::with_internal_keys(|key| match key {
+        InternalServerKey::Cpu(cpu_key) => {
+            let blocks = values
+                .iter()
+                .map(|value| value.ciphertext.on_cpu().into_owned())
+                .collect::<Vec<_>>();
+            FheBool::new(
+                cpu_key.pbs_key().any_true_parallelized(&blocks),
+                cpu_key.tag.clone(),
+            )
+        }
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(_) => {
+            panic!("Cuda devices do not support fhe_any yet");
+        }
+    })
+}
+
+/// Returns the number of encrypted booleans that are `true`
+///
+/// The booleans are summed together, the carries being only propagated once. The count wraps
+/// around if it does not fit in the output type.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::algorithms::fhe_count_true;
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool, FheUint8};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let values = [true, false, true, true].map(|v| FheBool::encrypt(v, &client_key));
+///
+/// let count: FheUint8 = fhe_count_true(&values);
+/// let decrypted: u8 = count.decrypt(&client_key);
+/// assert_eq!(decrypted, 3);
+/// ```
+pub fn fhe_count_true<Id>(values: &[FheBool]) -> FheUint<Id>
+where
+    Id: FheUintId,
+{
+    // The booleans are summed in the output type
+    let _permit = match values.first() {
+        Some(first) => global_state::enforce_operation_policy(
+            OperationKind::Add,
+            FheUint::<Id>::num_bits(),
+            &first.tag,
+        ),
+        None => global_state::permit_unrestricted_operation(),
+    };
+    global_state::with_internal_keys(|key| match key {
+        InternalServerKey::Cpu(cpu_key) => {
+            let blocks = values
+                .iter()
+                .map(|value| value.ciphertext.on_cpu().into_owned())
+                .collect::<Vec<_>>();
+            let num_blocks = Id::num_blocks(cpu_key.message_modulus());
+            FheUint::new(
+                cpu_key
+                    .pbs_key()
+                    .count_true_parallelized(&blocks, num_blocks),
+                cpu_key.tag.clone(),
+            )
+        }
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(_) => {
+            panic!("Cuda devices do not support fhe_count_true yet");
+        }
+    })
+}
+
+/// Returns the minimum of the encrypted values, or None if there are none
+///
+/// The minimum is computed by a balanced tree of `min` operations, the operations of each level
+/// of the tree are computed in parallel.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::algorithms::{fhe_max, fhe_min};
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let values = [513u16, 2, 40000, 77].map(|v| FheUint16::encrypt(v, &client_key));
+///
+/// let min = fhe_min(&values).unwrap();
+/// let max = fhe_max(&values).unwrap();
+/// let decrypted_min: u16 = min.decrypt(&client_key);
+/// let decrypted_max: u16 = max.decrypt(&client_key);
+/// assert_eq!(decrypted_min, 2);
+/// assert_eq!(decrypted_max, 40000);
+/// ```
+pub fn fhe_min<Id>(values: &[FheUint<Id>]) -> Option<FheUint<Id>>
+where
+    Id: FheUintId,
+{
+    let _permit = match values.first() {
+        Some(first) => first.enforce_operation_policy(OperationKind::Min),
+        None => global_state::permit_unrestricted_operation(),
+    };
+    global_state::with_internal_keys(|key| match key {
+        InternalServerKey::Cpu(cpu_key) => {
+            let cts = values
+                .iter()
+                .map(|value| value.ciphertext.on_cpu().into_owned())
+                .collect::<Vec<_>>();
+            cpu_key
+                .pbs_key()
+                .min_of_slice_parallelized(&cts)
+                .map(|ct| FheUint::new(ct, cpu_key.tag.clone()))
+        }
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(_) => {
+            panic!("Cuda devices do not support fhe_min yet");
+        }
+    })
+}
+
+/// Returns the maximum of the encrypted values, or None if there are none
+///
+/// See [fhe_min]
+pub fn fhe_max<Id>(values: &[FheUint<Id>]) -> Option<FheUint<Id>>
+where
+    Id: FheUintId,
+{
+    let _permit = match values.first() {
+        Some(first) => first.enforce_operation_policy(OperationKind::Max),
+        None => global_state::permit_unrestricted_operation(),
+    };
+    global_state::with_internal_keys(|key| match key {
+        InternalServerKey::Cpu(cpu_key) => {
+            let cts = values
+                .iter()
+                .map(|value| value.ciphertext.on_cpu().into_owned())
+                .collect::<Vec<_>>();
+            cpu_key
+                .pbs_key()
+                .max_of_slice_parallelized(&cts)
+                .map(|ct| FheUint::new(ct, cpu_key.tag.clone()))
+        }
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(_) => {
+            panic!("Cuda devices do not support fhe_max yet");
+        }
+    })
+}
+
+/// Returns the dot product of two vectors of encrypted values, i.e. `sum(lhs[i] * rhs[i])`
+///
+/// The values are cast to the output type before being multiplied, so choosing an output type
+/// larger than the input one avoids the overflow of the products and of their sum, otherwise
+/// the result wraps around.
+///
+/// The partial products of all the pairs of values are computed in parallel, and accumulated
+/// together so that the carries are propagated only once instead of after each operation.
+///
+/// The dot product of empty vectors is zero.
+///
+/// # Panics
+///
+/// Panics if the vectors do not have the same length
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::algorithms::fhe_dot_product;
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint32};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let lhs = [1000u16, 2, 65535].map(|v| FheUint16::encrypt(v, &client_key));
+/// let rhs = [3000u16, 7, 2].map(|v| FheUint16::encrypt(v, &client_key));
+///
+/// let result: FheUint32 = fhe_dot_product(&lhs, &rhs);
+/// let decrypted: u32 = result.decrypt(&client_key);
+/// assert_eq!(decrypted, 1000 * 3000 + 2 * 7 + 65535 * 2);
+/// ```
+pub fn fhe_dot_product<Id, OutId>(lhs: &[FheUint<Id>], rhs: &[FheUint<Id>]) -> FheUint<OutId>
+where
+    Id: FheUintId,
+    OutId: FheUintId,
+{
+    assert_eq!(
+        lhs.len(),
+        rhs.len(),
+        "Both vectors must have the same length"
+    );
+
+    global_state::with_internal_keys(|key| match key {
+        InternalServerKey::Cpu(cpu_key) => {
+            let sks = cpu_key.pbs_key();
+            let num_blocks = OutId::num_blocks(cpu_key.message_modulus());
+            if lhs.is_empty() {
+                return FheUint::new(
+                    sks.create_trivial_zero_radix(num_blocks),
+                    cpu_key.tag.clone(),
+                );
+            }
+
+            let cast = |values: &[FheUint<Id>]| {
+                values
+                    .iter()
+                    .map(|value| {
+                        sks.cast_to_unsigned(value.ciphertext.on_cpu().into_owned(), num_blocks)
+                    })
+                    .collect::<Vec<_>>()
+            };
+            let (lhs, rhs) = (cast(lhs), cast(rhs));
+
+            FheUint::new(
+                sks.dot_product_parallelized(&lhs, &rhs),
+                cpu_key.tag.clone(),
+            )
+        }
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(_) => {
+            panic!("Cuda devices do not support fhe_dot_product yet");
+        }
+    })
+}
+
+/// Returns the dot product of a vector of encrypted values with a vector of clear values, i.e.
+/// `sum(lhs[i] * rhs[i])`
+///
+/// As for [fhe_dot_product], the values are cast to the output type before being multiplied,
+/// and the carries are propagated only once. Multiplying by a clear value only requires to
+/// shift and add the encrypted value, which is much cheaper than an encrypted multiplication.
+///
+/// The dot product of empty vectors is zero.
+///
+/// # Panics
+///
+/// Panics if the vectors do not have the same length
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::algorithms::fhe_scalar_dot_product;
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint32};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let inputs = [1000u16, 2, 65535].map(|v| FheUint16::encrypt(v, &client_key));
+/// let weights = [3000u16, 0, 2];
+///
+/// let result: FheUint32 = fhe_scalar_dot_product(&inputs, &weights);
+/// let decrypted: u32 = result.decrypt(&client_key);
+/// assert_eq!(decrypted, 1000 * 3000 + 65535 * 2);
+/// ```
+pub fn fhe_scalar_dot_product<Id, OutId, Clear>(
+    lhs: &[FheUint<Id>],
+    rhs: &[Clear],
+) -> FheUint<OutId>
+where
+    Id: FheUintId,
+    OutId: FheUintId,
+    Clear: DecomposableInto<u8> + Copy + Sync,
+{
+    assert_eq!(
+        lhs.len(),
+        rhs.len(),
+        "Both vectors must have the same length"
+    );
+
+    global_state::with_internal_keys(|key| match key {
+        InternalServerKey::Cpu(cpu_key) => {
+            let sks = cpu_key.pbs_key();
+            let num_blocks = OutId::num_blocks(cpu_key.message_modulus());
+            if lhs.is_empty() {
+                return FheUint::new(
+                    sks.create_trivial_zero_radix(num_blocks),
+                    cpu_key.tag.clone(),
+                );
+            }
+
+            let lhs = lhs
+                .iter()
+                .map(|value| {
+                    sks.cast_to_unsigned(value.ciphertext.on_cpu().into_owned(), num_blocks)
+                })
+                .collect::<Vec<_>>();
+
+            FheUint::new(
+                sks.scalar_dot_product_parallelized(&lhs, rhs),
+                cpu_key.tag.clone(),
+            )
+        }
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(_) => {
+            panic!("Cuda devices do not support fhe_scalar_dot_product yet");
+        }
+    })
+}
+
+/// Returns the 2D convolution of an encrypted image with a clear kernel
+///
+/// The image is given as rows of encrypted pixels, and is surrounded by `padding` rows and
+/// columns of zeros. The kernel slides over the padded image by steps of `stride` pixels and, as
+/// in deep learning frameworks, is not flipped. The result has
+/// `(height + 2 * padding - kernel_height) / stride + 1` rows of
+/// `(width + 2 * padding - kernel_width) / stride + 1` pixels, which wrap around if they do not
+/// fit in the type.
+///
+/// The image patches under the kernel are laid out as the rows of a clear matrix (im2col): the
+/// multiplications of a pixel by the kernel coefficients are shared by all the patches it belongs
+/// to, and the carries are only propagated once per output pixel. The output pixels are computed
+/// in parallel.
+///
+/// # Panics
+///
+/// - if the image is empty or its rows do not all have the same non zero length
+/// - if the kernel is empty or its rows do not all have the same non zero length
+/// - if `stride` is zero
+/// - if the kernel is larger than the padded image
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::algorithms::fhe_conv2d;
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let image = [[1i16, 2, 3], [4, 5, 6], [7, 8, 9]]
+///     .map(|row| row.map(|pixel| FheInt16::encrypt(pixel, &client_key)));
+/// // Vertical edge detection
+/// let kernel = [[1i16, 0, -1], [2, 0, -2], [1, 0, -1]];
+///
+/// let result = fhe_conv2d(&image, &kernel, 1, 1);
+///
+/// let decrypted = result
+///     .iter()
+///     .map(|row| {
+///         row.iter()
+///             .map(|pixel| pixel.decrypt(&client_key))
+///             .collect::<Vec<i16>>()
+///     })
+///     .collect::<Vec<_>>();
+/// assert_eq!(
+///     decrypted,
+///     vec![vec![-9, -6, 9], vec![-20, -8, 20], vec![-21, -6, 21]]
+/// );
+/// ```
+pub fn fhe_conv2d<Id, ImageRow, KernelRow, Clear>(
+    image: &[ImageRow],
+    kernel: &[KernelRow],
+    stride: usize,
+    padding: usize,
+) -> Vec<Vec<FheInt<Id>>>
+where
+    Id: FheIntId,
+    ImageRow: AsRef<[FheInt<Id>]>,
+    KernelRow: AsRef<[Clear]>,
+    Clear: Copy + Into<i64>,
+{
+    assert!(!image.is_empty(), "The image must not be empty");
+    let width = image[0].as_ref().len();
+    assert!(
+        width != 0 && image.iter().all(|row| row.as_ref().len() == width),
+        "The image rows must all have the same non zero length"
+    );
+
+    let kernel = kernel
+        .iter()
+        .map(|row| {
+            row.as_ref()
+                .iter()
+                .map(|&coefficient| coefficient.into())
+                .collect::<Vec<i64>>()
+        })
+        .collect::<Vec<_>>();
+
+    global_state::with_internal_keys(|key| match key {
+        InternalServerKey::Cpu(cpu_key) => {
+            let cts = image
+                .iter()
+                .flat_map(|row| row.as_ref())
+                .map(|pixel| pixel.ciphertext.on_cpu().into_owned())
+                .collect::<Vec<_>>();
+            let mut result = cpu_key
+                .pbs_key()
+                .clear_conv2d_parallelized(&cts, width, &kernel, stride, padding)
+                .into_iter()
+                .map(|ct| FheInt::new(ct, cpu_key.tag.clone()));
+
+            let output_height = (image.len() + 2 * padding - kernel.len()) / stride + 1;
+            let output_width = (width + 2 * padding - kernel[0].len()) / stride + 1;
+            (0..output_height)
+                .map(|_| result.by_ref().take(output_width).collect())
+                .collect()
+        }
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(_) => {
+            panic!("Cuda devices do not support fhe_conv2d yet");
+        }
+    })
+}
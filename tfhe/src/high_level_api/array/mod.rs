@@ -10,8 +10,9 @@ pub(in crate::high_level_api) mod traits;
 
 use crate::array::traits::TensorSlice;
 use crate::high_level_api::array::traits::HasClear;
-use crate::high_level_api::global_state::with_cpu_internal_keys;
+use crate::high_level_api::global_state::{permit_unrestricted_operation, with_cpu_internal_keys};
 use crate::high_level_api::integers::FheUintId;
+use crate::high_level_api::policy::OperationKind;
 use crate::{FheBool, FheId, FheUint};
 use std::ops::RangeBounds;
 use traits::{ArrayBackend, BackendDataContainer, BackendDataContainerMut};
@@ -320,6 +321,10 @@ declare_concrete_array_types!(
 );
 
 pub fn fhe_uint_array_eq<Id: FheUintId>(lhs: &[FheUint<Id>], rhs: &[FheUint<Id>]) -> FheBool {
+    let _permit = match lhs.first() {
+        Some(first) => first.enforce_operation_policy(OperationKind::Eq),
+        None => permit_unrestricted_operation(),
+    };
     with_cpu_internal_keys(|cpu_keys| {
         let tmp_lhs = lhs
             .iter()
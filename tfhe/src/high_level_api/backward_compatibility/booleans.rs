@@ -4,7 +4,8 @@ use serde::{Deserialize, Serialize};
 use tfhe_versionable::{Upgrade, Version, Versionize, VersionsDispatch};
 
 use crate::high_level_api::booleans::{
-    InnerBoolean, InnerBooleanVersionOwned, InnerCompressedFheBool,
+    FheBoolExprGraph, FheBoolExprNode, InnerBoolean, InnerBooleanVersionOwned,
+    InnerCompressedFheBool,
 };
 use crate::integer::ciphertext::{CompactCiphertextList, DataKind};
 use crate::prelude::CiphertextList;
@@ -160,3 +161,13 @@ impl CompactFheBoolList {
             .collect::<Result<Vec<_>, _>>()
     }
 }
+
+#[derive(VersionsDispatch)]
+pub enum FheBoolExprGraphVersions {
+    V0(FheBoolExprGraph),
+}
+
+#[derive(VersionsDispatch)]
+pub enum FheBoolExprNodeVersions {
+    V0(FheBoolExprNode),
+}
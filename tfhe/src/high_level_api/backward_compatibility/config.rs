@@ -1,8 +1,26 @@
-use tfhe_versionable::VersionsDispatch;
-
+use crate::high_level_api::keys::IntegerConfig;
 use crate::Config;
+use std::convert::Infallible;
+use tfhe_versionable::{Upgrade, Version, VersionsDispatch};
+
+#[derive(Version)]
+pub struct ConfigV0 {
+    pub(crate) inner: IntegerConfig,
+}
+
+impl Upgrade<Config> for ConfigV0 {
+    type Error = Infallible;
+
+    fn upgrade(self) -> Result<Config, Self::Error> {
+        Ok(Config {
+            inner: self.inner,
+            implicit_widening: false,
+        })
+    }
+}
 
 #[derive(VersionsDispatch)]
 pub enum ConfigVersions {
-    V0(Config),
+    V0(ConfigV0),
+    V1(Config),
 }
@@ -5,7 +5,7 @@ use std::convert::Infallible;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use tfhe_versionable::{Upgrade, Version, Versionize, VersionsDispatch};
 
-use crate::high_level_api::global_state::with_cpu_internal_keys;
+use crate::high_level_api::global_state::{permit_unrestricted_operation, with_cpu_internal_keys};
 use crate::high_level_api::integers::*;
 use crate::integer::backward_compatibility::ciphertext::{
     CompressedModulusSwitchedRadixCiphertextTFHE06,
@@ -62,6 +62,7 @@ impl Upgrade<CompressedSignedRadixCiphertext> for CompressedSignedRadixCiphertex
     type Error = Infallible;
 
     fn upgrade(self) -> Result<CompressedSignedRadixCiphertext, Self::Error> {
+        let _permit = permit_unrestricted_operation();
         match self {
             Self::Seeded(ct) => Ok(CompressedSignedRadixCiphertext::Seeded(ct)),
 
@@ -100,6 +101,7 @@ impl Upgrade<CompressedRadixCiphertext> for CompressedRadixCiphertextV0 {
     type Error = Infallible;
 
     fn upgrade(self) -> Result<CompressedRadixCiphertext, Self::Error> {
+        let _permit = permit_unrestricted_operation();
         match self {
             Self::Seeded(ct) => Ok(CompressedRadixCiphertext::Seeded(ct)),
 
@@ -241,6 +243,11 @@ pub enum CompressedFheUintVersions<Id: FheUintId> {
     V1(CompressedFheUint<Id>),
 }
 
+#[derive(VersionsDispatch)]
+pub enum FheAccumulatorVersions<T> {
+    V0(FheAccumulator<T>),
+}
+
 #[derive(VersionsDispatch)]
 pub enum CompactFheUintListVersions<Id: FheUintId> {
     V0(CompactFheUintList<Id>),
@@ -8,7 +8,8 @@ use tfhe_versionable::{Upgrade, Version, VersionsDispatch};
 #[derive(VersionsDispatch)]
 pub enum ClientKeyVersions {
     V0(ClientKeyV0),
-    V1(ClientKey),
+    V1(ClientKeyV1),
+    V2(ClientKey),
 }
 
 #[derive(Version)]
@@ -16,18 +17,47 @@ pub struct ClientKeyV0 {
     pub(crate) key: IntegerClientKey,
 }
 
-impl Upgrade<ClientKey> for ClientKeyV0 {
+impl Upgrade<ClientKeyV1> for ClientKeyV0 {
     type Error = Infallible;
 
-    fn upgrade(self) -> Result<ClientKey, Self::Error> {
+    fn upgrade(self) -> Result<ClientKeyV1, Self::Error> {
         let Self { key } = self;
-        Ok(ClientKey {
+        Ok(ClientKeyV1 {
             key,
             tag: Tag::default(),
         })
     }
 }
 
+#[derive(Version)]
+pub struct ClientKeyV1 {
+    pub(crate) key: IntegerClientKey,
+    pub(crate) tag: Tag,
+}
+
+impl Upgrade<ClientKey> for ClientKeyV1 {
+    type Error = Infallible;
+
+    fn upgrade(self) -> Result<ClientKey, Self::Error> {
+        let Self { key, tag } = self;
+        Ok(ClientKey {
+            key,
+            tag,
+            implicit_widening: false,
+        })
+    }
+}
+
+#[derive(VersionsDispatch)]
+pub enum DecryptionKeyVersions {
+    V0(DecryptionKey),
+}
+
+#[derive(VersionsDispatch)]
+pub enum EncryptionKeyVersions {
+    V0(EncryptionKey),
+}
+
 // This type was previously versioned using a manual implementation with a conversion
 // to a type where the inner key was name `integer_key`
 #[derive(Version)]
@@ -50,13 +80,51 @@ pub struct ServerKeyV1 {
     pub(crate) key: Arc<IntegerServerKey>,
 }
 
-impl Upgrade<ServerKey> for ServerKeyV1 {
+impl Upgrade<ServerKeyV2> for ServerKeyV1 {
+    type Error = Infallible;
+
+    fn upgrade(self) -> Result<ServerKeyV2, Self::Error> {
+        Ok(ServerKeyV2 {
+            key: self.key,
+            tag: Tag::default(),
+        })
+    }
+}
+
+#[derive(Version)]
+pub struct ServerKeyV2 {
+    pub(crate) key: Arc<IntegerServerKey>,
+    pub(crate) tag: Tag,
+}
+
+impl Upgrade<ServerKeyV3> for ServerKeyV2 {
+    type Error = Infallible;
+
+    fn upgrade(self) -> Result<ServerKeyV3, Self::Error> {
+        Ok(ServerKeyV3 {
+            key: self.key,
+            tag: self.tag,
+            implicit_widening: false,
+        })
+    }
+}
+
+#[derive(Version)]
+pub struct ServerKeyV3 {
+    pub(crate) key: Arc<IntegerServerKey>,
+    pub(crate) tag: Tag,
+    pub(crate) implicit_widening: bool,
+}
+
+impl Upgrade<ServerKey> for ServerKeyV3 {
     type Error = Infallible;
 
     fn upgrade(self) -> Result<ServerKey, Self::Error> {
         Ok(ServerKey {
             key: self.key,
-            tag: Tag::default(),
+            tag: self.tag,
+            implicit_widening: self.implicit_widening,
+            policy: None,
         })
     }
 }
@@ -65,7 +133,9 @@ impl Upgrade<ServerKey> for ServerKeyV1 {
 pub enum ServerKeyVersions {
     V0(ServerKeyV0),
     V1(ServerKeyV1),
-    V2(ServerKey),
+    V2(ServerKeyV2),
+    V3(ServerKeyV3),
+    V4(ServerKey),
 }
 
 #[derive(Version)]
@@ -73,13 +143,51 @@ pub struct CompressedServerKeyV0 {
     pub(crate) integer_key: IntegerCompressedServerKey,
 }
 
-impl Upgrade<CompressedServerKey> for CompressedServerKeyV0 {
+impl Upgrade<CompressedServerKeyV1> for CompressedServerKeyV0 {
+    type Error = Infallible;
+
+    fn upgrade(self) -> Result<CompressedServerKeyV1, Self::Error> {
+        Ok(CompressedServerKeyV1 {
+            integer_key: self.integer_key,
+            tag: Tag::default(),
+        })
+    }
+}
+
+#[derive(Version)]
+pub struct CompressedServerKeyV1 {
+    pub(crate) integer_key: IntegerCompressedServerKey,
+    pub(crate) tag: Tag,
+}
+
+impl Upgrade<CompressedServerKeyV2> for CompressedServerKeyV1 {
+    type Error = Infallible;
+
+    fn upgrade(self) -> Result<CompressedServerKeyV2, Self::Error> {
+        Ok(CompressedServerKeyV2 {
+            integer_key: self.integer_key,
+            tag: self.tag,
+            implicit_widening: false,
+        })
+    }
+}
+
+#[derive(Version)]
+pub struct CompressedServerKeyV2 {
+    pub(crate) integer_key: IntegerCompressedServerKey,
+    pub(crate) tag: Tag,
+    pub(crate) implicit_widening: bool,
+}
+
+impl Upgrade<CompressedServerKey> for CompressedServerKeyV2 {
     type Error = Infallible;
 
     fn upgrade(self) -> Result<CompressedServerKey, Self::Error> {
         Ok(CompressedServerKey {
             integer_key: self.integer_key,
-            tag: Tag::default(),
+            tag: self.tag,
+            implicit_widening: self.implicit_widening,
+            policy: None,
         })
     }
 }
@@ -87,7 +195,9 @@ impl Upgrade<CompressedServerKey> for CompressedServerKeyV0 {
 #[derive(VersionsDispatch)]
 pub enum CompressedServerKeyVersions {
     V0(CompressedServerKeyV0),
-    V1(CompressedServerKey),
+    V1(CompressedServerKeyV1),
+    V2(CompressedServerKeyV2),
+    V3(CompressedServerKey),
 }
 
 #[derive(Version)]
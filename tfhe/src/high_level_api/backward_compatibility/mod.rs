@@ -6,4 +6,5 @@ pub mod compressed_ciphertext_list;
 pub mod config;
 pub mod integers;
 pub mod keys;
+pub mod policy;
 pub mod tag;
@@ -0,0 +1,12 @@
+use crate::high_level_api::policy::{OperationKind, OperationPolicy};
+use tfhe_versionable::VersionsDispatch;
+
+#[derive(VersionsDispatch)]
+pub enum OperationKindVersions {
+    V0(OperationKind),
+}
+
+#[derive(VersionsDispatch)]
+pub enum OperationPolicyVersions {
+    V0(OperationPolicy),
+}
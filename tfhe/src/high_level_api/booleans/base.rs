@@ -6,7 +6,9 @@ use crate::high_level_api::global_state;
 use crate::high_level_api::global_state::with_thread_local_cuda_streams;
 use crate::high_level_api::integers::{FheInt, FheIntId, FheUint, FheUintId};
 use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::policy::{OperationKind, OperationPermit};
 use crate::high_level_api::traits::{FheEq, IfThenElse, Tagged};
+use crate::high_level_api::utils::block_bytes;
 #[cfg(feature = "gpu")]
 use crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
 #[cfg(feature = "gpu")]
@@ -14,6 +16,7 @@ use crate::integer::gpu::ciphertext::CudaIntegerRadixCiphertext;
 use crate::integer::prelude::*;
 use crate::integer::BooleanBlock;
 use crate::named::Named;
+use crate::safe_serialization::{SerializedSizeBound, OBJECT_METADATA_SIZE_BOUND};
 use crate::shortint::ciphertext::NotTrivialCiphertextError;
 use crate::shortint::parameters::CiphertextConformanceParams;
 use crate::shortint::PBSParameters;
@@ -58,6 +61,7 @@ impl Named for FheBool {
 }
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
 pub struct FheBoolConformanceParams(pub(crate) CiphertextConformanceParams);
 
 impl<P> From<P> for FheBoolConformanceParams
@@ -91,6 +95,12 @@ impl ParameterSetConformant for FheBool {
     }
 }
 
+impl SerializedSizeBound for FheBool {
+    fn serialized_size_bound(params: &FheBoolConformanceParams) -> u64 {
+        crate::shortint::Ciphertext::serialized_size_bound(&params.0) + OBJECT_METADATA_SIZE_BOUND
+    }
+}
+
 impl FheBool {
     pub(in crate::high_level_api) fn new<T: Into<InnerBoolean>>(ciphertext: T, tag: Tag) -> Self {
         Self {
@@ -107,6 +117,15 @@ impl FheBool {
         1
     }
 
+    /// Checks that the [OperationPolicy](crate::OperationPolicy) of the server key, if any,
+    /// permits the operation on this [FheBool]
+    pub(in crate::high_level_api) fn enforce_operation_policy(
+        &self,
+        kind: OperationKind,
+    ) -> OperationPermit {
+        global_state::enforce_operation_policy(kind, Self::num_bits(), &self.tag)
+    }
+
     /// Moves (in-place) the ciphertext to the desired device.
     ///
     /// Does nothing if the ciphertext is already in the desired device
@@ -178,6 +197,35 @@ impl FheBool {
     pub fn is_trivial(&self) -> bool {
         self.ciphertext.on_cpu().is_trivial()
     }
+
+    /// Returns the bytes of each block of the ciphertext
+    ///
+    /// The bytes are the ones of the LWE ciphertexts of the blocks, with their coefficients in
+    /// little endian, so they do not depend on the platform nor on the serialization format.
+    /// Identical ciphertexts (e.g. the same trivial constant) have the same bytes, which
+    /// lets storage layers hash and deduplicate ciphertexts without serializing them.
+    ///
+    /// The metadata of the blocks (degree, noise level) are not included.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheBool::encrypt_trivial(true);
+    /// let b = FheBool::encrypt_trivial(true);
+    /// let c = FheBool::encrypt(true, &client_key);
+    ///
+    /// assert_eq!(a.as_block_bytes(), b.as_block_bytes());
+    /// assert_ne!(a.as_block_bytes(), c.as_block_bytes());
+    /// ```
+    pub fn as_block_bytes(&self) -> Vec<Vec<u8>> {
+        block_bytes(std::slice::from_ref(&self.ciphertext.on_cpu().0))
+    }
 }
 
 impl<Id> IfThenElse<FheUint<Id>> for FheBool
@@ -191,6 +239,11 @@ where
     /// - if `self` is true, the output will have the value of `ct_then`
     /// - if `self` is false, the output will have the value of `ct_else`
     fn if_then_else(&self, ct_then: &FheUint<Id>, ct_else: &FheUint<Id>) -> FheUint<Id> {
+        let _permit = global_state::enforce_operation_policy(
+            OperationKind::IfThenElse,
+            FheUint::<Id>::num_bits(),
+            &self.tag,
+        );
         let ct_condition = self;
         global_state::with_internal_keys(|sks| match sks {
             InternalServerKey::Cpu(cpu_sks) => {
@@ -214,6 +267,74 @@ where
             }),
         })
     }
+
+    /// Element-wise conditional selection between two slices.
+    ///
+    /// The work depending only on the condition is shared by all the elements, which is
+    /// faster than calling [Self::if_then_else] on each pair.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool, FheUint32};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let condition = FheBool::encrypt(true, &client_key);
+    /// let a = [1u32, 2, 3].map(|v| FheUint32::encrypt(v, &client_key));
+    /// let b = [4u32, 5, 6].map(|v| FheUint32::encrypt(v, &client_key));
+    ///
+    /// let result = condition.select_slice(&a, &b);
+    /// let decrypted = result
+    ///     .iter()
+    ///     .map(|ct| ct.decrypt(&client_key))
+    ///     .collect::<Vec<u32>>();
+    /// assert_eq!(decrypted, vec![1, 2, 3]);
+    /// ```
+    fn if_then_else_slice(
+        &self,
+        ct_then: &[FheUint<Id>],
+        ct_else: &[FheUint<Id>],
+    ) -> Vec<FheUint<Id>> {
+        let _permit = global_state::enforce_operation_policy(
+            OperationKind::IfThenElse,
+            FheUint::<Id>::num_bits(),
+            &self.tag,
+        );
+        global_state::with_internal_keys(|sks| match sks {
+            InternalServerKey::Cpu(cpu_sks) => {
+                let ct_then = ct_then
+                    .iter()
+                    .map(|ct| ct.ciphertext.on_cpu().into_owned())
+                    .collect::<Vec<_>>();
+                let ct_else = ct_else
+                    .iter()
+                    .map(|ct| ct.ciphertext.on_cpu().into_owned())
+                    .collect::<Vec<_>>();
+                cpu_sks
+                    .pbs_key()
+                    .if_then_else_slice_parallelized(&self.ciphertext.on_cpu(), &ct_then, &ct_else)
+                    .into_iter()
+                    .map(|ct| FheUint::new(ct, cpu_sks.tag.clone()))
+                    .collect()
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                assert_eq!(
+                    ct_then.len(),
+                    ct_else.len(),
+                    "Both slices must have the same length"
+                );
+                ct_then
+                    .iter()
+                    .zip(ct_else.iter())
+                    .map(|(ct_then, ct_else)| self.if_then_else(ct_then, ct_else))
+                    .collect()
+            }
+        })
+    }
 }
 
 impl Tagged for FheBool {
@@ -234,6 +355,11 @@ impl<Id: FheIntId> IfThenElse<FheInt<Id>> for FheBool {
     /// - if `self` is true, the output will have the value of `ct_then`
     /// - if `self` is false, the output will have the value of `ct_else`
     fn if_then_else(&self, ct_then: &FheInt<Id>, ct_else: &FheInt<Id>) -> FheInt<Id> {
+        let _permit = global_state::enforce_operation_policy(
+            OperationKind::IfThenElse,
+            FheInt::<Id>::num_bits(),
+            &self.tag,
+        );
         let ct_condition = self;
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(key) => {
@@ -250,10 +376,48 @@ impl<Id: FheIntId> IfThenElse<FheInt<Id>> for FheBool {
             }
         })
     }
+
+    /// Element-wise conditional selection between two slices.
+    ///
+    /// The work depending only on the condition is shared by all the elements, which is
+    /// faster than calling [Self::if_then_else] on each pair.
+    fn if_then_else_slice(
+        &self,
+        ct_then: &[FheInt<Id>],
+        ct_else: &[FheInt<Id>],
+    ) -> Vec<FheInt<Id>> {
+        let _permit = global_state::enforce_operation_policy(
+            OperationKind::IfThenElse,
+            FheInt::<Id>::num_bits(),
+            &self.tag,
+        );
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(key) => {
+                let ct_then = ct_then
+                    .iter()
+                    .map(|ct| ct.ciphertext.on_cpu().into_owned())
+                    .collect::<Vec<_>>();
+                let ct_else = ct_else
+                    .iter()
+                    .map(|ct| ct.ciphertext.on_cpu().into_owned())
+                    .collect::<Vec<_>>();
+                key.pbs_key()
+                    .if_then_else_slice_parallelized(&self.ciphertext.on_cpu(), &ct_then, &ct_else)
+                    .into_iter()
+                    .map(|ct| FheInt::new(ct, key.tag.clone()))
+                    .collect()
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support signed integers")
+            }
+        })
+    }
 }
 
 impl IfThenElse<Self> for FheBool {
     fn if_then_else(&self, ct_then: &Self, ct_else: &Self) -> Self {
+        let _permit = self.enforce_operation_policy(OperationKind::IfThenElse);
         let ct_condition = self;
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(key) => {
@@ -296,6 +460,7 @@ where
     /// assert!(!decrypted);
     /// ```
     fn eq(&self, other: B) -> Self {
+        let _permit = self.enforce_operation_policy(OperationKind::Eq);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(key) => {
                 let inner = key.pbs_key().key.equal(
@@ -338,6 +503,7 @@ where
     /// assert_eq!(decrypted, true != false);
     /// ```
     fn ne(&self, other: B) -> Self {
+        let _permit = self.enforce_operation_policy(OperationKind::Ne);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(key) => {
                 let inner = key.pbs_key().key.not_equal(
@@ -381,6 +547,7 @@ impl FheEq<bool> for FheBool {
     /// assert!(!decrypted);
     /// ```
     fn eq(&self, other: bool) -> FheBool {
+        let _permit = self.enforce_operation_policy(OperationKind::Eq);
         let (ciphertext, tag) = global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(key) => {
                 let inner = key
@@ -424,6 +591,7 @@ impl FheEq<bool> for FheBool {
     /// assert_eq!(decrypted, true != false);
     /// ```
     fn ne(&self, other: bool) -> FheBool {
+        let _permit = self.enforce_operation_policy(OperationKind::Ne);
         let (ciphertext, tag) = global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(key) => {
                 let inner = key
@@ -502,6 +670,7 @@ where
     /// assert!(result);
     /// ```
     fn bitand(self, rhs: B) -> Self::Output {
+        let _permit = self.enforce_operation_policy(OperationKind::BitAnd);
         let (ciphertext, tag) = global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(key) => {
                 let inner_ct = key
@@ -583,6 +752,7 @@ where
     /// assert_eq!(result, true | false);
     /// ```
     fn bitor(self, rhs: B) -> Self::Output {
+        let _permit = self.enforce_operation_policy(OperationKind::BitOr);
         let (ciphertext, tag) = global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(key) => {
                 let inner_ct = key.pbs_key().key.bitor(
@@ -667,6 +837,7 @@ where
     /// assert!(!result);
     /// ```
     fn bitxor(self, rhs: B) -> Self::Output {
+        let _permit = self.enforce_operation_policy(OperationKind::BitXor);
         let (ciphertext, tag) = global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(key) => {
                 let inner_ct = key.pbs_key().key.bitxor(
@@ -743,6 +914,7 @@ impl BitAnd<bool> for &FheBool {
     /// assert_eq!(decrypted, true & false);
     /// ```
     fn bitand(self, rhs: bool) -> Self::Output {
+        let _permit = self.enforce_operation_policy(OperationKind::BitAnd);
         let (ciphertext, tag) = global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(key) => {
                 let inner_ct = key
@@ -819,6 +991,7 @@ impl BitOr<bool> for &FheBool {
     /// assert_eq!(decrypted, true | false);
     /// ```
     fn bitor(self, rhs: bool) -> Self::Output {
+        let _permit = self.enforce_operation_policy(OperationKind::BitOr);
         let (ciphertext, tag) = global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(key) => {
                 let inner_ct = key
@@ -895,6 +1068,7 @@ impl BitXor<bool> for &FheBool {
     /// assert_eq!(decrypted, true ^ false);
     /// ```
     fn bitxor(self, rhs: bool) -> Self::Output {
+        let _permit = self.enforce_operation_policy(OperationKind::BitXor);
         let (ciphertext, tag) = global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(key) => {
                 let inner_ct = key
@@ -1102,6 +1276,7 @@ where
     /// assert!(result);
     /// ```
     fn bitand_assign(&mut self, rhs: B) {
+        let _permit = self.enforce_operation_policy(OperationKind::BitAnd);
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(key) => {
@@ -1145,6 +1320,7 @@ where
     /// assert!(result);
     /// ```
     fn bitor_assign(&mut self, rhs: B) {
+        let _permit = self.enforce_operation_policy(OperationKind::BitOr);
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(key) => {
@@ -1188,6 +1364,7 @@ where
     /// assert!(!result);
     /// ```
     fn bitxor_assign(&mut self, rhs: B) {
+        let _permit = self.enforce_operation_policy(OperationKind::BitXor);
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(key) => {
@@ -1227,6 +1404,7 @@ impl BitAndAssign<bool> for FheBool {
     /// assert_eq!(result, true & false);
     /// ```
     fn bitand_assign(&mut self, rhs: bool) {
+        let _permit = self.enforce_operation_policy(OperationKind::BitAnd);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(key) => {
                 key.pbs_key()
@@ -1264,6 +1442,7 @@ impl BitOrAssign<bool> for FheBool {
     /// assert_eq!(result, true | false);
     /// ```
     fn bitor_assign(&mut self, rhs: bool) {
+        let _permit = self.enforce_operation_policy(OperationKind::BitOr);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(key) => {
                 key.pbs_key()
@@ -1301,6 +1480,7 @@ impl BitXorAssign<bool> for FheBool {
     /// assert_eq!(result, true ^ false);
     /// ```
     fn bitxor_assign(&mut self, rhs: bool) {
+        let _permit = self.enforce_operation_policy(OperationKind::BitXor);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(key) => {
                 key.pbs_key()
@@ -1365,6 +1545,7 @@ impl std::ops::Not for &FheBool {
     /// assert!(!result);
     /// ```
     fn not(self) -> Self::Output {
+        let _permit = self.enforce_operation_policy(OperationKind::Not);
         let (ciphertext, tag) = global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(key) => {
                 let inner = key.pbs_key().boolean_bitnot(&self.ciphertext.on_cpu());
@@ -2,7 +2,7 @@ use crate::backward_compatibility::booleans::{
     CompressedFheBoolVersions, InnerCompressedFheBoolVersions,
 };
 use crate::conformance::ParameterSetConformant;
-use crate::high_level_api::global_state::with_cpu_internal_keys;
+use crate::high_level_api::global_state::{permit_unrestricted_operation, with_cpu_internal_keys};
 use crate::high_level_api::traits::Tagged;
 use crate::integer::BooleanBlock;
 use crate::named::Named;
@@ -70,6 +70,7 @@ impl CompressedFheBool {
     ///
     /// See [CompressedFheBool] example.
     pub fn decompress(&self) -> FheBool {
+        let _permit = permit_unrestricted_operation();
         let ciphertext = BooleanBlock::new_unchecked(match &self.inner {
             InnerCompressedFheBool::Seeded(seeded) => seeded.decompress(),
             InnerCompressedFheBool::ModulusSwitched(modulus_switched) => {
@@ -112,6 +113,7 @@ impl Named for CompressedFheBool {
 
 impl FheBool {
     pub fn compress(&self) -> CompressedFheBool {
+        let _permit = permit_unrestricted_operation();
         with_cpu_internal_keys(|sk| {
             let inner = InnerCompressedFheBool::ModulusSwitched(
                 sk.pbs_key()
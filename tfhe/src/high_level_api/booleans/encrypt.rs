@@ -8,8 +8,10 @@ use crate::high_level_api::keys::InternalServerKey;
 use crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
 #[cfg(feature = "gpu")]
 use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaUnsignedRadixCiphertext};
-use crate::prelude::{FheDecrypt, FheTrivialEncrypt, FheTryEncrypt, FheTryTrivialEncrypt};
-use crate::{ClientKey, CompressedPublicKey, PublicKey};
+use crate::prelude::{
+    FheDecrypt, FheDecryptWith, FheTrivialEncrypt, FheTryEncrypt, FheTryTrivialEncrypt,
+};
+use crate::{ClientKey, CompressedPublicKey, DecryptionKey, PublicKey};
 
 impl FheTryEncrypt<bool, ClientKey> for FheBool {
     type Error = crate::Error;
@@ -80,10 +82,17 @@ impl FheDecrypt<bool> for FheBool {
     }
 }
 
+impl FheDecryptWith<bool, DecryptionKey> for FheBool {
+    fn decrypt_with(&self, key: &DecryptionKey) -> bool {
+        key.key.decrypt_bool(&self.ciphertext.on_cpu())
+    }
+}
+
 impl FheTryTrivialEncrypt<bool> for FheBool {
     type Error = crate::Error;
 
     fn try_encrypt_trivial(value: bool) -> Result<Self, Self::Error> {
+        let _permit = global_state::permit_unrestricted_operation();
         let (ciphertext, tag) = global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(key) => {
                 let ct = InnerBoolean::Cpu(key.pbs_key().create_trivial_boolean_block(value));
@@ -1,13 +1,16 @@
 pub use base::{FheBool, FheBoolConformanceParams};
 pub use compressed::CompressedFheBool;
+pub use predicate::FheBoolExpr;
 
 pub(in crate::high_level_api) use compressed::InnerCompressedFheBool;
 pub(in crate::high_level_api) use inner::{InnerBoolean, InnerBooleanVersionOwned};
+pub(in crate::high_level_api) use predicate::{FheBoolExprGraph, FheBoolExprNode};
 
 mod base;
 mod compressed;
 mod encrypt;
 mod inner;
 mod oprf;
+mod predicate;
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,547 @@
+//! Lazy boolean expressions over [FheBool] values.
+//!
+//! Homomorphic computations cannot short-circuit: `a & (b | c)` always computes both sides,
+//! and evaluating a large condition left to right costs one bootstrap per binary operation.
+//! [FheBoolExpr] instead collects the whole expression and evaluates it at once, which allows to:
+//!
+//! - fold constants and trivial cases (`x & false`, `x | !x`, duplicated operands, ...)
+//! - evaluate each shared subexpression only once
+//! - push negations to the operands, as negating an encrypted boolean is free
+//! - reduce the operands of n-ary `and`/`or` by summing as many of them as the parameters allow
+//!   before applying a single bootstrap, instead of one bootstrap per operand
+//! - evaluate all the independent subexpressions of the same depth in parallel
+//!
+//! The evaluation is still oblivious: all the operands are always computed.
+//!
+//! An expression that is not evaluated yet can be serialized, together with its input
+//! ciphertexts, and evaluated on another machine that has the same server key. This allows to
+//! move pending computations between the workers of a cluster.
+
+use super::FheBool;
+use crate::backward_compatibility::booleans::{FheBoolExprGraphVersions, FheBoolExprNodeVersions};
+use crate::high_level_api::global_state;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::integer::{BooleanBlock, ServerKey};
+use crate::named::Named;
+use crate::shortint::ciphertext::NoiseLevel;
+use crate::Error;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::ops::{BitAnd, BitOr, Not};
+use std::sync::Arc;
+use tfhe_versionable::Versionize;
+
+/// A boolean expression over [FheBool] values, which is computed when [evaluated](Self::evaluate)
+///
+/// Expressions are built from [FheBool] values and constants, and combined with `&`, `|` and
+/// `!` or with [Self::all] and [Self::any]. Cloning an expression is cheap, and using a clone
+/// in several places of a bigger expression makes it a shared subexpression, computed only once.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBoolExpr, FheUint16};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let age = FheUint16::encrypt(42u16, &client_key);
+/// let income = FheUint16::encrypt(3200u16, &client_key);
+///
+/// // WHERE age >= 18 AND age < 65 AND (income > 3000 OR NOT age < 30)
+/// let is_adult = FheBoolExpr::from(age.ge(18u16));
+/// let condition = FheBoolExpr::all([is_adult, age.lt(65u16).into()])
+///     & (FheBoolExpr::from(income.gt(3000u16)) | !FheBoolExpr::from(age.lt(30u16)));
+///
+/// let result = condition.evaluate();
+/// assert!(result.decrypt(&client_key));
+/// ```
+///
+/// Expressions can be serialized before being evaluated, the subexpressions shared in the
+/// expression are still shared once it is deserialized:
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::safe_serialization::{safe_deserialize, safe_serialize};
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool, FheBoolExpr};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+///
+/// let a = FheBoolExpr::from(FheBool::encrypt(true, &client_key));
+/// let b = FheBoolExpr::from(FheBool::encrypt(false, &client_key));
+/// let shared = a | b;
+/// let condition = shared.clone() & !shared;
+///
+/// let mut buffer = vec![];
+/// safe_serialize(&condition, &mut buffer, 1 << 20).unwrap();
+///
+/// // On another worker, with the same server key
+/// set_server_key(server_key);
+/// let condition: FheBoolExpr = safe_deserialize(buffer.as_slice(), 1 << 20).unwrap();
+/// let result = condition.evaluate();
+/// assert!(!result.decrypt(&client_key));
+/// ```
+#[derive(Clone, Serialize, Deserialize, Versionize)]
+#[serde(try_from = "FheBoolExprGraph", into = "FheBoolExprGraph")]
+#[versionize(
+    FheBoolExprGraphVersions,
+    try_from = FheBoolExprGraph,
+    into = FheBoolExprGraph
+)]
+pub struct FheBoolExpr {
+    node: Arc<ExprNode>,
+}
+
+enum ExprNode {
+    Value(FheBool),
+    Constant(bool),
+    Not(FheBoolExpr),
+    All(Vec<FheBoolExpr>),
+    Any(Vec<FheBoolExpr>),
+}
+
+impl FheBoolExpr {
+    fn from_node(node: ExprNode) -> Self {
+        Self {
+            node: Arc::new(node),
+        }
+    }
+
+    /// Creates an expression with a clear value
+    pub fn constant(value: bool) -> Self {
+        Self::from_node(ExprNode::Constant(value))
+    }
+
+    /// Creates an expression that is true when all the given expressions are true
+    ///
+    /// An empty list of expressions is true.
+    pub fn all<I, E>(exprs: I) -> Self
+    where
+        I: IntoIterator<Item = E>,
+        E: Into<Self>,
+    {
+        let mut operands = Vec::new();
+        for expr in exprs {
+            let expr = expr.into();
+            // Nested `and`s are flattened, so that all their operands get reduced together
+            match &*expr.node {
+                ExprNode::All(sub_operands) => operands.extend(sub_operands.iter().cloned()),
+                _ => operands.push(expr),
+            }
+        }
+        Self::from_node(ExprNode::All(operands))
+    }
+
+    /// Creates an expression that is true when at least one of the given expressions is true
+    ///
+    /// An empty list of expressions is false.
+    pub fn any<I, E>(exprs: I) -> Self
+    where
+        I: IntoIterator<Item = E>,
+        E: Into<Self>,
+    {
+        let mut operands = Vec::new();
+        for expr in exprs {
+            let expr = expr.into();
+            // Nested `or`s are flattened, so that all their operands get reduced together
+            match &*expr.node {
+                ExprNode::Any(sub_operands) => operands.extend(sub_operands.iter().cloned()),
+                _ => operands.push(expr),
+            }
+        }
+        Self::from_node(ExprNode::Any(operands))
+    }
+
+    /// Computes the value of the expression
+    ///
+    /// # Panics
+    ///
+    /// Panics if the server key is on a Cuda device, which is not supported yet.
+    pub fn evaluate(&self) -> FheBool {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let mut plan = EvaluationPlan::default();
+                let root = plan.lower(self);
+                let result = plan.evaluate(root, cpu_key.pbs_key());
+                FheBool::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support FheBoolExpr yet");
+            }
+        })
+    }
+}
+
+impl From<FheBool> for FheBoolExpr {
+    fn from(value: FheBool) -> Self {
+        Self::from_node(ExprNode::Value(value))
+    }
+}
+
+impl From<&FheBool> for FheBoolExpr {
+    fn from(value: &FheBool) -> Self {
+        Self::from(value.clone())
+    }
+}
+
+impl From<bool> for FheBoolExpr {
+    fn from(value: bool) -> Self {
+        Self::constant(value)
+    }
+}
+
+impl<E> BitAnd<E> for FheBoolExpr
+where
+    E: Into<Self>,
+{
+    type Output = Self;
+
+    fn bitand(self, rhs: E) -> Self::Output {
+        Self::all([self, rhs.into()])
+    }
+}
+
+impl<E> BitOr<E> for FheBoolExpr
+where
+    E: Into<Self>,
+{
+    type Output = Self;
+
+    fn bitor(self, rhs: E) -> Self::Output {
+        Self::any([self, rhs.into()])
+    }
+}
+
+impl Not for FheBoolExpr {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        // Double negations cancel out
+        match &*self.node {
+            ExprNode::Not(inner) => inner.clone(),
+            _ => Self::from_node(ExprNode::Not(self)),
+        }
+    }
+}
+
+impl Named for FheBoolExpr {
+    const NAME: &'static str = "high_level_api::FheBoolExpr";
+}
+
+/// Serialized form of a [FheBoolExpr]
+///
+/// Each node of the expression is stored once, even if it is used several times, and after the
+/// nodes of its operands. Operands are referenced by their index in `nodes`.
+#[derive(Serialize, Deserialize, Versionize)]
+#[versionize(FheBoolExprGraphVersions)]
+pub struct FheBoolExprGraph {
+    nodes: Vec<FheBoolExprNode>,
+    root: usize,
+}
+
+#[derive(Serialize, Deserialize, Versionize)]
+#[versionize(FheBoolExprNodeVersions)]
+pub enum FheBoolExprNode {
+    Value(FheBool),
+    Constant(bool),
+    Not(usize),
+    All(Vec<usize>),
+    Any(Vec<usize>),
+}
+
+impl FheBoolExprGraph {
+    fn push(&mut self, expr: &FheBoolExpr, indices: &mut HashMap<usize, usize>) -> usize {
+        let key = Arc::as_ptr(&expr.node) as usize;
+        if let Some(index) = indices.get(&key) {
+            return *index;
+        }
+
+        let node = match &*expr.node {
+            ExprNode::Value(value) => FheBoolExprNode::Value(value.clone()),
+            ExprNode::Constant(value) => FheBoolExprNode::Constant(*value),
+            ExprNode::Not(inner) => FheBoolExprNode::Not(self.push(inner, indices)),
+            ExprNode::All(operands) => FheBoolExprNode::All(
+                operands
+                    .iter()
+                    .map(|operand| self.push(operand, indices))
+                    .collect(),
+            ),
+            ExprNode::Any(operands) => FheBoolExprNode::Any(
+                operands
+                    .iter()
+                    .map(|operand| self.push(operand, indices))
+                    .collect(),
+            ),
+        };
+
+        self.nodes.push(node);
+        let index = self.nodes.len() - 1;
+        indices.insert(key, index);
+        index
+    }
+}
+
+impl From<FheBoolExpr> for FheBoolExprGraph {
+    fn from(expr: FheBoolExpr) -> Self {
+        let mut graph = Self {
+            nodes: Vec::new(),
+            root: 0,
+        };
+        graph.root = graph.push(&expr, &mut HashMap::new());
+        graph
+    }
+}
+
+impl TryFrom<FheBoolExprGraph> for FheBoolExpr {
+    type Error = Error;
+
+    fn try_from(graph: FheBoolExprGraph) -> Result<Self, Self::Error> {
+        let mut exprs: Vec<Self> = Vec::with_capacity(graph.nodes.len());
+
+        for (index, node) in graph.nodes.into_iter().enumerate() {
+            // Operands are always stored before the nodes using them,
+            // which also guarantees that the graph has no cycle
+            let operand = |operand_index: usize| {
+                exprs.get(operand_index).cloned().ok_or_else(|| {
+                    Error::new(format!(
+                        "Invalid FheBoolExpr graph, node {index} uses node {operand_index} \
+                        which is not stored before it"
+                    ))
+                })
+            };
+
+            let node = match node {
+                FheBoolExprNode::Value(value) => ExprNode::Value(value),
+                FheBoolExprNode::Constant(value) => ExprNode::Constant(value),
+                FheBoolExprNode::Not(inner) => ExprNode::Not(operand(inner)?),
+                FheBoolExprNode::All(operands) => ExprNode::All(
+                    operands
+                        .into_iter()
+                        .map(operand)
+                        .collect::<Result<_, _>>()?,
+                ),
+                FheBoolExprNode::Any(operands) => ExprNode::Any(
+                    operands
+                        .into_iter()
+                        .map(operand)
+                        .collect::<Result<_, _>>()?,
+                ),
+            };
+            exprs.push(Self::from_node(node));
+        }
+
+        exprs.get(graph.root).cloned().ok_or_else(|| {
+            Error::new(format!(
+                "Invalid FheBoolExpr graph, the root {} is not one of its {} nodes",
+                graph.root,
+                exprs.len()
+            ))
+        })
+    }
+}
+
+/// Reference to a node of the plan, negations are stored here as they are free to compute
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Operand {
+    node: usize,
+    negated: bool,
+}
+
+#[derive(Copy, Clone)]
+enum Lowered {
+    Constant(bool),
+    Operand(Operand),
+}
+
+impl Lowered {
+    fn negate(self) -> Self {
+        match self {
+            Self::Constant(value) => Self::Constant(!value),
+            Self::Operand(operand) => Self::Operand(Operand {
+                node: operand.node,
+                negated: !operand.negated,
+            }),
+        }
+    }
+}
+
+enum PlanNode<'a> {
+    Value(&'a FheBool),
+    /// Reduction of the operands, with `and` if `is_any` is false, with `or` otherwise
+    Reduction {
+        is_any: bool,
+        operands: Vec<Operand>,
+    },
+}
+
+/// Simplified form of an expression, where each node is only present once
+///
+/// Nodes are always pushed after the nodes of their operands.
+#[derive(Default)]
+struct EvaluationPlan<'a> {
+    nodes: Vec<PlanNode<'a>>,
+    // Reductions already in the plan, to share identical subexpressions
+    reductions: HashMap<(bool, Vec<Operand>), usize>,
+    // Expressions already lowered (identified by their address),
+    // to share subexpressions used several times
+    lowered: HashMap<usize, Lowered>,
+}
+
+impl<'a> EvaluationPlan<'a> {
+    fn lower(&mut self, expr: &'a FheBoolExpr) -> Lowered {
+        let key = Arc::as_ptr(&expr.node) as usize;
+        if let Some(lowered) = self.lowered.get(&key) {
+            return *lowered;
+        }
+
+        let lowered = match &*expr.node {
+            ExprNode::Value(value) => {
+                self.nodes.push(PlanNode::Value(value));
+                Lowered::Operand(Operand {
+                    node: self.nodes.len() - 1,
+                    negated: false,
+                })
+            }
+            ExprNode::Constant(value) => Lowered::Constant(*value),
+            ExprNode::Not(inner) => self.lower(inner).negate(),
+            ExprNode::All(operands) => self.lower_reduction(operands, false),
+            ExprNode::Any(operands) => self.lower_reduction(operands, true),
+        };
+
+        self.lowered.insert(key, lowered);
+        lowered
+    }
+
+    fn lower_reduction(&mut self, exprs: &'a [FheBoolExpr], is_any: bool) -> Lowered {
+        // `true` absorbs an `or`, `false` absorbs an `and`
+        let absorbing_value = is_any;
+
+        let mut operands = Vec::with_capacity(exprs.len());
+        for expr in exprs {
+            match self.lower(expr) {
+                Lowered::Constant(value) if value == absorbing_value => {
+                    return Lowered::Constant(absorbing_value);
+                }
+                Lowered::Constant(_) => {}
+                Lowered::Operand(operand) => operands.push(operand),
+            }
+        }
+
+        operands.sort_unstable();
+        operands.dedup();
+
+        // As operands are sorted and deduplicated, two consecutive operands with the same node
+        // are `x` and `!x`
+        if operands.windows(2).any(|pair| pair[0].node == pair[1].node) {
+            return Lowered::Constant(absorbing_value);
+        }
+
+        match operands.len() {
+            0 => Lowered::Constant(!absorbing_value),
+            1 => Lowered::Operand(operands[0]),
+            _ => {
+                let nodes = &mut self.nodes;
+                let node = *self
+                    .reductions
+                    .entry((is_any, operands.clone()))
+                    .or_insert_with(|| {
+                        nodes.push(PlanNode::Reduction { is_any, operands });
+                        nodes.len() - 1
+                    });
+                Lowered::Operand(Operand {
+                    node,
+                    negated: false,
+                })
+            }
+        }
+    }
+
+    fn evaluate(&self, root: Lowered, sks: &ServerKey) -> BooleanBlock {
+        let root = match root {
+            Lowered::Constant(value) => return sks.create_trivial_boolean_block(value),
+            Lowered::Operand(operand) => operand,
+        };
+
+        // Only the nodes reachable from the root are computed,
+        // operands always have a smaller index than the node using them
+        let mut is_needed = vec![false; self.nodes.len()];
+        is_needed[root.node] = true;
+        for index in (0..self.nodes.len()).rev() {
+            if let (true, PlanNode::Reduction { operands, .. }) =
+                (is_needed[index], &self.nodes[index])
+            {
+                for operand in operands {
+                    is_needed[operand.node] = true;
+                }
+            }
+        }
+
+        let mut depths = vec![0usize; self.nodes.len()];
+        for index in 0..self.nodes.len() {
+            if let PlanNode::Reduction { operands, .. } = &self.nodes[index] {
+                depths[index] = 1 + operands
+                    .iter()
+                    .map(|operand| depths[operand.node])
+                    .max()
+                    .unwrap_or(0);
+            }
+        }
+
+        let operand_block = |results: &[Option<BooleanBlock>], operand: Operand| {
+            let block = results[operand.node]
+                .as_ref()
+                .expect("internal error, operand was not computed");
+            if operand.negated {
+                sks.boolean_bitnot(block)
+            } else {
+                block.clone()
+            }
+        };
+
+        // All the nodes of the same depth are independent and computed in parallel
+        let mut results: Vec<Option<BooleanBlock>> = vec![None; self.nodes.len()];
+        for depth in 0..=depths[root.node] {
+            let computed = (0..self.nodes.len())
+                .into_par_iter()
+                .filter(|&index| is_needed[index] && depths[index] == depth)
+                .map(|index| {
+                    let result = match &self.nodes[index] {
+                        PlanNode::Value(value) => {
+                            let mut block = value.ciphertext.on_cpu().into_owned();
+                            // Operands are summed before being bootstrapped,
+                            // which requires them to be clean booleans
+                            if block.0.noise_level() > NoiseLevel::NOMINAL
+                                || block.0.degree.get() > 1
+                            {
+                                sks.key.message_extract_assign(&mut block.0);
+                            }
+                            block
+                        }
+                        PlanNode::Reduction { is_any, operands } => {
+                            let blocks = operands
+                                .iter()
+                                .map(|&operand| operand_block(&results, operand).0)
+                                .collect::<Vec<_>>();
+                            let block = if *is_any {
+                                sks.is_at_least_one_comparisons_block_true(blocks)
+                            } else {
+                                sks.are_all_comparisons_block_true(blocks)
+                            };
+                            BooleanBlock::new_unchecked(block)
+                        }
+                    };
+                    (index, result)
+                })
+                .collect::<Vec<_>>();
+
+            for (index, result) in computed {
+                results[index] = Some(result);
+            }
+        }
+
+        operand_block(&results, root)
+    }
+}
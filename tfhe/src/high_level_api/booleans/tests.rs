@@ -7,7 +7,7 @@
 use crate::prelude::*;
 use crate::{
     generate_keys, set_server_key, ClientKey, CompressedFheBool, CompressedPublicKey,
-    ConfigBuilder, Device, FheBool,
+    ConfigBuilder, Device, FheBool, FheBoolExpr,
 };
 
 #[inline(always)]
@@ -719,6 +719,124 @@ mod cpu {
         let decrypted: bool = deserialized_a.decompress().decrypt(&client_key);
         assert_eq!(decrypted, clear_a);
     }
+
+    #[test]
+    fn test_fhe_bool_expr() {
+        let keys = setup_default();
+
+        for _ in 0..5 {
+            let clears = (0..6).map(|_| random::<bool>()).collect::<Vec<_>>();
+            let encrypted = clears
+                .iter()
+                .map(|&b| FheBoolExpr::from(FheBool::encrypt(b, &keys)))
+                .collect::<Vec<_>>();
+            let [a, b, c, d, e, f] = encrypted.as_slice() else {
+                unreachable!()
+            };
+            let [ca, cb, cc, cd, ce, cf] = clears.as_slice() else {
+                unreachable!()
+            };
+
+            // Shared subexpression, used in both branches
+            let shared = a.clone() | !b.clone();
+            let clear_shared = *ca || !*cb;
+
+            let expr = FheBoolExpr::all([
+                shared.clone(),
+                c.clone() | d.clone() | e.clone(),
+                !(f.clone() & shared.clone()) | a.clone(),
+            ]) | (FheBoolExpr::any([e.clone(), !e.clone()]) & d.clone() & true);
+            let expected =
+                (clear_shared && (*cc || *cd || *ce) && (!(*cf && clear_shared) || *ca)) || *cd;
+
+            let result = expr.evaluate();
+            assert_degree_is_ok(&result);
+            assert_eq!(result.decrypt(&keys), expected);
+
+            // Result of an evaluation can be used in other expressions
+            let result = (FheBoolExpr::from(&result) & !b.clone()).evaluate();
+            assert_degree_is_ok(&result);
+            assert_eq!(result.decrypt(&keys), expected && !*cb);
+        }
+
+        // Trivial expressions
+        let a = FheBoolExpr::from(FheBool::encrypt(true, &keys));
+        assert!((a.clone() | !a.clone()).evaluate().decrypt(&keys));
+        assert!(!(a.clone() & false).evaluate().decrypt(&keys));
+        assert!(!(!a.clone()).evaluate().decrypt(&keys));
+        assert!(FheBoolExpr::all(Vec::<FheBoolExpr>::new())
+            .evaluate()
+            .decrypt(&keys));
+        assert!(!FheBoolExpr::any(Vec::<FheBoolExpr>::new())
+            .evaluate()
+            .decrypt(&keys));
+    }
+
+    #[test]
+    fn test_fhe_all_any_count_true() {
+        let keys = setup_default();
+
+        for len in [1, 5, 17] {
+            let mut clears = (0..len).map(|_| random::<bool>()).collect::<Vec<_>>();
+            let mut values = clears
+                .iter()
+                .map(|&b| FheBool::encrypt(b, &keys))
+                .collect::<Vec<_>>();
+            // A negated value, so that not all the booleans are fresh encryptions
+            values[0] = !&values[0];
+            clears[0] = !clears[0];
+
+            let all = crate::algorithms::fhe_all(&values);
+            let any = crate::algorithms::fhe_any(&values);
+            let count: crate::FheUint8 = crate::algorithms::fhe_count_true(&values);
+            assert_degree_is_ok(&all);
+            assert_degree_is_ok(&any);
+            assert_eq!(all.decrypt(&keys), clears.iter().all(|&b| b));
+            assert_eq!(any.decrypt(&keys), clears.iter().any(|&b| b));
+            let count: u8 = count.decrypt(&keys);
+            assert_eq!(usize::from(count), clears.iter().filter(|&&b| b).count());
+        }
+
+        assert!(crate::algorithms::fhe_all(&[]).decrypt(&keys));
+        assert!(!crate::algorithms::fhe_any(&[]).decrypt(&keys));
+        let count: crate::FheUint8 = crate::algorithms::fhe_count_true(&[]);
+        let count: u8 = count.decrypt(&keys);
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn test_fhe_bool_expr_serialization() {
+        let keys = setup_default();
+
+        let clears = [true, false, true];
+        let [a, b, c] = clears.map(|v| FheBoolExpr::from(FheBool::encrypt(v, &keys)));
+
+        let [ca, cb, cc] = clears;
+
+        let shared = a.clone() & !b;
+        let expr = (shared.clone() | c.clone()) & !(shared & c) & (a | false);
+        let clear_shared = ca && !cb;
+        let expected = (clear_shared || cc) && !(clear_shared && cc) && ca;
+
+        let mut serialized = vec![];
+        SerializationConfig::new(1 << 20)
+            .serialize_into(&expr, &mut serialized)
+            .unwrap();
+        let deserialized: FheBoolExpr = DeserializationConfig::new(1 << 20)
+            .disable_conformance()
+            .deserialize_from(serialized.as_slice())
+            .unwrap();
+
+        let result = deserialized.evaluate();
+        assert_degree_is_ok(&result);
+        assert_eq!(result.decrypt(&keys), expected);
+        assert_eq!(expr.evaluate().decrypt(&keys), expected);
+
+        // Constant expressions have no ciphertext
+        let serialized = bincode::serialize(&FheBoolExpr::constant(true)).unwrap();
+        let deserialized: FheBoolExpr = bincode::deserialize(&serialized).unwrap();
+        assert!(deserialized.evaluate().decrypt(&keys));
+    }
 }
 
 #[cfg(feature = "gpu")]
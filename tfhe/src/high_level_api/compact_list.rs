@@ -39,8 +39,12 @@ impl crate::FheTypes {
                     12 => Self::Uint12,
                     14 => Self::Uint14,
                     16 => Self::Uint16,
+                    24 => Self::Uint24,
                     32 => Self::Uint32,
+                    40 => Self::Uint40,
+                    48 => Self::Uint48,
                     64 => Self::Uint64,
+                    72 => Self::Uint72,
                     128 => Self::Uint128,
                     160 => Self::Uint160,
                     256 => Self::Uint256,
@@ -62,11 +66,18 @@ impl crate::FheTypes {
                     12 => Self::Int12,
                     14 => Self::Int14,
                     16 => Self::Int16,
+                    24 => Self::Int24,
                     32 => Self::Int32,
+                    40 => Self::Int40,
+                    48 => Self::Int48,
                     64 => Self::Int64,
+                    72 => Self::Int72,
                     128 => Self::Int128,
                     160 => Self::Int160,
                     256 => Self::Int256,
+                    512 => Self::Int512,
+                    1024 => Self::Int1024,
+                    2048 => Self::Int2048,
                     _ => return None,
                 }
             }
@@ -139,7 +150,41 @@ impl CompactCiphertextList {
                     tag: self.tag.clone(),
                 }),
             #[cfg(feature = "gpu")]
-            Some(_) => Err(crate::Error::new("Expected a CPU server key".to_string())),
+            Some(InternalServerKey::Cuda(cuda_key)) => self.expand_with_cuda_key(cuda_key),
+        })
+    }
+
+    /// Expands the list using the CUDA server key set for the current thread
+    ///
+    /// The keyswitches and programmable bootstraps required to cast the ciphertexts to the
+    /// computation parameters and to unpack them are run on the GPU. The expanded ciphertexts are
+    /// the same as the ones returned by [Self::expand] with a CPU server key.
+    #[cfg(feature = "gpu")]
+    pub fn expand_on_gpu(&self) -> crate::Result<CompactCiphertextListExpander> {
+        global_state::try_with_internal_keys(|maybe_keys| match maybe_keys {
+            None => Err(crate::high_level_api::errors::UninitializedServerKey.into()),
+            Some(InternalServerKey::Cuda(cuda_key)) => self.expand_with_cuda_key(cuda_key),
+            Some(InternalServerKey::Cpu(_)) => {
+                Err(crate::Error::new("Expected a CUDA server key".to_string()))
+            }
+        })
+    }
+
+    #[cfg(feature = "gpu")]
+    fn expand_with_cuda_key(
+        &self,
+        cuda_key: &crate::high_level_api::keys::CudaServerKey,
+    ) -> crate::Result<CompactCiphertextListExpander> {
+        global_state::with_thread_local_cuda_streams(|streams| {
+            self.inner.expand_on_gpu(
+                &cuda_key.key.key,
+                cuda_key.key.cpk_key_switching_key_material.as_ref(),
+                streams,
+            )
+        })
+        .map(|inner| CompactCiphertextListExpander {
+            inner,
+            tag: self.tag.clone(),
         })
     }
 }
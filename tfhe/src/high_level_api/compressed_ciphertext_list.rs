@@ -265,11 +265,18 @@ impl CiphertextList for CompressedCiphertextList {
                         12 => crate::FheTypes::Uint12,
                         14 => crate::FheTypes::Uint14,
                         16 => crate::FheTypes::Uint16,
+                        24 => crate::FheTypes::Uint24,
                         32 => crate::FheTypes::Uint32,
+                        40 => crate::FheTypes::Uint40,
+                        48 => crate::FheTypes::Uint48,
                         64 => crate::FheTypes::Uint64,
+                        72 => crate::FheTypes::Uint72,
                         128 => crate::FheTypes::Uint128,
                         160 => crate::FheTypes::Uint160,
                         256 => crate::FheTypes::Uint256,
+                        512 => crate::FheTypes::Uint512,
+                        1024 => crate::FheTypes::Uint1024,
+                        2048 => crate::FheTypes::Uint2048,
                         _ => return None,
                     }
                 }
@@ -285,11 +292,18 @@ impl CiphertextList for CompressedCiphertextList {
                         12 => crate::FheTypes::Int12,
                         14 => crate::FheTypes::Int14,
                         16 => crate::FheTypes::Int16,
+                        24 => crate::FheTypes::Int24,
                         32 => crate::FheTypes::Int32,
+                        40 => crate::FheTypes::Int40,
+                        48 => crate::FheTypes::Int48,
                         64 => crate::FheTypes::Int64,
+                        72 => crate::FheTypes::Int72,
                         128 => crate::FheTypes::Int128,
                         160 => crate::FheTypes::Int160,
                         256 => crate::FheTypes::Int256,
+                        512 => crate::FheTypes::Int512,
+                        1024 => crate::FheTypes::Int1024,
+                        2048 => crate::FheTypes::Int2048,
                         _ => return None,
                     }
                 }
@@ -309,11 +323,18 @@ impl CiphertextList for CompressedCiphertextList {
                         12 => crate::FheTypes::Uint12,
                         14 => crate::FheTypes::Uint14,
                         16 => crate::FheTypes::Uint16,
+                        24 => crate::FheTypes::Uint24,
                         32 => crate::FheTypes::Uint32,
+                        40 => crate::FheTypes::Uint40,
+                        48 => crate::FheTypes::Uint48,
                         64 => crate::FheTypes::Uint64,
+                        72 => crate::FheTypes::Uint72,
                         128 => crate::FheTypes::Uint128,
                         160 => crate::FheTypes::Uint160,
                         256 => crate::FheTypes::Uint256,
+                        512 => crate::FheTypes::Uint512,
+                        1024 => crate::FheTypes::Uint1024,
+                        2048 => crate::FheTypes::Uint2048,
                         _ => return None,
                     }
                 }
@@ -329,11 +350,18 @@ impl CiphertextList for CompressedCiphertextList {
                         12 => crate::FheTypes::Int12,
                         14 => crate::FheTypes::Int14,
                         16 => crate::FheTypes::Int16,
+                        24 => crate::FheTypes::Int24,
                         32 => crate::FheTypes::Int32,
+                        40 => crate::FheTypes::Int40,
+                        48 => crate::FheTypes::Int48,
                         64 => crate::FheTypes::Int64,
+                        72 => crate::FheTypes::Int72,
                         128 => crate::FheTypes::Int128,
                         160 => crate::FheTypes::Int160,
                         256 => crate::FheTypes::Int256,
+                        512 => crate::FheTypes::Int512,
+                        1024 => crate::FheTypes::Int1024,
+                        2048 => crate::FheTypes::Int2048,
                         _ => return None,
                     }
                 }
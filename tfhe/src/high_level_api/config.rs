@@ -2,6 +2,7 @@ use tfhe_versionable::Versionize;
 
 use crate::backward_compatibility::config::ConfigVersions;
 use crate::high_level_api::keys::IntegerConfig;
+use crate::named::Named;
 use crate::shortint::parameters::list_compression::CompressionParameters;
 
 /// The config type
@@ -9,9 +10,16 @@ use crate::shortint::parameters::list_compression::CompressionParameters;
 #[versionize(ConfigVersions)]
 pub struct Config {
     pub(crate) inner: IntegerConfig,
+    pub(crate) implicit_widening: bool,
 }
 
 impl Config {
+    /// Returns whether binary operations between [FheUint](crate::FheUint) of different widths
+    /// are allowed, see [ConfigBuilder::enable_implicit_widening]
+    pub fn is_implicit_widening_enabled(&self) -> bool {
+        self.implicit_widening
+    }
+
     pub fn public_key_encryption_parameters(
         &self,
     ) -> Result<crate::shortint::parameters::CompactPublicKeyEncryptionParameters, crate::Error>
@@ -20,6 +28,12 @@ impl Config {
     }
 }
 
+impl Named for Config {
+    const NAME: &'static str = "high_level_api::Config";
+    // The implicit widening flag was added in version 1
+    const MIN_READER_VERSION: Option<&'static str> = Some("0.8");
+}
+
 /// The builder to create your config
 ///
 /// The configuration is needed to select parameters you wish to use for these types
@@ -53,6 +67,7 @@ impl ConfigBuilder {
         Self {
             config: Config {
                 inner: IntegerConfig::default_big(),
+                implicit_widening: false,
             },
         }
     }
@@ -64,6 +79,7 @@ impl ConfigBuilder {
         Self {
             config: Config {
                 inner: IntegerConfig::default_small(),
+                implicit_widening: false,
             },
         }
     }
@@ -75,6 +91,7 @@ impl ConfigBuilder {
         Self {
             config: Config {
                 inner: IntegerConfig::new(block_parameters.into(), None),
+                implicit_widening: false,
             },
         }
     }
@@ -99,6 +116,41 @@ impl ConfigBuilder {
         self
     }
 
+    /// Allows binary operations between [FheUint](crate::FheUint) of different widths
+    ///
+    /// When enabled, the narrower operand of an arithmetic, bitwise or comparison operation
+    /// between two of [FheUint8](crate::FheUint8), [FheUint16](crate::FheUint16),
+    /// [FheUint32](crate::FheUint32), [FheUint64](crate::FheUint64),
+    /// [FheUint128](crate::FheUint128) and [FheUint256](crate::FheUint256) is cast to the type
+    /// of the wider one, which is also the type of the result.
+    ///
+    /// The policy is recorded in the keys generated from the config. Computing such an operation
+    /// with a server key generated from a config where it is not enabled panics, so that
+    /// implicit casts cannot go unnoticed in code where they are not wanted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint8};
+    ///
+    /// let config = ConfigBuilder::default().enable_implicit_widening().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(200u8, &client_key);
+    /// let b = FheUint16::encrypt(1000u16, &client_key);
+    ///
+    /// // `a` is cast to a FheUint16
+    /// let result: FheUint16 = &a + &b;
+    /// let result: u16 = result.decrypt(&client_key);
+    /// assert_eq!(result, 1200);
+    /// ```
+    pub fn enable_implicit_widening(mut self) -> Self {
+        self.config.implicit_widening = true;
+        self
+    }
+
     pub fn build(self) -> Config {
         self.config
     }
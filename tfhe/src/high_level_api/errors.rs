@@ -45,3 +45,42 @@ impl From<UninitializedServerKey> for Error {
         Self::new(format!("{value}"))
     }
 }
+
+/// The memory usage cap was exceeded
+///
+/// See [set_memory_usage_cap](crate::set_memory_usage_cap)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MemoryUsageCapExceeded {
+    pub(in crate::high_level_api) usage: usize,
+    pub(in crate::high_level_api) cap: usize,
+}
+
+impl MemoryUsageCapExceeded {
+    /// Memory usage, in bytes, when the cap was checked
+    pub fn usage(&self) -> usize {
+        self.usage
+    }
+
+    /// The cap, in bytes, that was exceeded
+    pub fn cap(&self) -> usize {
+        self.cap
+    }
+}
+
+impl Display for MemoryUsageCapExceeded {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "The memory usage ({} bytes) exceeds the memory usage cap ({} bytes)",
+            self.usage, self.cap
+        )
+    }
+}
+
+impl std::error::Error for MemoryUsageCapExceeded {}
+
+impl From<MemoryUsageCapExceeded> for Error {
+    fn from(value: MemoryUsageCapExceeded) -> Self {
+        Self::new(format!("{value}"))
+    }
+}
@@ -0,0 +1,186 @@
+//! Encrypted geographic coordinates, to compute private proximity checks.
+//!
+//! Coordinates are expressed in microdegrees (millionths of a degree), and distances are
+//! approximated with the Euclidean distance on these coordinates. This is precise enough to
+//! compare nearby points, but does not account for the shrinking of the longitude degrees away
+//! from the equator, nor for the wrap-around of the longitude at the antimeridian.
+use crate::prelude::*;
+use crate::{ClientKey, FheBool, FheInt32, FheInt64, FheUint64, FheUint8};
+
+/// Maximum absolute value of a latitude in microdegrees
+const MAX_LATITUDE: i32 = 90_000_000;
+/// Maximum absolute value of a longitude in microdegrees
+const MAX_LONGITUDE: i32 = 180_000_000;
+
+/// An encrypted point on the earth, made of a latitude and a longitude in microdegrees
+#[derive(Clone)]
+pub struct FheGeoPoint {
+    latitude: FheInt32,
+    longitude: FheInt32,
+}
+
+impl FheGeoPoint {
+    /// Creates a point from encrypted coordinates in microdegrees
+    ///
+    /// The latitude must be in [-90_000_000, 90_000_000] and the longitude in
+    /// [-180_000_000, 180_000_000], this cannot be checked on encrypted values.
+    pub fn from_coordinates(latitude: FheInt32, longitude: FheInt32) -> Self {
+        Self {
+            latitude,
+            longitude,
+        }
+    }
+
+    /// Encrypts a point given as coordinates in microdegrees
+    ///
+    /// Returns an error if the latitude is not in [-90_000_000, 90_000_000] or the longitude is
+    /// not in [-180_000_000, 180_000_000].
+    pub fn try_encrypt(latitude: i32, longitude: i32, key: &ClientKey) -> crate::Result<Self> {
+        if !(-MAX_LATITUDE..=MAX_LATITUDE).contains(&latitude) {
+            return Err(crate::Error::new(format!(
+                "Latitude {latitude} is out of the [-{MAX_LATITUDE}, {MAX_LATITUDE}] microdegrees \
+range"
+            )));
+        }
+        if !(-MAX_LONGITUDE..=MAX_LONGITUDE).contains(&longitude) {
+            return Err(crate::Error::new(format!(
+                "Longitude {longitude} is out of the [-{MAX_LONGITUDE}, {MAX_LONGITUDE}] \
+microdegrees range"
+            )));
+        }
+
+        Ok(Self {
+            latitude: FheInt32::encrypt(latitude, key),
+            longitude: FheInt32::encrypt(longitude, key),
+        })
+    }
+
+    /// Decrypts the point, returning its latitude and longitude in microdegrees
+    pub fn decrypt(&self, key: &ClientKey) -> (i32, i32) {
+        (self.latitude.decrypt(key), self.longitude.decrypt(key))
+    }
+
+    /// Returns the encrypted latitude in microdegrees
+    pub fn latitude(&self) -> &FheInt32 {
+        &self.latitude
+    }
+
+    /// Returns the encrypted longitude in microdegrees
+    pub fn longitude(&self) -> &FheInt32 {
+        &self.longitude
+    }
+
+    /// Returns the encrypted latitude and longitude in microdegrees
+    pub fn into_coordinates(self) -> (FheInt32, FheInt32) {
+        (self.latitude, self.longitude)
+    }
+
+    /// Computes the squared Euclidean distance between two points, in squared microdegrees
+    ///
+    /// The result cannot overflow for coordinates in the valid ranges.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheGeoPoint};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// // Paris and London
+    /// let a = FheGeoPoint::try_encrypt(48_856_600, 2_352_200, &client_key).unwrap();
+    /// let b = FheGeoPoint::try_encrypt(51_507_400, -127_800, &client_key).unwrap();
+    ///
+    /// let distance = a.squared_distance(&b);
+    ///
+    /// let decrypted: u64 = distance.decrypt(&client_key);
+    /// assert_eq!(decrypted, 2_650_800u64.pow(2) + 2_480_000u64.pow(2));
+    /// ```
+    pub fn squared_distance(&self, other: &Self) -> FheUint64 {
+        let delta_latitude = FheInt64::cast_from(self.latitude.clone())
+            - FheInt64::cast_from(other.latitude.clone());
+        let delta_longitude = FheInt64::cast_from(self.longitude.clone())
+            - FheInt64::cast_from(other.longitude.clone());
+
+        let squared_distance =
+            &delta_latitude * &delta_latitude + &delta_longitude * &delta_longitude;
+
+        // A sum of squares is never negative
+        FheUint64::cast_from(squared_distance)
+    }
+
+    /// Returns whether the distance between the two points is at most `radius` microdegrees
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheGeoPoint};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheGeoPoint::try_encrypt(48_856_600, 2_352_200, &client_key).unwrap();
+    /// let b = FheGeoPoint::try_encrypt(48_858_400, 2_294_500, &client_key).unwrap();
+    ///
+    /// // The points are less than 0.1 degree apart
+    /// let is_near = a.is_within_distance(&b, 100_000);
+    /// assert!(is_near.decrypt(&client_key));
+    /// ```
+    pub fn is_within_distance(&self, other: &Self, radius: u32) -> FheBool {
+        let squared_radius = u64::from(radius) * u64::from(radius);
+        self.squared_distance(other).le(squared_radius)
+    }
+
+    /// Returns the index of the distance bucket the two points fall in
+    ///
+    /// `thresholds` are the upper bounds, in microdegrees, of the buckets and must be sorted
+    /// in increasing order: the result is the number of thresholds that are strictly smaller
+    /// than the distance. So 0 means the distance is at most `thresholds[0]`, and
+    /// `thresholds.len()` means the distance is greater than all the thresholds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the thresholds are not sorted, or if there are more than 255 of them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheGeoPoint};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheGeoPoint::try_encrypt(0, 0, &client_key).unwrap();
+    /// let b = FheGeoPoint::try_encrypt(3_000, 4_000, &client_key).unwrap();
+    ///
+    /// // The distance is exactly 5_000 microdegrees
+    /// let bucket = a.distance_bucket(&b, &[1_000, 5_000, 10_000]);
+    /// let decrypted: u8 = bucket.decrypt(&client_key);
+    /// assert_eq!(decrypted, 1);
+    /// ```
+    pub fn distance_bucket(&self, other: &Self, thresholds: &[u32]) -> FheUint8 {
+        assert!(
+            thresholds.windows(2).all(|pair| pair[0] <= pair[1]),
+            "thresholds must be sorted in increasing order"
+        );
+        assert!(
+            thresholds.len() <= u8::MAX as usize,
+            "at most {} thresholds are supported",
+            u8::MAX
+        );
+
+        let squared_distance = self.squared_distance(other);
+
+        thresholds
+            .iter()
+            .map(|&threshold| {
+                let squared_threshold = u64::from(threshold) * u64::from(threshold);
+                FheUint8::cast_from(squared_distance.gt(squared_threshold))
+            })
+            .reduce(|acc, is_greater| acc + is_greater)
+            .unwrap_or_else(|| FheUint8::encrypt_trivial(0u8))
+    }
+}
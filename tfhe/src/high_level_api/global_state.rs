@@ -2,8 +2,11 @@
 //! perform operations.
 #[cfg(feature = "gpu")]
 use crate::core_crypto::gpu::CudaStreams;
+use crate::high_level_api::accounting::{self, account_operation};
 use crate::high_level_api::errors::{UninitializedServerKey, UnwrapResultExt};
 use crate::high_level_api::keys::{InternalServerKey, ServerKey};
+use crate::high_level_api::memory_usage::{self, enforce_memory_usage_cap};
+use crate::high_level_api::policy::{OperationKind, OperationPermit};
 use std::cell::RefCell;
 /// We store the internal keys as thread local, meaning each thread has its own set of keys.
 ///
@@ -78,11 +81,171 @@ where
     result
 }
 
+/// Runs two closures, potentially in parallel, with the server key of the calling thread set in
+/// both of them, and returns their results.
+///
+/// This is the equivalent of [rayon::join] for computations on homomorphic types: the closures
+/// may run on threads of the rayon thread pool, which do not have the server key of the calling
+/// thread. This function sets it in the threads for the duration of the closures, and then
+/// restores the key the threads had before. If no server key is set in the calling thread, none
+/// is set in the closures.
+///
+/// The closures are part of the computation of the calling thread: if it runs
+/// [try_with_memory_usage_cap](crate::try_with_memory_usage_cap), so do the closures, and their
+/// operations are not accounted in the [tag statistics](crate::enable_tag_statistics) if the
+/// calling thread is itself running an operation.
+///
+/// See the [fhe_join!](crate::fhe_join!) macro to run more than two expressions.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{fhe_join, generate_keys, set_server_key, ConfigBuilder, FheUint8};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let a = FheUint8::encrypt(27u8, &client_key);
+/// let b = FheUint8::encrypt(128u8, &client_key);
+///
+/// let (sum, product) = fhe_join(|| &a + &b, || &a * &b);
+///
+/// let sum: u8 = sum.decrypt(&client_key);
+/// let product: u8 = product.decrypt(&client_key);
+/// assert_eq!(sum, 27u8.wrapping_add(128));
+/// assert_eq!(product, 27u8.wrapping_mul(128));
+/// ```
+pub fn fhe_join<A, B, RA, RB>(oper_a: A, oper_b: B) -> (RA, RB)
+where
+    A: FnOnce() -> RA + Send,
+    B: FnOnce() -> RB + Send,
+    RA: Send,
+    RB: Send,
+{
+    let context_a = TaskContext::current();
+    let context_b = context_a.clone();
+
+    rayon::join(
+        move || with_task_context(context_a, oper_a),
+        move || with_task_context(context_b, oper_b),
+    )
+}
+
+/// Runs independent expressions on homomorphic types, potentially in parallel, and returns their
+/// results in a tuple.
+///
+/// The server key of the calling thread is set in the threads running the expressions, see
+/// [fhe_join](crate::fhe_join). Between 2 and 4 expressions are supported.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{fhe_join, generate_keys, set_server_key, ConfigBuilder, FheUint8};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let a = FheUint8::encrypt(27u8, &client_key);
+/// let b = FheUint8::encrypt(128u8, &client_key);
+///
+/// let (sum, difference, is_greater) = fhe_join!(&a + &b, &b - &a, a.gt(&b));
+///
+/// let sum: u8 = sum.decrypt(&client_key);
+/// let difference: u8 = difference.decrypt(&client_key);
+/// assert_eq!(sum, 155);
+/// assert_eq!(difference, 101);
+/// assert!(!is_greater.decrypt(&client_key));
+/// ```
+#[macro_export]
+macro_rules! fhe_join {
+    ($a:expr, $b:expr $(,)?) => {
+        $crate::fhe_join(|| $a, || $b)
+    };
+    ($a:expr, $b:expr, $c:expr $(,)?) => {{
+        let (a, (b, c)) = $crate::fhe_join(|| $a, || $crate::fhe_join(|| $b, || $c));
+        (a, b, c)
+    }};
+    ($a:expr, $b:expr, $c:expr, $d:expr $(,)?) => {{
+        let ((a, b), (c, d)) = $crate::fhe_join(
+            || $crate::fhe_join(|| $a, || $b),
+            || $crate::fhe_join(|| $c, || $d),
+        );
+        (a, b, c, d)
+    }};
+}
+
+/// The state of a thread that is carried to the threads running the tasks it spawns with
+/// [fhe_join]
+#[derive(Clone)]
+struct TaskContext {
+    keys: Option<InternalServerKey>,
+    // So that operations run by a task are accounted only if the spawning thread was not itself
+    // running an accounted operation
+    nesting_depth: usize,
+    // So that operations run by a task are not checked against the memory usage cap if the
+    // spawning thread runs `try_with_memory_usage_cap`
+    try_scope_depth: usize,
+}
+
+impl TaskContext {
+    fn current() -> Self {
+        Self {
+            keys: INTERNAL_KEYS.with(|keys| keys.borrow().clone()),
+            nesting_depth: accounting::current_nesting_depth(),
+            try_scope_depth: memory_usage::current_try_scope_depth(),
+        }
+    }
+
+    /// Sets the context in the current thread, returns the previous one
+    fn replace_current(self) -> Self {
+        Self {
+            keys: INTERNAL_KEYS.with(|internal_keys| internal_keys.replace(self.keys)),
+            nesting_depth: accounting::replace_nesting_depth(self.nesting_depth),
+            try_scope_depth: memory_usage::replace_try_scope_depth(self.try_scope_depth),
+        }
+    }
+}
+
+/// Sets the given context in the current thread while `func` runs, the previous context of the
+/// thread is restored afterward, even if `func` panics.
+///
+/// The [OperationPermit]s of the thread are suspended while `func` runs, as `func` is not part of
+/// the operations they were given for.
+fn with_task_context<T, F>(context: TaskContext, func: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    struct RestoreContext(Option<TaskContext>, usize);
+
+    impl Drop for RestoreContext {
+        fn drop(&mut self) {
+            if let Some(previous_context) = self.0.take() {
+                previous_context.replace_current();
+            }
+            OperationPermit::restore_all(self.1);
+        }
+    }
+
+    let _restore = RestoreContext(
+        Some(context.replace_current()),
+        OperationPermit::suspend_all(),
+    );
+    func()
+}
+
 /// Convenience function that allows to write functions that needs to access the internal keys
 ///
 /// # Panics
 ///
-/// Panics if the server key is not set
+/// - Panics if the server key is not set
+/// - Panics if the memory usage exceeds the cap when the operation is not run by another operation
+///   nor by [try_with_memory_usage_cap](crate::try_with_memory_usage_cap) (see
+///   [set_memory_usage_cap](crate::set_memory_usage_cap))
+/// - Panics if the [OperationPolicy](crate::OperationPolicy) of the server key refuses the
+///   operations without a kind and the caller did not get an [OperationPermit] (see
+///   [enforce_operation_policy] and [permit_unrestricted_operation])
 #[track_caller]
 #[inline]
 pub(in crate::high_level_api) fn with_internal_keys<T, F>(func: F) -> T
@@ -91,7 +254,9 @@ where
 {
     try_with_internal_keys(|maybe_key| {
         let key = maybe_key.ok_or(UninitializedServerKey).unwrap_display();
-        func(key)
+        check_operation_is_permitted(key);
+        enforce_memory_usage_cap();
+        account_operation(key, || func(key))
     })
 }
 
@@ -100,12 +265,11 @@ pub(in crate::high_level_api) fn try_with_internal_keys<T, F>(func: F) -> T
 where
     F: FnOnce(Option<&InternalServerKey>) -> T,
 {
-    // Should use `with_borrow` when its stabilized
-    INTERNAL_KEYS.with(|keys| {
-        let maybe_key = &*keys.borrow();
-        let key = maybe_key.as_ref();
-        func(key)
-    })
+    // The key is cloned (which only clones an Arc) rather than borrowed for the duration of the
+    // operation, as the thread may run a task of `fhe_join` while waiting for rayon tasks of the
+    // operation, and such tasks set the key of the thread
+    let maybe_key = INTERNAL_KEYS.with(|keys| keys.borrow().clone());
+    func(maybe_key.as_ref())
 }
 
 #[inline]
@@ -134,26 +298,103 @@ pub(in crate::high_level_api) fn tag_of_internal_server_key() -> crate::Result<c
     })
 }
 
+/// Checks the operation against the [OperationPolicy](crate::OperationPolicy) of the internal
+/// server key, if it has one
+///
+/// Nothing is checked if there is no internal server key, the operation will fail when trying to
+/// use it.
+///
+/// The internal keys can be used for the operation while the returned permit is alive.
+///
+/// # Panics
+///
+/// Panics if the policy does not permit the operation
+#[inline]
+pub(in crate::high_level_api) fn enforce_operation_policy(
+    kind: OperationKind,
+    num_bits: usize,
+    tag: &crate::Tag,
+) -> OperationPermit {
+    let policy = INTERNAL_KEYS.with(|keys| {
+        keys.borrow()
+            .as_ref()
+            .and_then(|key| key.operation_policy().cloned())
+    });
+    if let Some(policy) = policy {
+        policy.enforce(kind, num_bits, tag);
+    }
+    OperationPermit::new()
+}
+
+/// Permits an operation that only creates, moves or converts ciphertexts without computing on
+/// their values (e.g. a trivial encryption or a compression), which the
+/// [OperationPolicy](crate::OperationPolicy) of the server key does not restrict
+#[inline]
+pub(in crate::high_level_api) fn permit_unrestricted_operation() -> OperationPermit {
+    OperationPermit::new()
+}
+
+/// Refuses to use the key for an operation that was not permitted by [enforce_operation_policy] or
+/// [permit_unrestricted_operation], if the policy of the key only permits the operations having
+/// an [OperationKind]
+#[track_caller]
+#[inline]
+fn check_operation_is_permitted(key: &InternalServerKey) {
+    if let Some(policy) = key.operation_policy() {
+        assert!(
+            OperationPermit::is_active() || policy.permits_operations_without_kind(),
+            "This operation does not have an OperationKind, it is not allowed by the operation \
+            policy of the server key"
+        );
+    }
+}
+
+/// This returns whether the internal server key allows binary operations between integers of
+/// different widths (see
+/// [enable_implicit_widening](crate::ConfigBuilder::enable_implicit_widening))
+#[inline]
+pub(in crate::high_level_api) fn implicit_widening_of_internal_server_key() -> crate::Result<bool> {
+    INTERNAL_KEYS.with(|keys| {
+        let cell = keys.borrow();
+        Ok(match cell.as_ref().ok_or(UninitializedServerKey)? {
+            InternalServerKey::Cpu(cpu_key) => cpu_key.implicit_widening,
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(cuda_key) => cuda_key.implicit_widening,
+        })
+    })
+}
+
+/// Same as [with_internal_keys], for operations that only have a CPU implementation
+///
+/// # Panics
+///
+/// - Panics if the server key is not set or is not a CPU key
+/// - Panics if the memory usage exceeds the cap when the operation is not run by another operation
+///   nor by [try_with_memory_usage_cap](crate::try_with_memory_usage_cap) (see
+///   [set_memory_usage_cap](crate::set_memory_usage_cap))
+/// - Panics if the operation is not permitted by the [OperationPolicy](crate::OperationPolicy) of
+///   the server key
 #[inline]
 pub(crate) fn with_cpu_internal_keys<T, F>(func: F) -> T
 where
     F: FnOnce(&ServerKey) -> T,
 {
-    // Should use `with_borrow` when its stabilized
-    INTERNAL_KEYS.with(|keys| {
-        let maybe_key = &*keys.borrow();
-        let key = maybe_key
-            .as_ref()
-            .ok_or(UninitializedServerKey)
-            .unwrap_display();
-        match key {
-            InternalServerKey::Cpu(key) => func(key),
-            #[cfg(feature = "gpu")]
-            InternalServerKey::Cuda(_) => {
-                panic!("Cpu key requested but only cuda key is available")
-            }
+    // See `try_with_internal_keys` for why the key is cloned
+    let key = INTERNAL_KEYS
+        .with(|keys| keys.borrow().clone())
+        .ok_or(UninitializedServerKey)
+        .unwrap_display();
+    match &key {
+        InternalServerKey::Cpu(cpu_key) => {
+            check_operation_is_permitted(&key);
+            enforce_memory_usage_cap();
+            account_operation(&key, || func(cpu_key))
         }
-    })
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(_) => {
+            panic!("Cpu key requested but only cuda key is available")
+        }
+    }
 }
 
 #[cfg(feature = "gpu")]
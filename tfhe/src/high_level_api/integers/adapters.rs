@@ -0,0 +1,162 @@
+//! Newtype adapters making the overflow behaviour of integer arithmetic explicit in the type.
+//!
+//! They mirror [std::num::Wrapping] and the `checked_*` family of the standard library, so that
+//! the intended arithmetic semantics of a piece of code can be enforced during reviews.
+
+use crate::high_level_api::traits::{OverflowingAdd, OverflowingMul, OverflowingSub};
+use crate::FheBool;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+/// Integer with explicitly wrapping arithmetic
+///
+/// Operations on the wrapped integer are modular, i.e. on overflow the result wraps around.
+/// This is the same behaviour as the one of the plain [FheUint](crate::FheUint) and
+/// [FheInt](crate::FheInt) operators, the purpose of this type is to document it.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, Wrapping};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let a = Wrapping(FheUint16::encrypt(u16::MAX, &client_key));
+/// let b = Wrapping(FheUint16::encrypt(2u16, &client_key));
+///
+/// let result = &a + &b;
+/// let decrypted: u16 = result.0.decrypt(&client_key);
+/// assert_eq!(decrypted, u16::MAX.wrapping_add(2));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Wrapping<T>(pub T);
+
+/// Integer with explicitly checked arithmetic
+///
+/// Operations on the wrapped integer return the result alongside a [FheBool] encrypting `true`
+/// if the operation overflowed. The result itself is computed modularly.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, Checked, ConfigBuilder, FheUint16};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let a = Checked(FheUint16::encrypt(u16::MAX, &client_key));
+/// let b = Checked(FheUint16::encrypt(2u16, &client_key));
+///
+/// let (result, overflowed) = &a + &b;
+/// let decrypted: u16 = result.0.decrypt(&client_key);
+/// assert_eq!(decrypted, u16::MAX.wrapping_add(2));
+/// assert!(overflowed.decrypt(&client_key));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Checked<T>(pub T);
+
+macro_rules! impl_wrapping_op {
+    ($op:ident, $op_fn:ident, $op_assign:ident, $op_assign_fn:ident) => {
+        impl<T, B> $op<Wrapping<B>> for Wrapping<T>
+        where
+            T: $op<B>,
+        {
+            type Output = Wrapping<<T as $op<B>>::Output>;
+
+            fn $op_fn(self, rhs: Wrapping<B>) -> Self::Output {
+                Wrapping(self.0.$op_fn(rhs.0))
+            }
+        }
+
+        impl<'b, T, B> $op<&'b Wrapping<B>> for Wrapping<T>
+        where
+            T: $op<&'b B>,
+        {
+            type Output = Wrapping<<T as $op<&'b B>>::Output>;
+
+            fn $op_fn(self, rhs: &'b Wrapping<B>) -> Self::Output {
+                Wrapping(self.0.$op_fn(&rhs.0))
+            }
+        }
+
+        impl<'a, T, B> $op<Wrapping<B>> for &'a Wrapping<T>
+        where
+            &'a T: $op<B>,
+        {
+            type Output = Wrapping<<&'a T as $op<B>>::Output>;
+
+            fn $op_fn(self, rhs: Wrapping<B>) -> Self::Output {
+                Wrapping((&self.0).$op_fn(rhs.0))
+            }
+        }
+
+        impl<'a, 'b, T, B> $op<&'b Wrapping<B>> for &'a Wrapping<T>
+        where
+            &'a T: $op<&'b B>,
+        {
+            type Output = Wrapping<<&'a T as $op<&'b B>>::Output>;
+
+            fn $op_fn(self, rhs: &'b Wrapping<B>) -> Self::Output {
+                Wrapping((&self.0).$op_fn(&rhs.0))
+            }
+        }
+
+        impl<T, B> $op_assign<Wrapping<B>> for Wrapping<T>
+        where
+            T: $op_assign<B>,
+        {
+            fn $op_assign_fn(&mut self, rhs: Wrapping<B>) {
+                self.0.$op_assign_fn(rhs.0)
+            }
+        }
+
+        impl<'b, T, B> $op_assign<&'b Wrapping<B>> for Wrapping<T>
+        where
+            T: $op_assign<&'b B>,
+        {
+            fn $op_assign_fn(&mut self, rhs: &'b Wrapping<B>) {
+                self.0.$op_assign_fn(&rhs.0)
+            }
+        }
+    };
+}
+
+impl_wrapping_op!(Add, add, AddAssign, add_assign);
+impl_wrapping_op!(Sub, sub, SubAssign, sub_assign);
+impl_wrapping_op!(Mul, mul, MulAssign, mul_assign);
+
+macro_rules! impl_checked_op {
+    ($op:ident, $op_fn:ident, $overflowing_op:ident, $overflowing_op_fn:ident) => {
+        impl<'b, T, B> $op<&'b Checked<B>> for Checked<T>
+        where
+            T: $overflowing_op<&'b B>,
+        {
+            type Output = (Checked<<T as $overflowing_op<&'b B>>::Output>, FheBool);
+
+            fn $op_fn(self, rhs: &'b Checked<B>) -> Self::Output {
+                let (result, overflowed) = self.0.$overflowing_op_fn(&rhs.0);
+                (Checked(result), overflowed)
+            }
+        }
+
+        impl<'a, 'b, T, B> $op<&'b Checked<B>> for &'a Checked<T>
+        where
+            &'a T: $overflowing_op<&'b B>,
+        {
+            type Output = (Checked<<&'a T as $overflowing_op<&'b B>>::Output>, FheBool);
+
+            fn $op_fn(self, rhs: &'b Checked<B>) -> Self::Output {
+                let (result, overflowed) = (&self.0).$overflowing_op_fn(&rhs.0);
+                (Checked(result), overflowed)
+            }
+        }
+    };
+}
+
+impl_checked_op!(Add, add, OverflowingAdd, overflowing_add);
+impl_checked_op!(Sub, sub, OverflowingSub, overflowing_sub);
+impl_checked_op!(Mul, mul, OverflowingMul, overflowing_mul);
@@ -1,14 +1,16 @@
 expand_pub_use_fhe_type!(
     pub use unsigned{
         FheUint2, FheUint4, FheUint6, FheUint8, FheUint10, FheUint12, FheUint14, FheUint16,
-        FheUint32, FheUint64, FheUint128, FheUint160, FheUint256, FheUint512, FheUint1024, FheUint2048,
+        FheUint24, FheUint32, FheUint40, FheUint48, FheUint64, FheUint72, FheUint128,
+        FheUint160, FheUint256, FheUint512, FheUint1024, FheUint2048,
     };
 );
 
 expand_pub_use_fhe_type!(
     pub use signed{
         FheInt2, FheInt4, FheInt6, FheInt8, FheInt10, FheInt12, FheInt14, FheInt16,
-        FheInt32, FheInt64, FheInt128, FheInt160, FheInt256
+        FheInt24, FheInt32, FheInt40, FheInt48, FheInt64, FheInt72, FheInt128,
+        FheInt160, FheInt256, FheInt512, FheInt1024, FheInt2048,
     };
 );
 
@@ -23,10 +25,15 @@ pub(in crate::high_level_api) use unsigned::{
 // These are pub-exported so that their doc can appear in generated rust docs
 use crate::high_level_api::traits::FheId;
 use crate::shortint::MessageModulus;
+pub use adapters::{Checked, Wrapping};
 pub use signed::{CompressedFheInt, FheInt};
-pub use unsigned::{CompressedFheUint, FheUint};
+pub use unsigned::{
+    fhe_join_eq, fhe_join_eq_sum, CompressedFheUint, DynFheUint, FheAccumulator, FheUint,
+};
 
+mod adapters;
 pub mod oprf;
+mod saturating_ops;
 pub(super) mod signed;
 pub(super) mod unsigned;
 
@@ -80,6 +80,56 @@ impl<Id: FheUintId> FheUint<Id> {
             }
         })
     }
+
+    /// Generates an encrypted unsigned integer
+    /// taken uniformly in `[0, excluded_upper_bound[` using the given seed.
+    /// The encryted value is oblivious to the server.
+    /// It can be useful to make server random generation deterministic.
+    ///
+    /// `excluded_upper_bound` does not have to be a power of two, the statistical distance
+    /// between the generated value and the uniform distribution is less than 2^-64.
+    ///
+    /// ```rust
+    /// use tfhe::prelude::FheDecrypt;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8, Seed};
+    ///
+    /// let config = ConfigBuilder::default().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// // Roll a die
+    /// let ct_res = FheUint8::generate_oblivious_pseudo_random_in_range(Seed(0), 6);
+    ///
+    /// let dec_result: u8 = ct_res.decrypt(&client_key);
+    /// assert!(dec_result < 6);
+    /// ```
+    pub fn generate_oblivious_pseudo_random_in_range(
+        seed: Seed,
+        excluded_upper_bound: u64,
+    ) -> Self {
+        // 64 bits more than what is needed to represent the range
+        let random_bits_count = 64 + u64::from(u64::BITS - excluded_upper_bound.leading_zeros());
+
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(key) => {
+                let ct = key
+                    .pbs_key()
+                    .par_generate_oblivious_pseudo_random_unsigned_integer_in_range(
+                        seed,
+                        excluded_upper_bound,
+                        random_bits_count,
+                        Id::num_blocks(key.message_modulus()) as u64,
+                    );
+
+                Self::new(ct, key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                todo!("Cuda devices do not yet support oblivious pseudo random generation")
+            }
+        })
+    }
 }
 
 impl<Id: FheIntId> FheInt<Id> {
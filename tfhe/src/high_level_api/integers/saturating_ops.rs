@@ -0,0 +1,250 @@
+use crate::core_crypto::prelude::{SignedNumeric, UnsignedNumeric};
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::{FheIntId, FheUintId};
+use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::policy::OperationKind;
+use crate::integer::block_decomposition::DecomposableInto;
+use crate::prelude::{SaturatingAdd, SaturatingMul, SaturatingSub};
+use crate::{FheInt, FheUint};
+
+// Implements a saturating operation between two ciphertexts and between a ciphertext and a clear,
+// for the reference and owned forms of the ciphertext.
+//
+// The example of the doc computes `clear_type::example_lhs op example_rhs`, which saturates to
+// `clear_type::example_expected`.
+macro_rules! impl_saturating_operation (
+    (
+        fhe_type: $fhe_type:ident($id_trait:ident),
+        rust_trait: $rust_trait_name:ident($rust_trait_method:ident),
+        operation_kind: $kind:ident,
+        verb: $verb:literal,
+        saturation: $saturation_doc:literal,
+        example: $example_type:ident($clear_type:ident) {
+            $example_lhs:literal, $example_rhs:literal => $example_expected:literal
+        },
+        clear_bounds: [$($clear_bounds:tt)*],
+        implem: $method:ident, $scalar_method:ident
+        $(,)?
+    ) => {
+        impl<Id> $rust_trait_name<Self> for &$fhe_type<Id>
+        where
+            Id: $id_trait,
+        {
+            type Output = $fhe_type<Id>;
+
+            #[doc = concat!(
+                $verb, " two [", stringify!($fhe_type), "], saturating instead of wrapping around."
+            )]
+            #[doc = ""]
+            #[doc = concat!("* ", $saturation_doc)]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```rust"]
+            #[doc = "use tfhe::prelude::*;"]
+            #[doc = concat!(
+                "use tfhe::{generate_keys, set_server_key, ConfigBuilder, ",
+                stringify!($example_type), "};"
+            )]
+            #[doc = ""]
+            #[doc = "let (client_key, server_key) = generate_keys(ConfigBuilder::default());"]
+            #[doc = "set_server_key(server_key);"]
+            #[doc = ""]
+            #[doc = concat!(
+                "let a = ", stringify!($example_type), "::encrypt(",
+                stringify!($clear_type), "::", $example_lhs, ", &client_key);"
+            )]
+            #[doc = concat!(
+                "let b = ", stringify!($example_type), "::encrypt(",
+                $example_rhs, stringify!($clear_type), ", &client_key);"
+            )]
+            #[doc = ""]
+            #[doc = concat!("let result = (&a).", stringify!($rust_trait_method), "(&b);")]
+            #[doc = concat!(
+                "let result: ", stringify!($clear_type), " = result.decrypt(&client_key);"
+            )]
+            #[doc = concat!(
+                "assert_eq!(result, (", stringify!($clear_type), "::", $example_lhs, ").",
+                stringify!($rust_trait_method), "(", $example_rhs, stringify!($clear_type), "));"
+            )]
+            #[doc = concat!(
+                "assert_eq!(result, ", stringify!($clear_type), "::", $example_expected, ");"
+            )]
+            #[doc = "```"]
+            fn $rust_trait_method(self, other: Self) -> Self::Output {
+                let _permit = self.enforce_operation_policy(OperationKind::$kind);
+                global_state::with_internal_keys(|key| match key {
+                    InternalServerKey::Cpu(cpu_key) => {
+                        let result = cpu_key
+                            .pbs_key()
+                            .$method(&self.ciphertext.on_cpu(), &other.ciphertext.on_cpu());
+                        $fhe_type::new(result, cpu_key.tag.clone())
+                    }
+                    #[cfg(feature = "gpu")]
+                    InternalServerKey::Cuda(_) => {
+                        panic!(
+                            "Cuda devices do not support {} yet",
+                            stringify!($rust_trait_method)
+                        )
+                    }
+                })
+            }
+        }
+
+        impl<Id> $rust_trait_name<&Self> for $fhe_type<Id>
+        where
+            Id: $id_trait,
+        {
+            type Output = Self;
+
+            fn $rust_trait_method(self, other: &Self) -> Self::Output {
+                <&Self as $rust_trait_name<&Self>>::$rust_trait_method(&self, other)
+            }
+        }
+
+        impl<Id, Clear> $rust_trait_name<Clear> for &$fhe_type<Id>
+        where
+            Id: $id_trait,
+            Clear: $($clear_bounds)*,
+        {
+            type Output = $fhe_type<Id>;
+
+            #[doc = concat!(
+                $verb, " a [", stringify!($fhe_type), "] with a Clear, ",
+                "saturating instead of wrapping around."
+            )]
+            #[doc = ""]
+            #[doc = concat!("* ", $saturation_doc)]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```rust"]
+            #[doc = "use tfhe::prelude::*;"]
+            #[doc = concat!(
+                "use tfhe::{generate_keys, set_server_key, ConfigBuilder, ",
+                stringify!($example_type), "};"
+            )]
+            #[doc = ""]
+            #[doc = "let (client_key, server_key) = generate_keys(ConfigBuilder::default());"]
+            #[doc = "set_server_key(server_key);"]
+            #[doc = ""]
+            #[doc = concat!(
+                "let a = ", stringify!($example_type), "::encrypt(",
+                stringify!($clear_type), "::", $example_lhs, ", &client_key);"
+            )]
+            #[doc = ""]
+            #[doc = concat!(
+                "let result = (&a).", stringify!($rust_trait_method), "(",
+                $example_rhs, stringify!($clear_type), ");"
+            )]
+            #[doc = concat!(
+                "let result: ", stringify!($clear_type), " = result.decrypt(&client_key);"
+            )]
+            #[doc = concat!(
+                "assert_eq!(result, (", stringify!($clear_type), "::", $example_lhs, ").",
+                stringify!($rust_trait_method), "(", $example_rhs, stringify!($clear_type), "));"
+            )]
+            #[doc = concat!(
+                "assert_eq!(result, ", stringify!($clear_type), "::", $example_expected, ");"
+            )]
+            #[doc = "```"]
+            fn $rust_trait_method(self, other: Clear) -> Self::Output {
+                let _permit = self.enforce_operation_policy(OperationKind::$kind);
+                global_state::with_internal_keys(|key| match key {
+                    InternalServerKey::Cpu(cpu_key) => {
+                        let result = cpu_key
+                            .pbs_key()
+                            .$scalar_method(&self.ciphertext.on_cpu(), other);
+                        $fhe_type::new(result, cpu_key.tag.clone())
+                    }
+                    #[cfg(feature = "gpu")]
+                    InternalServerKey::Cuda(_) => {
+                        panic!(
+                            "Cuda devices do not support {} yet",
+                            stringify!($rust_trait_method)
+                        )
+                    }
+                })
+            }
+        }
+
+        impl<Id, Clear> $rust_trait_name<Clear> for $fhe_type<Id>
+        where
+            Id: $id_trait,
+            Clear: $($clear_bounds)*,
+        {
+            type Output = Self;
+
+            fn $rust_trait_method(self, other: Clear) -> Self::Output {
+                <&Self as $rust_trait_name<Clear>>::$rust_trait_method(&self, other)
+            }
+        }
+    }
+);
+
+impl_saturating_operation!(
+    fhe_type: FheUint(FheUintId),
+    rust_trait: SaturatingAdd(saturating_add),
+    operation_kind: Add,
+    verb: "Adds",
+    saturation: "On overflow the result is the maximum value of the type",
+    example: FheUint16(u16) { "MAX - 1", "2" => "MAX" },
+    clear_bounds: [UnsignedNumeric + DecomposableInto<u8> + DecomposableInto<u64>],
+    implem: unsigned_saturating_add_parallelized, unsigned_saturating_scalar_add_parallelized,
+);
+impl_saturating_operation!(
+    fhe_type: FheUint(FheUintId),
+    rust_trait: SaturatingSub(saturating_sub),
+    operation_kind: Sub,
+    verb: "Subtracts",
+    saturation: "On underflow the result is zero",
+    example: FheUint16(u16) { "MIN + 1", "2" => "MIN" },
+    clear_bounds: [UnsignedNumeric + DecomposableInto<u8> + DecomposableInto<u64>],
+    implem: unsigned_saturating_sub_parallelized, unsigned_saturating_scalar_sub_parallelized,
+);
+impl_saturating_operation!(
+    fhe_type: FheUint(FheUintId),
+    rust_trait: SaturatingMul(saturating_mul),
+    operation_kind: Mul,
+    verb: "Multiplies",
+    saturation: "On overflow the result is the maximum value of the type",
+    example: FheUint16(u16) { "MAX / 2", "3" => "MAX" },
+    clear_bounds: [UnsignedNumeric + DecomposableInto<u8> + DecomposableInto<u64>],
+    implem: unsigned_saturating_mul_parallelized, unsigned_saturating_scalar_mul_parallelized,
+);
+
+impl_saturating_operation!(
+    fhe_type: FheInt(FheIntId),
+    rust_trait: SaturatingAdd(saturating_add),
+    operation_kind: Add,
+    verb: "Adds",
+    saturation: "On overflow the result is clamped to the minimum or maximum value of the type",
+    example: FheInt16(i16) { "MAX - 1", "2" => "MAX" },
+    clear_bounds: [SignedNumeric + DecomposableInto<u8> + DecomposableInto<u64>],
+    implem: signed_saturating_add_parallelized, signed_saturating_scalar_add_parallelized,
+);
+impl_saturating_operation!(
+    fhe_type: FheInt(FheIntId),
+    rust_trait: SaturatingSub(saturating_sub),
+    operation_kind: Sub,
+    verb: "Subtracts",
+    saturation: "On overflow the result is clamped to the minimum or maximum value of the type",
+    example: FheInt16(i16) { "MIN + 1", "2" => "MIN" },
+    clear_bounds: [
+        SignedNumeric
+            + DecomposableInto<u8>
+            + DecomposableInto<u64>
+            + std::ops::Not<Output = Clear>
+    ],
+    implem: signed_saturating_sub_parallelized, signed_saturating_scalar_sub_parallelized,
+);
+impl_saturating_operation!(
+    fhe_type: FheInt(FheIntId),
+    rust_trait: SaturatingMul(saturating_mul),
+    operation_kind: Mul,
+    verb: "Multiplies",
+    saturation: "On overflow the result is clamped to the minimum or maximum value of the type",
+    example: FheInt16(i16) { "MIN / 2", "3" => "MIN" },
+    clear_bounds: [SignedNumeric + DecomposableInto<u8> + DecomposableInto<u64>],
+    implem: signed_saturating_mul_parallelized, signed_saturating_scalar_mul_parallelized,
+);
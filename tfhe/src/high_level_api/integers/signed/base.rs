@@ -6,11 +6,14 @@ use crate::conformance::ParameterSetConformant;
 use crate::high_level_api::global_state;
 use crate::high_level_api::integers::{FheUint, FheUintId, IntegerId};
 use crate::high_level_api::keys::InternalServerKey;
-use crate::high_level_api::traits::Tagged;
+use crate::high_level_api::policy::{OperationKind, OperationPermit};
+use crate::high_level_api::traits::{OverflowingCastFrom, SaturatingCastFrom, Tagged};
+use crate::high_level_api::utils::block_bytes;
 use crate::integer::client_key::RecomposableSignedInteger;
 use crate::integer::parameters::RadixCiphertextConformanceParams;
 use crate::named::Named;
 use crate::prelude::CastFrom;
+use crate::safe_serialization::{SerializedSizeBound, OBJECT_METADATA_SIZE_BOUND};
 use crate::shortint::ciphertext::NotTrivialCiphertextError;
 use crate::shortint::PBSParameters;
 use crate::{Device, FheBool, ServerKey, Tag};
@@ -18,7 +21,10 @@ use std::marker::PhantomData;
 
 #[cfg(feature = "gpu")]
 use crate::high_level_api::global_state::with_thread_local_cuda_streams;
-pub trait FheIntId: IntegerId {}
+pub trait FheIntId: IntegerId {
+    /// Id of the unsigned integer type with the same number of bits
+    type UnsignedId: FheUintId;
+}
 
 /// A Generic FHE signed integer
 ///
@@ -43,11 +49,20 @@ pub struct FheInt<Id: FheIntId> {
 }
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
 pub struct FheIntConformanceParams<Id: FheIntId> {
     pub(crate) params: RadixCiphertextConformanceParams,
+    #[cfg_attr(feature = "json", serde(skip))]
     pub(crate) id: PhantomData<Id>,
 }
 
+#[cfg(feature = "json")]
+impl<Id: FheIntId> crate::json::ToJsonPretty for FheIntConformanceParams<Id> {
+    fn to_json_pretty(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
 impl<Id: FheIntId, P: Into<PBSParameters>> From<P> for FheIntConformanceParams<Id> {
     fn from(params: P) -> Self {
         let params = params.into();
@@ -87,6 +102,13 @@ impl<Id: FheIntId> ParameterSetConformant for FheInt<Id> {
     }
 }
 
+impl<Id: FheIntId> SerializedSizeBound for FheInt<Id> {
+    fn serialized_size_bound(params: &FheIntConformanceParams<Id>) -> u64 {
+        crate::integer::SignedRadixCiphertext::serialized_size_bound(&params.params)
+            + OBJECT_METADATA_SIZE_BOUND
+    }
+}
+
 impl<Id: FheIntId> Named for FheInt<Id> {
     const NAME: &'static str = "high_level_api::FheInt";
 }
@@ -141,6 +163,15 @@ where
         Id::num_bits()
     }
 
+    /// Checks that the [OperationPolicy](crate::OperationPolicy) of the server key, if any,
+    /// permits the operation on this [FheInt]
+    pub(in crate::high_level_api) fn enforce_operation_policy(
+        &self,
+        kind: OperationKind,
+    ) -> OperationPermit {
+        global_state::enforce_operation_policy(kind, Self::num_bits(), &self.tag)
+    }
+
     /// Moves (in-place) the ciphertext to the desired device.
     ///
     /// Does nothing if the ciphertext is already in the desired device
@@ -193,6 +224,325 @@ where
         })
     }
 
+    /// Returns a FheInt encrypting the sign of `self`
+    ///
+    /// The result encrypts -1 if `self` is negative, 0 if it is zero, and 1 if it is positive,
+    /// like [i64::signum].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// for clear in [-3i16, 0, 3] {
+    ///     let a = FheInt16::encrypt(clear, &client_key);
+    ///     let result: i16 = a.signum().decrypt(&client_key);
+    ///     assert_eq!(result, clear.signum());
+    /// }
+    /// ```
+    pub fn signum(&self) -> Self {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let ciphertext = cpu_key
+                    .pbs_key()
+                    .signum_parallelized(&*self.ciphertext.on_cpu());
+                Self::new(ciphertext, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support signum yet")
+            }
+        })
+    }
+
+    /// Returns the absolute value of `self`, as an unsigned integer of the same size
+    ///
+    /// Unlike [Self::abs], the absolute value of the minimum can be represented,
+    /// like with [i64::unsigned_abs].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-3i16, &client_key);
+    /// let result: FheUint16 = a.unsigned_abs();
+    /// let result: u16 = result.decrypt(&client_key);
+    /// assert_eq!(result, 3u16);
+    ///
+    /// let a = FheInt16::encrypt(i16::MIN, &client_key);
+    /// let result: u16 = a.unsigned_abs().decrypt(&client_key);
+    /// assert_eq!(result, i16::MIN.unsigned_abs());
+    /// ```
+    pub fn unsigned_abs(&self) -> FheUint<Id::UnsignedId> {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let ciphertext = cpu_key
+                    .pbs_key()
+                    .unsigned_abs_parallelized(&*self.ciphertext.on_cpu());
+                FheUint::new(ciphertext, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support unsigned_abs yet")
+            }
+        })
+    }
+
+    /// Computes the quotient of the Euclidean division of `self` by `rhs`
+    ///
+    /// The quotient is such that the remainder, see [Self::rem_euclid], is never negative, like
+    /// with [i64::div_euclid]. When the divisor is 0, the result should not be relied on.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-23i16, &client_key);
+    /// let b = FheInt16::encrypt(3i16, &client_key);
+    ///
+    /// let result: i16 = a.div_euclid(&b).decrypt(&client_key);
+    /// assert_eq!(result, (-23i16).div_euclid(3));
+    /// ```
+    pub fn div_euclid(&self, rhs: &Self) -> Self {
+        let _permit = self.enforce_operation_policy(OperationKind::Div);
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let (q, _) = cpu_key.pbs_key().div_rem_euclid_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    &*rhs.ciphertext.on_cpu(),
+                );
+                Self::new(q, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices does not support division yet")
+            }
+        })
+    }
+
+    /// Computes the remainder of the Euclidean division of `self` by `rhs`
+    ///
+    /// The remainder is never negative, like with [i64::rem_euclid], which is what modular
+    /// indexing needs. When the divisor is 0, the result should not be relied on.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-23i16, &client_key);
+    /// let b = FheInt16::encrypt(-3i16, &client_key);
+    ///
+    /// let result: i16 = a.rem_euclid(&b).decrypt(&client_key);
+    /// assert_eq!(result, (-23i16).rem_euclid(-3));
+    /// ```
+    pub fn rem_euclid(&self, rhs: &Self) -> Self {
+        let _permit = self.enforce_operation_policy(OperationKind::Rem);
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let (_, r) = cpu_key.pbs_key().div_rem_euclid_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    &*rhs.ciphertext.on_cpu(),
+                );
+                Self::new(r, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices does not support division yet")
+            }
+        })
+    }
+
+    /// Computes both the quotient and the remainder of the Euclidean division of `self` by `rhs`
+    ///
+    /// If both are needed, this is cheaper than calling [Self::div_euclid] and
+    /// [Self::rem_euclid], as the division is only computed once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-23i16, &client_key);
+    /// let b = FheInt16::encrypt(3i16, &client_key);
+    ///
+    /// let (q, r) = a.div_rem_euclid(&b);
+    /// let q: i16 = q.decrypt(&client_key);
+    /// let r: i16 = r.decrypt(&client_key);
+    /// assert_eq!(q, (-23i16).div_euclid(3));
+    /// assert_eq!(r, (-23i16).rem_euclid(3));
+    /// ```
+    pub fn div_rem_euclid(&self, rhs: &Self) -> (Self, Self) {
+        let _permit = self.enforce_operation_policy(OperationKind::DivRem);
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let (q, r) = cpu_key.pbs_key().div_rem_euclid_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    &*rhs.ciphertext.on_cpu(),
+                );
+                (
+                    Self::new(q, cpu_key.tag.clone()),
+                    Self::new(r, cpu_key.tag.clone()),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices does not support division yet")
+            }
+        })
+    }
+
+    /// Computes the quotient of the division of `self` by `rhs`, rounded towards negative
+    /// infinity
+    ///
+    /// The `/` operator rounds the quotient towards zero. When the divisor is 0, the result
+    /// should not be relied on.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-23i16, &client_key);
+    /// let b = FheInt16::encrypt(3i16, &client_key);
+    ///
+    /// let result: i16 = a.div_floor(&b).decrypt(&client_key);
+    /// assert_eq!(result, -8i16);
+    /// ```
+    pub fn div_floor(&self, rhs: &Self) -> Self {
+        let _permit = self.enforce_operation_policy(OperationKind::Div);
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let (q, _) = cpu_key.pbs_key().div_rem_floor_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    &*rhs.ciphertext.on_cpu(),
+                );
+                Self::new(q, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices does not support division yet")
+            }
+        })
+    }
+
+    /// Computes the quotient of the division of `self` by `rhs`, along with a FheBool that
+    /// encrypts `true` if the divisor is zero
+    ///
+    /// This allows to propagate the division by zero error homomorphically, instead of relying
+    /// on the value the quotient has in that case.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-23i16, &client_key);
+    /// let b = FheInt16::encrypt(3i16, &client_key);
+    ///
+    /// let (result, is_divisor_zero) = a.checked_div(&b);
+    ///
+    /// let is_divisor_zero = is_divisor_zero.decrypt(&client_key);
+    /// assert!(!is_divisor_zero);
+    ///
+    /// let decrypted: i16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, -23i16 / 3);
+    /// ```
+    pub fn checked_div(&self, rhs: &Self) -> (Self, FheBool) {
+        let _permit = self.enforce_operation_policy(OperationKind::Div);
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let (q, is_divisor_zero) = cpu_key.pbs_key().checked_div_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    &*rhs.ciphertext.on_cpu(),
+                );
+                (
+                    Self::new(q, cpu_key.tag.clone()),
+                    FheBool::new(is_divisor_zero, cpu_key.tag.clone()),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices does not support division yet")
+            }
+        })
+    }
+
+    /// Computes the remainder of the division of `self` by `rhs`, along with a FheBool that
+    /// encrypts `true` if the divisor is zero
+    ///
+    /// When the divisor is zero, the remainder is the numerator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-23i16, &client_key);
+    /// let b = FheInt16::encrypt(0i16, &client_key);
+    ///
+    /// let (result, is_divisor_zero) = a.checked_rem(&b);
+    ///
+    /// let is_divisor_zero = is_divisor_zero.decrypt(&client_key);
+    /// assert!(is_divisor_zero);
+    ///
+    /// let decrypted: i16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, -23i16);
+    /// ```
+    pub fn checked_rem(&self, rhs: &Self) -> (Self, FheBool) {
+        let _permit = self.enforce_operation_policy(OperationKind::Rem);
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let (r, is_divisor_zero) = cpu_key.pbs_key().checked_rem_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    &*rhs.ciphertext.on_cpu(),
+                );
+                (
+                    Self::new(r, cpu_key.tag.clone()),
+                    FheBool::new(is_divisor_zero, cpu_key.tag.clone()),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices does not support division yet")
+            }
+        })
+    }
+
     /// Returns a FheBool that encrypts `true` if the value is even
     ///
     /// # Example
@@ -229,7 +579,80 @@ where
         })
     }
 
-    /// Returns a FheBool that encrypts `true` if the value is odd
+    /// Returns a FheBool that encrypts `true` if the value is odd
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(1i16, &client_key);
+    ///
+    /// let result = a.is_odd();
+    /// let decrypted = result.decrypt(&client_key);
+    /// assert!(decrypted);
+    /// ```
+    pub fn is_odd(&self) -> FheBool {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .is_odd_parallelized(&*self.ciphertext.on_cpu());
+                FheBool::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(cuda_key) => with_thread_local_cuda_streams(|streams| {
+                let result = cuda_key.key.key.is_odd(&*self.ciphertext.on_gpu(), streams);
+                FheBool::new(result, cuda_key.tag.clone())
+            }),
+        })
+    }
+
+    /// Returns a FheBool that encrypts `true` if the value is zero
+    ///
+    /// This is cheaper than comparing with zero using `eq`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(0i16, &client_key);
+    ///
+    /// let result = a.is_zero();
+    /// let decrypted = result.decrypt(&client_key);
+    /// assert!(decrypted);
+    /// ```
+    pub fn is_zero(&self) -> FheBool {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .is_zero_parallelized(&*self.ciphertext.on_cpu());
+                FheBool::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(cuda_key) => with_thread_local_cuda_streams(|streams| {
+                let result = cuda_key
+                    .key
+                    .key
+                    .scalar_eq(&*self.ciphertext.on_gpu(), 0i64, streams);
+                FheBool::new(result, cuda_key.tag.clone())
+            }),
+        })
+    }
+
+    /// Returns a FheBool that encrypts `true` if the value is not zero
+    ///
+    /// This is cheaper than comparing with zero using `ne`
     ///
     /// # Example
     ///
@@ -240,23 +663,26 @@ where
     /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
     /// set_server_key(server_key);
     ///
-    /// let a = FheInt16::encrypt(1i16, &client_key);
+    /// let a = FheInt16::encrypt(42i16, &client_key);
     ///
-    /// let result = a.is_odd();
+    /// let result = a.is_nonzero();
     /// let decrypted = result.decrypt(&client_key);
     /// assert!(decrypted);
     /// ```
-    pub fn is_odd(&self) -> FheBool {
+    pub fn is_nonzero(&self) -> FheBool {
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
                     .pbs_key()
-                    .is_odd_parallelized(&*self.ciphertext.on_cpu());
+                    .is_nonzero_parallelized(&*self.ciphertext.on_cpu());
                 FheBool::new(result, cpu_key.tag.clone())
             }
             #[cfg(feature = "gpu")]
             InternalServerKey::Cuda(cuda_key) => with_thread_local_cuda_streams(|streams| {
-                let result = cuda_key.key.key.is_odd(&*self.ciphertext.on_gpu(), streams);
+                let result = cuda_key
+                    .key
+                    .key
+                    .scalar_ne(&*self.ciphertext.on_gpu(), 0i64, streams);
                 FheBool::new(result, cuda_key.tag.clone())
             }),
         })
@@ -280,6 +706,7 @@ where
     /// assert_eq!(decrypted, 0);
     /// ```
     pub fn leading_zeros(&self) -> crate::FheUint32 {
+        let _permit = self.enforce_operation_policy(OperationKind::BitCount);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -325,6 +752,7 @@ where
     /// assert_eq!(decrypted, 16);
     /// ```
     pub fn leading_ones(&self) -> crate::FheUint32 {
+        let _permit = self.enforce_operation_policy(OperationKind::BitCount);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -370,6 +798,7 @@ where
     /// assert_eq!(decrypted, 2);
     /// ```
     pub fn trailing_zeros(&self) -> crate::FheUint32 {
+        let _permit = self.enforce_operation_policy(OperationKind::BitCount);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -415,6 +844,7 @@ where
     /// assert_eq!(decrypted, 2);
     /// ```
     pub fn trailing_ones(&self) -> crate::FheUint32 {
+        let _permit = self.enforce_operation_policy(OperationKind::BitCount);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -461,6 +891,7 @@ where
     /// assert_eq!(decrypted, clear_a.count_ones());
     /// ```
     pub fn count_ones(&self) -> crate::FheUint32 {
+        let _permit = self.enforce_operation_policy(OperationKind::BitCount);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -498,6 +929,7 @@ where
     /// assert_eq!(decrypted, clear_a.count_zeros());
     /// ```
     pub fn count_zeros(&self) -> crate::FheUint32 {
+        let _permit = self.enforce_operation_policy(OperationKind::BitCount);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -536,6 +968,7 @@ where
     /// assert_eq!(decrypted, 1);
     /// ```
     pub fn ilog2(&self) -> crate::FheUint32 {
+        let _permit = self.enforce_operation_policy(OperationKind::BitCount);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -584,6 +1017,7 @@ where
     /// assert_eq!(decrypted, 15); // result is meaningless
     /// ```
     pub fn checked_ilog2(&self) -> (crate::FheUint32, FheBool) {
+        let _permit = self.enforce_operation_policy(OperationKind::BitCount);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let (result, is_ok) = cpu_key
@@ -655,6 +1089,35 @@ where
         self.ciphertext.on_cpu().decrypt_trivial()
     }
 
+    /// Returns the bytes of each block of the ciphertext
+    ///
+    /// The bytes are the ones of the LWE ciphertexts of the blocks, with their coefficients in
+    /// little endian, so they do not depend on the platform nor on the serialization format.
+    /// Identical ciphertexts (e.g. the same trivial constant) have the same bytes, which
+    /// lets storage layers hash and deduplicate ciphertexts without serializing them.
+    ///
+    /// The metadata of the blocks (degree, noise level) are not included.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt_trivial(-3i16);
+    /// let b = FheInt16::encrypt_trivial(-3i16);
+    /// let c = FheInt16::encrypt(-3i16, &client_key);
+    ///
+    /// assert_eq!(a.as_block_bytes(), b.as_block_bytes());
+    /// assert_ne!(a.as_block_bytes(), c.as_block_bytes());
+    /// ```
+    pub fn as_block_bytes(&self) -> Vec<Vec<u8>> {
+        block_bytes(&self.ciphertext.on_cpu().blocks)
+    }
+
     /// Reverse the bit of the signed integer
     ///
     /// # Example
@@ -690,6 +1153,235 @@ where
             }
         })
     }
+
+    /// Reverse the byte order of the signed integer
+    ///
+    /// When the parameters allow it (i.e. a byte is made of a whole number of blocks),
+    /// this is a pure permutation of the blocks and no PBS is computed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of bits of the type is not a multiple of 8.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let msg = 0x12_34_i16;
+    ///
+    /// let a = FheInt16::encrypt(msg, &client_key);
+    ///
+    /// let result: FheInt16 = a.swap_bytes();
+    ///
+    /// let decrypted: i16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, msg.swap_bytes());
+    /// ```
+    pub fn swap_bytes(&self) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sk = &cpu_key.pbs_key();
+
+                let ct = self.ciphertext.on_cpu();
+
+                Self::new(sk.swap_bytes_parallelized(&*ct), cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(cuda_key) => with_thread_local_cuda_streams(|streams| {
+                let result = cuda_key
+                    .key
+                    .key
+                    .swap_bytes(&*self.ciphertext.on_gpu(), streams);
+                Self::new(result, cuda_key.tag.clone())
+            }),
+        })
+    }
+
+    /// Rotates the blocks of the signed integer to the left by `n` blocks
+    ///
+    /// This is equivalent to rotating the bits to the left by `n * bits_per_block`,
+    /// where `bits_per_block` is the number of message bits of the parameters,
+    /// but as it is a pure permutation of the blocks, no PBS is computed
+    /// (unless the input has carries that need to be propagated first).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let msg = 0x12_34_i16;
+    ///
+    /// let a = FheInt16::encrypt(msg, &client_key);
+    ///
+    /// // Default parameters have 2 bits of message per block
+    /// let result: FheInt16 = a.rotate_blocks(1);
+    ///
+    /// let decrypted: i16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, msg.rotate_left(2));
+    /// ```
+    pub fn rotate_blocks(&self, n: usize) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sk = &cpu_key.pbs_key();
+
+                let ct = self.ciphertext.on_cpu();
+
+                Self::new(sk.rotate_blocks(&*ct, n), cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(cuda_key) => with_thread_local_cuda_streams(|streams| {
+                // Rotating by a whole number of blocks only moves the blocks
+                let num_blocks = Id::num_blocks(cuda_key.message_modulus());
+                let bits_per_block = cuda_key.message_modulus().0.ilog2();
+                let shift = (n % num_blocks) as u32 * bits_per_block;
+                let result =
+                    cuda_key
+                        .key
+                        .key
+                        .scalar_rotate_left(&*self.ciphertext.on_gpu(), shift, streams);
+                Self::new(result, cuda_key.tag.clone())
+            }),
+        })
+    }
+
+    /// Splits the signed integer into its bytes, in little endian order
+    ///
+    /// Like with [i32::to_le_bytes], the bytes are unsigned and the sign is held by the most
+    /// significant one. The blocks of the integer are regrouped into bytes, so no PBS is computed
+    /// (unless the input has carries that need to be propagated first).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of bits of the type is not a multiple of 8.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt32};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let msg = -0x12_34_56_78_i32;
+    ///
+    /// let a = FheInt32::encrypt(msg, &client_key);
+    ///
+    /// let bytes = a.to_le_bytes();
+    /// let decrypted = bytes
+    ///     .iter()
+    ///     .map(|byte| byte.decrypt(&client_key))
+    ///     .collect::<Vec<u8>>();
+    /// assert_eq!(decrypted, msg.to_le_bytes());
+    /// ```
+    pub fn to_le_bytes(&self) -> Vec<crate::FheUint8> {
+        assert_eq!(
+            Id::num_bits() % 8,
+            0,
+            "The number of bits of the type must be a multiple of 8"
+        );
+
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sk = cpu_key.pbs_key();
+
+                let mut ct = self.ciphertext.on_cpu().into_owned();
+                if !ct.block_carries_are_empty() {
+                    sk.full_propagate_parallelized(&mut ct);
+                }
+
+                let blocks_per_byte = crate::FheUint8Id::num_blocks(sk.message_modulus());
+                ct.blocks
+                    .chunks_exact(blocks_per_byte)
+                    .map(|byte_blocks| {
+                        crate::FheUint8::new(
+                            crate::integer::RadixCiphertext::from(byte_blocks.to_vec()),
+                            cpu_key.tag.clone(),
+                        )
+                    })
+                    .collect()
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support to_le_bytes yet");
+            }
+        })
+    }
+
+    /// Creates a signed integer from its bytes, in little endian order
+    ///
+    /// Like with [i32::from_le_bytes], the bytes are unsigned and the sign is held by the most
+    /// significant one. The blocks of the bytes are regrouped into the integer, so no PBS is
+    /// computed (unless the inputs have carries that need to be propagated first).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of bytes does not match the number of bits of the type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt32, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let msg = -0x12_34_56_78_i32;
+    ///
+    /// let bytes = msg
+    ///     .to_le_bytes()
+    ///     .iter()
+    ///     .map(|&byte| FheUint8::encrypt(byte, &client_key))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let a = FheInt32::from_le_bytes(&bytes);
+    /// let decrypted: i32 = a.decrypt(&client_key);
+    /// assert_eq!(decrypted, msg);
+    /// ```
+    pub fn from_le_bytes(bytes: &[crate::FheUint8]) -> Self {
+        assert_eq!(
+            bytes.len() * 8,
+            Id::num_bits(),
+            "Expected {} bytes, got {}",
+            Id::num_bits() / 8,
+            bytes.len()
+        );
+
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sk = cpu_key.pbs_key();
+
+                let blocks = bytes
+                    .iter()
+                    .flat_map(|byte| {
+                        let mut ct = byte.ciphertext.on_cpu().into_owned();
+                        if !ct.block_carries_are_empty() {
+                            sk.full_propagate_parallelized(&mut ct);
+                        }
+                        ct.blocks
+                    })
+                    .collect::<Vec<_>>();
+
+                Self::new(
+                    crate::integer::SignedRadixCiphertext::from(blocks),
+                    cpu_key.tag.clone(),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support from_le_bytes yet");
+            }
+        })
+    }
 }
 
 impl<FromId, IntoId> CastFrom<FheInt<FromId>> for FheInt<IntoId>
@@ -715,6 +1407,11 @@ where
     /// assert_eq!(decrypted, i32::MAX as i16);
     /// ```
     fn cast_from(input: FheInt<FromId>) -> Self {
+        let _permit = global_state::enforce_operation_policy(
+            OperationKind::Cast,
+            Self::num_bits().max(FromId::num_bits()),
+            &input.tag,
+        );
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let target_num_blocks = IntoId::num_blocks(cpu_key.message_modulus());
@@ -760,6 +1457,11 @@ where
     /// assert_eq!(decrypted, u32::MAX as i16);
     /// ```
     fn cast_from(input: FheUint<FromId>) -> Self {
+        let _permit = global_state::enforce_operation_policy(
+            OperationKind::Cast,
+            Self::num_bits().max(FromId::num_bits()),
+            &input.tag,
+        );
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let new_ciphertext = cpu_key.pbs_key().cast_to_signed(
@@ -781,6 +1483,190 @@ where
     }
 }
 
+impl<FromId, IntoId> SaturatingCastFrom<FheInt<FromId>> for FheInt<IntoId>
+where
+    FromId: FheIntId,
+    IntoId: FheIntId,
+{
+    /// Cast a FheInt to another FheInt, clamping the values that do not fit
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16, FheInt8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-1000i16, &client_key);
+    /// let b = FheInt8::saturating_cast_from(a);
+    ///
+    /// let decrypted: i8 = b.decrypt(&client_key);
+    /// assert_eq!(decrypted, i8::MIN);
+    /// ```
+    fn saturating_cast_from(input: FheInt<FromId>) -> Self {
+        let _permit = global_state::enforce_operation_policy(
+            OperationKind::Cast,
+            Self::num_bits().max(FromId::num_bits()),
+            &input.tag,
+        );
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let casted = cpu_key.pbs_key().saturating_cast_to_signed(
+                    input.ciphertext.into_cpu(),
+                    IntoId::num_blocks(cpu_key.message_modulus()),
+                );
+                Self::new(casted, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support saturating casts yet");
+            }
+        })
+    }
+}
+
+impl<FromId, IntoId> SaturatingCastFrom<FheUint<FromId>> for FheInt<IntoId>
+where
+    FromId: FheUintId,
+    IntoId: FheIntId,
+{
+    /// Cast a FheUint to a FheInt, clamping the values that do not fit
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt8, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(1000u16, &client_key);
+    /// let b = FheInt8::saturating_cast_from(a);
+    ///
+    /// let decrypted: i8 = b.decrypt(&client_key);
+    /// assert_eq!(decrypted, i8::MAX);
+    /// ```
+    fn saturating_cast_from(input: FheUint<FromId>) -> Self {
+        let _permit = global_state::enforce_operation_policy(
+            OperationKind::Cast,
+            Self::num_bits().max(FromId::num_bits()),
+            &input.tag,
+        );
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let casted = cpu_key.pbs_key().saturating_cast_to_signed(
+                    input.ciphertext.on_cpu().to_owned(),
+                    IntoId::num_blocks(cpu_key.message_modulus()),
+                );
+                Self::new(casted, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support saturating casts yet");
+            }
+        })
+    }
+}
+
+impl<FromId, IntoId> OverflowingCastFrom<FheInt<FromId>> for FheInt<IntoId>
+where
+    FromId: FheIntId,
+    IntoId: FheIntId,
+{
+    /// Cast a FheInt to another FheInt, also returning whether the value did not fit
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16, FheInt8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-100i16, &client_key);
+    /// let (b, overflowed) = FheInt8::overflowing_cast_from(a);
+    ///
+    /// let decrypted: i8 = b.decrypt(&client_key);
+    /// assert_eq!(decrypted, -100i8);
+    /// assert_eq!(overflowed.decrypt(&client_key), false);
+    /// ```
+    fn overflowing_cast_from(input: FheInt<FromId>) -> (Self, FheBool) {
+        let _permit = global_state::enforce_operation_policy(
+            OperationKind::Cast,
+            Self::num_bits().max(FromId::num_bits()),
+            &input.tag,
+        );
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let (casted, overflowed) = cpu_key.pbs_key().overflowing_cast_to_signed(
+                    input.ciphertext.into_cpu(),
+                    IntoId::num_blocks(cpu_key.message_modulus()),
+                );
+                (
+                    Self::new(casted, cpu_key.tag.clone()),
+                    FheBool::new(overflowed, cpu_key.tag.clone()),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support overflowing casts yet");
+            }
+        })
+    }
+}
+
+impl<FromId, IntoId> OverflowingCastFrom<FheUint<FromId>> for FheInt<IntoId>
+where
+    FromId: FheUintId,
+    IntoId: FheIntId,
+{
+    /// Cast a FheUint to a FheInt, also returning whether the value did not fit
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt8, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(200u16, &client_key);
+    /// let (b, overflowed) = FheInt8::overflowing_cast_from(a);
+    ///
+    /// let decrypted: i8 = b.decrypt(&client_key);
+    /// assert_eq!(decrypted, 200u16 as i8);
+    /// assert_eq!(overflowed.decrypt(&client_key), true);
+    /// ```
+    fn overflowing_cast_from(input: FheUint<FromId>) -> (Self, FheBool) {
+        let _permit = global_state::enforce_operation_policy(
+            OperationKind::Cast,
+            Self::num_bits().max(FromId::num_bits()),
+            &input.tag,
+        );
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let (casted, overflowed) = cpu_key.pbs_key().overflowing_cast_to_signed(
+                    input.ciphertext.on_cpu().to_owned(),
+                    IntoId::num_blocks(cpu_key.message_modulus()),
+                );
+                (
+                    Self::new(casted, cpu_key.tag.clone()),
+                    FheBool::new(overflowed, cpu_key.tag.clone()),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support overflowing casts yet");
+            }
+        })
+    }
+}
+
 impl<Id> CastFrom<FheBool> for FheInt<Id>
 where
     Id: FheIntId,
@@ -803,6 +1689,11 @@ where
     /// assert_eq!(decrypted, i16::from(true));
     /// ```
     fn cast_from(input: FheBool) -> Self {
+        let _permit = global_state::enforce_operation_policy(
+            OperationKind::Cast,
+            Self::num_bits(),
+            &input.tag,
+        );
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let ciphertext = input
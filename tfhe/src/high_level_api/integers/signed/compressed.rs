@@ -5,7 +5,7 @@ use crate::backward_compatibility::integers::{
 };
 use crate::conformance::ParameterSetConformant;
 use crate::core_crypto::prelude::SignedNumeric;
-use crate::high_level_api::global_state::with_cpu_internal_keys;
+use crate::high_level_api::global_state::{permit_unrestricted_operation, with_cpu_internal_keys};
 use crate::high_level_api::integers::signed::base::FheIntConformanceParams;
 use crate::high_level_api::integers::{FheInt, FheIntId};
 use crate::high_level_api::traits::Tagged;
@@ -107,6 +107,7 @@ where
     ///
     /// See [CompressedFheInt] example.
     pub fn decompress(&self) -> FheInt<Id> {
+        let _permit = permit_unrestricted_operation();
         let ciphertext = match &self.ciphertext {
             CompressedSignedRadixCiphertext::Seeded(ct) => ct.decompress(),
             CompressedSignedRadixCiphertext::ModulusSwitched(ct) => {
@@ -175,6 +176,7 @@ where
     Id: FheIntId,
 {
     pub fn compress(&self) -> CompressedFheInt<Id> {
+        let _permit = permit_unrestricted_operation();
         let a = with_cpu_internal_keys(|sk| {
             sk.pbs_key()
                 .switch_modulus_and_compress_signed_parallelized(&self.ciphertext.on_cpu())
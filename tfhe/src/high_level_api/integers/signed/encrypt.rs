@@ -3,8 +3,10 @@ use crate::high_level_api::global_state;
 use crate::high_level_api::integers::FheIntId;
 use crate::integer::block_decomposition::DecomposableInto;
 use crate::integer::client_key::RecomposableSignedInteger;
-use crate::prelude::{FheDecrypt, FheTrivialEncrypt, FheTryEncrypt, FheTryTrivialEncrypt};
-use crate::{ClientKey, CompressedPublicKey, FheInt, PublicKey};
+use crate::prelude::{
+    FheDecrypt, FheDecryptWith, FheTrivialEncrypt, FheTryEncrypt, FheTryTrivialEncrypt,
+};
+use crate::{ClientKey, CompressedPublicKey, DecryptionKey, FheInt, PublicKey};
 
 impl<Id, ClearType> FheDecrypt<ClearType> for FheInt<Id>
 where
@@ -38,6 +40,16 @@ where
     }
 }
 
+impl<Id, ClearType> FheDecryptWith<ClearType, DecryptionKey> for FheInt<Id>
+where
+    Id: FheIntId,
+    ClearType: RecomposableSignedInteger,
+{
+    fn decrypt_with(&self, key: &DecryptionKey) -> ClearType {
+        key.key.decrypt_signed_radix(&self.ciphertext.on_cpu())
+    }
+}
+
 impl<Id, T> FheTryEncrypt<T, ClientKey> for FheInt<Id>
 where
     Id: FheIntId,
@@ -101,6 +113,7 @@ where
     /// Trivial encryptions become real encrypted data once used in an operation
     /// that involves a real ciphertext
     fn try_encrypt_trivial(value: T) -> Result<Self, Self::Error> {
+        let _permit = global_state::permit_unrestricted_operation();
         global_state::with_cpu_internal_keys(|sks| {
             let ciphertext = sks
                 .pbs_key()
@@ -18,6 +18,7 @@ pub(in crate::high_level_api) use inner::{RadixCiphertext, RadixCiphertextVersio
 expand_pub_use_fhe_type!(
     pub use static_{
         FheInt2, FheInt4, FheInt6, FheInt8, FheInt10, FheInt12, FheInt14, FheInt16,
-        FheInt32, FheInt64, FheInt128, FheInt160, FheInt256
+        FheInt24, FheInt32, FheInt40, FheInt48, FheInt64, FheInt72, FheInt128,
+        FheInt160, FheInt256, FheInt512, FheInt1024, FheInt2048,
     };
 );
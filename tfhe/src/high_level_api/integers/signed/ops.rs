@@ -1,9 +1,10 @@
 use crate::high_level_api::global_state;
 use crate::high_level_api::integers::{FheIntId, FheUintId};
 use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::policy::{operation_kind, OperationKind};
 use crate::high_level_api::traits::{
-    DivRem, FheEq, FheMax, FheMin, FheOrd, RotateLeft, RotateLeftAssign, RotateRight,
-    RotateRightAssign,
+    DivRem, FheClamp, FheEq, FheMax, FheMaxAssign, FheMin, FheMinAssign, FheOrd, RotateLeft,
+    RotateLeftAssign, RotateRight, RotateRightAssign,
 };
 use crate::{FheBool, FheInt, FheUint};
 use std::borrow::Borrow;
@@ -47,6 +48,12 @@ where
     /// assert_eq!(decrypted, clears.into_iter().sum::<i16>());
     /// ```
     fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        let mut iter = iter.peekable();
+        let _permit = match iter.peek() {
+            Some(first) => first.enforce_operation_policy(OperationKind::Add),
+            // The sum of no ciphertext is a trivial zero
+            None => global_state::permit_unrestricted_operation(),
+        };
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let ciphertexts = iter
@@ -100,6 +107,7 @@ where
     /// assert_eq!(decrypted_max, 2i16);
     /// ```
     fn max(&self, rhs: &Self) -> Self::Output {
+        let _permit = self.enforce_operation_policy(OperationKind::Max);
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -146,6 +154,7 @@ where
     /// assert_eq!(decrypted_min, -1i16);
     /// ```
     fn min(&self, rhs: &Self) -> Self::Output {
+        let _permit = self.enforce_operation_policy(OperationKind::Min);
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -166,6 +175,145 @@ where
     }
 }
 
+impl<Id> FheClamp<&Self> for FheInt<Id>
+where
+    Id: FheIntId,
+{
+    type Output = Self;
+
+    /// Restricts a [FheInt] to the range given by two other [FheInt]
+    ///
+    /// Returns `min` if `self` is less than `min`, `max` if `self` is greater than `max`,
+    /// and `self` otherwise. Unlike [Ord::clamp], this does not panic if `min > max`,
+    /// `min` is returned when `self` is less than `min`, and `max` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-300i16, &client_key);
+    /// let min = FheInt16::encrypt(-10i16, &client_key);
+    /// let max = FheInt16::encrypt(100i16, &client_key);
+    ///
+    /// let result = a.clamp(&min, &max);
+    ///
+    /// let decrypted: i16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, -10i16);
+    /// ```
+    fn clamp(&self, min: &Self, max: &Self) -> Self::Output {
+        let _permit = self.enforce_operation_policy(OperationKind::Clamp);
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner_result = cpu_key.pbs_key().clamp_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    &*min.ciphertext.on_cpu(),
+                    &*max.ciphertext.on_cpu(),
+                );
+                Self::new(inner_result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support clamp yet")
+            }
+        })
+    }
+}
+
+impl<Id> FheMaxAssign<&Self> for FheInt<Id>
+where
+    Id: FheIntId,
+{
+    /// Assigns the max between two [FheInt] to self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let mut a = FheInt16::encrypt(-1i16, &client_key);
+    /// let b = FheInt16::encrypt(2i16, &client_key);
+    ///
+    /// a.max_assign(&b);
+    ///
+    /// let decrypted_max: i16 = a.decrypt(&client_key);
+    /// assert_eq!(decrypted_max, 2i16);
+    /// ```
+    fn max_assign(&mut self, rhs: &Self) {
+        let _permit = self.enforce_operation_policy(OperationKind::Max);
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                cpu_key.pbs_key().max_assign_parallelized(
+                    self.ciphertext.as_cpu_mut(),
+                    &*rhs.ciphertext.on_cpu(),
+                );
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(cuda_key) => with_thread_local_cuda_streams(|streams| {
+                let inner_result = cuda_key.key.key.max(
+                    &*self.ciphertext.on_gpu(),
+                    &*rhs.ciphertext.on_gpu(),
+                    streams,
+                );
+                *self.ciphertext.as_gpu_mut() = inner_result;
+            }),
+        })
+    }
+}
+
+impl<Id> FheMinAssign<&Self> for FheInt<Id>
+where
+    Id: FheIntId,
+{
+    /// Assigns the min between two [FheInt] to self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let mut a = FheInt16::encrypt(-1i16, &client_key);
+    /// let b = FheInt16::encrypt(2i16, &client_key);
+    ///
+    /// a.min_assign(&b);
+    ///
+    /// let decrypted_min: i16 = a.decrypt(&client_key);
+    /// assert_eq!(decrypted_min, -1i16);
+    /// ```
+    fn min_assign(&mut self, rhs: &Self) {
+        let _permit = self.enforce_operation_policy(OperationKind::Min);
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                cpu_key.pbs_key().min_assign_parallelized(
+                    self.ciphertext.as_cpu_mut(),
+                    &*rhs.ciphertext.on_cpu(),
+                );
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(cuda_key) => with_thread_local_cuda_streams(|streams| {
+                let inner_result = cuda_key.key.key.min(
+                    &*self.ciphertext.on_gpu(),
+                    &*rhs.ciphertext.on_gpu(),
+                    streams,
+                );
+                *self.ciphertext.as_gpu_mut() = inner_result;
+            }),
+        })
+    }
+}
+
 impl<Id> FheEq<Self> for FheInt<Id>
 where
     Id: FheIntId,
@@ -203,6 +351,7 @@ where
     /// assert_eq!(decrypted, -1i16 == 2i16);
     /// ```
     fn eq(&self, rhs: &Self) -> FheBool {
+        let _permit = self.enforce_operation_policy(OperationKind::Eq);
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -242,6 +391,7 @@ where
     /// assert_eq!(decrypted, -1i16 != 2i16);
     /// ```
     fn ne(&self, rhs: &Self) -> FheBool {
+        let _permit = self.enforce_operation_policy(OperationKind::Ne);
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -307,6 +457,7 @@ where
     /// assert_eq!(decrypted, -1i16 < 2i16);
     /// ```
     fn lt(&self, rhs: &Self) -> FheBool {
+        let _permit = self.enforce_operation_policy(OperationKind::Lt);
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -346,6 +497,7 @@ where
     /// assert_eq!(decrypted, -1i16 <= 2i16);
     /// ```
     fn le(&self, rhs: &Self) -> FheBool {
+        let _permit = self.enforce_operation_policy(OperationKind::Le);
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -385,6 +537,7 @@ where
     /// assert_eq!(decrypted, -1i16 > 2i16);
     /// ```
     fn gt(&self, rhs: &Self) -> FheBool {
+        let _permit = self.enforce_operation_policy(OperationKind::Gt);
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -424,6 +577,7 @@ where
     /// assert_eq!(decrypted, -1i16 >= 2i16);
     /// ```
     fn ge(&self, rhs: &Self) -> FheBool {
+        let _permit = self.enforce_operation_policy(OperationKind::Ge);
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -503,6 +657,7 @@ where
     /// assert_eq!(remainder, -23i16 % 3i16);
     /// ```
     fn div_rem(self, rhs: Self) -> Self::Output {
+        let _permit = self.enforce_operation_policy(OperationKind::DivRem);
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let (q, r) = cpu_key
@@ -551,6 +706,7 @@ macro_rules! generic_integer_impl_operation (
 
             $(#[$outer])*
             fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                let _permit = self.enforce_operation_policy(operation_kind!($rust_trait_name));
                 $closure(self, rhs.borrow())
             }
         }
@@ -965,6 +1121,7 @@ macro_rules! generic_integer_impl_shift_rotate (
 
             $(#[$outer])*
             fn $rust_trait_method(self, rhs: &FheUint<Id2>) -> Self::Output {
+                let _permit = self.enforce_operation_policy(operation_kind!($rust_trait_name));
                 $closure(self, rhs.borrow())
             }
         }
@@ -1175,6 +1332,7 @@ where
     /// assert_eq!(result, 3i16.wrapping_add(7849i16));
     /// ```
     fn add_assign(&mut self, rhs: I) {
+        let _permit = self.enforce_operation_policy(OperationKind::Add);
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -1222,6 +1380,7 @@ where
     /// assert_eq!(result, 3i16.wrapping_sub(7849i16));
     /// ```
     fn sub_assign(&mut self, rhs: I) {
+        let _permit = self.enforce_operation_policy(OperationKind::Sub);
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -1269,6 +1428,7 @@ where
     /// assert_eq!(result, 3i16.wrapping_mul(7849i16));
     /// ```
     fn mul_assign(&mut self, rhs: I) {
+        let _permit = self.enforce_operation_policy(OperationKind::Mul);
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -1314,6 +1474,7 @@ where
     /// assert_eq!(result, 3i16 & 7849i16);
     /// ```
     fn bitand_assign(&mut self, rhs: I) {
+        let _permit = self.enforce_operation_policy(OperationKind::BitAnd);
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -1359,6 +1520,7 @@ where
     /// assert_eq!(result, 3i16 | 7849i16);
     /// ```
     fn bitor_assign(&mut self, rhs: I) {
+        let _permit = self.enforce_operation_policy(OperationKind::BitOr);
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -1404,6 +1566,7 @@ where
     /// assert_eq!(result, 3i16 ^ 7849i16);
     /// ```
     fn bitxor_assign(&mut self, rhs: I) {
+        let _permit = self.enforce_operation_policy(OperationKind::BitXor);
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -1454,6 +1617,7 @@ where
     /// assert_eq!(result, 7849i16 / 3i16);
     /// ```
     fn div_assign(&mut self, rhs: I) {
+        let _permit = self.enforce_operation_policy(OperationKind::Div);
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -1498,6 +1662,7 @@ where
     /// assert_eq!(result, 7849i16 % 3i16);
     /// ```
     fn rem_assign(&mut self, rhs: I) {
+        let _permit = self.enforce_operation_policy(OperationKind::Rem);
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -1548,6 +1713,7 @@ where
     /// assert_eq!(result, 7849i16 << 3u16);
     /// ```
     fn shl_assign(&mut self, rhs: &FheUint<Id2>) {
+        let _permit = self.enforce_operation_policy(OperationKind::Shl);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 cpu_key.pbs_key().left_shift_assign_parallelized(
@@ -1602,6 +1768,7 @@ where
     /// assert_eq!(result, 7849i16 >> 3u16);
     /// ```
     fn shr_assign(&mut self, rhs: &FheUint<Id2>) {
+        let _permit = self.enforce_operation_policy(OperationKind::Shr);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 cpu_key.pbs_key().right_shift_assign_parallelized(
@@ -1657,6 +1824,7 @@ where
     /// assert_eq!(result, 7849i16.rotate_left(3));
     /// ```
     fn rotate_left_assign(&mut self, rhs: &FheUint<Id2>) {
+        let _permit = self.enforce_operation_policy(OperationKind::RotateLeft);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 cpu_key.pbs_key().rotate_left_assign_parallelized(
@@ -1714,6 +1882,7 @@ where
     /// assert_eq!(result, 7849i16.rotate_right(3));
     /// ```
     fn rotate_right_assign(&mut self, rhs: &FheUint<Id2>) {
+        let _permit = self.enforce_operation_policy(OperationKind::RotateRight);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 cpu_key.pbs_key().rotate_right_assign_parallelized(
@@ -1787,6 +1956,7 @@ where
     /// assert_eq!(result, 3i16);
     /// ```
     fn neg(self) -> Self::Output {
+        let _permit = self.enforce_operation_policy(OperationKind::Neg);
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let ciphertext = cpu_key
@@ -1855,6 +2025,7 @@ where
     /// assert_eq!(result, !-3i16);
     /// ```
     fn not(self) -> Self::Output {
+        let _permit = self.enforce_operation_policy(OperationKind::Not);
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let ciphertext = cpu_key.pbs_key().bitnot(&*self.ciphertext.on_cpu());
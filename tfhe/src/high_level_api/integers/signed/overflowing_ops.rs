@@ -4,6 +4,7 @@ use crate::high_level_api::global_state;
 use crate::high_level_api::global_state::with_thread_local_cuda_streams;
 use crate::high_level_api::integers::FheIntId;
 use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::policy::OperationKind;
 use crate::integer::block_decomposition::DecomposableInto;
 use crate::prelude::{OverflowingAdd, OverflowingMul, OverflowingSub};
 use crate::{FheBool, FheInt};
@@ -41,6 +42,7 @@ where
     /// assert!(overflowed.decrypt(&client_key));
     /// ```
     fn overflowing_add(self, other: Self) -> (Self::Output, FheBool) {
+        let _permit = self.enforce_operation_policy(OperationKind::Add);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let (result, overflow) = cpu_key.pbs_key().signed_overflowing_add_parallelized(
@@ -138,6 +140,7 @@ where
     /// assert!(overflowed.decrypt(&client_key));
     /// ```
     fn overflowing_add(self, other: Clear) -> (Self::Output, FheBool) {
+        let _permit = self.enforce_operation_policy(OperationKind::Add);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let (result, overflow) = cpu_key
@@ -271,6 +274,7 @@ where
     /// assert!(overflowed.decrypt(&client_key));
     /// ```
     fn overflowing_sub(self, other: Self) -> (Self::Output, FheBool) {
+        let _permit = self.enforce_operation_policy(OperationKind::Sub);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let (result, overflow) = cpu_key.pbs_key().signed_overflowing_sub_parallelized(
@@ -367,6 +371,7 @@ where
     /// assert!(overflowed.decrypt(&client_key));
     /// ```
     fn overflowing_sub(self, other: Clear) -> (Self::Output, FheBool) {
+        let _permit = self.enforce_operation_policy(OperationKind::Sub);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let (result, overflow) = cpu_key
@@ -462,6 +467,7 @@ where
     /// assert!(overflowed.decrypt(&client_key));
     /// ```
     fn overflowing_mul(self, other: Self) -> (Self::Output, FheBool) {
+        let _permit = self.enforce_operation_policy(OperationKind::Mul);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let (result, overflow) = cpu_key.pbs_key().signed_overflowing_mul_parallelized(
@@ -7,13 +7,15 @@ use crate::high_level_api::global_state::with_thread_local_cuda_streams;
 use crate::high_level_api::integers::signed::inner::RadixCiphertext;
 use crate::high_level_api::integers::FheIntId;
 use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::policy::{operation_kind, OperationKind};
 use crate::high_level_api::traits::{
-    DivRem, FheEq, FheMax, FheMin, FheOrd, RotateLeft, RotateLeftAssign, RotateRight,
+    DivRem, FheClamp, FheEq, FheMax, FheMin, FheOrd, RotateLeft, RotateLeftAssign, RotateRight,
     RotateRightAssign,
 };
+use crate::integer::bigint::{I1024, I2048, U1024, U2048};
 use crate::integer::block_decomposition::DecomposableInto;
 use crate::integer::ciphertext::IntegerCiphertext;
-use crate::integer::{I256, U256};
+use crate::integer::{I256, I512, U256, U512};
 use crate::{FheBool, FheInt};
 use std::ops::{
     Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
@@ -46,6 +48,7 @@ where
     /// assert_eq!(decrypted_max, 2i16);
     /// ```
     fn max(&self, rhs: Clear) -> Self::Output {
+        let _permit = self.enforce_operation_policy(OperationKind::Max);
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -94,6 +97,7 @@ where
     /// assert_eq!(decrypted_min, -1i16);
     /// ```
     fn min(&self, rhs: Clear) -> Self::Output {
+        let _permit = self.enforce_operation_policy(OperationKind::Min);
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -116,6 +120,54 @@ where
     }
 }
 
+impl<Id, Clear> FheClamp<Clear> for FheInt<Id>
+where
+    Clear: DecomposableInto<u64>,
+    Id: FheIntId,
+{
+    type Output = Self;
+
+    /// Restricts a [FheInt] to the range given by two clear values
+    ///
+    /// Returns `min` if `self` is less than `min`, `max` if `self` is greater than `max`,
+    /// and `self` otherwise. Unlike [Ord::clamp], this does not panic if `min > max`,
+    /// `min` is returned when `self` is less than `min`, and `max` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-300i16, &client_key);
+    ///
+    /// let result = a.clamp(-10i16, 100i16);
+    ///
+    /// let decrypted: i16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, -10i16);
+    /// ```
+    fn clamp(&self, min: Clear, max: Clear) -> Self::Output {
+        let _permit = self.enforce_operation_policy(OperationKind::Clamp);
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner_result = cpu_key.pbs_key().scalar_clamp_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    min,
+                    max,
+                );
+                Self::new(inner_result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support clamp yet")
+            }
+        })
+    }
+}
+
 impl<Id, Clear> FheEq<Clear> for FheInt<Id>
 where
     Clear: DecomposableInto<u64>,
@@ -141,6 +193,7 @@ where
     /// assert_eq!(decrypted, -1i16 == 2i16);
     /// ```
     fn eq(&self, rhs: Clear) -> FheBool {
+        let _permit = self.enforce_operation_policy(OperationKind::Eq);
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -182,6 +235,7 @@ where
     /// assert_eq!(decrypted, -1i16 != 2i16);
     /// ```
     fn ne(&self, rhs: Clear) -> FheBool {
+        let _permit = self.enforce_operation_policy(OperationKind::Ne);
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -228,6 +282,7 @@ where
     /// assert_eq!(decrypted, -1i16 < 2i16);
     /// ```
     fn lt(&self, rhs: Clear) -> FheBool {
+        let _permit = self.enforce_operation_policy(OperationKind::Lt);
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -268,6 +323,7 @@ where
     /// assert_eq!(decrypted, -1i16 <= 2i16);
     /// ```
     fn le(&self, rhs: Clear) -> FheBool {
+        let _permit = self.enforce_operation_policy(OperationKind::Le);
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -308,6 +364,7 @@ where
     /// assert_eq!(decrypted, -1i16 > 2i16);
     /// ```
     fn gt(&self, rhs: Clear) -> FheBool {
+        let _permit = self.enforce_operation_policy(OperationKind::Gt);
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -348,6 +405,7 @@ where
     /// assert_eq!(decrypted, -1i16 >= 2i16);
     /// ```
     fn ge(&self, rhs: Clear) -> FheBool {
+        let _permit = self.enforce_operation_policy(OperationKind::Ge);
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -396,6 +454,7 @@ macro_rules! generic_integer_impl_scalar_div_rem {
                     type Output = ($concrete_type, $concrete_type);
 
                     fn div_rem(self, rhs: $scalar_type) -> Self::Output {
+                        let _permit = self.enforce_operation_policy(OperationKind::DivRem);
                         global_state::with_internal_keys(|keys| match keys {
                             InternalServerKey::Cpu(cpu_key) => {
                                 let (q, r) = cpu_key
@@ -437,11 +496,18 @@ generic_integer_impl_scalar_div_rem!(
         (super::FheInt12, i16),
         (super::FheInt14, i16),
         (super::FheInt16, i16),
+        (super::FheInt24, i32),
         (super::FheInt32, i32),
+        (super::FheInt40, i64),
+        (super::FheInt48, i64),
         (super::FheInt64, i64),
+        (super::FheInt72, i128),
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 
 use crate::high_level_api::integers::unsigned::scalar_ops::{
@@ -481,11 +547,18 @@ generic_integer_impl_scalar_operation!(
         (super::FheInt12, i16),
         (super::FheInt14, i16),
         (super::FheInt16, i16),
+        (super::FheInt24, i32),
         (super::FheInt32, i32),
+        (super::FheInt40, i64),
+        (super::FheInt48, i64),
         (super::FheInt64, i64),
+        (super::FheInt72, i128),
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: Sub(sub),
@@ -519,11 +592,18 @@ generic_integer_impl_scalar_operation!(
         (super::FheInt12, i16),
         (super::FheInt14, i16),
         (super::FheInt16, i16),
+        (super::FheInt24, i32),
         (super::FheInt32, i32),
+        (super::FheInt40, i64),
+        (super::FheInt48, i64),
         (super::FheInt64, i64),
+        (super::FheInt72, i128),
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: Mul(mul),
@@ -557,11 +637,18 @@ generic_integer_impl_scalar_operation!(
         (super::FheInt12, i16),
         (super::FheInt14, i16),
         (super::FheInt16, i16),
+        (super::FheInt24, i32),
         (super::FheInt32, i32),
+        (super::FheInt40, i64),
+        (super::FheInt48, i64),
         (super::FheInt64, i64),
+        (super::FheInt72, i128),
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: BitAnd(bitand),
@@ -595,11 +682,18 @@ generic_integer_impl_scalar_operation!(
         (super::FheInt12, i16),
         (super::FheInt14, i16),
         (super::FheInt16, i16),
+        (super::FheInt24, i32),
         (super::FheInt32, i32),
+        (super::FheInt40, i64),
+        (super::FheInt48, i64),
         (super::FheInt64, i64),
+        (super::FheInt72, i128),
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: BitOr(bitor),
@@ -633,11 +727,18 @@ generic_integer_impl_scalar_operation!(
         (super::FheInt12, i16),
         (super::FheInt14, i16),
         (super::FheInt16, i16),
+        (super::FheInt24, i32),
         (super::FheInt32, i32),
+        (super::FheInt40, i64),
+        (super::FheInt48, i64),
         (super::FheInt64, i64),
+        (super::FheInt72, i128),
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: BitXor(bitxor),
@@ -672,11 +773,18 @@ generic_integer_impl_scalar_operation!(
         (super::FheInt12, i16),
         (super::FheInt14, i16),
         (super::FheInt16, i16),
+        (super::FheInt24, i32),
         (super::FheInt32, i32),
+        (super::FheInt40, i64),
+        (super::FheInt48, i64),
         (super::FheInt64, i64),
+        (super::FheInt72, i128),
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: Shl(shl),
@@ -710,11 +818,18 @@ generic_integer_impl_scalar_operation!(
         (super::FheInt12, u8, u16, u32, u64, u128),
         (super::FheInt14, u8, u16, u32, u64, u128),
         (super::FheInt16, u8, u16, u32, u64, u128),
+        (super::FheInt24, u8, u16, u32, u64, u128),
         (super::FheInt32, u8, u16, u32, u64, u128),
+        (super::FheInt40, u8, u16, u32, u64, u128),
+        (super::FheInt48, u8, u16, u32, u64, u128),
         (super::FheInt64, u8, u16, u32, u64, u128),
+        (super::FheInt72, u8, u16, u32, u64, u128),
         (super::FheInt128, u8, u16, u32, u64, u128),
         (super::FheInt160, u8, u16, u32, u64, u128, U256),
         (super::FheInt256, u8, u16, u32, u64, u128, U256),
+        (super::FheInt512, u8, u16, u32, u64, u128, U256, U512),
+        (super::FheInt1024, u8, u16, u32, u64, u128, U256, U512, U1024),
+        (super::FheInt2048, u8, u16, u32, u64, u128, U256, U512, U1024, U2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: Shr(shr),
@@ -748,11 +863,18 @@ generic_integer_impl_scalar_operation!(
         (super::FheInt12, u8, u16, u32, u64, u128),
         (super::FheInt14, u8, u16, u32, u64, u128),
         (super::FheInt16, u8, u16, u32, u64, u128),
+        (super::FheInt24, u8, u16, u32, u64, u128),
         (super::FheInt32, u8, u16, u32, u64, u128),
+        (super::FheInt40, u8, u16, u32, u64, u128),
+        (super::FheInt48, u8, u16, u32, u64, u128),
         (super::FheInt64, u8, u16, u32, u64, u128),
+        (super::FheInt72, u8, u16, u32, u64, u128),
         (super::FheInt128, u8, u16, u32, u64, u128),
         (super::FheInt160, u8, u16, u32, u64, u128, U256),
         (super::FheInt256, u8, u16, u32, u64, u128, U256),
+        (super::FheInt512, u8, u16, u32, u64, u128, U256, U512),
+        (super::FheInt1024, u8, u16, u32, u64, u128, U256, U512, U1024),
+        (super::FheInt2048, u8, u16, u32, u64, u128, U256, U512, U1024, U2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: RotateLeft(rotate_left),
@@ -786,11 +908,18 @@ generic_integer_impl_scalar_operation!(
         (super::FheInt12, u8, u16, u32, u64, u128),
         (super::FheInt14, u8, u16, u32, u64, u128),
         (super::FheInt16, u8, u16, u32, u64, u128),
+        (super::FheInt24, u8, u16, u32, u64, u128),
         (super::FheInt32, u8, u16, u32, u64, u128),
+        (super::FheInt40, u8, u16, u32, u64, u128),
+        (super::FheInt48, u8, u16, u32, u64, u128),
         (super::FheInt64, u8, u16, u32, u64, u128),
+        (super::FheInt72, u8, u16, u32, u64, u128),
         (super::FheInt128, u8, u16, u32, u64, u128),
         (super::FheInt160, u8, u16, u32, u64, u128, U256),
         (super::FheInt256, u8, u16, u32, u64, u128, U256),
+        (super::FheInt512, u8, u16, u32, u64, u128, U256, U512),
+        (super::FheInt1024, u8, u16, u32, u64, u128, U256, U512, U1024),
+        (super::FheInt2048, u8, u16, u32, u64, u128, U256, U512, U1024, U2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: RotateRight(rotate_right),
@@ -824,11 +953,18 @@ generic_integer_impl_scalar_operation!(
         (super::FheInt12, u8, u16, u32, u64, u128),
         (super::FheInt14, u8, u16, u32, u64, u128),
         (super::FheInt16, u8, u16, u32, u64, u128),
+        (super::FheInt24, u8, u16, u32, u64, u128),
         (super::FheInt32, u8, u16, u32, u64, u128),
+        (super::FheInt40, u8, u16, u32, u64, u128),
+        (super::FheInt48, u8, u16, u32, u64, u128),
         (super::FheInt64, u8, u16, u32, u64, u128),
+        (super::FheInt72, u8, u16, u32, u64, u128),
         (super::FheInt128, u8, u16, u32, u64, u128),
         (super::FheInt160, u8, u16, u32, u64, u128, U256),
         (super::FheInt256, u8, u16, u32, u64, u128, U256),
+        (super::FheInt512, u8, u16, u32, u64, u128, U256, U512),
+        (super::FheInt1024, u8, u16, u32, u64, u128, U256, U512, U1024),
+        (super::FheInt2048, u8, u16, u32, u64, u128, U256, U512, U1024, U2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: Div(div),
@@ -862,11 +998,18 @@ generic_integer_impl_scalar_operation!(
         (super::FheInt12, i16),
         (super::FheInt14, i16),
         (super::FheInt16, i16),
+        (super::FheInt24, i32),
         (super::FheInt32, i32),
+        (super::FheInt40, i64),
+        (super::FheInt48, i64),
         (super::FheInt64, i64),
+        (super::FheInt72, i128),
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: Rem(rem),
@@ -900,11 +1043,18 @@ generic_integer_impl_scalar_operation!(
         (super::FheInt12, i16),
         (super::FheInt14, i16),
         (super::FheInt16, i16),
+        (super::FheInt24, i32),
         (super::FheInt32, i32),
+        (super::FheInt40, i64),
+        (super::FheInt48, i64),
         (super::FheInt64, i64),
+        (super::FheInt72, i128),
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 
 generic_integer_impl_scalar_left_operation!(
@@ -946,11 +1096,18 @@ generic_integer_impl_scalar_left_operation!(
             /// assert_eq!(result, 23i16 + 3i16);
             /// ```
             i16),
+        (super::FheInt24, i32),
         (super::FheInt32, i32),
+        (super::FheInt40, i64),
+        (super::FheInt48, i64),
         (super::FheInt64, i64),
+        (super::FheInt72, i128),
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_left_operation!(
     rust_trait: Sub(sub),
@@ -1010,11 +1167,18 @@ generic_integer_impl_scalar_left_operation!(
             /// assert_eq!(result, 23i16 - 3i16);
             /// ```
             i16),
+        (super::FheInt24, i32),
         (super::FheInt32, i32),
+        (super::FheInt40, i64),
+        (super::FheInt48, i64),
         (super::FheInt64, i64),
+        (super::FheInt72, i128),
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_left_operation!(
     rust_trait: Mul(mul),
@@ -1055,11 +1219,18 @@ generic_integer_impl_scalar_left_operation!(
             /// assert_eq!(result, 23i16 * 3i16);
             /// ```
             i16),
+        (super::FheInt24, i32),
         (super::FheInt32, i32),
+        (super::FheInt40, i64),
+        (super::FheInt48, i64),
         (super::FheInt64, i64),
+        (super::FheInt72, i128),
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_left_operation!(
     rust_trait: BitAnd(bitand),
@@ -1098,11 +1269,18 @@ generic_integer_impl_scalar_left_operation!(
             /// assert_eq!(result, 23i16 & 3i16);
             /// ```
             i16),
+        (super::FheInt24, i32),
         (super::FheInt32, i32),
+        (super::FheInt40, i64),
+        (super::FheInt48, i64),
         (super::FheInt64, i64),
+        (super::FheInt72, i128),
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_left_operation!(
     rust_trait: BitOr(bitor),
@@ -1141,11 +1319,18 @@ generic_integer_impl_scalar_left_operation!(
             /// assert_eq!(result, 23i16 | 3i16);
             /// ```
             i16),
+        (super::FheInt24, i32),
         (super::FheInt32, i32),
+        (super::FheInt40, i64),
+        (super::FheInt48, i64),
         (super::FheInt64, i64),
+        (super::FheInt72, i128),
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_left_operation!(
     rust_trait: BitXor(bitxor),
@@ -1184,11 +1369,18 @@ generic_integer_impl_scalar_left_operation!(
             /// assert_eq!(result, 23i16 ^ 3i16);
             /// ```
             i16),
+        (super::FheInt24, i32),
         (super::FheInt32, i32),
+        (super::FheInt40, i64),
+        (super::FheInt48, i64),
         (super::FheInt64, i64),
+        (super::FheInt72, i128),
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 
 generic_integer_impl_scalar_operation_assign!(
@@ -1242,11 +1434,18 @@ generic_integer_impl_scalar_operation_assign!(
         /// assert_eq!(result, 23i16 + 3i16);
         /// ```
         i16),
+        (super::FheInt24, i32),
         (super::FheInt32, i32),
+        (super::FheInt40, i64),
+        (super::FheInt48, i64),
         (super::FheInt64, i64),
+        (super::FheInt72, i128),
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: SubAssign(sub_assign),
@@ -1277,11 +1476,18 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheInt12, i16),
         (super::FheInt14, i16),
         (super::FheInt16, i16),
+        (super::FheInt24, i32),
         (super::FheInt32, i32),
+        (super::FheInt40, i64),
+        (super::FheInt48, i64),
         (super::FheInt64, i64),
+        (super::FheInt72, i128),
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: MulAssign(mul_assign),
@@ -1312,11 +1518,18 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheInt12, i16),
         (super::FheInt14, i16),
         (super::FheInt16, i16),
+        (super::FheInt24, i32),
         (super::FheInt32, i32),
+        (super::FheInt40, i64),
+        (super::FheInt48, i64),
         (super::FheInt64, i64),
+        (super::FheInt72, i128),
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: BitAndAssign(bitand_assign),
@@ -1347,11 +1560,18 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheInt12, i16),
         (super::FheInt14, i16),
         (super::FheInt16, i16),
+        (super::FheInt24, i32),
         (super::FheInt32, i32),
+        (super::FheInt40, i64),
+        (super::FheInt48, i64),
         (super::FheInt64, i64),
+        (super::FheInt72, i128),
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: BitOrAssign(bitor_assign),
@@ -1382,11 +1602,18 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheInt12, i16),
         (super::FheInt14, i16),
         (super::FheInt16, i16),
+        (super::FheInt24, i32),
         (super::FheInt32, i32),
+        (super::FheInt40, i64),
+        (super::FheInt48, i64),
         (super::FheInt64, i64),
+        (super::FheInt72, i128),
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: BitXorAssign(bitxor_assign),
@@ -1417,11 +1644,18 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheInt12, i16),
         (super::FheInt14, i16),
         (super::FheInt16, i16),
+        (super::FheInt24, i32),
         (super::FheInt32, i32),
+        (super::FheInt40, i64),
+        (super::FheInt48, i64),
         (super::FheInt64, i64),
+        (super::FheInt72, i128),
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: ShlAssign(shl_assign),
@@ -1452,11 +1686,18 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheInt12, u8, u16, u32, u64, u128),
         (super::FheInt14, u8, u16, u32, u64, u128),
         (super::FheInt16, u8, u16, u32, u64, u128),
+        (super::FheInt24, u8, u16, u32, u64, u128),
         (super::FheInt32, u8, u16, u32, u64, u128),
+        (super::FheInt40, u8, u16, u32, u64, u128),
+        (super::FheInt48, u8, u16, u32, u64, u128),
         (super::FheInt64, u8, u16, u32, u64, u128),
+        (super::FheInt72, u8, u16, u32, u64, u128),
         (super::FheInt128, u8, u16, u32, u64, u128),
         (super::FheInt160, u8, u16, u32, u64, u128, U256),
         (super::FheInt256, u8, u16, u32, u64, u128, U256),
+        (super::FheInt512, u8, u16, u32, u64, u128, U256, U512),
+        (super::FheInt1024, u8, u16, u32, u64, u128, U256, U512, U1024),
+        (super::FheInt2048, u8, u16, u32, u64, u128, U256, U512, U1024, U2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: ShrAssign(shr_assign),
@@ -1487,11 +1728,18 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheInt12, u8, u16, u32, u64, u128),
         (super::FheInt14, u8, u16, u32, u64, u128),
         (super::FheInt16, u8, u16, u32, u64, u128),
+        (super::FheInt24, u8, u16, u32, u64, u128),
         (super::FheInt32, u8, u16, u32, u64, u128),
+        (super::FheInt40, u8, u16, u32, u64, u128),
+        (super::FheInt48, u8, u16, u32, u64, u128),
         (super::FheInt64, u8, u16, u32, u64, u128),
+        (super::FheInt72, u8, u16, u32, u64, u128),
         (super::FheInt128, u8, u16, u32, u64, u128),
         (super::FheInt160, u8, u16, u32, u64, u128, U256),
         (super::FheInt256, u8, u16, u32, u64, u128, U256),
+        (super::FheInt512, u8, u16, u32, u64, u128, U256, U512),
+        (super::FheInt1024, u8, u16, u32, u64, u128, U256, U512, U1024),
+        (super::FheInt2048, u8, u16, u32, u64, u128, U256, U512, U1024, U2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: RotateLeftAssign(rotate_left_assign),
@@ -1522,11 +1770,18 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheInt12, u8, u16, u32, u64, u128),
         (super::FheInt14, u8, u16, u32, u64, u128),
         (super::FheInt16, u8, u16, u32, u64, u128),
+        (super::FheInt24, u8, u16, u32, u64, u128),
         (super::FheInt32, u8, u16, u32, u64, u128),
+        (super::FheInt40, u8, u16, u32, u64, u128),
+        (super::FheInt48, u8, u16, u32, u64, u128),
         (super::FheInt64, u8, u16, u32, u64, u128),
+        (super::FheInt72, u8, u16, u32, u64, u128),
         (super::FheInt128, u8, u16, u32, u64, u128),
         (super::FheInt160, u8, u16, u32, u64, u128, U256),
         (super::FheInt256, u8, u16, u32, u64, u128, U256),
+        (super::FheInt512, u8, u16, u32, u64, u128, U256, U512),
+        (super::FheInt1024, u8, u16, u32, u64, u128, U256, U512, U1024),
+        (super::FheInt2048, u8, u16, u32, u64, u128, U256, U512, U1024, U2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: RotateRightAssign(rotate_right_assign),
@@ -1557,11 +1812,18 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheInt12, u8, u16, u32, u64, u128),
         (super::FheInt14, u8, u16, u32, u64, u128),
         (super::FheInt16, u8, u16, u32, u64, u128),
+        (super::FheInt24, u8, u16, u32, u64, u128),
         (super::FheInt32, u8, u16, u32, u64, u128),
+        (super::FheInt40, u8, u16, u32, u64, u128),
+        (super::FheInt48, u8, u16, u32, u64, u128),
         (super::FheInt64, u8, u16, u32, u64, u128),
+        (super::FheInt72, u8, u16, u32, u64, u128),
         (super::FheInt128, u8, u16, u32, u64, u128),
         (super::FheInt160, u8, u16, u32, u64, u128, U256),
         (super::FheInt256, u8, u16, u32, u64, u128, U256),
+        (super::FheInt512, u8, u16, u32, u64, u128, U256, U512),
+        (super::FheInt1024, u8, u16, u32, u64, u128, U256, U512, U1024),
+        (super::FheInt2048, u8, u16, u32, u64, u128, U256, U512, U1024, U2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: DivAssign(div_assign),
@@ -1589,11 +1851,18 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheInt12, i16),
         (super::FheInt14, i16),
         (super::FheInt16, i16),
+        (super::FheInt24, i32),
         (super::FheInt32, i32),
+        (super::FheInt40, i64),
+        (super::FheInt48, i64),
         (super::FheInt64, i64),
+        (super::FheInt72, i128),
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: RemAssign(rem_assign),
@@ -1621,9 +1890,16 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheInt12, i16),
         (super::FheInt14, i16),
         (super::FheInt16, i16),
+        (super::FheInt24, i32),
         (super::FheInt32, i32),
+        (super::FheInt40, i64),
+        (super::FheInt48, i64),
         (super::FheInt64, i64),
+        (super::FheInt72, i128),
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
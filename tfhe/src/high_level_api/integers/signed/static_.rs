@@ -1,5 +1,6 @@
 use crate::high_level_api::integers::signed::base::{FheInt, FheIntConformanceParams, FheIntId};
 use crate::high_level_api::integers::signed::compressed::CompressedFheInt;
+use crate::high_level_api::integers::unsigned;
 use crate::high_level_api::{FheId, IntegerId};
 use serde::{Deserialize, Serialize};
 use tfhe_versionable::NotVersioned;
@@ -27,7 +28,9 @@ macro_rules! static_int_type {
 
             impl FheId for [<FheInt $num_bits Id>] { }
 
-            impl FheIntId for [<FheInt $num_bits Id>] { }
+            impl FheIntId for [<FheInt $num_bits Id>] {
+                type UnsignedId = unsigned::[<FheUint $num_bits Id>];
+            }
         }
 
         // Define all specialization of all the necessary types
@@ -98,18 +101,42 @@ static_int_type! {
     }
 }
 
+static_int_type! {
+    Signed {
+        num_bits: 24,
+    }
+}
+
 static_int_type! {
     Signed {
         num_bits: 32,
     }
 }
 
+static_int_type! {
+    Signed {
+        num_bits: 40,
+    }
+}
+
+static_int_type! {
+    Signed {
+        num_bits: 48,
+    }
+}
+
 static_int_type! {
     Signed {
         num_bits: 64,
     }
 }
 
+static_int_type! {
+    Signed {
+        num_bits: 72,
+    }
+}
+
 static_int_type! {
     Signed {
         num_bits: 128,
@@ -127,3 +154,21 @@ static_int_type! {
         num_bits: 256,
     }
 }
+
+static_int_type! {
+    Signed {
+        num_bits: 512,
+    }
+}
+
+static_int_type! {
+    Signed {
+        num_bits: 1024,
+    }
+}
+
+static_int_type! {
+    Signed {
+        num_bits: 2048,
+    }
+}
@@ -1,11 +1,12 @@
-use crate::integer::I256;
+use crate::integer::{I256, I512};
 use crate::prelude::*;
 use crate::safe_serialization::{DeserializationConfig, SerializationConfig};
 use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
 use crate::{
     generate_keys, set_server_key, ClientKey, CompactCiphertextList, CompactPublicKey,
-    CompressedFheInt16, CompressedFheInt32, Config, ConfigBuilder, FheInt16, FheInt256, FheInt32,
-    FheInt32ConformanceParams, FheInt64, FheInt8, FheUint64, FheUint8,
+    CompressedFheInt16, CompressedFheInt32, Config, ConfigBuilder, FheInt16, FheInt24, FheInt256,
+    FheInt32, FheInt32ConformanceParams, FheInt40, FheInt48, FheInt512, FheInt64, FheInt72,
+    FheInt8, FheUint64, FheUint8,
 };
 use rand::prelude::*;
 
@@ -371,6 +372,75 @@ fn test_multi_div_rem() {
         .build();
     fhe_int32_div_rem(config);
 }
+#[test]
+fn test_euclid_floor_div() {
+    let (cks, sks) = generate_keys(ConfigBuilder::default());
+    set_server_key(sks);
+
+    let mut rng = rand::thread_rng();
+    let clear_a = rng.gen::<i16>();
+    let clear_b = loop {
+        let value = rng.gen::<i16>();
+        if value != 0 {
+            break value;
+        }
+    };
+
+    let a = FheInt16::encrypt(clear_a, &cks);
+    let b = FheInt16::encrypt(clear_b, &cks);
+
+    let decrypted: i16 = a.div_euclid(&b).decrypt(&cks);
+    assert_eq!(decrypted, clear_a.wrapping_div_euclid(clear_b));
+
+    let decrypted: i16 = a.rem_euclid(&b).decrypt(&cks);
+    assert_eq!(decrypted, clear_a.wrapping_rem_euclid(clear_b));
+
+    let (q, r) = a.div_rem_euclid(&b);
+    let q: i16 = q.decrypt(&cks);
+    let r: i16 = r.decrypt(&cks);
+    assert_eq!(q, clear_a.wrapping_div_euclid(clear_b));
+    assert_eq!(r, clear_a.wrapping_rem_euclid(clear_b));
+
+    let mut expected_floor = clear_a.wrapping_div(clear_b);
+    if clear_a.wrapping_rem(clear_b) != 0 && ((clear_a < 0) != (clear_b < 0)) {
+        expected_floor -= 1;
+    }
+    let decrypted: i16 = a.div_floor(&b).decrypt(&cks);
+    assert_eq!(decrypted, expected_floor);
+}
+
+#[test]
+fn test_checked_div_rem() {
+    let (cks, sks) = generate_keys(ConfigBuilder::default());
+    set_server_key(sks);
+
+    let mut rng = rand::thread_rng();
+    let clear_a = rng.gen::<i16>();
+    let clear_b = rng.gen::<i16>();
+
+    let a = FheInt16::encrypt(clear_a, &cks);
+    for clear_b in [clear_b, 0] {
+        let b = FheInt16::encrypt(clear_b, &cks);
+
+        let (q, is_divisor_zero) = a.checked_div(&b);
+        assert_eq!(is_divisor_zero.decrypt(&cks), clear_b == 0);
+        if let Some(expected) = clear_a.checked_div(clear_b) {
+            let decrypted: i16 = q.decrypt(&cks);
+            assert_eq!(decrypted, expected);
+        }
+
+        let (r, is_divisor_zero) = a.checked_rem(&b);
+        assert_eq!(is_divisor_zero.decrypt(&cks), clear_b == 0);
+        let decrypted: i16 = r.decrypt(&cks);
+        let expected = if clear_b == 0 {
+            clear_a
+        } else {
+            clear_a.wrapping_rem(clear_b)
+        };
+        assert_eq!(decrypted, expected);
+    }
+}
+
 #[test]
 fn test_integer_casting() {
     let config = ConfigBuilder::default().build();
@@ -501,6 +571,106 @@ fn test_abs() {
     }
 }
 
+#[test]
+fn test_signum_and_unsigned_abs() {
+    let config = ConfigBuilder::default().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+
+    for clear in [
+        rng.gen_range(i64::MIN..0),
+        0,
+        rng.gen_range(1..=i64::MAX),
+        i64::MIN,
+    ] {
+        let a = FheInt64::encrypt(clear, &client_key);
+
+        let signum: i64 = a.signum().decrypt(&client_key);
+        assert_eq!(signum, clear.signum());
+
+        let unsigned_abs: u64 = a.unsigned_abs().decrypt(&client_key);
+        assert_eq!(unsigned_abs, clear.unsigned_abs());
+    }
+}
+
+#[test]
+fn test_saturating_ops() {
+    let config = ConfigBuilder::default().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+
+    for (clear_a, clear_b) in [
+        (i16::MAX - 1, 2i16),
+        (i16::MIN + 1, 2i16),
+        (i16::MIN / 2, -3i16),
+        (rng.gen::<i16>(), rng.gen::<i16>()),
+    ] {
+        let a = FheInt16::encrypt(clear_a, &client_key);
+        let b = FheInt16::encrypt(clear_b, &client_key);
+
+        let result: i16 = (&a).saturating_add(&b).decrypt(&client_key);
+        assert_eq!(result, clear_a.saturating_add(clear_b));
+        let result: i16 = (&a).saturating_sub(&b).decrypt(&client_key);
+        assert_eq!(result, clear_a.saturating_sub(clear_b));
+        let result: i16 = (&a).saturating_mul(&b).decrypt(&client_key);
+        assert_eq!(result, clear_a.saturating_mul(clear_b));
+
+        let result: i16 = (&a).saturating_add(clear_b).decrypt(&client_key);
+        assert_eq!(result, clear_a.saturating_add(clear_b));
+        let result: i16 = (&a).saturating_sub(clear_b).decrypt(&client_key);
+        assert_eq!(result, clear_a.saturating_sub(clear_b));
+        let result: i16 = (&a).saturating_mul(clear_b).decrypt(&client_key);
+        assert_eq!(result, clear_a.saturating_mul(clear_b));
+    }
+}
+
+#[test]
+fn test_conv2d() {
+    let config = ConfigBuilder::default().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+
+    let clear_image = [[0i8; 4]; 4].map(|row| row.map(|_| rng.gen::<i8>()));
+    let kernel = [[1i8, -2, 3], [0, 5, -6], [-7, 8, 0]];
+    let (stride, padding) = (2, 1);
+
+    let image = clear_image.map(|row| row.map(|pixel| FheInt8::encrypt(pixel, &client_key)));
+
+    let result = crate::algorithms::fhe_conv2d(&image, &kernel, stride, padding);
+    assert_eq!(result.len(), 2);
+
+    for (output_y, row) in result.iter().enumerate() {
+        assert_eq!(row.len(), 2);
+        for (output_x, pixel) in row.iter().enumerate() {
+            let mut expected = 0i8;
+            for (kernel_y, kernel_row) in kernel.iter().enumerate() {
+                for (kernel_x, &coefficient) in kernel_row.iter().enumerate() {
+                    let y = (output_y * stride + kernel_y).checked_sub(padding);
+                    let x = (output_x * stride + kernel_x).checked_sub(padding);
+                    if let (Some(y @ 0..=3), Some(x @ 0..=3)) = (y, x) {
+                        expected =
+                            expected.wrapping_add(clear_image[y][x].wrapping_mul(coefficient));
+                    }
+                }
+            }
+            let decrypted: i8 = pixel.decrypt(&client_key);
+            assert_eq!(decrypted, expected);
+        }
+    }
+}
+
 #[test]
 fn test_integer_compress_decompress() {
     let config = ConfigBuilder::default().build();
@@ -539,6 +709,33 @@ fn test_trivial_fhe_int256_small() {
     let clear: I256 = a.decrypt(&client_key);
     assert_eq!(clear, clear_a);
 }
+
+#[test]
+fn test_fhe_int512_scalar_ops() {
+    let config = ConfigBuilder::default().build();
+    let (client_key, sks) = generate_keys(config);
+
+    set_server_key(sks);
+
+    // Trivial ciphertexts are used to keep the test fast
+    let clear_a = I512::MIN + I512::from(123_456_789i64);
+    let clear_b = I512::from(-987_654_321i64);
+    let a = FheInt512::encrypt_trivial(clear_a);
+
+    let clear: I512 = (&a + clear_b).decrypt(&client_key);
+    assert_eq!(clear, clear_a + clear_b);
+
+    let (q, r) = a.div_rem(clear_b);
+    let q: I512 = q.decrypt(&client_key);
+    let r: I512 = r.decrypt(&client_key);
+    assert_eq!(q, clear_a / clear_b);
+    assert_eq!(r, clear_a % clear_b);
+
+    assert!(a.lt(clear_b).decrypt(&client_key));
+    let clear: I512 = (-&a).decrypt(&client_key);
+    assert_eq!(clear, -clear_a);
+}
+
 #[test]
 fn test_compact_public_key_big() {
     let config = ConfigBuilder::default()
@@ -624,6 +821,35 @@ fn test_case_ilog2(cks: &ClientKey) {
     }
 }
 
+#[test]
+fn test_le_bytes() {
+    let config = ConfigBuilder::default().build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+
+    let clear = rng.gen_range(i64::MIN..0);
+    let a = FheInt64::encrypt(clear, &client_key);
+
+    let bytes = a.to_le_bytes();
+    assert_eq!(bytes.len(), 8);
+    let decrypted = bytes
+        .iter()
+        .map(|byte| byte.decrypt(&client_key))
+        .collect::<Vec<u8>>();
+    assert_eq!(decrypted, clear.to_le_bytes());
+
+    // Flip the sign byte before regrouping the bytes
+    let mut bytes = bytes;
+    bytes[7] = &bytes[7] ^ 0x80u8;
+    let b = FheInt64::from_le_bytes(&bytes);
+    let decrypted: i64 = b.decrypt(&client_key);
+    let mut expected = clear.to_le_bytes();
+    expected[7] ^= 0x80;
+    assert_eq!(decrypted, i64::from_le_bytes(expected));
+}
+
 #[test]
 fn test_ilog2() {
     let (client_key, server_key) = generate_keys(ConfigBuilder::default());
@@ -688,3 +914,29 @@ fn test_safe_deserialize_conformant_compressed_fhe_int32() {
     let decrypted: i32 = deserialized_a.decompress().decrypt(&client_key);
     assert_eq!(decrypted, clear_a);
 }
+
+#[test]
+fn test_odd_width_signed_types() {
+    let config = ConfigBuilder::default().build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    let max_24 = (1i32 << 23) - 1;
+    let a = FheInt24::encrypt(max_24, &client_key);
+    let decrypted: i32 = (&a + 1i32).decrypt(&client_key);
+    assert_eq!(decrypted, -(1i32 << 23));
+
+    let a = FheInt40::encrypt(-123_456_789_012i64, &client_key);
+    let decrypted: i64 = (-&a).decrypt(&client_key);
+    assert_eq!(decrypted, 123_456_789_012i64);
+
+    let a = FheInt48::encrypt(-5i64, &client_key);
+    assert!(a.lt(0i64).decrypt(&client_key));
+    let decrypted: i64 = a.abs().decrypt(&client_key);
+    assert_eq!(decrypted, 5);
+
+    let min_72 = -(1i128 << 71);
+    let a = FheInt72::encrypt(min_72, &client_key);
+    let decrypted: i128 = (&a - 1i128).decrypt(&client_key);
+    assert_eq!(decrypted, (1i128 << 71) - 1);
+}
@@ -0,0 +1,148 @@
+use super::base::{FheUint, FheUintId};
+use crate::backward_compatibility::integers::FheAccumulatorVersions;
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::IntegerId;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::policy::OperationKind;
+use crate::integer::IntegerRadixCiphertext;
+use crate::named::Named;
+use std::marker::PhantomData;
+use tfhe_versionable::Versionize;
+
+/// Accumulates encrypted values given one at a time, e.g. as they arrive from a stream of events
+///
+/// Each value is added without propagating the carries, which are left in the carry space of
+/// the blocks. The carries are only propagated when the carry space of the sum would not be able
+/// to hold the next value, and once at the end in [Self::finish]. Compared to a sequence of `+=`,
+/// this saves most of the carry propagations, while keeping a single ciphertext as state.
+///
+/// The sum wraps around if it does not fit in the type.
+///
+/// The accumulator can be serialized, e.g. to checkpoint a long running accumulation and resume
+/// it later.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAccumulator, FheUint64};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let mut accumulator = FheAccumulator::<FheUint64>::new();
+/// for clear in [12u64, 5000, 7, 123456] {
+///     let value = FheUint64::encrypt(clear, &client_key);
+///     accumulator.absorb(&value);
+/// }
+///
+/// let sum = accumulator.finish();
+/// let decrypted: u64 = sum.decrypt(&client_key);
+/// assert_eq!(decrypted, 12 + 5000 + 7 + 123456);
+/// ```
+#[derive(Clone, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(FheAccumulatorVersions)]
+pub struct FheAccumulator<T> {
+    sum: Option<crate::integer::RadixCiphertext>,
+    _marker: PhantomData<T>,
+}
+
+impl<T> Named for FheAccumulator<T> {
+    const NAME: &'static str = "high_level_api::FheAccumulator";
+}
+
+impl<Id> Default for FheAccumulator<FheUint<Id>>
+where
+    Id: FheUintId,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Id> FheAccumulator<FheUint<Id>>
+where
+    Id: FheUintId,
+{
+    /// Creates an accumulator whose sum is zero
+    pub fn new() -> Self {
+        Self {
+            sum: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Adds the value to the sum
+    ///
+    /// # Panics
+    ///
+    /// Panics if the server key is on a Cuda device, which is not supported yet.
+    pub fn absorb(&mut self, value: &FheUint<Id>) {
+        let _permit = value.enforce_operation_policy(OperationKind::Add);
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sks = cpu_key.pbs_key();
+                let mut value = value.ciphertext.on_cpu().into_owned();
+
+                let Some(sum) = self.sum.as_mut() else {
+                    self.sum = Some(value);
+                    return;
+                };
+
+                if sks.is_add_possible(sum, &value).is_err() {
+                    // Once both operands are clean, the addition is always possible
+                    rayon::join(
+                        || {
+                            if !sum.block_carries_are_empty() {
+                                sks.full_propagate_parallelized(sum);
+                            }
+                        },
+                        || {
+                            if !value.block_carries_are_empty() {
+                                sks.full_propagate_parallelized(&mut value);
+                            }
+                        },
+                    );
+                }
+                sks.unchecked_add_assign(sum, &value);
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support FheAccumulator yet");
+            }
+        })
+    }
+
+    /// Returns the sum of all the absorbed values, with its carries propagated
+    ///
+    /// The sum of no values is zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the server key is on a Cuda device, which is not supported yet.
+    pub fn finish(self) -> FheUint<Id> {
+        // The additions were checked against the policy when the values were absorbed
+        let _permit = global_state::permit_unrestricted_operation();
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sks = cpu_key.pbs_key();
+                let sum = match self.sum {
+                    Some(mut sum) => {
+                        if !sum.block_carries_are_empty() {
+                            sks.full_propagate_parallelized(&mut sum);
+                        }
+                        sum
+                    }
+                    None => {
+                        sks.create_trivial_zero_radix(Id::num_blocks(cpu_key.message_modulus()))
+                    }
+                };
+                FheUint::new(sum, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support FheAccumulator yet");
+            }
+        })
+    }
+}
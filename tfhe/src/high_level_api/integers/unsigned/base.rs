@@ -9,13 +9,18 @@ use crate::high_level_api::global_state::with_thread_local_cuda_streams;
 use crate::high_level_api::integers::signed::{FheInt, FheIntId};
 use crate::high_level_api::integers::IntegerId;
 use crate::high_level_api::keys::InternalServerKey;
-use crate::high_level_api::traits::Tagged;
+use crate::high_level_api::policy::{OperationKind, OperationPermit};
+use crate::high_level_api::traits::{
+    FheMax, FheMin, OverflowingCastFrom, SaturatingCastFrom, SelectCase, Tagged,
+};
+use crate::high_level_api::utils::block_bytes;
 use crate::high_level_api::{global_state, Device};
 use crate::integer::block_decomposition::{DecomposableInto, RecomposableFrom};
 use crate::integer::parameters::RadixCiphertextConformanceParams;
 use crate::integer::server_key::MatchValues;
 use crate::named::Named;
 use crate::prelude::CastInto;
+use crate::safe_serialization::{SerializedSizeBound, OBJECT_METADATA_SIZE_BOUND};
 use crate::shortint::ciphertext::NotTrivialCiphertextError;
 use crate::shortint::PBSParameters;
 use crate::{FheBool, ServerKey, Tag};
@@ -83,11 +88,20 @@ pub struct FheUint<Id: FheUintId> {
 }
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
 pub struct FheUintConformanceParams<Id: FheUintId> {
     pub(crate) params: RadixCiphertextConformanceParams,
+    #[cfg_attr(feature = "json", serde(skip))]
     pub(crate) id: PhantomData<Id>,
 }
 
+#[cfg(feature = "json")]
+impl<Id: FheUintId> crate::json::ToJsonPretty for FheUintConformanceParams<Id> {
+    fn to_json_pretty(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
 impl<Id: FheUintId, P: Into<PBSParameters>> From<P> for FheUintConformanceParams<Id> {
     fn from(params: P) -> Self {
         let params = params.into();
@@ -127,6 +141,13 @@ impl<Id: FheUintId> ParameterSetConformant for FheUint<Id> {
     }
 }
 
+impl<Id: FheUintId> SerializedSizeBound for FheUint<Id> {
+    fn serialized_size_bound(params: &FheUintConformanceParams<Id>) -> u64 {
+        crate::integer::RadixCiphertext::serialized_size_bound(&params.params)
+            + OBJECT_METADATA_SIZE_BOUND
+    }
+}
+
 impl<Id: FheUintId> Named for FheUint<Id> {
     const NAME: &'static str = "high_level_api::FheUint";
 }
@@ -183,6 +204,15 @@ where
         Id::num_bits()
     }
 
+    /// Checks that the [OperationPolicy](crate::OperationPolicy) of the server key, if any,
+    /// permits the operation on this [FheUint]
+    pub(in crate::high_level_api) fn enforce_operation_policy(
+        &self,
+        kind: OperationKind,
+    ) -> OperationPermit {
+        global_state::enforce_operation_policy(kind, Self::num_bits(), &self.tag)
+    }
+
     pub(in crate::high_level_api) fn move_to_device_of_server_key_if_set(&mut self) {
         self.ciphertext.move_to_device_of_server_key_if_set();
     }
@@ -268,6 +298,128 @@ where
         })
     }
 
+    /// Returns a FheBool that encrypts `true` if the value is zero
+    ///
+    /// This is cheaper than comparing with zero using `eq`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(0u16, &client_key);
+    ///
+    /// let result = a.is_zero();
+    /// let decrypted = result.decrypt(&client_key);
+    /// assert!(decrypted);
+    /// ```
+    pub fn is_zero(&self) -> FheBool {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .is_zero_parallelized(&*self.ciphertext.on_cpu());
+                FheBool::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(cuda_key) => with_thread_local_cuda_streams(|streams| {
+                let result = cuda_key
+                    .key
+                    .key
+                    .scalar_eq(&*self.ciphertext.on_gpu(), 0u64, streams);
+                FheBool::new(result, cuda_key.tag.clone())
+            }),
+        })
+    }
+
+    /// Returns a FheBool that encrypts `true` if the value is not zero
+    ///
+    /// This is cheaper than comparing with zero using `ne`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(42u16, &client_key);
+    ///
+    /// let result = a.is_nonzero();
+    /// let decrypted = result.decrypt(&client_key);
+    /// assert!(decrypted);
+    /// ```
+    pub fn is_nonzero(&self) -> FheBool {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .is_nonzero_parallelized(&*self.ciphertext.on_cpu());
+                FheBool::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(cuda_key) => with_thread_local_cuda_streams(|streams| {
+                let result = cuda_key
+                    .key
+                    .key
+                    .scalar_ne(&*self.ciphertext.on_gpu(), 0u64, streams);
+                FheBool::new(result, cuda_key.tag.clone())
+            }),
+        })
+    }
+
+    /// Compares the value against many clear thresholds at once
+    ///
+    /// Returns, for each threshold, a FheBool that encrypts `true` if the value is greater than
+    /// or equal to the threshold. The results are in the same order as the thresholds.
+    ///
+    /// The work shared by the thresholds (i.e. comparing the same part of the value with the same
+    /// clear digits) is done only once, which makes this much cheaper than calling `ge` for each
+    /// threshold, e.g. to classify a value in bands.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(1500u16, &client_key);
+    ///
+    /// let results = a.cmp_against_thresholds(&[100, 1000, 2000, 10000]);
+    /// let decrypted = results
+    ///     .iter()
+    ///     .map(|result| result.decrypt(&client_key))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(decrypted, vec![true, true, false, false]);
+    ///
+    /// // The band the value falls in is the number of thresholds it reaches
+    /// let band = decrypted.iter().filter(|&&is_ge| is_ge).count();
+    /// assert_eq!(band, 2);
+    /// ```
+    pub fn cmp_against_thresholds(&self, thresholds: &[u64]) -> Vec<FheBool> {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => cpu_key
+                .pbs_key()
+                .scalar_ge_thresholds_parallelized(&*self.ciphertext.on_cpu(), thresholds)
+                .into_iter()
+                .map(|result| FheBool::new(result, cpu_key.tag.clone()))
+                .collect(),
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support cmp_against_thresholds yet");
+            }
+        })
+    }
+
     /// Tries to decrypt a trivial ciphertext
     ///
     /// Trivial ciphertexts are ciphertexts which are not encrypted
@@ -327,6 +479,35 @@ where
         self.ciphertext.on_cpu().is_trivial()
     }
 
+    /// Returns the bytes of each block of the ciphertext
+    ///
+    /// The bytes are the ones of the LWE ciphertexts of the blocks, with their coefficients in
+    /// little endian, so they do not depend on the platform nor on the serialization format.
+    /// Identical ciphertexts (e.g. the same trivial constant) have the same bytes, which
+    /// lets storage layers hash and deduplicate ciphertexts without serializing them.
+    ///
+    /// The metadata of the blocks (degree, noise level) are not included.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt_trivial(3u16);
+    /// let b = FheUint16::encrypt_trivial(3u16);
+    /// let c = FheUint16::encrypt(3u16, &client_key);
+    ///
+    /// assert_eq!(a.as_block_bytes(), b.as_block_bytes());
+    /// assert_ne!(a.as_block_bytes(), c.as_block_bytes());
+    /// ```
+    pub fn as_block_bytes(&self) -> Vec<Vec<u8>> {
+        block_bytes(&self.ciphertext.on_cpu().blocks)
+    }
+
     /// Sums multiple ciphertexts together.
     ///
     /// This is much more efficient than manually calling the `+` operator, thus
@@ -361,6 +542,236 @@ where
         collection.as_ref().iter().copied().sum()
     }
 
+    /// Returns the number of distinct values in the slice.
+    ///
+    /// All the pairs of values are compared, so the cost is quadratic in the number of values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let values = [3u8, 1, 3, 7]
+    ///     .iter()
+    ///     .map(|&value| FheUint8::encrypt(value, &client_key))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let result = FheUint8::count_distinct(&values);
+    /// let decrypted: u32 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 3);
+    /// ```
+    pub fn count_distinct(values: &[Self]) -> super::FheUint32 {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let cts = values
+                    .iter()
+                    .map(|value| value.ciphertext.on_cpu().into_owned())
+                    .collect::<Vec<_>>();
+                let result = cpu_key.pbs_key().count_distinct_parallelized(&cts);
+                let result = cpu_key.pbs_key().cast_to_unsigned(
+                    result,
+                    super::FheUint32Id::num_blocks(cpu_key.pbs_key().message_modulus()),
+                );
+                super::FheUint32::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support count_distinct yet");
+            }
+        })
+    }
+
+    /// Computes the product of a clear matrix with an encrypted vector.
+    ///
+    /// Returns one value per row of the matrix, each being the sum of the products of the row
+    /// coefficients with the vector values, with wrapping arithmetic. Negative coefficients are
+    /// supported.
+    ///
+    /// The rows are computed in parallel, and the carries of each row are propagated only once,
+    /// which is much more efficient than computing each product and sum separately.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is empty or if a row does not have one coefficient per vector value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let matrix = [[1i64, 2, 3], [-1, 0, 5]];
+    /// let vector = [4u8, 5, 6]
+    ///     .iter()
+    ///     .map(|&value| FheUint8::encrypt(value, &client_key))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let result = FheUint8::clear_matrix_mul_enc_vector(&matrix, &vector);
+    /// let decrypted = result
+    ///     .iter()
+    ///     .map(|value| value.decrypt(&client_key))
+    ///     .collect::<Vec<u8>>();
+    /// assert_eq!(decrypted, vec![32, 26]);
+    /// ```
+    pub fn clear_matrix_mul_enc_vector<Row>(matrix: &[Row], vector: &[Self]) -> Vec<Self>
+    where
+        Row: AsRef<[i64]> + Sync,
+    {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let cts = vector
+                    .iter()
+                    .map(|value| value.ciphertext.on_cpu().into_owned())
+                    .collect::<Vec<_>>();
+                cpu_key
+                    .pbs_key()
+                    .clear_matrix_mul_enc_vector_parallelized(matrix, &cts)
+                    .into_iter()
+                    .map(|ct| Self::new(ct, cpu_key.tag.clone()))
+                    .collect()
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support clear_matrix_mul_enc_vector yet");
+            }
+        })
+    }
+
+    /// Sums the values in order, until the deadline is reached.
+    ///
+    /// Returns the sum of the first values of the slice, along with the number of values included
+    /// in it. The values are summed by chunks to benefit from [Self::sum], and the deadline is
+    /// checked before each chunk, so the computation may end after the deadline by the time
+    /// needed to sum one chunk.
+    ///
+    /// If the deadline is already reached, the result is a trivial zero and no value is included.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let clears = [1u16, 2, 3, 4, 5];
+    /// let values = clears
+    ///     .iter()
+    ///     .map(|&value| FheUint16::encrypt(value, &client_key))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let deadline = Instant::now() + Duration::from_secs(60);
+    /// let (result, count) = FheUint16::sum_until(&values, deadline);
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, clears[..count].iter().sum::<u16>());
+    /// ```
+    pub fn sum_until(values: &[Self], deadline: std::time::Instant) -> (Self, usize) {
+        let chunk_size = rayon::current_num_threads().max(2);
+
+        let mut result = values[..0].iter().sum::<Self>();
+        let mut count = 0;
+        for chunk in values.chunks(chunk_size) {
+            if std::time::Instant::now() >= deadline {
+                break;
+            }
+            result = std::iter::once(&result).chain(chunk).sum();
+            count += chunk.len();
+        }
+
+        (result, count)
+    }
+
+    /// Computes the minimum of the values in order, until the deadline is reached.
+    ///
+    /// Returns the minimum of the first values of the slice, along with the number of values
+    /// included in it, or `None` if the slice is empty. The first value is always included as it
+    /// requires no computation. The deadline is checked before each comparison.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let clears = [4u16, 2, 5, 1];
+    /// let values = clears
+    ///     .iter()
+    ///     .map(|&value| FheUint16::encrypt(value, &client_key))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let deadline = Instant::now() + Duration::from_secs(60);
+    /// let (result, count) = FheUint16::min_until(&values, deadline).unwrap();
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, *clears[..count].iter().min().unwrap());
+    /// ```
+    pub fn min_until(values: &[Self], deadline: std::time::Instant) -> Option<(Self, usize)> {
+        Self::reduce_until(values, deadline, |lhs, rhs| FheMin::min(lhs, rhs))
+    }
+
+    /// Computes the maximum of the values in order, until the deadline is reached.
+    ///
+    /// Returns the maximum of the first values of the slice, along with the number of values
+    /// included in it, or `None` if the slice is empty. The first value is always included as it
+    /// requires no computation. The deadline is checked before each comparison.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let clears = [4u16, 2, 5, 1];
+    /// let values = clears
+    ///     .iter()
+    ///     .map(|&value| FheUint16::encrypt(value, &client_key))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let deadline = Instant::now() + Duration::from_secs(60);
+    /// let (result, count) = FheUint16::max_until(&values, deadline).unwrap();
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, *clears[..count].iter().max().unwrap());
+    /// ```
+    pub fn max_until(values: &[Self], deadline: std::time::Instant) -> Option<(Self, usize)> {
+        Self::reduce_until(values, deadline, |lhs, rhs| FheMax::max(lhs, rhs))
+    }
+
+    fn reduce_until(
+        values: &[Self],
+        deadline: std::time::Instant,
+        op: impl Fn(&Self, &Self) -> Self,
+    ) -> Option<(Self, usize)> {
+        let (first, rest) = values.split_first()?;
+
+        let mut result = first.clone();
+        let mut count = 1;
+        for value in rest {
+            if std::time::Instant::now() >= deadline {
+                break;
+            }
+            result = op(&result, value);
+            count += 1;
+        }
+
+        Some((result, count))
+    }
+
     /// Returns the number of leading zeros in the binary representation of self.
     ///
     /// # Example
@@ -379,6 +790,7 @@ where
     /// assert_eq!(decrypted, 2);
     /// ```
     pub fn leading_zeros(&self) -> super::FheUint32 {
+        let _permit = self.enforce_operation_policy(OperationKind::BitCount);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -424,6 +836,7 @@ where
     /// assert_eq!(decrypted, 2);
     /// ```
     pub fn leading_ones(&self) -> super::FheUint32 {
+        let _permit = self.enforce_operation_policy(OperationKind::BitCount);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -469,6 +882,7 @@ where
     /// assert_eq!(decrypted, 3);
     /// ```
     pub fn trailing_zeros(&self) -> super::FheUint32 {
+        let _permit = self.enforce_operation_policy(OperationKind::BitCount);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -514,6 +928,7 @@ where
     /// assert_eq!(decrypted, 3);
     /// ```
     pub fn trailing_ones(&self) -> super::FheUint32 {
+        let _permit = self.enforce_operation_policy(OperationKind::BitCount);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -560,6 +975,7 @@ where
     /// assert_eq!(decrypted, clear_a.count_ones());
     /// ```
     pub fn count_ones(&self) -> super::FheUint32 {
+        let _permit = self.enforce_operation_policy(OperationKind::BitCount);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -597,6 +1013,7 @@ where
     /// assert_eq!(decrypted, clear_a.count_zeros());
     /// ```
     pub fn count_zeros(&self) -> super::FheUint32 {
+        let _permit = self.enforce_operation_policy(OperationKind::BitCount);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -615,9 +1032,9 @@ where
         })
     }
 
-    /// Returns the base 2 logarithm of the number, rounded down.
+    /// Returns the number of bits that differ between self and other.
     ///
-    /// Result has no meaning if self encrypts 0. See [Self::checked_ilog2]
+    /// This is the number of ones of `self ^ other`, the xor and the count are fused.
     ///
     /// # Example
     ///
@@ -628,18 +1045,22 @@ where
     /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
     /// set_server_key(server_key);
     ///
-    /// let a = FheUint16::encrypt(2u16, &client_key);
+    /// let clear_a = 0b0110_1001_0000_1111u16;
+    /// let clear_b = 0b0110_0001_1100_1011u16;
+    /// let a = FheUint16::encrypt(clear_a, &client_key);
+    /// let b = FheUint16::encrypt(clear_b, &client_key);
     ///
-    /// let result = a.ilog2();
-    /// let decrypted: u16 = result.decrypt(&client_key);
-    /// assert_eq!(decrypted, 1);
+    /// let result = a.hamming_distance(&b);
+    /// let decrypted: u32 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, (clear_a ^ clear_b).count_ones());
     /// ```
-    pub fn ilog2(&self) -> super::FheUint32 {
+    pub fn hamming_distance(&self, other: &Self) -> super::FheUint32 {
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
-                let result = cpu_key
-                    .pbs_key()
-                    .ilog2_parallelized(&*self.ciphertext.on_cpu());
+                let result = cpu_key.pbs_key().hamming_distance_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    &*other.ciphertext.on_cpu(),
+                );
                 let result = cpu_key.pbs_key().cast_to_unsigned(
                     result,
                     super::FheUint32Id::num_blocks(cpu_key.pbs_key().message_modulus()),
@@ -647,21 +1068,15 @@ where
                 super::FheUint32::new(result, cpu_key.tag.clone())
             }
             #[cfg(feature = "gpu")]
-            InternalServerKey::Cuda(cuda_key) => with_thread_local_cuda_streams(|streams| {
-                let result = cuda_key.key.key.ilog2(&*self.ciphertext.on_gpu(), streams);
-                let result = cuda_key.key.key.cast_to_unsigned(
-                    result,
-                    super::FheUint32Id::num_blocks(cuda_key.key.key.message_modulus),
-                    streams,
-                );
-                super::FheUint32::new(result, cuda_key.tag.clone())
-            }),
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support hamming_distance yet");
+            }
         })
     }
 
-    /// Returns the base 2 logarithm of the number, rounded down.
+    /// Returns whether self and other differ by at most `max_distance` bits.
     ///
-    /// Also returns a boolean flag that is true if the result is valid (i.e self was > 0)
+    /// See [Self::hamming_distance]
     ///
     /// # Example
     ///
@@ -672,47 +1087,699 @@ where
     /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
     /// set_server_key(server_key);
     ///
-    /// let a = FheUint16::encrypt(0u16, &client_key);
-    ///
-    /// let (result, is_ok) = a.checked_ilog2();
-    ///
-    /// let is_ok = is_ok.decrypt(&client_key);
-    /// assert!(!is_ok);
+    /// let a = FheUint16::encrypt(0b1111_0000u16, &client_key);
+    /// let b = FheUint16::encrypt(0b1111_0011u16, &client_key);
+    ///
+    /// assert!(a.is_within_hamming(&b, 2).decrypt(&client_key));
+    /// assert!(!a.is_within_hamming(&b, 1).decrypt(&client_key));
+    /// ```
+    pub fn is_within_hamming(&self, other: &Self, max_distance: u32) -> FheBool {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key.pbs_key().is_within_hamming_distance_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    &*other.ciphertext.on_cpu(),
+                    max_distance,
+                );
+                FheBool::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support is_within_hamming yet");
+            }
+        })
+    }
+
+    /// Returns the base 2 logarithm of the number, rounded down.
+    ///
+    /// Result has no meaning if self encrypts 0. See [Self::checked_ilog2]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(2u16, &client_key);
+    ///
+    /// let result = a.ilog2();
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 1);
+    /// ```
+    pub fn ilog2(&self) -> super::FheUint32 {
+        let _permit = self.enforce_operation_policy(OperationKind::BitCount);
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .ilog2_parallelized(&*self.ciphertext.on_cpu());
+                let result = cpu_key.pbs_key().cast_to_unsigned(
+                    result,
+                    super::FheUint32Id::num_blocks(cpu_key.pbs_key().message_modulus()),
+                );
+                super::FheUint32::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(cuda_key) => with_thread_local_cuda_streams(|streams| {
+                let result = cuda_key.key.key.ilog2(&*self.ciphertext.on_gpu(), streams);
+                let result = cuda_key.key.key.cast_to_unsigned(
+                    result,
+                    super::FheUint32Id::num_blocks(cuda_key.key.key.message_modulus),
+                    streams,
+                );
+                super::FheUint32::new(result, cuda_key.tag.clone())
+            }),
+        })
+    }
+
+    /// Returns the base 2 logarithm of the number, rounded down.
+    ///
+    /// Also returns a boolean flag that is true if the result is valid (i.e self was > 0)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(0u16, &client_key);
+    ///
+    /// let (result, is_ok) = a.checked_ilog2();
+    ///
+    /// let is_ok = is_ok.decrypt(&client_key);
+    /// assert!(!is_ok);
     ///
     /// let decrypted: u16 = result.decrypt(&client_key);
     /// assert_eq!(decrypted, 63); // result is meaningless
     /// ```
-    pub fn checked_ilog2(&self) -> (super::FheUint32, FheBool) {
+    pub fn checked_ilog2(&self) -> (super::FheUint32, FheBool) {
+        let _permit = self.enforce_operation_policy(OperationKind::BitCount);
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let (result, is_ok) = cpu_key
+                    .pbs_key()
+                    .checked_ilog2_parallelized(&*self.ciphertext.on_cpu());
+                let result = cpu_key.pbs_key().cast_to_unsigned(
+                    result,
+                    super::FheUint32Id::num_blocks(cpu_key.pbs_key().message_modulus()),
+                );
+                (
+                    super::FheUint32::new(result, cpu_key.tag.clone()),
+                    FheBool::new(is_ok, cpu_key.tag.clone()),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(cuda_key) => with_thread_local_cuda_streams(|streams| {
+                let (result, is_ok) = cuda_key
+                    .key
+                    .key
+                    .checked_ilog2(&*self.ciphertext.on_gpu(), streams);
+                let result = cuda_key.key.key.cast_to_unsigned(
+                    result,
+                    super::FheUint32Id::num_blocks(cuda_key.key.key.message_modulus),
+                    streams,
+                );
+                (
+                    super::FheUint32::new(result, cuda_key.tag.clone()),
+                    FheBool::new(is_ok, cuda_key.tag.clone()),
+                )
+            }),
+        })
+    }
+
+    /// Computes the quotient of the division of `self` by `rhs`, along with a FheBool that
+    /// encrypts `true` if the divisor is zero
+    ///
+    /// This allows to propagate the division by zero error homomorphically, instead of relying
+    /// on the value the quotient has in that case (the max value), which can also be a valid
+    /// quotient.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(37849u16, &client_key);
+    /// let b = FheUint16::encrypt(0u16, &client_key);
+    ///
+    /// let (result, is_divisor_zero) = a.checked_div(&b);
+    ///
+    /// let is_divisor_zero = is_divisor_zero.decrypt(&client_key);
+    /// assert!(is_divisor_zero);
+    ///
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, u16::MAX); // result is meaningless
+    /// ```
+    pub fn checked_div(&self, rhs: &Self) -> (Self, FheBool) {
+        let _permit = self.enforce_operation_policy(OperationKind::Div);
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let (result, is_divisor_zero) = cpu_key.pbs_key().checked_div_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    &*rhs.ciphertext.on_cpu(),
+                );
+                (
+                    Self::new(result, cpu_key.tag.clone()),
+                    FheBool::new(is_divisor_zero, cpu_key.tag.clone()),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(cuda_key) => with_thread_local_cuda_streams(|streams| {
+                let rhs = rhs.ciphertext.on_gpu();
+                let result = cuda_key
+                    .key
+                    .key
+                    .div(&*self.ciphertext.on_gpu(), &rhs, streams);
+                let is_divisor_zero = cuda_key.key.key.scalar_eq(&*rhs, 0u64, streams);
+                (
+                    Self::new(result, cuda_key.tag.clone()),
+                    FheBool::new(is_divisor_zero, cuda_key.tag.clone()),
+                )
+            }),
+        })
+    }
+
+    /// Computes the remainder of the division of `self` by `rhs`, along with a FheBool that
+    /// encrypts `true` if the divisor is zero
+    ///
+    /// When the divisor is zero, the remainder is the numerator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(37849u16, &client_key);
+    /// let b = FheUint16::encrypt(3u16, &client_key);
+    ///
+    /// let (result, is_divisor_zero) = a.checked_rem(&b);
+    ///
+    /// let is_divisor_zero = is_divisor_zero.decrypt(&client_key);
+    /// assert!(!is_divisor_zero);
+    ///
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 37849u16 % 3);
+    /// ```
+    pub fn checked_rem(&self, rhs: &Self) -> (Self, FheBool) {
+        let _permit = self.enforce_operation_policy(OperationKind::Rem);
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let (result, is_divisor_zero) = cpu_key.pbs_key().checked_rem_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    &*rhs.ciphertext.on_cpu(),
+                );
+                (
+                    Self::new(result, cpu_key.tag.clone()),
+                    FheBool::new(is_divisor_zero, cpu_key.tag.clone()),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(cuda_key) => with_thread_local_cuda_streams(|streams| {
+                let rhs = rhs.ciphertext.on_gpu();
+                let result = cuda_key
+                    .key
+                    .key
+                    .rem(&*self.ciphertext.on_gpu(), &rhs, streams);
+                let is_divisor_zero = cuda_key.key.key.scalar_eq(&*rhs, 0u64, streams);
+                (
+                    Self::new(result, cuda_key.tag.clone()),
+                    FheBool::new(is_divisor_zero, cuda_key.tag.clone()),
+                )
+            }),
+        })
+    }
+
+    /// Multiplies `self` by `rhs` without wrapping around, returning a result of a wider type
+    ///
+    /// The output type must have at least twice as many bits as `Self`, otherwise an error is
+    /// returned, a larger output type is zero extended.
+    ///
+    /// This is cheaper than casting both operands to the wide type and multiplying them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint32};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(60_000u16, &client_key);
+    /// let b = FheUint16::encrypt(50_000u16, &client_key);
+    ///
+    /// let result: FheUint32 = a.widening_mul(&b).unwrap();
+    ///
+    /// let decrypted: u32 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 60_000u32 * 50_000u32);
+    /// ```
+    pub fn widening_mul<OutId>(&self, rhs: &Self) -> crate::Result<FheUint<OutId>>
+    where
+        OutId: FheUintId,
+    {
+        // The product is computed on the bits of the output
+        let _permit = global_state::enforce_operation_policy(
+            OperationKind::Mul,
+            OutId::num_bits(),
+            &self.tag,
+        );
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let target_num_blocks = OutId::num_blocks(cpu_key.message_modulus());
+                if target_num_blocks < 2 * Id::num_blocks(cpu_key.message_modulus()) {
+                    return Err(crate::Error::new(
+                        "Output type does not have enough bits to represent the full product"
+                            .to_string(),
+                    ));
+                }
+                let result = cpu_key
+                    .pbs_key()
+                    .widening_mul_parallelized(&self.ciphertext.on_cpu(), &rhs.ciphertext.on_cpu());
+                let result = cpu_key
+                    .pbs_key()
+                    .cast_to_unsigned(result, target_num_blocks);
+                Ok(FheUint::new(result, cpu_key.tag.clone()))
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support widening_mul yet");
+            }
+        })
+    }
+
+    /// Returns the high half of the product of `self` and `rhs`
+    ///
+    /// That is, the most significant bits of the product that `*` would wrap around.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(60_000u16, &client_key);
+    /// let b = FheUint16::encrypt(50_000u16, &client_key);
+    ///
+    /// let result = a.mul_high(&b);
+    ///
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, ((60_000u32 * 50_000u32) >> 16) as u16);
+    /// ```
+    pub fn mul_high(&self, rhs: &Self) -> Self {
+        let _permit = self.enforce_operation_policy(OperationKind::Mul);
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .mul_high_parallelized(&self.ciphertext.on_cpu(), &rhs.ciphertext.on_cpu());
+                Self::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support mul_high yet");
+            }
+        })
+    }
+
+    /// Raises `self` to the power of an encrypted `exponent`, wrapping around on overflow
+    ///
+    /// The cost grows with the number of bits of the exponent, when the exponent is known by
+    /// the server, [Self::pow_scalar] is much cheaper.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(3u8, &client_key);
+    /// let b = FheUint8::encrypt(7u8, &client_key);
+    ///
+    /// let result = a.pow(&b);
+    ///
+    /// let decrypted: u8 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 3u8.wrapping_pow(7));
+    /// ```
+    pub fn pow(&self, exponent: &Self) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .pow_parallelized(&*self.ciphertext.on_cpu(), &*exponent.ciphertext.on_cpu());
+                Self::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support pow yet");
+            }
+        })
+    }
+
+    /// Raises `self` to the power of a clear `exponent`, wrapping around on overflow
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(11u16, &client_key);
+    ///
+    /// let result = a.pow_scalar(4);
+    ///
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 11u16.wrapping_pow(4));
+    /// ```
+    pub fn pow_scalar(&self, exponent: u32) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .scalar_pow_parallelized(&*self.ciphertext.on_cpu(), exponent);
+                Self::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support pow_scalar yet");
+            }
+        })
+    }
+
+    /// Computes `base` raised to the power of an encrypted `exponent`, modulo `modulus`,
+    /// where `base` and `modulus` are clear
+    ///
+    /// As the base is clear, its powers are precomputed in the clear and selected by the digits
+    /// of the exponent, which is much cheaper than [Self::pow].
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `modulus` is 0
+    /// - Panics if `modulus - 1` cannot be represented by this type
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let exponent = FheUint16::encrypt(3u16, &client_key);
+    ///
+    /// let result = FheUint16::scalar_base_pow_mod(7, &exponent, 1000);
+    ///
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 343);
+    /// ```
+    pub fn scalar_base_pow_mod(base: u64, exponent: &Self, modulus: u64) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key.pbs_key().scalar_base_pow_mod_parallelized(
+                    base,
+                    &exponent.ciphertext.on_cpu(),
+                    modulus,
+                );
+                Self::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support scalar_base_pow_mod yet");
+            }
+        })
+    }
+
+    /// Computes `(self + rhs) % modulus`, where `modulus` is clear
+    ///
+    /// Unlike `(a + b) % modulus`, the sum does not wrap around before being reduced, and only
+    /// needs a conditional subtraction of the modulus instead of a division.
+    ///
+    /// The operands must be smaller than `modulus`, otherwise the result is meaningless.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `modulus` is 0
+    /// - Panics if `modulus - 1` cannot be represented by this type
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(60000u16, &client_key);
+    /// let b = FheUint16::encrypt(50000u16, &client_key);
+    ///
+    /// let result = a.mod_add(&b, 65521);
+    ///
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, ((60000u32 + 50000) % 65521) as u16);
+    /// ```
+    pub fn mod_add(&self, rhs: &Self, modulus: u64) -> Self {
+        let _permit = self.enforce_operation_policy(OperationKind::Add);
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key.pbs_key().mod_add_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    &*rhs.ciphertext.on_cpu(),
+                    modulus,
+                );
+                Self::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support mod_add yet");
+            }
+        })
+    }
+
+    /// Computes `(self - rhs) % modulus`, where `modulus` is clear
+    ///
+    /// The result is always in `[0, modulus)`.
+    ///
+    /// The operands must be smaller than `modulus`, otherwise the result is meaningless.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `modulus` is 0
+    /// - Panics if `modulus - 1` cannot be represented by this type
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(50000u16, &client_key);
+    /// let b = FheUint16::encrypt(60000u16, &client_key);
+    ///
+    /// let result = a.mod_sub(&b, 65521);
+    ///
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, ((50000u32 + 65521 - 60000) % 65521) as u16);
+    /// ```
+    pub fn mod_sub(&self, rhs: &Self, modulus: u64) -> Self {
+        let _permit = self.enforce_operation_policy(OperationKind::Sub);
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key.pbs_key().mod_sub_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    &*rhs.ciphertext.on_cpu(),
+                    modulus,
+                );
+                Self::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support mod_sub yet");
+            }
+        })
+    }
+
+    /// Computes `(self * rhs) % modulus`, where `modulus` is clear
+    ///
+    /// The product is computed without wrapping around, and reduced with a Barrett reduction
+    /// whose constants are precomputed from the clear modulus, which is much cheaper than a
+    /// multiplication on a wider type followed by a remainder.
+    ///
+    /// The operands must be smaller than `modulus`, otherwise the result is meaningless.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `modulus` is 0
+    /// - Panics if `modulus - 1` cannot be represented by this type
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(60000u16, &client_key);
+    /// let b = FheUint16::encrypt(50000u16, &client_key);
+    ///
+    /// let result = a.mod_mul(&b, 65521);
+    ///
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, ((60000u64 * 50000) % 65521) as u16);
+    /// ```
+    pub fn mod_mul(&self, rhs: &Self, modulus: u64) -> Self {
+        let _permit = self.enforce_operation_policy(OperationKind::Mul);
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key.pbs_key().mod_mul_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    &*rhs.ciphertext.on_cpu(),
+                    modulus,
+                );
+                Self::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support mod_mul yet");
+            }
+        })
+    }
+
+    /// Computes `self` raised to the power of the clear `exponent`, modulo `modulus`, where
+    /// `modulus` is clear
+    ///
+    /// All the intermediate products are reduced with [Self::mod_mul].
+    ///
+    /// The operands must be smaller than `modulus`, otherwise the result is meaningless.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `modulus` is 0
+    /// - Panics if `modulus - 1` cannot be represented by this type
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(7u16, &client_key);
+    ///
+    /// let result = a.mod_pow_scalar(10, 65521);
+    ///
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, (7u64.pow(10) % 65521) as u16);
+    /// ```
+    pub fn mod_pow_scalar(&self, exponent: u64, modulus: u64) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key.pbs_key().scalar_pow_mod_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    exponent,
+                    modulus,
+                );
+                Self::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support mod_pow_scalar yet");
+            }
+        })
+    }
+
+    /// Computes the integer square root of `self`, rounded down
+    ///
+    /// The root is computed bit by bit, so the cost grows quadratically with the number of bits
+    /// of the type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(1000u16, &client_key);
+    ///
+    /// let result = a.isqrt();
+    ///
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 31);
+    /// ```
+    pub fn isqrt(&self) -> Self {
+        self.sqrt_rem().0
+    }
+
+    /// Computes the integer square root of `self`, rounded down, along with the remainder
+    ///
+    /// Returns `(root, remainder)`, such that `root * root + remainder == self`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(1000u16, &client_key);
+    ///
+    /// let (root, remainder) = a.sqrt_rem();
+    ///
+    /// let root: u16 = root.decrypt(&client_key);
+    /// let remainder: u16 = remainder.decrypt(&client_key);
+    /// assert_eq!(root, 31);
+    /// assert_eq!(remainder, 1000 - 31 * 31);
+    /// ```
+    pub fn sqrt_rem(&self) -> (Self, Self) {
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
-                let (result, is_ok) = cpu_key
+                let (root, remainder) = cpu_key
                     .pbs_key()
-                    .checked_ilog2_parallelized(&*self.ciphertext.on_cpu());
-                let result = cpu_key.pbs_key().cast_to_unsigned(
-                    result,
-                    super::FheUint32Id::num_blocks(cpu_key.pbs_key().message_modulus()),
-                );
+                    .sqrt_rem_parallelized(&*self.ciphertext.on_cpu());
                 (
-                    super::FheUint32::new(result, cpu_key.tag.clone()),
-                    FheBool::new(is_ok, cpu_key.tag.clone()),
+                    Self::new(root, cpu_key.tag.clone()),
+                    Self::new(remainder, cpu_key.tag.clone()),
                 )
             }
             #[cfg(feature = "gpu")]
-            InternalServerKey::Cuda(cuda_key) => with_thread_local_cuda_streams(|streams| {
-                let (result, is_ok) = cuda_key
-                    .key
-                    .key
-                    .checked_ilog2(&*self.ciphertext.on_gpu(), streams);
-                let result = cuda_key.key.key.cast_to_unsigned(
-                    result,
-                    super::FheUint32Id::num_blocks(cuda_key.key.key.message_modulus),
-                    streams,
-                );
-                (
-                    super::FheUint32::new(result, cuda_key.tag.clone()),
-                    FheBool::new(is_ok, cuda_key.tag.clone()),
-                )
-            }),
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support sqrt_rem yet");
+            }
         })
     }
 
@@ -763,126 +1830,447 @@ where
     {
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
-                let (result, matched) = cpu_key
-                    .pbs_key()
-                    .match_value_parallelized(&self.ciphertext.on_cpu(), matches);
-                let target_num_blocks = OutId::num_blocks(cpu_key.message_modulus());
-                if target_num_blocks >= result.blocks.len() {
-                    let result = cpu_key
-                        .pbs_key()
-                        .cast_to_unsigned(result, target_num_blocks);
-                    Ok((
-                        FheUint::new(result, cpu_key.tag.clone()),
-                        FheBool::new(matched, cpu_key.tag.clone()),
-                    ))
-                } else {
-                    Err(crate::Error::new("Output type does not have enough bits to represent all possible output values".to_string()))
+                let (result, matched) = cpu_key
+                    .pbs_key()
+                    .match_value_parallelized(&self.ciphertext.on_cpu(), matches);
+                let target_num_blocks = OutId::num_blocks(cpu_key.message_modulus());
+                if target_num_blocks >= result.blocks.len() {
+                    let result = cpu_key
+                        .pbs_key()
+                        .cast_to_unsigned(result, target_num_blocks);
+                    Ok((
+                        FheUint::new(result, cpu_key.tag.clone()),
+                        FheBool::new(matched, cpu_key.tag.clone()),
+                    ))
+                } else {
+                    Err(crate::Error::new("Output type does not have enough bits to represent all possible output values".to_string()))
+                }
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support match_value yet");
+            }
+        })
+    }
+
+    /// `match` an input value to an output value
+    ///
+    /// - Input values are not required to span all possible values that `self` could hold. And the
+    ///   output type can be different.
+    ///
+    /// If none of the input matched the `self` then, `self` will encrypt the
+    /// value given to `or_value`
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{
+    ///     generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint8, MatchValues,
+    /// };
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(17u16, &client_key);
+    ///
+    /// let match_values = MatchValues::new(vec![
+    ///     (0u16, 3u16), // map 0 to 3
+    ///     (1u16, 234u16),
+    ///     (2u16, 123u16),
+    /// ])
+    /// .unwrap();
+    /// let result: FheUint8 = a.match_value_or(&match_values, 25u16)
+    ///     .unwrap(); // All possible output values fit on a u8
+    ///
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 25u16)
+    /// ```
+    pub fn match_value_or<Clear, OutId>(
+        &self,
+        matches: &MatchValues<Clear>,
+        or_value: Clear,
+    ) -> crate::Result<FheUint<OutId>>
+    where
+        Clear: UnsignedInteger + DecomposableInto<u64> + CastInto<usize>,
+        OutId: FheUintId,
+    {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key.pbs_key().match_value_or_parallelized(
+                    &self.ciphertext.on_cpu(),
+                    matches,
+                    or_value,
+                );
+                let target_num_blocks = OutId::num_blocks(cpu_key.message_modulus());
+                if target_num_blocks >= result.blocks.len() {
+                    let result = cpu_key
+                        .pbs_key()
+                        .cast_to_unsigned(result, target_num_blocks);
+                    Ok(FheUint::new(result, cpu_key.tag.clone()))
+                } else {
+                    Err(crate::Error::new("Output type does not have enough bits to represent all possible output values".to_string()))
+                }
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support match_value_or yet");
+            }
+        })
+    }
+
+    /// Reverse the bit of the unsigned integer
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let msg = 0b10110100_u8;
+    ///
+    /// let a = FheUint8::encrypt(msg, &client_key);
+    ///
+    /// let result: FheUint8 = a.reverse_bits();
+    ///
+    /// let decrypted: u8 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, msg.reverse_bits());
+    /// ```
+    pub fn reverse_bits(&self) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sk = &cpu_key.pbs_key();
+
+                let ct = self.ciphertext.on_cpu();
+
+                Self::new(sk.reverse_bits_parallelized(&*ct), cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support reverse yet");
+            }
+        })
+    }
+
+    /// Selects the bits of `self` where the bits of `mask` are set, and the bits of `other`
+    /// elsewhere, i.e. computes `(self & mask) | (other & !mask)`.
+    ///
+    /// This is the classic branchless bitwise select of constant-time code, computed in a single
+    /// pass over the blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let clear_a = 0b1010_1010u8;
+    /// let clear_b = 0b0101_0101u8;
+    /// let clear_mask = 0b1111_0000u8;
+    ///
+    /// let a = FheUint8::encrypt(clear_a, &client_key);
+    /// let b = FheUint8::encrypt(clear_b, &client_key);
+    /// let mask = FheUint8::encrypt(clear_mask, &client_key);
+    ///
+    /// let result = a.merge_by_mask(&b, &mask);
+    ///
+    /// let decrypted: u8 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, (clear_a & clear_mask) | (clear_b & !clear_mask));
+    /// ```
+    pub fn merge_by_mask(&self, other: &Self, mask: &Self) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sk = &cpu_key.pbs_key();
+
+                let result = sk.merge_by_mask_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    &*other.ciphertext.on_cpu(),
+                    &*mask.ciphertext.on_cpu(),
+                );
+
+                Self::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support merge_by_mask yet");
+            }
+        })
+    }
+
+    /// Reverse the byte order of the unsigned integer
+    ///
+    /// When the parameters allow it (i.e. a byte is made of a whole number of blocks),
+    /// this is a pure permutation of the blocks and no PBS is computed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of bits of the type is not a multiple of 8.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let msg = 0x12_34_u16;
+    ///
+    /// let a = FheUint16::encrypt(msg, &client_key);
+    ///
+    /// let result: FheUint16 = a.swap_bytes();
+    ///
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, msg.swap_bytes());
+    /// ```
+    pub fn swap_bytes(&self) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sk = &cpu_key.pbs_key();
+
+                let ct = self.ciphertext.on_cpu();
+
+                Self::new(sk.swap_bytes_parallelized(&*ct), cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(cuda_key) => with_thread_local_cuda_streams(|streams| {
+                let result = cuda_key
+                    .key
+                    .key
+                    .swap_bytes(&*self.ciphertext.on_gpu(), streams);
+                Self::new(result, cuda_key.tag.clone())
+            }),
+        })
+    }
+
+    /// Rotates the blocks of the unsigned integer to the left by `n` blocks
+    ///
+    /// This is equivalent to rotating the bits to the left by `n * bits_per_block`,
+    /// where `bits_per_block` is the number of message bits of the parameters,
+    /// but as it is a pure permutation of the blocks, no PBS is computed
+    /// (unless the input has carries that need to be propagated first).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let msg = 0x12_34_u16;
+    ///
+    /// let a = FheUint16::encrypt(msg, &client_key);
+    ///
+    /// // Default parameters have 2 bits of message per block
+    /// let result: FheUint16 = a.rotate_blocks(1);
+    ///
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, msg.rotate_left(2));
+    /// ```
+    pub fn rotate_blocks(&self, n: usize) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sk = &cpu_key.pbs_key();
+
+                let ct = self.ciphertext.on_cpu();
+
+                Self::new(sk.rotate_blocks(&*ct, n), cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(cuda_key) => with_thread_local_cuda_streams(|streams| {
+                // Rotating by a whole number of blocks only moves the blocks
+                let num_blocks = Id::num_blocks(cuda_key.message_modulus());
+                let bits_per_block = cuda_key.message_modulus().0.ilog2();
+                let shift = (n % num_blocks) as u32 * bits_per_block;
+                let result =
+                    cuda_key
+                        .key
+                        .key
+                        .scalar_rotate_left(&*self.ciphertext.on_gpu(), shift, streams);
+                Self::new(result, cuda_key.tag.clone())
+            }),
+        })
+    }
+
+    /// Splits the unsigned integer into its bytes, in little endian order
+    ///
+    /// The blocks of the integer are regrouped into bytes, so no PBS is computed
+    /// (unless the input has carries that need to be propagated first).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of bits of the type is not a multiple of 8.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint32};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let msg = 0x12_34_56_78_u32;
+    ///
+    /// let a = FheUint32::encrypt(msg, &client_key);
+    ///
+    /// let bytes = a.to_le_bytes();
+    /// let decrypted = bytes
+    ///     .iter()
+    ///     .map(|byte| byte.decrypt(&client_key))
+    ///     .collect::<Vec<u8>>();
+    /// assert_eq!(decrypted, msg.to_le_bytes());
+    /// ```
+    pub fn to_le_bytes(&self) -> Vec<super::FheUint8> {
+        assert_eq!(
+            Id::num_bits() % 8,
+            0,
+            "The number of bits of the type must be a multiple of 8"
+        );
+
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sk = cpu_key.pbs_key();
+
+                let mut ct = self.ciphertext.on_cpu().into_owned();
+                if !ct.block_carries_are_empty() {
+                    sk.full_propagate_parallelized(&mut ct);
                 }
+
+                let blocks_per_byte = super::FheUint8Id::num_blocks(sk.message_modulus());
+                ct.blocks
+                    .chunks_exact(blocks_per_byte)
+                    .map(|byte_blocks| {
+                        super::FheUint8::new(
+                            crate::integer::RadixCiphertext::from(byte_blocks.to_vec()),
+                            cpu_key.tag.clone(),
+                        )
+                    })
+                    .collect()
             }
             #[cfg(feature = "gpu")]
             InternalServerKey::Cuda(_) => {
-                panic!("Cuda devices do not support match_value yet");
+                panic!("Cuda devices do not support to_le_bytes yet");
             }
         })
     }
 
-    /// `match` an input value to an output value
+    /// Creates an unsigned integer from its bytes, in little endian order
     ///
-    /// - Input values are not required to span all possible values that `self` could hold. And the
-    ///   output type can be different.
+    /// The blocks of the bytes are regrouped into the integer, so no PBS is computed
+    /// (unless the inputs have carries that need to be propagated first).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of bytes does not match the number of bits of the type.
     ///
-    /// If none of the input matched the `self` then, `self` will encrypt the
-    /// value given to `or_value`
     /// # Example
     ///
     /// ```rust
     /// use tfhe::prelude::*;
-    /// use tfhe::{
-    ///     generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint8, MatchValues,
-    /// };
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint32, FheUint8};
     ///
     /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
     /// set_server_key(server_key);
     ///
-    /// let a = FheUint16::encrypt(17u16, &client_key);
+    /// let msg = 0x12_34_56_78_u32;
     ///
-    /// let match_values = MatchValues::new(vec![
-    ///     (0u16, 3u16), // map 0 to 3
-    ///     (1u16, 234u16),
-    ///     (2u16, 123u16),
-    /// ])
-    /// .unwrap();
-    /// let result: FheUint8 = a.match_value_or(&match_values, 25u16)
-    ///     .unwrap(); // All possible output values fit on a u8
+    /// let bytes = msg
+    ///     .to_le_bytes()
+    ///     .iter()
+    ///     .map(|&byte| FheUint8::encrypt(byte, &client_key))
+    ///     .collect::<Vec<_>>();
     ///
-    /// let decrypted: u16 = result.decrypt(&client_key);
-    /// assert_eq!(decrypted, 25u16)
+    /// let a = FheUint32::from_le_bytes(&bytes);
+    /// let decrypted: u32 = a.decrypt(&client_key);
+    /// assert_eq!(decrypted, msg);
     /// ```
-    pub fn match_value_or<Clear, OutId>(
-        &self,
-        matches: &MatchValues<Clear>,
-        or_value: Clear,
-    ) -> crate::Result<FheUint<OutId>>
-    where
-        Clear: UnsignedInteger + DecomposableInto<u64> + CastInto<usize>,
-        OutId: FheUintId,
-    {
+    pub fn from_le_bytes(bytes: &[super::FheUint8]) -> Self {
+        assert_eq!(
+            bytes.len() * 8,
+            Id::num_bits(),
+            "Expected {} bytes, got {}",
+            Id::num_bits() / 8,
+            bytes.len()
+        );
+
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
-                let result = cpu_key.pbs_key().match_value_or_parallelized(
-                    &self.ciphertext.on_cpu(),
-                    matches,
-                    or_value,
-                );
-                let target_num_blocks = OutId::num_blocks(cpu_key.message_modulus());
-                if target_num_blocks >= result.blocks.len() {
-                    let result = cpu_key
-                        .pbs_key()
-                        .cast_to_unsigned(result, target_num_blocks);
-                    Ok(FheUint::new(result, cpu_key.tag.clone()))
-                } else {
-                    Err(crate::Error::new("Output type does not have enough bits to represent all possible output values".to_string()))
-                }
+                let sk = cpu_key.pbs_key();
+
+                let blocks = bytes
+                    .iter()
+                    .flat_map(|byte| {
+                        let mut ct = byte.ciphertext.on_cpu().into_owned();
+                        if !ct.block_carries_are_empty() {
+                            sk.full_propagate_parallelized(&mut ct);
+                        }
+                        ct.blocks
+                    })
+                    .collect::<Vec<_>>();
+
+                Self::new(
+                    crate::integer::RadixCiphertext::from(blocks),
+                    cpu_key.tag.clone(),
+                )
             }
             #[cfg(feature = "gpu")]
             InternalServerKey::Cuda(_) => {
-                panic!("Cuda devices do not support match_value_or yet");
+                panic!("Cuda devices do not support from_le_bytes yet");
             }
         })
     }
 
-    /// Reverse the bit of the unsigned integer
+    /// Computes the quotient and remainder of the division of `self` by `2^divisor_log2`,
+    /// where the exponent is encrypted.
+    ///
+    /// As the divisor is known to be a power of two, this is computed using shifts
+    /// which is much faster than using [DivRem](crate::prelude::DivRem).
+    ///
+    /// Like for shifts, `divisor_log2` is reduced modulo the number of bits of the type.
     ///
     /// # Example
     ///
     /// ```rust
     /// use tfhe::prelude::*;
-    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
     ///
     /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
     /// set_server_key(server_key);
     ///
-    /// let msg = 0b10110100_u8;
-    ///
-    /// let a = FheUint8::encrypt(msg, &client_key);
-    ///
-    /// let result: FheUint8 = a.reverse_bits();
+    /// let a = FheUint16::encrypt(1337u16, &client_key);
+    /// let divisor_log2 = FheUint16::encrypt(4u16, &client_key);
     ///
-    /// let decrypted: u8 = result.decrypt(&client_key);
-    /// assert_eq!(decrypted, msg.reverse_bits());
+    /// let (quotient, remainder) = a.div_rem_pow2_hint(&divisor_log2);
+    /// let quotient: u16 = quotient.decrypt(&client_key);
+    /// let remainder: u16 = remainder.decrypt(&client_key);
+    /// assert_eq!(quotient, 1337u16 / 16);
+    /// assert_eq!(remainder, 1337u16 % 16);
     /// ```
-    pub fn reverse_bits(&self) -> Self {
+    pub fn div_rem_pow2_hint(&self, divisor_log2: &Self) -> (Self, Self) {
+        let _permit = self.enforce_operation_policy(OperationKind::DivRem);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
-                let sk = &cpu_key.pbs_key();
-
-                let ct = self.ciphertext.on_cpu();
-
-                Self::new(sk.reverse_bits_parallelized(&*ct), cpu_key.tag.clone())
+                let (q, r) = cpu_key.pbs_key().div_rem_pow2_hint_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    &*divisor_log2.ciphertext.on_cpu(),
+                );
+                (
+                    Self::new(q, cpu_key.tag.clone()),
+                    Self::new(r, cpu_key.tag.clone()),
+                )
             }
             #[cfg(feature = "gpu")]
             InternalServerKey::Cuda(_) => {
-                panic!("Cuda devices do not support reverse yet");
+                panic!("Cuda devices do not support div_rem_pow2_hint yet");
             }
         })
     }
@@ -895,6 +2283,7 @@ where
     type Error = GenericIntegerBlockError;
 
     fn try_from(other: crate::integer::RadixCiphertext) -> Result<Self, GenericIntegerBlockError> {
+        let _permit = global_state::permit_unrestricted_operation();
         // Get correct carry modulus and message modulus from ServerKey
         let (correct_carry_mod, correct_message_mod) =
             global_state::with_internal_keys(|sks| match sks {
@@ -976,6 +2365,11 @@ where
     /// assert_eq!(decrypted, i32::MIN as u16);
     /// ```
     fn cast_from(input: FheInt<FromId>) -> Self {
+        let _permit = global_state::enforce_operation_policy(
+            OperationKind::Cast,
+            Self::num_bits().max(FromId::num_bits()),
+            &input.tag,
+        );
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let casted = cpu_key.pbs_key().cast_to_unsigned(
@@ -1020,6 +2414,11 @@ where
     /// assert_eq!(decrypted, u32::MAX as u16);
     /// ```
     fn cast_from(input: FheUint<FromId>) -> Self {
+        let _permit = global_state::enforce_operation_policy(
+            OperationKind::Cast,
+            Self::num_bits().max(FromId::num_bits()),
+            &input.tag,
+        );
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let casted = cpu_key.pbs_key().cast_to_unsigned(
@@ -1041,6 +2440,190 @@ where
     }
 }
 
+impl<FromId, IntoId> SaturatingCastFrom<FheInt<FromId>> for FheUint<IntoId>
+where
+    FromId: FheIntId,
+    IntoId: FheUintId,
+{
+    /// Cast a FheInt to an FheUint, clamping the values that do not fit
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-1000i16, &client_key);
+    /// let b = FheUint8::saturating_cast_from(a);
+    ///
+    /// let decrypted: u8 = b.decrypt(&client_key);
+    /// assert_eq!(decrypted, 0u8);
+    /// ```
+    fn saturating_cast_from(input: FheInt<FromId>) -> Self {
+        let _permit = global_state::enforce_operation_policy(
+            OperationKind::Cast,
+            Self::num_bits().max(FromId::num_bits()),
+            &input.tag,
+        );
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let casted = cpu_key.pbs_key().saturating_cast_to_unsigned(
+                    input.ciphertext.into_cpu(),
+                    IntoId::num_blocks(cpu_key.message_modulus()),
+                );
+                Self::new(casted, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support saturating casts yet");
+            }
+        })
+    }
+}
+
+impl<FromId, IntoId> SaturatingCastFrom<FheUint<FromId>> for FheUint<IntoId>
+where
+    FromId: FheUintId,
+    IntoId: FheUintId,
+{
+    /// Cast FheUint to another FheUint, clamping the values that do not fit
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(1000u16, &client_key);
+    /// let b = FheUint8::saturating_cast_from(a);
+    ///
+    /// let decrypted: u8 = b.decrypt(&client_key);
+    /// assert_eq!(decrypted, u8::MAX);
+    /// ```
+    fn saturating_cast_from(input: FheUint<FromId>) -> Self {
+        let _permit = global_state::enforce_operation_policy(
+            OperationKind::Cast,
+            Self::num_bits().max(FromId::num_bits()),
+            &input.tag,
+        );
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let casted = cpu_key.pbs_key().saturating_cast_to_unsigned(
+                    input.ciphertext.on_cpu().to_owned(),
+                    IntoId::num_blocks(cpu_key.message_modulus()),
+                );
+                Self::new(casted, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support saturating casts yet");
+            }
+        })
+    }
+}
+
+impl<FromId, IntoId> OverflowingCastFrom<FheInt<FromId>> for FheUint<IntoId>
+where
+    FromId: FheIntId,
+    IntoId: FheUintId,
+{
+    /// Cast a FheInt to a FheUint, also returning whether the value did not fit
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-1i16, &client_key);
+    /// let (b, overflowed) = FheUint8::overflowing_cast_from(a);
+    ///
+    /// let decrypted: u8 = b.decrypt(&client_key);
+    /// assert_eq!(decrypted, u8::MAX);
+    /// assert_eq!(overflowed.decrypt(&client_key), true);
+    /// ```
+    fn overflowing_cast_from(input: FheInt<FromId>) -> (Self, FheBool) {
+        let _permit = global_state::enforce_operation_policy(
+            OperationKind::Cast,
+            Self::num_bits().max(FromId::num_bits()),
+            &input.tag,
+        );
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let (casted, overflowed) = cpu_key.pbs_key().overflowing_cast_to_unsigned(
+                    input.ciphertext.into_cpu(),
+                    IntoId::num_blocks(cpu_key.message_modulus()),
+                );
+                (
+                    Self::new(casted, cpu_key.tag.clone()),
+                    FheBool::new(overflowed, cpu_key.tag.clone()),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support overflowing casts yet");
+            }
+        })
+    }
+}
+
+impl<FromId, IntoId> OverflowingCastFrom<FheUint<FromId>> for FheUint<IntoId>
+where
+    FromId: FheUintId,
+    IntoId: FheUintId,
+{
+    /// Cast a FheUint to another FheUint, also returning whether the value did not fit
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(1000u16, &client_key);
+    /// let (b, overflowed) = FheUint8::overflowing_cast_from(a);
+    ///
+    /// let decrypted: u8 = b.decrypt(&client_key);
+    /// assert_eq!(decrypted, 1000u16 as u8);
+    /// assert_eq!(overflowed.decrypt(&client_key), true);
+    /// ```
+    fn overflowing_cast_from(input: FheUint<FromId>) -> (Self, FheBool) {
+        let _permit = global_state::enforce_operation_policy(
+            OperationKind::Cast,
+            Self::num_bits().max(FromId::num_bits()),
+            &input.tag,
+        );
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let (casted, overflowed) = cpu_key.pbs_key().overflowing_cast_to_unsigned(
+                    input.ciphertext.on_cpu().to_owned(),
+                    IntoId::num_blocks(cpu_key.message_modulus()),
+                );
+                (
+                    Self::new(casted, cpu_key.tag.clone()),
+                    FheBool::new(overflowed, cpu_key.tag.clone()),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support overflowing casts yet");
+            }
+        })
+    }
+}
+
 impl<Id> CastFrom<FheBool> for FheUint<Id>
 where
     Id: FheUintId,
@@ -1063,6 +2646,11 @@ where
     /// assert_eq!(decrypted, u16::from(true));
     /// ```
     fn cast_from(input: FheBool) -> Self {
+        let _permit = global_state::enforce_operation_policy(
+            OperationKind::Cast,
+            Self::num_bits(),
+            &input.tag,
+        );
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let ciphertext: crate::integer::RadixCiphertext = input
@@ -1085,6 +2673,90 @@ where
     }
 }
 
+impl<Id, ArmId> SelectCase<FheUint<ArmId>> for FheUint<Id>
+where
+    Id: FheUintId,
+    ArmId: FheUintId,
+{
+    /// Returns the arm at index `self`, or an encryption of zero if `self` is out of bounds
+    ///
+    /// The arms are selected using a balanced tree of [if_then_else](crate::prelude::IfThenElse),
+    /// which is much faster than a chain of `if_then_else` when there are many arms.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `arms` is empty
+    /// - Panics if there are more arms than values `self` can encrypt
+    /// - Panics if the server key is on a Cuda device, which is not supported yet.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let arms = [
+    ///     FheUint16::encrypt(100u16, &client_key),
+    ///     FheUint16::encrypt(200u16, &client_key),
+    ///     FheUint16::encrypt(300u16, &client_key),
+    /// ];
+    ///
+    /// let selector = FheUint8::encrypt(1u8, &client_key);
+    /// let result = selector.select_case(&arms);
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 200);
+    /// ```
+    fn select_case(&self, arms: &[FheUint<ArmId>]) -> FheUint<ArmId> {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let arms = arms
+                    .iter()
+                    .map(|arm| arm.ciphertext.on_cpu().into_owned())
+                    .collect::<Vec<_>>();
+                let inner = cpu_key
+                    .pbs_key()
+                    .select_case_parallelized(&self.ciphertext.on_cpu(), &arms);
+                FheUint::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support select_case yet");
+            }
+        })
+    }
+}
+
+impl<Id, ArmId> SelectCase<FheInt<ArmId>> for FheUint<Id>
+where
+    Id: FheUintId,
+    ArmId: FheIntId,
+{
+    /// Returns the arm at index `self`, or an encryption of zero if `self` is out of bounds
+    ///
+    /// See [SelectCase::select_case] on unsigned arms.
+    fn select_case(&self, arms: &[FheInt<ArmId>]) -> FheInt<ArmId> {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let arms = arms
+                    .iter()
+                    .map(|arm| arm.ciphertext.on_cpu().into_owned())
+                    .collect::<Vec<_>>();
+                let inner = cpu_key
+                    .pbs_key()
+                    .select_case_parallelized(&self.ciphertext.on_cpu(), &arms);
+                FheInt::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support select_case yet");
+            }
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
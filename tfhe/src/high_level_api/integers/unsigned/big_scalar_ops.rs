@@ -0,0 +1,313 @@
+//! Scalar operations with big clear values
+//!
+//! Besides their clear type (e.g. [U256] for [FheUint256](super::FheUint256)), the wide
+//! [FheUint] types accept clear values given as little endian `[u64; N]` limbs, or as
+//! `num_bigint::BigUint` with the `num-bigint` feature.
+//!
+//! For arithmetic operations, which wrap around, the clear value is converted to the clear type
+//! of the [FheUint] by keeping its least significant bits. Comparisons are done on the full
+//! value.
+use super::base::FheUint;
+use crate::high_level_api::integers::FheUintId;
+use crate::high_level_api::traits::{FheEq, FheOrd};
+#[cfg(feature = "num-bigint")]
+use crate::integer::bigint::U4096;
+use crate::integer::bigint::{StaticUnsignedBigInt, U1024, U2048, U512};
+use crate::integer::U256;
+use crate::prelude::CastFrom;
+use crate::FheBool;
+#[cfg(feature = "num-bigint")]
+use num_bigint::BigUint;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+macro_rules! generic_integer_impl_big_scalar_operation {
+    (
+        rust_trait: $rust_trait_name:ident($rust_trait_method:ident),
+        fhe_and_scalar_type: $(
+            ($concrete_type:ty, $scalar_type:ty)
+        ),*
+        $(,)?
+    ) => {
+        $(
+            impl<const N: usize> $rust_trait_name<[u64; N]> for &$concrete_type {
+                type Output = $concrete_type;
+
+                fn $rust_trait_method(self, rhs: [u64; N]) -> Self::Output {
+                    let rhs = <$scalar_type>::cast_from(StaticUnsignedBigInt::from(rhs));
+                    <Self as $rust_trait_name<$scalar_type>>::$rust_trait_method(self, rhs)
+                }
+            }
+
+            impl<const N: usize> $rust_trait_name<[u64; N]> for $concrete_type {
+                type Output = $concrete_type;
+
+                fn $rust_trait_method(self, rhs: [u64; N]) -> Self::Output {
+                    <&Self as $rust_trait_name<[u64; N]>>::$rust_trait_method(&self, rhs)
+                }
+            }
+
+            #[cfg(feature = "num-bigint")]
+            impl $rust_trait_name<&BigUint> for &$concrete_type {
+                type Output = $concrete_type;
+
+                fn $rust_trait_method(self, rhs: &BigUint) -> Self::Output {
+                    let rhs = <$scalar_type>::cast_from(rhs);
+                    <Self as $rust_trait_name<$scalar_type>>::$rust_trait_method(self, rhs)
+                }
+            }
+
+            #[cfg(feature = "num-bigint")]
+            impl $rust_trait_name<BigUint> for &$concrete_type {
+                type Output = $concrete_type;
+
+                fn $rust_trait_method(self, rhs: BigUint) -> Self::Output {
+                    <Self as $rust_trait_name<&BigUint>>::$rust_trait_method(self, &rhs)
+                }
+            }
+
+            #[cfg(feature = "num-bigint")]
+            impl $rust_trait_name<&BigUint> for $concrete_type {
+                type Output = $concrete_type;
+
+                fn $rust_trait_method(self, rhs: &BigUint) -> Self::Output {
+                    <&Self as $rust_trait_name<&BigUint>>::$rust_trait_method(&self, rhs)
+                }
+            }
+
+            #[cfg(feature = "num-bigint")]
+            impl $rust_trait_name<BigUint> for $concrete_type {
+                type Output = $concrete_type;
+
+                fn $rust_trait_method(self, rhs: BigUint) -> Self::Output {
+                    <&Self as $rust_trait_name<&BigUint>>::$rust_trait_method(&self, &rhs)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! generic_integer_impl_big_scalar_operation_assign {
+    (
+        rust_trait: $rust_trait_name:ident($rust_trait_method:ident),
+        fhe_and_scalar_type: $(
+            ($concrete_type:ty, $scalar_type:ty)
+        ),*
+        $(,)?
+    ) => {
+        $(
+            impl<const N: usize> $rust_trait_name<[u64; N]> for $concrete_type {
+                fn $rust_trait_method(&mut self, rhs: [u64; N]) {
+                    let rhs = <$scalar_type>::cast_from(StaticUnsignedBigInt::from(rhs));
+                    <Self as $rust_trait_name<$scalar_type>>::$rust_trait_method(self, rhs);
+                }
+            }
+
+            #[cfg(feature = "num-bigint")]
+            impl $rust_trait_name<&BigUint> for $concrete_type {
+                fn $rust_trait_method(&mut self, rhs: &BigUint) {
+                    let rhs = <$scalar_type>::cast_from(rhs);
+                    <Self as $rust_trait_name<$scalar_type>>::$rust_trait_method(self, rhs);
+                }
+            }
+
+            #[cfg(feature = "num-bigint")]
+            impl $rust_trait_name<BigUint> for $concrete_type {
+                fn $rust_trait_method(&mut self, rhs: BigUint) {
+                    <Self as $rust_trait_name<&BigUint>>::$rust_trait_method(self, &rhs);
+                }
+            }
+        )*
+    };
+}
+
+generic_integer_impl_big_scalar_operation!(
+    rust_trait: Add(add),
+    fhe_and_scalar_type:
+        (super::FheUint160, U256),
+        (super::FheUint256, U256),
+        (super::FheUint512, U512),
+        (super::FheUint1024, U1024),
+        (super::FheUint2048, U2048),
+);
+generic_integer_impl_big_scalar_operation!(
+    rust_trait: Sub(sub),
+    fhe_and_scalar_type:
+        (super::FheUint160, U256),
+        (super::FheUint256, U256),
+        (super::FheUint512, U512),
+        (super::FheUint1024, U1024),
+        (super::FheUint2048, U2048),
+);
+generic_integer_impl_big_scalar_operation!(
+    rust_trait: Mul(mul),
+    fhe_and_scalar_type:
+        (super::FheUint160, U256),
+        (super::FheUint256, U256),
+        (super::FheUint512, U512),
+        (super::FheUint1024, U1024),
+        (super::FheUint2048, U2048),
+);
+generic_integer_impl_big_scalar_operation_assign!(
+    rust_trait: AddAssign(add_assign),
+    fhe_and_scalar_type:
+        (super::FheUint160, U256),
+        (super::FheUint256, U256),
+        (super::FheUint512, U512),
+        (super::FheUint1024, U1024),
+        (super::FheUint2048, U2048),
+);
+generic_integer_impl_big_scalar_operation_assign!(
+    rust_trait: SubAssign(sub_assign),
+    fhe_and_scalar_type:
+        (super::FheUint160, U256),
+        (super::FheUint256, U256),
+        (super::FheUint512, U512),
+        (super::FheUint1024, U1024),
+        (super::FheUint2048, U2048),
+);
+generic_integer_impl_big_scalar_operation_assign!(
+    rust_trait: MulAssign(mul_assign),
+    fhe_and_scalar_type:
+        (super::FheUint160, U256),
+        (super::FheUint256, U256),
+        (super::FheUint512, U512),
+        (super::FheUint1024, U1024),
+        (super::FheUint2048, U2048),
+);
+
+/// Converts the value to a clear type that can be compared with all the [FheUint] types
+///
+/// Values that do not fit in the widest type are replaced by `2^2048`, which compares the same
+/// way with all the [FheUint] values.
+#[cfg(feature = "num-bigint")]
+fn big_uint_to_comparable(value: &BigUint) -> U4096 {
+    if value.bits() > u64::from(U2048::BITS) {
+        U4096::ONE << U2048::BITS
+    } else {
+        U4096::cast_from(value)
+    }
+}
+
+impl<Id, const N: usize> FheEq<[u64; N]> for FheUint<Id>
+where
+    Id: FheUintId,
+{
+    /// Test for equality between a [FheUint] and a clear given as little endian limbs
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint256};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint256::encrypt(u128::MAX, &client_key);
+    ///
+    /// let result = a.eq([u64::MAX, u64::MAX, 0, 0]);
+    ///
+    /// let decrypted = result.decrypt(&client_key);
+    /// assert!(decrypted);
+    /// ```
+    fn eq(&self, rhs: [u64; N]) -> FheBool {
+        <Self as FheEq<StaticUnsignedBigInt<N>>>::eq(self, StaticUnsignedBigInt::from(rhs))
+    }
+
+    fn ne(&self, rhs: [u64; N]) -> FheBool {
+        <Self as FheEq<StaticUnsignedBigInt<N>>>::ne(self, StaticUnsignedBigInt::from(rhs))
+    }
+}
+
+impl<Id, const N: usize> FheOrd<[u64; N]> for FheUint<Id>
+where
+    Id: FheUintId,
+{
+    fn lt(&self, rhs: [u64; N]) -> FheBool {
+        <Self as FheOrd<StaticUnsignedBigInt<N>>>::lt(self, StaticUnsignedBigInt::from(rhs))
+    }
+
+    fn le(&self, rhs: [u64; N]) -> FheBool {
+        <Self as FheOrd<StaticUnsignedBigInt<N>>>::le(self, StaticUnsignedBigInt::from(rhs))
+    }
+
+    fn gt(&self, rhs: [u64; N]) -> FheBool {
+        <Self as FheOrd<StaticUnsignedBigInt<N>>>::gt(self, StaticUnsignedBigInt::from(rhs))
+    }
+
+    fn ge(&self, rhs: [u64; N]) -> FheBool {
+        <Self as FheOrd<StaticUnsignedBigInt<N>>>::ge(self, StaticUnsignedBigInt::from(rhs))
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl<Id> FheEq<&BigUint> for FheUint<Id>
+where
+    Id: FheUintId,
+{
+    fn eq(&self, rhs: &BigUint) -> FheBool {
+        <Self as FheEq<U4096>>::eq(self, big_uint_to_comparable(rhs))
+    }
+
+    fn ne(&self, rhs: &BigUint) -> FheBool {
+        <Self as FheEq<U4096>>::ne(self, big_uint_to_comparable(rhs))
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl<Id> FheEq<BigUint> for FheUint<Id>
+where
+    Id: FheUintId,
+{
+    fn eq(&self, rhs: BigUint) -> FheBool {
+        <Self as FheEq<&BigUint>>::eq(self, &rhs)
+    }
+
+    fn ne(&self, rhs: BigUint) -> FheBool {
+        <Self as FheEq<&BigUint>>::ne(self, &rhs)
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl<Id> FheOrd<&BigUint> for FheUint<Id>
+where
+    Id: FheUintId,
+{
+    fn lt(&self, rhs: &BigUint) -> FheBool {
+        <Self as FheOrd<U4096>>::lt(self, big_uint_to_comparable(rhs))
+    }
+
+    fn le(&self, rhs: &BigUint) -> FheBool {
+        <Self as FheOrd<U4096>>::le(self, big_uint_to_comparable(rhs))
+    }
+
+    fn gt(&self, rhs: &BigUint) -> FheBool {
+        <Self as FheOrd<U4096>>::gt(self, big_uint_to_comparable(rhs))
+    }
+
+    fn ge(&self, rhs: &BigUint) -> FheBool {
+        <Self as FheOrd<U4096>>::ge(self, big_uint_to_comparable(rhs))
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl<Id> FheOrd<BigUint> for FheUint<Id>
+where
+    Id: FheUintId,
+{
+    fn lt(&self, rhs: BigUint) -> FheBool {
+        <Self as FheOrd<&BigUint>>::lt(self, &rhs)
+    }
+
+    fn le(&self, rhs: BigUint) -> FheBool {
+        <Self as FheOrd<&BigUint>>::le(self, &rhs)
+    }
+
+    fn gt(&self, rhs: BigUint) -> FheBool {
+        <Self as FheOrd<&BigUint>>::gt(self, &rhs)
+    }
+
+    fn ge(&self, rhs: BigUint) -> FheBool {
+        <Self as FheOrd<&BigUint>>::ge(self, &rhs)
+    }
+}
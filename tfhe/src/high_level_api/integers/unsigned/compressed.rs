@@ -5,7 +5,7 @@ use crate::backward_compatibility::integers::{
 };
 use crate::conformance::ParameterSetConformant;
 use crate::core_crypto::prelude::UnsignedNumeric;
-use crate::high_level_api::global_state::with_cpu_internal_keys;
+use crate::high_level_api::global_state::{permit_unrestricted_operation, with_cpu_internal_keys};
 use crate::high_level_api::integers::unsigned::base::{
     FheUint, FheUintConformanceParams, FheUintId,
 };
@@ -104,6 +104,7 @@ where
     ///
     /// See [CompressedFheUint] example.
     pub fn decompress(&self) -> FheUint<Id> {
+        let _permit = permit_unrestricted_operation();
         let inner = match &self.ciphertext {
             CompressedRadixCiphertext::Seeded(ct) => ct.decompress(),
             CompressedRadixCiphertext::ModulusSwitched(ct) => {
@@ -177,6 +178,7 @@ where
     Id: FheUintId,
 {
     pub fn compress(&self) -> CompressedFheUint<Id> {
+        let _permit = permit_unrestricted_operation();
         let ciphertext = CompressedRadixCiphertext::ModulusSwitched(with_cpu_internal_keys(|sk| {
             sk.pbs_key()
                 .switch_modulus_and_compress_parallelized(&self.ciphertext.on_cpu())
@@ -0,0 +1,589 @@
+use std::borrow::Borrow;
+use std::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+};
+
+use crate::core_crypto::prelude::{CastFrom, UnsignedNumeric};
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::{FheUintId, IntegerId};
+use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::policy::{operation_kind, OperationKind, OperationPermit};
+use crate::high_level_api::traits::{
+    DivRem, FheDecrypt, FheEq, FheMax, FheMin, FheOrd, IfThenElse, Tagged,
+};
+use crate::integer::block_decomposition::{DecomposableInto, RecomposableFrom};
+use crate::integer::RadixCiphertext;
+use crate::{ClientKey, FheBool, FheUint, Tag};
+
+/// Runs `func` with the integer server key and the tag of the server key set in the current
+/// thread
+fn with_cpu_server_key<R, F>(func: F) -> R
+where
+    F: FnOnce(&crate::integer::ServerKey, &Tag) -> R,
+{
+    global_state::with_internal_keys(|key| match key {
+        InternalServerKey::Cpu(cpu_key) => func(cpu_key.pbs_key(), &cpu_key.tag),
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(_) => {
+            panic!("Cuda devices do not support DynFheUint yet");
+        }
+    })
+}
+
+/// An unsigned integer whose number of blocks is chosen at runtime
+///
+/// The width of the [FheUint] types is part of their type, which requires to know all the
+/// widths used by a program at compile time. A [DynFheUint] stores its number of blocks
+/// instead, and supports the same arithmetic, bitwise and comparison operations.
+///
+/// The operands of a binary operation (except shifts) must have the same number of blocks.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, DynFheUint, FheUint16};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// // 10 blocks of 2 bits, i.e. a 20 bits integer
+/// let num_blocks = 10;
+/// let a = DynFheUint::encrypt(1_000_000u32, num_blocks, &client_key);
+/// let b = DynFheUint::encrypt(48_576u32, num_blocks, &client_key);
+///
+/// // 1_000_000 + 48_576 = 2^20, which wraps around
+/// let c = &a + &b;
+/// let decrypted: u32 = c.decrypt(&client_key);
+/// assert_eq!(decrypted, 0);
+///
+/// let is_greater = a.gt(&b).decrypt(&client_key);
+/// assert!(is_greater);
+///
+/// // Conversions to the static types are casts
+/// let d = FheUint16::cast_from(a);
+/// let decrypted: u16 = d.decrypt(&client_key);
+/// assert_eq!(decrypted, 1_000_000u32 as u16);
+/// ```
+#[derive(Clone)]
+pub struct DynFheUint {
+    ciphertext: RadixCiphertext,
+    tag: Tag,
+}
+
+impl DynFheUint {
+    fn new(ciphertext: RadixCiphertext, tag: &Tag) -> Self {
+        Self {
+            ciphertext,
+            tag: tag.clone(),
+        }
+    }
+
+    /// Encrypts `value` in a ciphertext of `num_blocks` blocks
+    ///
+    /// The value is truncated if it does not fit in the blocks.
+    pub fn encrypt<T>(value: T, num_blocks: usize, key: &ClientKey) -> Self
+    where
+        T: DecomposableInto<u64> + UnsignedNumeric,
+    {
+        Self::new(key.key.key.encrypt_radix(value, num_blocks), &key.tag)
+    }
+
+    /// Creates a trivial encryption of `value` with `num_blocks` blocks
+    ///
+    /// The value is truncated if it does not fit in the blocks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the server key is not set
+    pub fn encrypt_trivial<T>(value: T, num_blocks: usize) -> Self
+    where
+        T: DecomposableInto<u64> + UnsignedNumeric,
+    {
+        let _permit = global_state::permit_unrestricted_operation();
+        with_cpu_server_key(|sks, tag| Self::new(sks.create_trivial_radix(value, num_blocks), tag))
+    }
+
+    /// Returns the number of blocks of the ciphertext
+    pub fn num_blocks(&self) -> usize {
+        self.ciphertext.blocks.len()
+    }
+
+    /// Casts the integer to `num_blocks` blocks
+    ///
+    /// The most significant blocks are removed if `num_blocks` is smaller than the current number
+    /// of blocks, zero blocks are added otherwise.
+    pub fn cast(&self, num_blocks: usize) -> Self {
+        let _permit = global_state::enforce_operation_policy(
+            OperationKind::Cast,
+            self.num_bits().max(num_blocks * self.bits_per_block()),
+            &self.tag,
+        );
+        with_cpu_server_key(|sks, tag| {
+            Self::new(
+                sks.cast_to_unsigned(self.ciphertext.clone(), num_blocks),
+                tag,
+            )
+        })
+    }
+
+    pub fn into_raw_parts(self) -> (RadixCiphertext, Tag) {
+        (self.ciphertext, self.tag)
+    }
+
+    pub fn from_raw_parts(ciphertext: RadixCiphertext, tag: Tag) -> Self {
+        Self { ciphertext, tag }
+    }
+
+    fn bits_per_block(&self) -> usize {
+        self.ciphertext
+            .blocks
+            .first()
+            .map_or(0, |block| block.message_modulus.0.ilog2() as usize)
+    }
+
+    fn num_bits(&self) -> usize {
+        self.num_blocks() * self.bits_per_block()
+    }
+
+    /// Checks that the [OperationPolicy](crate::OperationPolicy) of the server key, if any,
+    /// permits the operation on this [DynFheUint]
+    fn enforce_operation_policy(&self, kind: OperationKind) -> OperationPermit {
+        global_state::enforce_operation_policy(kind, self.num_bits(), &self.tag)
+    }
+
+    fn assert_same_num_blocks(&self, other: &Self) {
+        assert_eq!(
+            self.num_blocks(),
+            other.num_blocks(),
+            "Both operands must have the same number of blocks"
+        );
+    }
+}
+
+impl Tagged for DynFheUint {
+    fn tag(&self) -> &Tag {
+        &self.tag
+    }
+
+    fn tag_mut(&mut self) -> &mut Tag {
+        &mut self.tag
+    }
+}
+
+impl<ClearType> FheDecrypt<ClearType> for DynFheUint
+where
+    ClearType: RecomposableFrom<u64> + UnsignedNumeric,
+{
+    fn decrypt(&self, key: &ClientKey) -> ClearType {
+        key.key.key.decrypt_radix(&self.ciphertext)
+    }
+}
+
+impl<Id> From<FheUint<Id>> for DynFheUint
+where
+    Id: FheUintId,
+{
+    fn from(value: FheUint<Id>) -> Self {
+        let (ciphertext, _, tag) = value.into_raw_parts();
+        Self { ciphertext, tag }
+    }
+}
+
+impl<Id> CastFrom<DynFheUint> for FheUint<Id>
+where
+    Id: FheUintId,
+{
+    /// Casts a [DynFheUint] to a [FheUint]
+    ///
+    /// The most significant blocks are removed if the [DynFheUint] has more blocks than the
+    /// [FheUint], zero blocks are added otherwise.
+    fn cast_from(input: DynFheUint) -> Self {
+        let _permit = global_state::enforce_operation_policy(
+            OperationKind::Cast,
+            input.num_bits().max(Self::num_bits()),
+            &input.tag,
+        );
+        with_cpu_server_key(|sks, tag| {
+            let num_blocks = Id::num_blocks(sks.message_modulus());
+            Self::new(
+                sks.cast_to_unsigned(input.ciphertext, num_blocks),
+                tag.clone(),
+            )
+        })
+    }
+}
+
+macro_rules! dyn_fhe_uint_impl_operation (
+    ($rust_trait:ident($rust_method:ident), $rust_assign_trait:ident($rust_assign_method:ident) => $integer_method:ident) => {
+        impl<B> $rust_trait<B> for &DynFheUint
+        where
+            B: Borrow<DynFheUint>,
+        {
+            type Output = DynFheUint;
+
+            fn $rust_method(self, rhs: B) -> Self::Output {
+                let rhs = rhs.borrow();
+                self.assert_same_num_blocks(rhs);
+                let _permit = self.enforce_operation_policy(operation_kind!($rust_trait));
+                with_cpu_server_key(|sks, tag| {
+                    DynFheUint::new(sks.$integer_method(&self.ciphertext, &rhs.ciphertext), tag)
+                })
+            }
+        }
+
+        impl<B> $rust_trait<B> for DynFheUint
+        where
+            B: Borrow<Self>,
+        {
+            type Output = Self;
+
+            fn $rust_method(self, rhs: B) -> Self::Output {
+                <&Self as $rust_trait<B>>::$rust_method(&self, rhs)
+            }
+        }
+
+        impl<B> $rust_assign_trait<B> for DynFheUint
+        where
+            B: Borrow<Self>,
+        {
+            fn $rust_assign_method(&mut self, rhs: B) {
+                *self = <&Self as $rust_trait<B>>::$rust_method(self, rhs);
+            }
+        }
+    };
+);
+
+dyn_fhe_uint_impl_operation!(Add(add), AddAssign(add_assign) => add_parallelized);
+dyn_fhe_uint_impl_operation!(Sub(sub), SubAssign(sub_assign) => sub_parallelized);
+dyn_fhe_uint_impl_operation!(Mul(mul), MulAssign(mul_assign) => mul_parallelized);
+dyn_fhe_uint_impl_operation!(BitAnd(bitand), BitAndAssign(bitand_assign) => bitand_parallelized);
+dyn_fhe_uint_impl_operation!(BitOr(bitor), BitOrAssign(bitor_assign) => bitor_parallelized);
+dyn_fhe_uint_impl_operation!(BitXor(bitxor), BitXorAssign(bitxor_assign) => bitxor_parallelized);
+dyn_fhe_uint_impl_operation!(Div(div), DivAssign(div_assign) => div_parallelized);
+dyn_fhe_uint_impl_operation!(Rem(rem), RemAssign(rem_assign) => rem_parallelized);
+
+macro_rules! dyn_fhe_uint_impl_shift (
+    ($rust_trait:ident($rust_method:ident), $rust_assign_trait:ident($rust_assign_method:ident) => $integer_method:ident) => {
+        // The shift amount can have any number of blocks
+        impl<B> $rust_trait<B> for &DynFheUint
+        where
+            B: Borrow<DynFheUint>,
+        {
+            type Output = DynFheUint;
+
+            fn $rust_method(self, rhs: B) -> Self::Output {
+                let _permit = self.enforce_operation_policy(operation_kind!($rust_trait));
+                with_cpu_server_key(|sks, tag| {
+                    DynFheUint::new(
+                        sks.$integer_method(&self.ciphertext, &rhs.borrow().ciphertext),
+                        tag,
+                    )
+                })
+            }
+        }
+
+        impl<B> $rust_trait<B> for DynFheUint
+        where
+            B: Borrow<Self>,
+        {
+            type Output = Self;
+
+            fn $rust_method(self, rhs: B) -> Self::Output {
+                <&Self as $rust_trait<B>>::$rust_method(&self, rhs)
+            }
+        }
+
+        impl<B> $rust_assign_trait<B> for DynFheUint
+        where
+            B: Borrow<Self>,
+        {
+            fn $rust_assign_method(&mut self, rhs: B) {
+                *self = <&Self as $rust_trait<B>>::$rust_method(self, rhs);
+            }
+        }
+    };
+);
+
+dyn_fhe_uint_impl_shift!(Shl(shl), ShlAssign(shl_assign) => left_shift_parallelized);
+dyn_fhe_uint_impl_shift!(Shr(shr), ShrAssign(shr_assign) => right_shift_parallelized);
+
+macro_rules! dyn_fhe_uint_impl_scalar_operation (
+    ($rust_trait:ident($rust_method:ident), $rust_assign_trait:ident($rust_assign_method:ident) => $integer_method:ident, $($scalar_type:ty),* $(,)?) => {
+        $(
+            impl $rust_trait<$scalar_type> for &DynFheUint {
+                type Output = DynFheUint;
+
+                fn $rust_method(self, rhs: $scalar_type) -> Self::Output {
+                    let _permit = self.enforce_operation_policy(operation_kind!($rust_trait));
+                    with_cpu_server_key(|sks, tag| {
+                        DynFheUint::new(sks.$integer_method(&self.ciphertext, rhs), tag)
+                    })
+                }
+            }
+
+            impl $rust_trait<$scalar_type> for DynFheUint {
+                type Output = Self;
+
+                fn $rust_method(self, rhs: $scalar_type) -> Self::Output {
+                    <&Self as $rust_trait<$scalar_type>>::$rust_method(&self, rhs)
+                }
+            }
+
+            impl $rust_assign_trait<$scalar_type> for DynFheUint {
+                fn $rust_assign_method(&mut self, rhs: $scalar_type) {
+                    *self = <&Self as $rust_trait<$scalar_type>>::$rust_method(self, rhs);
+                }
+            }
+        )*
+    };
+);
+
+dyn_fhe_uint_impl_scalar_operation!(Add(add), AddAssign(add_assign) => scalar_add_parallelized, u8, u16, u32, u64, u128);
+dyn_fhe_uint_impl_scalar_operation!(Sub(sub), SubAssign(sub_assign) => scalar_sub_parallelized, u8, u16, u32, u64, u128);
+dyn_fhe_uint_impl_scalar_operation!(Mul(mul), MulAssign(mul_assign) => scalar_mul_parallelized, u8, u16, u32, u64, u128);
+dyn_fhe_uint_impl_scalar_operation!(BitAnd(bitand), BitAndAssign(bitand_assign) => scalar_bitand_parallelized, u8, u16, u32, u64, u128);
+dyn_fhe_uint_impl_scalar_operation!(BitOr(bitor), BitOrAssign(bitor_assign) => scalar_bitor_parallelized, u8, u16, u32, u64, u128);
+dyn_fhe_uint_impl_scalar_operation!(BitXor(bitxor), BitXorAssign(bitxor_assign) => scalar_bitxor_parallelized, u8, u16, u32, u64, u128);
+dyn_fhe_uint_impl_scalar_operation!(Div(div), DivAssign(div_assign) => scalar_div_parallelized, u8, u16, u32, u64, u128);
+dyn_fhe_uint_impl_scalar_operation!(Rem(rem), RemAssign(rem_assign) => scalar_rem_parallelized, u8, u16, u32, u64, u128);
+dyn_fhe_uint_impl_scalar_operation!(Shl(shl), ShlAssign(shl_assign) => scalar_left_shift_parallelized, u8, u16, u32, u64, u128);
+dyn_fhe_uint_impl_scalar_operation!(Shr(shr), ShrAssign(shr_assign) => scalar_right_shift_parallelized, u8, u16, u32, u64, u128);
+
+impl<B> DivRem<B> for &DynFheUint
+where
+    B: Borrow<DynFheUint>,
+{
+    type Output = (DynFheUint, DynFheUint);
+
+    fn div_rem(self, rhs: B) -> Self::Output {
+        let rhs = rhs.borrow();
+        self.assert_same_num_blocks(rhs);
+        let _permit = self.enforce_operation_policy(OperationKind::DivRem);
+        with_cpu_server_key(|sks, tag| {
+            let (quotient, remainder) = sks.div_rem_parallelized(&self.ciphertext, &rhs.ciphertext);
+            (
+                DynFheUint::new(quotient, tag),
+                DynFheUint::new(remainder, tag),
+            )
+        })
+    }
+}
+
+impl<B> DivRem<B> for DynFheUint
+where
+    B: Borrow<Self>,
+{
+    type Output = (Self, Self);
+
+    fn div_rem(self, rhs: B) -> Self::Output {
+        <&Self as DivRem<B>>::div_rem(&self, rhs)
+    }
+}
+
+impl Neg for &DynFheUint {
+    type Output = DynFheUint;
+
+    fn neg(self) -> Self::Output {
+        let _permit = self.enforce_operation_policy(OperationKind::Neg);
+        with_cpu_server_key(|sks, tag| DynFheUint::new(sks.neg_parallelized(&self.ciphertext), tag))
+    }
+}
+
+impl Neg for DynFheUint {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+impl Not for &DynFheUint {
+    type Output = DynFheUint;
+
+    fn not(self) -> Self::Output {
+        let _permit = self.enforce_operation_policy(OperationKind::Not);
+        with_cpu_server_key(|sks, tag| DynFheUint::new(sks.bitnot(&self.ciphertext), tag))
+    }
+}
+
+impl Not for DynFheUint {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        !&self
+    }
+}
+
+impl<B> FheEq<B> for DynFheUint
+where
+    B: Borrow<Self>,
+{
+    fn eq(&self, other: B) -> FheBool {
+        let other = other.borrow();
+        self.assert_same_num_blocks(other);
+        let _permit = self.enforce_operation_policy(OperationKind::Eq);
+        with_cpu_server_key(|sks, tag| {
+            FheBool::new(
+                sks.eq_parallelized(&self.ciphertext, &other.ciphertext),
+                tag.clone(),
+            )
+        })
+    }
+
+    fn ne(&self, other: B) -> FheBool {
+        let other = other.borrow();
+        self.assert_same_num_blocks(other);
+        let _permit = self.enforce_operation_policy(OperationKind::Ne);
+        with_cpu_server_key(|sks, tag| {
+            FheBool::new(
+                sks.ne_parallelized(&self.ciphertext, &other.ciphertext),
+                tag.clone(),
+            )
+        })
+    }
+}
+
+macro_rules! dyn_fhe_uint_impl_comparison (
+    ($($method:ident($kind:ident) => $integer_method:ident),* $(,)?) => {
+        impl<B> FheOrd<B> for DynFheUint
+        where
+            B: Borrow<Self>,
+        {
+            $(
+                fn $method(&self, other: B) -> FheBool {
+                    let other = other.borrow();
+                    self.assert_same_num_blocks(other);
+                    let _permit = self.enforce_operation_policy(OperationKind::$kind);
+                    with_cpu_server_key(|sks, tag| {
+                        FheBool::new(
+                            sks.$integer_method(&self.ciphertext, &other.ciphertext),
+                            tag.clone(),
+                        )
+                    })
+                }
+            )*
+        }
+    };
+);
+
+dyn_fhe_uint_impl_comparison!(
+    lt(Lt) => lt_parallelized,
+    le(Le) => le_parallelized,
+    gt(Gt) => gt_parallelized,
+    ge(Ge) => ge_parallelized,
+);
+
+macro_rules! dyn_fhe_uint_impl_scalar_comparison (
+    ($($scalar_type:ty),* $(,)?) => {
+        $(
+            impl FheEq<$scalar_type> for DynFheUint {
+                fn eq(&self, other: $scalar_type) -> FheBool {
+                    let _permit = self.enforce_operation_policy(OperationKind::Eq);
+                    with_cpu_server_key(|sks, tag| {
+                        FheBool::new(sks.scalar_eq_parallelized(&self.ciphertext, other), tag.clone())
+                    })
+                }
+
+                fn ne(&self, other: $scalar_type) -> FheBool {
+                    let _permit = self.enforce_operation_policy(OperationKind::Ne);
+                    with_cpu_server_key(|sks, tag| {
+                        FheBool::new(sks.scalar_ne_parallelized(&self.ciphertext, other), tag.clone())
+                    })
+                }
+            }
+
+            impl FheOrd<$scalar_type> for DynFheUint {
+                fn lt(&self, other: $scalar_type) -> FheBool {
+                    let _permit = self.enforce_operation_policy(OperationKind::Lt);
+                    with_cpu_server_key(|sks, tag| {
+                        FheBool::new(sks.scalar_lt_parallelized(&self.ciphertext, other), tag.clone())
+                    })
+                }
+
+                fn le(&self, other: $scalar_type) -> FheBool {
+                    let _permit = self.enforce_operation_policy(OperationKind::Le);
+                    with_cpu_server_key(|sks, tag| {
+                        FheBool::new(sks.scalar_le_parallelized(&self.ciphertext, other), tag.clone())
+                    })
+                }
+
+                fn gt(&self, other: $scalar_type) -> FheBool {
+                    let _permit = self.enforce_operation_policy(OperationKind::Gt);
+                    with_cpu_server_key(|sks, tag| {
+                        FheBool::new(sks.scalar_gt_parallelized(&self.ciphertext, other), tag.clone())
+                    })
+                }
+
+                fn ge(&self, other: $scalar_type) -> FheBool {
+                    let _permit = self.enforce_operation_policy(OperationKind::Ge);
+                    with_cpu_server_key(|sks, tag| {
+                        FheBool::new(sks.scalar_ge_parallelized(&self.ciphertext, other), tag.clone())
+                    })
+                }
+            }
+        )*
+    };
+);
+
+dyn_fhe_uint_impl_scalar_comparison!(u8, u16, u32, u64, u128);
+
+impl<B> FheMin<B> for DynFheUint
+where
+    B: Borrow<Self>,
+{
+    type Output = Self;
+
+    fn min(&self, other: B) -> Self::Output {
+        let other = other.borrow();
+        self.assert_same_num_blocks(other);
+        let _permit = self.enforce_operation_policy(OperationKind::Min);
+        with_cpu_server_key(|sks, tag| {
+            Self::new(
+                sks.min_parallelized(&self.ciphertext, &other.ciphertext),
+                tag,
+            )
+        })
+    }
+}
+
+impl<B> FheMax<B> for DynFheUint
+where
+    B: Borrow<Self>,
+{
+    type Output = Self;
+
+    fn max(&self, other: B) -> Self::Output {
+        let other = other.borrow();
+        self.assert_same_num_blocks(other);
+        let _permit = self.enforce_operation_policy(OperationKind::Max);
+        with_cpu_server_key(|sks, tag| {
+            Self::new(
+                sks.max_parallelized(&self.ciphertext, &other.ciphertext),
+                tag,
+            )
+        })
+    }
+}
+
+impl IfThenElse<DynFheUint> for FheBool {
+    fn if_then_else(&self, ct_then: &DynFheUint, ct_else: &DynFheUint) -> DynFheUint {
+        ct_then.assert_same_num_blocks(ct_else);
+        let _permit = global_state::enforce_operation_policy(
+            OperationKind::IfThenElse,
+            ct_then.num_bits(),
+            &self.tag,
+        );
+        with_cpu_server_key(|sks, tag| {
+            DynFheUint::new(
+                sks.if_then_else_parallelized(
+                    &self.ciphertext.on_cpu(),
+                    &ct_then.ciphertext,
+                    &ct_else.ciphertext,
+                ),
+                tag,
+            )
+        })
+    }
+}
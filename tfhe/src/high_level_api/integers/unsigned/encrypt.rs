@@ -7,8 +7,10 @@ use crate::high_level_api::keys::InternalServerKey;
 use crate::integer::block_decomposition::{DecomposableInto, RecomposableFrom};
 #[cfg(feature = "gpu")]
 use crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
-use crate::prelude::{FheDecrypt, FheTrivialEncrypt, FheTryEncrypt, FheTryTrivialEncrypt};
-use crate::{ClientKey, CompressedPublicKey, FheUint, PublicKey};
+use crate::prelude::{
+    FheDecrypt, FheDecryptWith, FheTrivialEncrypt, FheTryEncrypt, FheTryTrivialEncrypt,
+};
+use crate::{ClientKey, CompressedPublicKey, DecryptionKey, FheUint, PublicKey};
 
 impl<Id, ClearType> FheDecrypt<ClearType> for FheUint<Id>
 where
@@ -42,6 +44,16 @@ where
     }
 }
 
+impl<Id, ClearType> FheDecryptWith<ClearType, DecryptionKey> for FheUint<Id>
+where
+    Id: FheUintId,
+    ClearType: RecomposableFrom<u64> + UnsignedNumeric,
+{
+    fn decrypt_with(&self, key: &DecryptionKey) -> ClearType {
+        key.key.decrypt_radix(&self.ciphertext.on_cpu())
+    }
+}
+
 impl<Id, T> FheTryEncrypt<T, ClientKey> for FheUint<Id>
 where
     Id: FheUintId,
@@ -107,6 +119,7 @@ where
     type Error = crate::Error;
 
     fn try_encrypt_trivial(value: T) -> Result<Self, Self::Error> {
+        let _permit = global_state::permit_unrestricted_operation();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(key) => {
                 let ciphertext: crate::integer::RadixCiphertext = key
@@ -0,0 +1,183 @@
+use super::base::{FheUint, FheUintId};
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::IntegerId;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::FheBool;
+
+/// Compares each value of `left` with each value of `right`, as done by an equality join
+///
+/// Returns the matrix of the comparisons, where `result[i][j]` encrypts `left[i] == right[j]`.
+///
+/// Encrypted values cannot be hashed nor sorted, so all the pairs are compared (a nested loop
+/// join). All the comparisons are computed in a single parallel batch, which is much faster than
+/// calling [eq](crate::prelude::FheEq::eq) on each pair.
+///
+/// # Panics
+///
+/// Panics if the server key is on a Cuda device, which is not supported yet.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{fhe_join_eq, generate_keys, set_server_key, ConfigBuilder, FheUint64};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let left = [1u64, 2, 3];
+/// let right = [3u64, 1];
+///
+/// let enc_left = left
+///     .iter()
+///     .map(|v| FheUint64::encrypt(*v, &client_key))
+///     .collect::<Vec<_>>();
+/// let enc_right = right
+///     .iter()
+///     .map(|v| FheUint64::encrypt(*v, &client_key))
+///     .collect::<Vec<_>>();
+///
+/// let matches = fhe_join_eq(&enc_left, &enc_right);
+///
+/// for (i, row) in matches.iter().enumerate() {
+///     for (j, is_match) in row.iter().enumerate() {
+///         assert_eq!(is_match.decrypt(&client_key), left[i] == right[j]);
+///     }
+/// }
+/// ```
+pub fn fhe_join_eq<Id>(left: &[FheUint<Id>], right: &[FheUint<Id>]) -> Vec<Vec<FheBool>>
+where
+    Id: FheUintId,
+{
+    global_state::with_internal_keys(|key| match key {
+        InternalServerKey::Cpu(cpu_key) => {
+            let left = left
+                .iter()
+                .map(|ct| ct.ciphertext.on_cpu().into_owned())
+                .collect::<Vec<_>>();
+            let right = right
+                .iter()
+                .map(|ct| ct.ciphertext.on_cpu().into_owned())
+                .collect::<Vec<_>>();
+
+            cpu_key
+                .pbs_key()
+                .eq_join_parallelized(&left, &right)
+                .into_iter()
+                .map(|row| {
+                    row.into_iter()
+                        .map(|is_match| FheBool::new(is_match, cpu_key.tag.clone()))
+                        .collect()
+                })
+                .collect()
+        }
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(_) => {
+            panic!("Cuda devices do not support fhe_join_eq yet");
+        }
+    })
+}
+
+/// Computes, for each key of `left_keys`, the sum of the `right_values` whose key in
+/// `right_keys` is equal to it
+///
+/// `result[i]` is the sum of all the `right_values[j]` such that `left_keys[i] == right_keys[j]`,
+/// or 0 if there is none. The sums wrap around on overflow.
+///
+/// This is an equality join followed by a grouped sum (e.g. `SELECT SUM(value) ... GROUP BY
+/// key`), the matches are only used to mask the values and are never returned.
+///
+/// # Panics
+///
+/// - Panics if `right_keys` and `right_values` do not have the same length
+/// - Panics if the server key is on a Cuda device, which is not supported yet.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{
+///     fhe_join_eq_sum, generate_keys, set_server_key, ConfigBuilder, FheUint32, FheUint64,
+/// };
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let customers = [1u64, 2, 7];
+/// let orders = [(2u64, 10u32), (1, 5), (2, 20)];
+///
+/// let enc_customers = customers
+///     .iter()
+///     .map(|v| FheUint64::encrypt(*v, &client_key))
+///     .collect::<Vec<_>>();
+/// let enc_order_customers = orders
+///     .iter()
+///     .map(|o| FheUint64::encrypt(o.0, &client_key))
+///     .collect::<Vec<_>>();
+/// let enc_amounts = orders
+///     .iter()
+///     .map(|o| FheUint32::encrypt(o.1, &client_key))
+///     .collect::<Vec<_>>();
+///
+/// let totals = fhe_join_eq_sum(&enc_customers, &enc_order_customers, &enc_amounts);
+///
+/// let totals = totals
+///     .iter()
+///     .map(|total| total.decrypt(&client_key))
+///     .collect::<Vec<u32>>();
+/// assert_eq!(totals, vec![5, 30, 0]);
+/// ```
+pub fn fhe_join_eq_sum<Id, ValueId>(
+    left_keys: &[FheUint<Id>],
+    right_keys: &[FheUint<Id>],
+    right_values: &[FheUint<ValueId>],
+) -> Vec<FheUint<ValueId>>
+where
+    Id: FheUintId,
+    ValueId: FheUintId,
+{
+    assert_eq!(
+        right_keys.len(),
+        right_values.len(),
+        "There must be exactly one value per right key"
+    );
+
+    global_state::with_internal_keys(|key| match key {
+        InternalServerKey::Cpu(cpu_key) => {
+            let sks = cpu_key.pbs_key();
+            if right_values.is_empty() {
+                return left_keys
+                    .iter()
+                    .map(|_| {
+                        let zero: crate::integer::RadixCiphertext = sks.create_trivial_zero_radix(
+                            ValueId::num_blocks(cpu_key.message_modulus()),
+                        );
+                        FheUint::new(zero, cpu_key.tag.clone())
+                    })
+                    .collect();
+            }
+
+            let left_keys = left_keys
+                .iter()
+                .map(|ct| ct.ciphertext.on_cpu().into_owned())
+                .collect::<Vec<_>>();
+            let right_keys = right_keys
+                .iter()
+                .map(|ct| ct.ciphertext.on_cpu().into_owned())
+                .collect::<Vec<_>>();
+            let right_values = right_values
+                .iter()
+                .map(|ct| ct.ciphertext.on_cpu().into_owned())
+                .collect::<Vec<_>>();
+
+            sks.eq_join_sum_parallelized(&left_keys, &right_keys, &right_values)
+                .into_iter()
+                .map(|sum| FheUint::new(sum, cpu_key.tag.clone()))
+                .collect()
+        }
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(_) => {
+            panic!("Cuda devices do not support fhe_join_eq_sum yet");
+        }
+    })
+}
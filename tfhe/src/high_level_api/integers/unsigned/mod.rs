@@ -1,9 +1,13 @@
+pub use accumulator::FheAccumulator;
 pub use base::{FheUint, FheUintId};
+pub use dynamic::DynFheUint;
+pub use join::{fhe_join_eq, fhe_join_eq_sum};
 
 expand_pub_use_fhe_type!(
     pub use static_{
         FheUint2, FheUint4, FheUint6, FheUint8, FheUint10, FheUint12, FheUint14, FheUint16,
-        FheUint32, FheUint64, FheUint128, FheUint160, FheUint256, FheUint512, FheUint1024, FheUint2048,
+        FheUint24, FheUint32, FheUint40, FheUint48, FheUint64, FheUint72, FheUint128,
+        FheUint160, FheUint256, FheUint512, FheUint1024, FheUint2048,
     };
 );
 
@@ -12,14 +16,19 @@ pub use compressed::CompressedFheUint;
 pub(in crate::high_level_api) use compressed::CompressedRadixCiphertext;
 pub(in crate::high_level_api) use inner::{RadixCiphertext, RadixCiphertextVersionOwned};
 
+mod accumulator;
 mod base;
+mod big_scalar_ops;
 mod compressed;
+mod dynamic;
 mod static_;
 
 mod encrypt;
 mod inner;
+mod join;
 mod ops;
 mod overflowing_ops;
 pub(crate) mod scalar_ops;
 #[cfg(test)]
 mod tests;
+mod widening;
@@ -9,9 +9,10 @@ use crate::high_level_api::global_state;
 use crate::high_level_api::global_state::with_thread_local_cuda_streams;
 use crate::high_level_api::integers::FheUintId;
 use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::policy::{operation_kind, OperationKind};
 use crate::high_level_api::traits::{
-    DivRem, FheEq, FheMax, FheMin, FheOrd, RotateLeft, RotateLeftAssign, RotateRight,
-    RotateRightAssign,
+    DivRem, FheClamp, FheEq, FheIsInRange, FheMax, FheMaxAssign, FheMin, FheMinAssign, FheOrd,
+    RotateLeft, RotateLeftAssign, RotateRight, RotateRightAssign,
 };
 #[cfg(feature = "gpu")]
 use crate::integer::gpu::ciphertext::CudaIntegerRadixCiphertext;
@@ -54,6 +55,12 @@ where
     /// assert_eq!(decrypted, clears.into_iter().sum::<u16>());
     /// ```
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let mut iter = iter.peekable();
+        let _permit = match iter.peek() {
+            Some(first) => first.enforce_operation_policy(OperationKind::Add),
+            // The sum of no ciphertext is a trivial zero
+            None => global_state::permit_unrestricted_operation(),
+        };
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let ciphertexts = iter.map(|elem| elem.ciphertext.into_cpu()).collect();
@@ -127,6 +134,12 @@ where
     /// assert_eq!(decrypted, clears.into_iter().sum::<u16>());
     /// ```
     fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        let mut iter = iter.peekable();
+        let _permit = match iter.peek() {
+            Some(first) => first.enforce_operation_policy(OperationKind::Add),
+            // The sum of no ciphertext is a trivial zero
+            None => global_state::permit_unrestricted_operation(),
+        };
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let ciphertexts = iter
@@ -214,6 +227,7 @@ where
     /// assert_eq!(decrypted_max, 2u16);
     /// ```
     fn max(&self, rhs: &Self) -> Self::Output {
+        let _permit = self.enforce_operation_policy(OperationKind::Max);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -260,6 +274,7 @@ where
     /// assert_eq!(decrypted_min, 1u16);
     /// ```
     fn min(&self, rhs: &Self) -> Self::Output {
+        let _permit = self.enforce_operation_policy(OperationKind::Min);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -280,6 +295,145 @@ where
     }
 }
 
+impl<Id> FheClamp<&Self> for FheUint<Id>
+where
+    Id: FheUintId,
+{
+    type Output = Self;
+
+    /// Restricts a [FheUint] to the range given by two other [FheUint]
+    ///
+    /// Returns `min` if `self` is less than `min`, `max` if `self` is greater than `max`,
+    /// and `self` otherwise. Unlike [Ord::clamp], this does not panic if `min > max`,
+    /// `min` is returned when `self` is less than `min`, and `max` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(300u16, &client_key);
+    /// let min = FheUint16::encrypt(10u16, &client_key);
+    /// let max = FheUint16::encrypt(100u16, &client_key);
+    ///
+    /// let result = a.clamp(&min, &max);
+    ///
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 100u16);
+    /// ```
+    fn clamp(&self, min: &Self, max: &Self) -> Self::Output {
+        let _permit = self.enforce_operation_policy(OperationKind::Clamp);
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner_result = cpu_key.pbs_key().clamp_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    &*min.ciphertext.on_cpu(),
+                    &*max.ciphertext.on_cpu(),
+                );
+                Self::new(inner_result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support clamp yet")
+            }
+        })
+    }
+}
+
+impl<Id> FheMaxAssign<&Self> for FheUint<Id>
+where
+    Id: FheUintId,
+{
+    /// Assigns the max between two [FheUint] to self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let mut a = FheUint16::encrypt(1u16, &client_key);
+    /// let b = FheUint16::encrypt(2u16, &client_key);
+    ///
+    /// a.max_assign(&b);
+    ///
+    /// let decrypted_max: u16 = a.decrypt(&client_key);
+    /// assert_eq!(decrypted_max, 2u16);
+    /// ```
+    fn max_assign(&mut self, rhs: &Self) {
+        let _permit = self.enforce_operation_policy(OperationKind::Max);
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                cpu_key.pbs_key().max_assign_parallelized(
+                    self.ciphertext.as_cpu_mut(),
+                    &*rhs.ciphertext.on_cpu(),
+                );
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(cuda_key) => with_thread_local_cuda_streams(|streams| {
+                let inner_result = cuda_key.key.key.max(
+                    &*self.ciphertext.on_gpu(),
+                    &*rhs.ciphertext.on_gpu(),
+                    streams,
+                );
+                *self.ciphertext.as_gpu_mut() = inner_result;
+            }),
+        })
+    }
+}
+
+impl<Id> FheMinAssign<&Self> for FheUint<Id>
+where
+    Id: FheUintId,
+{
+    /// Assigns the min between two [FheUint] to self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let mut a = FheUint16::encrypt(1u16, &client_key);
+    /// let b = FheUint16::encrypt(2u16, &client_key);
+    ///
+    /// a.min_assign(&b);
+    ///
+    /// let decrypted_min: u16 = a.decrypt(&client_key);
+    /// assert_eq!(decrypted_min, 1u16);
+    /// ```
+    fn min_assign(&mut self, rhs: &Self) {
+        let _permit = self.enforce_operation_policy(OperationKind::Min);
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                cpu_key.pbs_key().min_assign_parallelized(
+                    self.ciphertext.as_cpu_mut(),
+                    &*rhs.ciphertext.on_cpu(),
+                );
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(cuda_key) => with_thread_local_cuda_streams(|streams| {
+                let inner_result = cuda_key.key.key.min(
+                    &*self.ciphertext.on_gpu(),
+                    &*rhs.ciphertext.on_gpu(),
+                    streams,
+                );
+                *self.ciphertext.as_gpu_mut() = inner_result;
+            }),
+        })
+    }
+}
+
 impl<Id> FheEq<Self> for FheUint<Id>
 where
     Id: FheUintId,
@@ -317,6 +471,7 @@ where
     /// assert_eq!(decrypted, 1u16 == 2u16);
     /// ```
     fn eq(&self, rhs: &Self) -> FheBool {
+        let _permit = self.enforce_operation_policy(OperationKind::Eq);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -356,6 +511,7 @@ where
     /// assert_eq!(decrypted, 1u16 != 2u16);
     /// ```
     fn ne(&self, rhs: &Self) -> FheBool {
+        let _permit = self.enforce_operation_policy(OperationKind::Ne);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -421,6 +577,7 @@ where
     /// assert_eq!(decrypted, 1u16 < 2u16);
     /// ```
     fn lt(&self, rhs: &Self) -> FheBool {
+        let _permit = self.enforce_operation_policy(OperationKind::Lt);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -460,6 +617,7 @@ where
     /// assert_eq!(decrypted, 1u16 <= 2u16);
     /// ```
     fn le(&self, rhs: &Self) -> FheBool {
+        let _permit = self.enforce_operation_policy(OperationKind::Le);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -499,6 +657,7 @@ where
     /// assert_eq!(decrypted, 1u16 > 2u16);
     /// ```
     fn gt(&self, rhs: &Self) -> FheBool {
+        let _permit = self.enforce_operation_policy(OperationKind::Gt);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -538,6 +697,7 @@ where
     /// assert_eq!(decrypted, 1u16 > 2u16);
     /// ```
     fn ge(&self, rhs: &Self) -> FheBool {
+        let _permit = self.enforce_operation_policy(OperationKind::Ge);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -558,6 +718,54 @@ where
     }
 }
 
+impl<Id> FheIsInRange<&Self> for FheUint<Id>
+where
+    Id: FheUintId,
+{
+    type Output = FheBool;
+
+    /// Tests whether a [FheUint] is in the inclusive range given by two other [FheUint]
+    ///
+    /// Returns whether `low <= self <= high`, which is false if `low > high`.
+    /// Both bounds are checked by a single fused circuit, which is faster than
+    /// `self.ge(low) & self.le(high)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(300u16, &client_key);
+    /// let low = FheUint16::encrypt(10u16, &client_key);
+    /// let high = FheUint16::encrypt(1000u16, &client_key);
+    ///
+    /// let result = a.is_in_range(&low, &high);
+    ///
+    /// let decrypted: bool = result.decrypt(&client_key);
+    /// assert!(decrypted);
+    /// ```
+    fn is_in_range(&self, low: &Self, high: &Self) -> Self::Output {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner_result = cpu_key.pbs_key().is_in_range_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    &*low.ciphertext.on_cpu(),
+                    &*high.ciphertext.on_cpu(),
+                );
+                FheBool::new(inner_result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support is_in_range yet")
+            }
+        })
+    }
+}
+
 impl<Id> DivRem<Self> for FheUint<Id>
 where
     Id: FheUintId,
@@ -618,6 +826,7 @@ where
     /// assert_eq!(remainder, 23u16 % 3u16);
     /// ```
     fn div_rem(self, rhs: Self) -> Self::Output {
+        let _permit = self.enforce_operation_policy(OperationKind::DivRem);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let (q, r) = cpu_key
@@ -677,6 +886,7 @@ macro_rules! generic_integer_impl_operation (
 
             $(#[$outer])*
             fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                let _permit = self.enforce_operation_policy(operation_kind!($rust_trait_name));
                 $closure(self, rhs.borrow())
             }
         }
@@ -1100,6 +1310,7 @@ macro_rules! generic_integer_impl_shift_rotate (
 
             $(#[$outer])*
             fn $rust_trait_method(self, rhs: &FheUint<Id2>) -> Self::Output {
+                let _permit = self.enforce_operation_policy(operation_kind!($rust_trait_name));
                 $closure(self, rhs.borrow())
             }
         }
@@ -1310,6 +1521,7 @@ where
     /// assert_eq!(result, 3u16.wrapping_add(37849u16));
     /// ```
     fn add_assign(&mut self, rhs: I) {
+        let _permit = self.enforce_operation_policy(OperationKind::Add);
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -1355,6 +1567,7 @@ where
     /// assert_eq!(result, 3u16.wrapping_sub(37849u16));
     /// ```
     fn sub_assign(&mut self, rhs: I) {
+        let _permit = self.enforce_operation_policy(OperationKind::Sub);
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -1400,6 +1613,7 @@ where
     /// assert_eq!(result, 3u16.wrapping_mul(37849u16));
     /// ```
     fn mul_assign(&mut self, rhs: I) {
+        let _permit = self.enforce_operation_policy(OperationKind::Mul);
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -1443,6 +1657,7 @@ where
     /// assert_eq!(result, 3u16 & 37849u16);
     /// ```
     fn bitand_assign(&mut self, rhs: I) {
+        let _permit = self.enforce_operation_policy(OperationKind::BitAnd);
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -1486,6 +1701,7 @@ where
     /// assert_eq!(result, 3u16 | 37849u16);
     /// ```
     fn bitor_assign(&mut self, rhs: I) {
+        let _permit = self.enforce_operation_policy(OperationKind::BitOr);
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -1529,6 +1745,7 @@ where
     /// assert_eq!(result, 3u16 ^ 37849u16);
     /// ```
     fn bitxor_assign(&mut self, rhs: I) {
+        let _permit = self.enforce_operation_policy(OperationKind::BitXor);
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -1577,6 +1794,7 @@ where
     /// assert_eq!(result, 37849u16 / 3u16);
     /// ```
     fn div_assign(&mut self, rhs: I) {
+        let _permit = self.enforce_operation_policy(OperationKind::Div);
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -1625,6 +1843,7 @@ where
     /// assert_eq!(result, 37849u16 % 3u16);
     /// ```
     fn rem_assign(&mut self, rhs: I) {
+        let _permit = self.enforce_operation_policy(OperationKind::Rem);
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -1679,6 +1898,7 @@ where
     /// assert_eq!(result, 37849u16 << 3u16);
     /// ```
     fn shl_assign(&mut self, rhs: &FheUint<Id2>) {
+        let _permit = self.enforce_operation_policy(OperationKind::Shl);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 cpu_key.pbs_key().left_shift_assign_parallelized(
@@ -1733,6 +1953,7 @@ where
     /// assert_eq!(result, 37849u16 >> 3u16);
     /// ```
     fn shr_assign(&mut self, rhs: &FheUint<Id2>) {
+        let _permit = self.enforce_operation_policy(OperationKind::Shr);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 cpu_key.pbs_key().right_shift_assign_parallelized(
@@ -1788,6 +2009,7 @@ where
     /// assert_eq!(result, 37849u16.rotate_left(3));
     /// ```
     fn rotate_left_assign(&mut self, rhs: &FheUint<Id2>) {
+        let _permit = self.enforce_operation_policy(OperationKind::RotateLeft);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 cpu_key.pbs_key().rotate_left_assign_parallelized(
@@ -1843,6 +2065,7 @@ where
     /// assert_eq!(result, 37849u16.rotate_right(3));
     /// ```
     fn rotate_right_assign(&mut self, rhs: &FheUint<Id2>) {
+        let _permit = self.enforce_operation_policy(OperationKind::RotateRight);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 cpu_key.pbs_key().rotate_right_assign_parallelized(
@@ -1924,6 +2147,7 @@ where
     /// assert_eq!(result, 3u16.wrapping_neg());
     /// ```
     fn neg(self) -> Self::Output {
+        let _permit = self.enforce_operation_policy(OperationKind::Neg);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let ciphertext = cpu_key
@@ -1992,6 +2216,7 @@ where
     /// assert_eq!(result, !3u16);
     /// ```
     fn not(self) -> Self::Output {
+        let _permit = self.enforce_operation_policy(OperationKind::Not);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let ciphertext = cpu_key.pbs_key().bitnot(&*self.ciphertext.on_cpu());
@@ -4,6 +4,7 @@ use crate::high_level_api::global_state;
 use crate::high_level_api::global_state::with_thread_local_cuda_streams;
 use crate::high_level_api::integers::FheUintId;
 use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::policy::OperationKind;
 use crate::integer::block_decomposition::DecomposableInto;
 use crate::prelude::{CastInto, OverflowingAdd, OverflowingMul, OverflowingSub};
 use crate::{FheBool, FheUint};
@@ -41,6 +42,7 @@ where
     /// assert!(overflowed.decrypt(&client_key));
     /// ```
     fn overflowing_add(self, other: Self) -> (Self::Output, FheBool) {
+        let _permit = self.enforce_operation_policy(OperationKind::Add);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let (result, overflow) = cpu_key.pbs_key().unsigned_overflowing_add_parallelized(
@@ -138,6 +140,7 @@ where
     /// assert!(overflowed.decrypt(&client_key));
     /// ```
     fn overflowing_add(self, other: Clear) -> (Self::Output, FheBool) {
+        let _permit = self.enforce_operation_policy(OperationKind::Add);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let (result, overflow) = cpu_key
@@ -273,6 +276,7 @@ where
     /// assert!(overflowed.decrypt(&client_key));
     /// ```
     fn overflowing_sub(self, other: Self) -> (Self::Output, FheBool) {
+        let _permit = self.enforce_operation_policy(OperationKind::Sub);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let (result, overflow) = cpu_key.pbs_key().unsigned_overflowing_sub_parallelized(
@@ -370,6 +374,7 @@ where
     /// assert!(overflowed.decrypt(&client_key));
     /// ```
     fn overflowing_sub(self, other: Clear) -> (Self::Output, FheBool) {
+        let _permit = self.enforce_operation_policy(OperationKind::Sub);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let (result, overflow) = cpu_key
@@ -456,6 +461,7 @@ where
     /// assert!(overflowed.decrypt(&client_key));
     /// ```
     fn overflowing_mul(self, other: Self) -> (Self::Output, FheBool) {
+        let _permit = self.enforce_operation_policy(OperationKind::Mul);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let (result, overflow) = cpu_key.pbs_key().unsigned_overflowing_mul_parallelized(
@@ -11,9 +11,10 @@ use crate::high_level_api::global_state;
 use crate::high_level_api::global_state::with_thread_local_cuda_streams;
 use crate::high_level_api::integers::FheUintId;
 use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::policy::{operation_kind, OperationKind};
 use crate::high_level_api::traits::{
-    BitSlice, DivRem, FheEq, FheMax, FheMin, FheOrd, RotateLeft, RotateLeftAssign, RotateRight,
-    RotateRightAssign,
+    BitSlice, DivRem, FheClamp, FheEq, FheIsInRange, FheMax, FheMin, FheOrd, RotateLeft,
+    RotateLeftAssign, RotateRight, RotateRightAssign,
 };
 use crate::integer::bigint::{U1024, U2048, U512};
 use crate::integer::block_decomposition::DecomposableInto;
@@ -53,6 +54,7 @@ where
     /// assert_eq!(decrypted, 1u16 == 2u16);
     /// ```
     fn eq(&self, rhs: Clear) -> FheBool {
+        let _permit = self.enforce_operation_policy(OperationKind::Eq);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -92,6 +94,7 @@ where
     /// assert_eq!(decrypted, 1u16 != 2u16);
     /// ```
     fn ne(&self, rhs: Clear) -> FheBool {
+        let _permit = self.enforce_operation_policy(OperationKind::Ne);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -137,6 +140,7 @@ where
     /// assert_eq!(decrypted, 1u16 < 2u16);
     /// ```
     fn lt(&self, rhs: Clear) -> FheBool {
+        let _permit = self.enforce_operation_policy(OperationKind::Lt);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -176,6 +180,7 @@ where
     /// assert_eq!(decrypted, 1u16 <= 2u16);
     /// ```
     fn le(&self, rhs: Clear) -> FheBool {
+        let _permit = self.enforce_operation_policy(OperationKind::Le);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -215,6 +220,7 @@ where
     /// assert_eq!(decrypted, 1u16 > 2u16);
     /// ```
     fn gt(&self, rhs: Clear) -> FheBool {
+        let _permit = self.enforce_operation_policy(OperationKind::Gt);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -254,6 +260,7 @@ where
     /// assert_eq!(decrypted, 1u16 >= 2u16);
     /// ```
     fn ge(&self, rhs: Clear) -> FheBool {
+        let _permit = self.enforce_operation_policy(OperationKind::Ge);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -301,6 +308,7 @@ where
     /// assert_eq!(decrypted_max, 2u16);
     /// ```
     fn max(&self, rhs: Clear) -> Self::Output {
+        let _permit = self.enforce_operation_policy(OperationKind::Max);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -348,6 +356,7 @@ where
     /// assert_eq!(decrypted_min, 1u16);
     /// ```
     fn min(&self, rhs: Clear) -> Self::Output {
+        let _permit = self.enforce_operation_policy(OperationKind::Min);
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -368,6 +377,101 @@ where
     }
 }
 
+impl<Id, Clear> FheClamp<Clear> for FheUint<Id>
+where
+    Clear: DecomposableInto<u64>,
+    Id: FheUintId,
+{
+    type Output = Self;
+
+    /// Restricts a [FheUint] to the range given by two clear values
+    ///
+    /// Returns `min` if `self` is less than `min`, `max` if `self` is greater than `max`,
+    /// and `self` otherwise. Unlike [Ord::clamp], this does not panic if `min > max`,
+    /// `min` is returned when `self` is less than `min`, and `max` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(300u16, &client_key);
+    ///
+    /// let result = a.clamp(10u16, 100u16);
+    ///
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 100u16);
+    /// ```
+    fn clamp(&self, min: Clear, max: Clear) -> Self::Output {
+        let _permit = self.enforce_operation_policy(OperationKind::Clamp);
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner_result = cpu_key.pbs_key().scalar_clamp_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    min,
+                    max,
+                );
+                Self::new(inner_result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support clamp yet")
+            }
+        })
+    }
+}
+
+impl<Id, Clear> FheIsInRange<Clear> for FheUint<Id>
+where
+    Clear: DecomposableInto<u64>,
+    Id: FheUintId,
+{
+    type Output = FheBool;
+
+    /// Tests whether a [FheUint] is in the inclusive range given by two clear values
+    ///
+    /// Returns whether `low <= self <= high`, which is false if `low > high`.
+    /// Both bounds are checked by a single fused circuit, which is faster than
+    /// `self.ge(low) & self.le(high)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(300u16, &client_key);
+    ///
+    /// let result = a.is_in_range(10u16, 100u16);
+    ///
+    /// let decrypted: bool = result.decrypt(&client_key);
+    /// assert!(!decrypted);
+    /// ```
+    fn is_in_range(&self, low: Clear, high: Clear) -> Self::Output {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner_result = cpu_key.pbs_key().scalar_is_in_range_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    low,
+                    high,
+                );
+                FheBool::new(inner_result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support is_in_range yet")
+            }
+        })
+    }
+}
+
 impl<Id, Clear> BitSlice<Clear> for &FheUint<Id>
 where
     Id: FheUintId,
@@ -483,6 +587,7 @@ macro_rules! generic_integer_impl_scalar_div_rem {
                     type Output = ($concrete_type, $concrete_type);
 
                     fn div_rem(self, rhs: $scalar_type) -> Self::Output {
+                        let _permit = self.enforce_operation_policy(OperationKind::DivRem);
                         global_state::with_internal_keys(|key| {
                             match key {
                                 InternalServerKey::Cpu(cpu_key) => {
@@ -523,8 +628,12 @@ generic_integer_impl_scalar_div_rem!(
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint40, u64),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
+        (super::FheUint72, u128),
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
@@ -564,6 +673,7 @@ macro_rules! generic_integer_impl_scalar_operation {
                     type Output = $concrete_type;
 
                     fn $rust_trait_method(self, rhs: $scalar_type) -> Self::Output {
+                        let _permit = self.enforce_operation_policy(operation_kind!($rust_trait_name));
                         let inner_result = $closure(self, rhs);
                         let tag = global_state::tag_of_internal_server_key().unwrap_display();
                         <$concrete_type>::new(inner_result, tag)
@@ -608,8 +718,12 @@ generic_integer_impl_scalar_operation!(
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint40, u64),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
+        (super::FheUint72, u128),
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
@@ -649,8 +763,12 @@ generic_integer_impl_scalar_operation!(
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint40, u64),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
+        (super::FheUint72, u128),
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
@@ -690,8 +808,12 @@ generic_integer_impl_scalar_operation!(
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint40, u64),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
+        (super::FheUint72, u128),
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
@@ -731,8 +853,12 @@ generic_integer_impl_scalar_operation!(
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint40, u64),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
+        (super::FheUint72, u128),
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
@@ -772,8 +898,12 @@ generic_integer_impl_scalar_operation!(
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint40, u64),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
+        (super::FheUint72, u128),
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
@@ -814,8 +944,12 @@ generic_integer_impl_scalar_operation!(
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint40, u64),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
+        (super::FheUint72, u128),
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
@@ -855,8 +989,12 @@ generic_integer_impl_scalar_operation!(
         (super::FheUint12, u8, u16, u32, u64, u128),
         (super::FheUint14, u8, u16, u32, u64, u128),
         (super::FheUint16, u8, u16, u32, u64, u128),
+        (super::FheUint24, u8, u16, u32, u64, u128),
         (super::FheUint32, u8, u16, u32, u64, u128),
+        (super::FheUint40, u8, u16, u32, u64, u128),
+        (super::FheUint48, u8, u16, u32, u64, u128),
         (super::FheUint64, u8, u16, u32, u64, u128),
+        (super::FheUint72, u8, u16, u32, u64, u128),
         (super::FheUint128, u8, u16, u32, u64, u128),
         (super::FheUint160, u8, u16, u32, u64, u128, U256),
         (super::FheUint256, u8, u16, u32, u64, u128, U256),
@@ -896,8 +1034,12 @@ generic_integer_impl_scalar_operation!(
         (super::FheUint12, u8, u16, u32, u64, u128),
         (super::FheUint14, u8, u16, u32, u64, u128),
         (super::FheUint16, u8, u16, u32, u64, u128),
+        (super::FheUint24, u8, u16, u32, u64, u128),
         (super::FheUint32, u8, u16, u32, u64, u128),
+        (super::FheUint40, u8, u16, u32, u64, u128),
+        (super::FheUint48, u8, u16, u32, u64, u128),
         (super::FheUint64, u8, u16, u32, u64, u128),
+        (super::FheUint72, u8, u16, u32, u64, u128),
         (super::FheUint128, u8, u16, u32, u64, u128),
         (super::FheUint160, u8, u16, u32, u64, u128, U256),
         (super::FheUint256, u8, u16, u32, u64, u128, U256),
@@ -937,8 +1079,12 @@ generic_integer_impl_scalar_operation!(
         (super::FheUint12, u8, u16, u32, u64, u128),
         (super::FheUint14, u8, u16, u32, u64, u128),
         (super::FheUint16, u8, u16, u32, u64, u128),
+        (super::FheUint24, u8, u16, u32, u64, u128),
         (super::FheUint32, u8, u16, u32, u64, u128),
+        (super::FheUint40, u8, u16, u32, u64, u128),
+        (super::FheUint48, u8, u16, u32, u64, u128),
         (super::FheUint64, u8, u16, u32, u64, u128),
+        (super::FheUint72, u8, u16, u32, u64, u128),
         (super::FheUint128, u8, u16, u32, u64, u128),
         (super::FheUint160, u8, u16, u32, u64, u128, U256),
         (super::FheUint256, u8, u16, u32, u64, u128, U256),
@@ -978,8 +1124,12 @@ generic_integer_impl_scalar_operation!(
         (super::FheUint12, u8, u16, u32, u64, u128),
         (super::FheUint14, u8, u16, u32, u64, u128),
         (super::FheUint16, u8, u16, u32, u64, u128),
+        (super::FheUint24, u8, u16, u32, u64, u128),
         (super::FheUint32, u8, u16, u32, u64, u128),
+        (super::FheUint40, u8, u16, u32, u64, u128),
+        (super::FheUint48, u8, u16, u32, u64, u128),
         (super::FheUint64, u8, u16, u32, u64, u128),
+        (super::FheUint72, u8, u16, u32, u64, u128),
         (super::FheUint128, u8, u16, u32, u64, u128),
         (super::FheUint160, u8, u16, u32, u64, u128, U256),
         (super::FheUint256, u8, u16, u32, u64, u128, U256),
@@ -1019,8 +1169,12 @@ generic_integer_impl_scalar_operation!(
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint40, u64),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
+        (super::FheUint72, u128),
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
@@ -1060,8 +1214,12 @@ generic_integer_impl_scalar_operation!(
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint40, u64),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
+        (super::FheUint72, u128),
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
@@ -1125,6 +1283,7 @@ macro_rules! generic_integer_impl_scalar_left_operation {
 
                     $(#[$doc])*
                     fn $rust_trait_method(self, rhs: &$concrete_type) -> Self::Output {
+                        let _permit = rhs.enforce_operation_policy(operation_kind!($rust_trait_name));
                         let inner_result = $closure(*self, rhs);
                         let tag = global_state::tag_of_internal_server_key().unwrap_display();
                         <$concrete_type>::new(inner_result, tag)
@@ -1176,8 +1335,12 @@ generic_integer_impl_scalar_left_operation!(
             /// assert_eq!(result, 23u16 + 3u16);
             /// ```
             u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint40, u64),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
+        (super::FheUint72, u128),
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
@@ -1243,8 +1406,12 @@ generic_integer_impl_scalar_left_operation!(
             /// assert_eq!(result, 23u16 - 3u16);
             /// ```
             u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint40, u64),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
+        (super::FheUint72, u128),
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
@@ -1291,8 +1458,12 @@ generic_integer_impl_scalar_left_operation!(
             /// assert_eq!(result, 23u16 * 3u16);
             /// ```
             u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint40, u64),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
+        (super::FheUint72, u128),
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
@@ -1337,8 +1508,12 @@ generic_integer_impl_scalar_left_operation!(
             /// assert_eq!(result, 23u16 & 3u16);
             /// ```
             u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint40, u64),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
+        (super::FheUint72, u128),
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
@@ -1383,8 +1558,12 @@ generic_integer_impl_scalar_left_operation!(
             /// assert_eq!(result, 23u16 | 3u16);
             /// ```
             u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint40, u64),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
+        (super::FheUint72, u128),
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
@@ -1429,8 +1608,12 @@ generic_integer_impl_scalar_left_operation!(
             /// assert_eq!(result, 23u16 ^ 3u16);
             /// ```
             u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint40, u64),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
+        (super::FheUint72, u128),
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
@@ -1459,6 +1642,7 @@ macro_rules! generic_integer_impl_scalar_operation_assign {
                 {
                     $(#[$doc])*
                     fn $rust_trait_method(&mut self, rhs: $scalar_type) {
+                        let _permit = self.enforce_operation_policy(operation_kind!($rust_trait_name));
                         $closure(self, rhs);
                     }
                 }
@@ -1520,8 +1704,12 @@ generic_integer_impl_scalar_operation_assign!(
         /// assert_eq!(result, 23u16 + 3u16);
         /// ```
         u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint40, u64),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
+        (super::FheUint72, u128),
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
@@ -1558,8 +1746,12 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint40, u64),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
+        (super::FheUint72, u128),
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
@@ -1596,8 +1788,12 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint40, u64),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
+        (super::FheUint72, u128),
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
@@ -1634,8 +1830,12 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint40, u64),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
+        (super::FheUint72, u128),
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
@@ -1672,8 +1872,12 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint40, u64),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
+        (super::FheUint72, u128),
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
@@ -1710,8 +1914,12 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint40, u64),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
+        (super::FheUint72, u128),
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
@@ -1748,8 +1956,12 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheUint12, u8, u16, u32, u64, u128),
         (super::FheUint14, u8, u16, u32, u64, u128),
         (super::FheUint16, u8, u16, u32, u64, u128),
+        (super::FheUint24, u8, u16, u32, u64, u128),
         (super::FheUint32, u8, u16, u32, u64, u128),
+        (super::FheUint40, u8, u16, u32, u64, u128),
+        (super::FheUint48, u8, u16, u32, u64, u128),
         (super::FheUint64, u8, u16, u32, u64, u128),
+        (super::FheUint72, u8, u16, u32, u64, u128),
         (super::FheUint128, u8, u16, u32, u64, u128),
         (super::FheUint160, u8, u16, u32, u64, u128, U256),
         (super::FheUint256, u8, u16, u32, u64, u128, U256),
@@ -1786,8 +1998,12 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheUint12, u8, u16, u32, u64, u128),
         (super::FheUint14, u8, u16, u32, u64, u128),
         (super::FheUint16, u8, u16, u32, u64, u128),
+        (super::FheUint24, u8, u16, u32, u64, u128),
         (super::FheUint32, u8, u16, u32, u64, u128),
+        (super::FheUint40, u8, u16, u32, u64, u128),
+        (super::FheUint48, u8, u16, u32, u64, u128),
         (super::FheUint64, u8, u16, u32, u64, u128),
+        (super::FheUint72, u8, u16, u32, u64, u128),
         (super::FheUint128, u8, u16, u32, u64, u128),
         (super::FheUint160, u8, u16, u32, u64, u128, U256),
         (super::FheUint256, u8, u16, u32, u64, u128, U256),
@@ -1824,8 +2040,12 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheUint12, u8, u16, u32, u64, u128),
         (super::FheUint14, u8, u16, u32, u64, u128),
         (super::FheUint16, u8, u16, u32, u64, u128),
+        (super::FheUint24, u8, u16, u32, u64, u128),
         (super::FheUint32, u8, u16, u32, u64, u128),
+        (super::FheUint40, u8, u16, u32, u64, u128),
+        (super::FheUint48, u8, u16, u32, u64, u128),
         (super::FheUint64, u8, u16, u32, u64, u128),
+        (super::FheUint72, u8, u16, u32, u64, u128),
         (super::FheUint128, u8, u16, u32, u64, u128),
         (super::FheUint160, u8, u16, u32, u64, u128, U256),
         (super::FheUint256, u8, u16, u32, u64, u128, U256),
@@ -1862,8 +2082,12 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheUint12, u8, u16, u32, u64, u128),
         (super::FheUint14, u8, u16, u32, u64, u128),
         (super::FheUint16, u8, u16, u32, u64, u128),
+        (super::FheUint24, u8, u16, u32, u64, u128),
         (super::FheUint32, u8, u16, u32, u64, u128),
+        (super::FheUint40, u8, u16, u32, u64, u128),
+        (super::FheUint48, u8, u16, u32, u64, u128),
         (super::FheUint64, u8, u16, u32, u64, u128),
+        (super::FheUint72, u8, u16, u32, u64, u128),
         (super::FheUint128, u8, u16, u32, u64, u128),
         (super::FheUint160, u8, u16, u32, u64, u128, U256),
         (super::FheUint256, u8, u16, u32, u64, u128, U256),
@@ -1897,8 +2121,12 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint40, u64),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
+        (super::FheUint72, u128),
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
@@ -1932,8 +2160,12 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint40, u64),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
+        (super::FheUint72, u128),
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
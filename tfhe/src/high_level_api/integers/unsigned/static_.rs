@@ -99,18 +99,42 @@ static_int_type! {
     }
 }
 
+static_int_type! {
+    Unsigned {
+        num_bits: 24,
+    }
+}
+
 static_int_type! {
     Unsigned {
         num_bits: 32,
     }
 }
 
+static_int_type! {
+    Unsigned {
+        num_bits: 40,
+    }
+}
+
+static_int_type! {
+    Unsigned {
+        num_bits: 48,
+    }
+}
+
 static_int_type! {
     Unsigned {
         num_bits: 64,
     }
 }
 
+static_int_type! {
+    Unsigned {
+        num_bits: 72,
+    }
+}
+
 static_int_type! {
     Unsigned {
         num_bits: 128,
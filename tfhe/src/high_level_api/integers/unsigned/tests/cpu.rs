@@ -10,8 +10,9 @@ use crate::shortint::parameters::*;
 use crate::{
     ClientKey, CompactCiphertextList, CompactCiphertextListConformanceParams, CompactPublicKey,
     CompressedCompactPublicKey, CompressedFheUint16, CompressedFheUint256, CompressedFheUint32,
-    CompressedPublicKey, CompressedServerKey, FheInt16, FheInt32, FheInt8, FheUint128, FheUint16,
-    FheUint256, FheUint32, FheUint32ConformanceParams,
+    CompressedPublicKey, CompressedServerKey, DynFheUint, FheBool, FheInt16, FheInt32, FheInt8,
+    FheUint128, FheUint16, FheUint16Id, FheUint24, FheUint256, FheUint32,
+    FheUint32ConformanceParams, FheUint40, FheUint48, FheUint64, FheUint72, MatchValues,
 };
 use rand::prelude::*;
 
@@ -345,12 +346,155 @@ fn test_integer_casting() {
     }
 }
 
+#[test]
+fn test_integer_saturating_casting() {
+    let client_key = setup_default_cpu();
+
+    let mut rng = rand::thread_rng();
+
+    for clear in [0u16, 255, 256, u16::MAX, rng.gen::<u16>()] {
+        let a = FheUint16::encrypt(clear, &client_key);
+
+        let b: FheUint8 = a.clone().saturating_cast_into();
+        let db: u8 = b.decrypt(&client_key);
+        assert_eq!(db, u8::try_from(clear).unwrap_or(u8::MAX));
+
+        let b: FheInt8 = a.clone().saturating_cast_into();
+        let db: i8 = b.decrypt(&client_key);
+        assert_eq!(db, i8::try_from(clear).unwrap_or(i8::MAX));
+
+        // Upcasting is the same as a regular cast
+        let b = FheUint32::saturating_cast_from(a);
+        let db: u32 = b.decrypt(&client_key);
+        assert_eq!(db, u32::from(clear));
+    }
+
+    for clear in [
+        0i16,
+        -1,
+        127,
+        128,
+        -128,
+        -129,
+        i16::MIN,
+        i16::MAX,
+        rng.gen::<i16>(),
+    ] {
+        let a = FheInt16::encrypt(clear, &client_key);
+
+        let b: FheInt8 = a.clone().saturating_cast_into();
+        let db: i8 = b.decrypt(&client_key);
+        assert_eq!(db, clear.clamp(i8::MIN.into(), i8::MAX.into()) as i8);
+
+        let b: FheUint8 = a.clone().saturating_cast_into();
+        let db: u8 = b.decrypt(&client_key);
+        assert_eq!(db, clear.clamp(0, u8::MAX.into()) as u8);
+
+        let b = FheUint32::saturating_cast_from(a);
+        let db: u32 = b.decrypt(&client_key);
+        assert_eq!(db, clear.max(0) as u32);
+    }
+}
+
+#[test]
+fn test_integer_overflowing_casting() {
+    let client_key = setup_default_cpu();
+
+    let mut rng = rand::thread_rng();
+
+    for clear in [0u16, 127, 128, 256, u16::MAX, rng.gen::<u16>()] {
+        let a = FheUint16::encrypt(clear, &client_key);
+
+        let (b, overflowed): (FheUint8, _) = a.clone().overflowing_cast_into();
+        let db: u8 = b.decrypt(&client_key);
+        assert_eq!(db, clear as u8);
+        assert_eq!(
+            overflowed.decrypt(&client_key),
+            u8::try_from(clear).is_err()
+        );
+
+        let (b, overflowed): (FheInt8, _) = a.clone().overflowing_cast_into();
+        let db: i8 = b.decrypt(&client_key);
+        assert_eq!(db, clear as i8);
+        assert_eq!(
+            overflowed.decrypt(&client_key),
+            i8::try_from(clear).is_err()
+        );
+
+        let (b, overflowed) = FheUint32::overflowing_cast_from(a);
+        let db: u32 = b.decrypt(&client_key);
+        assert_eq!(db, u32::from(clear));
+        assert!(!overflowed.decrypt(&client_key));
+    }
+
+    for clear in [0i16, -1, 127, 128, -128, -129, i16::MIN, rng.gen::<i16>()] {
+        let a = FheInt16::encrypt(clear, &client_key);
+
+        let (b, overflowed): (FheInt8, _) = a.clone().overflowing_cast_into();
+        let db: i8 = b.decrypt(&client_key);
+        assert_eq!(db, clear as i8);
+        assert_eq!(
+            overflowed.decrypt(&client_key),
+            i8::try_from(clear).is_err()
+        );
+
+        let (b, overflowed): (FheUint8, _) = a.clone().overflowing_cast_into();
+        let db: u8 = b.decrypt(&client_key);
+        assert_eq!(db, clear as u8);
+        assert_eq!(
+            overflowed.decrypt(&client_key),
+            u8::try_from(clear).is_err()
+        );
+
+        let (b, overflowed) = FheUint32::overflowing_cast_from(a);
+        let db: u32 = b.decrypt(&client_key);
+        assert_eq!(db, clear as u32);
+        assert_eq!(overflowed.decrypt(&client_key), clear < 0);
+    }
+}
+
 #[test]
 fn test_if_then_else() {
     let client_key = setup_default_cpu();
     super::test_case_if_then_else(&client_key);
 }
 
+#[test]
+fn test_select_slice() {
+    let client_key = setup_default_cpu();
+
+    let clears_true = [1u32, u32::MAX, 42];
+    let clears_false = [7u32, 0, 1337];
+    let cts_true = clears_true.map(|v| FheUint32::encrypt(v, &client_key));
+    let cts_false = clears_false.map(|v| FheUint32::encrypt(v, &client_key));
+
+    for clear_condition in [false, true] {
+        let condition = FheBool::encrypt(clear_condition, &client_key);
+        let result = condition.select_slice(&cts_true, &cts_false);
+        let decrypted = result
+            .iter()
+            .map(|ct| ct.decrypt(&client_key))
+            .collect::<Vec<u32>>();
+        let expected = if clear_condition {
+            clears_true
+        } else {
+            clears_false
+        };
+        assert_eq!(decrypted, expected);
+    }
+
+    // Signed integers use the same batched selection
+    let cts_true = [-1i8, 5].map(|v| FheInt8::encrypt(v, &client_key));
+    let cts_false = [3i8, -128].map(|v| FheInt8::encrypt(v, &client_key));
+    let condition = FheBool::encrypt(false, &client_key);
+    let decrypted = condition
+        .if_then_else_slice(&cts_true, &cts_false)
+        .iter()
+        .map(|ct| ct.decrypt(&client_key))
+        .collect::<Vec<i8>>();
+    assert_eq!(decrypted, vec![3, -128]);
+}
+
 #[test]
 fn test_scalar_shift_when_clear_type_is_small() {
     // This is a regression tests
@@ -385,6 +529,18 @@ fn test_ilog2() {
     super::test_case_ilog2(&client_key);
 }
 
+#[test]
+fn test_div_rem_pow2_hint() {
+    let client_key = setup_default_cpu();
+    super::test_case_div_rem_pow2_hint(&client_key);
+}
+
+#[test]
+fn test_is_zero_is_nonzero() {
+    let client_key = setup_default_cpu();
+    super::test_case_is_zero_is_nonzero(&client_key);
+}
+
 #[test]
 fn test_is_even_is_odd() {
     let client_key = setup_default_cpu();
@@ -409,6 +565,18 @@ fn test_sum() {
     super::test_case_sum(&client_key);
 }
 
+#[test]
+fn test_wrapping_checked() {
+    let client_key = setup_default_cpu();
+    super::test_case_wrapping_checked(&client_key);
+}
+
+#[test]
+fn test_swap_bytes_rotate_blocks() {
+    let client_key = setup_default_cpu();
+    super::test_case_swap_bytes_rotate_blocks(&client_key);
+}
+
 #[test]
 fn test_safe_deserialize_conformant_fhe_uint32() {
     let block_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
@@ -594,3 +762,1011 @@ fn test_compressed_cpk_encrypt_cast_compute_hl() {
     let clear: u64 = mul.decrypt(&client_key);
     assert_eq!(clear, (input_msg * multiplier) % modulus);
 }
+
+#[test]
+fn test_reductions_until_deadline() {
+    use std::time::{Duration, Instant};
+
+    let client_key = setup_default_cpu();
+    let mut rng = rand::thread_rng();
+
+    let clears = (0..5).map(|_| rng.gen::<u8>()).collect::<Vec<_>>();
+    let values = clears
+        .iter()
+        .map(|&value| FheUint8::encrypt(value, &client_key))
+        .collect::<Vec<_>>();
+
+    // With a deadline already reached, only what requires no computation is included
+    let past = Instant::now();
+    let (sum, count) = FheUint8::sum_until(&values, past);
+    assert_eq!(count, 0);
+    let sum: u8 = sum.decrypt(&client_key);
+    assert_eq!(sum, 0);
+
+    let (min, count) = FheUint8::min_until(&values, past).unwrap();
+    assert_eq!(count, 1);
+    let min: u8 = min.decrypt(&client_key);
+    assert_eq!(min, clears[0]);
+
+    assert!(FheUint8::max_until(&[], past).is_none());
+
+    // With a far deadline, all the values are included
+    let future = Instant::now() + Duration::from_secs(3600);
+    let (sum, count) = FheUint8::sum_until(&values, future);
+    assert_eq!(count, clears.len());
+    let sum: u8 = sum.decrypt(&client_key);
+    assert_eq!(sum, clears.iter().copied().fold(0u8, u8::wrapping_add));
+
+    let (min, count) = FheUint8::min_until(&values, future).unwrap();
+    assert_eq!(count, clears.len());
+    let min: u8 = min.decrypt(&client_key);
+    assert_eq!(min, *clears.iter().min().unwrap());
+
+    let (max, count) = FheUint8::max_until(&values, future).unwrap();
+    assert_eq!(count, clears.len());
+    let max: u8 = max.decrypt(&client_key);
+    assert_eq!(max, *clears.iter().max().unwrap());
+}
+
+#[test]
+fn test_clear_matrix_mul_enc_vector() {
+    let client_key = setup_default_cpu();
+    let mut rng = rand::thread_rng();
+
+    let clears = [rng.gen::<u8>(), rng.gen::<u8>(), rng.gen::<u8>()];
+    let vector = clears
+        .iter()
+        .map(|&clear| FheUint8::encrypt(clear, &client_key))
+        .collect::<Vec<_>>();
+    let matrix = vec![vec![3i64, -7, 0], vec![0, 0, 0], vec![-128, 255, 1000]];
+
+    let result = FheUint8::clear_matrix_mul_enc_vector(&matrix, &vector);
+    assert_eq!(result.len(), matrix.len());
+
+    for (row, value) in matrix.iter().zip(result.iter()) {
+        let expected = row
+            .iter()
+            .zip(clears.iter())
+            .fold(0u8, |acc, (&coefficient, &clear)| {
+                acc.wrapping_add((coefficient as u8).wrapping_mul(clear))
+            });
+        let decrypted: u8 = value.decrypt(&client_key);
+        assert_eq!(decrypted, expected);
+    }
+}
+
+#[test]
+fn test_merge_by_mask() {
+    let client_key = setup_default_cpu();
+    let mut rng = rand::thread_rng();
+
+    let clear_a = rng.gen::<u16>();
+    let clear_b = rng.gen::<u16>();
+    let clear_mask = rng.gen::<u16>();
+
+    let a = FheUint16::encrypt(clear_a, &client_key);
+    let b = FheUint16::encrypt(clear_b, &client_key);
+    let mask = FheUint16::encrypt(clear_mask, &client_key);
+
+    let result = a.merge_by_mask(&b, &mask);
+    let decrypted: u16 = result.decrypt(&client_key);
+    assert_eq!(decrypted, (clear_a & clear_mask) | (clear_b & !clear_mask));
+}
+
+#[test]
+fn test_le_bytes() {
+    let client_key = setup_default_cpu();
+    let mut rng = rand::thread_rng();
+
+    let clear = rng.gen::<u64>();
+    let a = crate::FheUint64::encrypt(clear, &client_key);
+
+    let bytes = a.to_le_bytes();
+    assert_eq!(bytes.len(), 8);
+    let decrypted = bytes
+        .iter()
+        .map(|byte| byte.decrypt(&client_key))
+        .collect::<Vec<u8>>();
+    assert_eq!(decrypted, clear.to_le_bytes());
+
+    // Modify a byte before regrouping the bytes
+    let mut bytes = bytes;
+    bytes[0] = &bytes[0] + 1u8;
+    let b = crate::FheUint64::from_le_bytes(&bytes);
+    let decrypted: u64 = b.decrypt(&client_key);
+    let mut expected = clear.to_le_bytes();
+    expected[0] = expected[0].wrapping_add(1);
+    assert_eq!(decrypted, u64::from_le_bytes(expected));
+}
+
+#[test]
+fn test_cmp_against_thresholds() {
+    let client_key = setup_default_cpu();
+    let mut rng = rand::thread_rng();
+
+    let clear = rng.gen::<u16>();
+    let a = FheUint16::encrypt(clear, &client_key);
+
+    let thresholds = [
+        0u64,
+        u64::from(clear),
+        u64::from(clear) + 1,
+        u64::from(rng.gen::<u16>()),
+        u64::from(rng.gen::<u16>()),
+        1 << 16,
+    ];
+    let results = a.cmp_against_thresholds(&thresholds);
+    assert_eq!(results.len(), thresholds.len());
+
+    for (threshold, result) in thresholds.iter().zip(results.iter()) {
+        let decrypted = result.decrypt(&client_key);
+        assert_eq!(decrypted, u64::from(clear) >= *threshold);
+    }
+}
+
+#[test]
+fn test_checked_div_rem() {
+    let client_key = setup_default_cpu();
+    let mut rng = rand::thread_rng();
+
+    let clear_a = rng.gen::<u16>();
+    let a = FheUint16::encrypt(clear_a, &client_key);
+    for clear_b in [rng.gen::<u16>(), 0] {
+        let b = FheUint16::encrypt(clear_b, &client_key);
+
+        let (q, is_divisor_zero) = a.checked_div(&b);
+        assert_eq!(is_divisor_zero.decrypt(&client_key), clear_b == 0);
+        let decrypted: u16 = q.decrypt(&client_key);
+        assert_eq!(decrypted, clear_a.checked_div(clear_b).unwrap_or(u16::MAX));
+
+        let (r, is_divisor_zero) = a.checked_rem(&b);
+        assert_eq!(is_divisor_zero.decrypt(&client_key), clear_b == 0);
+        let decrypted: u16 = r.decrypt(&client_key);
+        assert_eq!(decrypted, clear_a.checked_rem(clear_b).unwrap_or(clear_a));
+    }
+}
+
+#[test]
+fn test_pow() {
+    let client_key = setup_default_cpu();
+    let mut rng = rand::thread_rng();
+
+    let clear_a = rng.gen::<u8>();
+    let clear_b = rng.gen::<u8>();
+    let a = FheUint8::encrypt(clear_a, &client_key);
+    let b = FheUint8::encrypt(clear_b, &client_key);
+
+    let result = a.pow(&b);
+    let decrypted: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.wrapping_pow(u32::from(clear_b)));
+
+    for exponent in [0, 1, rng.gen_range(2..1000)] {
+        let result = a.pow_scalar(exponent);
+        let decrypted: u8 = result.decrypt(&client_key);
+        assert_eq!(decrypted, clear_a.wrapping_pow(exponent));
+    }
+}
+
+#[test]
+fn test_fhe_sort() {
+    let client_key = setup_default_cpu();
+    let mut rng = rand::thread_rng();
+
+    for len in [0, 1, 5] {
+        let mut clears = (0..len).map(|_| rng.gen::<u16>()).collect::<Vec<_>>();
+        let mut values = clears
+            .iter()
+            .map(|&v| FheUint16::encrypt(v, &client_key))
+            .collect::<Vec<_>>();
+
+        crate::algorithms::fhe_sort(&mut values);
+        clears.sort_unstable();
+
+        let decrypted = values
+            .iter()
+            .map(|v| v.decrypt(&client_key))
+            .collect::<Vec<u16>>();
+        assert_eq!(decrypted, clears);
+    }
+
+    let clear_keys = [300u16, 7, 42, 1000];
+    let mut keys = clear_keys.map(|v| FheUint16::encrypt(v, &client_key));
+    let mut values = [0u8, 1, 2, 3].map(|v| FheUint8::encrypt(v, &client_key));
+
+    crate::algorithms::fhe_sort_by_key(&mut keys, &mut values);
+
+    let decrypted_keys = keys
+        .iter()
+        .map(|v| v.decrypt(&client_key))
+        .collect::<Vec<u16>>();
+    let decrypted_values = values
+        .iter()
+        .map(|v| v.decrypt(&client_key))
+        .collect::<Vec<u8>>();
+    assert_eq!(decrypted_keys, vec![7, 42, 300, 1000]);
+    assert_eq!(decrypted_values, vec![1, 2, 0, 3]);
+}
+
+#[test]
+fn test_fhe_sum_min_max() {
+    let client_key = setup_default_cpu();
+    let mut rng = rand::thread_rng();
+
+    let clears = (0..5).map(|_| rng.gen::<u16>()).collect::<Vec<_>>();
+    let values = clears
+        .iter()
+        .map(|&v| FheUint16::encrypt(v, &client_key))
+        .collect::<Vec<_>>();
+
+    let sum: u16 = crate::algorithms::fhe_sum(&values).decrypt(&client_key);
+    let min: u16 = crate::algorithms::fhe_min(&values)
+        .unwrap()
+        .decrypt(&client_key);
+    let max: u16 = crate::algorithms::fhe_max(&values)
+        .unwrap()
+        .decrypt(&client_key);
+    assert_eq!(sum, clears.iter().copied().fold(0, u16::wrapping_add));
+    assert_eq!(min, *clears.iter().min().unwrap());
+    assert_eq!(max, *clears.iter().max().unwrap());
+
+    let sum: u16 = crate::algorithms::fhe_sum::<FheUint16Id>(&[]).decrypt(&client_key);
+    assert_eq!(sum, 0);
+    assert!(crate::algorithms::fhe_min::<FheUint16Id>(&[]).is_none());
+    assert!(crate::algorithms::fhe_max::<FheUint16Id>(&[]).is_none());
+}
+
+#[test]
+fn test_fhe_accumulator() {
+    let client_key = setup_default_cpu();
+    let mut rng = rand::thread_rng();
+
+    let mut accumulator = crate::FheAccumulator::<FheUint16>::new();
+    let mut expected = 0u16;
+    // Enough values for the carries to have to be propagated several times
+    for _ in 0..12 {
+        let clear = rng.gen::<u16>();
+        accumulator.absorb(&FheUint16::encrypt(clear, &client_key));
+        expected = expected.wrapping_add(clear);
+    }
+
+    // The accumulation can be checkpointed and resumed
+    let mut serialized = vec![];
+    crate::safe_serialization::safe_serialize(&accumulator, &mut serialized, 1 << 20).unwrap();
+    let mut accumulator: crate::FheAccumulator<FheUint16> =
+        crate::safe_serialization::safe_deserialize(serialized.as_slice(), 1 << 20).unwrap();
+    for _ in 0..4 {
+        let clear = rng.gen::<u16>();
+        accumulator.absorb(&FheUint16::encrypt(clear, &client_key));
+        expected = expected.wrapping_add(clear);
+    }
+
+    let sum: u16 = accumulator.finish().decrypt(&client_key);
+    assert_eq!(sum, expected);
+
+    let sum: u16 = crate::FheAccumulator::<FheUint16>::new()
+        .finish()
+        .decrypt(&client_key);
+    assert_eq!(sum, 0);
+}
+
+#[test]
+fn test_fhe_dot_product() {
+    let client_key = setup_default_cpu();
+    let mut rng = rand::thread_rng();
+
+    let clear_lhs = (0..4).map(|_| rng.gen::<u16>()).collect::<Vec<_>>();
+    let clear_rhs = (0..4).map(|_| rng.gen::<u16>()).collect::<Vec<_>>();
+    let lhs = clear_lhs
+        .iter()
+        .map(|&v| FheUint16::encrypt(v, &client_key))
+        .collect::<Vec<_>>();
+    let rhs = clear_rhs
+        .iter()
+        .map(|&v| FheUint16::encrypt(v, &client_key))
+        .collect::<Vec<_>>();
+
+    let expected = clear_lhs
+        .iter()
+        .zip(clear_rhs.iter())
+        .fold(0u32, |acc, (&l, &r)| {
+            acc.wrapping_add(u32::from(l) * u32::from(r))
+        });
+
+    let result: FheUint32 = crate::algorithms::fhe_dot_product(&lhs, &rhs);
+    let decrypted: u32 = result.decrypt(&client_key);
+    assert_eq!(decrypted, expected);
+
+    let result: FheUint32 = crate::algorithms::fhe_scalar_dot_product(&lhs, &clear_rhs);
+    let decrypted: u32 = result.decrypt(&client_key);
+    assert_eq!(decrypted, expected);
+
+    // Without widening, the result wraps around
+    let result: FheUint16 = crate::algorithms::fhe_scalar_dot_product(&lhs, &clear_rhs);
+    let decrypted: u16 = result.decrypt(&client_key);
+    assert_eq!(decrypted, expected as u16);
+
+    let result: FheUint32 = crate::algorithms::fhe_scalar_dot_product::<_, _, u16>(&[], &[]);
+    let decrypted: u32 = result.decrypt(&client_key);
+    assert_eq!(decrypted, 0);
+}
+
+#[test]
+fn test_widening_mul_and_mul_high() {
+    let client_key = setup_default_cpu();
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..3 {
+        let clear_a = rng.gen::<u32>();
+        let clear_b = rng.gen::<u32>();
+        let a = FheUint32::encrypt(clear_a, &client_key);
+        let b = FheUint32::encrypt(clear_b, &client_key);
+
+        let expected = u64::from(clear_a) * u64::from(clear_b);
+
+        let result: FheUint64 = a.widening_mul(&b).unwrap();
+        let decrypted: u64 = result.decrypt(&client_key);
+        assert_eq!(decrypted, expected);
+
+        let result = a.mul_high(&b);
+        let decrypted: u32 = result.decrypt(&client_key);
+        assert_eq!(decrypted, (expected >> 32) as u32);
+    }
+
+    let a = FheUint32::encrypt(u32::MAX, &client_key);
+    let result: crate::Result<FheUint48> = a.widening_mul(&a);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_as_block_bytes_deduplication() {
+    let client_key = setup_default_cpu();
+
+    let values = [
+        FheUint32::encrypt_trivial(7u32),
+        FheUint32::encrypt(7u32, &client_key),
+        FheUint32::encrypt_trivial(7u32),
+        FheUint32::encrypt_trivial(8u32),
+    ];
+    let a = values[1].clone();
+
+    let distinct = values
+        .iter()
+        .chain(std::iter::once(&a))
+        .map(|value| value.as_block_bytes())
+        .collect::<std::collections::HashSet<_>>();
+    assert_eq!(distinct.len(), 3);
+
+    let block_bytes = a.as_block_bytes();
+    assert_eq!(block_bytes.len(), 16);
+    assert!(block_bytes
+        .iter()
+        .all(|bytes| bytes.len() == block_bytes[0].len()));
+}
+
+#[test]
+fn test_saturating_ops() {
+    let client_key = setup_default_cpu();
+
+    let mut rng = rand::thread_rng();
+
+    for (clear_a, clear_b) in [
+        (u16::MAX - 1, 2u16),
+        (1u16, 2u16),
+        (rng.gen::<u16>(), rng.gen::<u16>()),
+    ] {
+        let a = FheUint16::encrypt(clear_a, &client_key);
+        let b = FheUint16::encrypt(clear_b, &client_key);
+
+        let result: u16 = (&a).saturating_add(&b).decrypt(&client_key);
+        assert_eq!(result, clear_a.saturating_add(clear_b));
+        let result: u16 = (&a).saturating_sub(&b).decrypt(&client_key);
+        assert_eq!(result, clear_a.saturating_sub(clear_b));
+        let result: u16 = (&a).saturating_mul(&b).decrypt(&client_key);
+        assert_eq!(result, clear_a.saturating_mul(clear_b));
+
+        let result: u16 = (&a).saturating_add(clear_b).decrypt(&client_key);
+        assert_eq!(result, clear_a.saturating_add(clear_b));
+        let result: u16 = (&a).saturating_sub(clear_b).decrypt(&client_key);
+        assert_eq!(result, clear_a.saturating_sub(clear_b));
+        let result: u16 = (&a).saturating_mul(clear_b).decrypt(&client_key);
+        assert_eq!(result, clear_a.saturating_mul(clear_b));
+    }
+}
+
+#[test]
+fn test_oblivious_pseudo_random_in_range() {
+    let client_key = setup_default_cpu();
+
+    for (seed, excluded_upper_bound) in [(0u128, 1u64), (1, 6), (2, 52), (3, 1 << 16)] {
+        let a = FheUint16::generate_oblivious_pseudo_random_in_range(
+            crate::Seed(seed),
+            excluded_upper_bound,
+        );
+        let decrypted: u16 = a.decrypt(&client_key);
+        assert!(u64::from(decrypted) < excluded_upper_bound);
+    }
+}
+
+#[test]
+fn test_clamp() {
+    let client_key = setup_default_cpu();
+
+    let mut rng = rand::thread_rng();
+
+    let clear_min = rng.gen_range(0..u16::MAX / 2);
+    let clear_max = rng.gen_range(clear_min..=u16::MAX);
+    let min = FheUint16::encrypt(clear_min, &client_key);
+    let max = FheUint16::encrypt(clear_max, &client_key);
+
+    for clear in [0u16, clear_min, clear_max, u16::MAX, rng.gen::<u16>()] {
+        let a = FheUint16::encrypt(clear, &client_key);
+
+        let result: u16 = a.clamp(&min, &max).decrypt(&client_key);
+        assert_eq!(result, clear.clamp(clear_min, clear_max));
+
+        let result: u16 = a.clamp(clear_min, clear_max).decrypt(&client_key);
+        assert_eq!(result, clear.clamp(clear_min, clear_max));
+    }
+}
+
+#[test]
+fn test_is_in_range() {
+    let client_key = setup_default_cpu();
+
+    let mut rng = rand::thread_rng();
+
+    let clear_low = rng.gen_range(0..u16::MAX / 2);
+    let clear_high = rng.gen_range(clear_low..=u16::MAX);
+    let low = FheUint16::encrypt(clear_low, &client_key);
+    let high = FheUint16::encrypt(clear_high, &client_key);
+
+    for clear in [0u16, clear_low, clear_high, u16::MAX, rng.gen::<u16>()] {
+        let a = FheUint16::encrypt(clear, &client_key);
+        let expected = (clear_low..=clear_high).contains(&clear);
+
+        let result: bool = a.is_in_range(&low, &high).decrypt(&client_key);
+        assert_eq!(result, expected);
+
+        let result: bool = a.is_in_range(clear_low, clear_high).decrypt(&client_key);
+        assert_eq!(result, expected);
+
+        // Empty range
+        let result: bool = a.is_in_range(&high, &low).decrypt(&client_key);
+        assert_eq!(result, clear_low == clear_high && clear == clear_low);
+    }
+}
+
+#[test]
+fn test_implicit_widening() {
+    let config = ConfigBuilder::default().enable_implicit_widening().build();
+    assert!(config.is_implicit_widening_enabled());
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+
+    let clear_a = rng.gen::<u8>();
+    let clear_b = rng.gen::<u32>();
+    let a = FheUint8::encrypt(clear_a, &client_key);
+    let b = FheUint32::encrypt(clear_b, &client_key);
+
+    let result: u32 = (&a + &b).decrypt(&client_key);
+    assert_eq!(result, u32::from(clear_a).wrapping_add(clear_b));
+
+    let result: u32 = (&b - &a).decrypt(&client_key);
+    assert_eq!(result, clear_b.wrapping_sub(u32::from(clear_a)));
+
+    let result: u32 = (&a * &b).decrypt(&client_key);
+    assert_eq!(result, u32::from(clear_a).wrapping_mul(clear_b));
+
+    let result: u32 = (&a ^ &b).decrypt(&client_key);
+    assert_eq!(result, u32::from(clear_a) ^ clear_b);
+
+    let result: bool = a.lt(&b).decrypt(&client_key);
+    assert_eq!(result, u32::from(clear_a) < clear_b);
+
+    let result: bool = b.eq(&a).decrypt(&client_key);
+    assert_eq!(result, clear_b == u32::from(clear_a));
+
+    // Keys generated without the policy forbid operations between different widths
+    let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    set_server_key(server_key);
+    let a = FheUint8::encrypt(clear_a, &client_key);
+    let b = FheUint32::encrypt(clear_b, &client_key);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| &a + &b));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_hamming_distance() {
+    let client_key = setup_default_cpu();
+    let mut rng = rand::thread_rng();
+
+    let clear_a = rng.gen::<u16>();
+    let clear_b = rng.gen::<u16>();
+    let a = FheUint16::encrypt(clear_a, &client_key);
+    let b = FheUint16::encrypt(clear_b, &client_key);
+    let expected = (clear_a ^ clear_b).count_ones();
+
+    let result = a.hamming_distance(&b);
+    let decrypted: u32 = result.decrypt(&client_key);
+    assert_eq!(decrypted, expected);
+
+    let result = crate::algorithms::fhe_hamming_distance(&a, &b);
+    let decrypted: u32 = result.decrypt(&client_key);
+    assert_eq!(decrypted, expected);
+
+    for max_distance in [0, expected.saturating_sub(1), expected, 16] {
+        let result = a.is_within_hamming(&b, max_distance);
+        assert_eq!(result.decrypt(&client_key), expected <= max_distance);
+    }
+}
+
+#[test]
+fn test_match_value() {
+    let client_key = setup_default_cpu();
+
+    let table = [(0u16, 3u16), (1, 200), (17, 25), (1000, 0)];
+    let matches = MatchValues::try_from(table.as_slice()).unwrap();
+
+    for clear_input in [0u16, 1, 17, 1000, 42] {
+        let input = FheUint16::encrypt(clear_input, &client_key);
+        let expected = table
+            .iter()
+            .find(|(key, _)| *key == clear_input)
+            .map(|(_, value)| *value);
+
+        let (result, matched): (FheUint8, _) = input.match_value(&matches).unwrap();
+        let decrypted: u8 = result.decrypt(&client_key);
+        assert_eq!(matched.decrypt(&client_key), expected.is_some());
+        assert_eq!(u16::from(decrypted), expected.unwrap_or(0));
+
+        let result: FheUint8 = input.match_value_or(&matches, 255).unwrap();
+        let decrypted: u8 = result.decrypt(&client_key);
+        assert_eq!(u16::from(decrypted), expected.unwrap_or(255));
+    }
+
+    // The output type must be able to hold all the output values
+    let matches = MatchValues::try_from([(0u16, 1000u16)].as_slice()).unwrap();
+    let input = FheUint16::encrypt(0u16, &client_key);
+    let result: crate::Result<(FheUint8, FheBool)> = input.match_value(&matches);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_scalar_base_pow_mod() {
+    let client_key = setup_default_cpu();
+    let mut rng = rand::thread_rng();
+
+    let clear_exponent = rng.gen::<u16>();
+    let exponent = FheUint16::encrypt(clear_exponent, &client_key);
+
+    for (base, modulus) in [
+        (2u64, 65521u64),
+        (rng.gen(), rng.gen_range(1..=u64::from(u16::MAX))),
+    ] {
+        let result = FheUint16::scalar_base_pow_mod(base, &exponent, modulus);
+        let decrypted: u16 = result.decrypt(&client_key);
+
+        let expected = (0..clear_exponent).fold(1 % modulus, |acc, _| {
+            ((u128::from(acc) * u128::from(base)) % u128::from(modulus)) as u64
+        });
+        assert_eq!(u64::from(decrypted), expected);
+    }
+}
+
+#[test]
+fn test_modular_arith() {
+    let client_key = setup_default_cpu();
+    let mut rng = rand::thread_rng();
+
+    for modulus in [65521u64, 1 << 16, rng.gen_range(1..=u64::from(u16::MAX))] {
+        let clear_a = rng.gen_range(0..modulus);
+        let clear_b = rng.gen_range(0..modulus);
+        let clear_exponent = rng.gen::<u8>();
+        let a = FheUint16::encrypt(clear_a as u16, &client_key);
+        let b = FheUint16::encrypt(clear_b as u16, &client_key);
+
+        let result: u16 = a.mod_add(&b, modulus).decrypt(&client_key);
+        assert_eq!(u64::from(result), (clear_a + clear_b) % modulus);
+
+        let result: u16 = a.mod_sub(&b, modulus).decrypt(&client_key);
+        assert_eq!(u64::from(result), (clear_a + modulus - clear_b) % modulus);
+
+        let result: u16 = a.mod_mul(&b, modulus).decrypt(&client_key);
+        assert_eq!(u64::from(result), (clear_a * clear_b) % modulus);
+
+        let result: u16 = a
+            .mod_pow_scalar(u64::from(clear_exponent), modulus)
+            .decrypt(&client_key);
+        let expected = (0..clear_exponent).fold(1 % modulus, |acc, _| (acc * clear_a) % modulus);
+        assert_eq!(u64::from(result), expected);
+    }
+}
+
+#[test]
+fn test_big_scalar_operands() {
+    let client_key = setup_default_cpu();
+    let mut rng = rand::thread_rng();
+
+    let clear_a = rng.gen::<U256>();
+    let limbs = rng.gen::<[u64; 4]>();
+    let clear_b = U256::from(limbs);
+    let a = FheUint256::encrypt(clear_a, &client_key);
+
+    let result: U256 = (&a + limbs).decrypt(&client_key);
+    assert_eq!(result, clear_a + clear_b);
+
+    let result: U256 = (&a - limbs).decrypt(&client_key);
+    assert_eq!(result, clear_a - clear_b);
+
+    let result: U256 = (&a * limbs).decrypt(&client_key);
+    assert_eq!(result, clear_a * clear_b);
+
+    let mut b = a.clone();
+    b += limbs;
+    let result: U256 = b.decrypt(&client_key);
+    assert_eq!(result, clear_a + clear_b);
+
+    assert_eq!(a.eq(limbs).decrypt(&client_key), clear_a == clear_b);
+    assert_eq!(a.lt(limbs).decrypt(&client_key), clear_a < clear_b);
+    assert_eq!(a.ge(limbs).decrypt(&client_key), clear_a >= clear_b);
+
+    // Limbs wider than the ciphertext are compared on their full value
+    let wide_limbs = [0u64, 0, 0, 0, 1];
+    assert!(a.lt(wide_limbs).decrypt(&client_key));
+    assert!(!a.eq(wide_limbs).decrypt(&client_key));
+
+    #[cfg(feature = "num-bigint")]
+    {
+        use num_bigint::BigUint;
+
+        let big_b = BigUint::from_slice(&[
+            limbs[0] as u32,
+            (limbs[0] >> 32) as u32,
+            limbs[1] as u32,
+            (limbs[1] >> 32) as u32,
+            limbs[2] as u32,
+            (limbs[2] >> 32) as u32,
+            limbs[3] as u32,
+            (limbs[3] >> 32) as u32,
+        ]);
+
+        let result: U256 = (&a + &big_b).decrypt(&client_key);
+        assert_eq!(result, clear_a + clear_b);
+
+        let result: U256 = (&a * big_b.clone()).decrypt(&client_key);
+        assert_eq!(result, clear_a * clear_b);
+
+        assert_eq!(a.le(&big_b).decrypt(&client_key), clear_a <= clear_b);
+
+        let huge = BigUint::from(1u8) << 5000;
+        assert!(a.lt(&huge).decrypt(&client_key));
+        assert!(a.ne(huge).decrypt(&client_key));
+    }
+}
+
+#[test]
+fn test_isqrt() {
+    let client_key = setup_default_cpu();
+    let mut rng = rand::thread_rng();
+
+    for clear in [0u16, u16::MAX, rng.gen::<u16>()] {
+        let a = FheUint16::encrypt(clear, &client_key);
+
+        let (root, remainder) = a.sqrt_rem();
+        let root: u16 = root.decrypt(&client_key);
+        let remainder: u16 = remainder.decrypt(&client_key);
+        assert!(u32::from(root) * u32::from(root) <= u32::from(clear));
+        assert!((u32::from(root) + 1) * (u32::from(root) + 1) > u32::from(clear));
+        assert_eq!(
+            u32::from(root) * u32::from(root) + u32::from(remainder),
+            u32::from(clear)
+        );
+
+        let isqrt: u16 = a.isqrt().decrypt(&client_key);
+        assert_eq!(isqrt, root);
+    }
+}
+
+#[test]
+fn test_join_eq() {
+    let client_key = setup_default_cpu();
+
+    let left = [1u8, 2, 3];
+    let right = [3u8, 1, 3];
+    let amounts = [10u16, 20, 300];
+
+    let enc_left = left
+        .iter()
+        .map(|v| FheUint8::encrypt(*v, &client_key))
+        .collect::<Vec<_>>();
+    let enc_right = right
+        .iter()
+        .map(|v| FheUint8::encrypt(*v, &client_key))
+        .collect::<Vec<_>>();
+    let enc_amounts = amounts
+        .iter()
+        .map(|v| FheUint16::encrypt(*v, &client_key))
+        .collect::<Vec<_>>();
+
+    let matches = crate::fhe_join_eq(&enc_left, &enc_right);
+    assert_eq!(matches.len(), left.len());
+    for (i, row) in matches.iter().enumerate() {
+        assert_eq!(row.len(), right.len());
+        for (j, is_match) in row.iter().enumerate() {
+            assert_eq!(is_match.decrypt(&client_key), left[i] == right[j]);
+        }
+    }
+
+    let sums = crate::fhe_join_eq_sum(&enc_left, &enc_right, &enc_amounts);
+    let sums = sums
+        .iter()
+        .map(|sum| sum.decrypt(&client_key))
+        .collect::<Vec<u16>>();
+    assert_eq!(sums, vec![20, 0, 310]);
+
+    let no_sums = crate::fhe_join_eq_sum(&enc_left, &[], &[] as &[FheUint16]);
+    assert_eq!(no_sums.len(), left.len());
+    for sum in no_sums {
+        let sum: u16 = sum.decrypt(&client_key);
+        assert_eq!(sum, 0);
+    }
+}
+
+#[test]
+fn test_select_case() {
+    let client_key = setup_default_cpu();
+
+    let clear_arms = [10u16, 200, 3000, 30000, 5];
+    let arms = clear_arms
+        .iter()
+        .map(|v| FheUint16::encrypt(*v, &client_key))
+        .collect::<Vec<_>>();
+    let signed_arms = clear_arms
+        .iter()
+        .map(|v| FheInt16::encrypt(-(*v as i16), &client_key))
+        .collect::<Vec<_>>();
+
+    for clear_selector in [0u8, 3, 4, 5, 255] {
+        let selector = FheUint8::encrypt(clear_selector, &client_key);
+
+        let expected = clear_arms
+            .get(clear_selector as usize)
+            .copied()
+            .unwrap_or(0);
+        let result: u16 = selector.select_case(&arms).decrypt(&client_key);
+        assert_eq!(result, expected);
+
+        let result: i16 = selector.select_case(&signed_arms).decrypt(&client_key);
+        assert_eq!(result, -(expected as i16));
+    }
+}
+
+#[test]
+fn test_odd_width_types() {
+    let client_key = setup_default_cpu();
+
+    // e.g. a 24-bit RGB color
+    let color = FheUint24::encrypt(0xFF_FF_FFu32, &client_key);
+    let decrypted: u32 = (&color + 1u32).decrypt(&client_key);
+    assert_eq!(decrypted, 0);
+    let decrypted: u32 = (&color >> 16u32).decrypt(&client_key);
+    assert_eq!(decrypted, 0xFF);
+
+    // e.g. a 48-bit timestamp
+    let clear_timestamp = (1u64 << 48) - 10;
+    let timestamp = FheUint48::encrypt(clear_timestamp, &client_key);
+    let decrypted: u64 = (&timestamp + 20u64).decrypt(&client_key);
+    assert_eq!(decrypted, 10);
+    assert!(timestamp.gt(1u64 << 47).decrypt(&client_key));
+
+    let a = FheUint40::encrypt(u64::MAX, &client_key);
+    let decrypted: u64 = a.decrypt(&client_key);
+    assert_eq!(decrypted, (1u64 << 40) - 1);
+
+    let clear_b = (1u128 << 72) - 1;
+    let b = FheUint72::encrypt(clear_b, &client_key);
+    let decrypted: u128 = (&b * 3u128).decrypt(&client_key);
+    assert_eq!(decrypted, (clear_b * 3) % (1u128 << 72));
+}
+
+#[test]
+fn test_dyn_fhe_uint() {
+    let client_key = setup_default_cpu();
+
+    // 5 blocks of 2 bits
+    let num_blocks = 5;
+    let modulus = 1u32 << 10;
+    let clear_a = 1000u32;
+    let clear_b = 37u32;
+
+    let a = DynFheUint::encrypt(clear_a, num_blocks, &client_key);
+    let b = DynFheUint::encrypt(clear_b, num_blocks, &client_key);
+    assert_eq!(a.num_blocks(), num_blocks);
+
+    let decrypted: u32 = (&a + &b).decrypt(&client_key);
+    assert_eq!(decrypted, (clear_a + clear_b) % modulus);
+    let decrypted: u32 = (&b - &a).decrypt(&client_key);
+    assert_eq!(decrypted, (clear_b.wrapping_sub(clear_a)) % modulus);
+    let decrypted: u32 = (&a * &b).decrypt(&client_key);
+    assert_eq!(decrypted, (clear_a * clear_b) % modulus);
+    let (q, r) = (&a).div_rem(&b);
+    let decrypted_q: u32 = q.decrypt(&client_key);
+    let decrypted_r: u32 = r.decrypt(&client_key);
+    assert_eq!(decrypted_q, clear_a / clear_b);
+    assert_eq!(decrypted_r, clear_a % clear_b);
+    let decrypted: u32 = (&a ^ 0x3FFu32).decrypt(&client_key);
+    assert_eq!(decrypted, clear_a ^ 0x3FF);
+    let decrypted: u32 = (&a >> 3u32).decrypt(&client_key);
+    assert_eq!(decrypted, clear_a >> 3);
+
+    assert!(a.gt(&b).decrypt(&client_key));
+    assert!(!a.eq(&b).decrypt(&client_key));
+    assert!(b.le(37u32).decrypt(&client_key));
+    let decrypted: u32 = a.min(&b).decrypt(&client_key);
+    assert_eq!(decrypted, clear_b);
+    let decrypted: u32 = a.gt(&b).if_then_else(&b, &a).decrypt(&client_key);
+    assert_eq!(decrypted, clear_b);
+
+    // Conversions from and to the static types
+    let c = FheUint16::encrypt(u16::MAX, &client_key);
+    let c = DynFheUint::from(c);
+    assert_eq!(c.num_blocks(), 8);
+    let decrypted: u32 = c.cast(num_blocks).decrypt(&client_key);
+    assert_eq!(decrypted, modulus - 1);
+
+    let d = FheUint32::cast_from(a.clone());
+    let decrypted: u32 = d.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a);
+
+    let e = FheUint8::cast_from(a);
+    let decrypted: u8 = e.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a as u8);
+}
+
+#[test]
+fn test_operation_policy() {
+    use crate::{policy_operation_count, OperationKind, OperationPolicy, ServerKey};
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let mut client_key = ClientKey::generate(ConfigBuilder::default());
+    // A tag no other test uses, as the operation counts are global
+    client_key.tag_mut().set_data(b"test_operation_policy");
+    let policy = OperationPolicy::new()
+        .allow_only([OperationKind::Add, OperationKind::Mul, OperationKind::Lt])
+        .deny(OperationKind::Mul)
+        .with_max_bits(16)
+        .with_max_operations_per_tag(3);
+    assert!(policy.is_allowed(OperationKind::Add));
+    assert!(!policy.is_allowed(OperationKind::Mul));
+    assert!(!policy.is_allowed(OperationKind::Sub));
+
+    let server_key = ServerKey::new(&client_key).with_operation_policy(policy.clone());
+    assert_eq!(server_key.operation_policy(), Some(&policy));
+    set_server_key(server_key);
+
+    let a = FheUint16::encrypt(12u16, &client_key);
+    let b = FheUint16::encrypt(30u16, &client_key);
+
+    let c = &a + &b;
+    let decrypted: u16 = c.decrypt(&client_key);
+    assert_eq!(decrypted, 42);
+    assert!(a.lt(30u16).decrypt(&client_key));
+    assert_eq!(policy_operation_count(client_key.tag()), 2);
+
+    // Denied, not allowed, or too wide operations are not counted
+    assert!(catch_unwind(AssertUnwindSafe(|| &a * &b)).is_err());
+    assert!(catch_unwind(AssertUnwindSafe(|| &a - 1u16)).is_err());
+    let wide = FheUint32::encrypt(1u32, &client_key);
+    assert!(catch_unwind(AssertUnwindSafe(|| &wide + &wide)).is_err());
+    assert_eq!(policy_operation_count(client_key.tag()), 2);
+
+    let mut d = a.clone();
+    d += &b;
+    assert_eq!(policy_operation_count(client_key.tag()), 3);
+    assert!(catch_unwind(AssertUnwindSafe(|| &a + &b)).is_err());
+
+    // The policy survives serialization
+    let server_key = ServerKey::new(&client_key).with_operation_policy(policy.clone());
+    let serialized = bincode::serialize(&server_key).unwrap();
+    let deserialized: ServerKey = bincode::deserialize(&serialized).unwrap();
+    assert_eq!(deserialized.operation_policy(), Some(&policy));
+    assert!(deserialized
+        .without_operation_policy()
+        .operation_policy()
+        .is_none());
+}
+
+#[test]
+fn test_operation_policy_enforced_on_all_operations() {
+    use crate::{policy_operation_count, OperationKind, OperationPolicy, ServerKey};
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let mut client_key = ClientKey::generate(ConfigBuilder::default());
+    // A tag no other test uses, as the operation counts are global
+    client_key
+        .tag_mut()
+        .set_data(b"test_operation_policy_enforced_on_all_operations");
+    let policy = OperationPolicy::new()
+        .allow_only([OperationKind::Add])
+        .with_max_operations_per_tag(100);
+    assert!(!policy.permits_operations_without_kind());
+
+    // The policy is kept when the server key is serialized compressed and decompressed
+    let compressed_server_key =
+        CompressedServerKey::new(&client_key).with_operation_policy(policy.clone());
+    let serialized = bincode::serialize(&compressed_server_key).unwrap();
+    let compressed_server_key: CompressedServerKey = bincode::deserialize(&serialized).unwrap();
+    assert_eq!(compressed_server_key.operation_policy(), Some(&policy));
+    let server_key = compressed_server_key.decompress();
+    assert_eq!(server_key.operation_policy(), Some(&policy));
+    set_server_key(server_key.clone());
+
+    let a = FheUint16::encrypt(12u16, &client_key);
+    let b = FheUint16::encrypt(30u16, &client_key);
+    let tag = client_key.tag();
+
+    let (c, overflowed) = (&a).overflowing_add(&b);
+    let decrypted: u16 = c.decrypt(&client_key);
+    assert_eq!(decrypted, 42);
+    assert!(!overflowed.decrypt(&client_key));
+    assert_eq!(policy_operation_count(tag), 1);
+
+    // Summing is counted once
+    let sum: FheUint16 = [a.clone(), b.clone(), a.clone()].iter().sum();
+    let decrypted: u16 = sum.decrypt(&client_key);
+    assert_eq!(decrypted, 54);
+    assert_eq!(policy_operation_count(tag), 2);
+
+    let mut accumulator = crate::FheAccumulator::<FheUint16>::new();
+    accumulator.absorb(&a);
+    accumulator.absorb(&b);
+    let decrypted: u16 = accumulator.finish().decrypt(&client_key);
+    assert_eq!(decrypted, 42);
+    assert_eq!(policy_operation_count(tag), 4);
+
+    let dyn_a = DynFheUint::from(a.clone());
+    let dyn_b = DynFheUint::from(b.clone());
+    let decrypted: u16 = (&dyn_a + &dyn_b).decrypt(&client_key);
+    assert_eq!(decrypted, 42);
+    assert_eq!(policy_operation_count(tag), 5);
+
+    // Operations that do not compute on the values are not restricted, nor counted
+    let trivial = FheUint16::encrypt_trivial(3u16);
+    let decrypted: u16 = a.compress().decompress().decrypt(&client_key);
+    assert_eq!(decrypted, 12);
+    assert_eq!(policy_operation_count(tag), 5);
+
+    let condition = FheBool::encrypt(true, &client_key);
+    assert!(catch_unwind(AssertUnwindSafe(|| (&a).overflowing_sub(&b))).is_err());
+    assert!(catch_unwind(AssertUnwindSafe(|| a.checked_div(&b))).is_err());
+    assert!(catch_unwind(AssertUnwindSafe(|| a.clamp(&trivial, &b))).is_err());
+    assert!(catch_unwind(AssertUnwindSafe(|| a.leading_zeros())).is_err());
+    assert!(catch_unwind(AssertUnwindSafe(|| a.mod_mul(&b, 7))).is_err());
+    assert!(catch_unwind(AssertUnwindSafe(|| condition.if_then_else(&a, &b))).is_err());
+    let conditions = [condition.clone(), condition];
+    assert!(catch_unwind(AssertUnwindSafe(|| crate::algorithms::fhe_all(&conditions))).is_err());
+    assert!(catch_unwind(AssertUnwindSafe(|| &dyn_a * &dyn_b)).is_err());
+    assert!(catch_unwind(AssertUnwindSafe(|| FheUint32::cast_from(a.clone()))).is_err());
+    // Operations without a kind are refused
+    assert!(catch_unwind(AssertUnwindSafe(|| a.reverse_bits())).is_err());
+    assert_eq!(policy_operation_count(tag), 5);
+
+    // div_rem is a single operation
+    let policy = OperationPolicy::new()
+        .allow_only([OperationKind::DivRem])
+        .with_max_operations_per_tag(6);
+    set_server_key(server_key.clone().with_operation_policy(policy));
+    let (q, r) = (&b).div_rem(&a);
+    let decrypted_q: u16 = q.decrypt(&client_key);
+    let decrypted_r: u16 = r.decrypt(&client_key);
+    assert_eq!((decrypted_q, decrypted_r), (2, 6));
+    assert_eq!(policy_operation_count(tag), 6);
+    assert!(catch_unwind(AssertUnwindSafe(|| &b / &a)).is_err());
+
+    // A policy that does not restrict anything permits operations without a kind
+    set_server_key(server_key.with_operation_policy(OperationPolicy::new()));
+    let decrypted: u16 = a.reverse_bits().decrypt(&client_key);
+    assert_eq!(decrypted, 12u16.reverse_bits());
+}
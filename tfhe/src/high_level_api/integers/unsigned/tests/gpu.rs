@@ -145,3 +145,69 @@ fn test_ilog2_multibit() {
     let client_key = setup_gpu(Some(PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_3_KS_PBS));
     super::test_case_ilog2(&client_key);
 }
+
+#[test]
+fn test_swap_bytes_rotate_blocks_gpu() {
+    let client_key = setup_default_gpu();
+    super::test_case_swap_bytes_rotate_blocks(&client_key);
+}
+
+#[test]
+fn test_swap_bytes_rotate_blocks_gpu_multibit() {
+    let client_key = setup_gpu(Some(PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_3_KS_PBS));
+    super::test_case_swap_bytes_rotate_blocks(&client_key);
+}
+
+#[test]
+fn test_compact_list_expand_on_gpu() {
+    use crate::prelude::*;
+    use crate::shortint::parameters::compact_public_key_only::p_fail_2_minus_64::ks_pbs::PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    use crate::shortint::parameters::key_switching::p_fail_2_minus_64::ks_pbs::PARAM_KEYSWITCH_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    use crate::{
+        CompactCiphertextList, CompactPublicKey, CompressedServerKey, FheBool, FheInt64, FheUint32,
+    };
+
+    let default_config = ConfigBuilder::default().build();
+    let casting_config =
+        ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64)
+            .use_dedicated_compact_public_key_parameters((
+                PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+                PARAM_KEYSWITCH_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+            ))
+            .build();
+
+    for config in [default_config, casting_config] {
+        let client_key = ClientKey::generate(config);
+        let compressed_server_key = CompressedServerKey::new(&client_key);
+        set_server_key(compressed_server_key.decompress_to_gpu());
+
+        let public_key = CompactPublicKey::new(&client_key);
+
+        for packed in [false, true] {
+            let mut builder = CompactCiphertextList::builder(&public_key);
+            builder.push(17u32).push(-1i64).push(false).push(true);
+            let list = if packed {
+                builder.build_packed()
+            } else {
+                builder.build()
+            };
+
+            let expander = list.expand_on_gpu().unwrap();
+
+            let a: FheUint32 = expander.get(0).unwrap().unwrap();
+            let b: FheInt64 = expander.get(1).unwrap().unwrap();
+            let c: FheBool = expander.get(2).unwrap().unwrap();
+            let d: FheBool = expander.get(3).unwrap().unwrap();
+
+            let a: u32 = a.decrypt(&client_key);
+            assert_eq!(a, 17);
+            let b: i64 = b.decrypt(&client_key);
+            assert_eq!(b, -1);
+            let c = c.decrypt(&client_key);
+            assert!(!c);
+            let d = d.decrypt(&client_key);
+            assert!(d);
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use crate::high_level_api::traits::BitSlice;
 use crate::integer::U256;
 use crate::prelude::*;
-use crate::{ClientKey, FheUint256, FheUint32, FheUint64, FheUint8};
+use crate::{ClientKey, FheUint16, FheUint256, FheUint32, FheUint64, FheUint8};
 use rand::{thread_rng, Rng};
 
 mod cpu;
@@ -534,6 +534,58 @@ fn test_case_sum(client_key: &ClientKey) {
     }
 }
 
+fn test_case_div_rem_pow2_hint(cks: &ClientKey) {
+    let mut rng = rand::thread_rng();
+    for _ in 0..5 {
+        let clear_a = rng.gen::<u16>();
+        let clear_log2 = rng.gen_range(0..u16::BITS as u16);
+
+        let a = FheUint16::encrypt(clear_a, cks);
+        let log2 = FheUint16::encrypt(clear_log2, cks);
+
+        let (q, r) = a.div_rem_pow2_hint(&log2);
+        let q: u16 = q.decrypt(cks);
+        let r: u16 = r.decrypt(cks);
+        assert_eq!(q, clear_a / (1 << clear_log2));
+        assert_eq!(r, clear_a % (1 << clear_log2));
+    }
+}
+
+fn test_case_is_zero_is_nonzero(cks: &ClientKey) {
+    let mut rng = rand::thread_rng();
+    for clear_a in [0u32, rng.gen_range(1..=u32::MAX)] {
+        let a = FheUint32::try_encrypt(clear_a, cks).unwrap();
+        assert_eq!(
+            a.is_zero().decrypt(cks),
+            clear_a == 0,
+            "Invalid is_zero result for {clear_a}"
+        );
+        assert_eq!(
+            a.is_nonzero().decrypt(cks),
+            clear_a != 0,
+            "Invalid is_nonzero result for {clear_a}"
+        );
+    }
+
+    for clear_a in [
+        0i32,
+        rng.gen_range(i32::MIN..0),
+        rng.gen_range(1..=i32::MAX),
+    ] {
+        let a = crate::FheInt32::try_encrypt(clear_a, cks).unwrap();
+        assert_eq!(
+            a.is_zero().decrypt(cks),
+            clear_a == 0,
+            "Invalid is_zero result for {clear_a}"
+        );
+        assert_eq!(
+            a.is_nonzero().decrypt(cks),
+            clear_a != 0,
+            "Invalid is_nonzero result for {clear_a}"
+        );
+    }
+}
+
 fn test_case_is_even_is_odd(cks: &ClientKey) {
     let mut rng = rand::thread_rng();
     // This operation is cheap
@@ -567,3 +619,62 @@ fn test_case_is_even_is_odd(cks: &ClientKey) {
         );
     }
 }
+
+fn test_case_wrapping_checked(cks: &ClientKey) {
+    let mut rng = rand::thread_rng();
+    for _ in 0..3 {
+        let clear_a = rng.gen::<u32>();
+        let clear_b = rng.gen::<u32>();
+
+        let a = crate::Wrapping(FheUint32::encrypt(clear_a, cks));
+        let b = crate::Wrapping(FheUint32::encrypt(clear_b, cks));
+
+        let result: u32 = (&a + &b).0.decrypt(cks);
+        assert_eq!(result, clear_a.wrapping_add(clear_b));
+
+        let result: u32 = (&a - &b).0.decrypt(cks);
+        assert_eq!(result, clear_a.wrapping_sub(clear_b));
+
+        let mut c = a.clone();
+        c *= &b;
+        let result: u32 = c.0.decrypt(cks);
+        assert_eq!(result, clear_a.wrapping_mul(clear_b));
+
+        let a = crate::Checked(a.0);
+        let b = crate::Checked(b.0);
+
+        let (result, overflowed) = &a + &b;
+        let result: u32 = result.0.decrypt(cks);
+        let expected = clear_a.overflowing_add(clear_b);
+        assert_eq!(result, expected.0);
+        assert_eq!(overflowed.decrypt(cks), expected.1);
+
+        let (result, overflowed) = &a - &b;
+        let result: u32 = result.0.decrypt(cks);
+        let expected = clear_a.overflowing_sub(clear_b);
+        assert_eq!(result, expected.0);
+        assert_eq!(overflowed.decrypt(cks), expected.1);
+
+        let (result, overflowed) = a * &b;
+        let result: u32 = result.0.decrypt(cks);
+        let expected = clear_a.overflowing_mul(clear_b);
+        assert_eq!(result, expected.0);
+        assert_eq!(overflowed.decrypt(cks), expected.1);
+    }
+}
+
+fn test_case_swap_bytes_rotate_blocks(cks: &ClientKey) {
+    let mut rng = rand::thread_rng();
+    let bits_per_block = cks.key.block_parameters().message_modulus().0.ilog2();
+    for _ in 0..3 {
+        let clear_a = rng.gen::<u32>();
+        let a = FheUint32::encrypt(clear_a, cks);
+
+        let result: u32 = a.swap_bytes().decrypt(cks);
+        assert_eq!(result, clear_a.swap_bytes());
+
+        let n = rng.gen_range(0..32 / bits_per_block);
+        let result: u32 = a.rotate_blocks(n as usize).decrypt(cks);
+        assert_eq!(result, clear_a.rotate_left(n * bits_per_block));
+    }
+}
@@ -0,0 +1,180 @@
+//! Operations between [FheUint] of different widths
+//!
+//! These operations are only allowed when the server key was generated from a config
+//! with [enable_implicit_widening](crate::ConfigBuilder::enable_implicit_widening).
+//! The narrower operand is then cast to the type of the wider one.
+use super::static_::{
+    FheUint128Id, FheUint16Id, FheUint256Id, FheUint32Id, FheUint64Id, FheUint8Id,
+};
+use crate::high_level_api::errors::UnwrapResultExt;
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::{FheUintId, IntegerId};
+use crate::high_level_api::traits::{FheEq, FheOrd};
+use crate::prelude::CastFrom;
+use crate::{FheBool, FheUint};
+use std::ops::{Add, BitAnd, BitOr, BitXor, Mul, Sub};
+
+/// Casts `input` to the wider type, if the current server key allows implicit widening
+///
+/// # Panics
+///
+/// - if no server key is set
+/// - if the server key does not allow implicit widening
+#[track_caller]
+fn widen<FromId, IntoId>(input: &FheUint<FromId>) -> FheUint<IntoId>
+where
+    FromId: FheUintId,
+    IntoId: FheUintId,
+{
+    let is_enabled = global_state::implicit_widening_of_internal_server_key().unwrap_display();
+    assert!(
+        is_enabled,
+        "Operations between FheUint{} and FheUint{} require the server key to be generated \
+        from a config with implicit widening enabled (see ConfigBuilder::enable_implicit_widening)",
+        FromId::num_bits(),
+        IntoId::num_bits(),
+    );
+    FheUint::cast_from(input.clone())
+}
+
+// Implements `$rust_trait` for all the combinations of owned and borrowed operands,
+// from the implementation for two borrowed operands
+macro_rules! impl_widening_operation_forms (
+    (
+        $rust_trait_name:ident($rust_trait_method:ident),
+        $lhs_id:ty, $rhs_id:ty => $output_id:ty
+    ) => {
+        impl $rust_trait_name<FheUint<$rhs_id>> for FheUint<$lhs_id> {
+            type Output = FheUint<$output_id>;
+
+            fn $rust_trait_method(self, rhs: FheUint<$rhs_id>) -> Self::Output {
+                <&Self as $rust_trait_name<&FheUint<$rhs_id>>>::$rust_trait_method(&self, &rhs)
+            }
+        }
+
+        impl $rust_trait_name<&FheUint<$rhs_id>> for FheUint<$lhs_id> {
+            type Output = FheUint<$output_id>;
+
+            fn $rust_trait_method(self, rhs: &FheUint<$rhs_id>) -> Self::Output {
+                <&Self as $rust_trait_name<&FheUint<$rhs_id>>>::$rust_trait_method(&self, rhs)
+            }
+        }
+
+        impl $rust_trait_name<FheUint<$rhs_id>> for &FheUint<$lhs_id> {
+            type Output = FheUint<$output_id>;
+
+            fn $rust_trait_method(self, rhs: FheUint<$rhs_id>) -> Self::Output {
+                <Self as $rust_trait_name<&FheUint<$rhs_id>>>::$rust_trait_method(self, &rhs)
+            }
+        }
+    }
+);
+
+macro_rules! impl_widening_operation (
+    (
+        $rust_trait_name:ident($rust_trait_method:ident),
+        $narrow_id:ty, $wide_id:ty
+    ) => {
+        impl $rust_trait_name<&FheUint<$wide_id>> for &FheUint<$narrow_id> {
+            type Output = FheUint<$wide_id>;
+
+            #[track_caller]
+            fn $rust_trait_method(self, rhs: &FheUint<$wide_id>) -> Self::Output {
+                let lhs = widen::<$narrow_id, $wide_id>(self);
+                <&FheUint<$wide_id> as $rust_trait_name<&FheUint<$wide_id>>>::$rust_trait_method(
+                    &lhs, rhs,
+                )
+            }
+        }
+
+        impl $rust_trait_name<&FheUint<$narrow_id>> for &FheUint<$wide_id> {
+            type Output = FheUint<$wide_id>;
+
+            #[track_caller]
+            fn $rust_trait_method(self, rhs: &FheUint<$narrow_id>) -> Self::Output {
+                let rhs = widen::<$narrow_id, $wide_id>(rhs);
+                <&FheUint<$wide_id> as $rust_trait_name<&FheUint<$wide_id>>>::$rust_trait_method(
+                    self, &rhs,
+                )
+            }
+        }
+
+        impl_widening_operation_forms!(
+            $rust_trait_name($rust_trait_method), $narrow_id, $wide_id => $wide_id
+        );
+        impl_widening_operation_forms!(
+            $rust_trait_name($rust_trait_method), $wide_id, $narrow_id => $wide_id
+        );
+    }
+);
+
+// Implements a comparison trait in both directions, for owned and borrowed right hand sides
+macro_rules! impl_widening_comparison (
+    (
+        $rust_trait_name:ident { $($rust_trait_method:ident),* },
+        $narrow_id:ty, $wide_id:ty
+    ) => {
+        impl $rust_trait_name<&FheUint<$wide_id>> for FheUint<$narrow_id> {
+            $(
+                #[track_caller]
+                fn $rust_trait_method(&self, rhs: &FheUint<$wide_id>) -> FheBool {
+                    let lhs = widen::<$narrow_id, $wide_id>(self);
+                    <FheUint<$wide_id> as $rust_trait_name<&FheUint<$wide_id>>>::$rust_trait_method(
+                        &lhs, rhs,
+                    )
+                }
+            )*
+        }
+
+        impl $rust_trait_name<FheUint<$wide_id>> for FheUint<$narrow_id> {
+            $(
+                #[track_caller]
+                fn $rust_trait_method(&self, rhs: FheUint<$wide_id>) -> FheBool {
+                    <Self as $rust_trait_name<&FheUint<$wide_id>>>::$rust_trait_method(self, &rhs)
+                }
+            )*
+        }
+
+        impl $rust_trait_name<&FheUint<$narrow_id>> for FheUint<$wide_id> {
+            $(
+                #[track_caller]
+                fn $rust_trait_method(&self, rhs: &FheUint<$narrow_id>) -> FheBool {
+                    let rhs = widen::<$narrow_id, $wide_id>(rhs);
+                    <Self as $rust_trait_name<&Self>>::$rust_trait_method(self, &rhs)
+                }
+            )*
+        }
+
+        impl $rust_trait_name<FheUint<$narrow_id>> for FheUint<$wide_id> {
+            $(
+                #[track_caller]
+                fn $rust_trait_method(&self, rhs: FheUint<$narrow_id>) -> FheBool {
+                    <Self as $rust_trait_name<&FheUint<$narrow_id>>>::$rust_trait_method(self, &rhs)
+                }
+            )*
+        }
+    }
+);
+
+macro_rules! impl_implicit_widening (
+    ($narrow_id:ty => $($wide_id:ty),+ $(,)?) => {
+        $(
+            impl_widening_operation!(Add(add), $narrow_id, $wide_id);
+            impl_widening_operation!(Sub(sub), $narrow_id, $wide_id);
+            impl_widening_operation!(Mul(mul), $narrow_id, $wide_id);
+            impl_widening_operation!(BitAnd(bitand), $narrow_id, $wide_id);
+            impl_widening_operation!(BitOr(bitor), $narrow_id, $wide_id);
+            impl_widening_operation!(BitXor(bitxor), $narrow_id, $wide_id);
+            impl_widening_comparison!(FheEq { eq, ne }, $narrow_id, $wide_id);
+            impl_widening_comparison!(FheOrd { lt, le, gt, ge }, $narrow_id, $wide_id);
+        )+
+    }
+);
+
+impl_implicit_widening!(
+    FheUint8Id => FheUint16Id, FheUint32Id, FheUint64Id, FheUint128Id, FheUint256Id
+);
+impl_implicit_widening!(FheUint16Id => FheUint32Id, FheUint64Id, FheUint128Id, FheUint256Id);
+impl_implicit_widening!(FheUint32Id => FheUint64Id, FheUint128Id, FheUint256Id);
+impl_implicit_widening!(FheUint64Id => FheUint128Id, FheUint256Id);
+impl_implicit_widening!(FheUint128Id => FheUint256Id);
@@ -2,7 +2,7 @@
 //!
 //! - [ClientKey] aggregates the keys used to encrypt/decrypt between normal and homomorphic types.
 
-use super::{CompressedServerKey, ServerKey};
+use super::{CompressedServerKey, DecryptionKey, ServerKey};
 use crate::high_level_api::backward_compatibility::keys::ClientKeyVersions;
 use crate::high_level_api::config::Config;
 use crate::high_level_api::keys::{CompactPrivateKey, IntegerClientKey};
@@ -13,6 +13,7 @@ use crate::shortint::MessageModulus;
 use crate::Tag;
 use concrete_csprng::seeders::Seed;
 use tfhe_versionable::Versionize;
+use zeroize::Zeroize;
 
 /// Key of the client
 ///
@@ -25,6 +26,7 @@ use tfhe_versionable::Versionize;
 pub struct ClientKey {
     pub(crate) key: IntegerClientKey,
     pub(crate) tag: Tag,
+    pub(crate) implicit_widening: bool,
 }
 
 impl ClientKey {
@@ -34,6 +36,7 @@ impl ClientKey {
         Self {
             key: IntegerClientKey::from(config.inner),
             tag: Tag::default(),
+            implicit_widening: config.implicit_widening,
         }
     }
 
@@ -67,6 +70,7 @@ impl ClientKey {
         Self {
             key: IntegerClientKey::with_seed(config.inner, seed),
             tag: Tag::default(),
+            implicit_widening: config.implicit_widening,
         }
     }
 
@@ -98,6 +102,7 @@ impl ClientKey {
                 compression_key,
             ),
             tag,
+            implicit_widening: false,
         }
     }
 
@@ -114,11 +119,40 @@ impl ClientKey {
         CompressedServerKey::new(self)
     }
 
+    /// Returns a [DecryptionKey], which can decrypt the data encrypted with this key, but
+    /// cannot encrypt new data.
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, ConfigBuilder, FheUint8};
+    ///
+    /// let (client_key, _server_key) = generate_keys(ConfigBuilder::default());
+    ///
+    /// let a = FheUint8::encrypt(42u8, &client_key);
+    ///
+    /// let decryption_key = client_key.decryption_key();
+    /// let decrypted: u8 = a.decrypt_with(&decryption_key);
+    /// assert_eq!(decrypted, 42);
+    /// ```
+    pub fn decryption_key(&self) -> DecryptionKey {
+        DecryptionKey::new(self)
+    }
+
     pub(crate) fn message_modulus(&self) -> MessageModulus {
         self.key.block_parameters().message_modulus()
     }
 }
 
+/// Overwrites all the secret keys with zeros, so that they do not remain in memory.
+///
+/// The key cannot be used anymore once zeroized. Wrap it in [`zeroize::Zeroizing`] to have it
+/// zeroized when dropped.
+impl Zeroize for ClientKey {
+    fn zeroize(&mut self) {
+        self.key.zeroize();
+    }
+}
+
 impl Tagged for ClientKey {
     fn tag(&self) -> &Tag {
         &self.tag
@@ -137,4 +171,6 @@ impl AsRef<crate::integer::ClientKey> for ClientKey {
 
 impl Named for ClientKey {
     const NAME: &'static str = "high_level_api::ClientKey";
+    // The implicit widening flag was added in version 2
+    const MIN_READER_VERSION: Option<&'static str> = Some("0.8");
 }
@@ -0,0 +1,58 @@
+//! This module defines DecryptionKey
+//!
+//! - [DecryptionKey] is the subset of a [ClientKey] that is needed to decrypt data.
+
+use crate::high_level_api::backward_compatibility::keys::DecryptionKeyVersions;
+use crate::named::Named;
+use crate::prelude::Tagged;
+use crate::{ClientKey, Tag};
+use tfhe_versionable::Versionize;
+use zeroize::Zeroize;
+
+/// Key that can only decrypt data
+///
+/// It is derived from a [ClientKey] using [ClientKey::decryption_key], and is meant for devices
+/// that only read results: contrary to the [ClientKey], this key does not offer any way to
+/// encrypt values or to generate other keys. The keys needed for the compression of
+/// ciphertexts and for the compact public key encryption are not included.
+///
+/// Decryption is done with [FheDecryptWith](crate::prelude::FheDecryptWith).
+///
+/// Note that this only restricts the API: the secret key used for decryption is still part of
+/// this key, so it **MUST NOT** be sent to the server either.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(DecryptionKeyVersions)]
+pub struct DecryptionKey {
+    pub(crate) key: crate::integer::ClientKey,
+    pub(crate) tag: Tag,
+}
+
+impl DecryptionKey {
+    pub(crate) fn new(client_key: &ClientKey) -> Self {
+        Self {
+            key: client_key.key.key.clone(),
+            tag: client_key.tag.clone(),
+        }
+    }
+}
+
+/// Overwrites the secret key with zeros, so that it does not remain in memory.
+impl Zeroize for DecryptionKey {
+    fn zeroize(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+impl Tagged for DecryptionKey {
+    fn tag(&self) -> &Tag {
+        &self.tag
+    }
+
+    fn tag_mut(&mut self) -> &mut Tag {
+        &mut self.tag
+    }
+}
+
+impl Named for DecryptionKey {
+    const NAME: &'static str = "high_level_api::DecryptionKey";
+}
@@ -0,0 +1,160 @@
+//! This module defines EncryptionKey
+//!
+//! - [EncryptionKey] bundles what a device needs to encrypt data, without being able to decrypt it.
+
+use super::{ClientKey, CompactPublicKey};
+use crate::conformance::ParameterSetConformant;
+use crate::high_level_api::backward_compatibility::keys::EncryptionKeyVersions;
+use crate::high_level_api::config::Config;
+use crate::high_level_api::CompactCiphertextListBuilder;
+use crate::named::Named;
+use crate::prelude::Tagged;
+use crate::shortint::parameters::CompactPublicKeyEncryptionParameters;
+#[cfg(feature = "zk-pok")]
+use crate::zk::{CompactPkePublicParams, Compressible, SerializableCompactPkePublicParams};
+use crate::{CompactCiphertextList, Tag};
+use tfhe_versionable::Versionize;
+
+/// Key that can only encrypt data
+///
+/// A secret key cannot encrypt without also being able to decrypt, so this key is made of public
+/// material only:
+///
+/// - the [CompactPublicKey] used to encrypt,
+/// - the [Config] the keys were generated with,
+/// - optionally, the public parameters of the zero knowledge proofs, to prove the encrypted lists.
+///
+/// It is meant to provision devices that only ingest data, with a single serialized object.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, EncryptionKey, FheUint8};
+///
+/// let config = ConfigBuilder::default().build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let encryption_key = EncryptionKey::new(config, &client_key);
+///
+/// let list = encryption_key.builder().push(42u8).build();
+///
+/// let expander = list.expand().unwrap();
+/// let a: FheUint8 = expander.get(0).unwrap().unwrap();
+///
+/// let decrypted: u8 = a.decrypt(&client_key);
+/// assert_eq!(decrypted, 42);
+/// ```
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(EncryptionKeyVersions)]
+pub struct EncryptionKey {
+    public_key: CompactPublicKey,
+    config: Config,
+    /// The public params are stored safe serialized and compressed, so that this type has the
+    /// same serialized form whether the `zk-pok` feature is enabled or not.
+    public_params: Option<Vec<u8>>,
+}
+
+impl EncryptionKey {
+    /// Creates an encryption key for a client key.
+    ///
+    /// The config must be the one that was used to generate the client key.
+    pub fn new<C: Into<Config>>(config: C, client_key: &ClientKey) -> Self {
+        Self::from_public_key(config, CompactPublicKey::new(client_key))
+    }
+
+    /// Creates an encryption key from an existing public key.
+    ///
+    /// The config must be the one that was used to generate the keys.
+    pub fn from_public_key<C: Into<Config>>(config: C, public_key: CompactPublicKey) -> Self {
+        Self {
+            public_key,
+            config: config.into(),
+            public_params: None,
+        }
+    }
+
+    /// Adds the public parameters of the zero knowledge proofs to the key
+    #[cfg(feature = "zk-pok")]
+    pub fn with_public_params(self, public_params: &CompactPkePublicParams) -> crate::Result<Self> {
+        let mut serialized = Vec::new();
+        crate::safe_serialization::SerializationConfig::new_with_unlimited_size()
+            .serialize_into(&public_params.compress(), &mut serialized)
+            .map_err(|err| crate::Error::new(err.to_string()))?;
+
+        Ok(Self {
+            public_params: Some(serialized),
+            ..self
+        })
+    }
+
+    /// Returns the public parameters of the zero knowledge proofs, if the key has some.
+    ///
+    /// The parameters are deserialized on each call.
+    #[cfg(feature = "zk-pok")]
+    pub fn public_params(&self) -> Option<crate::Result<CompactPkePublicParams>> {
+        self.public_params.as_ref().map(|serialized| {
+            // The data was already bounded by the deserialization of the key
+            let compressed: SerializableCompactPkePublicParams =
+                crate::safe_serialization::safe_deserialize(
+                    serialized.as_slice(),
+                    serialized.len() as u64,
+                )?;
+
+            CompactPkePublicParams::uncompress(compressed)
+                .map_err(|err| crate::Error::new(err.to_string()))
+        })
+    }
+
+    /// Returns whether the key holds the public parameters of the zero knowledge proofs
+    pub fn has_public_params(&self) -> bool {
+        self.public_params.is_some()
+    }
+
+    /// Returns the public key used to encrypt
+    pub fn public_key(&self) -> &CompactPublicKey {
+        &self.public_key
+    }
+
+    /// Returns the config the keys were generated with
+    pub fn config(&self) -> Config {
+        self.config
+    }
+
+    /// Returns a builder to encrypt values in a [CompactCiphertextList]
+    pub fn builder(&self) -> CompactCiphertextListBuilder {
+        CompactCiphertextList::builder(&self.public_key)
+    }
+}
+
+impl Tagged for EncryptionKey {
+    fn tag(&self) -> &Tag {
+        self.public_key.tag()
+    }
+
+    fn tag_mut(&mut self) -> &mut Tag {
+        self.public_key.tag_mut()
+    }
+}
+
+impl Named for EncryptionKey {
+    const NAME: &'static str = "high_level_api::EncryptionKey";
+}
+
+impl ParameterSetConformant for EncryptionKey {
+    type ParameterSet = CompactPublicKeyEncryptionParameters;
+
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        let Self {
+            public_key,
+            config,
+            public_params: _,
+        } = self;
+
+        config
+            .public_key_encryption_parameters()
+            .is_ok_and(|params| params == *parameter_set)
+            && public_key.is_conformant(parameter_set)
+    }
+}
@@ -8,16 +8,19 @@ use crate::integer::compression_keys::{
 };
 use crate::integer::public_key::CompactPublicKey;
 use crate::integer::CompressedCompactPublicKey;
+use crate::safe_serialization::{SerializedSizeBound, OBJECT_METADATA_SIZE_BOUND};
 use crate::shortint::key_switching_key::KeySwitchingKeyConformanceParams;
 use crate::shortint::parameters::list_compression::CompressionParameters;
 use crate::shortint::parameters::{
     CompactPublicKeyEncryptionParameters, ShortintKeySwitchingParameters,
 };
+use crate::shortint::server_key::fourier_bootstrap_key_size_bound;
 use crate::shortint::{EncryptionKeyChoice, MessageModulus, PBSParameters};
 use crate::Error;
 use concrete_csprng::seeders::Seed;
 use serde::{Deserialize, Serialize};
 use tfhe_versionable::Versionize;
+use zeroize::Zeroize;
 
 // Clippy complained that fields end in _parameters, :roll_eyes:
 #[derive(Copy, Clone, Debug, serde::Serialize, serde::Deserialize, Versionize)]
@@ -98,6 +101,18 @@ pub(crate) struct IntegerClientKey {
     pub(crate) compression_key: Option<CompressionPrivateKeys>,
 }
 
+impl Zeroize for IntegerClientKey {
+    fn zeroize(&mut self) {
+        self.key.zeroize();
+        if let Some((compact_private_key, _)) = &mut self.dedicated_compact_private_key {
+            compact_private_key.zeroize();
+        }
+        if let Some(compression_key) = &mut self.compression_key {
+            compression_key.zeroize();
+        }
+    }
+}
+
 impl IntegerClientKey {
     pub(crate) fn with_seed(config: IntegerConfig, seed: Seed) -> Self {
         assert!(
@@ -283,6 +298,8 @@ impl IntegerServerKey {
 #[cfg(feature = "gpu")]
 pub struct IntegerCudaServerKey {
     pub(crate) key: crate::integer::gpu::CudaServerKey,
+    pub(crate) cpk_key_switching_key_material:
+        Option<crate::integer::gpu::key_switching_key::CudaKeySwitchingKeyMaterial>,
     pub(crate) compression_key:
         Option<crate::integer::gpu::list_compression::server_keys::CudaCompressionKey>,
     pub(crate) decompression_key:
@@ -463,6 +480,7 @@ impl IntegerCompressedCompactPublicKey {
 }
 
 #[allow(clippy::struct_field_names)]
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
 pub struct IntegerServerKeyConformanceParams {
     pub sk_param: PBSParameters,
     pub cpk_param: Option<(
@@ -472,6 +490,9 @@ pub struct IntegerServerKeyConformanceParams {
     pub compression_param: Option<CompressionParameters>,
 }
 
+#[cfg(feature = "json")]
+crate::json::impl_to_json_pretty_with_serialize!(IntegerServerKeyConformanceParams);
+
 impl
     TryFrom<(
         PBSParameters,
@@ -563,6 +584,65 @@ impl ParameterSetConformant for IntegerServerKey {
     }
 }
 
+impl SerializedSizeBound for IntegerServerKey {
+    fn serialized_size_bound(parameter_set: &Self::ParameterSet) -> u64 {
+        let sk_param = parameter_set.sk_param;
+        let big_lwe_dimension = sk_param
+            .glwe_dimension()
+            .to_equivalent_lwe_dimension(sk_param.polynomial_size());
+
+        let key_size = crate::integer::ServerKey::serialized_size_bound(&sk_param);
+
+        let cpk_key_switching_key_size =
+            parameter_set
+                .cpk_param
+                .as_ref()
+                .map_or(0, |(cpk_params, ks_params)| {
+                    let output_lwe_size = match ks_params.destination_key {
+                        EncryptionKeyChoice::Big => big_lwe_dimension,
+                        EncryptionKeyChoice::Small => sk_param.lwe_dimension(),
+                    }
+                    .to_lwe_size();
+
+                    8 * cpk_params.encryption_lwe_dimension.0 as u64
+                        * ks_params.ks_level.0 as u64
+                        * output_lwe_size.0 as u64
+                });
+
+        let compression_keys_size =
+            parameter_set
+                .compression_param
+                .as_ref()
+                .map_or(0, |compression_param| {
+                    // The compression key is a packing keyswitch key from the big key
+                    let packing_glwe_size =
+                        compression_param.packing_ks_glwe_dimension.to_glwe_size();
+                    let compression_key_size = 8
+                        * big_lwe_dimension.0 as u64
+                        * compression_param.packing_ks_level.0 as u64
+                        * packing_glwe_size.0 as u64
+                        * compression_param.packing_ks_polynomial_size.0 as u64;
+
+                    // The decompression key is a bootstrapping key from the packing key
+                    let decompression_key_size = fourier_bootstrap_key_size_bound(
+                        compression_param
+                            .packing_ks_glwe_dimension
+                            .to_equivalent_lwe_dimension(
+                                compression_param.packing_ks_polynomial_size,
+                            ),
+                        None,
+                        compression_param.br_level,
+                        sk_param.glwe_dimension().to_glwe_size(),
+                        sk_param.polynomial_size(),
+                    );
+
+                    compression_key_size + decompression_key_size
+                });
+
+        key_size + cpk_key_switching_key_size + compression_keys_size + OBJECT_METADATA_SIZE_BOUND
+    }
+}
+
 impl ParameterSetConformant for IntegerCompressedServerKey {
     type ParameterSet = IntegerServerKeyConformanceParams;
 
@@ -1,4 +1,6 @@
 mod client;
+mod decryption_key;
+mod encryption_key;
 mod public;
 mod server;
 
@@ -7,6 +9,8 @@ mod key_switching_key;
 
 use crate::high_level_api::config::Config;
 pub use client::ClientKey;
+pub use decryption_key::DecryptionKey;
+pub use encryption_key::EncryptionKey;
 pub(crate) use inner::CompactPrivateKey;
 pub use key_switching_key::KeySwitchingKey;
 pub use public::{CompactPublicKey, CompressedCompactPublicKey, CompressedPublicKey, PublicKey};
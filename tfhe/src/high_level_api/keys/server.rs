@@ -8,14 +8,16 @@ use crate::core_crypto::gpu::{synchronize_devices, CudaStreams};
 #[cfg(feature = "gpu")]
 use crate::high_level_api::keys::inner::IntegerCudaServerKey;
 use crate::high_level_api::keys::{IntegerCompressedServerKey, IntegerServerKey};
+use crate::high_level_api::policy::OperationPolicy;
 use crate::integer::compression_keys::{
     CompressedCompressionKey, CompressedDecompressionKey, CompressionKey, DecompressionKey,
 };
 use crate::integer::parameters::IntegerCompactCiphertextListExpansionMode;
 use crate::named::Named;
 use crate::prelude::Tagged;
+use crate::safe_serialization::{SerializedSizeBound, OBJECT_METADATA_SIZE_BOUND};
 use crate::shortint::MessageModulus;
-use crate::Tag;
+use crate::{Config, Tag};
 use std::sync::Arc;
 
 /// Key of the server
@@ -34,6 +36,8 @@ use std::sync::Arc;
 pub struct ServerKey {
     pub(crate) key: Arc<IntegerServerKey>,
     pub(crate) tag: Tag,
+    pub(crate) implicit_widening: bool,
+    pub(crate) policy: Option<Arc<OperationPolicy>>,
 }
 
 impl ServerKey {
@@ -41,9 +45,73 @@ impl ServerKey {
         Self {
             key: Arc::new(IntegerServerKey::new(&keys.key)),
             tag: keys.tag.clone(),
+            implicit_widening: keys.implicit_widening,
+            policy: None,
         }
     }
 
+    /// Creates a server key to dry run computations for the given config
+    ///
+    /// No key is generated, which makes this function fast. The key has the dimensions given
+    /// by the config but its content is zero, thus it can only compute on trivial ciphertexts
+    /// (see [FheTrivialEncrypt](crate::prelude::FheTrivialEncrypt)), for which the PBS are
+    /// evaluated in the clear.
+    ///
+    /// This allows to run an application pipeline on trivial placeholders, to check the types,
+    /// the casts and the operations it uses (an unsupported operation panics the same way as
+    /// with a real key), in a fraction of the time the real computation would take.
+    ///
+    /// The key does not support compression nor the expansion of lists encrypted with a
+    /// dedicated compact public key.
+    ///
+    /// The key holds no key material: computing a PBS on a non-trivial ciphertext with it
+    /// panics, and it can neither be serialized nor pass a conformance check (see
+    /// [Self::is_dry_run]).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{set_server_key, ConfigBuilder, FheUint16, FheUint8, ServerKey};
+    ///
+    /// let config = ConfigBuilder::default().build();
+    /// set_server_key(ServerKey::new_dry_run(config));
+    ///
+    /// // Placeholders for the encrypted inputs the pipeline will receive
+    /// let a = FheUint16::encrypt_trivial(0u16);
+    /// let b = FheUint8::encrypt_trivial(0u8);
+    ///
+    /// let b = FheUint16::cast_from(b);
+    /// let c = (&a * &b).max(&a);
+    ///
+    /// // The values are computed in the clear, without any PBS
+    /// let c: u16 = c.try_decrypt_trivial().unwrap();
+    /// assert_eq!(c, 0);
+    /// ```
+    pub fn new_dry_run<C: Into<Config>>(config: C) -> Self {
+        let config = config.into();
+        let key =
+            crate::integer::ServerKey::new_dry_run_radix_server_key(config.inner.block_parameters);
+
+        Self {
+            key: Arc::new(IntegerServerKey {
+                key,
+                cpk_key_switching_key_material: None,
+                compression_key: None,
+                decompression_key: None,
+            }),
+            tag: Tag::default(),
+            implicit_widening: config.implicit_widening,
+            policy: None,
+        }
+    }
+
+    /// Returns `true` if this key was created with [Self::new_dry_run], in which case it can only
+    /// compute on trivial ciphertexts
+    pub fn is_dry_run(&self) -> bool {
+        self.key.key.key.is_dry_run()
+    }
+
     pub fn into_raw_parts(
         self,
     ) -> (
@@ -86,9 +154,33 @@ impl ServerKey {
                 decompression_key,
             }),
             tag,
+            implicit_widening: false,
+            policy: None,
         }
     }
 
+    /// Attaches a policy restricting the operations that can be performed with this key
+    ///
+    /// This replaces the policy previously attached, if any. The policy is kept when the key is
+    /// serialized.
+    ///
+    /// See [OperationPolicy] for an example.
+    pub fn with_operation_policy(mut self, policy: OperationPolicy) -> Self {
+        self.policy = Some(Arc::new(policy));
+        self
+    }
+
+    /// Removes the policy attached to this key, if any
+    pub fn without_operation_policy(mut self) -> Self {
+        self.policy = None;
+        self
+    }
+
+    /// Returns the policy attached to this key, if any
+    pub fn operation_policy(&self) -> Option<&OperationPolicy> {
+        self.policy.as_deref()
+    }
+
     pub(in crate::high_level_api) fn pbs_key(&self) -> &crate::integer::ServerKey {
         self.key.pbs_key()
     }
@@ -129,6 +221,8 @@ impl Tagged for ServerKey {
 
 impl Named for ServerKey {
     const NAME: &'static str = "high_level_api::ServerKey";
+    // The implicit widening flag and the operation policy were added in versions 3 and 4
+    const MIN_READER_VERSION: Option<&'static str> = Some("0.8");
 }
 
 impl AsRef<crate::integer::ServerKey> for ServerKey {
@@ -156,6 +250,8 @@ impl AsRef<crate::integer::ServerKey> for ServerKey {
 struct SerializableServerKey<'a> {
     pub(crate) integer_key: &'a IntegerServerKey,
     pub(crate) tag: &'a Tag,
+    pub(crate) implicit_widening: bool,
+    pub(crate) policy: Option<&'a OperationPolicy>,
 }
 
 impl serde::Serialize for ServerKey {
@@ -166,6 +262,8 @@ impl serde::Serialize for ServerKey {
         SerializableServerKey {
             integer_key: &self.key,
             tag: &self.tag,
+            implicit_widening: self.implicit_widening,
+            policy: self.policy.as_deref(),
         }
         .serialize(serializer)
     }
@@ -175,6 +273,8 @@ impl serde::Serialize for ServerKey {
 struct DeserializableServerKey {
     pub(crate) integer_key: IntegerServerKey,
     pub(crate) tag: Tag,
+    pub(crate) implicit_widening: bool,
+    pub(crate) policy: Option<OperationPolicy>,
 }
 
 impl<'de> serde::Deserialize<'de> for ServerKey {
@@ -185,6 +285,8 @@ impl<'de> serde::Deserialize<'de> for ServerKey {
         DeserializableServerKey::deserialize(deserializer).map(|deserialized| Self {
             key: Arc::new(deserialized.integer_key),
             tag: deserialized.tag,
+            implicit_widening: deserialized.implicit_widening,
+            policy: deserialized.policy.map(Arc::new),
         })
     }
 }
@@ -202,6 +304,8 @@ impl<'de> serde::Deserialize<'de> for ServerKey {
 pub struct CompressedServerKey {
     pub(crate) integer_key: IntegerCompressedServerKey,
     pub(crate) tag: Tag,
+    pub(crate) implicit_widening: bool,
+    pub(crate) policy: Option<OperationPolicy>,
 }
 
 impl CompressedServerKey {
@@ -209,9 +313,31 @@ impl CompressedServerKey {
         Self {
             integer_key: IntegerCompressedServerKey::new(&keys.key),
             tag: keys.tag.clone(),
+            implicit_widening: keys.implicit_widening,
+            policy: None,
         }
     }
 
+    /// Attaches a policy restricting the operations that can be performed with this key
+    ///
+    /// The policy is kept when the key is serialized, and is carried by the keys obtained by
+    /// decompressing it, on the CPU as well as on the GPU.
+    pub fn with_operation_policy(mut self, policy: OperationPolicy) -> Self {
+        self.policy = Some(policy);
+        self
+    }
+
+    /// Removes the policy attached to this key, if any
+    pub fn without_operation_policy(mut self) -> Self {
+        self.policy = None;
+        self
+    }
+
+    /// Returns the policy attached to this key, if any
+    pub fn operation_policy(&self) -> Option<&OperationPolicy> {
+        self.policy.as_ref()
+    }
+
     pub fn into_raw_parts(
         self,
     ) -> (
@@ -242,6 +368,8 @@ impl CompressedServerKey {
                 decompression_key,
             ),
             tag,
+            implicit_widening: false,
+            policy: None,
         }
     }
 
@@ -249,6 +377,8 @@ impl CompressedServerKey {
         ServerKey {
             key: Arc::new(self.integer_key.decompress()),
             tag: self.tag.clone(),
+            implicit_widening: self.implicit_widening,
+            policy: self.policy.clone().map(Arc::new),
         }
     }
 
@@ -259,6 +389,18 @@ impl CompressedServerKey {
             &self.integer_key.key,
             &streams,
         );
+        let cpk_key_switching_key_material: Option<
+            crate::integer::gpu::key_switching_key::CudaKeySwitchingKeyMaterial,
+        > = self
+            .integer_key
+            .cpk_key_switching_key_material
+            .as_ref()
+            .map(|material| {
+                crate::integer::gpu::key_switching_key::CudaKeySwitchingKeyMaterial::from_key_switching_key_material(
+                    &material.decompress(),
+                    &streams,
+                )
+            });
         let compression_key: Option<
             crate::integer::gpu::list_compression::server_keys::CudaCompressionKey,
         > = self
@@ -295,10 +437,13 @@ impl CompressedServerKey {
         CudaServerKey {
             key: Arc::new(IntegerCudaServerKey {
                 key,
+                cpk_key_switching_key_material,
                 compression_key,
                 decompression_key,
             }),
             tag: self.tag.clone(),
+            implicit_widening: self.implicit_widening,
+            policy: self.policy.clone().map(Arc::new),
         }
     }
 }
@@ -315,6 +460,8 @@ impl Tagged for CompressedServerKey {
 
 impl Named for CompressedServerKey {
     const NAME: &'static str = "high_level_api::CompressedServerKey";
+    // The implicit widening flag and the operation policy were added in versions 2 and 3
+    const MIN_READER_VERSION: Option<&'static str> = Some("0.8");
 }
 
 #[cfg(feature = "gpu")]
@@ -322,10 +469,19 @@ impl Named for CompressedServerKey {
 pub struct CudaServerKey {
     pub(crate) key: Arc<IntegerCudaServerKey>,
     pub(crate) tag: Tag,
+    pub(crate) implicit_widening: bool,
+    pub(crate) policy: Option<Arc<OperationPolicy>>,
 }
 
 #[cfg(feature = "gpu")]
 impl CudaServerKey {
+    /// Returns the policy attached to this key, if any
+    ///
+    /// The policy is the one of the [CompressedServerKey] this key was decompressed from.
+    pub fn operation_policy(&self) -> Option<&OperationPolicy> {
+        self.policy.as_deref()
+    }
+
     pub(crate) fn message_modulus(&self) -> crate::shortint::MessageModulus {
         self.key.key.message_modulus
     }
@@ -342,12 +498,23 @@ impl Tagged for CudaServerKey {
     }
 }
 
+#[derive(Clone)]
 pub enum InternalServerKey {
     Cpu(ServerKey),
     #[cfg(feature = "gpu")]
     Cuda(CudaServerKey),
 }
 
+impl InternalServerKey {
+    pub(in crate::high_level_api) fn operation_policy(&self) -> Option<&Arc<OperationPolicy>> {
+        match self {
+            Self::Cpu(cpu_key) => cpu_key.policy.as_ref(),
+            #[cfg(feature = "gpu")]
+            Self::Cuda(cuda_key) => cuda_key.policy.as_ref(),
+        }
+    }
+}
+
 impl From<ServerKey> for InternalServerKey {
     fn from(value: ServerKey) -> Self {
         Self::Cpu(value)
@@ -366,12 +533,23 @@ impl ParameterSetConformant for ServerKey {
     type ParameterSet = IntegerServerKeyConformanceParams;
 
     fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
-        let Self { key, tag: _ } = self;
+        let Self {
+            key,
+            tag: _,
+            implicit_widening: _,
+            policy: _,
+        } = self;
 
         key.is_conformant(parameter_set)
     }
 }
 
+impl SerializedSizeBound for ServerKey {
+    fn serialized_size_bound(parameter_set: &IntegerServerKeyConformanceParams) -> u64 {
+        IntegerServerKey::serialized_size_bound(parameter_set) + OBJECT_METADATA_SIZE_BOUND
+    }
+}
+
 impl ParameterSetConformant for CompressedServerKey {
     type ParameterSet = IntegerServerKeyConformanceParams;
 
@@ -379,6 +557,8 @@ impl ParameterSetConformant for CompressedServerKey {
         let Self {
             integer_key,
             tag: _,
+            implicit_widening: _,
+            policy: _,
         } = self;
 
         integer_key.is_conformant(parameter_set)
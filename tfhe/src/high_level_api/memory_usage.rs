@@ -0,0 +1,237 @@
+//! Accounting of the memory used by the process, with an optional cap.
+//!
+//! Ciphertexts and keys are large, and the memory a computation needs is hard to predict. A
+//! service sharing its host with other services can bound the memory used by the computations,
+//! so that a computation is refused instead of the whole process being killed by the OS once
+//! the memory is exhausted.
+//!
+//! The accounting relies on the [MemoryAccountingAllocator], which must be installed as the
+//! global allocator of the program. Without it, the usage is always 0 and the cap never
+//! exceeded.
+//!
+//! The usage and the cap are global to the process: the usage counts all the allocations of all
+//! the threads, not only the ones of the high level API, and a computation can be refused because
+//! of the memory used by another one.
+//!
+//! When a cap is set with [set_memory_usage_cap], each operation of the high level API checks,
+//! before starting, that the current usage does not exceed the cap, and panics otherwise (which
+//! aborts the process if it is built with `panic = "abort"`). Only the outermost operation is
+//! checked, an operation implemented using other operations is thus never stopped halfway. As
+//! the check is done before an operation and not during it, the usage may go over the cap by the
+//! memory needed by one operation.
+//!
+//! To handle a refused computation without panicking, run it with [try_with_memory_usage_cap]
+//! and check the cap explicitly, with [check_memory_usage_cap], between its operations.
+use crate::high_level_api::accounting;
+use crate::high_level_api::errors::MemoryUsageCapExceeded;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static CURRENT_USAGE: AtomicUsize = AtomicUsize::new(0);
+// usize::MAX means no cap
+static USAGE_CAP: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+thread_local! {
+    // Number of nested `try_with_memory_usage_cap` calls running on the thread
+    static TRY_SCOPE_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// A global allocator that keeps track of the number of bytes currently allocated
+///
+/// It forwards the allocations to an inner allocator ([System] by default).
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::{current_memory_usage, MemoryAccountingAllocator};
+///
+/// #[global_allocator]
+/// static ALLOCATOR: MemoryAccountingAllocator =
+///     MemoryAccountingAllocator::new(std::alloc::System);
+///
+/// fn main() {
+///     let usage_before = current_memory_usage();
+///     let buffer = vec![0u64; 1024];
+///     assert!(current_memory_usage() >= usage_before + 8 * 1024);
+///     drop(buffer);
+/// }
+/// ```
+pub struct MemoryAccountingAllocator<A = System> {
+    inner: A,
+}
+
+impl<A> MemoryAccountingAllocator<A> {
+    pub const fn new(inner: A) -> Self {
+        Self { inner }
+    }
+}
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for MemoryAccountingAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.inner.alloc(layout);
+        if !ptr.is_null() {
+            CURRENT_USAGE.fetch_add(layout.size(), Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.inner.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            CURRENT_USAGE.fetch_add(layout.size(), Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.inner.dealloc(ptr, layout);
+        CURRENT_USAGE.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = self.inner.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            if new_size >= layout.size() {
+                CURRENT_USAGE.fetch_add(new_size - layout.size(), Ordering::Relaxed);
+            } else {
+                CURRENT_USAGE.fetch_sub(layout.size() - new_size, Ordering::Relaxed);
+            }
+        }
+        new_ptr
+    }
+}
+
+/// Returns the number of bytes currently allocated through the [MemoryAccountingAllocator], by
+/// all the threads of the process
+///
+/// Returns 0 if the [MemoryAccountingAllocator] is not the global allocator.
+pub fn current_memory_usage() -> usize {
+    CURRENT_USAGE.load(Ordering::Relaxed)
+}
+
+/// Sets the maximum memory usage, in bytes, above which operations are refused
+///
+/// `None` removes the cap, which is the default.
+///
+/// The cap is global, it applies to the operations of all the threads, and is compared to the
+/// memory used by the whole process (see [current_memory_usage]).
+pub fn set_memory_usage_cap(cap: Option<usize>) {
+    USAGE_CAP.store(cap.unwrap_or(usize::MAX), Ordering::Relaxed);
+}
+
+/// Returns the current memory usage cap, see [set_memory_usage_cap]
+pub fn memory_usage_cap() -> Option<usize> {
+    let cap = USAGE_CAP.load(Ordering::Relaxed);
+    (cap != usize::MAX).then_some(cap)
+}
+
+/// Returns an error if the current memory usage exceeds the cap
+///
+/// This is used between the operations of a computation run by [try_with_memory_usage_cap], and
+/// can be used to check whether a computation is going to be refused before starting it.
+pub fn check_memory_usage_cap() -> Result<(), MemoryUsageCapExceeded> {
+    let cap = USAGE_CAP.load(Ordering::Relaxed);
+    if cap == usize::MAX {
+        return Ok(());
+    }
+
+    let usage = current_memory_usage();
+    if usage > cap {
+        Err(MemoryUsageCapExceeded { usage, cap })
+    } else {
+        Ok(())
+    }
+}
+
+/// Panics if the current memory usage exceeds the cap, unless an operation is already running
+/// on the thread or the thread runs [try_with_memory_usage_cap]
+#[inline]
+pub(in crate::high_level_api) fn enforce_memory_usage_cap() {
+    if accounting::current_nesting_depth() != 0 || TRY_SCOPE_DEPTH.with(Cell::get) != 0 {
+        return;
+    }
+
+    if let Err(error) = check_memory_usage_cap() {
+        panic!("{error}");
+    }
+}
+
+/// Returns the number of [try_with_memory_usage_cap] calls running on the current thread
+///
+/// Work spawned on other threads (e.g. by [fhe_join](crate::fhe_join)) must run with the depth of
+/// the thread that spawned it, otherwise its operations would panic instead of being checked by
+/// the caller of [try_with_memory_usage_cap].
+pub(in crate::high_level_api) fn current_try_scope_depth() -> usize {
+    TRY_SCOPE_DEPTH.with(Cell::get)
+}
+
+/// Sets the number of [try_with_memory_usage_cap] calls running on the current thread, returns
+/// the previous one
+pub(in crate::high_level_api) fn replace_try_scope_depth(depth: usize) -> usize {
+    TRY_SCOPE_DEPTH.with(|current| current.replace(depth))
+}
+
+/// Runs `func` if the memory usage does not exceed the cap, with the operations of `func` not
+/// checked against the cap
+///
+/// Instead of having its operations panic when the cap is exceeded, `func` checks the cap
+/// explicitly with [check_memory_usage_cap] between its operations, and returns the error to stop
+/// the computation. This also applies to the operations `func` runs on other threads with
+/// [fhe_join](crate::fhe_join).
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{
+///     check_memory_usage_cap, current_memory_usage, generate_keys, set_memory_usage_cap,
+///     set_server_key, try_with_memory_usage_cap, ConfigBuilder, FheUint32,
+///     MemoryAccountingAllocator,
+/// };
+///
+/// #[global_allocator]
+/// static ALLOCATOR: MemoryAccountingAllocator =
+///     MemoryAccountingAllocator::new(std::alloc::System);
+///
+/// fn main() {
+///     let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+///     set_server_key(server_key);
+///
+///     let a = FheUint32::encrypt(1u32, &client_key);
+///     let b = FheUint32::encrypt(2u32, &client_key);
+///
+///     // A cap smaller than what is already used, the computation cannot be started
+///     set_memory_usage_cap(Some(current_memory_usage() / 2));
+///     let result = try_with_memory_usage_cap(|| Ok(&a + &b));
+///     assert!(result.is_err());
+///
+///     set_memory_usage_cap(None);
+///     let result = try_with_memory_usage_cap(|| {
+///         let sum = &a + &b;
+///         check_memory_usage_cap()?;
+///         Ok(&sum * &b)
+///     })
+///     .unwrap();
+///     let decrypted: u32 = result.decrypt(&client_key);
+///     assert_eq!(decrypted, 6);
+/// }
+/// ```
+pub fn try_with_memory_usage_cap<R, F>(func: F) -> Result<R, MemoryUsageCapExceeded>
+where
+    F: FnOnce() -> Result<R, MemoryUsageCapExceeded>,
+{
+    struct TryScope;
+
+    impl Drop for TryScope {
+        fn drop(&mut self) {
+            TRY_SCOPE_DEPTH.with(|depth| depth.set(depth.get() - 1));
+        }
+    }
+
+    check_memory_usage_cap()?;
+
+    TRY_SCOPE_DEPTH.with(|depth| depth.set(depth.get() + 1));
+    let _scope = TryScope;
+    func()
+}
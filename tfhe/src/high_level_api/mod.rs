@@ -48,28 +48,48 @@ macro_rules! export_concrete_array_types {
 
 pub use crate::core_crypto::commons::math::random::Seed;
 pub use crate::integer::server_key::MatchValues;
+pub use accounting::{
+    disable_tag_statistics, enable_tag_statistics, reset_tag_statistics, tag_statistics_snapshot,
+    take_tag_statistics, TagStatistics,
+};
 pub use config::{Config, ConfigBuilder};
-pub use global_state::{set_server_key, unset_server_key, with_server_key_as_context};
+pub use errors::MemoryUsageCapExceeded;
+pub use geo::FheGeoPoint;
+pub use global_state::{fhe_join, set_server_key, unset_server_key, with_server_key_as_context};
 
-pub use integers::{CompressedFheInt, CompressedFheUint, FheInt, FheUint, IntegerId};
+pub use integers::{
+    fhe_join_eq, fhe_join_eq_sum, Checked, CompressedFheInt, CompressedFheUint, DynFheUint,
+    FheAccumulator, FheInt, FheUint, IntegerId, Wrapping,
+};
 #[cfg(feature = "gpu")]
 pub use keys::CudaServerKey;
 pub use keys::{
     generate_keys, ClientKey, CompactPublicKey, CompressedCompactPublicKey, CompressedPublicKey,
-    CompressedServerKey, KeySwitchingKey, PublicKey, ServerKey,
+    CompressedServerKey, DecryptionKey, EncryptionKey, KeySwitchingKey, PublicKey, ServerKey,
+};
+pub use memory_usage::{
+    check_memory_usage_cap, current_memory_usage, memory_usage_cap, set_memory_usage_cap,
+    try_with_memory_usage_cap, MemoryAccountingAllocator,
+};
+pub use policy::{
+    policy_operation_count, reset_policy_operation_counts, OperationKind, OperationPolicy,
 };
 
 #[cfg(test)]
 mod tests;
 
-pub use crate::high_level_api::booleans::{CompressedFheBool, FheBool, FheBoolConformanceParams};
+pub use crate::high_level_api::booleans::{
+    CompressedFheBool, FheBool, FheBoolConformanceParams, FheBoolExpr,
+};
 expand_pub_use_fhe_type!(
     pub use crate::high_level_api::integers{
         FheUint2, FheUint4, FheUint6, FheUint8, FheUint10, FheUint12, FheUint14, FheUint16,
-        FheUint32, FheUint64, FheUint128, FheUint160, FheUint256, FheUint512, FheUint1024, FheUint2048,
+        FheUint24, FheUint32, FheUint40, FheUint48, FheUint64, FheUint72, FheUint128,
+        FheUint160, FheUint256, FheUint512, FheUint1024, FheUint2048,
 
         FheInt2, FheInt4, FheInt6, FheInt8, FheInt10, FheInt12, FheInt14, FheInt16,
-        FheInt32, FheInt64, FheInt128, FheInt160, FheInt256
+        FheInt24, FheInt32, FheInt40, FheInt48, FheInt64, FheInt72, FheInt128,
+        FheInt160, FheInt256, FheInt512, FheInt1024, FheInt2048,
     };
 );
 pub use array::{
@@ -99,16 +119,21 @@ pub use compressed_ciphertext_list::{
 pub use tag::Tag;
 pub use traits::FheId;
 
+mod accounting;
 mod booleans;
 mod compressed_ciphertext_list;
 mod config;
 mod errors;
+mod geo;
 mod global_state;
 mod integers;
 mod keys;
+mod memory_usage;
+mod policy;
 mod traits;
 mod utils;
 
+pub mod algorithms;
 pub mod array;
 pub mod backward_compatibility;
 mod compact_list;
@@ -139,8 +164,12 @@ pub enum FheTypes {
     Uint12,
     Uint14,
     Uint16,
+    Uint24,
     Uint32,
+    Uint40,
+    Uint48,
     Uint64,
+    Uint72,
     Uint128,
     Uint160,
     Uint256,
@@ -155,9 +184,16 @@ pub enum FheTypes {
     Int12,
     Int14,
     Int16,
+    Int24,
     Int32,
+    Int40,
+    Int48,
     Int64,
+    Int72,
     Int128,
     Int160,
     Int256,
+    Int512,
+    Int1024,
+    Int2048,
 }
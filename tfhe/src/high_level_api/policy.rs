@@ -0,0 +1,361 @@
+//! Restriction of the homomorphic operations a [ServerKey](crate::ServerKey) may perform.
+//!
+//! A service running computations submitted by multiple tenants can attach an
+//! [OperationPolicy] to the server key of a tenant (see
+//! [ServerKey::with_operation_policy](crate::ServerKey::with_operation_policy)), so that only
+//! an approved set of operations can be evaluated with it, without having to write an
+//! interpreter that checks the computations beforehand.
+//!
+//! The policy is enforced when an operation is dispatched to the server key, on the CPU as well
+//! as on the GPU: an operation that is not permitted panics before any work is done.
+use crate::backward_compatibility::policy::{OperationKindVersions, OperationPolicyVersions};
+use crate::Tag;
+use std::cell::Cell;
+use std::collections::{HashMap, HashSet};
+use std::marker::PhantomData;
+use std::sync::Mutex;
+use tfhe_versionable::Versionize;
+
+static OPERATION_COUNTS: Mutex<Option<HashMap<Tag, u64>>> = Mutex::new(None);
+
+thread_local! {
+    static ACTIVE_PERMITS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// The operations that an [OperationPolicy] can restrict
+///
+/// Assigning variants of the operators (e.g. `+=`) have the same kind as the operator, and
+/// operations with a clear operand have the same kind as the operation between ciphertexts.
+/// The overflowing, checked, saturating and modular variants of an operation (e.g.
+/// `overflowing_add`, `checked_div` or `mod_mul`) have the kind of the operation, summing
+/// ciphertexts (e.g. [Iterator::sum] or an [FheAccumulator](crate::FheAccumulator)) is an
+/// [Add](Self::Add) counted once per call, and operations on [FheBool](crate::FheBool) have the
+/// kind of the corresponding operation on integers.
+///
+/// Operations that do not have a kind, such as `reverse_bits` or the operations on arrays, are
+/// refused by a server key having a policy, unless the policy does not restrict anything.
+/// Operations that only create, move or convert ciphertexts without computing on their values,
+/// such as trivial encryptions, compression or the expansion of lists, are never restricted.
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, Versionize,
+)]
+#[versionize(OperationKindVersions)]
+pub enum OperationKind {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    BitAnd,
+    BitOr,
+    BitXor,
+    Shl,
+    Shr,
+    RotateLeft,
+    RotateRight,
+    Neg,
+    Not,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Min,
+    Max,
+    /// Computes the quotient and the remainder at once, counted as a single operation
+    DivRem,
+    Clamp,
+    /// `if_then_else`, `select` and `cmux`
+    IfThenElse,
+    /// Counting bits: `leading_zeros`, `leading_ones`, `trailing_zeros`, `trailing_ones`,
+    /// `count_ones`, `count_zeros` and `ilog2`
+    BitCount,
+    /// Casts between integer types and to or from [FheBool](crate::FheBool)
+    Cast,
+}
+
+/// Restricts the operations that can be performed with a [ServerKey](crate::ServerKey)
+///
+/// The default policy permits everything, restrictions are added with the builder methods.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{ClientKey, ConfigBuilder, FheUint8, OperationKind, OperationPolicy, ServerKey};
+///
+/// let client_key = ClientKey::generate(ConfigBuilder::default());
+/// let policy = OperationPolicy::new()
+///     .allow_only([OperationKind::Add, OperationKind::Lt])
+///     .with_max_bits(32);
+/// let server_key = ServerKey::new(&client_key).with_operation_policy(policy);
+/// tfhe::set_server_key(server_key);
+///
+/// let a = FheUint8::encrypt(12u8, &client_key);
+/// let b = FheUint8::encrypt(30u8, &client_key);
+///
+/// let sum = &a + &b;
+/// let decrypted: u8 = sum.decrypt(&client_key);
+/// assert_eq!(decrypted, 42);
+///
+/// // The multiplication is not permitted by the policy
+/// let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| &a * &b));
+/// assert!(result.is_err());
+/// ```
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize,
+)]
+#[versionize(OperationPolicyVersions)]
+pub struct OperationPolicy {
+    allowed: Option<HashSet<OperationKind>>,
+    denied: HashSet<OperationKind>,
+    max_bits: Option<u32>,
+    max_operations_per_tag: Option<u64>,
+}
+
+impl OperationPolicy {
+    /// Creates a policy that permits all the operations
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only permits the given operations
+    ///
+    /// Calling this again replaces the previously allowed operations.
+    pub fn allow_only(mut self, kinds: impl IntoIterator<Item = OperationKind>) -> Self {
+        self.allowed = Some(kinds.into_iter().collect());
+        self
+    }
+
+    /// Forbids the given operation, even if it is in the allowed operations
+    pub fn deny(mut self, kind: OperationKind) -> Self {
+        self.denied.insert(kind);
+        self
+    }
+
+    /// Forbids operations on integers having more than `max_bits` bits
+    pub fn with_max_bits(mut self, max_bits: u32) -> Self {
+        self.max_bits = Some(max_bits);
+        self
+    }
+
+    /// Limits the number of operations done on ciphertexts having the same [Tag]
+    ///
+    /// The operations are counted for the tag of the (first) encrypted operand, by a counter
+    /// shared by all the server keys having a policy with such a limit, see
+    /// [policy_operation_count] and [reset_policy_operation_counts].
+    pub fn with_max_operations_per_tag(mut self, max_operations: u64) -> Self {
+        self.max_operations_per_tag = Some(max_operations);
+        self
+    }
+
+    /// Returns whether the policy permits the kind of operation, regardless of the other limits
+    pub fn is_allowed(&self, kind: OperationKind) -> bool {
+        !self.denied.contains(&kind)
+            && self
+                .allowed
+                .as_ref()
+                .map_or(true, |allowed| allowed.contains(&kind))
+    }
+
+    pub fn max_bits(&self) -> Option<u32> {
+        self.max_bits
+    }
+
+    pub fn max_operations_per_tag(&self) -> Option<u64> {
+        self.max_operations_per_tag
+    }
+
+    /// Returns whether the policy permits operations that do not have an [OperationKind]
+    ///
+    /// Only a policy that does not restrict anything permits them.
+    pub fn permits_operations_without_kind(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Checks that the policy permits the operation on a `num_bits` integer having the `tag`,
+    /// and counts it for the tag
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operation is not permitted
+    pub(in crate::high_level_api) fn enforce(
+        &self,
+        kind: OperationKind,
+        num_bits: usize,
+        tag: &Tag,
+    ) {
+        assert!(
+            self.is_allowed(kind),
+            "The operation {kind:?} is not allowed by the operation policy of the server key"
+        );
+
+        if let Some(max_bits) = self.max_bits {
+            assert!(
+                num_bits <= max_bits as usize,
+                "The operation policy of the server key does not allow operations on integers \
+                of more than {max_bits} bits, got {kind:?} on {num_bits} bits"
+            );
+        }
+
+        if let Some(max_operations) = self.max_operations_per_tag {
+            let is_within_limit = {
+                let mut counts = OPERATION_COUNTS.lock().unwrap();
+                let count = counts
+                    .get_or_insert_with(HashMap::new)
+                    .entry(tag.clone())
+                    .or_default();
+                if *count < max_operations {
+                    *count += 1;
+                    true
+                } else {
+                    false
+                }
+            };
+            // The lock is released before panicking, so that the counts are not poisoned
+            assert!(
+                is_within_limit,
+                "The operation policy of the server key allows at most {max_operations} \
+                operations on ciphertexts with the tag {tag:?}"
+            );
+        }
+    }
+}
+
+/// Marks the current thread as running an operation checked against the [OperationPolicy] of the
+/// server key, until dropped
+///
+/// The internal keys refuse to compute for an operation without a permit when the policy only
+/// permits the operations having an [OperationKind].
+#[must_use]
+pub(in crate::high_level_api) struct OperationPermit {
+    // The permit is only valid for the thread that created it
+    _not_send: PhantomData<*const ()>,
+}
+
+impl OperationPermit {
+    pub(in crate::high_level_api) fn new() -> Self {
+        ACTIVE_PERMITS.with(|permits| permits.set(permits.get() + 1));
+        Self {
+            _not_send: PhantomData,
+        }
+    }
+
+    /// Returns whether a permit is alive in the current thread
+    pub(in crate::high_level_api) fn is_active() -> bool {
+        ACTIVE_PERMITS.with(|permits| permits.get() > 0)
+    }
+
+    /// Suspends the permits of the current thread, e.g. while it runs a task unrelated to the
+    /// permitted operation, returns the number of permits that were suspended
+    pub(in crate::high_level_api) fn suspend_all() -> usize {
+        ACTIVE_PERMITS.with(|permits| permits.replace(0))
+    }
+
+    /// Restores the permits suspended by [Self::suspend_all]
+    pub(in crate::high_level_api) fn restore_all(suspended: usize) {
+        ACTIVE_PERMITS.with(|permits| permits.set(suspended));
+    }
+}
+
+impl Drop for OperationPermit {
+    fn drop(&mut self) {
+        ACTIVE_PERMITS.with(|permits| permits.set(permits.get() - 1));
+    }
+}
+
+/// Returns the number of operations counted for the tag by policies limiting the number of
+/// operations per tag (see [OperationPolicy::with_max_operations_per_tag])
+pub fn policy_operation_count(tag: &Tag) -> u64 {
+    OPERATION_COUNTS
+        .lock()
+        .unwrap()
+        .as_ref()
+        .and_then(|counts| counts.get(tag).copied())
+        .unwrap_or(0)
+}
+
+/// Resets the number of operations counted for all the tags, e.g. at the start of a new billing
+/// period
+pub fn reset_policy_operation_counts() {
+    let _ = OPERATION_COUNTS.lock().unwrap().take();
+}
+
+/// Returns the [OperationKind] corresponding to the name of an operator trait
+macro_rules! operation_kind {
+    (Add) => {
+        $crate::high_level_api::OperationKind::Add
+    };
+    (AddAssign) => {
+        $crate::high_level_api::OperationKind::Add
+    };
+    (Sub) => {
+        $crate::high_level_api::OperationKind::Sub
+    };
+    (SubAssign) => {
+        $crate::high_level_api::OperationKind::Sub
+    };
+    (Mul) => {
+        $crate::high_level_api::OperationKind::Mul
+    };
+    (MulAssign) => {
+        $crate::high_level_api::OperationKind::Mul
+    };
+    (Div) => {
+        $crate::high_level_api::OperationKind::Div
+    };
+    (DivAssign) => {
+        $crate::high_level_api::OperationKind::Div
+    };
+    (Rem) => {
+        $crate::high_level_api::OperationKind::Rem
+    };
+    (RemAssign) => {
+        $crate::high_level_api::OperationKind::Rem
+    };
+    (BitAnd) => {
+        $crate::high_level_api::OperationKind::BitAnd
+    };
+    (BitAndAssign) => {
+        $crate::high_level_api::OperationKind::BitAnd
+    };
+    (BitOr) => {
+        $crate::high_level_api::OperationKind::BitOr
+    };
+    (BitOrAssign) => {
+        $crate::high_level_api::OperationKind::BitOr
+    };
+    (BitXor) => {
+        $crate::high_level_api::OperationKind::BitXor
+    };
+    (BitXorAssign) => {
+        $crate::high_level_api::OperationKind::BitXor
+    };
+    (Shl) => {
+        $crate::high_level_api::OperationKind::Shl
+    };
+    (ShlAssign) => {
+        $crate::high_level_api::OperationKind::Shl
+    };
+    (Shr) => {
+        $crate::high_level_api::OperationKind::Shr
+    };
+    (ShrAssign) => {
+        $crate::high_level_api::OperationKind::Shr
+    };
+    (RotateLeft) => {
+        $crate::high_level_api::OperationKind::RotateLeft
+    };
+    (RotateLeftAssign) => {
+        $crate::high_level_api::OperationKind::RotateLeft
+    };
+    (RotateRight) => {
+        $crate::high_level_api::OperationKind::RotateRight
+    };
+    (RotateRightAssign) => {
+        $crate::high_level_api::OperationKind::RotateRight
+    };
+}
+
+pub(in crate::high_level_api) use operation_kind;
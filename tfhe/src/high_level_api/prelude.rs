@@ -7,10 +7,12 @@
 //! use tfhe::prelude::*;
 //! ```
 pub use crate::high_level_api::traits::{
-    BitSlice, CiphertextList, DivRem, FheBootstrap, FheDecrypt, FheEncrypt, FheEq, FheKeyswitch,
-    FheMax, FheMin, FheNumberConstant, FheOrd, FheTrivialEncrypt, FheTryEncrypt,
-    FheTryTrivialEncrypt, IfThenElse, OverflowingAdd, OverflowingMul, OverflowingSub, RotateLeft,
-    RotateLeftAssign, RotateRight, RotateRightAssign, Tagged,
+    BitSlice, CiphertextList, DivRem, FheBootstrap, FheClamp, FheDecrypt, FheDecryptWith,
+    FheEncrypt, FheEq, FheIsInRange, FheKeyswitch, FheMax, FheMaxAssign, FheMin, FheMinAssign,
+    FheNumberConstant, FheOrd, FheTrivialEncrypt, FheTryEncrypt, FheTryTrivialEncrypt, IfThenElse,
+    OverflowingAdd, OverflowingCastFrom, OverflowingCastInto, OverflowingMul, OverflowingSub,
+    RotateLeft, RotateLeftAssign, RotateRight, RotateRightAssign, SaturatingAdd,
+    SaturatingCastFrom, SaturatingCastInto, SaturatingMul, SaturatingSub, SelectCase, Tagged,
 };
 
 pub use crate::conformance::ParameterSetConformant;
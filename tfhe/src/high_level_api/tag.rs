@@ -308,6 +308,13 @@ pub struct Tag {
     inner: SmallVec,
 }
 
+// Consistent with `Eq`, which compares the stored bytes
+impl std::hash::Hash for Tag {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.data().hash(state);
+    }
+}
+
 impl Tag {
     /// Returns a slice to the bytes stored
     pub fn data(&self) -> &[u8] {
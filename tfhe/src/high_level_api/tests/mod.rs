@@ -6,7 +6,8 @@ use crate::high_level_api::{
 };
 use crate::integer::U256;
 use crate::{
-    set_server_key, CompactPublicKey, CompressedPublicKey, CompressedServerKey, FheUint32, Tag,
+    set_server_key, CompactPublicKey, CompressedPublicKey, CompressedServerKey, FheUint16,
+    FheUint32, Tag,
 };
 use std::fmt::Debug;
 
@@ -157,6 +158,31 @@ fn test_serialize_deserialize_are_implemented() {
     can_be_deserialized(&pksz);
 }
 
+#[test]
+fn test_keys_min_reader_version() {
+    use crate::safe_serialization::{inspect_header, safe_serialize};
+
+    let config = ConfigBuilder::default().build();
+    let cks = ClientKey::generate(config);
+    let csks = CompressedServerKey::new(&cks);
+
+    // Older versions cannot read the latest versions of the keys and of the config
+    let mut serialized = vec![];
+    safe_serialize(&config, &mut serialized, 1 << 20).unwrap();
+    let info = inspect_header(serialized.as_slice()).unwrap();
+    assert_eq!(info.min_reader_version(), Some("0.8"));
+
+    let mut serialized = vec![];
+    safe_serialize(&cks, &mut serialized, 1 << 30).unwrap();
+    let info = inspect_header(serialized.as_slice()).unwrap();
+    assert_eq!(info.min_reader_version(), Some("0.8"));
+
+    let mut serialized = vec![];
+    safe_serialize(&csks, &mut serialized, 1 << 30).unwrap();
+    let info = inspect_header(serialized.as_slice()).unwrap();
+    assert_eq!(info.min_reader_version(), Some("0.8"));
+}
+
 #[test]
 fn test_try_from_single_lwe_encryption_key() {
     let parameters = crate::shortint::prelude::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
@@ -186,3 +212,349 @@ fn test_try_from_single_lwe_encryption_key() {
     let clear_res: u32 = encrypted_res_mul.decrypt(&client_key);
     assert_eq!(clear_res, clear_a + clear_b);
 }
+
+#[test]
+fn test_tag_statistics() {
+    let config = ConfigBuilder::default().build();
+
+    // Statistics are global, use random tags so that other tests running concurrently
+    // do not interfere
+    let mut cks_1 = ClientKey::generate(config);
+    cks_1.tag_mut().set_data(&rand::random::<[u8; 16]>());
+    let mut cks_2 = cks_1.clone();
+    cks_2.tag_mut().set_data(&rand::random::<[u8; 16]>());
+    let sks_1 = ServerKey::new(&cks_1);
+    let sks_2 = ServerKey::new(&cks_2);
+
+    // The statistics are global, they must be disabled and cleared even if the test fails
+    struct RestoreStatistics;
+
+    impl Drop for RestoreStatistics {
+        fn drop(&mut self) {
+            crate::disable_tag_statistics();
+            crate::reset_tag_statistics();
+        }
+    }
+
+    crate::enable_tag_statistics();
+    let _restore = RestoreStatistics;
+
+    let a = FheUint8::encrypt(3u8, &cks_1);
+    let b = FheUint8::encrypt(5u8, &cks_1);
+
+    set_server_key(sks_1);
+    let c = &a + &b;
+    let _ = &c * &a;
+    // Operations implemented using other operations must only be counted once
+    let _ = c.min(&b);
+    // Operations run on other threads as part of an accounted operation must not be
+    // accounted again by these threads
+    let _ = crate::high_level_api::global_state::with_internal_keys(|_| {
+        crate::fhe_join(|| &a + &b, || &a * &b)
+    });
+
+    set_server_key(sks_2);
+    let _ = &a - &b;
+
+    // An operation that panics must not stop the accounting of the next ones
+    let result = std::panic::catch_unwind(|| {
+        crate::high_level_api::global_state::with_internal_keys(|_| panic!("failing operation"))
+    });
+    assert!(result.is_err());
+    let _ = &a ^ &b;
+
+    let statistics = crate::tag_statistics_snapshot();
+    assert_eq!(statistics[cks_1.tag()].operation_count(), 4);
+    assert_eq!(statistics[cks_2.tag()].operation_count(), 2);
+}
+
+#[test]
+fn test_geo_point() {
+    let config = ConfigBuilder::default().build();
+    let (cks, sks) = generate_keys(config);
+    set_server_key(sks);
+
+    assert!(crate::FheGeoPoint::try_encrypt(90_000_001, 0, &cks).is_err());
+    assert!(crate::FheGeoPoint::try_encrypt(0, -180_000_001, &cks).is_err());
+
+    let clear_a = (-90_000_000i32, 180_000_000i32);
+    let clear_b = (90_000_000i32, -180_000_000i32);
+    let a = crate::FheGeoPoint::try_encrypt(clear_a.0, clear_a.1, &cks).unwrap();
+    let b = crate::FheGeoPoint::try_encrypt(clear_b.0, clear_b.1, &cks).unwrap();
+    assert_eq!(a.decrypt(&cks), clear_a);
+
+    // The farthest points, the squared distance must not overflow
+    let squared_distance: u64 = a.squared_distance(&b).decrypt(&cks);
+    let expected = 180_000_000u64.pow(2) + 360_000_000u64.pow(2);
+    assert_eq!(squared_distance, expected);
+
+    let thresholds = [1_000, 100_000, 400_000_000, 500_000_000];
+    let bucket: u8 = a.distance_bucket(&b, &thresholds).decrypt(&cks);
+    assert_eq!(bucket, 3);
+    let bucket: u8 = a.distance_bucket(&a, &thresholds).decrypt(&cks);
+    assert_eq!(bucket, 0);
+
+    assert!(a.is_within_distance(&a, 0).decrypt(&cks));
+    assert!(!a.is_within_distance(&b, 400_000_000).decrypt(&cks));
+    assert!(a.is_within_distance(&b, 402_492_236).decrypt(&cks));
+}
+
+#[test]
+fn test_decryption_key() {
+    use crate::safe_serialization::{safe_serialize, DeserializationConfig};
+    use crate::{DecryptionKey, FheInt8};
+
+    let config = ConfigBuilder::default().build();
+    let (mut cks, _sks) = generate_keys(config);
+    cks.tag_mut().set_u64(42);
+
+    let a = FheUint8::encrypt(234u8, &cks);
+    let b = FheInt8::encrypt(-12i8, &cks);
+    let c = FheBool::encrypt(true, &cks);
+
+    let decryption_key = cks.decryption_key();
+    assert_eq!(decryption_key.tag(), cks.tag());
+
+    let mut serialized = vec![];
+    safe_serialize(&decryption_key, &mut serialized, 1 << 30).unwrap();
+    let decryption_key: DecryptionKey = DeserializationConfig::new(1 << 30)
+        .disable_conformance()
+        .deserialize_from(serialized.as_slice())
+        .unwrap();
+
+    let clear_a: u8 = a.decrypt_with(&decryption_key);
+    let clear_b: i8 = b.decrypt_with(&decryption_key);
+    let clear_c: bool = c.decrypt_with(&decryption_key);
+    assert_eq!(clear_a, 234);
+    assert_eq!(clear_b, -12);
+    assert!(clear_c);
+
+    // The client key can be used through the same trait
+    let clear_a: u8 = a.decrypt_with(&cks);
+    assert_eq!(clear_a, 234);
+}
+
+#[test]
+fn test_encryption_key() {
+    use crate::safe_serialization::{safe_serialize, DeserializationConfig};
+    use crate::EncryptionKey;
+
+    let config = ConfigBuilder::default().build();
+    let (mut cks, sks) = generate_keys(config);
+    cks.tag_mut().set_u64(42);
+    set_server_key(sks);
+
+    let encryption_key = EncryptionKey::new(config, &cks);
+    assert_eq!(encryption_key.tag(), cks.tag());
+    assert!(!encryption_key.has_public_params());
+
+    let mut serialized = vec![];
+    safe_serialize(&encryption_key, &mut serialized, 1 << 30).unwrap();
+    let parameter_set = config.public_key_encryption_parameters().unwrap();
+    let encryption_key: EncryptionKey = DeserializationConfig::new(1 << 30)
+        .deserialize_from(serialized.as_slice(), &parameter_set)
+        .unwrap();
+
+    let list = encryption_key.builder().push(234u8).push(true).build();
+    let expander = list.expand().unwrap();
+    let a: FheUint8 = expander.get(0).unwrap().unwrap();
+    let b: FheBool = expander.get(1).unwrap().unwrap();
+
+    let clear_a: u8 = a.decrypt(&cks);
+    assert_eq!(clear_a, 234);
+    assert!(b.decrypt(&cks));
+}
+
+#[test]
+#[cfg(feature = "zk-pok")]
+fn test_encryption_key_with_public_params() {
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    use crate::EncryptionKey;
+
+    let config =
+        ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64).build();
+    let crs = crate::zk::CompactPkeCrs::from_config(config, 32).unwrap();
+
+    let (cks, sks) = generate_keys(config);
+    set_server_key(sks);
+
+    let encryption_key = EncryptionKey::new(config, &cks)
+        .with_public_params(crs.public_params())
+        .unwrap();
+    let encryption_key: EncryptionKey =
+        bincode::deserialize(&bincode::serialize(&encryption_key).unwrap()).unwrap();
+    assert!(encryption_key.has_public_params());
+
+    let public_params = encryption_key.public_params().unwrap().unwrap();
+    let metadata = [b'h', b'l', b'a', b'p', b'i'];
+    let list = encryption_key
+        .builder()
+        .push(17u32)
+        .build_with_proof_packed(&public_params, &metadata, crate::zk::ZkComputeLoad::Proof)
+        .unwrap();
+
+    let expander = list
+        .verify_and_expand(&public_params, encryption_key.public_key(), &metadata)
+        .unwrap();
+    let a: FheUint32 = expander.get(0).unwrap().unwrap();
+    let clear_a: u32 = a.decrypt(&cks);
+    assert_eq!(clear_a, 17);
+}
+
+#[test]
+fn test_client_key_zeroize() {
+    use crate::shortint::parameters::list_compression::COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    use zeroize::Zeroize;
+
+    let config = ConfigBuilder::default()
+        .enable_compression(COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64)
+        .build();
+    let (mut cks, _sks) = generate_keys(config);
+
+    let mut decryption_key = cks.decryption_key();
+
+    let is_zero = |key: &[u64]| key.iter().all(|&x| x == 0);
+    let shortint_key = &cks.key.key.key;
+    assert!(!is_zero(shortint_key.large_lwe_secret_key().as_ref()));
+    assert!(!is_zero(shortint_key.small_lwe_secret_key().as_ref()));
+
+    cks.zeroize();
+    decryption_key.zeroize();
+
+    for shortint_key in [&cks.key.key.key, &decryption_key.key.key] {
+        assert!(is_zero(shortint_key.large_lwe_secret_key().as_ref()));
+        assert!(is_zero(shortint_key.small_lwe_secret_key().as_ref()));
+    }
+    let compression_key = &cks.key.compression_key.as_ref().unwrap().key;
+    assert!(is_zero(compression_key.post_packing_ks_key.as_ref()));
+}
+
+#[test]
+fn test_fhe_join() {
+    let config = ConfigBuilder::default().build();
+    let (cks, sks) = generate_keys(config);
+
+    let clear_a = 27u8;
+    let clear_b = 128u8;
+    let a = FheUint8::encrypt(clear_a, &cks);
+    let b = FheUint8::encrypt(clear_b, &cks);
+
+    // The threads of a new pool do not have any key set
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(4)
+        .build()
+        .unwrap();
+
+    pool.install(|| {
+        set_server_key(sks);
+
+        let (sum, difference, product, is_equal) =
+            crate::fhe_join!(&a + &b, &a - &b, &a * &b, a.eq(&b));
+
+        let sum: u8 = sum.decrypt(&cks);
+        let difference: u8 = difference.decrypt(&cks);
+        let product: u8 = product.decrypt(&cks);
+        assert_eq!(sum, clear_a.wrapping_add(clear_b));
+        assert_eq!(difference, clear_a.wrapping_sub(clear_b));
+        assert_eq!(product, clear_a.wrapping_mul(clear_b));
+        assert!(!is_equal.decrypt(&cks));
+
+        // The key of the calling thread is still set
+        let (a_plus_one, b_plus_one) = crate::fhe_join(|| &a + 1u8, || &b + 1u8);
+        let a_plus_one: u8 = a_plus_one.decrypt(&cks);
+        let b_plus_one: u8 = b_plus_one.decrypt(&cks);
+        assert_eq!(a_plus_one, clear_a + 1);
+        assert_eq!(b_plus_one, clear_b + 1);
+
+        crate::unset_server_key();
+    });
+}
+
+#[test]
+fn test_dry_run_server_key() {
+    let config = ConfigBuilder::default().build();
+    set_server_key(ServerKey::new_dry_run(config));
+
+    let clear_a = 300u16;
+    let clear_b = 7u8;
+
+    let a = FheUint16::encrypt_trivial(clear_a);
+    let b = FheUint8::encrypt_trivial(clear_b);
+
+    let b = FheUint16::cast_from(b);
+    let product = (&a * &b).max(&a);
+    let (quotient, remainder) = (&a).div_rem(&b);
+    let is_greater = a.gt(&b);
+
+    let product: u16 = product.try_decrypt_trivial().unwrap();
+    let quotient: u16 = quotient.try_decrypt_trivial().unwrap();
+    let remainder: u16 = remainder.try_decrypt_trivial().unwrap();
+    let is_greater = is_greater.try_decrypt_trivial().unwrap();
+    assert_eq!(
+        product,
+        clear_a.wrapping_mul(u16::from(clear_b)).max(clear_a)
+    );
+    assert_eq!(quotient, clear_a / u16::from(clear_b));
+    assert_eq!(remainder, clear_a % u16::from(clear_b));
+    assert!(is_greater);
+
+    // The key holds no key material, so it cannot compute on real encryptions
+    let client_key = ClientKey::generate(config);
+    let encrypted = FheUint16::encrypt(clear_a, &client_key);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| &encrypted * &a));
+    assert!(result.is_err());
+
+    crate::unset_server_key();
+}
+
+#[test]
+fn test_dry_run_server_key_is_not_serializable_nor_conformant() {
+    use crate::conformance::ParameterSetConformant;
+    use crate::high_level_api::keys::inner::IntegerServerKeyConformanceParams;
+
+    let config = ConfigBuilder::default().build();
+    let server_key = ServerKey::new_dry_run(config);
+    assert!(server_key.is_dry_run());
+
+    assert!(bincode::serialize(&server_key).is_err());
+    let mut serialized = vec![];
+    assert!(
+        crate::safe_serialization::safe_serialize(&server_key, &mut serialized, 1 << 20).is_err()
+    );
+
+    let conformance_params = IntegerServerKeyConformanceParams {
+        sk_param: config.inner.block_parameters,
+        cpk_param: None,
+        compression_param: None,
+    };
+    assert!(!server_key.is_conformant(&conformance_params));
+
+    let (_, real_server_key) = generate_keys(config);
+    assert!(!real_server_key.is_dry_run());
+}
+
+#[test]
+fn test_memory_usage_cap() {
+    // The accounting allocator is not installed in tests, so the usage stays at 0 and setting a
+    // cap does not impact the other tests running concurrently
+    assert_eq!(crate::current_memory_usage(), 0);
+
+    crate::set_memory_usage_cap(Some(0));
+    assert_eq!(crate::memory_usage_cap(), Some(0));
+    assert!(crate::check_memory_usage_cap().is_ok());
+
+    let result = crate::try_with_memory_usage_cap(|| crate::try_with_memory_usage_cap(|| Ok(42)));
+    assert_eq!(result, Ok(42));
+
+    // Panics are not caught
+    let panic = std::panic::catch_unwind(|| {
+        let _ = crate::try_with_memory_usage_cap(|| -> Result<(), _> { panic!("failing") });
+    });
+    assert!(panic.is_err());
+
+    // Operations on real ciphertexts are tested in `tests/memory_usage_cap.rs`, which installs
+    // the accounting allocator
+
+    crate::set_memory_usage_cap(None);
+    assert_eq!(crate::memory_usage_cap(), None);
+}
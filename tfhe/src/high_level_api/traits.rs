@@ -65,6 +65,23 @@ pub trait FheDecrypt<T> {
     fn decrypt(&self, key: &ClientKey) -> T;
 }
 
+/// Decrypt a FHE type to a native type, with a key that may not be a [ClientKey].
+///
+/// This allows to decrypt using a [DecryptionKey](crate::DecryptionKey), and is implemented
+/// with a [ClientKey] for all types implementing [FheDecrypt].
+pub trait FheDecryptWith<T, Key> {
+    fn decrypt_with(&self, key: &Key) -> T;
+}
+
+impl<T, C> FheDecryptWith<T, ClientKey> for C
+where
+    C: FheDecrypt<T>,
+{
+    fn decrypt_with(&self, key: &ClientKey) -> T {
+        self.decrypt(key)
+    }
+}
+
 /// Key switch an ciphertext into a new ciphertext of same type but encrypted
 /// under a different key.
 pub trait FheKeyswitch<T> {
@@ -112,6 +129,28 @@ pub trait FheMax<Rhs = Self> {
     fn max(&self, other: Rhs) -> Self::Output;
 }
 
+/// Restricts a value to a range, see [Ord::clamp]
+pub trait FheClamp<Bound = Self> {
+    type Output;
+
+    fn clamp(&self, min: Bound, max: Bound) -> Self::Output;
+}
+
+/// Tests whether a value is in an inclusive range, see [std::ops::RangeInclusive::contains]
+pub trait FheIsInRange<Bound = Self> {
+    type Output;
+
+    fn is_in_range(&self, low: Bound, high: Bound) -> Self::Output;
+}
+
+pub trait FheMinAssign<Rhs = Self> {
+    fn min_assign(&mut self, other: Rhs);
+}
+
+pub trait FheMaxAssign<Rhs = Self> {
+    fn max_assign(&mut self, other: Rhs);
+}
+
 /// Trait required to apply univariate function over homomorphic types.
 ///
 /// A `univariate function` is a function with one variable, e.g., of the form f(x).
@@ -168,6 +207,44 @@ pub trait IfThenElse<Ciphertext> {
     fn cmux(&self, ct_then: &Ciphertext, ct_else: &Ciphertext) -> Ciphertext {
         self.if_then_else(ct_then, ct_else)
     }
+
+    /// Element-wise selection between two slices with the same condition
+    ///
+    /// The element `i` of the result has the value of `ct_then[i]` if `self` is true, and
+    /// the value of `ct_else[i]` otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices do not have the same length
+    fn if_then_else_slice(
+        &self,
+        ct_then: &[Ciphertext],
+        ct_else: &[Ciphertext],
+    ) -> Vec<Ciphertext> {
+        assert_eq!(
+            ct_then.len(),
+            ct_else.len(),
+            "Both slices must have the same length"
+        );
+        ct_then
+            .iter()
+            .zip(ct_else.iter())
+            .map(|(ct_then, ct_else)| self.if_then_else(ct_then, ct_else))
+            .collect()
+    }
+    fn select_slice(
+        &self,
+        ct_when_true: &[Ciphertext],
+        ct_when_false: &[Ciphertext],
+    ) -> Vec<Ciphertext> {
+        self.if_then_else_slice(ct_when_true, ct_when_false)
+    }
+}
+
+/// Selects one ciphertext among multiple ones, using an encrypted index
+pub trait SelectCase<Ciphertext> {
+    /// Returns the arm at index `self`, or an encryption of zero if `self` is out of bounds
+    fn select_case(&self, arms: &[Ciphertext]) -> Ciphertext;
 }
 
 pub trait OverflowingAdd<Rhs> {
@@ -188,6 +265,72 @@ pub trait OverflowingMul<Rhs> {
     fn overflowing_mul(self, rhs: Rhs) -> (Self::Output, FheBool);
 }
 
+/// Addition that saturates at the numeric bounds of the type instead of wrapping around
+pub trait SaturatingAdd<Rhs> {
+    type Output;
+
+    fn saturating_add(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Subtraction that saturates at the numeric bounds of the type instead of wrapping around
+pub trait SaturatingSub<Rhs> {
+    type Output;
+
+    fn saturating_sub(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Multiplication that saturates at the numeric bounds of the type instead of wrapping around
+pub trait SaturatingMul<Rhs> {
+    type Output;
+
+    fn saturating_mul(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Cast that saturates instead of wrapping.
+///
+/// Values that cannot be represented by the output type become the output type's
+/// minimum or maximum value, whereas [CastFrom](crate::prelude::CastFrom) truncates them.
+pub trait SaturatingCastFrom<Input> {
+    fn saturating_cast_from(input: Input) -> Self;
+}
+
+/// Cast that saturates instead of wrapping, see [SaturatingCastFrom]
+pub trait SaturatingCastInto<Output> {
+    fn saturating_cast_into(self) -> Output;
+}
+
+impl<Input, Output> SaturatingCastInto<Output> for Input
+where
+    Output: SaturatingCastFrom<Input>,
+{
+    fn saturating_cast_into(self) -> Output {
+        Output::saturating_cast_from(self)
+    }
+}
+
+/// Cast that also returns whether the value did not fit.
+///
+/// The casted value is the same as the one of [CastFrom](crate::prelude::CastFrom) (i.e. it is
+/// truncated), the returned [FheBool] encrypts true if the input value cannot be represented
+/// by the output type.
+pub trait OverflowingCastFrom<Input>: Sized {
+    fn overflowing_cast_from(input: Input) -> (Self, FheBool);
+}
+
+/// Cast that also returns whether the value did not fit, see [OverflowingCastFrom]
+pub trait OverflowingCastInto<Output> {
+    fn overflowing_cast_into(self) -> (Output, FheBool);
+}
+
+impl<Input, Output> OverflowingCastInto<Output> for Input
+where
+    Output: OverflowingCastFrom<Input>,
+{
+    fn overflowing_cast_into(self) -> (Output, FheBool) {
+        Output::overflowing_cast_from(self)
+    }
+}
+
 pub trait BitSlice<Bounds> {
     type Output;
 
@@ -12,6 +12,24 @@ fn num_bits_of_blocks(blocks: &[Ciphertext]) -> u32 {
         .sum::<u32>()
 }
 
+/// Returns the bytes of the LWE ciphertext of each block, the coefficients being in little endian
+///
+/// Unlike the serialized form, only the ciphertexts are included and not their metadata (degree,
+/// noise level), so two identical ciphertexts always have the same bytes.
+pub(in crate::high_level_api) fn block_bytes(blocks: &[Ciphertext]) -> Vec<Vec<u8>> {
+    blocks
+        .iter()
+        .map(|block| {
+            block
+                .ct
+                .as_ref()
+                .iter()
+                .flat_map(|coefficient| coefficient.to_le_bytes())
+                .collect()
+        })
+        .collect()
+}
+
 impl<Id: FheUintId> Expandable for FheUint<Id> {
     fn from_expanded_blocks(blocks: Vec<Ciphertext>, kind: DataKind) -> crate::Result<Self> {
         match kind {
@@ -17,6 +17,10 @@ pub type U1024 = static_unsigned::StaticUnsignedBigInt<16>;
 pub type U2048 = static_unsigned::StaticUnsignedBigInt<32>;
 pub type U4096 = static_unsigned::StaticUnsignedBigInt<64>;
 
+pub type I1024 = static_signed::StaticSignedBigInt<16>;
+pub type I2048 = static_signed::StaticSignedBigInt<32>;
+pub type I4096 = static_signed::StaticSignedBigInt<64>;
+
 #[cfg(test)]
 fn u64_with_odd_bits_set() -> u64 {
     let mut v = 0u64;
@@ -382,6 +382,18 @@ impl<const N_IN: usize, const N_OUT: usize> CastFrom<StaticUnsignedBigInt<N_IN>>
     }
 }
 
+#[cfg(feature = "num-bigint")]
+impl<const N: usize> CastFrom<&num_bigint::BigUint> for StaticUnsignedBigInt<N> {
+    /// Keeps the `64 * N` least significant bits
+    fn cast_from(input: &num_bigint::BigUint) -> Self {
+        let mut data = [0u64; N];
+        for (limb, digit) in data.iter_mut().zip(input.iter_u64_digits()) {
+            *limb = digit;
+        }
+        Self(data)
+    }
+}
+
 impl<const N: usize> CastFrom<StaticUnsignedBigInt<N>> for u8 {
     fn cast_from(input: StaticUnsignedBigInt<N>) -> Self {
         input.0[0] as Self
@@ -6,6 +6,7 @@ use crate::integer::backward_compatibility::ciphertext::{
 };
 use crate::integer::block_decomposition::{BlockRecomposer, RecomposableFrom};
 use crate::integer::client_key::{sign_extend_partial_number, RecomposableSignedInteger};
+use crate::safe_serialization::{SerializedSizeBound, OBJECT_METADATA_SIZE_BOUND};
 use crate::shortint::ciphertext::NotTrivialCiphertextError;
 use crate::shortint::parameters::CiphertextConformanceParams;
 use crate::shortint::Ciphertext;
@@ -45,6 +46,15 @@ impl<T: ParameterSetConformant<ParameterSet = CiphertextConformanceParams>> Para
     }
 }
 
+impl<T: SerializedSizeBound<ParameterSet = CiphertextConformanceParams>> SerializedSizeBound
+    for BaseRadixCiphertext<T>
+{
+    fn serialized_size_bound(params: &RadixCiphertextConformanceParams) -> u64 {
+        params.num_blocks_per_integer as u64 * T::serialized_size_bound(&params.shortint_params)
+            + OBJECT_METADATA_SIZE_BOUND
+    }
+}
+
 impl RadixCiphertext {
     pub fn block_carries_are_empty(&self) -> bool {
         self.blocks.iter().all(Ciphertext::carry_is_empty)
@@ -147,6 +157,15 @@ impl<T: ParameterSetConformant<ParameterSet = CiphertextConformanceParams>> Para
     }
 }
 
+impl<T: SerializedSizeBound<ParameterSet = CiphertextConformanceParams>> SerializedSizeBound
+    for BaseSignedRadixCiphertext<T>
+{
+    fn serialized_size_bound(params: &RadixCiphertextConformanceParams) -> u64 {
+        params.num_blocks_per_integer as u64 * T::serialized_size_bound(&params.shortint_params)
+            + OBJECT_METADATA_SIZE_BOUND
+    }
+}
+
 impl SignedRadixCiphertext {
     pub fn block_carries_are_empty(&self) -> bool {
         self.blocks.iter().all(Ciphertext::carry_is_empty)
@@ -370,7 +370,7 @@ pub struct CompactCiphertextListExpander {
 }
 
 impl CompactCiphertextListExpander {
-    fn new(expanded_blocks: Vec<Ciphertext>, info: Vec<DataKind>) -> Self {
+    pub(crate) fn new(expanded_blocks: Vec<Ciphertext>, info: Vec<DataKind>) -> Self {
         Self {
             expanded_blocks,
             info,
@@ -449,10 +449,10 @@ pub const WRONG_UNPACKING_MODE_ERR_MSG: &str =
     or IntegerCompactCiphertextListExpansionMode::CastAndUnpackIfNecessary \
     as unpacking_mode.";
 
-struct IntegerUnpackingToShortintCastingModeHelper {
+pub(crate) struct IntegerUnpackingToShortintCastingModeHelper {
     msg_extract: Box<dyn Fn(u64) -> u64 + Sync>,
     carry_extract: Box<dyn Fn(u64) -> u64 + Sync>,
-    msg_extract_bool: Box<dyn Fn(u64) -> u64 + Sync>,
+    pub(crate) msg_extract_bool: Box<dyn Fn(u64) -> u64 + Sync>,
     carry_extract_bool: Box<dyn Fn(u64) -> u64 + Sync>,
 }
 
@@ -994,6 +994,7 @@ impl ProvenCompactCiphertextList {
 
 #[cfg(feature = "zk-pok")]
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
 pub struct IntegerProvenCompactCiphertextListConformanceParams {
     pub encryption_lwe_dimension: LweDimension,
     pub message_modulus: MessageModulus,
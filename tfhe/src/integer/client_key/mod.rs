@@ -32,6 +32,7 @@ pub use radix::RadixClientKey;
 use secret_encryption_key::SecretEncryptionKeyView;
 use serde::{Deserialize, Serialize};
 use tfhe_versionable::Versionize;
+use zeroize::Zeroize;
 
 pub trait RecomposableSignedInteger:
     RecomposableFrom<u64>
@@ -93,6 +94,15 @@ pub struct ClientKey {
     pub(crate) key: ShortintClientKey,
 }
 
+/// Overwrites the secret keys with zeros, so that they do not remain in memory.
+///
+/// Wrap the key in [`zeroize::Zeroizing`] to have it zeroized when dropped.
+impl Zeroize for ClientKey {
+    fn zeroize(&mut self) {
+        self.key.zeroize();
+    }
+}
+
 impl From<ShortintClientKey> for ClientKey {
     fn from(key: ShortintClientKey) -> Self {
         Self { key }
@@ -3,6 +3,7 @@ use crate::conformance::ParameterSetConformant;
 use crate::integer::backward_compatibility::list_compression::*;
 use serde::{Deserialize, Serialize};
 use tfhe_versionable::Versionize;
+use zeroize::Zeroize;
 
 #[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
 #[versionize(CompressionPrivateKeysVersions)]
@@ -50,6 +51,12 @@ impl CompressedDecompressionKey {
     }
 }
 
+impl Zeroize for CompressionPrivateKeys {
+    fn zeroize(&mut self) {
+        self.key.zeroize();
+    }
+}
+
 impl CompressionPrivateKeys {
     pub fn into_raw_parts(self) -> crate::shortint::list_compression::CompressionPrivateKeys {
         let Self { key } = self;
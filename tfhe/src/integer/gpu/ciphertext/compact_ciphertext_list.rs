@@ -0,0 +1,298 @@
+use crate::core_crypto::gpu::glwe_ciphertext_list::CudaGlweCiphertextList;
+use crate::core_crypto::gpu::lwe_ciphertext_list::CudaLweCiphertextList;
+use crate::core_crypto::gpu::lwe_keyswitch_key::CudaLweKeyswitchKey;
+use crate::core_crypto::gpu::vec::CudaVec;
+use crate::core_crypto::gpu::{
+    cuda_keyswitch_lwe_ciphertext, cuda_programmable_bootstrap_lwe_ciphertext, CudaStreams,
+};
+use crate::core_crypto::prelude::{
+    ContiguousEntityContainer, GlweCiphertextList, LweCiphertext, LweCiphertextCount,
+};
+use crate::integer::ciphertext::{
+    CompactCiphertextList, CompactCiphertextListExpander, DataKind,
+    IntegerUnpackingToShortintCastingModeHelper,
+};
+use crate::integer::gpu::key_switching_key::CudaKeySwitchingKeyMaterial;
+use crate::integer::gpu::server_key::CudaBootstrappingKey;
+use crate::integer::gpu::CudaServerKey;
+use crate::integer::parameters::IntegerCompactCiphertextListExpansionMode;
+use crate::shortint::ciphertext::NoiseLevel;
+use crate::shortint::parameters::CompactCiphertextListExpansionKind;
+use crate::shortint::{Ciphertext, EncryptionKeyChoice, PBSOrder};
+
+fn to_cuda_indexes(indexes: &[u64], streams: &CudaStreams) -> CudaVec<u64> {
+    let mut d_indexes = CudaVec::new(indexes.len(), streams, 0);
+    unsafe {
+        d_indexes.copy_from_cpu_async(indexes, streams, 0);
+    }
+    streams.synchronize();
+    d_indexes
+}
+
+fn keyswitch_all(
+    keyswitch_key: &CudaLweKeyswitchKey<u64>,
+    input: &CudaLweCiphertextList<u64>,
+    streams: &CudaStreams,
+) -> CudaLweCiphertextList<u64> {
+    let count = input.lwe_ciphertext_count();
+    let mut output = CudaLweCiphertextList::new(
+        keyswitch_key.output_key_lwe_size().to_lwe_dimension(),
+        count,
+        input.ciphertext_modulus(),
+        streams,
+    );
+
+    let indexes = (0..count.0 as u64).collect::<Vec<_>>();
+    let d_indexes = to_cuda_indexes(&indexes, streams);
+
+    cuda_keyswitch_lwe_ciphertext(
+        keyswitch_key,
+        input,
+        &mut output,
+        &d_indexes,
+        &d_indexes,
+        streams,
+    );
+
+    output
+}
+
+impl CompactCiphertextList {
+    /// Expands the list, running the keyswitches and programmable bootstraps required to cast
+    /// and unpack the ciphertexts on the GPU.
+    ///
+    /// The result is the same as [CompactCiphertextList::expand] with the equivalent CPU keys,
+    /// the expanded ciphertexts are returned in CPU memory.
+    ///
+    /// `casting_key` is required if the list was encrypted with a dedicated compact public key,
+    /// see [CompactCiphertextList::needs_casting].
+    pub fn expand_on_gpu(
+        &self,
+        sks: &CudaServerKey,
+        casting_key: Option<&CudaKeySwitchingKeyMaterial>,
+        streams: &CudaStreams,
+    ) -> crate::Result<CompactCiphertextListExpander> {
+        let CudaBootstrappingKey::Classic(d_bsk) = &sks.bootstrapping_key else {
+            return Err(crate::Error::new(
+                "Expanding a compact list on GPU is not yet compatible with multi-bit PBS"
+                    .to_string(),
+            ));
+        };
+
+        if sks.pbs_order != PBSOrder::KeyswitchBootstrap {
+            return Err(crate::Error::new(
+                "Expanding a compact list on GPU requires a KeyswitchBootstrap server key"
+                    .to_string(),
+            ));
+        }
+
+        let casting_key = match self.ct_list.expansion_kind {
+            CompactCiphertextListExpansionKind::RequiresCasting => {
+                let Some(casting_key) = casting_key else {
+                    return Err(crate::Error::new(
+                        "This compact list requires casting, but no casting key was provided"
+                            .to_string(),
+                    ));
+                };
+                if casting_key.cast_rshift != 0 {
+                    return Err(crate::Error::new(
+                        "Casting to a different message modulus is not yet supported on GPU"
+                            .to_string(),
+                    ));
+                }
+                if casting_key.lwe_keyswitch_key.input_key_lwe_size()
+                    != self.ct_list.ct_list.lwe_size()
+                {
+                    return Err(crate::Error::new(
+                        "The casting key does not match the key used to encrypt the list"
+                            .to_string(),
+                    ));
+                }
+                Some(casting_key)
+            }
+            CompactCiphertextListExpansionKind::NoCasting(PBSOrder::KeyswitchBootstrap) => None,
+            CompactCiphertextListExpansionKind::NoCasting(PBSOrder::BootstrapKeyswitch) => {
+                return Err(crate::Error::new(
+                    "This compact list is not encrypted under the key of a KeyswitchBootstrap \
+                    server key"
+                        .to_string(),
+                ));
+            }
+        };
+
+        if self.ct_list.ct_list.lwe_ciphertext_count().0 == 0 {
+            return Ok(CompactCiphertextListExpander::new(
+                vec![],
+                self.info.clone(),
+            ));
+        }
+
+        let is_packed = self.is_packed();
+
+        if !is_packed
+            && casting_key.is_none()
+            && !self
+                .info
+                .iter()
+                .any(|kind| matches!(kind, DataKind::Boolean))
+        {
+            // Nothing to compute
+            return self.expand(IntegerCompactCiphertextListExpansionMode::NoCastingAndNoUnpacking);
+        }
+
+        let helper = IntegerUnpackingToShortintCastingModeHelper::new(
+            sks.message_modulus,
+            sks.carry_modulus,
+        );
+
+        // For each output block: the index of the expanded ciphertext it comes from, and the
+        // function to apply to it, None meaning that the ciphertext is only casted if required
+        let mut outputs: Vec<(usize, Option<&(dyn Fn(u64) -> u64 + Sync)>)> = Vec::new();
+        if is_packed {
+            let functions = helper.generate_function(&self.info);
+            for (input_index, block_functions) in functions.iter().enumerate() {
+                for function in block_functions.iter().flatten() {
+                    outputs.push((input_index, Some(*function)));
+                }
+            }
+        } else {
+            let mut input_index = 0;
+            for data_kind in &self.info {
+                for _ in 0..data_kind.num_blocks() {
+                    // Booleans are sanitized when not casted, as done on CPU
+                    let function =
+                        if casting_key.is_none() && matches!(data_kind, DataKind::Boolean) {
+                            Some(helper.msg_extract_bool.as_ref())
+                        } else {
+                            None
+                        };
+                    outputs.push((input_index, function));
+                    input_index += 1;
+                }
+            }
+        }
+
+        // Casted ciphertexts all go through a PBS to land under the computation key
+        let is_casting = casting_key.is_some();
+
+        let lwe_list = self.ct_list.expand_into_lwe_ciphertext_list();
+        let d_input = CudaLweCiphertextList::from_lwe_ciphertext_list(&lwe_list, streams);
+
+        // Bring the ciphertexts under the input key of the bootstrapping key
+        let d_pbs_input = match casting_key {
+            Some(casting_key) => {
+                let d_casted = keyswitch_all(&casting_key.lwe_keyswitch_key, &d_input, streams);
+                match casting_key.destination_key {
+                    EncryptionKeyChoice::Small => d_casted,
+                    EncryptionKeyChoice::Big => {
+                        keyswitch_all(&sks.key_switching_key, &d_casted, streams)
+                    }
+                }
+            }
+            None => keyswitch_all(&sks.key_switching_key, &d_input, streams),
+        };
+
+        let pbs_outputs = outputs
+            .iter()
+            .filter(|(_, function)| is_casting || function.is_some())
+            .collect::<Vec<_>>();
+        let pbs_count = pbs_outputs.len();
+
+        let luts = pbs_outputs
+            .iter()
+            .map(|(_, function)| match function {
+                Some(function) => sks.generate_lookup_table(function),
+                None => sks.generate_lookup_table(|x| x),
+            })
+            .collect::<Vec<_>>();
+
+        let glwe_size = d_bsk.glwe_dimension.to_glwe_size();
+        let polynomial_size = d_bsk.polynomial_size;
+        let mut accumulators_data = Vec::with_capacity(pbs_count * glwe_size.0 * polynomial_size.0);
+        for lut in &luts {
+            accumulators_data.extend_from_slice(lut.acc.as_ref());
+        }
+        let accumulators = GlweCiphertextList::from_container(
+            accumulators_data,
+            glwe_size,
+            polynomial_size,
+            sks.ciphertext_modulus,
+        );
+        let d_accumulators =
+            CudaGlweCiphertextList::from_glwe_ciphertext_list(&accumulators, streams);
+
+        let lut_indexes = (0..pbs_count as u64).collect::<Vec<_>>();
+        let input_indexes = pbs_outputs
+            .iter()
+            .map(|(input_index, _)| *input_index as u64)
+            .collect::<Vec<_>>();
+        let d_lut_indexes = to_cuda_indexes(&lut_indexes, streams);
+        let d_input_indexes = to_cuda_indexes(&input_indexes, streams);
+
+        let mut d_output = CudaLweCiphertextList::new(
+            d_bsk.output_lwe_dimension(),
+            LweCiphertextCount(pbs_count),
+            sks.ciphertext_modulus,
+            streams,
+        );
+
+        cuda_programmable_bootstrap_lwe_ciphertext(
+            &d_pbs_input,
+            &mut d_output,
+            &d_accumulators,
+            &d_lut_indexes,
+            &d_lut_indexes,
+            &d_input_indexes,
+            LweCiphertextCount(pbs_count),
+            d_bsk,
+            streams,
+        );
+
+        let pbs_results = d_output.to_lwe_ciphertext_list(streams);
+        let mut pbs_results = pbs_results.iter().zip(luts.iter());
+
+        let expanded_blocks = outputs
+            .iter()
+            .map(|(input_index, function)| {
+                if is_casting || function.is_some() {
+                    let (lwe, lut) = pbs_results.next().unwrap();
+                    // If we apply an identity LUT the degree of the list is a tighter bound
+                    let degree = if function.is_some() {
+                        lut.degree
+                    } else {
+                        self.ct_list.degree
+                    };
+
+                    Ciphertext {
+                        ct: LweCiphertext::from_container(
+                            lwe.as_ref().to_vec(),
+                            sks.ciphertext_modulus,
+                        ),
+                        degree,
+                        noise_level: NoiseLevel::NOMINAL,
+                        message_modulus: sks.message_modulus,
+                        carry_modulus: sks.carry_modulus,
+                        pbs_order: PBSOrder::KeyswitchBootstrap,
+                    }
+                } else {
+                    Ciphertext {
+                        ct: LweCiphertext::from_container(
+                            lwe_list.get(*input_index).as_ref().to_vec(),
+                            self.ct_list.ct_list.ciphertext_modulus(),
+                        ),
+                        degree: self.ct_list.degree,
+                        noise_level: self.ct_list.noise_level,
+                        message_modulus: self.ct_list.message_modulus,
+                        carry_modulus: self.ct_list.carry_modulus,
+                        pbs_order: PBSOrder::KeyswitchBootstrap,
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Ok(CompactCiphertextListExpander::new(
+            expanded_blocks,
+            self.info.clone(),
+        ))
+    }
+}
@@ -1,4 +1,5 @@
 pub mod boolean_value;
+pub mod compact_ciphertext_list;
 pub mod compressed_ciphertext_list;
 pub mod info;
 
@@ -0,0 +1,32 @@
+use crate::core_crypto::gpu::lwe_keyswitch_key::CudaLweKeyswitchKey;
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::key_switching_key::KeySwitchingKeyMaterial;
+use crate::shortint::EncryptionKeyChoice;
+
+/// Key switching material stored on the GPU
+///
+/// It is used to cast ciphertexts encrypted with a dedicated compact public key to the
+/// parameters of a [CudaServerKey](crate::integer::gpu::CudaServerKey).
+pub struct CudaKeySwitchingKeyMaterial {
+    pub lwe_keyswitch_key: CudaLweKeyswitchKey<u64>,
+    pub cast_rshift: i8,
+    pub destination_key: EncryptionKeyChoice,
+}
+
+impl CudaKeySwitchingKeyMaterial {
+    pub fn from_key_switching_key_material(
+        key_switching_key_material: &KeySwitchingKeyMaterial,
+        streams: &CudaStreams,
+    ) -> Self {
+        let material = &key_switching_key_material.material;
+
+        Self {
+            lwe_keyswitch_key: CudaLweKeyswitchKey::from_lwe_keyswitch_key(
+                &material.key_switching_key,
+                streams,
+            ),
+            cast_rshift: material.cast_rshift,
+            destination_key: material.destination_key,
+        }
+    }
+}
@@ -1,5 +1,6 @@
 pub mod ciphertext;
 pub mod client_key;
+pub mod key_switching_key;
 pub mod list_compression;
 pub mod server_key;
 
@@ -159,6 +159,10 @@ impl CudaServerKey {
     /// CPU & GPU computations. When using trivial encryption it is then possible to track
     /// intermediate and final result values easily between CPU and GPU.
     ///
+    /// The bootstrapping key is decompressed on the host by chunks, each chunk being uploaded to
+    /// the GPU before the next one is decompressed, so that the decompressed bootstrapping key is
+    /// never fully held in host memory. The decompression itself still runs on the CPU.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -201,18 +205,20 @@ impl CudaServerKey {
             max_noise_level,
             ciphertext_modulus,
             pbs_order,
-        } = cpu_key.key.clone();
+        } = &cpu_key.key;
 
-        let h_key_switching_key = key_switching_key.par_decompress_into_lwe_keyswitch_key();
+        let h_key_switching_key = key_switching_key
+            .clone()
+            .par_decompress_into_lwe_keyswitch_key();
         let key_switching_key =
             CudaLweKeyswitchKey::from_lwe_keyswitch_key(&h_key_switching_key, streams);
         let bootstrapping_key = match bootstrapping_key {
             crate::shortint::server_key::compressed::ShortintCompressedBootstrappingKey::Classic(h_bootstrap_key) => {
-                let standard_bootstrapping_key =
-                    h_bootstrap_key.par_decompress_into_lwe_bootstrap_key();
-
                 let d_bootstrap_key =
-                    CudaLweBootstrapKey::from_lwe_bootstrap_key(&standard_bootstrapping_key, streams);
+                    CudaLweBootstrapKey::from_seeded_lwe_bootstrap_key_with_chunked_decompression(
+                        h_bootstrap_key,
+                        streams,
+                    );
 
                 CudaBootstrappingKey::Classic(d_bootstrap_key)
             }
@@ -220,12 +226,9 @@ impl CudaServerKey {
                 seeded_bsk: bootstrapping_key,
                 deterministic_execution: _,
             } => {
-                let standard_bootstrapping_key =
-                    bootstrapping_key.par_decompress_into_lwe_multi_bit_bootstrap_key();
-
                 let d_bootstrap_key =
-                    CudaLweMultiBitBootstrapKey::from_lwe_multi_bit_bootstrap_key(
-                        &standard_bootstrapping_key, streams);
+                    CudaLweMultiBitBootstrapKey::from_seeded_lwe_multi_bit_bootstrap_key_with_chunked_decompression(
+                        bootstrapping_key, streams);
 
                 CudaBootstrappingKey::MultiBit(d_bootstrap_key)
             }
@@ -234,12 +237,12 @@ impl CudaServerKey {
         Self {
             key_switching_key,
             bootstrapping_key,
-            message_modulus,
-            carry_modulus,
-            max_degree,
-            max_noise_level,
-            ciphertext_modulus,
-            pbs_order,
+            message_modulus: *message_modulus,
+            carry_modulus: *carry_modulus,
+            max_degree: *max_degree,
+            max_noise_level: *max_noise_level,
+            ciphertext_modulus: *ciphertext_modulus,
+            pbs_order: *pbs_order,
         }
     }
 }
@@ -44,6 +44,7 @@ mod scalar_shift;
 mod scalar_sub;
 mod shift;
 mod sub;
+mod swap_bytes;
 
 #[cfg(test)]
 mod tests_signed;
@@ -0,0 +1,123 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::CudaIntegerRadixCiphertext;
+use crate::integer::gpu::CudaServerKey;
+
+impl CudaServerKey {
+    /// Reverses the byte order of the integer.
+    ///
+    /// The number of message bits of the ciphertext must be a multiple of 8.
+    ///
+    /// When a byte is made of a whole number of blocks (e.g. 2 bits of message per block)
+    /// this is a pure permutation of the blocks that does not require any PBS. Otherwise each
+    /// byte is isolated and moved to its place with shifts and bitwise operations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of message bits is not a multiple of 8.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(gpu_index);
+    ///
+    /// // We have 8 * 2 = 16 bits of message
+    /// let num_blocks = 8;
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks, &streams);
+    ///
+    /// let msg = 0x12_34_u16;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Copy to GPU
+    /// let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+    ///
+    /// let d_ct_res = sks.swap_bytes(&d_ct, &streams);
+    ///
+    /// // Copy back to CPU
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    ///
+    /// let dec: u16 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg.swap_bytes());
+    /// ```
+    pub fn swap_bytes<T>(&self, ct: &T, streams: &CudaStreams) -> T
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let bits_per_block = self.message_modulus.0.ilog2() as usize;
+        let num_blocks = ct.as_ref().d_blocks.lwe_ciphertext_count().0;
+        let total_num_bits = bits_per_block * num_blocks;
+        assert_eq!(
+            total_num_bits % 8,
+            0,
+            "swap_bytes requires the number of bits ({total_num_bits}) to be a multiple of 8"
+        );
+
+        let mut source = unsafe { ct.duplicate_async(streams) };
+        if !source.block_carries_are_empty() {
+            unsafe {
+                self.full_propagate_assign_async(&mut source, streams);
+            }
+        }
+
+        let num_bytes = total_num_bits / 8;
+        if 8 % bits_per_block != 0 {
+            let mut result: T = self.create_trivial_zero_radix(num_blocks, streams);
+            for byte_index in 0..num_bytes {
+                let byte = self.scalar_right_shift(&source, (8 * byte_index) as u32, streams);
+                let byte = self.scalar_bitand(&byte, 0xFFu8, streams);
+                let moved_byte = self.scalar_left_shift(
+                    &byte,
+                    (8 * (num_bytes - 1 - byte_index)) as u32,
+                    streams,
+                );
+                self.bitor_assign(&mut result, &moved_byte, streams);
+            }
+            return result;
+        }
+
+        let blocks_per_byte = 8 / bits_per_block;
+        let lwe_size = ct.as_ref().d_blocks.lwe_dimension().to_lwe_size().0;
+        let byte_len = blocks_per_byte * lwe_size;
+
+        let mut result = unsafe { source.duplicate_async(streams) };
+        for byte_index in 0..num_bytes {
+            let swapped_index = num_bytes - 1 - byte_index;
+
+            let src_slice = source
+                .as_mut()
+                .d_blocks
+                .0
+                .d_vec
+                .as_mut_slice(
+                    byte_len * byte_index..byte_len * (byte_index + 1),
+                    streams.gpu_indexes[0],
+                )
+                .unwrap();
+            let mut dest_slice = result
+                .as_mut()
+                .d_blocks
+                .0
+                .d_vec
+                .as_mut_slice(
+                    byte_len * swapped_index..byte_len * (swapped_index + 1),
+                    streams.gpu_indexes[0],
+                )
+                .unwrap();
+            unsafe {
+                dest_slice.copy_from_gpu_async(&src_slice, streams, 0);
+            }
+
+            let src_blocks = blocks_per_byte * byte_index..blocks_per_byte * (byte_index + 1);
+            let dest_start = blocks_per_byte * swapped_index;
+            result.as_mut().info.blocks[dest_start..dest_start + blocks_per_byte]
+                .copy_from_slice(&source.as_ref().info.blocks[src_blocks]);
+        }
+        streams.synchronize();
+        result
+    }
+}
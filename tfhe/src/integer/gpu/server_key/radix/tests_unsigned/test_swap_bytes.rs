@@ -0,0 +1,16 @@
+use crate::integer::gpu::server_key::radix::tests_unsigned::{
+    create_gpu_parametrized_test, GpuFunctionExecutor,
+};
+use crate::integer::gpu::CudaServerKey;
+use crate::integer::server_key::radix_parallel::swap_bytes::tests::swap_bytes_test;
+use crate::shortint::parameters::*;
+
+create_gpu_parametrized_test!(integer_swap_bytes);
+
+fn integer_swap_bytes<P>(param: P)
+where
+    P: Into<PBSParameters> + Copy,
+{
+    let executor = GpuFunctionExecutor::new(&CudaServerKey::swap_bytes);
+    swap_bytes_test(param, executor);
+}
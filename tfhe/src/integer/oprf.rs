@@ -287,6 +287,80 @@ impl ServerKey {
 
         SignedRadixCiphertext::from(blocks)
     }
+
+    /// Generates an encrypted `num_block` blocks unsigned integer
+    /// taken in `[0, excluded_upper_bound[` using the given seed.
+    /// The encryted value is oblivious to the server.
+    /// It can be useful to make server random generation deterministic.
+    ///
+    /// Contrary to [Self::par_generate_oblivious_pseudo_random_unsigned_integer_bounded],
+    /// `excluded_upper_bound` does not have to be a power of two.
+    /// `random_bits_count` uniform random bits are generated (rounded up to a whole number of
+    /// blocks) and mapped to the range, so that the statistical distance between the result and
+    /// the uniform distribution is at most `excluded_upper_bound / 2^random_bits_count`.
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::Seed;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let excluded_upper_bound = 6;
+    /// let random_bits_count = 16;
+    ///
+    /// let ct_res = sks.par_generate_oblivious_pseudo_random_unsigned_integer_in_range(
+    ///     Seed(0),
+    ///     excluded_upper_bound,
+    ///     random_bits_count,
+    ///     size as u64,
+    /// );
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert!(dec_result < excluded_upper_bound);
+    /// ```
+    pub fn par_generate_oblivious_pseudo_random_unsigned_integer_in_range(
+        &self,
+        seed: Seed,
+        excluded_upper_bound: u64,
+        random_bits_count: u64,
+        num_blocks: u64,
+    ) -> RadixCiphertext {
+        assert!(self.message_modulus().0.is_power_of_two());
+        let message_bits_count = self.message_modulus().0.ilog2() as u64;
+        let range_log_size = message_bits_count * num_blocks;
+
+        assert!(
+            excluded_upper_bound > 0,
+            "The range asked for a random value must not be empty"
+        );
+        assert!(
+            range_log_size >= u64::BITS as u64 || (excluded_upper_bound - 1) >> range_log_size == 0,
+            "The range asked for a random value (=[0, {excluded_upper_bound}[) does not fit in the available range [0, 2^{range_log_size}[",
+        );
+        assert!(random_bits_count > 0, "At least one random bit is required");
+
+        let num_random_blocks = random_bits_count.div_ceil(message_bits_count);
+
+        let random =
+            self.par_generate_oblivious_pseudo_random_unsigned_integer(seed, num_random_blocks);
+
+        // With r uniform in [0, 2^k[, floor(r * excluded_upper_bound / 2^k) lies in
+        // [0, excluded_upper_bound[, each value being reached by either
+        // floor(2^k / excluded_upper_bound) or ceil(2^k / excluded_upper_bound) values of r.
+        // The product is computed on enough blocks to never overflow, and the division is a
+        // removal of the k / message_bits_count least significant blocks.
+        let mut result =
+            self.extend_radix_with_trivial_zero_blocks_msb(&random, num_blocks as usize);
+        self.scalar_mul_assign_parallelized(&mut result, excluded_upper_bound);
+        self.trim_radix_blocks_lsb_assign(&mut result, num_random_blocks as usize);
+
+        result
+    }
 }
 
 #[cfg(test)]
@@ -348,5 +422,21 @@ pub(crate) mod test {
 
             result as u64
         });
+
+        let excluded_upper_bound = 5;
+
+        test_uniformity(excluded_upper_bound, &|seed| {
+            let img = sk.par_generate_oblivious_pseudo_random_unsigned_integer_in_range(
+                Seed(seed as u128),
+                excluded_upper_bound,
+                16,
+                num_blocks as u64,
+            );
+            let result: u64 = ck.decrypt(&img);
+
+            assert!(result < excluded_upper_bound);
+
+            result
+        });
     }
 }
@@ -162,6 +162,7 @@ pub const PARAM_MESSAGE_1_CARRY_1_KS_PBS_32_BITS: WopbsParameters = WopbsParamet
 };
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
 pub struct RadixCiphertextConformanceParams {
     pub shortint_params: CiphertextConformanceParams,
     pub num_blocks_per_integer: usize,
@@ -197,6 +198,7 @@ impl RadixCiphertextConformanceParams {
 /// Can be used on a server to check if client inputs are well formed
 /// before running a computation on them
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
 pub struct CompactCiphertextListConformanceParams {
     pub shortint_params: CiphertextConformanceParams,
     pub num_elements_constraint: ListSizeConstraint,
@@ -1,5 +1,5 @@
 use crate::conformance::ParameterSetConformant;
-use crate::core_crypto::commons::traits::Container;
+use crate::core_crypto::commons::traits::{Container, ContainerMut};
 use crate::integer::backward_compatibility::public_key::{
     CompactPrivateKeyVersions, CompactPublicKeyVersions, CompressedCompactPublicKeyVersions,
 };
@@ -14,6 +14,7 @@ use crate::shortint::{
 };
 use serde::{Deserialize, Serialize};
 use tfhe_versionable::Versionize;
+use zeroize::Zeroize;
 
 #[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
 #[versionize(CompactPrivateKeyVersions)]
@@ -21,6 +22,12 @@ pub struct CompactPrivateKey<C: Container<Element = u64>> {
     pub(crate) key: ShortintCompactPrivateKey<C>,
 }
 
+impl<C: ContainerMut<Element = u64>> Zeroize for CompactPrivateKey<C> {
+    fn zeroize(&mut self) {
+        self.key.zeroize();
+    }
+}
+
 impl<C: Container<Element = u64>> CompactPrivateKey<C> {
     pub fn from_raw_parts(key: ShortintCompactPrivateKey<C>) -> Self {
         Self { key }
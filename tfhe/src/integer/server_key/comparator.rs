@@ -27,7 +27,7 @@ enum MinMaxSelector {
 /// `x` is actually two ordering values packed
 /// where in the first 2 lsb bits there is the ordering value of the less significant block,
 /// and the 2 msb bits of x contains the ordering of the more significant block.
-fn reduce_two_orderings_function(x: u64) -> u64 {
+pub(crate) fn reduce_two_orderings_function(x: u64) -> u64 {
     let msb = (x >> 2) & 3;
     let lsb = x & 3;
 
@@ -55,7 +55,7 @@ pub struct Comparator<'a> {
 
 impl<'a> Comparator<'a> {
     pub(crate) const IS_INFERIOR: u64 = 0;
-    const IS_EQUAL: u64 = 1;
+    pub(crate) const IS_EQUAL: u64 = 1;
     pub(crate) const IS_SUPERIOR: u64 = 2;
 
     /// Creates a new Comparator for the given ServerKey
@@ -213,7 +213,7 @@ impl<'a> Comparator<'a> {
     /// Reduces a vec containing shortint blocks that encrypts a sign
     /// (inferior, equal, superior) to one single shortint block containing the
     /// final sign
-    fn reduce_signs<F>(
+    pub(crate) fn reduce_signs<F>(
         &self,
         mut sign_blocks: Vec<Ciphertext>,
         sign_result_handler_fn: F,
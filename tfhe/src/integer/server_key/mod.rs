@@ -12,6 +12,7 @@ use super::backward_compatibility::server_key::{CompressedServerKeyVersions, Ser
 use crate::conformance::ParameterSetConformant;
 use crate::core_crypto::prelude::UnsignedInteger;
 use crate::integer::client_key::ClientKey;
+use crate::safe_serialization::{SerializedSizeBound, OBJECT_METADATA_SIZE_BOUND};
 use crate::shortint::ciphertext::{Degree, MaxDegree};
 /// Error returned when the carry buffer is full.
 pub use crate::shortint::CheckError;
@@ -108,6 +109,23 @@ impl ServerKey {
         Self { key: sks }
     }
 
+    /// Creates a radix server key for dry runs
+    ///
+    /// The key holds no key material, it can only compute on trivial ciphertexts, for which no
+    /// PBS is executed. Computing a PBS on a non-trivial ciphertext panics.
+    pub(crate) fn new_dry_run_radix_server_key(parameters: crate::shortint::PBSParameters) -> Self {
+        let max_degree = MaxDegree::integer_radix_server_key(
+            parameters.message_modulus(),
+            parameters.carry_modulus(),
+        );
+
+        let sks = crate::shortint::server_key::ServerKey::new_dry_run_with_max_degree(
+            parameters, max_degree,
+        );
+
+        Self { key: sks }
+    }
+
     pub fn new_crt_server_key<C>(cks: C) -> Self
     where
         C: AsRef<ClientKey>,
@@ -312,6 +330,18 @@ impl ParameterSetConformant for ServerKey {
     }
 }
 
+impl SerializedSizeBound for ServerKey {
+    fn serialized_size_bound(parameter_set: &PBSParameters) -> u64 {
+        let expected_max_degree = MaxDegree::integer_radix_server_key(
+            parameter_set.message_modulus(),
+            parameter_set.carry_modulus(),
+        );
+
+        crate::shortint::ServerKey::serialized_size_bound(&(*parameter_set, expected_max_degree))
+            + OBJECT_METADATA_SIZE_BOUND
+    }
+}
+
 impl ParameterSetConformant for CompressedServerKey {
     type ParameterSet = PBSParameters;
 
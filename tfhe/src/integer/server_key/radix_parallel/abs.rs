@@ -1,5 +1,5 @@
 use crate::integer::ciphertext::IntegerRadixCiphertext;
-use crate::integer::ServerKey;
+use crate::integer::{RadixCiphertext, ServerKey, SignedRadixCiphertext};
 
 impl ServerKey {
     pub fn unchecked_abs_parallelized<T>(&self, ct: &T) -> T
@@ -45,4 +45,165 @@ impl ServerKey {
             self.unchecked_abs_parallelized(&cloned)
         }
     }
+
+    /// Computes homomorphically the sign of `ct`
+    ///
+    /// The result encrypts -1 if `ct` is negative, 0 if it is zero, and 1 if it is positive, like
+    /// [i64::signum]. For unsigned ciphertexts the result is 0 or 1.
+    ///
+    /// Only the sign bit and whether `ct` is zero are computed, the result blocks are then built
+    /// with a single layer of PBS, which is cheaper than comparing `ct` with 0 twice.
+    ///
+    /// # Notes
+    ///
+    /// - ct must not have any carries
+    pub fn unchecked_signum_parallelized<T>(&self, ct: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let num_blocks = ct.blocks().len();
+        if num_blocks == 0 {
+            return ct.clone();
+        }
+
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let bits_per_block = message_modulus.ilog2();
+
+        let (is_negative, is_not_zero) = rayon::join(
+            || {
+                if T::IS_SIGNED {
+                    let lut = self
+                        .key
+                        .generate_lookup_table(|x| (x >> (bits_per_block - 1)) & 1);
+                    self.key
+                        .apply_lookup_table(&ct.blocks()[num_blocks - 1], &lut)
+                } else {
+                    self.key.create_trivial(0)
+                }
+            },
+            || self.unchecked_scalar_ne_parallelized(ct, 0u64),
+        );
+
+        // -1 has all its bits set, 1 only has its least significant bit set
+        let (least_significant_block, other_block) = rayon::join(
+            || {
+                let lut = self
+                    .key
+                    .generate_lookup_table_bivariate(|is_negative, is_not_zero| {
+                        if is_negative == 1 {
+                            message_modulus - 1
+                        } else {
+                            is_not_zero
+                        }
+                    });
+                self.key
+                    .unchecked_apply_lookup_table_bivariate(&is_negative, &is_not_zero.0, &lut)
+            },
+            || {
+                let lut = self
+                    .key
+                    .generate_lookup_table(|is_negative| is_negative * (message_modulus - 1));
+                self.key.apply_lookup_table(&is_negative, &lut)
+            },
+        );
+
+        let mut blocks = Vec::with_capacity(num_blocks);
+        blocks.push(least_significant_block);
+        blocks.resize(num_blocks, other_block);
+        T::from_blocks(blocks)
+    }
+
+    /// Computes homomorphically the sign of `ct`
+    ///
+    /// The result encrypts -1 if `ct` is negative, 0 if it is zero, and 1 if it is positive, like
+    /// [i64::signum]. For unsigned ciphertexts the result is 0 or 1.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertext block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// for msg in [-37i8, 0, 100] {
+    ///     let ct = cks.encrypt_signed(msg);
+    ///
+    ///     let ct_res = sks.signum_parallelized(&ct);
+    ///
+    ///     let dec: i8 = cks.decrypt_signed(&ct_res);
+    ///     assert_eq!(dec, msg.signum());
+    /// }
+    /// ```
+    pub fn signum_parallelized<T>(&self, ct: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if ct.block_carries_are_empty() {
+            self.unchecked_signum_parallelized(ct)
+        } else {
+            let mut cloned = ct.clone();
+            self.full_propagate_parallelized(&mut cloned);
+            self.unchecked_signum_parallelized(&cloned)
+        }
+    }
+
+    /// Computes homomorphically the absolute value of a signed ciphertext, as an unsigned
+    /// ciphertext with the same number of blocks
+    ///
+    /// Unlike [Self::unchecked_abs_parallelized], the absolute value of the minimum value can be
+    /// represented, like with [i64::unsigned_abs].
+    ///
+    /// # Notes
+    ///
+    /// - ct must not have any carries
+    pub fn unchecked_unsigned_abs_parallelized(
+        &self,
+        ct: &SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        // The wrapping absolute value of the minimum is the minimum itself, whose bits are
+        // the ones of its absolute value as an unsigned integer
+        let abs = self.unchecked_abs_parallelized(ct);
+        RadixCiphertext::from_blocks(abs.into_blocks())
+    }
+
+    /// Computes homomorphically the absolute value of a signed ciphertext, as an unsigned
+    /// ciphertext with the same number of blocks
+    ///
+    /// Unlike [Self::abs_parallelized], the absolute value of the minimum value can be
+    /// represented, like with [i64::unsigned_abs].
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertext block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let ct = cks.encrypt_signed(i8::MIN);
+    ///
+    /// let ct_res = sks.unsigned_abs_parallelized(&ct);
+    ///
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, i8::MIN.unsigned_abs());
+    /// ```
+    pub fn unsigned_abs_parallelized(&self, ct: &SignedRadixCiphertext) -> RadixCiphertext {
+        let abs = self.abs_parallelized(ct);
+        RadixCiphertext::from_blocks(abs.into_blocks())
+    }
 }
@@ -1,5 +1,6 @@
 use crate::integer::ciphertext::IntegerRadixCiphertext;
 use crate::integer::ServerKey;
+use crate::shortint::ciphertext::Degree;
 use rayon::prelude::*;
 
 impl ServerKey {
@@ -555,4 +556,138 @@ impl ServerKey {
             .iter_mut()
             .for_each(|block| self.key.bitnot_assign(block));
     }
+
+    /// Computes homomorphically the bitwise selection `(ct_true & mask) | (ct_false & !mask)`,
+    /// i.e. each bit of the result is taken from `ct_true` when the corresponding bit of `mask`
+    /// is set, and from `ct_false` otherwise.
+    ///
+    /// All the blocks are computed in a single parallel pass: for each block, the two masked
+    /// values are computed with one PBS each. As their bits are disjoint, they are combined by
+    /// a simple addition, which does not create any carry.
+    ///
+    /// # Notes
+    ///
+    /// - the input ciphertexts must not have any carries
+    /// - the input ciphertexts must have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg_true = 14u64;
+    /// let msg_false = 97u64;
+    /// let msg_mask = 0b1111_0000u64;
+    ///
+    /// let ct_true = cks.encrypt(msg_true);
+    /// let ct_false = cks.encrypt(msg_false);
+    /// let ct_mask = cks.encrypt(msg_mask);
+    ///
+    /// let ct_res = sks.unchecked_merge_by_mask_parallelized(&ct_true, &ct_false, &ct_mask);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(
+    ///     dec_result,
+    ///     (msg_true & msg_mask) | (msg_false & !msg_mask & 0xFF)
+    /// );
+    /// ```
+    pub fn unchecked_merge_by_mask_parallelized<T>(&self, ct_true: &T, ct_false: &T, mask: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert_eq!(
+            ct_true.blocks().len(),
+            ct_false.blocks().len(),
+            "Both ciphertexts must have the same number of blocks"
+        );
+        assert_eq!(
+            ct_true.blocks().len(),
+            mask.blocks().len(),
+            "The mask must have the same number of blocks as the ciphertexts"
+        );
+
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let and_lut = self.key.generate_lookup_table_bivariate(|x, m| x & m);
+        let and_not_lut = self
+            .key
+            .generate_lookup_table_bivariate(|x, m| x & (!m % message_modulus));
+
+        let blocks = ct_true
+            .blocks()
+            .par_iter()
+            .zip(ct_false.blocks().par_iter())
+            .zip(mask.blocks().par_iter())
+            .map(|((block_true, block_false), block_mask)| {
+                let (mut selected_true, selected_false) = rayon::join(
+                    || {
+                        self.key.unchecked_apply_lookup_table_bivariate(
+                            block_true, block_mask, &and_lut,
+                        )
+                    },
+                    || {
+                        self.key.unchecked_apply_lookup_table_bivariate(
+                            block_false,
+                            block_mask,
+                            &and_not_lut,
+                        )
+                    },
+                );
+
+                self.key
+                    .unchecked_add_assign(&mut selected_true, &selected_false);
+                // The two values have disjoint bits, so their sum fits in the message
+                selected_true.degree = Degree::new(message_modulus as usize - 1);
+                selected_true
+            })
+            .collect::<Vec<_>>();
+
+        T::from_blocks(blocks)
+    }
+
+    /// Computes homomorphically the bitwise selection `(ct_true & mask) | (ct_false & !mask)`.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// See [Self::unchecked_merge_by_mask_parallelized]
+    pub fn merge_by_mask_parallelized<T>(&self, ct_true: &T, ct_false: &T, mask: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_true;
+        let mut tmp_false;
+        let mut tmp_mask;
+
+        let ct_true = if ct_true.block_carries_are_empty() {
+            ct_true
+        } else {
+            tmp_true = ct_true.clone();
+            self.full_propagate_parallelized(&mut tmp_true);
+            &tmp_true
+        };
+        let ct_false = if ct_false.block_carries_are_empty() {
+            ct_false
+        } else {
+            tmp_false = ct_false.clone();
+            self.full_propagate_parallelized(&mut tmp_false);
+            &tmp_false
+        };
+        let mask = if mask.block_carries_are_empty() {
+            mask
+        } else {
+            tmp_mask = mask.clone();
+            self.full_propagate_parallelized(&mut tmp_mask);
+            &tmp_mask
+        };
+
+        self.unchecked_merge_by_mask_parallelized(ct_true, ct_false, mask)
+    }
 }
@@ -0,0 +1,234 @@
+use crate::integer::block_decomposition::DecomposableInto;
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::{BooleanBlock, ServerKey};
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Selects `min` if `is_below` encrypts true, else `max` if `is_above` encrypts true,
+    /// and `ct` otherwise
+    ///
+    /// When blocks can hold at least 2 bits, both conditions are packed in a single block
+    /// without any PBS, and each of the three candidates is zeroed out from that block in the
+    /// same layer of PBS. This is one layer of PBS less than chaining two cmux.
+    fn select_clamped<T>(
+        &self,
+        ct: &T,
+        min: &T,
+        max: &T,
+        is_below: BooleanBlock,
+        is_above: BooleanBlock,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if self.message_modulus().0 < 4 {
+            let is_above_or_ct = self.unchecked_if_then_else_parallelized(&is_above, max, ct);
+            return self.unchecked_if_then_else_parallelized(&is_below, min, &is_above_or_ct);
+        }
+
+        // case = is_below + 2 * is_above, in [0, 3]
+        let mut case = is_above.0;
+        self.key.unchecked_scalar_mul_assign(&mut case, 2);
+        self.key.unchecked_add_assign(&mut case, &is_below.0);
+
+        let zeroed_out_unless = |ct: &T, keep: fn(u64) -> bool| {
+            let mut ct = ct.clone();
+            self.zero_out_if(&mut ct, &case, |value| !keep(value));
+            ct
+        };
+
+        let (mut result, (min, max)) = rayon::join(
+            || zeroed_out_unless(ct, |case| case == 0),
+            || {
+                rayon::join(
+                    // When both conditions are true, min is selected
+                    || zeroed_out_unless(min, |case| case & 1 == 1),
+                    || zeroed_out_unless(max, |case| case == 2),
+                )
+            },
+        );
+
+        // Only one of the three candidates was kept, the others are zeros
+        result
+            .blocks_mut()
+            .par_iter_mut()
+            .zip(min.blocks().par_iter().zip(max.blocks().par_iter()))
+            .for_each(|(block, (min_block, max_block))| {
+                self.key.unchecked_add_assign(block, min_block);
+                self.key.unchecked_add_assign(block, max_block);
+                self.key.message_extract_assign(block);
+            });
+
+        result
+    }
+
+    /// Clamps `ct` between `min` and `max`
+    ///
+    /// Returns `min` if `ct < min`, `max` if `ct > max` and `ct` otherwise.
+    /// If `min > max`, `min` is returned when `ct < min`, and `max` otherwise.
+    ///
+    /// Both comparisons are computed in parallel and then fused in a single selection,
+    /// instead of computing a max and then a min.
+    ///
+    /// * inputs must have the same number of blocks
+    /// * block carries of all inputs must be empty
+    pub fn unchecked_clamp_parallelized<T>(&self, ct: &T, min: &T, max: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let (is_below, is_above) = rayon::join(
+            || self.unchecked_lt_parallelized(ct, min),
+            || self.unchecked_gt_parallelized(ct, max),
+        );
+
+        self.select_clamped(ct, min, max, is_below, is_above)
+    }
+
+    /// Clamps `ct` between `min` and `max`
+    ///
+    /// Returns `min` if `ct < min`, `max` if `ct > max` and `ct` otherwise.
+    /// If `min > max`, `min` is returned when `ct < min`, and `max` otherwise.
+    ///
+    /// Both comparisons are computed in parallel and then fused in a single selection,
+    /// instead of computing a max and then a min.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// This means that when using only "default" operations, a given operation (like add for
+    /// example) has always the same performance characteristics from one call to another and
+    /// guarantees correctness by pre-emptively clearing carries of output ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let ct = cks.encrypt(200u64);
+    /// let min = cks.encrypt(10u64);
+    /// let max = cks.encrypt(100u64);
+    ///
+    /// let ct_res = sks.clamp_parallelized(&ct, &min, &max);
+    ///
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 200u64.clamp(10, 100));
+    /// ```
+    pub fn clamp_parallelized<T>(&self, ct: &T, min: &T, max: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_ct = None;
+        let mut tmp_min = None;
+        let mut tmp_max = None;
+
+        let propagated = |input: &T, tmp: &mut Option<T>| {
+            if !input.block_carries_are_empty() {
+                let tmp = tmp.insert(input.clone());
+                self.full_propagate_parallelized(tmp);
+            }
+        };
+        rayon::join(
+            || propagated(ct, &mut tmp_ct),
+            || {
+                rayon::join(
+                    || propagated(min, &mut tmp_min),
+                    || propagated(max, &mut tmp_max),
+                )
+            },
+        );
+
+        self.unchecked_clamp_parallelized(
+            tmp_ct.as_ref().unwrap_or(ct),
+            tmp_min.as_ref().unwrap_or(min),
+            tmp_max.as_ref().unwrap_or(max),
+        )
+    }
+
+    /// Clamps `ct` between the clear values `min` and `max`
+    ///
+    /// Returns `min` if `ct < min`, `max` if `ct > max` and `ct` otherwise.
+    /// If `min > max`, `min` is returned when `ct < min`, and `max` otherwise.
+    ///
+    /// * block carries of `ct` must be empty
+    pub fn unchecked_scalar_clamp_parallelized<T, Scalar>(
+        &self,
+        ct: &T,
+        min: Scalar,
+        max: Scalar,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+        Scalar: DecomposableInto<u64>,
+    {
+        let num_blocks = ct.blocks().len();
+        let ((is_below, is_above), (min, max)) = rayon::join(
+            || {
+                rayon::join(
+                    || self.unchecked_scalar_lt_parallelized(ct, min),
+                    || self.unchecked_scalar_gt_parallelized(ct, max),
+                )
+            },
+            || {
+                (
+                    self.create_trivial_radix(min, num_blocks),
+                    self.create_trivial_radix(max, num_blocks),
+                )
+            },
+        );
+
+        self.select_clamped(ct, &min, &max, is_below, is_above)
+    }
+
+    /// Clamps `ct` between the clear values `min` and `max`
+    ///
+    /// Returns `min` if `ct < min`, `max` if `ct > max` and `ct` otherwise.
+    /// If `min > max`, `min` is returned when `ct < min`, and `max` otherwise.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertext block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// This means that when using only "default" operations, a given operation (like add for
+    /// example) has always the same performance characteristics from one call to another and
+    /// guarantees correctness by pre-emptively clearing carries of output ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let ct = cks.encrypt_signed(-100i64);
+    ///
+    /// let ct_res = sks.scalar_clamp_parallelized(&ct, -10i64, 10i64);
+    ///
+    /// let dec_result: i64 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, (-100i64).clamp(-10, 10));
+    /// ```
+    pub fn scalar_clamp_parallelized<T, Scalar>(&self, ct: &T, min: Scalar, max: Scalar) -> T
+    where
+        T: IntegerRadixCiphertext,
+        Scalar: DecomposableInto<u64>,
+    {
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+
+        self.unchecked_scalar_clamp_parallelized(ct, min, max)
+    }
+}
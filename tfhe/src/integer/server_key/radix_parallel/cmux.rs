@@ -307,6 +307,171 @@ impl ServerKey {
         self.smart_if_then_else_parallelized(condition, true_ct, false_ct)
     }
 
+    /// Element-wise "if then else" selection over slices, with a single condition
+    ///
+    /// Returns a vector where the element `i` encrypts the same value as `true_cts[i]` if
+    /// `condition` is true, and as `false_cts[i]` otherwise.
+    ///
+    /// This is equivalent to calling [Self::unchecked_if_then_else_parallelized] on each pair,
+    /// but the lookup tables depending on the condition are only generated once, and the blocks
+    /// of all the elements are selected in a single parallel batch.
+    ///
+    /// # Notes
+    ///
+    /// - the ciphertexts must not have any carries
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices do not have the same length, or if two ciphertexts of the same
+    /// index do not have the same number of blocks
+    pub fn unchecked_if_then_else_slice_parallelized<T>(
+        &self,
+        condition: &BooleanBlock,
+        true_cts: &[T],
+        false_cts: &[T],
+    ) -> Vec<T>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert_eq!(
+            true_cts.len(),
+            false_cts.len(),
+            "Both slices must have the same length"
+        );
+        assert!(
+            true_cts
+                .iter()
+                .zip(false_cts.iter())
+                .all(|(true_ct, false_ct)| true_ct.blocks().len() == false_ct.blocks().len()),
+            "Ciphertexts of the same index must have the same number of blocks"
+        );
+
+        let condition_block = &condition.0;
+        assert!(condition_block.degree.get() < condition_block.message_modulus.0);
+
+        if condition_block.degree.get() == 0 {
+            // The condition 'encrypts' false, and only false
+            return false_cts.to_vec();
+        }
+
+        let keep_if_true =
+            self.key.generate_lookup_table_bivariate(
+                |block, condition| {
+                    if condition == 1 {
+                        block
+                    } else {
+                        0
+                    }
+                },
+            );
+        let keep_if_false =
+            self.key.generate_lookup_table_bivariate(
+                |block, condition| {
+                    if condition == 1 {
+                        0
+                    } else {
+                        block
+                    }
+                },
+            );
+
+        let mut results = true_cts.to_vec();
+        results
+            .par_iter_mut()
+            .zip(false_cts.par_iter())
+            .flat_map(|(true_ct, false_ct)| {
+                true_ct
+                    .blocks_mut()
+                    .par_iter_mut()
+                    .zip(false_ct.blocks().par_iter())
+            })
+            .for_each(|(true_block, false_block)| {
+                // Blocks with a degree of 0 already encrypt 0, they do not need to be zeroed out
+                let (_, false_block) = rayon::join(
+                    || {
+                        if true_block.degree.get() != 0 {
+                            self.key.unchecked_apply_lookup_table_bivariate_assign(
+                                true_block,
+                                condition_block,
+                                &keep_if_true,
+                            );
+                        }
+                    },
+                    || {
+                        (false_block.degree.get() != 0).then(|| {
+                            self.key.unchecked_apply_lookup_table_bivariate(
+                                false_block,
+                                condition_block,
+                                &keep_if_false,
+                            )
+                        })
+                    },
+                );
+
+                if let Some(false_block) = false_block {
+                    self.key.unchecked_add_assign(true_block, &false_block);
+                }
+                self.key.message_extract_assign(true_block);
+            });
+
+        results
+    }
+
+    /// Element-wise "if then else" selection over slices, with a single condition
+    ///
+    /// Returns a vector where the element `i` encrypts the same value as `true_cts[i]` if
+    /// `condition` is true, and as `false_cts[i]` otherwise.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs ciphertexts whose block carries are always
+    /// empty.
+    ///
+    /// See [Self::unchecked_if_then_else_slice_parallelized]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let a = [1u8, 2, 3];
+    /// let b = [4u8, 5, 6];
+    ///
+    /// let ct_a = a.iter().map(|&v| cks.encrypt(v)).collect::<Vec<_>>();
+    /// let ct_b = b.iter().map(|&v| cks.encrypt(v)).collect::<Vec<_>>();
+    ///
+    /// let condition = cks.encrypt_bool(false);
+    ///
+    /// let ct_res = sks.if_then_else_slice_parallelized(&condition, &ct_a, &ct_b);
+    ///
+    /// // Decrypt:
+    /// let dec = ct_res.iter().map(|ct| cks.decrypt(ct)).collect::<Vec<u8>>();
+    /// assert_eq!(dec, b);
+    /// ```
+    pub fn if_then_else_slice_parallelized<T>(
+        &self,
+        condition: &BooleanBlock,
+        true_cts: &[T],
+        false_cts: &[T],
+    ) -> Vec<T>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut true_clones = vec![];
+        let mut false_clones = vec![];
+        let (true_cts, false_cts) = rayon::join(
+            || self.carry_free_slice(true_cts, &mut true_clones),
+            || self.carry_free_slice(false_cts, &mut false_clones),
+        );
+
+        self.unchecked_if_then_else_slice_parallelized(condition, true_cts, false_cts)
+    }
+
     /// if do clean message is false, the resulting ciphertext won't be cleaned (message_extract)
     /// meaning that yes, the resulting ciphertext's encrypted message is within 0..msg_msg
     /// but its degree is the same as after adding to ciphertext
@@ -112,7 +112,7 @@ impl ServerKey {
     /// * inputs must have the same number of blocks
     /// * block carries of both inputs must be empty
     /// * carry modulus == message modulus
-    fn compare<T>(&self, a: &T, b: &T, compare: ComparisonKind) -> BooleanBlock
+    pub(super) fn compare<T>(&self, a: &T, b: &T, compare: ComparisonKind) -> BooleanBlock
     where
         T: IntegerRadixCiphertext,
     {
@@ -1060,4 +1060,62 @@ impl ServerKey {
 
         self.unchecked_min_parallelized(lhs, rhs)
     }
+
+    /// Computes the max of `lhs` and `rhs`, and assigns it to `lhs`.
+    ///
+    /// The carries of `lhs` are propagated in place, so unlike [Self::max_parallelized] it
+    /// is never cloned.
+    pub fn max_assign_parallelized<T>(&self, lhs: &mut T, rhs: &T)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_rhs = None;
+        let rhs = self.propagate_min_max_operands(lhs, rhs, &mut tmp_rhs);
+
+        *lhs = self.unchecked_max_parallelized(lhs, rhs);
+    }
+
+    /// Computes the min of `lhs` and `rhs`, and assigns it to `lhs`.
+    ///
+    /// The carries of `lhs` are propagated in place, so unlike [Self::min_parallelized] it
+    /// is never cloned.
+    pub fn min_assign_parallelized<T>(&self, lhs: &mut T, rhs: &T)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_rhs = None;
+        let rhs = self.propagate_min_max_operands(lhs, rhs, &mut tmp_rhs);
+
+        *lhs = self.unchecked_min_parallelized(lhs, rhs);
+    }
+
+    /// Propagates the carries of `lhs` in place and those of `rhs` in `tmp_rhs` if needed,
+    /// returning the `rhs` to use
+    fn propagate_min_max_operands<'a, T>(
+        &self,
+        lhs: &mut T,
+        rhs: &'a T,
+        tmp_rhs: &'a mut Option<T>,
+    ) -> &'a T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if rhs.block_carries_are_empty() {
+            if !lhs.block_carries_are_empty() {
+                self.full_propagate_parallelized(lhs);
+            }
+            return rhs;
+        }
+
+        let tmp_rhs = tmp_rhs.insert(rhs.clone());
+        rayon::join(
+            || {
+                if !lhs.block_carries_are_empty() {
+                    self.full_propagate_parallelized(lhs);
+                }
+            },
+            || self.full_propagate_parallelized(tmp_rhs),
+        );
+        tmp_rhs
+    }
 }
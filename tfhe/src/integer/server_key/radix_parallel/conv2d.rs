@@ -0,0 +1,162 @@
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::ServerKey;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Computes the 2D convolution of an encrypted image with a clear kernel.
+    ///
+    /// The image is given in row-major order with `width` pixels per row, and is surrounded by
+    /// `padding` rows and columns of zeros. The kernel slides over the padded image by steps of
+    /// `stride` pixels and, as in deep learning frameworks, is not flipped. The output is in
+    /// row-major order, it has `(height + 2 * padding - kernel_height) / stride + 1` rows of
+    /// `(width + 2 * padding - kernel_width) / stride + 1` pixels.
+    ///
+    /// Each output pixel is the sum of the products of the kernel coefficients with the patch of
+    /// pixels under the kernel, computed modulo the ciphertext modulus. The patches are laid out
+    /// as the rows of a sparse clear matrix (im2col), so that the shifts of a pixel needed by the
+    /// kernel coefficients are computed once and reused by all the patches it belongs to. The
+    /// terms of a patch are accumulated without propagating carries, which are propagated once
+    /// per output pixel. Coefficients over the padding are skipped, the padding is never
+    /// encrypted.
+    ///
+    /// # Notes
+    ///
+    /// - ciphertexts of the image must not have any carries
+    /// - ciphertexts of the image must all have the same number of blocks
+    ///
+    /// # Panics
+    ///
+    /// - if the image is empty or its length is not a multiple of `width`
+    /// - if the kernel is empty or its rows do not all have the same non zero length
+    /// - if `stride` is zero
+    /// - if the kernel is larger than the padded image
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// // 3x3 image
+    /// let image = [1i64, 2, 3, 4, 5, 6, 7, 8, 9]
+    ///     .iter()
+    ///     .map(|&x| cks.encrypt_signed(x))
+    ///     .collect::<Vec<_>>();
+    /// let kernel = [[2i64, 0], [0, -1]];
+    ///
+    /// let result = sks.unchecked_clear_conv2d_parallelized(&image, 3, &kernel, 1, 0);
+    ///
+    /// // 2x2 output
+    /// let decrypted = result
+    ///     .iter()
+    ///     .map(|ct| cks.decrypt_signed::<i64>(ct))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(decrypted, vec![-3, -2, 0, 1]);
+    /// ```
+    pub fn unchecked_clear_conv2d_parallelized<T, Row>(
+        &self,
+        image: &[T],
+        width: usize,
+        kernel: &[Row],
+        stride: usize,
+        padding: usize,
+    ) -> Vec<T>
+    where
+        T: IntegerRadixCiphertext,
+        Row: AsRef<[i64]>,
+    {
+        assert!(
+            !image.is_empty() && width != 0 && image.len() % width == 0,
+            "The image must not be empty and its length must be a multiple of its width"
+        );
+        let height = image.len() / width;
+
+        assert!(!kernel.is_empty(), "The kernel must not be empty");
+        let kernel_height = kernel.len();
+        let kernel_width = kernel[0].as_ref().len();
+        assert!(
+            kernel_width != 0 && kernel.iter().all(|row| row.as_ref().len() == kernel_width),
+            "The kernel rows must all have the same non zero length"
+        );
+
+        assert_ne!(stride, 0, "The stride must not be zero");
+        assert!(
+            kernel_height <= height + 2 * padding && kernel_width <= width + 2 * padding,
+            "The kernel ({kernel_height}x{kernel_width}) must not be larger than the padded image \
+            ({}x{})",
+            height + 2 * padding,
+            width + 2 * padding,
+        );
+
+        let output_height = (height + 2 * padding - kernel_height) / stride + 1;
+        let output_width = (width + 2 * padding - kernel_width) / stride + 1;
+
+        // Index in the image of the pixel at the given coordinates of the padded image, if it is
+        // not in the padding
+        let pixel_index = |padded_y: usize, padded_x: usize| {
+            let y = padded_y.checked_sub(padding).filter(|&y| y < height)?;
+            let x = padded_x.checked_sub(padding).filter(|&x| x < width)?;
+            Some(y * width + x)
+        };
+
+        // One row of (pixel index, coefficient) per output pixel
+        let patches = (0..output_height)
+            .flat_map(|output_y| (0..output_width).map(move |output_x| (output_y, output_x)))
+            .map(|(output_y, output_x)| {
+                let mut patch = Vec::with_capacity(kernel_height * kernel_width);
+                for (kernel_y, kernel_row) in kernel.iter().enumerate() {
+                    for (kernel_x, &coefficient) in kernel_row.as_ref().iter().enumerate() {
+                        let index =
+                            pixel_index(output_y * stride + kernel_y, output_x * stride + kernel_x);
+                        if let Some(index) = index.filter(|_| coefficient != 0) {
+                            patch.push((index, coefficient));
+                        }
+                    }
+                }
+                patch
+            })
+            .collect::<Vec<_>>();
+
+        self.unchecked_clear_sparse_matrix_mul_enc_vector_parallelized(&patches, image)
+    }
+
+    /// Computes the 2D convolution of an encrypted image with a clear kernel.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs ciphertexts whose block carries are always
+    /// empty.
+    ///
+    /// See [Self::unchecked_clear_conv2d_parallelized]
+    pub fn clear_conv2d_parallelized<T, Row>(
+        &self,
+        image: &[T],
+        width: usize,
+        kernel: &[Row],
+        stride: usize,
+        padding: usize,
+    ) -> Vec<T>
+    where
+        T: IntegerRadixCiphertext,
+        Row: AsRef<[i64]>,
+    {
+        let mut tmp_image;
+
+        let image = if image.iter().any(|ct| !ct.block_carries_are_empty()) {
+            tmp_image = image.to_vec();
+            tmp_image
+                .par_iter_mut()
+                .filter(|ct| !ct.block_carries_are_empty())
+                .for_each(|ct| self.full_propagate_parallelized(ct));
+            &tmp_image
+        } else {
+            image
+        };
+
+        self.unchecked_clear_conv2d_parallelized(image, width, kernel, stride, padding)
+    }
+}
@@ -1,5 +1,7 @@
 use super::ServerKey;
-use crate::integer::{IntegerRadixCiphertext, RadixCiphertext, SignedRadixCiphertext};
+use crate::integer::{
+    BooleanBlock, IntegerRadixCiphertext, RadixCiphertext, SignedRadixCiphertext,
+};
 use crate::shortint::ciphertext::Degree;
 
 use rayon::prelude::*;
@@ -126,6 +128,158 @@ impl ServerKey {
         self.unchecked_count_bits_parallelized(ct, kind)
     }
 
+    /// Returns the number of bits that differ between `lhs` and `rhs`
+    ///
+    /// This is the number of ones in the binary representation of `lhs ^ rhs`, when the
+    /// parameters allow it, the xor and the count of ones of each pair of blocks are fused
+    /// in a single bivariate PBS.
+    ///
+    /// * lhs and rhs must not have any carries
+    /// * The returned result has enough blocks to encrypt 32bits (e.g. 1_1 parameters -> 32 blocks,
+    ///   3_3 parameters -> 11 blocks == 33 bits)
+    ///
+    /// # Panics
+    ///
+    /// Panics if lhs and rhs do not have the same number of blocks
+    pub fn unchecked_hamming_distance_parallelized<T>(&self, lhs: &T, rhs: &T) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert_eq!(
+            lhs.blocks().len(),
+            rhs.blocks().len(),
+            "lhs and rhs must have the same number of blocks"
+        );
+
+        // A bivariate PBS needs the carry space to hold a copy of the message
+        if self.carry_modulus().0 < self.message_modulus().0 {
+            let xored = self.unchecked_bitxor_parallelized(lhs, rhs);
+            return self.unchecked_count_ones_parallelized(&xored);
+        }
+
+        let num_bits_in_block = self.message_modulus().0.ilog2();
+        let min_num_blocks_to_have_32_bits = 32u32.div_ceil(num_bits_in_block) as usize;
+        if lhs.blocks().is_empty() {
+            return self.create_trivial_zero_radix(min_num_blocks_to_have_32_bits);
+        }
+
+        let max_possible_bit_count = num_bits_in_block
+            .checked_mul(lhs.blocks().len() as u32)
+            .expect("Number of bits exceed u32::MAX");
+        let num_unsigned_blocks =
+            self.num_blocks_to_represent_unsigned_value(max_possible_bit_count);
+
+        let lut = self
+            .key
+            .generate_lookup_table_bivariate(|x, y| u64::from((x ^ y).count_ones()));
+        let things_to_sum = lhs
+            .blocks()
+            .par_iter()
+            .zip(rhs.blocks().par_iter())
+            .map(|(lhs_block, rhs_block)| {
+                let count = self
+                    .key
+                    .unchecked_apply_lookup_table_bivariate(lhs_block, rhs_block, &lut);
+                self.cast_to_unsigned(RadixCiphertext::from(vec![count]), num_unsigned_blocks)
+            })
+            .collect::<Vec<_>>();
+
+        let result = self
+            .unchecked_sum_ciphertexts_vec_parallelized(things_to_sum)
+            .unwrap_or_else(|| self.create_trivial_zero_radix(min_num_blocks_to_have_32_bits));
+
+        self.cast_to_unsigned(result, min_num_blocks_to_have_32_bits)
+    }
+
+    /// Returns the number of bits that differ between `lhs` and `rhs`
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// * The returned result has enough blocks to encrypt 32bits (e.g. 1_1 parameters -> 32 blocks,
+    ///   3_3 parameters -> 11 blocks == 33 bits)
+    ///
+    /// # Panics
+    ///
+    /// Panics if lhs and rhs do not have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let clear_a = 0b1011_0110u64;
+    /// let clear_b = 0b0011_1100u64;
+    /// let a = cks.encrypt(clear_a);
+    /// let b = cks.encrypt(clear_b);
+    ///
+    /// let distance = sks.hamming_distance_parallelized(&a, &b);
+    /// let is_close = sks.is_within_hamming_distance_parallelized(&a, &b, 3);
+    ///
+    /// let decrypted: u64 = cks.decrypt(&distance);
+    /// assert_eq!(decrypted, u64::from((clear_a ^ clear_b).count_ones()));
+    /// assert!(cks.decrypt_bool(&is_close));
+    /// ```
+    pub fn hamming_distance_parallelized<T>(&self, lhs: &T, rhs: &T) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_lhs;
+        let mut tmp_rhs;
+
+        let (lhs, rhs) = match (lhs.block_carries_are_empty(), rhs.block_carries_are_empty()) {
+            (true, true) => (lhs, rhs),
+            (true, false) => {
+                tmp_rhs = rhs.clone();
+                self.full_propagate_parallelized(&mut tmp_rhs);
+                (lhs, &tmp_rhs)
+            }
+            (false, true) => {
+                tmp_lhs = lhs.clone();
+                self.full_propagate_parallelized(&mut tmp_lhs);
+                (&tmp_lhs, rhs)
+            }
+            (false, false) => {
+                tmp_lhs = lhs.clone();
+                tmp_rhs = rhs.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_lhs),
+                    || self.full_propagate_parallelized(&mut tmp_rhs),
+                );
+                (&tmp_lhs, &tmp_rhs)
+            }
+        };
+
+        self.unchecked_hamming_distance_parallelized(lhs, rhs)
+    }
+
+    /// Returns whether `lhs` and `rhs` differ by at most `max_distance` bits
+    ///
+    /// See [Self::hamming_distance_parallelized]
+    ///
+    /// # Panics
+    ///
+    /// Panics if lhs and rhs do not have the same number of blocks
+    pub fn is_within_hamming_distance_parallelized<T>(
+        &self,
+        lhs: &T,
+        rhs: &T,
+        max_distance: u32,
+    ) -> BooleanBlock
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let distance = self.hamming_distance_parallelized(lhs, rhs);
+        self.unchecked_scalar_le_parallelized(&distance, max_distance)
+    }
+
     /// 'Naive' implementation of count zeros/ones
     ///
     /// * It will work for all parameters
@@ -88,6 +88,235 @@ impl ServerKey {
         (quotient, remainder)
     }
 
+    /// Computes homomorphically the quotient and remainder of the flooring division of two
+    /// signed ciphertexts.
+    ///
+    /// The quotient is rounded towards negative infinity, so the remainder has the sign of the
+    /// divisor.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs ciphertexts whose block carries are always
+    /// empty.
+    ///
+    /// When the divisor is 0, the result should not be relied on.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let ct1 = cks.encrypt_signed(-7i8);
+    /// let ct2 = cks.encrypt_signed(3i8);
+    ///
+    /// let (q_res, r_res) = sks.div_rem_floor_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let q: i8 = cks.decrypt_signed(&q_res);
+    /// let r: i8 = cks.decrypt_signed(&r_res);
+    /// assert_eq!(q, -3);
+    /// assert_eq!(r, 2);
+    /// ```
+    pub fn div_rem_floor_parallelized(
+        &self,
+        numerator: &SignedRadixCiphertext,
+        divisor: &SignedRadixCiphertext,
+    ) -> (SignedRadixCiphertext, SignedRadixCiphertext) {
+        let mut tmp_numerator;
+        let mut tmp_divisor;
+
+        let (numerator, divisor) = match (
+            numerator.block_carries_are_empty(),
+            divisor.block_carries_are_empty(),
+        ) {
+            (true, true) => (numerator, divisor),
+            (true, false) => {
+                tmp_divisor = divisor.clone();
+                self.full_propagate_parallelized(&mut tmp_divisor);
+                (numerator, &tmp_divisor)
+            }
+            (false, true) => {
+                tmp_numerator = numerator.clone();
+                self.full_propagate_parallelized(&mut tmp_numerator);
+                (&tmp_numerator, divisor)
+            }
+            (false, false) => {
+                tmp_divisor = divisor.clone();
+                tmp_numerator = numerator.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_numerator),
+                    || self.full_propagate_parallelized(&mut tmp_divisor),
+                );
+                (&tmp_numerator, &tmp_divisor)
+            }
+        };
+
+        self.unchecked_div_rem_floor_parallelized(numerator, divisor)
+    }
+
+    /// Computes homomorphically the quotient and remainder of the Euclidean division of two
+    /// signed ciphertexts.
+    ///
+    /// The remainder is always positive, `0 <= r < |divisor|`, and the quotient is such that
+    /// `numerator == quotient * divisor + remainder`, like with [i64::div_euclid] and
+    /// [i64::rem_euclid]. When the numerator is the minimum value and the divisor is -1, the
+    /// quotient wraps around.
+    ///
+    /// The result is computed from the truncated division, the quotient and remainder are
+    /// corrected when the remainder is negative, depending on the sign of the divisor.
+    ///
+    /// # Notes
+    ///
+    /// - the input ciphertexts must not have any carries
+    /// - when the divisor is 0, the result should not be relied on
+    pub fn unchecked_div_rem_euclid_parallelized(
+        &self,
+        numerator: &SignedRadixCiphertext,
+        divisor: &SignedRadixCiphertext,
+    ) -> (SignedRadixCiphertext, SignedRadixCiphertext) {
+        let (quotient, remainder) = self.unchecked_div_rem_parallelized(numerator, divisor);
+
+        // The condition block encodes the sign of the remainder in its first bit,
+        // and the sign of the divisor in its second bit
+        let condition = {
+            let sign_bit_pos = self.key.message_modulus.0.ilog2() - 1;
+            let signs = |x, y| {
+                let x_sign_bit = (x >> sign_bit_pos) & 1;
+                let y_sign_bit = (y >> sign_bit_pos) & 1;
+                x_sign_bit | (y_sign_bit << 1)
+            };
+            let lut = self.key.generate_lookup_table_bivariate(signs);
+            self.key.unchecked_apply_lookup_table_bivariate(
+                remainder.blocks().last().unwrap(),
+                divisor.blocks().last().unwrap(),
+                &lut,
+            )
+        };
+        const NEGATIVE_REMAINDER_POSITIVE_DIVISOR: u64 = 0b01;
+        const NEGATIVE_REMAINDER_NEGATIVE_DIVISOR: u64 = 0b11;
+
+        let (
+            (remainder_plus_divisor, remainder_minus_divisor),
+            (quotient_minus_one, quotient_plus_one),
+        ) = rayon::join(
+            || {
+                rayon::join(
+                    || self.add_parallelized(&remainder, divisor),
+                    || self.sub_parallelized(&remainder, divisor),
+                )
+            },
+            || {
+                rayon::join(
+                    || self.scalar_sub_parallelized(&quotient, 1),
+                    || self.scalar_add_parallelized(&quotient, 1),
+                )
+            },
+        );
+
+        let select = |if_positive_divisor: &SignedRadixCiphertext,
+                      if_negative_divisor: &SignedRadixCiphertext,
+                      otherwise: &SignedRadixCiphertext| {
+            let result = self.unchecked_programmable_if_then_else_parallelized(
+                &condition,
+                if_negative_divisor,
+                otherwise,
+                |x| x == NEGATIVE_REMAINDER_NEGATIVE_DIVISOR,
+                true,
+            );
+            self.unchecked_programmable_if_then_else_parallelized(
+                &condition,
+                if_positive_divisor,
+                &result,
+                |x| x == NEGATIVE_REMAINDER_POSITIVE_DIVISOR,
+                true,
+            )
+        };
+
+        rayon::join(
+            || select(&quotient_minus_one, &quotient_plus_one, &quotient),
+            || {
+                select(
+                    &remainder_plus_divisor,
+                    &remainder_minus_divisor,
+                    &remainder,
+                )
+            },
+        )
+    }
+
+    /// Computes homomorphically the quotient and remainder of the Euclidean division of two
+    /// signed ciphertexts.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs ciphertexts whose block carries are always
+    /// empty.
+    ///
+    /// See [Self::unchecked_div_rem_euclid_parallelized]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let ct1 = cks.encrypt_signed(-7i8);
+    /// let ct2 = cks.encrypt_signed(-3i8);
+    ///
+    /// let (q_res, r_res) = sks.div_rem_euclid_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let q: i8 = cks.decrypt_signed(&q_res);
+    /// let r: i8 = cks.decrypt_signed(&r_res);
+    /// assert_eq!(q, (-7i8).div_euclid(-3));
+    /// assert_eq!(r, (-7i8).rem_euclid(-3));
+    /// ```
+    pub fn div_rem_euclid_parallelized(
+        &self,
+        numerator: &SignedRadixCiphertext,
+        divisor: &SignedRadixCiphertext,
+    ) -> (SignedRadixCiphertext, SignedRadixCiphertext) {
+        let mut tmp_numerator;
+        let mut tmp_divisor;
+
+        let (numerator, divisor) = match (
+            numerator.block_carries_are_empty(),
+            divisor.block_carries_are_empty(),
+        ) {
+            (true, true) => (numerator, divisor),
+            (true, false) => {
+                tmp_divisor = divisor.clone();
+                self.full_propagate_parallelized(&mut tmp_divisor);
+                (numerator, &tmp_divisor)
+            }
+            (false, true) => {
+                tmp_numerator = numerator.clone();
+                self.full_propagate_parallelized(&mut tmp_numerator);
+                (&tmp_numerator, divisor)
+            }
+            (false, false) => {
+                tmp_divisor = divisor.clone();
+                tmp_numerator = numerator.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_numerator),
+                    || self.full_propagate_parallelized(&mut tmp_divisor),
+                );
+                (&tmp_numerator, &tmp_divisor)
+            }
+        };
+
+        self.unchecked_div_rem_euclid_parallelized(numerator, divisor)
+    }
+
     fn unsigned_unchecked_div_rem_parallelized(
         &self,
         numerator: &RadixCiphertext,
@@ -1010,4 +1239,115 @@ impl ServerKey {
 
         (r, BooleanBlock::new_unchecked(rem_by_0))
     }
+
+    //======================================================================
+    //                Div Rem by an encrypted power of two
+    //======================================================================
+
+    /// Computes homomorphically the quotient and remainder of the division of `numerator` by
+    /// `2^divisor_log2`, where the exponent `divisor_log2` is encrypted.
+    ///
+    /// As the divisor is known to be a power of two, the division is computed using shifts
+    /// which is much cheaper than a general division.
+    ///
+    /// This requires:
+    /// - numerator to have clean carries
+    /// - divisor_log2 to have clean carries
+    /// - the number of bits in the block to be >= 3
+    ///
+    /// # Notes
+    ///
+    /// Like for shifts, `divisor_log2` is reduced modulo the number of bits of the numerator.
+    pub fn unchecked_div_rem_pow2_hint_parallelized(
+        &self,
+        numerator: &RadixCiphertext,
+        divisor_log2: &RadixCiphertext,
+    ) -> (RadixCiphertext, RadixCiphertext) {
+        let (quotient, mut mask) = rayon::join(
+            || self.unchecked_right_shift_parallelized(numerator, divisor_log2),
+            || {
+                // Has the bits that would be moved to the quotient set to 1
+                let all_ones: RadixCiphertext =
+                    self.create_trivial_max_radix(numerator.blocks().len());
+                self.unchecked_left_shift_parallelized(&all_ones, divisor_log2)
+            },
+        );
+
+        self.bitnot_assign(&mut mask);
+        let remainder = self.unchecked_bitand_parallelized(numerator, &mask);
+
+        (quotient, remainder)
+    }
+
+    /// Computes homomorphically the quotient and remainder of the division of `numerator` by
+    /// `2^divisor_log2`, where the exponent `divisor_log2` is encrypted.
+    ///
+    /// As the divisor is known to be a power of two, the division is computed using shifts
+    /// which is much cheaper than a general division.
+    ///
+    /// # Notes
+    ///
+    /// Like for shifts, `divisor_log2` is reduced modulo the number of bits of the numerator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 217u64;
+    /// let divisor_log2 = 3u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    /// let ct_log2 = cks.encrypt(divisor_log2);
+    ///
+    /// // Compute homomorphically the quotient and remainder:
+    /// let (q_res, r_res) = sks.div_rem_pow2_hint_parallelized(&ct, &ct_log2);
+    ///
+    /// // Decrypt:
+    /// let q: u64 = cks.decrypt(&q_res);
+    /// let r: u64 = cks.decrypt(&r_res);
+    /// assert_eq!(q, msg / (1 << divisor_log2));
+    /// assert_eq!(r, msg % (1 << divisor_log2));
+    /// ```
+    pub fn div_rem_pow2_hint_parallelized(
+        &self,
+        numerator: &RadixCiphertext,
+        divisor_log2: &RadixCiphertext,
+    ) -> (RadixCiphertext, RadixCiphertext) {
+        let mut tmp_numerator;
+        let mut tmp_divisor_log2;
+
+        let (numerator, divisor_log2) = match (
+            numerator.block_carries_are_empty(),
+            divisor_log2.block_carries_are_empty(),
+        ) {
+            (true, true) => (numerator, divisor_log2),
+            (true, false) => {
+                tmp_divisor_log2 = divisor_log2.clone();
+                self.full_propagate_parallelized(&mut tmp_divisor_log2);
+                (numerator, &tmp_divisor_log2)
+            }
+            (false, true) => {
+                tmp_numerator = numerator.clone();
+                self.full_propagate_parallelized(&mut tmp_numerator);
+                (&tmp_numerator, divisor_log2)
+            }
+            (false, false) => {
+                tmp_divisor_log2 = divisor_log2.clone();
+                tmp_numerator = numerator.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_numerator),
+                    || self.full_propagate_parallelized(&mut tmp_divisor_log2),
+                );
+                (&tmp_numerator, &tmp_divisor_log2)
+            }
+        };
+
+        self.unchecked_div_rem_pow2_hint_parallelized(numerator, divisor_log2)
+    }
 }
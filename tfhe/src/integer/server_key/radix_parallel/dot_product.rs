@@ -0,0 +1,245 @@
+use crate::integer::block_decomposition::{BlockDecomposer, DecomposableInto};
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::ServerKey;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Propagates the carries of the ciphertexts that have some, cloning the slice only if needed
+    fn propagated_slice_parallelized<'a, T>(&self, cts: &'a [T], tmp: &'a mut Vec<T>) -> &'a [T]
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if cts.iter().all(T::block_carries_are_empty) {
+            return cts;
+        }
+
+        *tmp = cts.to_vec();
+        tmp.par_iter_mut()
+            .filter(|ct| !ct.block_carries_are_empty())
+            .for_each(|ct| self.full_propagate_parallelized(ct));
+        tmp
+    }
+
+    /// Computes the dot product of two encrypted vectors, i.e. `sum(lhs[i] * rhs[i])`
+    ///
+    /// The result is computed modulo the ciphertext modulus, it has the same number of blocks as
+    /// the inputs. To avoid the overflow, the inputs can first be extended to a larger number of
+    /// blocks.
+    ///
+    /// The partial products of all the pairs of elements are computed in parallel and
+    /// accumulated together, so that the carries are only propagated once at the end instead
+    /// of after each multiplication and addition.
+    ///
+    /// # Notes
+    ///
+    /// - the ciphertexts must not have any carries
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the slices are empty or do not have the same length
+    /// - Panics if the ciphertexts do not all have the same number of blocks
+    pub fn unchecked_dot_product_parallelized<T>(&self, lhs: &[T], rhs: &[T]) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert_eq!(
+            lhs.len(),
+            rhs.len(),
+            "Both vectors must have the same length"
+        );
+        assert!(!lhs.is_empty(), "The vectors must not be empty");
+        let num_blocks = lhs[0].blocks().len();
+        assert!(
+            lhs.iter()
+                .chain(rhs.iter())
+                .all(|ct| ct.blocks().len() == num_blocks),
+            "Not all ciphertexts have the same number of blocks"
+        );
+
+        let terms = lhs
+            .par_iter()
+            .zip(rhs.par_iter())
+            .flat_map_iter(|(lhs, rhs)| self.compute_terms_for_mul_low(lhs, rhs))
+            .collect::<Vec<_>>();
+
+        self.unchecked_sum_ciphertexts_vec_parallelized(terms)
+            .unwrap_or_else(|| self.create_trivial_zero_radix(num_blocks))
+    }
+
+    /// Computes the dot product of two encrypted vectors, i.e. `sum(lhs[i] * rhs[i])`
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// See [Self::unchecked_dot_product_parallelized]
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the slices are empty or do not have the same length
+    /// - Panics if the ciphertexts do not all have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 8 * 2 = 16 bits of message
+    /// let size = 8;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let lhs = [3u64, 40, 255].map(|v| cks.encrypt(v));
+    /// let rhs = [7u64, 2, 100].map(|v| cks.encrypt(v));
+    ///
+    /// let result = sks.dot_product_parallelized(&lhs, &rhs);
+    ///
+    /// let decrypted: u64 = cks.decrypt(&result);
+    /// assert_eq!(decrypted, 3 * 7 + 40 * 2 + 255 * 100);
+    /// ```
+    pub fn dot_product_parallelized<T>(&self, lhs: &[T], rhs: &[T]) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_lhs = vec![];
+        let mut tmp_rhs = vec![];
+
+        let (lhs, rhs) = rayon::join(
+            || self.propagated_slice_parallelized(lhs, &mut tmp_lhs),
+            || self.propagated_slice_parallelized(rhs, &mut tmp_rhs),
+        );
+
+        self.unchecked_dot_product_parallelized(lhs, rhs)
+    }
+
+    /// Computes the dot product of an encrypted vector with a clear vector, i.e.
+    /// `sum(lhs[i] * rhs[i])`
+    ///
+    /// The result is computed modulo the ciphertext modulus, it has the same number of blocks as
+    /// the inputs. To avoid the overflow, the inputs can first be extended to a larger number of
+    /// blocks.
+    ///
+    /// Each product is decomposed into the element shifted by the positions of the bits set in
+    /// the clear value. Shifting by whole blocks is free, so only the shifts by less than one
+    /// block are computed, once per element. All the terms are then accumulated together, the
+    /// carries being only propagated once at the end.
+    ///
+    /// # Notes
+    ///
+    /// - the ciphertexts must not have any carries
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the slices are empty or do not have the same length
+    /// - Panics if the ciphertexts do not all have the same number of blocks
+    pub fn unchecked_scalar_dot_product_parallelized<T, Scalar>(
+        &self,
+        lhs: &[T],
+        rhs: &[Scalar],
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+        Scalar: DecomposableInto<u8> + Copy + Sync,
+    {
+        assert_eq!(
+            lhs.len(),
+            rhs.len(),
+            "Both vectors must have the same length"
+        );
+        assert!(!lhs.is_empty(), "The vectors must not be empty");
+        let num_blocks = lhs[0].blocks().len();
+        assert!(
+            lhs[1..].iter().all(|ct| ct.blocks().len() == num_blocks),
+            "Not all ciphertexts have the same number of blocks"
+        );
+
+        let msg_bits = self.key.message_modulus.0.ilog2() as usize;
+        let num_ciphertext_bits = msg_bits * num_blocks;
+
+        let terms = lhs
+            .par_iter()
+            .zip(rhs.par_iter())
+            .flat_map_iter(|(ct, &clear)| {
+                // Bits set in the clear value, those beyond the ciphertext bits would
+                // shift the element out
+                let set_bits = BlockDecomposer::with_early_stop_at_zero(clear, 1)
+                    .iter_as::<u8>()
+                    .take(num_ciphertext_bits)
+                    .enumerate()
+                    .filter_map(|(i, bit)| (bit == 1).then_some(i))
+                    .collect::<Vec<_>>();
+
+                // The shifts by less than one block, the others are created by block rotation
+                let preshifted = (0..msg_bits)
+                    .into_par_iter()
+                    .map(|shift_amount| {
+                        set_bits
+                            .iter()
+                            .any(|i| i % msg_bits == shift_amount)
+                            .then(|| {
+                                self.unchecked_scalar_left_shift_parallelized(ct, shift_amount)
+                            })
+                    })
+                    .collect::<Vec<_>>();
+
+                set_bits
+                    .into_iter()
+                    .map(|i| {
+                        let preshifted = preshifted[i % msg_bits]
+                            .as_ref()
+                            .expect("the shift is used by this clear value");
+                        self.blockshift(preshifted, i / msg_bits)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        self.unchecked_sum_ciphertexts_vec_parallelized(terms)
+            .unwrap_or_else(|| self.create_trivial_zero_radix(num_blocks))
+    }
+
+    /// Computes the dot product of an encrypted vector with a clear vector, i.e.
+    /// `sum(lhs[i] * rhs[i])`
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// See [Self::unchecked_scalar_dot_product_parallelized]
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the slices are empty or do not have the same length
+    /// - Panics if the ciphertexts do not all have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 8 * 2 = 16 bits of message
+    /// let size = 8;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let lhs = [3u64, 40, 255].map(|v| cks.encrypt(v));
+    /// let weights = [7u16, 0, 100];
+    ///
+    /// let result = sks.scalar_dot_product_parallelized(&lhs, &weights);
+    ///
+    /// let decrypted: u64 = cks.decrypt(&result);
+    /// assert_eq!(decrypted, 3 * 7 + 255 * 100);
+    /// ```
+    pub fn scalar_dot_product_parallelized<T, Scalar>(&self, lhs: &[T], rhs: &[Scalar]) -> T
+    where
+        T: IntegerRadixCiphertext,
+        Scalar: DecomposableInto<u8> + Copy + Sync,
+    {
+        let mut tmp_lhs = vec![];
+        let lhs = self.propagated_slice_parallelized(lhs, &mut tmp_lhs);
+
+        self.unchecked_scalar_dot_product_parallelized(lhs, rhs)
+    }
+}
@@ -0,0 +1,444 @@
+use crate::integer::block_decomposition::{BlockDecomposer, DecomposableInto};
+use crate::integer::ciphertext::boolean_value::BooleanBlock;
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::server_key::comparator::{reduce_two_orderings_function, Comparator};
+use crate::integer::ServerKey;
+use crate::shortint::Ciphertext;
+use rayon::prelude::*;
+use std::cmp::Ordering;
+
+fn ordering_to_u64(ordering: Ordering) -> u64 {
+    match ordering {
+        Ordering::Less => Comparator::IS_INFERIOR,
+        Ordering::Equal => Comparator::IS_EQUAL,
+        Ordering::Greater => Comparator::IS_SUPERIOR,
+    }
+}
+
+impl ServerKey {
+    /// Returns whether the fused range check can be used with the parameters of this key
+    ///
+    /// The orderings of two blocks are computed by a bivariate PBS, and the partial conclusions
+    /// of both bounds are packed in a single block, which requires 4 bits.
+    fn can_fuse_range_check(&self) -> bool {
+        self.carry_modulus().0 >= self.message_modulus().0
+            && self.message_modulus().0 * self.carry_modulus().0 >= 16
+    }
+
+    /// Reduces the orderings of blocks, given from the least significant to the most significant,
+    /// to a single block encrypting `handler(ordering)` where `ordering` is the ordering of the
+    /// whole values.
+    ///
+    /// The reduction happens in a tree, the reductions of each level are computed in parallel,
+    /// and `handler` is applied by the PBS of the last level. No blocks are equivalent to equal
+    /// values.
+    fn reduce_orderings_parallelized<F>(
+        &self,
+        mut orderings: Vec<Ciphertext>,
+        handler: F,
+    ) -> Ciphertext
+    where
+        F: Fn(u64) -> u64,
+    {
+        let reduction_lut = self
+            .key
+            .generate_lookup_table(reduce_two_orderings_function);
+        // We don't use pack_block_assign as the offset '4' does not depend on params
+        let pack = |chunk: &[Ciphertext]| {
+            let mut packed = self.key.unchecked_scalar_mul(&chunk[1], 4);
+            self.key.unchecked_add_assign(&mut packed, &chunk[0]);
+            packed
+        };
+
+        while orderings.len() > 2 {
+            let mut reduced = orderings
+                .par_chunks_exact(2)
+                .map(|chunk| {
+                    let mut packed = pack(chunk);
+                    self.key
+                        .apply_lookup_table_assign(&mut packed, &reduction_lut);
+                    packed
+                })
+                .collect::<Vec<_>>();
+
+            if orderings.len() % 2 == 1 {
+                reduced.push(orderings.pop().unwrap());
+            }
+            orderings = reduced;
+        }
+
+        // Orderings are in {0, 1, 2}, applying the modulus explicitly keeps the handler inputs
+        // valid for all the inputs of the lookup tables
+        match orderings.as_slice() {
+            [] => self
+                .key
+                .unchecked_create_trivial(handler(Comparator::IS_EQUAL)),
+            [ordering] => {
+                let lut = self.key.generate_lookup_table(|x| handler(x % 3));
+                self.key.apply_lookup_table(ordering, &lut)
+            }
+            chunk => {
+                let lut = self
+                    .key
+                    .generate_lookup_table(|x| handler(reduce_two_orderings_function(x) % 3));
+                let mut packed = pack(chunk);
+                self.key.apply_lookup_table_assign(&mut packed, &lut);
+                packed
+            }
+        }
+    }
+
+    /// Concludes whether a value is in a range, given the orderings of its blocks with the
+    /// blocks of the lower bound and of the upper bound, from the least significant to the most
+    /// significant.
+    ///
+    /// Checking the bounds separately requires a last PBS per comparison and a PBS for the AND.
+    /// Instead, the orderings of each bound are split in a low half and a high half, which are
+    /// reduced in parallel and whose last reduction directly outputs a partial conclusion:
+    ///
+    /// - the low half encodes whether its part is in range (`0` or `1`), which only matters when
+    ///   the high halves are equal
+    /// - the high half encodes its ordering as `0` (out of range), `1` (equal) or `2` (in range)
+    ///
+    /// so that the sum of both is at least `2` if and only if the bound is respected. The upper
+    /// bound sums are shifted by two bits, and a single PBS on the sum of the four blocks gives
+    /// the result. The whole check has the same number of PBS layers as reducing the orderings
+    /// of a single comparison.
+    fn conclude_is_in_range_parallelized(
+        &self,
+        mut low_orderings: Vec<Ciphertext>,
+        mut high_orderings: Vec<Ciphertext>,
+    ) -> BooleanBlock {
+        let low_high_half = low_orderings.split_off(low_orderings.len() / 2);
+        let high_high_half = high_orderings.split_off(high_orderings.len() / 2);
+
+        let ((low_low, low_high), (high_low, high_high)) = rayon::join(
+            || {
+                rayon::join(
+                    || {
+                        self.reduce_orderings_parallelized(low_orderings, |ordering| {
+                            u64::from(ordering != Comparator::IS_INFERIOR)
+                        })
+                    },
+                    || self.reduce_orderings_parallelized(low_high_half, |ordering| ordering),
+                )
+            },
+            || {
+                rayon::join(
+                    || {
+                        self.reduce_orderings_parallelized(high_orderings, |ordering| {
+                            u64::from(ordering != Comparator::IS_SUPERIOR) << 2
+                        })
+                    },
+                    || {
+                        self.reduce_orderings_parallelized(high_high_half, |ordering| {
+                            (Comparator::IS_SUPERIOR - ordering) << 2
+                        })
+                    },
+                )
+            },
+        );
+
+        let mut result = low_low;
+        for block in [&low_high, &high_low, &high_high] {
+            self.key.unchecked_add_assign(&mut result, block);
+        }
+        let lut = self
+            .key
+            .generate_lookup_table(|x| u64::from((x & 3) >= 2 && (x >> 2) >= 2));
+        self.key.apply_lookup_table_assign(&mut result, &lut);
+
+        BooleanBlock::new_unchecked(result)
+    }
+
+    /// Returns whether `ct` is in the inclusive range `[low, high]`, i.e. `low <= ct <= high`
+    ///
+    /// The result is false if `low > high`.
+    ///
+    /// Both bounds are checked by a single fused circuit: the orderings with both bounds are
+    /// reduced in parallel, and the boolean AND is merged in their last PBS instead of requiring
+    /// its own PBS. If the parameters do not have at least 4 bits of message and carry, the two
+    /// comparisons are computed in parallel and combined.
+    ///
+    /// * inputs must have the same number of blocks
+    /// * block carries of all inputs must be empty
+    pub fn unchecked_is_in_range_parallelized<T>(&self, ct: &T, low: &T, high: &T) -> BooleanBlock
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(
+            ct.blocks().len() == low.blocks().len() && ct.blocks().len() == high.blocks().len(),
+            "inputs must have the same number of blocks"
+        );
+
+        if ct.blocks().is_empty() {
+            // We interpret empty as 0
+            return self.create_trivial_boolean_block(true);
+        }
+
+        if !self.can_fuse_range_check() {
+            let (is_above_low, is_below_high) = rayon::join(
+                || self.unchecked_ge_parallelized(ct, low),
+                || self.unchecked_le_parallelized(ct, high),
+            );
+            return self.boolean_bitand(&is_above_low, &is_below_high);
+        }
+
+        let message_modulus = self.message_modulus().0 as u64;
+        let to_signed = |block: u64| {
+            if block >= message_modulus / 2 {
+                block as i64 - message_modulus as i64
+            } else {
+                block as i64
+            }
+        };
+        let ordering_lut = self
+            .key
+            .generate_lookup_table_bivariate(|x, y| ordering_to_u64(x.cmp(&y)));
+        // The last block of signed values holds the sign bit
+        let signed_ordering_lut = self.key.generate_lookup_table_bivariate(|x, y| {
+            ordering_to_u64(to_signed(x).cmp(&to_signed(y)))
+        });
+
+        let num_blocks = ct.blocks().len();
+        let orderings = |bound: &T| {
+            ct.blocks()
+                .par_iter()
+                .zip(bound.blocks().par_iter())
+                .enumerate()
+                .map(|(i, (block, bound_block))| {
+                    let lut = if T::IS_SIGNED && i == num_blocks - 1 {
+                        &signed_ordering_lut
+                    } else {
+                        &ordering_lut
+                    };
+                    self.key
+                        .unchecked_apply_lookup_table_bivariate(block, bound_block, lut)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let (low_orderings, high_orderings) = rayon::join(|| orderings(low), || orderings(high));
+
+        self.conclude_is_in_range_parallelized(low_orderings, high_orderings)
+    }
+
+    /// Returns whether `ct` is in the inclusive range `[low, high]`, i.e. `low <= ct <= high`
+    ///
+    /// The result is false if `low > high`.
+    ///
+    /// Both bounds are checked by a single fused circuit: the orderings with both bounds are
+    /// reduced in parallel, and the boolean AND is merged in their last PBS instead of requiring
+    /// its own PBS.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// This means that when using only "default" operations, a given operation (like add for
+    /// example) has always the same performance characteristics from one call to another and
+    /// guarantees correctness by pre-emptively clearing carries of output ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let ct = cks.encrypt(42u64);
+    /// let low = cks.encrypt(10u64);
+    /// let high = cks.encrypt(100u64);
+    ///
+    /// let ct_res = sks.is_in_range_parallelized(&ct, &low, &high);
+    ///
+    /// let dec_result = cks.decrypt_bool(&ct_res);
+    /// assert!(dec_result);
+    /// ```
+    pub fn is_in_range_parallelized<T>(&self, ct: &T, low: &T, high: &T) -> BooleanBlock
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_ct = None;
+        let mut tmp_low = None;
+        let mut tmp_high = None;
+
+        let propagated = |input: &T, tmp: &mut Option<T>| {
+            if !input.block_carries_are_empty() {
+                let tmp = tmp.insert(input.clone());
+                self.full_propagate_parallelized(tmp);
+            }
+        };
+        rayon::join(
+            || propagated(ct, &mut tmp_ct),
+            || {
+                rayon::join(
+                    || propagated(low, &mut tmp_low),
+                    || propagated(high, &mut tmp_high),
+                )
+            },
+        );
+
+        self.unchecked_is_in_range_parallelized(
+            tmp_ct.as_ref().unwrap_or(ct),
+            tmp_low.as_ref().unwrap_or(low),
+            tmp_high.as_ref().unwrap_or(high),
+        )
+    }
+
+    /// Returns whether `ct` is in the inclusive range `[low, high]` given by two clear values,
+    /// i.e. `low <= ct <= high`
+    ///
+    /// The result is false if `low > high`.
+    ///
+    /// Both bounds are checked by a single fused circuit: the orderings with both bounds are
+    /// reduced in parallel, and the boolean AND is merged in their last PBS instead of requiring
+    /// its own PBS. If the parameters do not have at least 4 bits of message and carry, the two
+    /// comparisons are computed in parallel and combined.
+    ///
+    /// * block carries of `ct` must be empty
+    pub fn unchecked_scalar_is_in_range_parallelized<T, Scalar>(
+        &self,
+        ct: &T,
+        low: Scalar,
+        high: Scalar,
+    ) -> BooleanBlock
+    where
+        T: IntegerRadixCiphertext,
+        Scalar: DecomposableInto<u64>,
+    {
+        if ct.blocks().is_empty() {
+            // We interpret empty as 0
+            return self.create_trivial_boolean_block(low <= Scalar::ZERO && Scalar::ZERO <= high);
+        }
+
+        if !self.can_fuse_range_check() {
+            let (is_above_low, is_below_high) = rayon::join(
+                || self.unchecked_scalar_ge_parallelized(ct, low),
+                || self.unchecked_scalar_le_parallelized(ct, high),
+            );
+            return self.boolean_bitand(&is_above_low, &is_below_high);
+        }
+
+        let (low_bounds, high_bounds) = (
+            self.is_scalar_out_of_bounds(ct, low),
+            self.is_scalar_out_of_bounds(ct, high),
+        );
+        if low_bounds == Ordering::Greater || high_bounds == Ordering::Less {
+            // No value of ct can be in the range
+            return self.create_trivial_boolean_block(false);
+        }
+
+        let message_modulus = self.message_modulus().0 as u64;
+        let to_signed = |block: u64| {
+            if block >= message_modulus / 2 {
+                block as i64 - message_modulus as i64
+            } else {
+                block as i64
+            }
+        };
+
+        let num_blocks = ct.blocks().len();
+        let orderings = |bound: Scalar, bound_ordering: Ordering| {
+            if bound_ordering != Ordering::Equal {
+                // The bound is respected by any value of ct, no blocks is equivalent to equal
+                // values which respect both bounds
+                return vec![];
+            }
+
+            let padding_value = if bound >= Scalar::ZERO {
+                0u64
+            } else {
+                message_modulus - 1
+            };
+            let bound_blocks = BlockDecomposer::new(bound, message_modulus.ilog2())
+                .iter_as::<u64>()
+                .chain(std::iter::repeat(padding_value))
+                .take(num_blocks)
+                .collect::<Vec<_>>();
+
+            ct.blocks()
+                .par_iter()
+                .zip(bound_blocks.into_par_iter())
+                .enumerate()
+                .map(|(i, (block, bound_block))| {
+                    let lut = if T::IS_SIGNED && i == num_blocks - 1 {
+                        self.key.generate_lookup_table(|x| {
+                            ordering_to_u64(to_signed(x).cmp(&to_signed(bound_block)))
+                        })
+                    } else {
+                        self.key
+                            .generate_lookup_table(|x| ordering_to_u64(x.cmp(&bound_block)))
+                    };
+                    self.key.apply_lookup_table(block, &lut)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let (low_orderings, high_orderings) = rayon::join(
+            || orderings(low, low_bounds),
+            || orderings(high, high_bounds),
+        );
+
+        self.conclude_is_in_range_parallelized(low_orderings, high_orderings)
+    }
+
+    /// Returns whether `ct` is in the inclusive range `[low, high]` given by two clear values,
+    /// i.e. `low <= ct <= high`
+    ///
+    /// The result is false if `low > high`.
+    ///
+    /// Both bounds are checked by a single fused circuit: the orderings with both bounds are
+    /// reduced in parallel, and the boolean AND is merged in their last PBS instead of requiring
+    /// its own PBS.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertext block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// This means that when using only "default" operations, a given operation (like add for
+    /// example) has always the same performance characteristics from one call to another and
+    /// guarantees correctness by pre-emptively clearing carries of output ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let ct = cks.encrypt_signed(-42i64);
+    ///
+    /// let ct_res = sks.scalar_is_in_range_parallelized(&ct, -50i64, -10i64);
+    ///
+    /// let dec_result = cks.decrypt_bool(&ct_res);
+    /// assert!(dec_result);
+    /// ```
+    pub fn scalar_is_in_range_parallelized<T, Scalar>(
+        &self,
+        ct: &T,
+        low: Scalar,
+        high: Scalar,
+    ) -> BooleanBlock
+    where
+        T: IntegerRadixCiphertext,
+        Scalar: DecomposableInto<u64>,
+    {
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+
+        self.unchecked_scalar_is_in_range_parallelized(ct, low, high)
+    }
+}
@@ -0,0 +1,213 @@
+use crate::integer::server_key::comparator::ZeroComparisonType;
+use crate::integer::{BooleanBlock, IntegerRadixCiphertext, ServerKey};
+
+impl ServerKey {
+    /// Returns an encryption of true if the value is zero
+    ///
+    /// This is cheaper than comparing with a zero, as blocks are summed together
+    /// before being compared to zero, reducing the number of PBS required.
+    ///
+    /// ct must have clean carries
+    pub fn unchecked_is_zero_parallelized<T>(&self, ct: &T) -> BooleanBlock
+    where
+        T: IntegerRadixCiphertext,
+    {
+        // An empty ciphertext is interpreted as being 0
+        let result = self.are_all_blocks_zero(ct.blocks());
+        BooleanBlock::new_unchecked(result)
+    }
+
+    /// Returns an encryption of true if the value is not zero
+    ///
+    /// This is cheaper than comparing with a zero, as blocks are summed together
+    /// before being compared to zero, reducing the number of PBS required.
+    ///
+    /// ct must have clean carries
+    pub fn unchecked_is_nonzero_parallelized<T>(&self, ct: &T) -> BooleanBlock
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if ct.blocks().is_empty() {
+            // Interpret empty as being 0
+            return self.create_trivial_boolean_block(false);
+        }
+
+        let block_comparisons =
+            self.compare_blocks_with_zero(ct.blocks(), ZeroComparisonType::Difference);
+        let result = self.is_at_least_one_comparisons_block_true(block_comparisons);
+        BooleanBlock::new_unchecked(result)
+    }
+
+    /// Returns an encryption of true if the value is zero
+    ///
+    /// The carries of ct will be cleaned if necessary
+    pub fn smart_is_zero_parallelized<T>(&self, ct: &mut T) -> BooleanBlock
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(ct);
+        }
+        self.unchecked_is_zero_parallelized(ct)
+    }
+
+    /// Returns an encryption of true if the value is not zero
+    ///
+    /// The carries of ct will be cleaned if necessary
+    pub fn smart_is_nonzero_parallelized<T>(&self, ct: &mut T) -> BooleanBlock
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(ct);
+        }
+        self.unchecked_is_nonzero_parallelized(ct)
+    }
+
+    /// Returns an encryption of true if the value is zero
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let ct = cks.encrypt(0u8);
+    ///
+    /// let ct_res = sks.is_zero_parallelized(&ct);
+    ///
+    /// let dec = cks.decrypt_bool(&ct_res);
+    /// assert!(dec);
+    /// ```
+    pub fn is_zero_parallelized<T>(&self, ct: &T) -> BooleanBlock
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+        self.unchecked_is_zero_parallelized(ct)
+    }
+
+    /// Returns an encryption of true if the value is not zero
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let ct = cks.encrypt(16u8);
+    ///
+    /// let ct_res = sks.is_nonzero_parallelized(&ct);
+    ///
+    /// let dec = cks.decrypt_bool(&ct_res);
+    /// assert!(dec);
+    /// ```
+    pub fn is_nonzero_parallelized<T>(&self, ct: &T) -> BooleanBlock
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+        self.unchecked_is_nonzero_parallelized(ct)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ServerKey;
+    use crate::integer::ciphertext::RadixCiphertext;
+    use crate::integer::keycache::KEY_CACHE;
+    use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
+    use crate::integer::server_key::radix_parallel::tests_unsigned::{
+        CpuFunctionExecutor, NB_CTXT,
+    };
+    use crate::integer::tests::create_parametrized_test;
+    use crate::integer::{BooleanBlock, IntegerKeyKind, RadixClientKey};
+    #[cfg(tarpaulin)]
+    use crate::shortint::parameters::coverage_parameters::*;
+    use crate::shortint::parameters::*;
+    use crate::shortint::PBSParameters;
+    use rand::prelude::*;
+    use std::sync::Arc;
+
+    pub(crate) fn is_zero_test<P, T>(param: P, mut executor: T, expect_zero: bool)
+    where
+        P: Into<PBSParameters>,
+        T: for<'a> FunctionExecutor<&'a RadixCiphertext, BooleanBlock>,
+    {
+        let param = param.into();
+        let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+        let cks = RadixClientKey::from((cks, NB_CTXT));
+
+        let sks = Arc::new(sks);
+        executor.setup(&cks, sks.clone());
+
+        let mut rng = rand::thread_rng();
+
+        // message_modulus^vec_length
+        let modulus = param.message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+        let nb_tests = 10;
+
+        // Make sure the zero case is tested
+        for clear in std::iter::once(0).chain((0..nb_tests).map(|_| rng.gen::<u64>() % modulus)) {
+            let ct = cks.encrypt(clear);
+            let result = executor.execute(&ct);
+            let decrypted_result = cks.decrypt_bool(&result);
+            assert_eq!(
+                decrypted_result,
+                (clear == 0) == expect_zero,
+                "Invalid result for {clear}"
+            );
+
+            // Non clean carries: clear + 0 with the addition not propagated
+            let zero = cks.encrypt(0u64);
+            let ct = sks.unchecked_add(&ct, &zero);
+            let result = executor.execute(&ct);
+            let decrypted_result = cks.decrypt_bool(&result);
+            assert_eq!(
+                decrypted_result,
+                (clear == 0) == expect_zero,
+                "Invalid result for {clear} with non clean carries"
+            );
+        }
+    }
+
+    fn integer_is_zero<P>(param: P)
+    where
+        P: Into<PBSParameters>,
+    {
+        let executor = CpuFunctionExecutor::new(&ServerKey::is_zero_parallelized);
+        is_zero_test(param, executor, true);
+    }
+
+    fn integer_is_nonzero<P>(param: P)
+    where
+        P: Into<PBSParameters>,
+    {
+        let executor = CpuFunctionExecutor::new(&ServerKey::is_nonzero_parallelized);
+        is_zero_test(param, executor, false);
+    }
+
+    create_parametrized_test!(integer_is_zero);
+    create_parametrized_test!(integer_is_nonzero);
+}
@@ -0,0 +1,278 @@
+use crate::integer::{BooleanBlock, IntegerRadixCiphertext, ServerKey};
+
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Returns the ciphertexts of the slice, with their carries propagated if needed
+    ///
+    /// `storage` is used to store the propagated copies, if any ciphertext has carries.
+    pub(super) fn carry_free_slice<'a, T>(&self, cts: &'a [T], storage: &'a mut Vec<T>) -> &'a [T]
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if cts.iter().all(|ct| ct.block_carries_are_empty()) {
+            return cts;
+        }
+
+        *storage = cts.to_vec();
+        storage
+            .par_iter_mut()
+            .filter(|ct| !ct.block_carries_are_empty())
+            .for_each(|ct| self.full_propagate_parallelized(ct));
+        storage
+    }
+
+    /// Compares each ciphertext of `left` with each ciphertext of `right`, and returns the
+    /// matrix of the results, where `result[i][j]` encrypts `left[i] == right[j]`
+    ///
+    /// This is the building block of an equality join (a nested loop join, as ciphertexts cannot
+    /// be hashed or sorted).
+    ///
+    /// The block comparisons of all the pairs are done in a single parallel batch, followed by
+    /// the reductions of all the pairs, so that the parallelism does not depend on the number of
+    /// blocks of the ciphertexts.
+    ///
+    /// # Notes
+    ///
+    /// - all the ciphertexts must not have any carries
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertexts do not all have the same number of blocks.
+    pub fn unchecked_eq_join_parallelized<T>(
+        &self,
+        left: &[T],
+        right: &[T],
+    ) -> Vec<Vec<BooleanBlock>>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let Some(num_blocks) = left.iter().chain(right).map(|ct| ct.blocks().len()).next() else {
+            return vec![Vec::new(); left.len()];
+        };
+        assert!(
+            left.iter()
+                .chain(right)
+                .all(|ct| ct.blocks().len() == num_blocks),
+            "All ciphertexts must have the same number of blocks"
+        );
+
+        if right.is_empty() {
+            return vec![Vec::new(); left.len()];
+        }
+
+        if num_blocks == 0 {
+            // Empty ciphertexts are interpreted as 0, so they are all equal
+            return vec![vec![self.create_trivial_boolean_block(true); right.len()]; left.len()];
+        }
+
+        let block_equality_lut = self
+            .key
+            .generate_lookup_table_bivariate(|l, r| u64::from(l == r));
+
+        let num_pairs = left.len() * right.len();
+        let block_comparisons = (0..num_pairs * num_blocks)
+            .into_par_iter()
+            .map(|index| {
+                let (pair_index, block_index) = (index / num_blocks, index % num_blocks);
+                let (left_index, right_index) =
+                    (pair_index / right.len(), pair_index % right.len());
+                self.key.unchecked_apply_lookup_table_bivariate(
+                    &left[left_index].blocks()[block_index],
+                    &right[right_index].blocks()[block_index],
+                    &block_equality_lut,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let results = block_comparisons
+            .par_chunks(num_blocks)
+            .map(|comparisons| {
+                BooleanBlock::new_unchecked(
+                    self.are_all_comparisons_block_true(comparisons.to_vec()),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        results
+            .chunks(right.len())
+            .map(<[BooleanBlock]>::to_vec)
+            .collect()
+    }
+
+    /// Compares each ciphertext of `left` with each ciphertext of `right`, and returns the
+    /// matrix of the results, where `result[i][j]` encrypts `left[i] == right[j]`
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it.
+    ///
+    /// See [Self::unchecked_eq_join_parallelized]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let left = [1u64, 2, 3];
+    /// let right = [3u64, 1];
+    ///
+    /// let ct_left = left.iter().map(|&v| cks.encrypt(v)).collect::<Vec<_>>();
+    /// let ct_right = right.iter().map(|&v| cks.encrypt(v)).collect::<Vec<_>>();
+    ///
+    /// let matches = sks.eq_join_parallelized(&ct_left, &ct_right);
+    ///
+    /// for (i, row) in matches.iter().enumerate() {
+    ///     for (j, is_match) in row.iter().enumerate() {
+    ///         assert_eq!(cks.decrypt_bool(is_match), left[i] == right[j]);
+    ///     }
+    /// }
+    /// ```
+    pub fn eq_join_parallelized<T>(&self, left: &[T], right: &[T]) -> Vec<Vec<BooleanBlock>>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut left_clone = vec![];
+        let mut right_clone = vec![];
+        let (left, right) = rayon::join(
+            || self.carry_free_slice(left, &mut left_clone),
+            || self.carry_free_slice(right, &mut right_clone),
+        );
+
+        self.unchecked_eq_join_parallelized(left, right)
+    }
+
+    /// Computes, for each ciphertext of `left_keys`, the sum of the `right_values` whose key in
+    /// `right_keys` is equal to it
+    ///
+    /// `result[i]` is the sum of all the `right_values[j]` such that
+    /// `left_keys[i] == right_keys[j]`, or 0 if there is none. Like the other additions, the sums
+    /// wrap around on overflow.
+    ///
+    /// This is an equality join immediately followed by a grouped sum, which avoids decrypting
+    /// or returning the matrix of matches.
+    ///
+    /// # Notes
+    ///
+    /// - all the ciphertexts must not have any carries
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `right_keys` and `right_values` do not have the same length
+    /// - Panics if `right_values` is empty, as the number of blocks of the sums is unknown
+    /// - Panics if the keys or the values do not all have the same number of blocks
+    pub fn unchecked_eq_join_sum_parallelized<T, V>(
+        &self,
+        left_keys: &[T],
+        right_keys: &[T],
+        right_values: &[V],
+    ) -> Vec<V>
+    where
+        T: IntegerRadixCiphertext,
+        V: IntegerRadixCiphertext,
+    {
+        assert_eq!(
+            right_keys.len(),
+            right_values.len(),
+            "There must be exactly one value per right key"
+        );
+        assert!(
+            !right_values.is_empty(),
+            "At least one right value is required"
+        );
+        let num_value_blocks = right_values[0].blocks().len();
+        assert!(
+            right_values
+                .iter()
+                .all(|value| value.blocks().len() == num_value_blocks),
+            "All values must have the same number of blocks"
+        );
+
+        let matches = self.unchecked_eq_join_parallelized(left_keys, right_keys);
+
+        matches
+            .par_iter()
+            .map(|row| {
+                let masked_values = row
+                    .par_iter()
+                    .zip(right_values.par_iter())
+                    .map(|(is_match, value)| {
+                        let mut masked_value = value.clone();
+                        self.zero_out_if_condition_is_false(&mut masked_value, &is_match.0);
+                        masked_value
+                    })
+                    .collect::<Vec<_>>();
+
+                self.unchecked_sum_ciphertexts_vec_parallelized(masked_values)
+                    .expect("internal error, there is at least one value")
+            })
+            .collect()
+    }
+
+    /// Computes, for each ciphertext of `left_keys`, the sum of the `right_values` whose key in
+    /// `right_keys` is equal to it
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs ciphertexts whose block carries are always
+    /// empty.
+    ///
+    /// See [Self::unchecked_eq_join_sum_parallelized]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// // SELECT customer, SUM(amount) FROM orders GROUP BY customer
+    /// let customers = [1u64, 2, 7];
+    /// let orders = [(2u64, 10u64), (1, 5), (2, 20)];
+    ///
+    /// let ct_customers = customers
+    ///     .iter()
+    ///     .map(|&v| cks.encrypt(v))
+    ///     .collect::<Vec<_>>();
+    /// let ct_order_customers = orders.iter().map(|o| cks.encrypt(o.0)).collect::<Vec<_>>();
+    /// let ct_amounts = orders.iter().map(|o| cks.encrypt(o.1)).collect::<Vec<_>>();
+    ///
+    /// let sums = sks.eq_join_sum_parallelized(&ct_customers, &ct_order_customers, &ct_amounts);
+    ///
+    /// let sums = sums.iter().map(|s| cks.decrypt(s)).collect::<Vec<u64>>();
+    /// assert_eq!(sums, vec![5, 30, 0]);
+    /// ```
+    pub fn eq_join_sum_parallelized<T, V>(
+        &self,
+        left_keys: &[T],
+        right_keys: &[T],
+        right_values: &[V],
+    ) -> Vec<V>
+    where
+        T: IntegerRadixCiphertext,
+        V: IntegerRadixCiphertext,
+    {
+        let mut left_clone = vec![];
+        let mut right_clone = vec![];
+        let mut values_clone = vec![];
+        let ((left_keys, right_keys), right_values) = rayon::join(
+            || {
+                rayon::join(
+                    || self.carry_free_slice(left_keys, &mut left_clone),
+                    || self.carry_free_slice(right_keys, &mut right_clone),
+                )
+            },
+            || self.carry_free_slice(right_values, &mut values_clone),
+        );
+
+        self.unchecked_eq_join_sum_parallelized(left_keys, right_keys, right_values)
+    }
+}
@@ -0,0 +1,221 @@
+use crate::integer::block_decomposition::BlockDecomposer;
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::ServerKey;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Computes the product of a clear matrix with an encrypted vector.
+    ///
+    /// The result has one ciphertext per row of the matrix, each being the sum of the products of
+    /// the row coefficients with the vector elements, computed modulo the ciphertext modulus. The
+    /// coefficients are signed, a negative coefficient multiplies the two's complement negation of
+    /// the element.
+    ///
+    /// Each product is decomposed into the vector element shifted by the positions of the bits set
+    /// in the coefficient. Shifting by whole blocks is free, so the shifts by less than one block
+    /// are computed once per vector element and reused by all the rows. The terms of a row are
+    /// then accumulated without propagating carries, which are propagated once per row. The rows
+    /// are computed in parallel.
+    ///
+    /// # Notes
+    ///
+    /// - ciphertexts in the vector must not have any carries
+    /// - ciphertexts in the vector must all have the same number of blocks
+    /// - each row of the matrix must have as many coefficients as there are vector elements
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let matrix = [[1i64, 2, 3], [-1, 0, 5]];
+    /// let clear_vector = [4u64, 5, 6];
+    ///
+    /// let vector = clear_vector
+    ///     .iter()
+    ///     .map(|&x| cks.encrypt(x))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let result = sks.unchecked_clear_matrix_mul_enc_vector_parallelized(&matrix, &vector);
+    ///
+    /// let decrypted = result
+    ///     .iter()
+    ///     .map(|ct| cks.decrypt::<u64>(ct))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(decrypted, vec![32, 26]);
+    /// ```
+    pub fn unchecked_clear_matrix_mul_enc_vector_parallelized<T, Row>(
+        &self,
+        matrix: &[Row],
+        vector: &[T],
+    ) -> Vec<T>
+    where
+        T: IntegerRadixCiphertext,
+        Row: AsRef<[i64]> + Sync,
+    {
+        assert!(
+            matrix.iter().all(|row| row.as_ref().len() == vector.len()),
+            "Each row of the matrix must have {} coefficients, one per vector element",
+            vector.len()
+        );
+
+        let rows = matrix
+            .iter()
+            .map(|row| row.as_ref().iter().copied().enumerate().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        self.unchecked_clear_sparse_matrix_mul_enc_vector_parallelized(&rows, vector)
+    }
+
+    /// Computes the product of a clear matrix, given as rows of `(vector index, coefficient)`
+    /// pairs, with an encrypted vector.
+    ///
+    /// This is the implementation behind
+    /// [Self::unchecked_clear_matrix_mul_enc_vector_parallelized]. Only the coefficients listed in
+    /// the rows are computed, which makes it suitable for sparse matrices. Vector elements that
+    /// are not referenced by any row are never shifted.
+    ///
+    /// # Notes
+    ///
+    /// - ciphertexts in the vector must not have any carries
+    /// - ciphertexts in the vector must all have the same number of blocks
+    /// - indices must be in bounds of the vector
+    pub(super) fn unchecked_clear_sparse_matrix_mul_enc_vector_parallelized<T>(
+        &self,
+        rows: &[Vec<(usize, i64)>],
+        vector: &[T],
+    ) -> Vec<T>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(!vector.is_empty(), "The encrypted vector must not be empty");
+        let num_blocks = vector[0].blocks().len();
+        assert!(
+            vector[1..].iter().all(|ct| ct.blocks().len() == num_blocks),
+            "Not all ciphertexts have the same number of blocks"
+        );
+
+        let msg_bits = self.key.message_modulus.0.ilog2() as usize;
+        let num_ciphertext_bits = msg_bits * num_blocks;
+
+        // Bits of the absolute value of the coefficient, shifts beyond the ciphertext bits
+        // technically result in 0s
+        let coefficient_bits = |coefficient: i64| {
+            BlockDecomposer::with_early_stop_at_zero(coefficient.unsigned_abs(), 1)
+                .iter_as::<u8>()
+                .take(num_ciphertext_bits)
+                .collect::<Vec<_>>()
+        };
+
+        // For each vector element, the shifts (in 0..msg_bits) that are used by the rows, for
+        // the element itself and for its negation
+        let mut used_shifts = vec![[vec![false; msg_bits], vec![false; msg_bits]]; vector.len()];
+        for row in rows {
+            for &(index, coefficient) in row {
+                let used = &mut used_shifts[index][usize::from(coefficient < 0)];
+                for (i, bit) in coefficient_bits(coefficient).into_iter().enumerate() {
+                    if bit == 1 {
+                        used[i % msg_bits] = true;
+                    }
+                }
+            }
+        }
+
+        // Contains all the needed shifted values of the elements and of their negations, for
+        // shifts in range (0..msg_bits). The other shifts are created for free by block rotation
+        let preshifted = vector
+            .par_iter()
+            .zip(used_shifts.par_iter())
+            .map(|(ct, [used, used_negated])| {
+                let preshift = |base: &T, used: &[bool]| {
+                    used.par_iter()
+                        .enumerate()
+                        .map(|(shift_amount, &is_used)| {
+                            is_used.then(|| {
+                                self.unchecked_scalar_left_shift_parallelized(base, shift_amount)
+                            })
+                        })
+                        .collect::<Vec<_>>()
+                };
+
+                rayon::join(
+                    || preshift(ct, used),
+                    || {
+                        if used_negated.contains(&true) {
+                            preshift(&self.neg_parallelized(ct), used_negated)
+                        } else {
+                            vec![None; msg_bits]
+                        }
+                    },
+                )
+            })
+            .collect::<Vec<_>>();
+
+        rows.par_iter()
+            .map(|row| {
+                let terms = row
+                    .iter()
+                    .flat_map(|&(index, coefficient)| {
+                        let (shifted, shifted_negated) = &preshifted[index];
+                        let shifted = if coefficient < 0 {
+                            shifted_negated
+                        } else {
+                            shifted
+                        };
+                        coefficient_bits(coefficient)
+                            .into_iter()
+                            .enumerate()
+                            .filter(|(_, bit)| *bit == 1)
+                            .map(move |(i, _)| {
+                                let preshifted = shifted[i % msg_bits]
+                                    .as_ref()
+                                    .expect("the shift is used by this coefficient");
+                                self.blockshift(preshifted, i / msg_bits)
+                            })
+                    })
+                    .collect::<Vec<_>>();
+
+                self.unchecked_sum_ciphertexts_vec_parallelized(terms)
+                    .unwrap_or_else(|| self.create_trivial_zero_radix(num_blocks))
+            })
+            .collect()
+    }
+
+    /// Computes the product of a clear matrix with an encrypted vector.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs ciphertexts whose block carries are always
+    /// empty.
+    ///
+    /// See [Self::unchecked_clear_matrix_mul_enc_vector_parallelized]
+    pub fn clear_matrix_mul_enc_vector_parallelized<T, Row>(
+        &self,
+        matrix: &[Row],
+        vector: &[T],
+    ) -> Vec<T>
+    where
+        T: IntegerRadixCiphertext,
+        Row: AsRef<[i64]> + Sync,
+    {
+        let mut tmp_vector;
+
+        let vector = if vector.iter().any(|ct| !ct.block_carries_are_empty()) {
+            tmp_vector = vector.to_vec();
+            tmp_vector
+                .par_iter_mut()
+                .filter(|ct| !ct.block_carries_are_empty())
+                .for_each(|ct| self.full_propagate_parallelized(ct));
+            &tmp_vector
+        } else {
+            vector
+        };
+
+        self.unchecked_clear_matrix_mul_enc_vector_parallelized(matrix, vector)
+    }
+}
@@ -5,9 +5,12 @@ mod bitwise_op;
 pub(crate) mod cmux;
 mod comparison;
 mod div_mod;
+mod dot_product;
 mod modulus_switch_compression;
 mod mul;
 mod neg;
+mod pow;
+mod progressive_comparison;
 mod rotate;
 mod scalar_add;
 mod scalar_bitwise_op;
@@ -21,10 +24,25 @@ mod shift;
 pub(crate) mod sub;
 mod sum;
 
+mod clamp;
+mod conv2d;
 mod count_zeros_ones;
 pub(crate) mod ilog2;
+mod is_in_range;
+mod is_zero;
+mod join;
+mod matrix_mul;
+mod modular_arith;
+mod reduce;
 mod reverse_bits;
+mod saturating_cast;
+mod saturating_ops;
+mod scalar_thresholds;
+mod select_case;
 mod slice;
+mod sort;
+mod sqrt;
+pub(crate) mod swap_bytes;
 #[cfg(test)]
 pub(crate) mod tests_cases_unsigned;
 #[cfg(test)]
@@ -0,0 +1,430 @@
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey};
+
+/// Constants of the Barrett reduction modulo a clear `modulus`
+///
+/// With `k` the number of bits of the modulus and `factor = floor(4^k / modulus)`,
+/// any `x < modulus^2` is reduced with `q = ((x >> (k - 1)) * factor) >> (k + 1)`,
+/// which underestimates `x / modulus` by at most 2, so that `x - q * modulus` is in
+/// `[0, 3 * modulus)` and only needs up to two conditional subtractions of the modulus.
+struct BarrettConstants {
+    modulus: u64,
+    num_bits: u32,
+    factor: u128,
+}
+
+impl BarrettConstants {
+    /// The modulus must be at least 2
+    fn new(modulus: u64) -> Self {
+        debug_assert!(modulus >= 2);
+        let num_bits = u64::BITS - modulus.leading_zeros();
+        // 4^num_bits may not fit in a u128, so it is divided as 4^num_bits - 1,
+        // whose quotient is one less only when the remainder would wrap around
+        let max = u128::MAX >> (u128::BITS - 2 * num_bits);
+        let modulus_128 = u128::from(modulus);
+        let factor = max / modulus_128 + u128::from(max % modulus_128 == modulus_128 - 1);
+
+        Self {
+            modulus,
+            num_bits,
+            factor,
+        }
+    }
+}
+
+impl ServerKey {
+    /// Number of blocks needed to store `num_bits` bits
+    fn num_blocks_for_bits(&self, num_bits: u32) -> usize {
+        num_bits.div_ceil(self.message_modulus().0.ilog2()) as usize
+    }
+
+    /// Checks that the modulus can be used with inputs of `num_blocks` blocks, and returns the
+    /// number of blocks needed to hold the modulus itself
+    fn modular_working_num_blocks(&self, num_blocks: usize, modulus: u64) -> usize {
+        assert_ne!(
+            modulus, 0,
+            "attempt to calculate the remainder with a divisor of zero"
+        );
+        let num_modulus_bits = (modulus - 1).checked_ilog2().map_or(0, |log2| log2 + 1);
+        let num_modulus_blocks = self.num_blocks_for_bits(num_modulus_bits);
+        assert!(
+            num_modulus_blocks <= num_blocks,
+            "The modulus {modulus} needs {num_modulus_blocks} blocks, \
+            but the ciphertexts only have {num_blocks} blocks"
+        );
+
+        self.num_blocks_for_bits(u64::BITS - modulus.leading_zeros())
+    }
+
+    /// Subtracts `modulus` from `ct` if `ct >= modulus`
+    ///
+    /// `overflowed` tells whether `ct` has wrapped around its number of blocks, in which case
+    /// the actual value is greater than the modulus.
+    fn conditional_sub_modulus(
+        &self,
+        ct: &RadixCiphertext,
+        overflowed: Option<&BooleanBlock>,
+        modulus: u64,
+    ) -> RadixCiphertext {
+        let (reduced, mut is_below_modulus) =
+            self.unsigned_overflowing_scalar_sub_parallelized(ct, modulus);
+        if let Some(overflowed) = overflowed {
+            let did_not_overflow = self.boolean_bitnot(overflowed);
+            self.boolean_bitand_assign(&mut is_below_modulus, &did_not_overflow);
+        }
+        self.unchecked_if_then_else_parallelized(&is_below_modulus, ct, &reduced)
+    }
+
+    /// Computes homomorphically `(lhs + rhs) % modulus`, where `modulus` is clear
+    ///
+    /// The sum is reduced with a single conditional subtraction of the modulus, which is much
+    /// cheaper than a remainder.
+    ///
+    /// # Notes
+    ///
+    /// - the inputs must not have any carries
+    /// - the inputs must be smaller than `modulus`, otherwise the result is meaningless
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `modulus` is 0
+    /// - Panics if `modulus - 1` cannot be represented with the number of blocks of the inputs
+    pub fn unchecked_mod_add_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        modulus: u64,
+    ) -> RadixCiphertext {
+        let num_blocks = lhs.blocks.len();
+        let working_num_blocks = self.modular_working_num_blocks(num_blocks, modulus);
+
+        let (lhs, rhs) = rayon::join(
+            || self.cast_to_unsigned(lhs.clone(), working_num_blocks),
+            || self.cast_to_unsigned(rhs.clone(), working_num_blocks),
+        );
+        let (sum, overflowed) = self.unsigned_overflowing_add_parallelized(&lhs, &rhs);
+        let result = self.conditional_sub_modulus(&sum, Some(&overflowed), modulus);
+
+        self.cast_to_unsigned(result, num_blocks)
+    }
+
+    /// Computes homomorphically `(lhs + rhs) % modulus`, where `modulus` is clear
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// See [Self::unchecked_mod_add_parallelized]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let modulus = 251u64;
+    /// let ct1 = cks.encrypt(200u64);
+    /// let ct2 = cks.encrypt(100u64);
+    ///
+    /// let ct_res = sks.mod_add_parallelized(&ct1, &ct2, modulus);
+    ///
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, (200 + 100) % modulus);
+    /// ```
+    pub fn mod_add_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        modulus: u64,
+    ) -> RadixCiphertext {
+        let (lhs, rhs) = self.propagated_operands_parallelized(lhs, rhs);
+        self.unchecked_mod_add_parallelized(&lhs, &rhs, modulus)
+    }
+
+    /// Computes homomorphically `(lhs - rhs) % modulus`, where `modulus` is clear
+    ///
+    /// The result is always in `[0, modulus)`: the modulus is added back when the difference
+    /// is negative.
+    ///
+    /// # Notes
+    ///
+    /// - the inputs must not have any carries
+    /// - the inputs must be smaller than `modulus`, otherwise the result is meaningless
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `modulus` is 0
+    /// - Panics if `modulus - 1` cannot be represented with the number of blocks of the inputs
+    pub fn unchecked_mod_sub_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        modulus: u64,
+    ) -> RadixCiphertext {
+        let num_blocks = lhs.blocks.len();
+        let working_num_blocks = self.modular_working_num_blocks(num_blocks, modulus);
+
+        let (lhs, rhs) = rayon::join(
+            || self.cast_to_unsigned(lhs.clone(), working_num_blocks),
+            || self.cast_to_unsigned(rhs.clone(), working_num_blocks),
+        );
+        let (difference, is_negative) = self.unsigned_overflowing_sub_parallelized(&lhs, &rhs);
+        // The difference wrapped around, adding the modulus wraps it back in [0, modulus)
+        let corrected = self.scalar_add_parallelized(&difference, modulus);
+        let result =
+            self.unchecked_if_then_else_parallelized(&is_negative, &corrected, &difference);
+
+        self.cast_to_unsigned(result, num_blocks)
+    }
+
+    /// Computes homomorphically `(lhs - rhs) % modulus`, where `modulus` is clear
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// See [Self::unchecked_mod_sub_parallelized]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let modulus = 251u64;
+    /// let ct1 = cks.encrypt(100u64);
+    /// let ct2 = cks.encrypt(200u64);
+    ///
+    /// let ct_res = sks.mod_sub_parallelized(&ct1, &ct2, modulus);
+    ///
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, (100 + modulus - 200) % modulus);
+    /// ```
+    pub fn mod_sub_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        modulus: u64,
+    ) -> RadixCiphertext {
+        let (lhs, rhs) = self.propagated_operands_parallelized(lhs, rhs);
+        self.unchecked_mod_sub_parallelized(&lhs, &rhs, modulus)
+    }
+
+    /// Computes homomorphically `(lhs * rhs) % modulus`, where `modulus` is clear
+    ///
+    /// The product is computed on as many blocks as needed to hold `modulus^2`, and reduced
+    /// with a Barrett reduction whose constants are precomputed in the clear: two clear
+    /// multiplications and up to two conditional subtractions of the modulus, instead of a
+    /// full remainder.
+    ///
+    /// # Notes
+    ///
+    /// - the inputs must not have any carries
+    /// - the inputs must be smaller than `modulus`, otherwise the result is meaningless
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `modulus` is 0
+    /// - Panics if `modulus - 1` cannot be represented with the number of blocks of the inputs
+    pub fn unchecked_mod_mul_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        modulus: u64,
+    ) -> RadixCiphertext {
+        let num_blocks = lhs.blocks.len();
+        self.modular_working_num_blocks(num_blocks, modulus);
+        if modulus == 1 {
+            return self.create_trivial_zero_radix(num_blocks);
+        }
+
+        let constants = BarrettConstants::new(modulus);
+        let k = constants.num_bits;
+
+        // lhs * rhs < modulus^2 < 2^(2k)
+        let product_num_blocks = self.num_blocks_for_bits(2 * k);
+        let (lhs, rhs) = rayon::join(
+            || self.cast_to_unsigned(lhs.clone(), product_num_blocks),
+            || self.cast_to_unsigned(rhs.clone(), product_num_blocks),
+        );
+        let product = self.mul_parallelized(&lhs, &rhs);
+
+        let result = self.unchecked_barrett_reduce_parallelized(product, &constants);
+        self.cast_to_unsigned(result, num_blocks)
+    }
+
+    /// Reduces `x < modulus^2` modulo the modulus of the constants
+    ///
+    /// The result has enough blocks to hold `k + 2` bits, `k` being the number of bits of the
+    /// modulus.
+    fn unchecked_barrett_reduce_parallelized(
+        &self,
+        x: RadixCiphertext,
+        constants: &BarrettConstants,
+    ) -> RadixCiphertext {
+        let k = constants.num_bits;
+        // The remainder before the conditional subtractions is smaller than 3 * modulus
+        let remainder_num_blocks = self.num_blocks_for_bits(k + 2);
+
+        // (x >> (k - 1)) < 2^(k + 1), and so its product with factor < 2^(k + 1)
+        // is smaller than 2^(2k + 2)
+        let quotient = self.scalar_right_shift_parallelized(&x, k - 1);
+        let quotient = self.cast_to_unsigned(quotient, self.num_blocks_for_bits(2 * k + 2));
+        let quotient = self.scalar_mul_parallelized(&quotient, constants.factor);
+        let quotient = self.scalar_right_shift_parallelized(&quotient, k + 1);
+
+        // The remainder fits in remainder_num_blocks, so both terms can be computed
+        // modulo 2^(remainder_num_blocks * bits_per_block)
+        let (x, quotient_times_modulus) = rayon::join(
+            || self.cast_to_unsigned(x, remainder_num_blocks),
+            || {
+                let quotient = self.cast_to_unsigned(quotient, remainder_num_blocks);
+                self.scalar_mul_parallelized(&quotient, constants.modulus)
+            },
+        );
+        let remainder = self.sub_parallelized(&x, &quotient_times_modulus);
+
+        let remainder = self.conditional_sub_modulus(&remainder, None, constants.modulus);
+        self.conditional_sub_modulus(&remainder, None, constants.modulus)
+    }
+
+    /// Computes homomorphically `(lhs * rhs) % modulus`, where `modulus` is clear
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// See [Self::unchecked_mod_mul_parallelized]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let modulus = 251u64;
+    /// let ct1 = cks.encrypt(200u64);
+    /// let ct2 = cks.encrypt(100u64);
+    ///
+    /// let ct_res = sks.mod_mul_parallelized(&ct1, &ct2, modulus);
+    ///
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, (200 * 100) % modulus);
+    /// ```
+    pub fn mod_mul_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        modulus: u64,
+    ) -> RadixCiphertext {
+        let (lhs, rhs) = self.propagated_operands_parallelized(lhs, rhs);
+        self.unchecked_mod_mul_parallelized(&lhs, &rhs, modulus)
+    }
+
+    /// Computes homomorphically `base^exponent % modulus`, where `exponent` and `modulus` are
+    /// clear
+    ///
+    /// The squares `base^(2^i) % modulus` are computed for each bit of the exponent, and those
+    /// selected by the exponent are multiplied together in a balanced tree, all the products
+    /// being reduced with [Self::unchecked_mod_mul_parallelized].
+    ///
+    /// # Notes
+    ///
+    /// - the base must not have any carries
+    /// - the base must be smaller than `modulus`, otherwise the result is meaningless
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `modulus` is 0
+    /// - Panics if `modulus - 1` cannot be represented with the number of blocks of the base
+    pub fn unchecked_scalar_pow_mod_parallelized(
+        &self,
+        base: &RadixCiphertext,
+        exponent: u64,
+        modulus: u64,
+    ) -> RadixCiphertext {
+        let num_blocks = base.blocks.len();
+        self.modular_working_num_blocks(num_blocks, modulus);
+        if exponent == 0 {
+            return self.create_trivial_radix(1 % modulus, num_blocks);
+        }
+
+        // Squares are only computed up to the most significant set bit of the exponent
+        let num_bits = u64::BITS - exponent.leading_zeros();
+        let mut factors = Vec::with_capacity(exponent.count_ones() as usize);
+        let mut square = base.clone();
+        for i in 0..num_bits {
+            if (exponent >> i) & 1 == 1 {
+                factors.push(square.clone());
+            }
+            if i + 1 < num_bits {
+                square = self.unchecked_mod_mul_parallelized(&square, &square, modulus);
+            }
+        }
+
+        Self::reduce_tree_parallelized(factors, |lhs, rhs| {
+            self.unchecked_mod_mul_parallelized(lhs, rhs, modulus)
+        })
+        .expect("internal error, the exponent is not zero")
+    }
+
+    /// Computes homomorphically `base^exponent % modulus`, where `exponent` and `modulus` are
+    /// clear
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertext block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// See [Self::unchecked_scalar_pow_mod_parallelized]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let modulus = 251u64;
+    /// let ct = cks.encrypt(7u64);
+    ///
+    /// let ct_res = sks.scalar_pow_mod_parallelized(&ct, 10, modulus);
+    ///
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 7u64.pow(10) % modulus);
+    /// ```
+    pub fn scalar_pow_mod_parallelized(
+        &self,
+        base: &RadixCiphertext,
+        exponent: u64,
+        modulus: u64,
+    ) -> RadixCiphertext {
+        let mut tmp_base;
+        let base = if base.block_carries_are_empty() {
+            base
+        } else {
+            tmp_base = base.clone();
+            self.full_propagate_parallelized(&mut tmp_base);
+            &tmp_base
+        };
+
+        self.unchecked_scalar_pow_mod_parallelized(base, exponent, modulus)
+    }
+}
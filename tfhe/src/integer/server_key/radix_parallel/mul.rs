@@ -295,7 +295,7 @@ impl ServerKey {
     /// This functions computes the terms resulting from multiplying each block
     /// of rhs with lhs. When summed these terms will give the low part of the result.
     /// i.e. in a (lhs: Nbit * rhs: Nbit) multiplication, summing the terms will give a N bit result
-    fn compute_terms_for_mul_low<T>(&self, lhs: &T, rhs: &T) -> Vec<T>
+    pub(super) fn compute_terms_for_mul_low<T>(&self, lhs: &T, rhs: &T) -> Vec<T>
     where
         T: IntegerRadixCiphertext,
     {
@@ -757,6 +757,171 @@ impl ServerKey {
         self.unchecked_unsigned_overflowing_mul_assign_parallelized(lhs, rhs)
     }
 
+    /// Computes homomorphically the full product of two ciphertexts encrypting unsigned integers
+    ///
+    /// The result has as many blocks as `lhs` and `rhs` together, so the product never wraps
+    /// around.
+    ///
+    /// Only the block products that are not known to be zero are computed, so this is about
+    /// the cost of a multiplication at the width of the inputs, and cheaper than first extending
+    /// the inputs to the width of the result and multiplying them.
+    ///
+    /// # Notes
+    ///
+    /// - the ciphertexts must not have any carries
+    pub fn unchecked_widening_mul_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let num_blocks = lhs.blocks.len() + rhs.blocks.len();
+        // The padding blocks are trivial zeros, the terms computation skips them
+        let lhs = self.extend_radix_with_trivial_zero_blocks_msb(lhs, rhs.blocks.len());
+        let rhs =
+            self.extend_radix_with_trivial_zero_blocks_msb(rhs, num_blocks - rhs.blocks.len());
+
+        let terms = self.compute_terms_for_mul_low(&lhs, &rhs);
+
+        self.unchecked_sum_ciphertexts_vec_parallelized(terms)
+            .unwrap_or_else(|| self.create_trivial_zero_radix(num_blocks))
+    }
+
+    /// Computes homomorphically the full product of two ciphertexts encrypting unsigned integers
+    ///
+    /// The result has as many blocks as `lhs` and `rhs` together, so the product never wraps
+    /// around.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, IntegerCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let clear_1 = 200u8;
+    /// let clear_2 = 150u8;
+    ///
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt(clear_1);
+    /// let ctxt_2 = cks.encrypt(clear_2);
+    ///
+    /// // Compute homomorphically the full multiplication
+    /// let ct_res = sks.widening_mul_parallelized(&ctxt_1, &ctxt_2);
+    /// assert_eq!(ct_res.blocks().len(), 2 * num_blocks);
+    ///
+    /// // Decrypt
+    /// let res: u16 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, u16::from(clear_1) * u16::from(clear_2));
+    /// ```
+    pub fn widening_mul_parallelized(
+        &self,
+        ct1: &RadixCiphertext,
+        ct2: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let mut tmp_lhs;
+        let mut tmp_rhs;
+
+        let (lhs, rhs) = match (ct1.block_carries_are_empty(), ct2.block_carries_are_empty()) {
+            (true, true) => (ct1, ct2),
+            (true, false) => {
+                tmp_rhs = ct2.clone();
+                self.full_propagate_parallelized(&mut tmp_rhs);
+                (ct1, &tmp_rhs)
+            }
+            (false, true) => {
+                tmp_lhs = ct1.clone();
+                self.full_propagate_parallelized(&mut tmp_lhs);
+                (&tmp_lhs, ct2)
+            }
+            (false, false) => {
+                tmp_lhs = ct1.clone();
+                tmp_rhs = ct2.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_lhs),
+                    || self.full_propagate_parallelized(&mut tmp_rhs),
+                );
+                (&tmp_lhs, &tmp_rhs)
+            }
+        };
+
+        self.unchecked_widening_mul_parallelized(lhs, rhs)
+    }
+
+    /// Computes homomorphically the high part of the product of two ciphertexts encrypting
+    /// unsigned integers
+    ///
+    /// That is, for `N` bits inputs, the `N` most significant bits of the `2N` bits product,
+    /// the low part being the result of [Self::unchecked_mul_parallelized].
+    ///
+    /// # Notes
+    ///
+    /// - the ciphertexts must not have any carries
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertexts do not have the same number of blocks
+    pub fn unchecked_mul_high_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        assert_eq!(
+            lhs.blocks.len(),
+            rhs.blocks.len(),
+            "lhs and rhs must have the same number of blocks"
+        );
+        let mut result = self.unchecked_widening_mul_parallelized(lhs, rhs);
+        self.trim_radix_blocks_lsb_assign(&mut result, lhs.blocks.len());
+        result
+    }
+
+    /// Computes homomorphically the high part of the product of two ciphertexts encrypting
+    /// unsigned integers
+    ///
+    /// That is, for `N` bits inputs, the `N` most significant bits of the `2N` bits product,
+    /// the low part being the result of [Self::mul_parallelized].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertexts do not have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let clear_1 = 200u8;
+    /// let clear_2 = 150u8;
+    ///
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt(clear_1);
+    /// let ctxt_2 = cks.encrypt(clear_2);
+    ///
+    /// // Compute homomorphically the high part of the multiplication
+    /// let ct_res = sks.mul_high_parallelized(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, ((u16::from(clear_1) * u16::from(clear_2)) >> 8) as u8);
+    /// ```
+    pub fn mul_high_parallelized(
+        &self,
+        ct1: &RadixCiphertext,
+        ct2: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let mut result = self.widening_mul_parallelized(ct1, ct2);
+        self.trim_radix_blocks_lsb_assign(&mut result, ct1.blocks.len());
+        result
+    }
+
     /// Computes homomorphically a multiplication along with an overflow flag
     ///
     /// # Example
@@ -0,0 +1,403 @@
+use crate::integer::ciphertext::{IntegerCiphertext, IntegerRadixCiphertext};
+use crate::integer::server_key::radix_parallel::bit_extractor::BitExtractor;
+use crate::integer::{RadixCiphertext, ServerKey};
+use crate::shortint::Ciphertext;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Multiplies all the factors together, using a balanced tree of multiplications
+    /// so that independent multiplications are computed in parallel
+    ///
+    /// Returns None if there are no factors
+    fn product_tree_parallelized<T>(&self, factors: Vec<T>) -> Option<T>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        Self::reduce_tree_parallelized(factors, |lhs, rhs| self.mul_parallelized(lhs, rhs))
+    }
+
+    fn trivial_one<T>(&self, num_blocks: usize) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let blocks = (0..num_blocks)
+            .map(|i| self.key.create_trivial(u64::from(i == 0)))
+            .collect::<Vec<_>>();
+        T::from_blocks(blocks)
+    }
+
+    /// Computes homomorphically `base` raised to the power of an encrypted `exponent`
+    ///
+    /// The result is computed modulo the number of values the base can represent
+    /// (i.e. it wraps around like `u64::wrapping_pow`).
+    ///
+    /// Uses the square-and-multiply algorithm: the squares `base^(2^i)` are computed for each
+    /// bit of the exponent, each square is replaced by one when the corresponding encrypted bit
+    /// is zero, and the selected factors are then multiplied together.
+    ///
+    /// # Notes
+    ///
+    /// - base and exponent must not have any carries
+    /// - the cost grows linearly with the number of bits of the exponent, use an exponent with as
+    ///   few blocks as possible
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let base = 3u64;
+    /// let exponent = 5u64;
+    ///
+    /// let ct_base = cks.encrypt(base);
+    /// // The exponent only needs 2 blocks
+    /// let ct_exponent = cks.as_ref().encrypt_radix(exponent, 2);
+    ///
+    /// let ct_res = sks.unchecked_pow_parallelized(&ct_base, &ct_exponent);
+    ///
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, base.pow(exponent as u32) % 256);
+    /// ```
+    pub fn unchecked_pow_parallelized<T>(&self, base: &T, exponent: &RadixCiphertext) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let num_blocks = base.blocks().len();
+        if num_blocks == 0 || exponent.blocks().is_empty() {
+            // An empty exponent is interpreted as 0, and base^0 == 1
+            return self.trivial_one(num_blocks);
+        }
+
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let bits_per_block = message_modulus.ilog2() as usize;
+
+        // The exponent bits are extracted while the squares are computed
+        let (exponent_bits, squares) = rayon::join(
+            || BitExtractor::new(self, bits_per_block).extract_all_bits(exponent.blocks()),
+            || {
+                let num_squares = exponent.blocks().len() * bits_per_block;
+                let mut squares = Vec::with_capacity(num_squares);
+                squares.push(base.clone());
+                for i in 1..num_squares {
+                    let previous = &squares[i - 1];
+                    let square = self.mul_parallelized(previous, previous);
+                    squares.push(square);
+                }
+                squares
+            },
+        );
+
+        // Selects base^(2^i) if the i-th bit is set, 1 otherwise,
+        // one bivariate PBS per block does the selection
+        let keep_lut = self
+            .key
+            .generate_lookup_table_bivariate(|x, bit| if bit == 0 { 0 } else { x });
+        let keep_or_one_lut =
+            self.key
+                .generate_lookup_table_bivariate(|x, bit| if bit == 0 { 1 } else { x });
+
+        let factors = squares
+            .par_iter()
+            .zip(exponent_bits.par_iter())
+            .map(|(square, bit)| {
+                let blocks = square
+                    .blocks()
+                    .par_iter()
+                    .enumerate()
+                    .map(|(block_index, block)| {
+                        let lut = if block_index == 0 {
+                            &keep_or_one_lut
+                        } else {
+                            &keep_lut
+                        };
+                        self.key
+                            .unchecked_apply_lookup_table_bivariate(block, bit, lut)
+                    })
+                    .collect::<Vec<Ciphertext>>();
+                T::from_blocks(blocks)
+            })
+            .collect::<Vec<_>>();
+
+        self.product_tree_parallelized(factors)
+            .expect("internal error, the exponent has at least one bit")
+    }
+
+    /// Computes homomorphically `base` raised to the power of an encrypted `exponent`
+    ///
+    /// The result is computed modulo the number of values the base can represent
+    /// (i.e. it wraps around like `u64::wrapping_pow`).
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// See [Self::unchecked_pow_parallelized]
+    pub fn pow_parallelized<T>(&self, base: &T, exponent: &RadixCiphertext) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_base;
+        let mut tmp_exponent;
+
+        let (base, exponent) = match (
+            base.block_carries_are_empty(),
+            exponent.block_carries_are_empty(),
+        ) {
+            (true, true) => (base, exponent),
+            (true, false) => {
+                tmp_exponent = exponent.clone();
+                self.full_propagate_parallelized(&mut tmp_exponent);
+                (base, &tmp_exponent)
+            }
+            (false, true) => {
+                tmp_base = base.clone();
+                self.full_propagate_parallelized(&mut tmp_base);
+                (&tmp_base, exponent)
+            }
+            (false, false) => {
+                tmp_base = base.clone();
+                tmp_exponent = exponent.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_base),
+                    || self.full_propagate_parallelized(&mut tmp_exponent),
+                );
+                (&tmp_base, &tmp_exponent)
+            }
+        };
+
+        self.unchecked_pow_parallelized(base, exponent)
+    }
+
+    /// Computes homomorphically `base` raised to the power of a clear `exponent`
+    ///
+    /// The result is computed modulo the number of values the base can represent
+    /// (i.e. it wraps around like `u64::wrapping_pow`).
+    ///
+    /// As the exponent is clear, only the squares needed by the set bits of the exponent are
+    /// computed and multiplied together, which is much cheaper than
+    /// [Self::pow_parallelized].
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertext block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let base = 7u64;
+    ///
+    /// let ct_base = cks.encrypt(base);
+    ///
+    /// let ct_res = sks.scalar_pow_parallelized(&ct_base, 3);
+    ///
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, base.pow(3) % 256);
+    /// ```
+    pub fn scalar_pow_parallelized<T>(&self, base: &T, exponent: u32) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let num_blocks = base.blocks().len();
+        if num_blocks == 0 || exponent == 0 {
+            return self.trivial_one(num_blocks);
+        }
+
+        let mut tmp_base;
+        let base = if base.block_carries_are_empty() {
+            base
+        } else {
+            tmp_base = base.clone();
+            self.full_propagate_parallelized(&mut tmp_base);
+            &tmp_base
+        };
+
+        // Squares are only computed up to the most significant set bit of the exponent
+        let num_bits = u32::BITS - exponent.leading_zeros();
+        let mut factors = Vec::with_capacity(exponent.count_ones() as usize);
+        let mut square = base.clone();
+        for i in 0..num_bits {
+            if (exponent >> i) & 1 == 1 {
+                factors.push(square.clone());
+            }
+            if i + 1 < num_bits {
+                square = self.mul_parallelized(&square, &square);
+            }
+        }
+
+        self.product_tree_parallelized(factors)
+            .expect("internal error, the exponent is not zero")
+    }
+
+    /// Computes homomorphically `base` raised to the power of an encrypted `exponent`, modulo
+    /// `modulus`, where `base` and `modulus` are clear
+    ///
+    /// The result has the same number of blocks as the exponent.
+    ///
+    /// As the base is clear, the powers `base^(d * message_modulus^i) % modulus` of each
+    /// possible digit `d` of each block `i` of the exponent are precomputed in the clear.
+    /// Each encrypted digit then selects its power with one PBS per output block, all these PBS
+    /// being independent, and the selected powers are multiplied together modulo `modulus` in a
+    /// balanced tree. This is much cheaper than [Self::pow_parallelized], which needs to square
+    /// an encrypted base for each bit of the exponent.
+    ///
+    /// # Notes
+    ///
+    /// - the exponent must not have any carries
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `modulus` is 0
+    /// - Panics if `modulus - 1` cannot be represented with the number of blocks of the exponent
+    pub fn unchecked_scalar_base_pow_mod_parallelized(
+        &self,
+        base: u64,
+        exponent: &RadixCiphertext,
+        modulus: u64,
+    ) -> RadixCiphertext {
+        assert_ne!(
+            modulus, 0,
+            "attempt to calculate the remainder with a divisor of zero"
+        );
+
+        let num_blocks = exponent.blocks.len();
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let bits_per_block = message_modulus.ilog2();
+
+        // Number of blocks needed to represent all the values in 0..modulus
+        let num_modulus_bits = (modulus - 1).checked_ilog2().map_or(0, |log2| log2 + 1);
+        let num_result_blocks = num_modulus_bits.div_ceil(bits_per_block) as usize;
+        assert!(
+            num_result_blocks <= num_blocks,
+            "The modulus {modulus} needs {num_result_blocks} blocks, \
+            but the exponent only has {num_blocks} blocks"
+        );
+
+        if num_result_blocks == 0 {
+            // modulus == 1, everything is 0
+            return self.create_trivial_zero_radix(num_blocks);
+        }
+
+        let clear_modulus = u128::from(modulus);
+        // tables[i][d] = base^(d * message_modulus^i) % modulus
+        let mut tables = Vec::with_capacity(num_blocks);
+        let mut digit_base = u128::from(base) % clear_modulus;
+        for _ in 0..num_blocks {
+            let mut table = Vec::with_capacity(message_modulus as usize);
+            let mut power = 1 % clear_modulus;
+            for _ in 0..message_modulus {
+                table.push(power as u64);
+                power = (power * digit_base) % clear_modulus;
+            }
+            // power == digit_base^message_modulus
+            digit_base = power;
+            tables.push(table);
+        }
+
+        // Blocks whose table only contains 1 do not change the product
+        let tables = tables
+            .into_iter()
+            .zip(exponent.blocks.iter())
+            .filter(|(table, _)| table.iter().any(|&power| power != 1))
+            .collect::<Vec<_>>();
+
+        let factors = tables
+            .par_iter()
+            .map(|(table, digit)| {
+                let blocks = (0..num_result_blocks)
+                    .into_par_iter()
+                    .map(|block_index| {
+                        let shift = block_index as u32 * bits_per_block;
+                        // The digit has no carries, the LUT is only evaluated on valid digits
+                        let lut = self.key.generate_lookup_table(|d| {
+                            (table[(d % message_modulus) as usize] >> shift) % message_modulus
+                        });
+                        self.key.apply_lookup_table(digit, &lut)
+                    })
+                    .collect::<Vec<_>>();
+                RadixCiphertext::from_blocks(blocks)
+            })
+            .collect::<Vec<_>>();
+
+        let mut result = Self::reduce_tree_parallelized(factors, |lhs, rhs| {
+            self.unchecked_mod_mul_parallelized(lhs, rhs, modulus)
+        })
+        .unwrap_or_else(|| self.create_trivial_radix(1u64, num_result_blocks));
+
+        self.extend_radix_with_trivial_zero_blocks_msb_assign(
+            &mut result,
+            num_blocks - num_result_blocks,
+        );
+        result
+    }
+
+    /// Computes homomorphically `base` raised to the power of an encrypted `exponent`, modulo
+    /// `modulus`, where `base` and `modulus` are clear
+    ///
+    /// The result has the same number of blocks as the exponent.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertext block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// See [Self::unchecked_scalar_base_pow_mod_parallelized]
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `modulus` is 0
+    /// - Panics if `modulus - 1` cannot be represented with the number of blocks of the exponent
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let base = 5u64;
+    /// let exponent = 117u64;
+    /// let modulus = 251u64;
+    ///
+    /// let ct_exponent = cks.encrypt(exponent);
+    ///
+    /// let ct_res = sks.scalar_base_pow_mod_parallelized(base, &ct_exponent, modulus);
+    ///
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// let expected = (0..exponent).fold(1, |acc, _| (acc * base) % modulus);
+    /// assert_eq!(dec, expected);
+    /// ```
+    pub fn scalar_base_pow_mod_parallelized(
+        &self,
+        base: u64,
+        exponent: &RadixCiphertext,
+        modulus: u64,
+    ) -> RadixCiphertext {
+        let mut tmp_exponent;
+        let exponent = if exponent.block_carries_are_empty() {
+            exponent
+        } else {
+            tmp_exponent = exponent.clone();
+            self.full_propagate_parallelized(&mut tmp_exponent);
+            &tmp_exponent
+        };
+
+        self.unchecked_scalar_base_pow_mod_parallelized(base, exponent, modulus)
+    }
+}
@@ -0,0 +1,386 @@
+use super::comparison::ComparisonKind;
+use crate::integer::ciphertext::boolean_value::BooleanBlock;
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::ServerKey;
+
+impl ServerKey {
+    /// Compares the `num_msb_blocks` most significant blocks, and all the blocks
+    ///
+    /// Returns `(approx, exact)` where `approx` only depends on the most significant blocks,
+    /// the low blocks being ignored, and `exact` is the result of the comparison on all the
+    /// blocks. Both comparisons are computed in parallel, so this costs the PBS of a regular
+    /// comparison plus the ones of a comparison of the most significant blocks.
+    ///
+    /// * inputs must have the same number of blocks
+    /// * block carries of both inputs must be empty
+    /// * carry modulus == message modulus
+    fn approx_then_exact_compare<T>(
+        &self,
+        lhs: &T,
+        rhs: &T,
+        num_msb_blocks: usize,
+        compare: ComparisonKind,
+    ) -> (BooleanBlock, BooleanBlock)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert_eq!(
+            lhs.blocks().len(),
+            rhs.blocks().len(),
+            "lhs and rhs must have the same number of blocks"
+        );
+        assert!(
+            num_msb_blocks > 0,
+            "At least one most significant block must be compared"
+        );
+
+        let num_blocks = lhs.blocks().len();
+        if num_msb_blocks >= num_blocks {
+            // There is nothing to refine
+            let exact = self.compare(lhs, rhs, compare);
+            return (exact.clone(), exact);
+        }
+
+        // The high blocks keep the signedness of the inputs
+        let num_low_blocks = num_blocks - num_msb_blocks;
+        let lhs_high = T::from_blocks(lhs.blocks()[num_low_blocks..].to_vec());
+        let rhs_high = T::from_blocks(rhs.blocks()[num_low_blocks..].to_vec());
+
+        rayon::join(
+            || self.compare(&lhs_high, &rhs_high, compare),
+            || self.compare(lhs, rhs, compare),
+        )
+    }
+
+    /// Propagates the carries of the inputs that have some
+    pub(super) fn propagated_operands_parallelized<T>(&self, lhs: &T, rhs: &T) -> (T, T)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut lhs = lhs.clone();
+        let mut rhs = rhs.clone();
+        rayon::join(
+            || {
+                if !lhs.block_carries_are_empty() {
+                    self.full_propagate_parallelized(&mut lhs);
+                }
+            },
+            || {
+                if !rhs.block_carries_are_empty() {
+                    self.full_propagate_parallelized(&mut rhs);
+                }
+            },
+        );
+        (lhs, rhs)
+    }
+
+    /// Computes `lhs > rhs` on the `num_msb_blocks` most significant blocks, then on all the
+    /// blocks
+    ///
+    /// This is an experimental API, see [Self::approx_then_exact_gt_parallelized]
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `num_msb_blocks` is 0
+    /// - Panics if the inputs do not have the same number of blocks
+    pub fn unchecked_approx_then_exact_gt_parallelized<T>(
+        &self,
+        lhs: &T,
+        rhs: &T,
+        num_msb_blocks: usize,
+    ) -> (BooleanBlock, BooleanBlock)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.approx_then_exact_compare(lhs, rhs, num_msb_blocks, ComparisonKind::Greater)
+    }
+
+    /// Computes `lhs >= rhs` on the `num_msb_blocks` most significant blocks, then on all the
+    /// blocks
+    ///
+    /// This is an experimental API, see [Self::approx_then_exact_gt_parallelized]
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `num_msb_blocks` is 0
+    /// - Panics if the inputs do not have the same number of blocks
+    pub fn unchecked_approx_then_exact_ge_parallelized<T>(
+        &self,
+        lhs: &T,
+        rhs: &T,
+        num_msb_blocks: usize,
+    ) -> (BooleanBlock, BooleanBlock)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.approx_then_exact_compare(lhs, rhs, num_msb_blocks, ComparisonKind::GreaterOrEqual)
+    }
+
+    /// Computes `lhs < rhs` on the `num_msb_blocks` most significant blocks, then on all the
+    /// blocks
+    ///
+    /// This is an experimental API, see [Self::approx_then_exact_gt_parallelized]
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `num_msb_blocks` is 0
+    /// - Panics if the inputs do not have the same number of blocks
+    pub fn unchecked_approx_then_exact_lt_parallelized<T>(
+        &self,
+        lhs: &T,
+        rhs: &T,
+        num_msb_blocks: usize,
+    ) -> (BooleanBlock, BooleanBlock)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.approx_then_exact_compare(lhs, rhs, num_msb_blocks, ComparisonKind::Less)
+    }
+
+    /// Computes `lhs <= rhs` on the `num_msb_blocks` most significant blocks, then on all the
+    /// blocks
+    ///
+    /// This is an experimental API, see [Self::approx_then_exact_gt_parallelized]
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `num_msb_blocks` is 0
+    /// - Panics if the inputs do not have the same number of blocks
+    pub fn unchecked_approx_then_exact_le_parallelized<T>(
+        &self,
+        lhs: &T,
+        rhs: &T,
+        num_msb_blocks: usize,
+    ) -> (BooleanBlock, BooleanBlock)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.approx_then_exact_compare(lhs, rhs, num_msb_blocks, ComparisonKind::LessOrEqual)
+    }
+
+    /// Computes the maximum on the `num_msb_blocks` most significant blocks, then on all the
+    /// blocks
+    ///
+    /// Returns `(approx, exact)`: `approx` is selected using only the most significant blocks,
+    /// when they are equal in both inputs it may be the smaller input.
+    ///
+    /// This is an experimental API, see [Self::approx_then_exact_gt_parallelized]
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `num_msb_blocks` is 0
+    /// - Panics if the inputs do not have the same number of blocks
+    pub fn unchecked_approx_then_exact_max_parallelized<T>(
+        &self,
+        lhs: &T,
+        rhs: &T,
+        num_msb_blocks: usize,
+    ) -> (T, T)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let (approx, exact) =
+            self.unchecked_approx_then_exact_gt_parallelized(lhs, rhs, num_msb_blocks);
+        rayon::join(
+            || self.unchecked_if_then_else_parallelized(&approx, lhs, rhs),
+            || self.unchecked_if_then_else_parallelized(&exact, lhs, rhs),
+        )
+    }
+
+    /// Computes the minimum on the `num_msb_blocks` most significant blocks, then on all the
+    /// blocks
+    ///
+    /// Returns `(approx, exact)`: `approx` is selected using only the most significant blocks,
+    /// when they are equal in both inputs it may be the greater input.
+    ///
+    /// This is an experimental API, see [Self::approx_then_exact_gt_parallelized]
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `num_msb_blocks` is 0
+    /// - Panics if the inputs do not have the same number of blocks
+    pub fn unchecked_approx_then_exact_min_parallelized<T>(
+        &self,
+        lhs: &T,
+        rhs: &T,
+        num_msb_blocks: usize,
+    ) -> (T, T)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let (approx, exact) =
+            self.unchecked_approx_then_exact_lt_parallelized(lhs, rhs, num_msb_blocks);
+        rayon::join(
+            || self.unchecked_if_then_else_parallelized(&approx, lhs, rhs),
+            || self.unchecked_if_then_else_parallelized(&exact, lhs, rhs),
+        )
+    }
+
+    /// Computes `lhs > rhs` on the `num_msb_blocks` most significant blocks, then on all the
+    /// blocks
+    ///
+    /// Returns `(approx, exact)`:
+    /// - `approx` is the comparison of the `num_msb_blocks` most significant blocks only, that is
+    ///   of the inputs with their low blocks set to zero.
+    /// - `exact` is `lhs > rhs`, it is equal to `approx` unless the most significant blocks are
+    ///   equal.
+    ///
+    /// This is not faster than [Self::gt_parallelized]: both results are computed, in parallel,
+    /// and no block is skipped as which result is the right one is never revealed to the server.
+    /// The cost is the one of [Self::gt_parallelized] plus the one of a comparison of
+    /// `num_msb_blocks` blocks.
+    ///
+    /// This is an experimental API, it may change or be removed in the future.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs ciphertexts whose block carries are always
+    /// empty.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `num_msb_blocks` is 0
+    /// - Panics if the inputs do not have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// // Both values only differ in their 2 low blocks
+    /// let ct1 = cks.encrypt(0b1011_0001u64);
+    /// let ct2 = cks.encrypt(0b1011_0000u64);
+    ///
+    /// let (approx, exact) = sks.approx_then_exact_gt_parallelized(&ct1, &ct2, 2);
+    ///
+    /// assert!(!cks.decrypt_bool(&approx));
+    /// assert!(cks.decrypt_bool(&exact));
+    /// ```
+    pub fn approx_then_exact_gt_parallelized<T>(
+        &self,
+        lhs: &T,
+        rhs: &T,
+        num_msb_blocks: usize,
+    ) -> (BooleanBlock, BooleanBlock)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let (lhs, rhs) = self.propagated_operands_parallelized(lhs, rhs);
+        self.unchecked_approx_then_exact_gt_parallelized(&lhs, &rhs, num_msb_blocks)
+    }
+
+    /// Computes `lhs >= rhs` on the `num_msb_blocks` most significant blocks, then on all the
+    /// blocks
+    ///
+    /// See [Self::approx_then_exact_gt_parallelized]
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `num_msb_blocks` is 0
+    /// - Panics if the inputs do not have the same number of blocks
+    pub fn approx_then_exact_ge_parallelized<T>(
+        &self,
+        lhs: &T,
+        rhs: &T,
+        num_msb_blocks: usize,
+    ) -> (BooleanBlock, BooleanBlock)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let (lhs, rhs) = self.propagated_operands_parallelized(lhs, rhs);
+        self.unchecked_approx_then_exact_ge_parallelized(&lhs, &rhs, num_msb_blocks)
+    }
+
+    /// Computes `lhs < rhs` on the `num_msb_blocks` most significant blocks, then on all the
+    /// blocks
+    ///
+    /// See [Self::approx_then_exact_gt_parallelized]
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `num_msb_blocks` is 0
+    /// - Panics if the inputs do not have the same number of blocks
+    pub fn approx_then_exact_lt_parallelized<T>(
+        &self,
+        lhs: &T,
+        rhs: &T,
+        num_msb_blocks: usize,
+    ) -> (BooleanBlock, BooleanBlock)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let (lhs, rhs) = self.propagated_operands_parallelized(lhs, rhs);
+        self.unchecked_approx_then_exact_lt_parallelized(&lhs, &rhs, num_msb_blocks)
+    }
+
+    /// Computes `lhs <= rhs` on the `num_msb_blocks` most significant blocks, then on all the
+    /// blocks
+    ///
+    /// See [Self::approx_then_exact_gt_parallelized]
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `num_msb_blocks` is 0
+    /// - Panics if the inputs do not have the same number of blocks
+    pub fn approx_then_exact_le_parallelized<T>(
+        &self,
+        lhs: &T,
+        rhs: &T,
+        num_msb_blocks: usize,
+    ) -> (BooleanBlock, BooleanBlock)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let (lhs, rhs) = self.propagated_operands_parallelized(lhs, rhs);
+        self.unchecked_approx_then_exact_le_parallelized(&lhs, &rhs, num_msb_blocks)
+    }
+
+    /// Computes the maximum on the `num_msb_blocks` most significant blocks, then on all the
+    /// blocks
+    ///
+    /// See [Self::unchecked_approx_then_exact_max_parallelized]
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `num_msb_blocks` is 0
+    /// - Panics if the inputs do not have the same number of blocks
+    pub fn approx_then_exact_max_parallelized<T>(
+        &self,
+        lhs: &T,
+        rhs: &T,
+        num_msb_blocks: usize,
+    ) -> (T, T)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let (lhs, rhs) = self.propagated_operands_parallelized(lhs, rhs);
+        self.unchecked_approx_then_exact_max_parallelized(&lhs, &rhs, num_msb_blocks)
+    }
+
+    /// Computes the minimum on the `num_msb_blocks` most significant blocks, then on all the
+    /// blocks
+    ///
+    /// See [Self::unchecked_approx_then_exact_min_parallelized]
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `num_msb_blocks` is 0
+    /// - Panics if the inputs do not have the same number of blocks
+    pub fn approx_then_exact_min_parallelized<T>(
+        &self,
+        lhs: &T,
+        rhs: &T,
+        num_msb_blocks: usize,
+    ) -> (T, T)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let (lhs, rhs) = self.propagated_operands_parallelized(lhs, rhs);
+        self.unchecked_approx_then_exact_min_parallelized(&lhs, &rhs, num_msb_blocks)
+    }
+}
@@ -0,0 +1,268 @@
+use crate::integer::{BooleanBlock, IntegerRadixCiphertext, RadixCiphertext, ServerKey};
+use crate::shortint::ciphertext::NoiseLevel;
+use crate::shortint::Ciphertext;
+
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Combines all the values using `op`, in a balanced tree so that independent
+    /// operations are computed in parallel
+    ///
+    /// `op` must be associative, values are only combined with their neighbours so it does not
+    /// need to be commutative.
+    ///
+    /// Returns None if there are no values
+    pub(crate) fn reduce_tree_parallelized<T, F>(mut values: Vec<T>, op: F) -> Option<T>
+    where
+        T: IntegerRadixCiphertext,
+        F: Fn(&T, &T) -> T + Sync,
+    {
+        while values.len() > 1 {
+            values = values
+                .par_chunks(2)
+                .map(|chunk| match chunk {
+                    [lhs, rhs] => op(lhs, rhs),
+                    [single] => single.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+        values.pop()
+    }
+
+    /// Returns a copy of the ciphertexts where the carries of those that have some are
+    /// propagated
+    fn propagated_copies_parallelized<T>(&self, cts: &[T]) -> Vec<T>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        cts.par_iter()
+            .map(|ct| {
+                let mut ct = ct.clone();
+                if !ct.block_carries_are_empty() {
+                    self.full_propagate_parallelized(&mut ct);
+                }
+                ct
+            })
+            .collect()
+    }
+
+    /// Returns the minimum of the ciphertexts
+    ///
+    /// The minimum is computed by a balanced tree of `min` operations, so only `log2(n)`
+    /// of them are done sequentially.
+    ///
+    /// Returns None if the slice is empty
+    ///
+    /// # Notes
+    ///
+    /// - the ciphertexts must not have any carries
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertexts do not all have the same number of blocks
+    pub fn unchecked_min_of_slice_parallelized<T>(&self, cts: &[T]) -> Option<T>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        Self::reduce_tree_parallelized(cts.to_vec(), |lhs, rhs| {
+            assert_eq!(
+                lhs.blocks().len(),
+                rhs.blocks().len(),
+                "All ciphertexts must have the same number of blocks"
+            );
+            self.unchecked_min_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Returns the maximum of the ciphertexts
+    ///
+    /// The maximum is computed by a balanced tree of `max` operations, so only `log2(n)`
+    /// of them are done sequentially.
+    ///
+    /// Returns None if the slice is empty
+    ///
+    /// # Notes
+    ///
+    /// - the ciphertexts must not have any carries
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertexts do not all have the same number of blocks
+    pub fn unchecked_max_of_slice_parallelized<T>(&self, cts: &[T]) -> Option<T>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        Self::reduce_tree_parallelized(cts.to_vec(), |lhs, rhs| {
+            assert_eq!(
+                lhs.blocks().len(),
+                rhs.blocks().len(),
+                "All ciphertexts must have the same number of blocks"
+            );
+            self.unchecked_max_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Returns the minimum of the ciphertexts
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// The carries of all the inputs are propagated in parallel before the reduction, so that
+    /// none of the intermediate results needs a propagation.
+    ///
+    /// Returns None if the slice is empty
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertexts do not all have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let cts = [42u64, 7, 255, 13].map(|v| cks.encrypt(v));
+    ///
+    /// let min = sks.min_of_slice_parallelized(&cts).unwrap();
+    /// let max = sks.max_of_slice_parallelized(&cts).unwrap();
+    ///
+    /// let decrypted_min: u64 = cks.decrypt(&min);
+    /// let decrypted_max: u64 = cks.decrypt(&max);
+    /// assert_eq!(decrypted_min, 7);
+    /// assert_eq!(decrypted_max, 255);
+    /// ```
+    pub fn min_of_slice_parallelized<T>(&self, cts: &[T]) -> Option<T>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if cts.iter().all(T::block_carries_are_empty) {
+            self.unchecked_min_of_slice_parallelized(cts)
+        } else {
+            let cts = self.propagated_copies_parallelized(cts);
+            self.unchecked_min_of_slice_parallelized(&cts)
+        }
+    }
+
+    /// Returns the maximum of the ciphertexts
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// See [Self::min_of_slice_parallelized]
+    ///
+    /// Returns None if the slice is empty
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertexts do not all have the same number of blocks
+    pub fn max_of_slice_parallelized<T>(&self, cts: &[T]) -> Option<T>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if cts.iter().all(T::block_carries_are_empty) {
+            self.unchecked_max_of_slice_parallelized(cts)
+        } else {
+            let cts = self.propagated_copies_parallelized(cts);
+            self.unchecked_max_of_slice_parallelized(&cts)
+        }
+    }
+
+    /// Returns the blocks of the booleans, with the ones that are not a clean 0 or 1 (e.g. the
+    /// result of a `not`) bootstrapped, so that they can be summed
+    fn clean_boolean_blocks_parallelized(&self, booleans: &[BooleanBlock]) -> Vec<Ciphertext> {
+        booleans
+            .par_iter()
+            .map(|boolean| {
+                let mut block = boolean.0.clone();
+                if block.noise_level() > NoiseLevel::NOMINAL || block.degree.get() > 1 {
+                    self.key.message_extract_assign(&mut block);
+                }
+                block
+            })
+            .collect()
+    }
+
+    /// Returns an encryption of `true` if all the booleans are `true`
+    ///
+    /// The booleans are summed by chunks that fit in a block, each sum being compared to the
+    /// number of booleans it contains with one PBS. The chunks are reduced in parallel, so only
+    /// a logarithmic number of PBS are done sequentially.
+    ///
+    /// An empty slice gives `true`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, 4);
+    ///
+    /// let booleans = [true, false, true].map(|v| cks.encrypt_bool(v));
+    ///
+    /// let all = sks.all_true_parallelized(&booleans);
+    /// let any = sks.any_true_parallelized(&booleans);
+    /// let count = sks.count_true_parallelized(&booleans, 4);
+    ///
+    /// assert!(!cks.decrypt_bool(&all));
+    /// assert!(cks.decrypt_bool(&any));
+    /// let decrypted_count: u64 = cks.decrypt(&count);
+    /// assert_eq!(decrypted_count, 2);
+    /// ```
+    pub fn all_true_parallelized(&self, booleans: &[BooleanBlock]) -> BooleanBlock {
+        let blocks = self.clean_boolean_blocks_parallelized(booleans);
+        BooleanBlock::new_unchecked(self.are_all_comparisons_block_true(blocks))
+    }
+
+    /// Returns an encryption of `true` if at least one of the booleans is `true`
+    ///
+    /// See [Self::all_true_parallelized] for the algorithm.
+    ///
+    /// An empty slice gives `false`.
+    pub fn any_true_parallelized(&self, booleans: &[BooleanBlock]) -> BooleanBlock {
+        if booleans.is_empty() {
+            return self.create_trivial_boolean_block(false);
+        }
+        let blocks = self.clean_boolean_blocks_parallelized(booleans);
+        BooleanBlock::new_unchecked(self.is_at_least_one_comparisons_block_true(blocks))
+    }
+
+    /// Returns the number of booleans that are `true`, as a ciphertext of `num_blocks` blocks
+    ///
+    /// The booleans are summed as the first block of otherwise zero ciphertexts, so the
+    /// carries are only propagated once. The count wraps around if it does not fit in
+    /// `num_blocks` blocks.
+    pub fn count_true_parallelized(
+        &self,
+        booleans: &[BooleanBlock],
+        num_blocks: usize,
+    ) -> RadixCiphertext {
+        if booleans.is_empty() || num_blocks == 0 {
+            return self.create_trivial_zero_radix(num_blocks);
+        }
+
+        let cts = self
+            .clean_boolean_blocks_parallelized(booleans)
+            .into_iter()
+            .map(|block| {
+                let mut blocks = Vec::with_capacity(num_blocks);
+                blocks.push(block);
+                blocks.resize_with(num_blocks, || self.key.create_trivial(0));
+                RadixCiphertext::from(blocks)
+            })
+            .collect::<Vec<_>>();
+
+        self.unchecked_sum_ciphertexts_vec_parallelized(cts)
+            .expect("there is at least one boolean")
+    }
+}
@@ -0,0 +1,560 @@
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey, SignedRadixCiphertext};
+use crate::shortint::Ciphertext;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Cast a RadixCiphertext or SignedRadixCiphertext to a RadixCiphertext
+    /// with a possibly different number of blocks, saturating instead of wrapping
+    ///
+    /// Values that are greater than the maximum value representable with `target_num_blocks`
+    /// become this maximum value, and negative values become 0.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::{gen_keys_radix, IntegerCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 8;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 1000u16;
+    ///
+    /// let ct1 = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.saturating_cast_to_unsigned(ct1, 4);
+    /// assert_eq!(ct_res.blocks().len(), 4);
+    ///
+    /// // Decrypt
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, u8::MAX);
+    /// ```
+    pub fn saturating_cast_to_unsigned<T: IntegerRadixCiphertext>(
+        &self,
+        mut source: T,
+        target_num_blocks: usize,
+    ) -> RadixCiphertext {
+        if !source.block_carries_are_empty() {
+            self.full_propagate_parallelized(&mut source);
+        }
+
+        let (is_too_big, is_negative) = self.unsigned_cast_out_of_range(&source, target_num_blocks);
+
+        let mut result = self.cast_to_unsigned(source, target_num_blocks);
+
+        if let Some(is_too_big) = is_too_big {
+            let max_value = self.unsigned_max_block_values(target_num_blocks);
+            self.assign_clear_blocks_if(&mut result.blocks, &is_too_big, &max_value);
+        }
+
+        if let Some(is_negative) = is_negative {
+            let min_value = vec![0; target_num_blocks];
+            self.assign_clear_blocks_if(&mut result.blocks, &is_negative, &min_value);
+        }
+
+        result
+    }
+
+    /// Cast a RadixCiphertext or SignedRadixCiphertext to a RadixCiphertext
+    /// with a possibly different number of blocks, and returns whether the value did not fit
+    ///
+    /// The casted value is the same as the one of [Self::cast_to_unsigned] (i.e. it wraps
+    /// around), the returned [BooleanBlock] encrypts true if the source value is negative or
+    /// greater than the maximum value representable with `target_num_blocks`.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::{gen_keys_radix, IntegerCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 8;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 1000u16;
+    ///
+    /// let ct1 = cks.encrypt(msg);
+    ///
+    /// let (ct_res, overflowed) = sks.overflowing_cast_to_unsigned(ct1, 4);
+    /// assert_eq!(ct_res.blocks().len(), 4);
+    ///
+    /// // Decrypt
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, msg as u8);
+    /// assert!(cks.decrypt_bool(&overflowed));
+    /// ```
+    pub fn overflowing_cast_to_unsigned<T: IntegerRadixCiphertext>(
+        &self,
+        mut source: T,
+        target_num_blocks: usize,
+    ) -> (RadixCiphertext, BooleanBlock) {
+        if !source.block_carries_are_empty() {
+            self.full_propagate_parallelized(&mut source);
+        }
+
+        let (is_too_big, is_negative) = self.unsigned_cast_out_of_range(&source, target_num_blocks);
+        let overflowed = self.any_out_of_range(is_too_big, is_negative);
+
+        (self.cast_to_unsigned(source, target_num_blocks), overflowed)
+    }
+
+    /// Returns whether `source` is too big, and whether it is negative, to be casted
+    /// to an unsigned integer of `target_num_blocks` blocks
+    ///
+    /// A condition is None when it is known to be false. Negative values are also
+    /// flagged as too big when blocks are trimmed.
+    ///
+    /// source must have clean carries
+    fn unsigned_cast_out_of_range<T: IntegerRadixCiphertext>(
+        &self,
+        source: &T,
+        target_num_blocks: usize,
+    ) -> (Option<BooleanBlock>, Option<BooleanBlock>) {
+        let current_num_blocks = source.blocks().len();
+
+        rayon::join(
+            || {
+                // The value is too big if any of the blocks that are trimmed is not zero,
+                // (negative values are also too big, but they are handled afterward)
+                (target_num_blocks < current_num_blocks).then(|| {
+                    let trimmed_blocks = source.blocks()[target_num_blocks..].to_vec();
+                    self.unchecked_is_nonzero_parallelized(&RadixCiphertext::from(trimmed_blocks))
+                })
+            },
+            || {
+                if T::IS_SIGNED {
+                    source
+                        .blocks()
+                        .last()
+                        .map(|block| self.sign_bit_of_block(block))
+                } else {
+                    None
+                }
+            },
+        )
+    }
+
+    /// Cast a RadixCiphertext or SignedRadixCiphertext to a SignedRadixCiphertext
+    /// with a possibly different number of blocks, saturating instead of wrapping
+    ///
+    /// Values that are outside of the range representable with `target_num_blocks`
+    /// become the maximum or minimum value of this range.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::{gen_keys_radix, IntegerCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 8;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = -1000i16;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg);
+    ///
+    /// let ct_res = sks.saturating_cast_to_signed(ct1, 4);
+    /// assert_eq!(ct_res.blocks().len(), 4);
+    ///
+    /// // Decrypt
+    /// let res: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(res, i8::MIN);
+    /// ```
+    pub fn saturating_cast_to_signed<T: IntegerRadixCiphertext>(
+        &self,
+        mut source: T,
+        target_num_blocks: usize,
+    ) -> SignedRadixCiphertext {
+        if !source.block_carries_are_empty() {
+            self.full_propagate_parallelized(&mut source);
+        }
+
+        let (is_too_big, is_too_small) = self.signed_cast_out_of_range(&source, target_num_blocks);
+
+        let mut result = self.cast_to_signed(source, target_num_blocks);
+
+        let message_modulus = self.key.message_modulus.0 as u64;
+        if let Some(is_too_big) = is_too_big {
+            let mut max_value = self.unsigned_max_block_values(target_num_blocks);
+            if let Some(msb_block) = max_value.last_mut() {
+                *msb_block = (message_modulus / 2) - 1;
+            }
+            self.assign_clear_blocks_if(&mut result.blocks, &is_too_big, &max_value);
+        }
+
+        if let Some(is_too_small) = is_too_small {
+            let mut min_value = vec![0; target_num_blocks];
+            if let Some(msb_block) = min_value.last_mut() {
+                *msb_block = message_modulus / 2;
+            }
+            self.assign_clear_blocks_if(&mut result.blocks, &is_too_small, &min_value);
+        }
+
+        result
+    }
+
+    /// Cast a RadixCiphertext or SignedRadixCiphertext to a SignedRadixCiphertext
+    /// with a possibly different number of blocks, and returns whether the value did not fit
+    ///
+    /// The casted value is the same as the one of [Self::cast_to_signed] (i.e. it wraps
+    /// around), the returned [BooleanBlock] encrypts true if the source value is outside of the
+    /// range representable with `target_num_blocks`.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::{gen_keys_radix, IntegerCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 8;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = -100i16;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg);
+    ///
+    /// let (ct_res, overflowed) = sks.overflowing_cast_to_signed(ct1, 4);
+    /// assert_eq!(ct_res.blocks().len(), 4);
+    ///
+    /// // Decrypt
+    /// let res: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(res, msg as i8);
+    /// assert!(!cks.decrypt_bool(&overflowed));
+    /// ```
+    pub fn overflowing_cast_to_signed<T: IntegerRadixCiphertext>(
+        &self,
+        mut source: T,
+        target_num_blocks: usize,
+    ) -> (SignedRadixCiphertext, BooleanBlock) {
+        if !source.block_carries_are_empty() {
+            self.full_propagate_parallelized(&mut source);
+        }
+
+        let (is_too_big, is_too_small) = self.signed_cast_out_of_range(&source, target_num_blocks);
+        let overflowed = self.any_out_of_range(is_too_big, is_too_small);
+
+        (self.cast_to_signed(source, target_num_blocks), overflowed)
+    }
+
+    /// Returns whether `source` is too big, and whether it is too small, to be casted
+    /// to a signed integer of `target_num_blocks` blocks
+    ///
+    /// A condition is None when it is known to be false.
+    ///
+    /// source must have clean carries
+    fn signed_cast_out_of_range<T: IntegerRadixCiphertext>(
+        &self,
+        source: &T,
+        target_num_blocks: usize,
+    ) -> (Option<BooleanBlock>, Option<BooleanBlock>) {
+        let current_num_blocks = source.blocks().len();
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let num_bits_in_block = message_modulus.ilog2();
+
+        if target_num_blocks == 0 {
+            (None, None)
+        } else if T::IS_SIGNED {
+            if target_num_blocks >= current_num_blocks {
+                // Sign extension, all values fit
+                (None, None)
+            } else {
+                // The value fits if the most significant bit of the target and all the bits
+                // that are trimmed are equal to the sign bit
+                let sign = self.sign_bit_of_block(&source.blocks()[current_num_blocks - 1]);
+
+                let msb_differs = self.key.generate_lookup_table_bivariate(|block, sign| {
+                    u64::from((block >> (num_bits_in_block - 1)) != sign)
+                });
+                let block_differs = self.key.generate_lookup_table_bivariate(|block, sign| {
+                    let sign_extension = if sign == 1 { message_modulus - 1 } else { 0 };
+                    u64::from(block != sign_extension)
+                });
+
+                let differences = source.blocks()[target_num_blocks - 1..]
+                    .par_iter()
+                    .enumerate()
+                    .map(|(i, block)| {
+                        let lut = if i == 0 { &msb_differs } else { &block_differs };
+                        self.key
+                            .unchecked_apply_lookup_table_bivariate(block, &sign.0, lut)
+                    })
+                    .collect::<Vec<_>>();
+                let is_out_of_range =
+                    self.unchecked_is_nonzero_parallelized(&RadixCiphertext::from(differences));
+
+                let (is_too_big, is_too_small) = rayon::join(
+                    || {
+                        self.key.unchecked_apply_lookup_table_bivariate(
+                            &is_out_of_range.0,
+                            &sign.0,
+                            &self.key.generate_lookup_table_bivariate(|out, sign| {
+                                u64::from(out == 1 && sign == 0)
+                            }),
+                        )
+                    },
+                    || self.key.unchecked_bitand(&is_out_of_range.0, &sign.0),
+                );
+
+                (
+                    Some(BooleanBlock::new_unchecked(is_too_big)),
+                    Some(BooleanBlock::new_unchecked(is_too_small)),
+                )
+            }
+        } else if target_num_blocks > current_num_blocks {
+            // Zero extension, all values fit
+            (None, None)
+        } else {
+            // The value is too big if the most significant bit of the target is set,
+            // or if any of the blocks that are trimmed is not zero
+            let msb = self.sign_bit_of_block(&source.blocks()[target_num_blocks - 1]);
+            let mut blocks = Vec::with_capacity(current_num_blocks - target_num_blocks + 1);
+            blocks.push(msb.0);
+            blocks.extend_from_slice(&source.blocks()[target_num_blocks..]);
+
+            let is_too_big = self.unchecked_is_nonzero_parallelized(&RadixCiphertext::from(blocks));
+            (Some(is_too_big), None)
+        }
+    }
+
+    /// Merges the out of range conditions into a single one, None being false
+    fn any_out_of_range(
+        &self,
+        first: Option<BooleanBlock>,
+        second: Option<BooleanBlock>,
+    ) -> BooleanBlock {
+        match (first, second) {
+            (Some(first), Some(second)) => self.boolean_bitor(&first, &second),
+            (Some(condition), None) | (None, Some(condition)) => condition,
+            (None, None) => self.create_trivial_boolean_block(false),
+        }
+    }
+
+    /// Returns a block encrypting the most significant bit of the message of `block`
+    ///
+    /// block must have clean carries
+    fn sign_bit_of_block(&self, block: &Ciphertext) -> BooleanBlock {
+        let num_bits_in_block = self.key.message_modulus.0.ilog2();
+        let lut = self
+            .key
+            .generate_lookup_table(|block| block >> (num_bits_in_block - 1));
+        BooleanBlock::new_unchecked(self.key.apply_lookup_table(block, &lut))
+    }
+
+    /// Returns the clear block values of the maximum unsigned value
+    fn unsigned_max_block_values(&self, num_blocks: usize) -> Vec<u64> {
+        vec![self.key.message_modulus.0 as u64 - 1; num_blocks]
+    }
+
+    /// Replaces each block by the corresponding clear value of `block_values` if
+    /// `condition` encrypts true
+    ///
+    /// blocks must have clean carries
+    fn assign_clear_blocks_if(
+        &self,
+        blocks: &mut [Ciphertext],
+        condition: &BooleanBlock,
+        block_values: &[u64],
+    ) {
+        assert_eq!(blocks.len(), block_values.len());
+
+        blocks
+            .par_iter_mut()
+            .zip(block_values.par_iter())
+            .for_each(|(block, &value)| {
+                let lut =
+                    self.key
+                        .generate_lookup_table_bivariate(|block, condition| {
+                            if condition == 1 {
+                                value
+                            } else {
+                                block
+                            }
+                        });
+                self.key
+                    .unchecked_apply_lookup_table_bivariate_assign(block, &condition.0, &lut);
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::integer::keycache::KEY_CACHE;
+    use crate::integer::server_key::radix_parallel::tests_unsigned::NB_CTXT;
+    use crate::integer::tests::create_parametrized_test;
+    use crate::integer::{IntegerKeyKind, RadixClientKey};
+    #[cfg(tarpaulin)]
+    use crate::shortint::parameters::coverage_parameters::*;
+    use crate::shortint::parameters::*;
+    use crate::shortint::PBSParameters;
+    use rand::prelude::*;
+
+    fn integer_saturating_cast<P>(param: P)
+    where
+        P: Into<PBSParameters>,
+    {
+        let param = param.into();
+        let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+        let cks = RadixClientKey::from((cks, NB_CTXT));
+
+        let mut rng = rand::thread_rng();
+
+        let num_bits_in_block = param.message_modulus().0.ilog2();
+        let source_num_bits = num_bits_in_block * NB_CTXT as u32;
+        let source_unsigned_max = (1i64 << source_num_bits) - 1;
+        let source_signed_min = -(1i64 << (source_num_bits - 1));
+        let source_signed_max = (1i64 << (source_num_bits - 1)) - 1;
+
+        let nb_tests = 5;
+
+        for target_num_blocks in [NB_CTXT / 2, NB_CTXT, NB_CTXT * 2] {
+            let target_num_bits = num_bits_in_block * target_num_blocks as u32;
+            let unsigned_max = (1i64 << target_num_bits) - 1;
+            let signed_min = -(1i64 << (target_num_bits - 1));
+            let signed_max = (1i64 << (target_num_bits - 1)) - 1;
+
+            let interesting_unsigned = [0, signed_max, signed_max + 1, unsigned_max];
+            for clear in interesting_unsigned
+                .into_iter()
+                .filter(|clear| *clear <= source_unsigned_max)
+                .chain([source_unsigned_max])
+                .chain((0..nb_tests).map(|_| rng.gen_range(0..=source_unsigned_max)))
+            {
+                let ct = cks.encrypt(clear as u64);
+
+                let res = sks.saturating_cast_to_unsigned(ct.clone(), target_num_blocks);
+                let dec: u64 = cks.decrypt(&res);
+                assert_eq!(
+                    dec as i64,
+                    clear.min(unsigned_max),
+                    "Invalid unsigned to unsigned cast of {clear} to {target_num_blocks} blocks"
+                );
+
+                let res = sks.saturating_cast_to_signed(ct, target_num_blocks);
+                let dec: i64 = cks.decrypt_signed(&res);
+                assert_eq!(
+                    dec,
+                    clear.min(signed_max),
+                    "Invalid unsigned to signed cast of {clear} to {target_num_blocks} blocks"
+                );
+            }
+
+            let interesting_signed = [
+                0,
+                -1,
+                signed_min,
+                signed_min - 1,
+                signed_max,
+                signed_max + 1,
+            ];
+            for clear in interesting_signed
+                .into_iter()
+                .filter(|clear| (source_signed_min..=source_signed_max).contains(clear))
+                .chain([source_signed_min, source_signed_max])
+                .chain((0..nb_tests).map(|_| rng.gen_range(source_signed_min..=source_signed_max)))
+            {
+                let ct = cks.encrypt_signed(clear);
+
+                let res = sks.saturating_cast_to_unsigned(ct.clone(), target_num_blocks);
+                let dec: u64 = cks.decrypt(&res);
+                assert_eq!(
+                    dec as i64,
+                    clear.clamp(0, unsigned_max),
+                    "Invalid signed to unsigned cast of {clear} to {target_num_blocks} blocks"
+                );
+
+                let res = sks.saturating_cast_to_signed(ct, target_num_blocks);
+                let dec: i64 = cks.decrypt_signed(&res);
+                assert_eq!(
+                    dec,
+                    clear.clamp(signed_min, signed_max),
+                    "Invalid signed to signed cast of {clear} to {target_num_blocks} blocks"
+                );
+            }
+        }
+    }
+
+    create_parametrized_test!(integer_saturating_cast);
+
+    fn integer_overflowing_cast<P>(param: P)
+    where
+        P: Into<PBSParameters>,
+    {
+        let param = param.into();
+        let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+        let cks = RadixClientKey::from((cks, NB_CTXT));
+
+        let mut rng = rand::thread_rng();
+
+        let num_bits_in_block = param.message_modulus().0.ilog2();
+        let source_num_bits = num_bits_in_block * NB_CTXT as u32;
+        let source_unsigned_max = (1i64 << source_num_bits) - 1;
+        let source_signed_min = -(1i64 << (source_num_bits - 1));
+        let source_signed_max = (1i64 << (source_num_bits - 1)) - 1;
+
+        let nb_tests = 5;
+
+        for target_num_blocks in [NB_CTXT / 2, NB_CTXT, NB_CTXT * 2] {
+            let target_num_bits = num_bits_in_block * target_num_blocks as u32;
+            let unsigned_max = (1i64 << target_num_bits) - 1;
+            let signed_min = -(1i64 << (target_num_bits - 1));
+            let signed_max = (1i64 << (target_num_bits - 1)) - 1;
+
+            let wrap_unsigned = |clear: i64| clear & unsigned_max;
+            let wrap_signed =
+                |clear: i64| (clear << (64 - target_num_bits)) >> (64 - target_num_bits);
+
+            for clear in [0, source_unsigned_max]
+                .into_iter()
+                .chain((0..nb_tests).map(|_| rng.gen_range(0..=source_unsigned_max)))
+            {
+                let ct = cks.encrypt(clear as u64);
+
+                let (res, overflowed) =
+                    sks.overflowing_cast_to_unsigned(ct.clone(), target_num_blocks);
+                let dec: u64 = cks.decrypt(&res);
+                assert_eq!(dec as i64, wrap_unsigned(clear));
+                assert_eq!(cks.decrypt_bool(&overflowed), clear > unsigned_max);
+
+                let (res, overflowed) = sks.overflowing_cast_to_signed(ct, target_num_blocks);
+                let dec: i64 = cks.decrypt_signed(&res);
+                assert_eq!(dec, wrap_signed(clear));
+                assert_eq!(cks.decrypt_bool(&overflowed), clear > signed_max);
+            }
+
+            for clear in [-1, source_signed_min, source_signed_max]
+                .into_iter()
+                .chain((0..nb_tests).map(|_| rng.gen_range(source_signed_min..=source_signed_max)))
+            {
+                let ct = cks.encrypt_signed(clear);
+
+                let (res, overflowed) =
+                    sks.overflowing_cast_to_unsigned(ct.clone(), target_num_blocks);
+                let dec: u64 = cks.decrypt(&res);
+                assert_eq!(dec as i64, wrap_unsigned(clear));
+                assert_eq!(
+                    cks.decrypt_bool(&overflowed),
+                    !(0..=unsigned_max).contains(&clear)
+                );
+
+                let (res, overflowed) = sks.overflowing_cast_to_signed(ct, target_num_blocks);
+                let dec: i64 = cks.decrypt_signed(&res);
+                assert_eq!(dec, wrap_signed(clear));
+                assert_eq!(
+                    cks.decrypt_bool(&overflowed),
+                    !(signed_min..=signed_max).contains(&clear)
+                );
+            }
+        }
+    }
+
+    create_parametrized_test!(integer_overflowing_cast);
+}
@@ -0,0 +1,646 @@
+use crate::core_crypto::prelude::{SignedNumeric, UnsignedNumeric};
+use crate::integer::block_decomposition::{BlockDecomposer, DecomposableInto};
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey, SignedRadixCiphertext};
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::Ciphertext;
+use rayon::prelude::*;
+
+/// The value a result saturates to when the operation overflowed
+enum SaturationBound<'a> {
+    Max,
+    Min,
+    /// The minimum if the block encrypts true, the maximum otherwise
+    MinIf(&'a BooleanBlock),
+}
+
+impl ServerKey {
+    /// Replaces the value of `result` by the bound if `overflowed` encrypts true
+    ///
+    /// Expects `result` to have empty carries.
+    ///
+    /// Each block is zeroed if the operation overflowed, and the block of the bound is added
+    /// to it, the bound being zero if the operation did not overflow. So this only takes one
+    /// layer of PBS, instead of the two of a cmux with the bound.
+    fn saturate_if_overflowed_parallelized<T>(
+        &self,
+        result: &mut T,
+        overflowed: &BooleanBlock,
+        bound: SaturationBound,
+    ) where
+        T: IntegerRadixCiphertext,
+    {
+        let message_modulus = self.message_modulus().0 as u64;
+        let (max, min) = (message_modulus - 1, 0);
+        // For signed integers, the block holding the sign bit has different bounds
+        let (last_max, last_min) = if T::IS_SIGNED {
+            (message_modulus / 2 - 1, message_modulus / 2)
+        } else {
+            (max, min)
+        };
+
+        // Computes a block encrypting its part of the bound if the operation overflowed,
+        // and 0 otherwise
+        let bound_block = |max: u64, min: u64| -> Ciphertext {
+            match &bound {
+                SaturationBound::Max => {
+                    let lut = self
+                        .key
+                        .generate_lookup_table(|o| if o == 1 { max } else { 0 });
+                    self.key.apply_lookup_table(&overflowed.0, &lut)
+                }
+                SaturationBound::Min => {
+                    let lut = self
+                        .key
+                        .generate_lookup_table(|o| if o == 1 { min } else { 0 });
+                    self.key.apply_lookup_table(&overflowed.0, &lut)
+                }
+                SaturationBound::MinIf(is_min) => {
+                    let lut = self.key.generate_lookup_table_bivariate(|o, is_min| {
+                        match (o == 1, is_min == 1) {
+                            (false, _) => 0,
+                            (true, true) => min,
+                            (true, false) => max,
+                        }
+                    });
+                    self.key
+                        .unchecked_apply_lookup_table_bivariate(&overflowed.0, &is_min.0, &lut)
+                }
+            }
+        };
+
+        let zero_out_lut = self
+            .key
+            .generate_lookup_table_bivariate(|x, o| if o == 1 { 0 } else { x });
+        let ((low_bound, last_bound), ()) = rayon::join(
+            || {
+                rayon::join(
+                    || bound_block(max, min),
+                    || T::IS_SIGNED.then(|| bound_block(last_max, last_min)),
+                )
+            },
+            || {
+                result.blocks_mut().par_iter_mut().for_each(|block| {
+                    self.key.unchecked_apply_lookup_table_bivariate_assign(
+                        block,
+                        &overflowed.0,
+                        &zero_out_lut,
+                    );
+                });
+            },
+        );
+
+        let num_blocks = result.blocks().len();
+        for (i, block) in result.blocks_mut().iter_mut().enumerate() {
+            let bound = match &last_bound {
+                Some(last_bound) if i == num_blocks - 1 => last_bound,
+                _ => &low_bound,
+            };
+            self.key.unchecked_add_assign(block, bound);
+            // Either the block or the bound is zero, so the sum has no carry
+            block.degree = Degree::new(message_modulus as usize - 1);
+        }
+    }
+
+    /// Computes the block encrypting whether the signed ciphertext is negative
+    ///
+    /// Expects `ct` to have empty carries.
+    fn is_negative_block(&self, ct: &SignedRadixCiphertext) -> BooleanBlock {
+        let sign_bit_pos = self.message_modulus().0.ilog2() - 1;
+        let lut = self.key.generate_lookup_table(|x| (x >> sign_bit_pos) & 1);
+        BooleanBlock::new_unchecked(self.key.apply_lookup_table(ct.blocks.last().unwrap(), &lut))
+    }
+
+    /// Propagates the carries of the input if it has some
+    fn propagated_if_needed<'a, T>(&self, ct: &'a T, tmp: &'a mut Option<T>) -> &'a T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if ct.block_carries_are_empty() {
+            return ct;
+        }
+        let mut propagated = ct.clone();
+        self.full_propagate_parallelized(&mut propagated);
+        tmp.insert(propagated)
+    }
+
+    /// Panics if the scalar cannot be represented by a ciphertext of `num_blocks` blocks
+    fn assert_scalar_fits<Scalar>(&self, scalar: Scalar, num_blocks: usize, is_signed: bool)
+    where
+        Scalar: DecomposableInto<u8>,
+    {
+        let num_bits = self.message_modulus().0.ilog2() as usize * num_blocks;
+        let fits = if is_signed {
+            // All the bits from the sign bit must be copies of the sign bit
+            let sign_bit = u8::from(scalar < Scalar::ZERO);
+            BlockDecomposer::new(scalar, 1)
+                .iter_as::<u8>()
+                .skip(num_bits - 1)
+                .all(|bit| bit == sign_bit)
+        } else {
+            BlockDecomposer::new(scalar, 1)
+                .iter_as::<u8>()
+                .skip(num_bits)
+                .all(|bit| bit == 0)
+        };
+        assert!(
+            fits,
+            "The scalar must be representable by a ciphertext of {num_blocks} blocks"
+        );
+    }
+
+    /// Computes homomorphically the addition of two unsigned ciphertexts, saturating at the
+    /// maximum value instead of wrapping around on overflow
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let ct1 = cks.encrypt(200u8);
+    /// let ct2 = cks.encrypt(100u8);
+    ///
+    /// let ct_res = sks.unsigned_saturating_add_parallelized(&ct1, &ct2);
+    ///
+    /// let dec_result: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 200u8.saturating_add(100u8));
+    /// ```
+    pub fn unsigned_saturating_add_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let (mut result, overflowed) = self.unsigned_overflowing_add_parallelized(lhs, rhs);
+        self.saturate_if_overflowed_parallelized(&mut result, &overflowed, SaturationBound::Max);
+        result
+    }
+
+    /// Computes homomorphically the addition of two signed ciphertexts, saturating at the
+    /// minimum or maximum value instead of wrapping around on overflow
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let ct1 = cks.encrypt_signed(-100i8);
+    /// let ct2 = cks.encrypt_signed(-50i8);
+    ///
+    /// let ct_res = sks.signed_saturating_add_parallelized(&ct1, &ct2);
+    ///
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, (-100i8).saturating_add(-50i8));
+    /// ```
+    pub fn signed_saturating_add_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let mut tmp_lhs = None;
+        let mut tmp_rhs = None;
+        let (lhs, rhs) = rayon::join(
+            || self.propagated_if_needed(lhs, &mut tmp_lhs),
+            || self.propagated_if_needed(rhs, &mut tmp_rhs),
+        );
+
+        // An addition can only overflow if both operands have the same sign,
+        // then the result saturates towards that sign
+        let ((mut result, overflowed), lhs_is_negative) = rayon::join(
+            || self.signed_overflowing_add_parallelized(lhs, rhs),
+            || self.is_negative_block(lhs),
+        );
+        self.saturate_if_overflowed_parallelized(
+            &mut result,
+            &overflowed,
+            SaturationBound::MinIf(&lhs_is_negative),
+        );
+        result
+    }
+
+    /// Computes homomorphically the subtraction of two unsigned ciphertexts, saturating at 0
+    /// instead of wrapping around on overflow
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let ct1 = cks.encrypt(100u8);
+    /// let ct2 = cks.encrypt(200u8);
+    ///
+    /// let ct_res = sks.unsigned_saturating_sub_parallelized(&ct1, &ct2);
+    ///
+    /// let dec_result: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 100u8.saturating_sub(200u8));
+    /// ```
+    pub fn unsigned_saturating_sub_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let (mut result, overflowed) = self.unsigned_overflowing_sub_parallelized(lhs, rhs);
+        self.saturate_if_overflowed_parallelized(&mut result, &overflowed, SaturationBound::Min);
+        result
+    }
+
+    /// Computes homomorphically the subtraction of two signed ciphertexts, saturating at the
+    /// minimum or maximum value instead of wrapping around on overflow
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let ct1 = cks.encrypt_signed(100i8);
+    /// let ct2 = cks.encrypt_signed(-50i8);
+    ///
+    /// let ct_res = sks.signed_saturating_sub_parallelized(&ct1, &ct2);
+    ///
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, 100i8.saturating_sub(-50i8));
+    /// ```
+    pub fn signed_saturating_sub_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let mut tmp_lhs = None;
+        let mut tmp_rhs = None;
+        let (lhs, rhs) = rayon::join(
+            || self.propagated_if_needed(lhs, &mut tmp_lhs),
+            || self.propagated_if_needed(rhs, &mut tmp_rhs),
+        );
+
+        // A subtraction can only overflow if the operands have different signs,
+        // then the result saturates towards the sign of lhs
+        let ((mut result, overflowed), lhs_is_negative) = rayon::join(
+            || self.signed_overflowing_sub_parallelized(lhs, rhs),
+            || self.is_negative_block(lhs),
+        );
+        self.saturate_if_overflowed_parallelized(
+            &mut result,
+            &overflowed,
+            SaturationBound::MinIf(&lhs_is_negative),
+        );
+        result
+    }
+
+    /// Computes homomorphically the multiplication of two unsigned ciphertexts, saturating at the
+    /// maximum value instead of wrapping around on overflow
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let ct1 = cks.encrypt(20u8);
+    /// let ct2 = cks.encrypt(30u8);
+    ///
+    /// let ct_res = sks.unsigned_saturating_mul_parallelized(&ct1, &ct2);
+    ///
+    /// let dec_result: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 20u8.saturating_mul(30u8));
+    /// ```
+    pub fn unsigned_saturating_mul_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let (mut result, overflowed) = self.unsigned_overflowing_mul_parallelized(lhs, rhs);
+        self.saturate_if_overflowed_parallelized(&mut result, &overflowed, SaturationBound::Max);
+        result
+    }
+
+    /// Computes homomorphically the multiplication of two signed ciphertexts, saturating at the
+    /// minimum or maximum value instead of wrapping around on overflow
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let ct1 = cks.encrypt_signed(-20i8);
+    /// let ct2 = cks.encrypt_signed(30i8);
+    ///
+    /// let ct_res = sks.signed_saturating_mul_parallelized(&ct1, &ct2);
+    ///
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, (-20i8).saturating_mul(30i8));
+    /// ```
+    pub fn signed_saturating_mul_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let mut tmp_lhs = None;
+        let mut tmp_rhs = None;
+        let (lhs, rhs) = rayon::join(
+            || self.propagated_if_needed(lhs, &mut tmp_lhs),
+            || self.propagated_if_needed(rhs, &mut tmp_rhs),
+        );
+
+        // On overflow, the result saturates towards the sign of the exact product
+        let ((mut result, overflowed), product_is_negative) = rayon::join(
+            || self.signed_overflowing_mul_parallelized(lhs, rhs),
+            || {
+                let sign_bit_pos = self.message_modulus().0.ilog2() - 1;
+                let lut = self.key.generate_lookup_table_bivariate(|x, y| {
+                    ((x >> sign_bit_pos) ^ (y >> sign_bit_pos)) & 1
+                });
+                BooleanBlock::new_unchecked(self.key.unchecked_apply_lookup_table_bivariate(
+                    lhs.blocks.last().unwrap(),
+                    rhs.blocks.last().unwrap(),
+                    &lut,
+                ))
+            },
+        );
+        self.saturate_if_overflowed_parallelized(
+            &mut result,
+            &overflowed,
+            SaturationBound::MinIf(&product_is_negative),
+        );
+        result
+    }
+
+    /// Computes homomorphically the addition of an unsigned ciphertext with a scalar, saturating
+    /// at the maximum value instead of wrapping around on overflow
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let ct = cks.encrypt(200u8);
+    ///
+    /// let ct_res = sks.unsigned_saturating_scalar_add_parallelized(&ct, 100u8);
+    ///
+    /// let dec_result: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 200u8.saturating_add(100u8));
+    /// ```
+    pub fn unsigned_saturating_scalar_add_parallelized<Scalar>(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar: Scalar,
+    ) -> RadixCiphertext
+    where
+        Scalar: UnsignedNumeric + DecomposableInto<u8>,
+    {
+        let (mut result, overflowed) =
+            self.unsigned_overflowing_scalar_add_parallelized(lhs, scalar);
+        self.saturate_if_overflowed_parallelized(&mut result, &overflowed, SaturationBound::Max);
+        result
+    }
+
+    /// Computes homomorphically the addition of a signed ciphertext with a scalar, saturating
+    /// at the minimum or maximum value instead of wrapping around on overflow
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let ct = cks.encrypt_signed(-100i8);
+    ///
+    /// let ct_res = sks.signed_saturating_scalar_add_parallelized(&ct, -50i8);
+    ///
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, (-100i8).saturating_add(-50i8));
+    /// ```
+    pub fn signed_saturating_scalar_add_parallelized<Scalar>(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        scalar: Scalar,
+    ) -> SignedRadixCiphertext
+    where
+        Scalar: SignedNumeric + DecomposableInto<u8>,
+    {
+        let (mut result, overflowed) = self.signed_overflowing_scalar_add_parallelized(lhs, scalar);
+        // An addition can only overflow if both operands have the same sign,
+        // here the sign of the scalar is known
+        let bound = if scalar < Scalar::ZERO {
+            SaturationBound::Min
+        } else {
+            SaturationBound::Max
+        };
+        self.saturate_if_overflowed_parallelized(&mut result, &overflowed, bound);
+        result
+    }
+
+    /// Computes homomorphically the subtraction of a scalar from an unsigned ciphertext,
+    /// saturating at 0 instead of wrapping around on overflow
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let ct = cks.encrypt(100u8);
+    ///
+    /// let ct_res = sks.unsigned_saturating_scalar_sub_parallelized(&ct, 200u8);
+    ///
+    /// let dec_result: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 100u8.saturating_sub(200u8));
+    /// ```
+    pub fn unsigned_saturating_scalar_sub_parallelized<Scalar>(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar: Scalar,
+    ) -> RadixCiphertext
+    where
+        Scalar: UnsignedNumeric + DecomposableInto<u8> + std::ops::Not<Output = Scalar>,
+    {
+        let (mut result, overflowed) =
+            self.unsigned_overflowing_scalar_sub_parallelized(lhs, scalar);
+        self.saturate_if_overflowed_parallelized(&mut result, &overflowed, SaturationBound::Min);
+        result
+    }
+
+    /// Computes homomorphically the subtraction of a scalar from a signed ciphertext, saturating
+    /// at the minimum or maximum value instead of wrapping around on overflow
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let ct = cks.encrypt_signed(100i8);
+    ///
+    /// let ct_res = sks.signed_saturating_scalar_sub_parallelized(&ct, -50i8);
+    ///
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, 100i8.saturating_sub(-50i8));
+    /// ```
+    pub fn signed_saturating_scalar_sub_parallelized<Scalar>(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        scalar: Scalar,
+    ) -> SignedRadixCiphertext
+    where
+        Scalar: SignedNumeric + DecomposableInto<u8> + std::ops::Not<Output = Scalar>,
+    {
+        let (mut result, overflowed) = self.signed_overflowing_scalar_sub_parallelized(lhs, scalar);
+        // A subtraction can only overflow if the operands have different signs,
+        // the result saturates towards the sign of lhs, that is the opposite of the scalar's one
+        let bound = if scalar < Scalar::ZERO {
+            SaturationBound::Max
+        } else {
+            SaturationBound::Min
+        };
+        self.saturate_if_overflowed_parallelized(&mut result, &overflowed, bound);
+        result
+    }
+
+    /// Computes homomorphically the multiplication of an unsigned ciphertext by a scalar,
+    /// saturating at the maximum value instead of wrapping around on overflow
+    ///
+    /// # Panics
+    ///
+    /// Panics if the scalar cannot be represented by a ciphertext with as many blocks as `lhs`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let ct = cks.encrypt(20u8);
+    ///
+    /// let ct_res = sks.unsigned_saturating_scalar_mul_parallelized(&ct, 30u8);
+    ///
+    /// let dec_result: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 20u8.saturating_mul(30u8));
+    /// ```
+    pub fn unsigned_saturating_scalar_mul_parallelized<Scalar>(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar: Scalar,
+    ) -> RadixCiphertext
+    where
+        Scalar: UnsignedNumeric + DecomposableInto<u8> + DecomposableInto<u64>,
+    {
+        self.assert_scalar_fits(scalar, lhs.blocks.len(), false);
+        let rhs: RadixCiphertext = self.create_trivial_radix(scalar, lhs.blocks.len());
+        self.unsigned_saturating_mul_parallelized(lhs, &rhs)
+    }
+
+    /// Computes homomorphically the multiplication of a signed ciphertext by a scalar,
+    /// saturating at the minimum or maximum value instead of wrapping around on overflow
+    ///
+    /// # Panics
+    ///
+    /// Panics if the scalar cannot be represented by a ciphertext with as many blocks as `lhs`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let ct = cks.encrypt_signed(20i8);
+    ///
+    /// let ct_res = sks.signed_saturating_scalar_mul_parallelized(&ct, -30i8);
+    ///
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, 20i8.saturating_mul(-30i8));
+    /// ```
+    pub fn signed_saturating_scalar_mul_parallelized<Scalar>(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        scalar: Scalar,
+    ) -> SignedRadixCiphertext
+    where
+        Scalar: SignedNumeric + DecomposableInto<u8> + DecomposableInto<u64>,
+    {
+        self.assert_scalar_fits(scalar, lhs.blocks.len(), true);
+        let rhs: SignedRadixCiphertext = self.create_trivial_radix(scalar, lhs.blocks.len());
+        self.signed_saturating_mul_parallelized(lhs, &rhs)
+    }
+}
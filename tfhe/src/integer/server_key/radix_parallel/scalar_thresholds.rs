@@ -0,0 +1,183 @@
+use crate::integer::block_decomposition::BlockDecomposer;
+use crate::integer::ciphertext::boolean_value::BooleanBlock;
+use crate::integer::ciphertext::{IntegerCiphertext, IntegerRadixCiphertext};
+use crate::integer::server_key::comparator::Comparator;
+use crate::integer::{RadixCiphertext, ServerKey};
+use crate::shortint::Ciphertext;
+use rayon::prelude::*;
+use std::collections::{BTreeMap, BTreeSet};
+
+impl ServerKey {
+    /// Compares an encrypted value against many clear thresholds at once, returning for each
+    /// threshold an encryption of `lhs >= threshold`.
+    ///
+    /// The results are in the same order as the thresholds.
+    ///
+    /// Blocks are packed two by two (when parameters allow it) and each packed block is compared
+    /// with the corresponding block of the thresholds. The comparison of a packed block with a
+    /// given clear value is only computed once, even if several thresholds share that block value
+    /// (e.g. the most significant blocks of small thresholds are all zeros). The block comparisons
+    /// of each threshold are then reduced in parallel. This is cheaper than calling
+    /// [Self::unchecked_scalar_ge_parallelized] once per threshold as soon as the thresholds have
+    /// blocks in common.
+    ///
+    /// # Notes
+    ///
+    /// - lhs must not have any carries
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message space + carry space is inferior to 4 bits
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let msg = 97u64;
+    /// let thresholds = [18u64, 50, 97, 120, 300];
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let results = sks.unchecked_scalar_ge_thresholds_parallelized(&ct, &thresholds);
+    ///
+    /// let decrypted = results
+    ///     .iter()
+    ///     .map(|ct| cks.decrypt_bool(ct))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(decrypted, vec![true, true, true, false, false]);
+    /// ```
+    pub fn unchecked_scalar_ge_thresholds_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        thresholds: &[u64],
+    ) -> Vec<BooleanBlock> {
+        let comparator = Comparator::new(self);
+
+        if lhs.blocks().is_empty() {
+            // We interpret empty as 0
+            return thresholds
+                .iter()
+                .map(|&threshold| self.create_trivial_boolean_block(threshold == 0))
+                .collect();
+        }
+
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let can_pack_blocks = self.key.carry_modulus.0 >= self.key.message_modulus.0;
+        let (blocks_per_chunk, chunk_modulus) = if can_pack_blocks {
+            (2, message_modulus * message_modulus)
+        } else {
+            (1, message_modulus)
+        };
+
+        let packed_blocks = lhs
+            .blocks()
+            .par_chunks(blocks_per_chunk)
+            .map(|chunk| self.pack_block_chunk(chunk))
+            .collect::<Vec<_>>();
+
+        // The thresholds that are out of bounds or equal to zero have a trivial result,
+        // the other ones are decomposed in blocks matching the packed blocks
+        let threshold_blocks = thresholds
+            .iter()
+            .map(|&threshold| {
+                let is_trivial = threshold == 0
+                    || self.is_scalar_out_of_bounds(lhs, threshold) != std::cmp::Ordering::Equal;
+                (!is_trivial).then(|| {
+                    BlockDecomposer::new(threshold, chunk_modulus.ilog2())
+                        .iter_as::<u64>()
+                        .take(packed_blocks.len())
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // For each packed block, the distinct clear values it has to be compared with
+        let mut block_values_to_compare = BTreeSet::new();
+        for blocks in threshold_blocks.iter().flatten() {
+            for (block_index, &block_value) in blocks.iter().enumerate() {
+                block_values_to_compare.insert((block_index, block_value));
+            }
+        }
+
+        // Each distinct value needs its own LUT, shared by all the blocks compared with it
+        let mut luts = BTreeMap::new();
+        for &(_, block_value) in &block_values_to_compare {
+            luts.entry(block_value).or_insert_with(|| {
+                self.key
+                    .generate_lookup_table(|x| match x.cmp(&block_value) {
+                        std::cmp::Ordering::Less => Comparator::IS_INFERIOR,
+                        std::cmp::Ordering::Equal => Comparator::IS_EQUAL,
+                        std::cmp::Ordering::Greater => Comparator::IS_SUPERIOR,
+                    })
+            });
+        }
+
+        let block_signs = block_values_to_compare
+            .into_iter()
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .map(|(block_index, block_value)| {
+                let sign = self
+                    .key
+                    .apply_lookup_table(&packed_blocks[block_index], &luts[&block_value]);
+                ((block_index, block_value), sign)
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        thresholds
+            .par_iter()
+            .zip(threshold_blocks.par_iter())
+            .map(|(&threshold, blocks)| {
+                let Some(blocks) = blocks else {
+                    // A zero threshold is always less than or equal to lhs,
+                    // others are out of bounds, thus greater than lhs
+                    return self.create_trivial_boolean_block(threshold == 0);
+                };
+
+                let sign_blocks = blocks
+                    .iter()
+                    .enumerate()
+                    .map(|(block_index, &block_value)| {
+                        block_signs[&(block_index, block_value)].clone()
+                    })
+                    .collect::<Vec<Ciphertext>>();
+
+                let result = comparator.reduce_signs(sign_blocks, |sign| {
+                    u64::from(sign == Comparator::IS_EQUAL || sign == Comparator::IS_SUPERIOR)
+                });
+                BooleanBlock::new_unchecked(result)
+            })
+            .collect()
+    }
+
+    /// Compares an encrypted value against many clear thresholds at once, returning for each
+    /// threshold an encryption of `lhs >= threshold`.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs ciphertexts whose block carries are always
+    /// empty.
+    ///
+    /// See [Self::unchecked_scalar_ge_thresholds_parallelized]
+    pub fn scalar_ge_thresholds_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        thresholds: &[u64],
+    ) -> Vec<BooleanBlock> {
+        let mut tmp_lhs;
+        let lhs = if lhs.block_carries_are_empty() {
+            lhs
+        } else {
+            tmp_lhs = lhs.clone();
+            self.full_propagate_parallelized(&mut tmp_lhs);
+            &tmp_lhs
+        };
+        self.unchecked_scalar_ge_thresholds_parallelized(lhs, thresholds)
+    }
+}
@@ -0,0 +1,147 @@
+use crate::integer::server_key::radix_parallel::bit_extractor::BitExtractor;
+use crate::integer::{BooleanBlock, IntegerRadixCiphertext, RadixCiphertext, ServerKey};
+
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Selects between the consecutive pairs of `cases` using `bit`, the number of cases is
+    /// halved (an unpaired last case is kept as is)
+    fn select_case_level<T>(&self, bit: &BooleanBlock, cases: &[T]) -> Vec<T>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        cases
+            .par_chunks(2)
+            .map(|pair| match pair {
+                [if_zero, if_one] => self.unchecked_if_then_else_parallelized(bit, if_one, if_zero),
+                [single] => single.clone(),
+                _ => unreachable!("chunks have at most 2 elements"),
+            })
+            .collect()
+    }
+
+    /// Returns `arms[selector]`, or an encryption of zero if `selector >= arms.len()`
+    ///
+    /// This is the encrypted equivalent of a `match` (or `switch`) on the selector, it replaces a
+    /// chain of `arms.len() - 1` sequential [Self::if_then_else_parallelized].
+    ///
+    /// The arms are the leaves of a balanced multiplexer tree: each level of the tree halves
+    /// the number of candidates using one bit of the selector, and all the selections of a
+    /// level are done in parallel. The depth of the computation is thus `log2(arms.len())`
+    /// selections.
+    ///
+    /// # Notes
+    ///
+    /// - the selector and the arms must not have any carries
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `arms` is empty
+    /// - Panics if there are more arms than values the selector can encrypt
+    /// - Panics if the arms do not all have the same number of blocks
+    pub fn unchecked_select_case_parallelized<T>(&self, selector: &RadixCiphertext, arms: &[T]) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(!arms.is_empty(), "At least one arm is required");
+        let num_arm_blocks = arms[0].blocks().len();
+        assert!(
+            arms.iter().all(|arm| arm.blocks().len() == num_arm_blocks),
+            "All arms must have the same number of blocks"
+        );
+
+        let bits_per_block = self.key.message_modulus.0.ilog2() as usize;
+        let num_selector_bits = selector.blocks().len() * bits_per_block;
+        // None if the selector can encrypt more values than any slice can hold
+        let max_num_arms = 1usize.checked_shl(num_selector_bits as u32);
+        assert!(
+            max_num_arms.map_or(true, |max| arms.len() <= max),
+            "The selector has {num_selector_bits} bits, it cannot select between {} arms",
+            arms.len()
+        );
+
+        // Only the bits needed to index the arms are used in the tree, the values
+        // greater or equal to the number of arms are handled separately
+        let num_levels = arms.len().next_power_of_two().ilog2() as usize;
+
+        let (mut result, is_in_range) = rayon::join(
+            || {
+                let bits = BitExtractor::new(self, bits_per_block)
+                    .extract_n_bits(selector.blocks(), num_levels)
+                    .into_iter()
+                    .map(BooleanBlock::new_unchecked)
+                    .collect::<Vec<_>>();
+
+                let Some((first_bit, other_bits)) = bits.split_first() else {
+                    return arms[0].clone();
+                };
+
+                let mut cases = self.select_case_level(first_bit, arms);
+                for bit in other_bits {
+                    cases = self.select_case_level(bit, &cases);
+                }
+                cases.pop().expect("internal error, the tree has a root")
+            },
+            || {
+                if max_num_arms == Some(arms.len()) {
+                    // All the values of the selector are valid indices
+                    None
+                } else {
+                    Some(self.unchecked_scalar_lt_parallelized(selector, arms.len() as u64))
+                }
+            },
+        );
+
+        if let Some(is_in_range) = is_in_range {
+            self.zero_out_if_condition_is_false(&mut result, &is_in_range.0);
+        }
+        result
+    }
+
+    /// Returns `arms[selector]`, or an encryption of zero if `selector >= arms.len()`
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// See [Self::unchecked_select_case_parallelized]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let arms = [10u64, 20, 30];
+    /// let ct_arms = arms.iter().map(|&v| cks.encrypt(v)).collect::<Vec<_>>();
+    ///
+    /// let selector = cks.encrypt(2u64);
+    /// let ct_res = sks.select_case_parallelized(&selector, &ct_arms);
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 30);
+    ///
+    /// // Out of range selectors give 0
+    /// let selector = cks.encrypt(3u64);
+    /// let ct_res = sks.select_case_parallelized(&selector, &ct_arms);
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 0);
+    /// ```
+    pub fn select_case_parallelized<T>(&self, selector: &RadixCiphertext, arms: &[T]) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut selector_clone = vec![];
+        let mut arms_clone = vec![];
+        let (selector, arms) = rayon::join(
+            || self.carry_free_slice(std::slice::from_ref(selector), &mut selector_clone),
+            || self.carry_free_slice(arms, &mut arms_clone),
+        );
+
+        self.unchecked_select_case_parallelized(&selector[0], arms)
+    }
+}
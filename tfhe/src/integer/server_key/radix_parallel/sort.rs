@@ -0,0 +1,255 @@
+use crate::integer::{BooleanBlock, IntegerRadixCiphertext, ServerKey};
+
+use rayon::prelude::*;
+
+/// Returns the layers of comparators of a bitonic sorting network for `len` elements
+///
+/// Each comparator `(i, j)` has `i < j` and puts the smaller element at `i`, the comparators of a
+/// layer are disjoint. The network is the one of the next power of two, in which the comparators
+/// with one end beyond `len` are removed: as all the comparators put the smaller element first,
+/// this is the same as padding the input with elements greater than all the others.
+pub(super) fn bitonic_layers(len: usize) -> Vec<Vec<(usize, usize)>> {
+    let padded_len = len.next_power_of_two();
+    let layer = |partner: &dyn Fn(usize) -> usize| {
+        (0..len)
+            .filter_map(|i| {
+                let j = partner(i);
+                (j > i && j < len).then_some((i, j))
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut layers = vec![];
+    let mut block_size = 2;
+    while block_size <= padded_len {
+        // Compares the two halves of each block in mirror order,
+        // which merges them into a bitonic sequence
+        layers.push(layer(&|i| i ^ (block_size - 1)));
+        let mut distance = block_size / 4;
+        while distance > 0 {
+            layers.push(layer(&|i| i ^ distance));
+            distance /= 2;
+        }
+        block_size *= 2;
+    }
+    layers
+}
+
+impl ServerKey {
+    /// Conditionally swaps the pairs of elements of a layer of the sorting network
+    fn swap_pairs_if_parallelized<T>(
+        &self,
+        cts: &mut [T],
+        layer: &[(usize, usize)],
+        must_swap: &[BooleanBlock],
+    ) where
+        T: IntegerRadixCiphertext,
+    {
+        let swapped = layer
+            .par_iter()
+            .zip(must_swap.par_iter())
+            .map(|(&(i, j), condition)| {
+                let lhs = &cts[i];
+                let rhs = &cts[j];
+                // The condition is shared by both selections
+                self.unchecked_if_then_else_slice_parallelized(
+                    condition,
+                    &[rhs.clone(), lhs.clone()],
+                    &[lhs.clone(), rhs.clone()],
+                )
+            })
+            .collect::<Vec<_>>();
+
+        for (&(i, j), pair) in layer.iter().zip(swapped) {
+            let [new_lhs, new_rhs]: [T; 2] = pair
+                .try_into()
+                .unwrap_or_else(|_| unreachable!("internal error, expected a pair"));
+            cts[i] = new_lhs;
+            cts[j] = new_rhs;
+        }
+    }
+
+    /// Sorts the ciphertexts in ascending order
+    ///
+    /// The ciphertexts are sorted by a bitonic sorting network: it is made of
+    /// `O(log2(n)^2)` layers of `n / 2` compare-and-swap operations, all the operations of a layer
+    /// being computed in parallel.
+    ///
+    /// # Notes
+    ///
+    /// - the ciphertexts must not have any carries
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertexts do not all have the same number of blocks
+    pub fn unchecked_sort_parallelized<T>(&self, cts: &mut [T])
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if let Some(first) = cts.first() {
+            let num_blocks = first.blocks().len();
+            assert!(
+                cts.iter().all(|ct| ct.blocks().len() == num_blocks),
+                "All ciphertexts must have the same number of blocks"
+            );
+        }
+
+        for layer in bitonic_layers(cts.len()) {
+            let must_swap = layer
+                .par_iter()
+                .map(|&(i, j)| self.unchecked_gt_parallelized(&cts[i], &cts[j]))
+                .collect::<Vec<_>>();
+            self.swap_pairs_if_parallelized(cts, &layer, &must_swap);
+        }
+    }
+
+    /// Sorts the ciphertexts in ascending order
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs ciphertexts whose block carries are always
+    /// empty.
+    ///
+    /// See [Self::unchecked_sort_parallelized]
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertexts do not all have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let clears = [42u64, 7, 255, 0, 7];
+    /// let mut cts = clears.iter().map(|&v| cks.encrypt(v)).collect::<Vec<_>>();
+    ///
+    /// sks.sort_parallelized(&mut cts);
+    ///
+    /// let decrypted = cts.iter().map(|ct| cks.decrypt(ct)).collect::<Vec<u64>>();
+    /// assert_eq!(decrypted, vec![0, 7, 7, 42, 255]);
+    /// ```
+    pub fn sort_parallelized<T>(&self, cts: &mut [T])
+    where
+        T: IntegerRadixCiphertext,
+    {
+        cts.par_iter_mut()
+            .filter(|ct| !ct.block_carries_are_empty())
+            .for_each(|ct| self.full_propagate_parallelized(ct));
+
+        self.unchecked_sort_parallelized(cts);
+    }
+
+    /// Sorts the keys in ascending order, and applies the same permutation to the values
+    ///
+    /// The sort is not stable: values associated to equal keys may be reordered.
+    ///
+    /// See [Self::unchecked_sort_parallelized] for the sorting network.
+    ///
+    /// # Notes
+    ///
+    /// - the keys and values must not have any carries
+    ///
+    /// # Panics
+    ///
+    /// - Panics if there are not as many keys as values
+    /// - Panics if the keys (or the values) do not all have the same number of blocks
+    pub fn unchecked_sort_by_key_parallelized<K, V>(&self, keys: &mut [K], values: &mut [V])
+    where
+        K: IntegerRadixCiphertext,
+        V: IntegerRadixCiphertext,
+    {
+        assert_eq!(
+            keys.len(),
+            values.len(),
+            "There must be as many keys as values"
+        );
+        if let (Some(first_key), Some(first_value)) = (keys.first(), values.first()) {
+            let num_key_blocks = first_key.blocks().len();
+            let num_value_blocks = first_value.blocks().len();
+            assert!(
+                keys.iter().all(|ct| ct.blocks().len() == num_key_blocks),
+                "All keys must have the same number of blocks"
+            );
+            assert!(
+                values
+                    .iter()
+                    .all(|ct| ct.blocks().len() == num_value_blocks),
+                "All values must have the same number of blocks"
+            );
+        }
+
+        for layer in bitonic_layers(keys.len()) {
+            let must_swap = layer
+                .par_iter()
+                .map(|&(i, j)| self.unchecked_gt_parallelized(&keys[i], &keys[j]))
+                .collect::<Vec<_>>();
+            rayon::join(
+                || self.swap_pairs_if_parallelized(keys, &layer, &must_swap),
+                || self.swap_pairs_if_parallelized(values, &layer, &must_swap),
+            );
+        }
+    }
+
+    /// Sorts the keys in ascending order, and applies the same permutation to the values
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs ciphertexts whose block carries are always
+    /// empty.
+    ///
+    /// See [Self::unchecked_sort_by_key_parallelized]
+    ///
+    /// # Panics
+    ///
+    /// - Panics if there are not as many keys as values
+    /// - Panics if the keys (or the values) do not all have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let mut keys = [30u64, 10, 20].map(|v| cks.encrypt(v));
+    /// let mut values = [1u64, 2, 3].map(|v| cks.encrypt(v));
+    ///
+    /// sks.sort_by_key_parallelized(&mut keys, &mut values);
+    ///
+    /// let decrypted = values
+    ///     .iter()
+    ///     .map(|ct| cks.decrypt(ct))
+    ///     .collect::<Vec<u64>>();
+    /// assert_eq!(decrypted, vec![2, 3, 1]);
+    /// ```
+    pub fn sort_by_key_parallelized<K, V>(&self, keys: &mut [K], values: &mut [V])
+    where
+        K: IntegerRadixCiphertext,
+        V: IntegerRadixCiphertext,
+    {
+        rayon::join(
+            || {
+                keys.par_iter_mut()
+                    .filter(|ct| !ct.block_carries_are_empty())
+                    .for_each(|ct| self.full_propagate_parallelized(ct));
+            },
+            || {
+                values
+                    .par_iter_mut()
+                    .filter(|ct| !ct.block_carries_are_empty())
+                    .for_each(|ct| self.full_propagate_parallelized(ct));
+            },
+        );
+
+        self.unchecked_sort_by_key_parallelized(keys, values);
+    }
+}
@@ -0,0 +1,200 @@
+use crate::integer::ciphertext::{IntegerCiphertext, IntegerRadixCiphertext};
+use crate::integer::server_key::radix_parallel::bit_extractor::BitExtractor;
+use crate::integer::{RadixCiphertext, ServerKey};
+use crate::shortint::ciphertext::NoiseLevel;
+use crate::shortint::Ciphertext;
+
+impl ServerKey {
+    /// Adds a block encrypting a bit at the given bit position of `ct`, the bit of `ct` at this
+    /// position must be zero
+    fn add_bit_at_position(&self, ct: &mut RadixCiphertext, bit: &Ciphertext, position: usize) {
+        let bits_per_block = self.key.message_modulus.0.ilog2() as usize;
+        let block = &mut ct.blocks[position / bits_per_block];
+
+        let shifted_bit = self
+            .key
+            .unchecked_scalar_mul(bit, 1 << (position % bits_per_block));
+        self.key.unchecked_add_assign(block, &shifted_bit);
+        self.clean_block_without_carry(block);
+    }
+
+    /// Cleans a block whose value is known to fit in the message space,
+    /// but whose degree or noise level may be too high
+    fn clean_block_without_carry(&self, block: &mut Ciphertext) {
+        if block.degree.get() >= self.key.message_modulus.0
+            || block.noise_level() > NoiseLevel::NOMINAL
+        {
+            self.key.message_extract_assign(block);
+        }
+    }
+
+    /// Computes homomorphically the integer square root of an unsigned integer, along with the
+    /// remainder
+    ///
+    /// Returns `(root, remainder)` such that `root * root + remainder == ct` and `root` is the
+    /// greatest integer satisfying `root * root <= ct`.
+    ///
+    /// The digit-by-digit algorithm is used: the bits of `ct` are processed two by two, starting
+    /// from the most significant ones, each step determining one bit of the root.
+    ///
+    /// # Complexity
+    ///
+    /// For a `ct` of `n` bits, `n / 2` steps are done sequentially. Each step does a
+    /// subtraction and a selection on a ciphertext of `n / 2 + 3` bits, so the number of PBS
+    /// grows quadratically with the number of blocks of `ct`.
+    ///
+    /// # Notes
+    ///
+    /// - ct must not have any carries
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let msg = 200u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let (root, rem) = sks.unchecked_sqrt_rem_parallelized(&ct);
+    ///
+    /// let root: u64 = cks.decrypt(&root);
+    /// let rem: u64 = cks.decrypt(&rem);
+    /// assert_eq!(root, 14);
+    /// assert_eq!(rem, 200 - 14 * 14);
+    /// ```
+    pub fn unchecked_sqrt_rem_parallelized(
+        &self,
+        ct: &RadixCiphertext,
+    ) -> (RadixCiphertext, RadixCiphertext) {
+        let num_blocks = ct.blocks().len();
+        if num_blocks == 0 {
+            return (ct.clone(), ct.clone());
+        }
+
+        let bits_per_block = self.key.message_modulus.0.ilog2() as usize;
+        let num_bits = num_blocks * bits_per_block;
+        let num_steps = num_bits.div_ceil(2);
+
+        let mut bits = BitExtractor::new(self, bits_per_block).extract_all_bits(ct.blocks());
+        // Odd number of bits, the last pair is completed with a zero
+        bits.resize_with(num_steps * 2, || self.key.create_trivial(0));
+
+        // The remainder is at most 2 * root, and 4 * remainder + 3 has to fit during the steps
+        let num_work_blocks = (num_steps + 3).div_ceil(bits_per_block);
+        let mut root: RadixCiphertext = self.create_trivial_zero_radix(num_work_blocks);
+        let mut rem: RadixCiphertext = self.create_trivial_zero_radix(num_work_blocks);
+
+        for step in (0..num_steps).rev() {
+            let (mut shifted_rem, shifted_root) = rayon::join(
+                || self.unchecked_scalar_left_shift_parallelized(&rem, 2u32),
+                || self.unchecked_scalar_left_shift_parallelized(&root, 1u32),
+            );
+
+            // rem = (rem << 2) | next two bits of ct
+            self.add_bit_at_position(&mut shifted_rem, &bits[2 * step], 0);
+            self.add_bit_at_position(&mut shifted_rem, &bits[2 * step + 1], 1);
+
+            // trial = (root << 2) | 1
+            let mut trial = self.unchecked_scalar_left_shift_parallelized(&shifted_root, 1u32);
+            self.key
+                .unchecked_scalar_add_assign(&mut trial.blocks[0], 1);
+            self.clean_block_without_carry(&mut trial.blocks[0]);
+
+            // If rem >= trial, the next bit of the root is 1 and trial is subtracted from rem
+            let (diff, is_bit_zero) =
+                self.unchecked_unsigned_overflowing_sub_parallelized(&shifted_rem, &trial);
+            let is_bit_one = self.boolean_bitnot(&is_bit_zero);
+
+            let (new_rem, new_root) = rayon::join(
+                || self.unchecked_if_then_else_parallelized(&is_bit_one, &diff, &shifted_rem),
+                || {
+                    let mut new_root = shifted_root;
+                    self.add_bit_at_position(&mut new_root, &is_bit_one.0, 0);
+                    new_root
+                },
+            );
+            rem = new_rem;
+            root = new_root;
+        }
+
+        // The root is smaller than 2^(num_bits / 2) and the remainder is at most twice the root,
+        // so both fit in the blocks of the input
+        for result in [&mut root, &mut rem] {
+            if num_work_blocks > num_blocks {
+                self.trim_radix_blocks_msb_assign(result, num_work_blocks - num_blocks);
+            } else {
+                self.extend_radix_with_trivial_zero_blocks_msb_assign(
+                    result,
+                    num_blocks - num_work_blocks,
+                );
+            }
+        }
+
+        (root, rem)
+    }
+
+    /// Computes homomorphically the integer square root of an unsigned integer, along with the
+    /// remainder
+    ///
+    /// Returns `(root, remainder)` such that `root * root + remainder == ct` and `root` is the
+    /// greatest integer satisfying `root * root <= ct`.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertext block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs ciphertexts whose block carries are always
+    /// empty.
+    ///
+    /// See [Self::unchecked_sqrt_rem_parallelized] for the complexity.
+    pub fn sqrt_rem_parallelized(
+        &self,
+        ct: &RadixCiphertext,
+    ) -> (RadixCiphertext, RadixCiphertext) {
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+        self.unchecked_sqrt_rem_parallelized(ct)
+    }
+
+    /// Computes homomorphically the integer square root of an unsigned integer, rounded down
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertext block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// See [Self::unchecked_sqrt_rem_parallelized] for the complexity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let msg = 81u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.isqrt_parallelized(&ct);
+    ///
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 9);
+    /// ```
+    pub fn isqrt_parallelized(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        self.sqrt_rem_parallelized(ct).0
+    }
+}
@@ -0,0 +1,225 @@
+use super::bit_extractor::BitExtractor;
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::ServerKey;
+use crate::shortint::Ciphertext;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Rotates the blocks of the ciphertext to the left (i.e. towards the most significant block)
+    /// by `n` blocks.
+    ///
+    /// This is equivalent to a bit rotation to the left by `n * bits_per_block` bits,
+    /// but as it is a pure permutation of the blocks it does not require any PBS.
+    ///
+    /// If necessary the carries of the input will be cleaned beforehand,
+    /// but its value won't change, the result is returned in a new ciphertext
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 0b10110100_u8;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.rotate_blocks(&ct, 1);
+    ///
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg.rotate_left(2));
+    /// ```
+    pub fn rotate_blocks<T>(&self, ct: &T, n: usize) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut result = ct.clone();
+        if !result.block_carries_are_empty() {
+            self.full_propagate_parallelized(&mut result);
+        }
+        let num_blocks = result.blocks().len();
+        if num_blocks != 0 {
+            result.blocks_mut().rotate_right(n % num_blocks);
+        }
+        result
+    }
+
+    /// Reverses the byte order of the integer.
+    ///
+    /// The number of message bits of the ciphertext must be a multiple of 8.
+    ///
+    /// When a byte is made of a whole number of blocks (e.g. 2 bits of message per block)
+    /// this is a pure permutation of the blocks that does not require any PBS. Otherwise the bits
+    /// are extracted and repacked, which requires one PBS per bit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of message bits is not a multiple of 8.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 8 * 2 = 16 bits of message
+    /// let num_blocks = 8;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 0x12_34_u16;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.swap_bytes_parallelized(&ct);
+    ///
+    /// let dec: u16 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg.swap_bytes());
+    /// ```
+    pub fn swap_bytes_parallelized<T>(&self, ct: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let bits_per_block = self.message_modulus().0.ilog2() as usize;
+        let num_blocks = ct.blocks().len();
+        let total_num_bits = bits_per_block * num_blocks;
+        assert_eq!(
+            total_num_bits % 8,
+            0,
+            "swap_bytes requires the number of bits ({total_num_bits}) to be a multiple of 8"
+        );
+
+        let mut clean_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            clean_ct = ct.clone();
+            self.full_propagate_parallelized(&mut clean_ct);
+            &clean_ct
+        };
+
+        if 8 % bits_per_block == 0 {
+            let blocks_per_byte = 8 / bits_per_block;
+            let blocks = ct
+                .blocks()
+                .rchunks_exact(blocks_per_byte)
+                .flat_map(|byte_blocks| byte_blocks.iter().cloned())
+                .collect();
+            return T::from_blocks(blocks);
+        }
+
+        let bit_extractor = BitExtractor::new(self, bits_per_block);
+        let bits = bit_extractor.extract_all_bits(ct.blocks());
+
+        let swapped_bits = bits
+            .rchunks_exact(8)
+            .flat_map(|byte_bits| byte_bits.iter())
+            .collect::<Vec<_>>();
+
+        let blocks = swapped_bits
+            .par_chunks_exact(bits_per_block)
+            .map(|block_bits| self.pack_bits_into_block(block_bits))
+            .collect();
+
+        T::from_blocks(blocks)
+    }
+
+    /// Packs ciphertexts each encrypting a single bit (least significant bit first) into one block
+    fn pack_bits_into_block(&self, bits: &[&Ciphertext]) -> Ciphertext {
+        let mut block = bits[0].clone();
+        for (i, bit) in bits.iter().enumerate().skip(1) {
+            let mut shifted_bit = (*bit).clone();
+            self.key
+                .unchecked_scalar_mul_assign(&mut shifted_bit, 1u8 << i);
+            self.key.unchecked_add_assign(&mut block, &shifted_bit);
+        }
+        block
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::ServerKey;
+    use crate::integer::ciphertext::RadixCiphertext;
+    use crate::integer::keycache::KEY_CACHE;
+    use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
+    use crate::integer::server_key::radix_parallel::tests_unsigned::CpuFunctionExecutor;
+    use crate::integer::tests::create_parametrized_test;
+    use crate::integer::{IntegerKeyKind, RadixClientKey};
+    #[cfg(tarpaulin)]
+    use crate::shortint::parameters::coverage_parameters::*;
+    use crate::shortint::parameters::*;
+    use crate::shortint::PBSParameters;
+    use rand::prelude::*;
+    use std::sync::Arc;
+
+    pub(crate) fn swap_bytes_test<P, T>(param: P, mut executor: T)
+    where
+        P: Into<PBSParameters>,
+        T: for<'a> FunctionExecutor<&'a RadixCiphertext, RadixCiphertext>,
+    {
+        let param = param.into();
+        let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+        let sks = Arc::new(sks);
+
+        let bits_per_block = param.message_modulus().0.ilog2() as usize;
+        // Smallest number of blocks holding a whole number of bytes, and at least 2 bytes
+        let mut nb_blocks = 8 / gcd(8, bits_per_block);
+        while nb_blocks * bits_per_block < 16 {
+            nb_blocks *= 2;
+        }
+        let log_modulus = nb_blocks * bits_per_block;
+        if log_modulus > 64 {
+            return;
+        }
+
+        let cks = RadixClientKey::from((cks, nb_blocks));
+
+        executor.setup(&cks, sks);
+
+        let modulus = if log_modulus == 64 {
+            u64::MAX
+        } else {
+            (1u64 << log_modulus) - 1
+        };
+
+        let nb_tests = 10;
+
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..nb_tests {
+            let clear = rng.gen::<u64>() & modulus;
+
+            let ct = cks.encrypt(clear);
+
+            let result = executor.execute(&ct);
+            let decrypted_result: u64 = cks.decrypt(&result);
+
+            let expected_result = clear.swap_bytes() >> (64 - log_modulus);
+
+            assert_eq!(
+                decrypted_result, expected_result,
+                "Invalid swap_bytes result, gave clear = {clear}, \
+            expected {expected_result}, got {decrypted_result}"
+            );
+        }
+    }
+
+    fn gcd(mut a: usize, mut b: usize) -> usize {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    }
+
+    fn integer_swap_bytes<P>(param: P)
+    where
+        P: Into<PBSParameters>,
+    {
+        let executor = CpuFunctionExecutor::new(&ServerKey::swap_bytes_parallelized);
+        swap_bytes_test(param, executor);
+    }
+
+    create_parametrized_test!(integer_swap_bytes);
+}
@@ -1486,6 +1486,111 @@ where
     }
 }
 
+pub(crate) fn default_widening_mul_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a RadixCiphertext), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests_smaller {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = executor.execute((&ctxt_0, &ctxt_1));
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res.blocks.len(), 2 * NB_CTXT);
+
+        let decrypted_result: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            decrypted_result,
+            clear_0 * clear_1,
+            "Invalid result for widening_mul, for {clear_0} * {clear_1}"
+        );
+
+        // Add non-zero scalar to have non-clean ciphertexts
+        let clear_2 = random_non_zero_value(&mut rng, modulus);
+        let ctxt_0 = sks.unchecked_scalar_add(&ctxt_0, clear_2);
+        let clear_lhs = clear_0.wrapping_add(clear_2) % modulus;
+
+        let ct_res = executor.execute((&ctxt_0, &ctxt_1));
+        assert!(ct_res.block_carries_are_empty());
+
+        let decrypted_result: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            decrypted_result,
+            clear_lhs * clear_1,
+            "Invalid result for widening_mul, for {clear_lhs} * {clear_1}"
+        );
+    }
+
+    // Largest values, the product needs all the bits of the result
+    let max = modulus - 1;
+    let a: RadixCiphertext = sks.create_trivial_radix(max, NB_CTXT);
+    let b = cks.encrypt(max);
+    let ct_res = executor.execute((&a, &b));
+    let decrypted_result: u64 = cks.decrypt(&ct_res);
+    assert_eq!(decrypted_result, max * max);
+}
+
+pub(crate) fn default_mul_high_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a RadixCiphertext), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests_smaller {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        // Add non-zero scalar to have non-clean ciphertexts
+        let clear_2 = random_non_zero_value(&mut rng, modulus);
+        let ctxt_0 = sks.unchecked_scalar_add(&cks.encrypt(clear_0), clear_2);
+        let ctxt_1 = cks.encrypt(clear_1);
+        let clear_lhs = clear_0.wrapping_add(clear_2) % modulus;
+
+        let ct_res = executor.execute((&ctxt_0, &ctxt_1));
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res.blocks.len(), NB_CTXT);
+
+        let expected_result = (clear_lhs * clear_1) / modulus;
+        let decrypted_result: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for mul_high, for {clear_lhs} * {clear_1} \
+                expected {expected_result}, got {decrypted_result}"
+        );
+    }
+}
+
 pub(crate) fn unchecked_bitnot_test<P, T>(param: P, mut executor: T)
 where
     P: Into<PBSParameters>,
@@ -1,13 +1,17 @@
 mod modulus_switch_compression;
 pub(crate) mod test_add;
 pub(crate) mod test_bitwise_op;
+mod test_clamp;
 pub(crate) mod test_cmux;
 pub(crate) mod test_comparison;
+mod test_conv2d;
 mod test_count_zeros_ones;
 pub(crate) mod test_ilog2;
+mod test_is_in_range;
 pub(crate) mod test_mul;
 pub(crate) mod test_neg;
 pub(crate) mod test_rotate;
+mod test_saturating_ops;
 pub(crate) mod test_scalar_add;
 pub(crate) mod test_scalar_bitwise_op;
 pub(crate) mod test_scalar_comparison;
@@ -487,6 +491,160 @@ fn integer_signed_default_absolute_value(param: impl Into<PBSParameters>) {
     }
 }
 
+create_parametrized_test!(integer_signed_default_signum);
+
+fn integer_signed_default_signum(param: impl Into<PBSParameters>) {
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    for clear_0 in [-modulus, -1, 0, 1, modulus - 1] {
+        let ctxt_0 = cks.encrypt_signed_radix(clear_0, NB_CTXT);
+        let ct_res = sks.signum_parallelized(&ctxt_0);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: i64 = cks.decrypt_signed_radix(&ct_res);
+        assert_eq!(dec_res, clear_0.signum(), "Invalid signum for {clear_0}");
+    }
+
+    for _ in 0..nb_tests_smaller {
+        let mut clear_0 = rng.gen::<i64>() % modulus;
+        let clear_to_add = rng.gen::<i64>() % modulus;
+
+        let mut ctxt_0 = cks.encrypt_signed_radix(clear_0, NB_CTXT);
+        sks.unchecked_scalar_add_assign(&mut ctxt_0, clear_to_add);
+        clear_0 = signed_add_under_modulus(clear_0, clear_to_add, modulus);
+
+        let ct_res = sks.signum_parallelized(&ctxt_0);
+        let dec_res: i64 = cks.decrypt_signed_radix(&ct_res);
+        assert_eq!(dec_res, clear_0.signum(), "Invalid signum for {clear_0}");
+    }
+}
+
+create_parametrized_test!(integer_signed_default_unsigned_absolute_value);
+
+fn integer_signed_default_unsigned_absolute_value(param: impl Into<PBSParameters>) {
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    // Unlike abs, the absolute value of the minimum is representable
+    {
+        let clear_0 = -modulus;
+        let ctxt_0 = cks.encrypt_signed_radix(clear_0, NB_CTXT);
+        let ct_res = sks.unsigned_abs_parallelized(&ctxt_0);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(dec_res, modulus as u64);
+    }
+
+    for _ in 0..nb_tests_smaller {
+        let mut clear_0 = rng.gen::<i64>() % modulus;
+        let clear_to_add = rng.gen::<i64>() % modulus;
+
+        let mut ctxt_0 = cks.encrypt_signed_radix(clear_0, NB_CTXT);
+        sks.unchecked_scalar_add_assign(&mut ctxt_0, clear_to_add);
+        clear_0 = signed_add_under_modulus(clear_0, clear_to_add, modulus);
+
+        let ct_res = sks.unsigned_abs_parallelized(&ctxt_0);
+        assert_eq!(ct_res.blocks.len(), NB_CTXT);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(dec_res, clear_0.unsigned_abs());
+    }
+}
+
+create_parametrized_test!(
+    integer_signed_default_div_rem_euclid {
+        coverage => {
+            COVERAGE_PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            COVERAGE_PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS,
+        },
+        no_coverage => {
+            // Does not support 1_1
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+            PARAM_MESSAGE_4_CARRY_4_KS_PBS,
+            PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS,
+            PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_2_KS_PBS,
+            PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_3_KS_PBS,
+            PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_3_KS_PBS,
+        }
+    }
+);
+
+fn integer_signed_default_div_rem_euclid(param: impl Into<PBSParameters>) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    if modulus > 8 {
+        // The remainder of the euclidean division is never negative
+        let values = [
+            (-8, 3, -3, 1),
+            (8, -3, -2, 2),
+            (7, 3, 2, 1),
+            (-7, 3, -3, 2),
+            (7, -3, -2, 1),
+            (-7, -3, 3, 2),
+        ];
+        for (clear_0, clear_1, expected_q, expected_r) in values {
+            let ctxt_0 = cks.encrypt_signed_radix(clear_0, NB_CTXT);
+            let ctxt_1 = cks.encrypt_signed_radix(clear_1, NB_CTXT);
+
+            let (q_res, r_res) = sks.div_rem_euclid_parallelized(&ctxt_0, &ctxt_1);
+            let q: i64 = cks.decrypt_signed_radix(&q_res);
+            let r: i64 = cks.decrypt_signed_radix(&r_res);
+
+            // Uses the hardcoded values to also test our clear function
+            let (q2, r2) = signed_div_rem_euclid_under_modulus(clear_0, clear_1, modulus);
+
+            assert_eq!(q2, expected_q);
+            assert_eq!(r2, expected_r);
+            assert_eq!(q, expected_q);
+            assert_eq!(r, expected_r);
+        }
+    }
+
+    // Div is the slowest operation
+    for _ in 0..5 {
+        let mut clear_0 = rng.gen::<i64>() % modulus;
+        let clear_to_add = rng.gen::<i64>() % modulus;
+        let clear_1 = random_non_zero_value(&mut rng, modulus);
+
+        // The numerator has carries
+        let mut ctxt_0 = cks.encrypt_signed_radix(clear_0, NB_CTXT);
+        sks.unchecked_scalar_add_assign(&mut ctxt_0, clear_to_add);
+        clear_0 = signed_add_under_modulus(clear_0, clear_to_add, modulus);
+        let ctxt_1 = cks.encrypt_signed_radix(clear_1, NB_CTXT);
+
+        let (q_res, r_res) = sks.div_rem_euclid_parallelized(&ctxt_0, &ctxt_1);
+        assert!(q_res.block_carries_are_empty());
+        assert!(r_res.block_carries_are_empty());
+
+        let q: i64 = cks.decrypt_signed_radix(&q_res);
+        let r: i64 = cks.decrypt_signed_radix(&r_res);
+        let (expected_q, expected_r) =
+            signed_div_rem_euclid_under_modulus(clear_0, clear_1, modulus);
+
+        assert_eq!(
+            q, expected_q,
+            "Invalid quotient for {clear_0}.div_euclid({clear_1})"
+        );
+        assert_eq!(
+            r, expected_r,
+            "Invalid remainder for {clear_0}.rem_euclid({clear_1})"
+        );
+    }
+}
+
 //================================================================================
 //     Unchecked Scalar Tests
 //================================================================================
@@ -823,6 +981,23 @@ pub(crate) fn signed_div_rem_floor_under_modulus(lhs: i64, rhs: i64, modulus: i6
     (q, r)
 }
 
+pub(crate) fn signed_div_rem_euclid_under_modulus(lhs: i64, rhs: i64, modulus: i64) -> (i64, i64) {
+    let mut q = signed_div_under_modulus(lhs, rhs, modulus);
+    let mut r = signed_rem_under_modulus(lhs, rhs, modulus);
+
+    if r < 0 {
+        if rhs > 0 {
+            q = signed_sub_under_modulus(q, 1, modulus);
+            r = signed_add_under_modulus(r, rhs, modulus);
+        } else {
+            q = signed_add_under_modulus(q, 1, modulus);
+            r = signed_sub_under_modulus(r, rhs, modulus);
+        }
+    }
+
+    (q, r)
+}
+
 /// helper function to do a rotate left when the type used to store
 /// the value is bigger than the actual intended bit size
 pub(crate) fn rotate_left_helper(value: i64, n: u32, actual_bit_size: u32) -> i64 {
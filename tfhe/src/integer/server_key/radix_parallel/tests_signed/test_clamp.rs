@@ -0,0 +1,46 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, NB_CTXT,
+};
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{IntegerKeyKind, RadixClientKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+create_parametrized_test!(integer_signed_default_clamp);
+
+fn integer_signed_default_clamp<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    for _ in 0..nb_tests_smaller {
+        let clear = rng.gen_range(-modulus..modulus);
+        let clear_min = rng.gen_range(-modulus..modulus);
+        let clear_max = rng.gen_range(clear_min..modulus);
+
+        let ctxt = cks.encrypt_signed(clear);
+        let ctxt_min = cks.encrypt_signed(clear_min);
+        let ctxt_max = cks.encrypt_signed(clear_max);
+
+        let ct_res = sks.clamp_parallelized(&ctxt, &ctxt_min, &ctxt_max);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(dec_res, clear.clamp(clear_min, clear_max));
+
+        let ct_res = sks.scalar_clamp_parallelized(&ctxt, clear_min, clear_max);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(dec_res, clear.clamp(clear_min, clear_max));
+    }
+}
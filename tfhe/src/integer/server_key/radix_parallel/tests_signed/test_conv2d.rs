@@ -0,0 +1,100 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_signed::{
+    signed_add_under_modulus, signed_mul_under_modulus,
+};
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, NB_CTXT,
+};
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{IntegerKeyKind, RadixClientKey, SignedRadixCiphertext};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+create_parametrized_test!(integer_signed_default_clear_conv2d);
+
+fn integer_signed_default_clear_conv2d<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    for (height, width, kernel_height, kernel_width, stride, padding) in [
+        (1, 1, 1, 1, 1, 0),
+        (3, 4, 2, 2, 1, 0),
+        (4, 3, 3, 3, 2, 1),
+        (2, 2, 3, 2, 1, 2),
+    ] {
+        for _ in 0..nb_tests_smaller {
+            let kernel = (0..kernel_height)
+                .map(|_| {
+                    (0..kernel_width)
+                        .map(|_| rng.gen_range(-300i64..300))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+            let mut clear_image = (0..height * width)
+                .map(|_| rng.gen_range(-modulus..modulus))
+                .collect::<Vec<_>>();
+
+            let mut image = clear_image
+                .iter()
+                .map(|&clear| cks.encrypt_signed(clear))
+                .collect::<Vec<SignedRadixCiphertext>>();
+            // Make the first pixel have carries
+            sks.unchecked_scalar_add_assign(&mut image[0], 1);
+            clear_image[0] = signed_add_under_modulus(clear_image[0], 1, modulus);
+
+            let result = sks.clear_conv2d_parallelized(&image, width, &kernel, stride, padding);
+
+            let output_height = (height + 2 * padding - kernel_height) / stride + 1;
+            let output_width = (width + 2 * padding - kernel_width) / stride + 1;
+            assert_eq!(result.len(), output_height * output_width);
+
+            for (i, ct) in result.iter().enumerate() {
+                assert!(ct.block_carries_are_empty());
+
+                let (output_y, output_x) = (i / output_width, i % output_width);
+                let mut expected = 0i64;
+                for (kernel_y, kernel_row) in kernel.iter().enumerate() {
+                    for (kernel_x, &coefficient) in kernel_row.iter().enumerate() {
+                        let y = (output_y * stride + kernel_y) as i64 - padding as i64;
+                        let x = (output_x * stride + kernel_x) as i64 - padding as i64;
+                        if (0..height as i64).contains(&y) && (0..width as i64).contains(&x) {
+                            let pixel = clear_image[y as usize * width + x as usize];
+                            let term = signed_mul_under_modulus(pixel, coefficient, modulus);
+                            expected = signed_add_under_modulus(expected, term, modulus);
+                        }
+                    }
+                }
+
+                let decrypted: i64 = cks.decrypt_signed(ct);
+                assert_eq!(
+                    decrypted, expected,
+                    "Invalid convolution at ({output_y}, {output_x}) for kernel {kernel:?}, \
+                    stride {stride}, padding {padding} and image {clear_image:?}"
+                );
+            }
+        }
+    }
+
+    // Output pixels whose patch only covers the padding give trivial zeros
+    let image = vec![cks.encrypt_signed(rng.gen_range(-modulus..modulus))];
+    let result = sks.clear_conv2d_parallelized(&image, 1, &[[1i64]], 1, 1);
+    assert_eq!(result.len(), 9);
+    for (i, ct) in result.iter().enumerate() {
+        if i != 4 {
+            assert!(ct.is_trivial());
+            let decrypted: i64 = cks.decrypt_signed(ct);
+            assert_eq!(decrypted, 0);
+        }
+    }
+}
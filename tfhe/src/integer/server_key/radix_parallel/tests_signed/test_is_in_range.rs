@@ -0,0 +1,65 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, NB_CTXT,
+};
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{IntegerKeyKind, RadixClientKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+create_parametrized_test!(integer_signed_default_is_in_range);
+
+fn integer_signed_default_is_in_range<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    for i in 0..nb_tests_smaller {
+        let clear = rng.gen_range(-modulus..modulus);
+        let (clear_low, clear_high) = match i % 4 {
+            // Bounds equal to the value
+            0 => (clear, clear),
+            // Bounds of opposite signs
+            1 => (rng.gen_range(-modulus..0), rng.gen_range(0..modulus)),
+            _ => (
+                rng.gen_range(-modulus..modulus),
+                rng.gen_range(-modulus..modulus),
+            ),
+        };
+        let expected = (clear_low..=clear_high).contains(&clear);
+
+        let ctxt = cks.encrypt_signed(clear);
+        let ctxt_low = cks.encrypt_signed(clear_low);
+        let ctxt_high = cks.encrypt_signed(clear_high);
+
+        let ct_res = sks.is_in_range_parallelized(&ctxt, &ctxt_low, &ctxt_high);
+        assert_eq!(
+            cks.decrypt_bool(&ct_res),
+            expected,
+            "Invalid result for {clear} in [{clear_low}, {clear_high}]"
+        );
+
+        let ct_res = sks.scalar_is_in_range_parallelized(&ctxt, clear_low, clear_high);
+        assert_eq!(
+            cks.decrypt_bool(&ct_res),
+            expected,
+            "Invalid result for {clear} in [{clear_low}, {clear_high}]"
+        );
+
+        // Clear bounds out of the range of the ciphertext
+        let ct_res = sks.scalar_is_in_range_parallelized(&ctxt, i64::MIN, clear_high);
+        assert_eq!(cks.decrypt_bool(&ct_res), clear <= clear_high);
+        let ct_res = sks.scalar_is_in_range_parallelized(&ctxt, i64::MIN, -modulus - 1);
+        assert!(!cks.decrypt_bool(&ct_res));
+    }
+}
@@ -0,0 +1,88 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, NB_CTXT,
+};
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{IntegerKeyKind, RadixClientKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+create_parametrized_test!(integer_signed_default_saturating_ops);
+create_parametrized_test!(integer_signed_default_saturating_scalar_ops);
+
+fn integer_signed_default_saturating_ops<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+    let saturate = |value: i64| value.clamp(-modulus, modulus - 1);
+
+    for _ in 0..nb_tests_smaller {
+        let clear_0 = rng.gen_range(-modulus..modulus);
+        let clear_1 = rng.gen_range(-modulus..modulus);
+
+        let ctxt_0 = cks.encrypt_signed(clear_0);
+        let ctxt_1 = cks.encrypt_signed(clear_1);
+
+        let ct_res = sks.signed_saturating_add_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(dec_res, saturate(clear_0 + clear_1));
+
+        let ct_res = sks.signed_saturating_sub_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(dec_res, saturate(clear_0 - clear_1));
+
+        let ct_res = sks.signed_saturating_mul_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(dec_res, saturate(clear_0 * clear_1));
+    }
+}
+
+fn integer_signed_default_saturating_scalar_ops<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+    let saturate = |value: i64| value.clamp(-modulus, modulus - 1);
+
+    for _ in 0..nb_tests_smaller {
+        let clear_0 = rng.gen_range(-modulus..modulus);
+        let scalar = rng.gen_range(-modulus..modulus);
+
+        let ctxt_0 = cks.encrypt_signed(clear_0);
+
+        let ct_res = sks.signed_saturating_scalar_add_parallelized(&ctxt_0, scalar);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(dec_res, saturate(clear_0 + scalar));
+
+        let ct_res = sks.signed_saturating_scalar_sub_parallelized(&ctxt_0, scalar);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(dec_res, saturate(clear_0 - scalar));
+
+        let ct_res = sks.signed_saturating_scalar_mul_parallelized(&ctxt_0, scalar);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(dec_res, saturate(clear_0 * scalar));
+    }
+}
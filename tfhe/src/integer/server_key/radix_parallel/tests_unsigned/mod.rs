@@ -1,14 +1,24 @@
 mod modulus_switch_compression;
 pub(crate) mod test_add;
 pub(crate) mod test_bitwise_op;
+mod test_clamp;
 pub(crate) mod test_cmux;
 pub(crate) mod test_comparison;
 mod test_count_zeros_ones;
 pub(crate) mod test_div_mod;
+mod test_dot_product;
 pub(crate) mod test_ilog2;
+mod test_is_in_range;
+mod test_join;
+mod test_matrix_mul;
+mod test_modular_arith;
 pub(crate) mod test_mul;
 pub(crate) mod test_neg;
+mod test_pow;
+mod test_progressive_comparison;
+mod test_reduce;
 pub(crate) mod test_rotate;
+mod test_saturating_ops;
 pub(crate) mod test_scalar_add;
 pub(crate) mod test_scalar_bitwise_op;
 pub(crate) mod test_scalar_comparison;
@@ -17,8 +27,12 @@ pub(crate) mod test_scalar_mul;
 pub(crate) mod test_scalar_rotate;
 pub(crate) mod test_scalar_shift;
 pub(crate) mod test_scalar_sub;
+mod test_scalar_thresholds;
+mod test_select_case;
 pub(crate) mod test_shift;
 pub(crate) mod test_slice;
+mod test_sort;
+mod test_sqrt;
 pub(crate) mod test_sub;
 pub(crate) mod test_sum;
 pub(crate) mod test_vector_comparisons;
@@ -580,6 +594,20 @@ where
     }
 }
 
+impl<'a, F> FunctionExecutor<&'a [RadixCiphertext], RadixCiphertext> for CpuFunctionExecutor<F>
+where
+    F: Fn(&ServerKey, &[RadixCiphertext]) -> RadixCiphertext,
+{
+    fn setup(&mut self, _cks: &RadixClientKey, sks: Arc<ServerKey>) {
+        self.sks = Some(sks);
+    }
+
+    fn execute(&mut self, input: &'a [RadixCiphertext]) -> RadixCiphertext {
+        let sks = self.sks.as_ref().expect("setup was not properly called");
+        (self.func)(sks, input)
+    }
+}
+
 impl<'a, F> FunctionExecutor<&'a Vec<RadixCiphertext>, Option<RadixCiphertext>>
     for CpuFunctionExecutor<F>
 where
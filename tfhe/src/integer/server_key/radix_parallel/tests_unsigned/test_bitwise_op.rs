@@ -1,14 +1,19 @@
+use crate::integer::keycache::KEY_CACHE;
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{
     default_bitand_test, default_bitnot_test, default_bitor_test, default_bitxor_test,
     smart_bitand_test, smart_bitor_test, smart_bitxor_test, unchecked_bitand_test,
-    unchecked_bitnot_test, unchecked_bitor_test, unchecked_bitxor_test,
+    unchecked_bitnot_test, unchecked_bitor_test, unchecked_bitxor_test, FunctionExecutor, NB_CTXT,
+};
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, unsigned_modulus, CpuFunctionExecutor,
 };
-use crate::integer::server_key::radix_parallel::tests_unsigned::CpuFunctionExecutor;
 use crate::integer::tests::create_parametrized_test;
-use crate::integer::ServerKey;
+use crate::integer::{IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
 #[cfg(tarpaulin)]
 use crate::shortint::parameters::coverage_parameters::*;
 use crate::shortint::parameters::*;
+use rand::Rng;
+use std::sync::Arc;
 
 create_parametrized_test!(integer_smart_bitand);
 create_parametrized_test!(integer_smart_bitor);
@@ -21,6 +26,7 @@ create_parametrized_test!(integer_unchecked_bitand);
 create_parametrized_test!(integer_unchecked_bitor);
 create_parametrized_test!(integer_unchecked_bitnot);
 create_parametrized_test!(integer_unchecked_bitxor);
+create_parametrized_test!(integer_default_merge_by_mask);
 
 fn integer_smart_bitand<P>(param: P)
 where
@@ -109,3 +115,74 @@ where
     let executor = CpuFunctionExecutor::new(&ServerKey::bitnot);
     unchecked_bitnot_test(param, executor);
 }
+
+fn integer_default_merge_by_mask<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::merge_by_mask_parallelized);
+    default_merge_by_mask_test(param, executor);
+}
+
+pub(crate) fn default_merge_by_mask_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (
+            &'a RadixCiphertext,
+            &'a RadixCiphertext,
+            &'a RadixCiphertext,
+        ),
+        RadixCiphertext,
+    >,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests_smaller {
+        let clear_true = rng.gen::<u64>() % modulus;
+        let clear_false = rng.gen::<u64>() % modulus;
+        let clear_mask = rng.gen::<u64>() % modulus;
+
+        let ctxt_true = cks.encrypt(clear_true);
+        let ctxt_false = cks.encrypt(clear_false);
+        let ctxt_mask = cks.encrypt(clear_mask);
+
+        let ct_res = executor.execute((&ctxt_true, &ctxt_false, &ctxt_mask));
+        assert!(ct_res.block_carries_are_empty());
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        let expected = (clear_true & clear_mask) | (clear_false & (!clear_mask % modulus));
+        assert_eq!(
+            dec_res, expected,
+            "Invalid merge of {clear_true} and {clear_false} by mask {clear_mask}"
+        );
+
+        // Add to have non empty carries
+        let clear_2 = rng.gen::<u64>() % modulus;
+        let mut ctxt_mask = ctxt_mask;
+        sks.unchecked_add_assign(&mut ctxt_mask, &cks.encrypt(clear_2));
+        assert!(!ctxt_mask.block_carries_are_empty());
+        let clear_mask = (clear_mask + clear_2) % modulus;
+
+        let ct_res = executor.execute((&ctxt_true, &ctxt_false, &ctxt_mask));
+        assert!(ct_res.block_carries_are_empty());
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        let expected = (clear_true & clear_mask) | (clear_false & (!clear_mask % modulus));
+        assert_eq!(
+            dec_res, expected,
+            "Invalid merge of {clear_true} and {clear_false} by mask {clear_mask}"
+        );
+    }
+}
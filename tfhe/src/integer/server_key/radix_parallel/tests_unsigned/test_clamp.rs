@@ -0,0 +1,51 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, random_non_zero_value, unsigned_modulus,
+};
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{IntegerKeyKind, RadixClientKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+use super::NB_CTXT;
+
+create_parametrized_test!(integer_default_clamp);
+
+fn integer_default_clamp<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests_smaller {
+        let clear = rng.gen::<u64>() % modulus;
+        let clear_min = rng.gen::<u64>() % modulus;
+        let clear_max = rng.gen_range(clear_min..modulus);
+
+        // Make the clamped ciphertext have carries
+        let clear_0 = random_non_zero_value(&mut rng, modulus);
+        let ctxt =
+            sks.unchecked_scalar_add(&cks.encrypt(clear.wrapping_sub(clear_0) % modulus), clear_0);
+        let ctxt_min = cks.encrypt(clear_min);
+        let ctxt_max = cks.encrypt(clear_max);
+
+        let ct_res = sks.clamp_parallelized(&ctxt, &ctxt_min, &ctxt_max);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, clear.clamp(clear_min, clear_max));
+
+        let ct_res = sks.scalar_clamp_parallelized(&ctxt, clear_min, clear_max);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, clear.clamp(clear_min, clear_max));
+    }
+}
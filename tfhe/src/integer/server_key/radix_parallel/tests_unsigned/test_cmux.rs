@@ -2,7 +2,7 @@ use crate::integer::keycache::KEY_CACHE;
 use crate::integer::prelude::*;
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{FunctionExecutor, NB_CTXT};
 use crate::integer::server_key::radix_parallel::tests_unsigned::{
-    nb_tests_for_params, CpuFunctionExecutor,
+    nb_tests_for_params, nb_tests_smaller_for_params, CpuFunctionExecutor,
 };
 use crate::integer::tests::create_parametrized_test;
 use crate::integer::{BooleanBlock, IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
@@ -14,6 +14,7 @@ use std::sync::Arc;
 
 create_parametrized_test!(integer_smart_if_then_else);
 create_parametrized_test!(integer_default_if_then_else);
+create_parametrized_test!(integer_default_if_then_else_slice);
 
 fn integer_smart_if_then_else<P>(param: P)
 where
@@ -232,3 +233,57 @@ where
         assert_eq!(cks.decrypt::<u64>(&result), 2);
     }
 }
+
+fn integer_default_if_then_else_slice<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..nb_tests_smaller {
+        let len = rng.gen_range(0..5);
+        let clears_true = (0..len)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        let clears_false = (0..len)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        let clear_condition = rng.gen_bool(0.5);
+
+        let mut cts_true = clears_true
+            .iter()
+            .map(|&v| cks.encrypt(v))
+            .collect::<Vec<_>>();
+        let cts_false = clears_false
+            .iter()
+            .map(|&v| cks.encrypt(v))
+            .collect::<Vec<_>>();
+        // Make one ciphertext have carries
+        if let Some(ct) = cts_true.first_mut() {
+            sks.unchecked_add_assign(ct, &cks.encrypt(0u64));
+        }
+        let condition = cks.encrypt_bool(clear_condition);
+
+        let cts_res = sks.if_then_else_slice_parallelized(&condition, &cts_true, &cts_false);
+        assert_eq!(cts_res.len(), len);
+
+        let expected = if clear_condition {
+            &clears_true
+        } else {
+            &clears_false
+        };
+        for (ct_res, &expected) in cts_res.iter().zip(expected.iter()) {
+            assert!(ct_res.block_carries_are_empty());
+            let dec_res: u64 = cks.decrypt(ct_res);
+            assert_eq!(dec_res, expected);
+        }
+    }
+}
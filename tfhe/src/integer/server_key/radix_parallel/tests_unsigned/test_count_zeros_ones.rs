@@ -4,7 +4,7 @@ use crate::integer::server_key::radix_parallel::tests_unsigned::{
     nb_tests_smaller_for_params, CpuFunctionExecutor, MAX_NB_CTXT, NB_CTXT,
 };
 use crate::integer::tests::create_parametrized_test;
-use crate::integer::{IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
+use crate::integer::{BooleanBlock, IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
 use crate::shortint::PBSParameters;
 use rand::Rng;
 use std::sync::Arc;
@@ -13,6 +13,7 @@ use crate::shortint::parameters::*;
 
 create_parametrized_test!(integer_extensive_trivial_default_count_zeros_ones);
 create_parametrized_test!(integer_default_count_zeros_ones);
+create_parametrized_test!(integer_default_hamming_distance);
 
 fn integer_extensive_trivial_default_count_zeros_ones<P>(param: P)
 where
@@ -36,6 +37,16 @@ where
     default_count_zeros_ones_test(param, count_zeros_executor, count_ones_executor);
 }
 
+fn integer_default_hamming_distance<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let distance_executor = CpuFunctionExecutor::new(&ServerKey::hamming_distance_parallelized);
+    let is_within_executor =
+        CpuFunctionExecutor::new(&ServerKey::is_within_hamming_distance_parallelized);
+    default_hamming_distance_test(param, distance_executor, is_within_executor);
+}
+
 pub(crate) fn default_count_zeros_ones_test<P, E1, E2>(
     param: P,
     mut count_zeros_executor: E1,
@@ -151,3 +162,74 @@ pub(crate) fn extensive_trivial_default_count_zeros_ones_test<P, E1, E2>(
         }
     }
 }
+
+pub(crate) fn default_hamming_distance_test<P, E1, E2>(
+    param: P,
+    mut distance_executor: E1,
+    mut is_within_executor: E2,
+) where
+    P: Into<PBSParameters>,
+    E1: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a RadixCiphertext), RadixCiphertext>,
+    E2: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a RadixCiphertext, u32), BooleanBlock>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    distance_executor.setup(&cks, sks.clone());
+    is_within_executor.setup(&cks, sks.clone());
+
+    let cks: crate::integer::ClientKey = cks.into();
+
+    for num_blocks in 1..=MAX_NB_CTXT {
+        let Some(modulus) =
+            (cks.parameters().message_modulus().0 as u128).checked_pow(num_blocks as u32)
+        else {
+            break;
+        };
+        for _ in 0..nb_tests {
+            let clear_a = rng.gen::<u128>() % modulus;
+            let clear_b = rng.gen::<u128>() % modulus;
+
+            let a: RadixCiphertext = cks.encrypt_radix(clear_a, num_blocks);
+            let mut b: RadixCiphertext = cks.encrypt_radix(clear_b, num_blocks);
+
+            let expected = (clear_a ^ clear_b).count_ones();
+            let encrypted = distance_executor.execute((&a, &b));
+            let decrypted: u32 = cks.decrypt_radix(&encrypted);
+            assert_eq!(
+                decrypted, expected,
+                "Invalid hamming_distance for inputs {clear_a} {clear_b}"
+            );
+
+            let max_distance = rng.gen_range(0..=modulus.ilog2());
+            let encrypted = is_within_executor.execute((&a, &b, max_distance));
+            assert_eq!(
+                cks.decrypt_bool(&encrypted),
+                expected <= max_distance,
+                "Invalid is_within_hamming_distance for inputs {clear_a} {clear_b} \
+                and max distance {max_distance}"
+            );
+
+            // Make the rhs have carries
+            let clear_c = rng.gen_range(0..modulus - clear_b);
+            let c: RadixCiphertext = cks.encrypt_radix(clear_c, num_blocks);
+            sks.unchecked_add_assign(&mut b, &c);
+            let clear_b = clear_b + clear_c;
+
+            let encrypted = distance_executor.execute((&a, &b));
+            let decrypted: u32 = cks.decrypt_radix(&encrypted);
+            assert_eq!(
+                decrypted,
+                (clear_a ^ clear_b).count_ones(),
+                "Invalid hamming_distance for inputs {clear_a} {clear_b}"
+            );
+        }
+    }
+}
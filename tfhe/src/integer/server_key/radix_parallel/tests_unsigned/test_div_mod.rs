@@ -126,6 +126,26 @@ create_parametrized_test!(
     }
 );
 
+create_parametrized_test!(
+    integer_default_div_rem_pow2_hint {
+        coverage => {
+            COVERAGE_PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            COVERAGE_PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS,
+        },
+        no_coverage => {
+            // Due to the use of shifts,
+            // this algorithm requires 3 bits
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+            PARAM_MESSAGE_4_CARRY_4_KS_PBS,
+            PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS,
+            PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_2_KS_PBS,
+            PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_3_KS_PBS,
+            PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_3_KS_PBS,
+        }
+    }
+);
+
 fn integer_smart_div_rem<P>(param: P)
 where
     P: Into<PBSParameters>,
@@ -174,6 +194,69 @@ where
     default_rem_test(param, executor);
 }
 
+fn integer_default_div_rem_pow2_hint<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::div_rem_pow2_hint_parallelized);
+    default_div_rem_pow2_hint_test(param, executor);
+}
+
+pub(crate) fn default_div_rem_pow2_hint_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a RadixCiphertext, &'a RadixCiphertext),
+        (RadixCiphertext, RadixCiphertext),
+    >,
+{
+    let param = param.into();
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+    let nb_bits = modulus.ilog2() as u64;
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests_smaller {
+        let mut clear_0 = rng.gen::<u64>() % modulus;
+        let clear_log2 = rng.gen_range(0..nb_bits);
+        let clear_2 = rng.gen::<u64>() % modulus;
+
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_log2 = cks.encrypt(clear_log2);
+
+        // add to change degree
+        sks.unchecked_scalar_add_assign(&mut ctxt_0, clear_2);
+        clear_0 += clear_2;
+        clear_0 %= modulus;
+
+        let (q_res, r_res) = executor.execute((&ctxt_0, &ctxt_log2));
+        let q: u64 = cks.decrypt(&q_res);
+        let r: u64 = cks.decrypt(&r_res);
+
+        assert!(q_res.block_carries_are_empty());
+        assert!(r_res.block_carries_are_empty());
+        assert_eq!(
+            clear_0 >> clear_log2,
+            q,
+            "Invalid quotient for {clear_0} / 2^{clear_log2}"
+        );
+        assert_eq!(
+            clear_0 & ((1 << clear_log2) - 1),
+            r,
+            "Invalid remainder for {clear_0} % 2^{clear_log2}"
+        );
+    }
+}
+
 pub(crate) fn default_div_rem_test<P, T>(param: P, mut executor: T)
 where
     P: Into<PBSParameters>,
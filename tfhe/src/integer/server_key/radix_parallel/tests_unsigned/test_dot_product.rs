@@ -0,0 +1,119 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, unsigned_modulus,
+};
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{IntegerKeyKind, RadixCiphertext, RadixClientKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+use super::NB_CTXT;
+
+create_parametrized_test!(integer_default_dot_product);
+create_parametrized_test!(integer_default_scalar_dot_product);
+
+fn clear_dot_product(lhs: &[u64], rhs: &[u64], modulus: u64) -> u64 {
+    lhs.iter()
+        .zip(rhs.iter())
+        .fold(0u64, |acc, (&l, &r)| acc.wrapping_add(l.wrapping_mul(r)))
+        % modulus
+}
+
+fn integer_default_dot_product<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for len in [1, 4] {
+        for _ in 0..nb_tests_smaller {
+            let mut clear_lhs = (0..len)
+                .map(|_| rng.gen::<u64>() % modulus)
+                .collect::<Vec<_>>();
+            let clear_rhs = (0..len)
+                .map(|_| rng.gen::<u64>() % modulus)
+                .collect::<Vec<_>>();
+
+            let mut lhs = clear_lhs
+                .iter()
+                .map(|&clear| cks.encrypt(clear))
+                .collect::<Vec<RadixCiphertext>>();
+            let rhs = clear_rhs
+                .iter()
+                .map(|&clear| cks.encrypt(clear))
+                .collect::<Vec<RadixCiphertext>>();
+            // Make the first element have carries
+            sks.unchecked_scalar_add_assign(&mut lhs[0], 1);
+            clear_lhs[0] = (clear_lhs[0] + 1) % modulus;
+
+            let result = sks.dot_product_parallelized(&lhs, &rhs);
+            assert!(result.block_carries_are_empty());
+
+            let decrypted: u64 = cks.decrypt(&result);
+            assert_eq!(
+                decrypted,
+                clear_dot_product(&clear_lhs, &clear_rhs, modulus),
+                "Invalid dot product of {clear_lhs:?} and {clear_rhs:?}"
+            );
+        }
+    }
+}
+
+fn integer_default_scalar_dot_product<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for len in [1, 5] {
+        for _ in 0..nb_tests_smaller {
+            let mut clear_lhs = (0..len)
+                .map(|_| rng.gen::<u64>() % modulus)
+                .collect::<Vec<_>>();
+            // Clear values may exceed the modulus, and some are zero
+            let clear_rhs = (0..len)
+                .map(|_| {
+                    if rng.gen_bool(0.2) {
+                        0
+                    } else {
+                        rng.gen::<u64>()
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            let mut lhs = clear_lhs
+                .iter()
+                .map(|&clear| cks.encrypt(clear))
+                .collect::<Vec<RadixCiphertext>>();
+            // Make the first element have carries
+            sks.unchecked_scalar_add_assign(&mut lhs[0], 1);
+            clear_lhs[0] = (clear_lhs[0] + 1) % modulus;
+
+            let result = sks.scalar_dot_product_parallelized(&lhs, &clear_rhs);
+            assert!(result.block_carries_are_empty());
+
+            let decrypted: u64 = cks.decrypt(&result);
+            assert_eq!(
+                decrypted,
+                clear_dot_product(&clear_lhs, &clear_rhs, modulus),
+                "Invalid scalar dot product of {clear_lhs:?} and {clear_rhs:?}"
+            );
+        }
+    }
+}
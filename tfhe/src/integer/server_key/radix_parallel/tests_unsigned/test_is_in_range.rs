@@ -0,0 +1,67 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, random_non_zero_value, unsigned_modulus,
+};
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{IntegerKeyKind, RadixClientKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+use super::NB_CTXT;
+
+create_parametrized_test!(integer_default_is_in_range);
+
+fn integer_default_is_in_range<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for i in 0..nb_tests_smaller {
+        let clear = rng.gen::<u64>() % modulus;
+        let (clear_low, clear_high) = match i % 4 {
+            // Bounds equal to the value
+            0 => (clear, clear),
+            // Empty range
+            1 => (clear, clear.wrapping_sub(1) % modulus),
+            _ => (rng.gen::<u64>() % modulus, rng.gen::<u64>() % modulus),
+        };
+        let expected = (clear_low..=clear_high).contains(&clear);
+
+        // Make the checked ciphertext have carries
+        let clear_0 = random_non_zero_value(&mut rng, modulus);
+        let ctxt =
+            sks.unchecked_scalar_add(&cks.encrypt(clear.wrapping_sub(clear_0) % modulus), clear_0);
+        let ctxt_low = cks.encrypt(clear_low);
+        let ctxt_high = cks.encrypt(clear_high);
+
+        let ct_res = sks.is_in_range_parallelized(&ctxt, &ctxt_low, &ctxt_high);
+        assert_eq!(
+            cks.decrypt_bool(&ct_res),
+            expected,
+            "Invalid result for {clear} in [{clear_low}, {clear_high}]"
+        );
+
+        let ct_res = sks.scalar_is_in_range_parallelized(&ctxt, clear_low, clear_high);
+        assert_eq!(
+            cks.decrypt_bool(&ct_res),
+            expected,
+            "Invalid result for {clear} in [{clear_low}, {clear_high}]"
+        );
+
+        // Clear bounds out of the range of the ciphertext
+        let ct_res = sks.scalar_is_in_range_parallelized(&ctxt, clear_low, u64::MAX);
+        assert_eq!(cks.decrypt_bool(&ct_res), clear >= clear_low);
+        let ct_res = sks.scalar_is_in_range_parallelized(&ctxt, modulus, u64::MAX);
+        assert!(!cks.decrypt_bool(&ct_res));
+    }
+}
@@ -0,0 +1,116 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, unsigned_modulus,
+};
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{IntegerKeyKind, RadixClientKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+use super::NB_CTXT;
+
+create_parametrized_test!(integer_default_eq_join);
+create_parametrized_test!(integer_default_eq_join_sum);
+
+// Keys are drawn from a small range so that matches are frequent
+const NUM_DISTINCT_KEYS: u64 = 3;
+
+fn integer_default_eq_join<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..nb_tests_smaller {
+        let left = (0..3)
+            .map(|_| rng.gen_range(0..NUM_DISTINCT_KEYS))
+            .collect::<Vec<_>>();
+        let right = (0..4)
+            .map(|_| rng.gen_range(0..NUM_DISTINCT_KEYS))
+            .collect::<Vec<_>>();
+
+        let ct_left = left.iter().map(|&v| cks.encrypt(v)).collect::<Vec<_>>();
+        let mut ct_right = right.iter().map(|&v| cks.encrypt(v)).collect::<Vec<_>>();
+        // Make one input have carries
+        sks.unchecked_add_assign(&mut ct_right[0], &cks.encrypt(0u64));
+
+        let matches = sks.eq_join_parallelized(&ct_left, &ct_right);
+        assert_eq!(matches.len(), left.len());
+
+        for (i, row) in matches.iter().enumerate() {
+            assert_eq!(row.len(), right.len());
+            for (j, is_match) in row.iter().enumerate() {
+                assert_eq!(
+                    cks.decrypt_bool(is_match),
+                    left[i] == right[j],
+                    "Invalid result for {} == {}",
+                    left[i],
+                    right[j]
+                );
+            }
+        }
+    }
+}
+
+fn integer_default_eq_join_sum<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests_smaller {
+        let left_keys = (0..3)
+            .map(|_| rng.gen_range(0..NUM_DISTINCT_KEYS))
+            .collect::<Vec<_>>();
+        let right_keys = (0..3)
+            .map(|_| rng.gen_range(0..NUM_DISTINCT_KEYS))
+            .collect::<Vec<_>>();
+        let right_values = (0..3)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+
+        let ct_left_keys = left_keys
+            .iter()
+            .map(|&v| cks.encrypt(v))
+            .collect::<Vec<_>>();
+        let ct_right_keys = right_keys
+            .iter()
+            .map(|&v| cks.encrypt(v))
+            .collect::<Vec<_>>();
+        let ct_right_values = right_values
+            .iter()
+            .map(|&v| cks.encrypt(v))
+            .collect::<Vec<_>>();
+
+        let sums = sks.eq_join_sum_parallelized(&ct_left_keys, &ct_right_keys, &ct_right_values);
+        assert_eq!(sums.len(), left_keys.len());
+
+        for (left_key, sum) in left_keys.iter().zip(sums.iter()) {
+            assert!(sum.block_carries_are_empty());
+            let expected = right_keys
+                .iter()
+                .zip(right_values.iter())
+                .filter(|(right_key, _)| *right_key == left_key)
+                .fold(0u64, |acc, (_, value)| (acc + value) % modulus);
+            let decrypted: u64 = cks.decrypt(sum);
+            assert_eq!(
+                decrypted, expected,
+                "Invalid sum for key {left_key}: {left_keys:?} {right_keys:?} {right_values:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, unsigned_modulus,
+};
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{IntegerKeyKind, RadixCiphertext, RadixClientKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+use super::NB_CTXT;
+
+create_parametrized_test!(integer_default_clear_matrix_mul_enc_vector);
+
+fn integer_default_clear_matrix_mul_enc_vector<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for (num_rows, num_columns) in [(1, 1), (3, 4), (4, 3)] {
+        for _ in 0..nb_tests_smaller {
+            let matrix = (0..num_rows)
+                .map(|_| {
+                    (0..num_columns)
+                        .map(|_| rng.gen_range(-300i64..300))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+            let clear_vector = (0..num_columns)
+                .map(|_| rng.gen::<u64>() % modulus)
+                .collect::<Vec<_>>();
+
+            let mut vector = clear_vector
+                .iter()
+                .map(|&clear| cks.encrypt(clear))
+                .collect::<Vec<RadixCiphertext>>();
+            // Make the first element have carries
+            sks.unchecked_scalar_add_assign(&mut vector[0], 1);
+
+            let result = sks.clear_matrix_mul_enc_vector_parallelized(&matrix, &vector);
+            assert_eq!(result.len(), num_rows);
+
+            for (row, ct) in matrix.iter().zip(result.iter()) {
+                assert!(ct.block_carries_are_empty());
+
+                let expected = row
+                    .iter()
+                    .zip(clear_vector.iter().enumerate())
+                    .map(|(&coefficient, (i, &value))| {
+                        let value = if i == 0 { value + 1 } else { value };
+                        (coefficient as u64).wrapping_mul(value)
+                    })
+                    .fold(0u64, u64::wrapping_add);
+                let expected = expected % modulus;
+
+                let decrypted: u64 = cks.decrypt(ct);
+                assert_eq!(
+                    decrypted, expected,
+                    "Invalid matrix product for row {row:?} and vector {clear_vector:?}"
+                );
+            }
+        }
+    }
+
+    // Rows with only zeros give trivial zeros
+    let vector = vec![cks.encrypt(rng.gen::<u64>() % modulus)];
+    let result = sks.clear_matrix_mul_enc_vector_parallelized(&[[0i64]], &vector);
+    assert!(result[0].is_trivial());
+    let decrypted: u64 = cks.decrypt(&result[0]);
+    assert_eq!(decrypted, 0);
+}
@@ -0,0 +1,83 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_unsigned::test_pow::clear_pow_mod;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, unsigned_modulus,
+};
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{IntegerKeyKind, RadixClientKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+use super::NB_CTXT;
+
+create_parametrized_test!(integer_default_modular_arith);
+
+fn integer_default_modular_arith<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let ct_modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for i in 0..nb_tests_smaller {
+        let modulus = match i % 3 {
+            // The largest modulus the ciphertexts can use, a power of two
+            0 => ct_modulus,
+            1 => ct_modulus - 1,
+            _ => rng.gen_range(1..=ct_modulus),
+        };
+        let clear_lhs = rng.gen::<u64>() % modulus;
+        let clear_rhs = rng.gen::<u64>() % modulus;
+        let clear_exponent = rng.gen_range(0..100u64);
+
+        let ctxt_lhs = cks.encrypt(clear_lhs);
+        let ctxt_rhs = cks.encrypt(clear_rhs);
+
+        let ct_res = sks.mod_add_parallelized(&ctxt_lhs, &ctxt_rhs, modulus);
+        assert!(ct_res.block_carries_are_empty());
+        let dec: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec,
+            (clear_lhs + clear_rhs) % modulus,
+            "Invalid result for ({clear_lhs} + {clear_rhs}) % {modulus}"
+        );
+
+        let ct_res = sks.mod_sub_parallelized(&ctxt_lhs, &ctxt_rhs, modulus);
+        assert!(ct_res.block_carries_are_empty());
+        let dec: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec,
+            (clear_lhs + modulus - clear_rhs) % modulus,
+            "Invalid result for ({clear_lhs} - {clear_rhs}) % {modulus}"
+        );
+
+        let ct_res = sks.mod_mul_parallelized(&ctxt_lhs, &ctxt_rhs, modulus);
+        assert!(ct_res.block_carries_are_empty());
+        let dec: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec,
+            ((u128::from(clear_lhs) * u128::from(clear_rhs)) % u128::from(modulus)) as u64,
+            "Invalid result for ({clear_lhs} * {clear_rhs}) % {modulus}"
+        );
+
+        // Make the base have carries
+        let mut ctxt_base = cks.encrypt(clear_lhs.wrapping_sub(1) % ct_modulus);
+        sks.unchecked_scalar_add_assign(&mut ctxt_base, 1);
+        let ct_res = sks.scalar_pow_mod_parallelized(&ctxt_base, clear_exponent, modulus);
+        assert!(ct_res.block_carries_are_empty());
+        let dec: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec,
+            clear_pow_mod(clear_lhs, clear_exponent, modulus),
+            "Invalid result for {clear_lhs}^{clear_exponent} % {modulus}"
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{
-    default_default_block_mul_test, default_mul_test, default_overflowing_mul_test,
-    smart_block_mul_test, smart_mul_test, unchecked_block_mul_test,
-    unchecked_mul_corner_cases_test, unchecked_mul_test,
+    default_default_block_mul_test, default_mul_high_test, default_mul_test,
+    default_overflowing_mul_test, default_widening_mul_test, smart_block_mul_test, smart_mul_test,
+    unchecked_block_mul_test, unchecked_mul_corner_cases_test, unchecked_mul_test,
 };
 use crate::integer::server_key::radix_parallel::tests_unsigned::CpuFunctionExecutor;
 use crate::integer::tests::create_parametrized_test;
@@ -17,6 +17,8 @@ create_parametrized_test!(integer_default_block_mul);
 create_parametrized_test!(integer_smart_mul);
 create_parametrized_test!(integer_default_mul);
 create_parametrized_test!(integer_default_unsigned_overflowing_mul);
+create_parametrized_test!(integer_default_widening_mul);
+create_parametrized_test!(integer_default_mul_high);
 create_parametrized_test!(integer_unchecked_mul);
 
 fn integer_unchecked_mul<P>(param: P)
@@ -75,6 +77,22 @@ where
     default_overflowing_mul_test(param, executor);
 }
 
+fn integer_default_widening_mul<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::widening_mul_parallelized);
+    default_widening_mul_test(param, executor);
+}
+
+fn integer_default_mul_high<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::mul_high_parallelized);
+    default_mul_high_test(param, executor);
+}
+
 fn integer_default_block_mul<P>(param: P)
 where
     P: Into<PBSParameters>,
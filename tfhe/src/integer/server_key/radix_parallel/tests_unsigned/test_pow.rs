@@ -0,0 +1,143 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, unsigned_modulus,
+};
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{IntegerKeyKind, RadixClientKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+use super::NB_CTXT;
+
+create_parametrized_test!(integer_default_pow);
+create_parametrized_test!(integer_default_scalar_pow);
+create_parametrized_test!(integer_default_scalar_base_pow_mod);
+
+fn integer_default_pow<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+    // Use a small exponent to keep the test fast
+    let exponent_num_blocks = 2;
+    let exponent_modulus = unsigned_modulus(
+        cks.parameters().message_modulus(),
+        exponent_num_blocks as u32,
+    );
+
+    for _ in 0..nb_tests_smaller {
+        let clear_base = rng.gen::<u64>() % modulus;
+        let clear_exponent = rng.gen::<u64>() % exponent_modulus;
+
+        let mut ct_base = cks.encrypt(clear_base);
+        let ct_exponent = cks
+            .as_ref()
+            .encrypt_radix(clear_exponent, exponent_num_blocks);
+
+        // Make the base have carries
+        sks.unchecked_scalar_add_assign(&mut ct_base, 1);
+        let clear_base = (clear_base + 1) % modulus;
+
+        let ct_res = sks.pow_parallelized(&ct_base, &ct_exponent);
+        assert!(ct_res.block_carries_are_empty());
+
+        let dec: u64 = cks.decrypt(&ct_res);
+        let expected = clear_base.wrapping_pow(clear_exponent as u32) % modulus;
+        assert_eq!(
+            dec, expected,
+            "Invalid result for {clear_base}^{clear_exponent}"
+        );
+    }
+}
+
+fn integer_default_scalar_pow<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests_smaller {
+        let clear_base = rng.gen::<u64>() % modulus;
+        // Always test the zero exponent
+        for clear_exponent in [0u32, rng.gen_range(1..100)] {
+            let ct_base = cks.encrypt(clear_base);
+
+            let ct_res = sks.scalar_pow_parallelized(&ct_base, clear_exponent);
+            assert!(ct_res.block_carries_are_empty());
+
+            let dec: u64 = cks.decrypt(&ct_res);
+            let expected = clear_base.wrapping_pow(clear_exponent) % modulus;
+            assert_eq!(
+                dec, expected,
+                "Invalid result for {clear_base}^{clear_exponent}"
+            );
+        }
+    }
+}
+
+pub(super) fn clear_pow_mod(base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let modulus = u128::from(modulus);
+    let mut square = u128::from(base) % modulus;
+    let mut result = 1 % modulus;
+    while exponent != 0 {
+        if exponent & 1 == 1 {
+            result = (result * square) % modulus;
+        }
+        square = (square * square) % modulus;
+        exponent >>= 1;
+    }
+    result as u64
+}
+
+fn integer_default_scalar_base_pow_mod<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests_smaller {
+        let clear_base = rng.gen::<u64>();
+        let clear_exponent = rng.gen::<u64>() % modulus;
+        // Also test the smallest moduli, and a modulus using all the blocks
+        for clear_modulus in [1, 2, rng.gen_range(3..=modulus), modulus] {
+            let mut ct_exponent = cks.encrypt(clear_exponent);
+            // Make the exponent have carries
+            sks.unchecked_scalar_add_assign(&mut ct_exponent, 0);
+
+            let ct_res =
+                sks.scalar_base_pow_mod_parallelized(clear_base, &ct_exponent, clear_modulus);
+            assert!(ct_res.block_carries_are_empty());
+            assert_eq!(ct_res.blocks.len(), NB_CTXT);
+
+            let dec: u64 = cks.decrypt(&ct_res);
+            let expected = clear_pow_mod(clear_base, clear_exponent, clear_modulus);
+            assert_eq!(
+                dec, expected,
+                "Invalid result for {clear_base}^{clear_exponent} % {clear_modulus}"
+            );
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, unsigned_modulus,
+};
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{IntegerKeyKind, RadixClientKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+use super::NB_CTXT;
+
+create_parametrized_test!(integer_default_approx_then_exact_comparisons);
+create_parametrized_test!(integer_default_approx_then_exact_min_max);
+
+fn integer_default_approx_then_exact_comparisons<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+    let bits_per_block = cks.parameters().message_modulus().0.ilog2();
+
+    for _ in 0..nb_tests_smaller {
+        let num_msb_blocks = rng.gen_range(1..=NB_CTXT);
+        let low_bits = (NB_CTXT - num_msb_blocks) as u32 * bits_per_block;
+
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let mut clear_1 = rng.gen::<u64>() % modulus;
+        if rng.gen_bool(0.5) {
+            // Force the most significant blocks to be equal
+            let low_mask = (1u64 << low_bits) - 1;
+            clear_1 = (clear_0 & !low_mask) | (clear_1 & low_mask);
+        }
+
+        // Make the first ciphertext have carries
+        let mut ctxt_0 = cks.encrypt((clear_0 + modulus - 1) % modulus);
+        sks.unchecked_scalar_add_assign(&mut ctxt_0, 1);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let (high_0, high_1) = (clear_0 >> low_bits, clear_1 >> low_bits);
+
+        let (approx, exact) =
+            sks.approx_then_exact_gt_parallelized(&ctxt_0, &ctxt_1, num_msb_blocks);
+        assert_eq!(cks.decrypt_bool(&approx), high_0 > high_1);
+        assert_eq!(cks.decrypt_bool(&exact), clear_0 > clear_1);
+
+        let (approx, exact) =
+            sks.approx_then_exact_ge_parallelized(&ctxt_0, &ctxt_1, num_msb_blocks);
+        assert_eq!(cks.decrypt_bool(&approx), high_0 >= high_1);
+        assert_eq!(cks.decrypt_bool(&exact), clear_0 >= clear_1);
+
+        let (approx, exact) =
+            sks.approx_then_exact_lt_parallelized(&ctxt_0, &ctxt_1, num_msb_blocks);
+        assert_eq!(cks.decrypt_bool(&approx), high_0 < high_1);
+        assert_eq!(cks.decrypt_bool(&exact), clear_0 < clear_1);
+
+        let (approx, exact) =
+            sks.approx_then_exact_le_parallelized(&ctxt_0, &ctxt_1, num_msb_blocks);
+        assert_eq!(cks.decrypt_bool(&approx), high_0 <= high_1);
+        assert_eq!(cks.decrypt_bool(&exact), clear_0 <= clear_1);
+    }
+}
+
+fn integer_default_approx_then_exact_min_max<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+    let bits_per_block = cks.parameters().message_modulus().0.ilog2();
+
+    for _ in 0..nb_tests_smaller {
+        let num_msb_blocks = rng.gen_range(1..=NB_CTXT);
+        let low_bits = (NB_CTXT - num_msb_blocks) as u32 * bits_per_block;
+
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let (high_0, high_1) = (clear_0 >> low_bits, clear_1 >> low_bits);
+
+        let (approx, exact) =
+            sks.approx_then_exact_max_parallelized(&ctxt_0, &ctxt_1, num_msb_blocks);
+        let expected_approx = if high_0 > high_1 { clear_0 } else { clear_1 };
+        assert_eq!(cks.decrypt::<u64>(&approx), expected_approx);
+        assert_eq!(cks.decrypt::<u64>(&exact), clear_0.max(clear_1));
+
+        let (approx, exact) =
+            sks.approx_then_exact_min_parallelized(&ctxt_0, &ctxt_1, num_msb_blocks);
+        let expected_approx = if high_0 < high_1 { clear_0 } else { clear_1 };
+        assert_eq!(cks.decrypt::<u64>(&approx), expected_approx);
+        assert_eq!(cks.decrypt::<u64>(&exact), clear_0.min(clear_1));
+    }
+}
@@ -0,0 +1,104 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, unsigned_modulus,
+};
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{IntegerKeyKind, RadixCiphertext, RadixClientKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+use super::NB_CTXT;
+
+create_parametrized_test!(integer_default_min_max_of_slice);
+create_parametrized_test!(integer_default_boolean_reductions);
+
+fn integer_default_min_max_of_slice<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    assert!(sks
+        .min_of_slice_parallelized::<RadixCiphertext>(&[])
+        .is_none());
+    assert!(sks
+        .max_of_slice_parallelized::<RadixCiphertext>(&[])
+        .is_none());
+
+    for _ in 0..nb_tests_smaller {
+        let len = rng.gen_range(1..=7);
+        let mut clears = (0..len)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        let mut cts = clears.iter().map(|&v| cks.encrypt(v)).collect::<Vec<_>>();
+
+        // Make one ciphertext have carries
+        let index = rng.gen_range(0..len);
+        sks.unchecked_scalar_add_assign(&mut cts[index], 1);
+        clears[index] = (clears[index] + 1) % modulus;
+
+        let min = sks.min_of_slice_parallelized(&cts).unwrap();
+        let max = sks.max_of_slice_parallelized(&cts).unwrap();
+        assert!(min.block_carries_are_empty());
+        assert!(max.block_carries_are_empty());
+
+        let decrypted_min: u64 = cks.decrypt(&min);
+        let decrypted_max: u64 = cks.decrypt(&max);
+        assert_eq!(decrypted_min, *clears.iter().min().unwrap());
+        assert_eq!(decrypted_max, *clears.iter().max().unwrap());
+    }
+}
+
+fn integer_default_boolean_reductions<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    assert!(cks.decrypt_bool(&sks.all_true_parallelized(&[])));
+    assert!(!cks.decrypt_bool(&sks.any_true_parallelized(&[])));
+    let count: u64 = cks.decrypt(&sks.count_true_parallelized(&[], NB_CTXT));
+    assert_eq!(count, 0);
+
+    for _ in 0..nb_tests_smaller {
+        let len = rng.gen_range(1..=20);
+        // Bias towards true, so that all the values being true is not too rare
+        let mut clears = (0..len).map(|_| rng.gen_ratio(9, 10)).collect::<Vec<_>>();
+        let mut booleans = clears
+            .iter()
+            .map(|&v| cks.encrypt_bool(v))
+            .collect::<Vec<_>>();
+
+        // Negate one of the booleans, so that it is not a clean boolean
+        let index = rng.gen_range(0..len);
+        booleans[index] = sks.boolean_bitnot(&booleans[index]);
+        clears[index] = !clears[index];
+
+        let all = sks.all_true_parallelized(&booleans);
+        let any = sks.any_true_parallelized(&booleans);
+        let count = sks.count_true_parallelized(&booleans, NB_CTXT);
+        assert!(count.block_carries_are_empty());
+
+        assert_eq!(cks.decrypt_bool(&all), clears.iter().all(|&v| v));
+        assert_eq!(cks.decrypt_bool(&any), clears.iter().any(|&v| v));
+        let decrypted_count: u64 = cks.decrypt(&count);
+        let expected_count = clears.iter().filter(|&&v| v).count() as u64 % modulus;
+        assert_eq!(decrypted_count, expected_count);
+    }
+}
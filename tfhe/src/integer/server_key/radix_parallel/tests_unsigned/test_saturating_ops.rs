@@ -0,0 +1,93 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, random_non_zero_value, unsigned_modulus,
+};
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{IntegerKeyKind, RadixClientKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+use super::NB_CTXT;
+
+create_parametrized_test!(integer_default_unsigned_saturating_ops);
+create_parametrized_test!(integer_default_unsigned_saturating_scalar_ops);
+
+fn integer_default_unsigned_saturating_ops<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+    let saturate = |value: u64| value.min(modulus - 1);
+
+    for _ in 0..nb_tests_smaller {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        // Make the first ciphertext have carries
+        let clear_2 = random_non_zero_value(&mut rng, modulus);
+        let ctxt_0 = sks.unchecked_scalar_add(&cks.encrypt(clear_0), clear_2);
+        let clear_0 = (clear_0 + clear_2) % modulus;
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = sks.unsigned_saturating_add_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, saturate(clear_0 + clear_1));
+
+        let ct_res = sks.unsigned_saturating_sub_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, clear_0.saturating_sub(clear_1));
+
+        let ct_res = sks.unsigned_saturating_mul_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, saturate(clear_0 * clear_1));
+    }
+}
+
+fn integer_default_unsigned_saturating_scalar_ops<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+    let saturate = |value: u64| value.min(modulus - 1);
+
+    for _ in 0..nb_tests_smaller {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let scalar = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+
+        let ct_res = sks.unsigned_saturating_scalar_add_parallelized(&ctxt_0, scalar);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, saturate(clear_0 + scalar));
+
+        let ct_res = sks.unsigned_saturating_scalar_sub_parallelized(&ctxt_0, scalar);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, clear_0.saturating_sub(scalar));
+
+        let ct_res = sks.unsigned_saturating_scalar_mul_parallelized(&ctxt_0, scalar);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, saturate(clear_0 * scalar));
+    }
+}
@@ -0,0 +1,54 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, unsigned_modulus,
+};
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{IntegerKeyKind, RadixClientKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+use super::NB_CTXT;
+
+create_parametrized_test!(integer_default_scalar_ge_thresholds);
+
+fn integer_default_scalar_ge_thresholds<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests_smaller {
+        let clear = rng.gen::<u64>() % modulus;
+        // Mix random thresholds with edge cases: zero, the value itself and out of bounds values
+        let mut thresholds = (0..5)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        thresholds.extend([0, clear, clear.saturating_add(1), modulus, u64::MAX]);
+
+        let mut ct = cks.encrypt(clear);
+        // Make the input have carries
+        sks.unchecked_scalar_add_assign(&mut ct, 1);
+        let clear = (clear + 1) % modulus;
+
+        let results = sks.scalar_ge_thresholds_parallelized(&ct, &thresholds);
+        assert_eq!(results.len(), thresholds.len());
+
+        for (threshold, result) in thresholds.iter().zip(results.iter()) {
+            let decrypted = cks.decrypt_bool(result);
+            assert_eq!(
+                decrypted,
+                clear >= *threshold,
+                "Invalid result for {clear} >= {threshold}"
+            );
+        }
+    }
+}
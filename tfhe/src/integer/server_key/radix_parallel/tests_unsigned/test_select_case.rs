@@ -0,0 +1,58 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, unsigned_modulus,
+};
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{IntegerKeyKind, RadixClientKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+use super::NB_CTXT;
+
+create_parametrized_test!(integer_default_select_case);
+
+// Number of blocks of the selector, small so that out of range selectors are frequent
+const NB_SELECTOR_BLOCKS: usize = 2;
+
+fn integer_default_select_case<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+    let selector_modulus = unsigned_modulus(
+        cks.parameters().message_modulus(),
+        NB_SELECTOR_BLOCKS as u32,
+    );
+
+    for _ in 0..nb_tests_smaller {
+        let num_arms = rng.gen_range(1..=selector_modulus.min(6)) as usize;
+        let arms = (0..num_arms)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        let selector = rng.gen::<u64>() % selector_modulus;
+
+        let mut ct_arms = arms.iter().map(|&v| cks.encrypt(v)).collect::<Vec<_>>();
+        // Make one arm have carries
+        sks.unchecked_add_assign(&mut ct_arms[0], &cks.encrypt(0u64));
+        let ct_selector = cks.as_ref().encrypt_radix(selector, NB_SELECTOR_BLOCKS);
+
+        let ct_res = sks.select_case_parallelized(&ct_selector, &ct_arms);
+        assert!(ct_res.block_carries_are_empty());
+
+        let expected = arms.get(selector as usize).copied().unwrap_or(0);
+        let dec: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec, expected,
+            "Invalid result for select_case({selector}, {arms:?})"
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::sort::bitonic_layers;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, unsigned_modulus,
+};
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{IntegerKeyKind, RadixClientKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+use super::NB_CTXT;
+
+create_parametrized_test!(integer_default_sort);
+create_parametrized_test!(integer_default_sort_by_key);
+
+#[test]
+fn test_bitonic_layers_sort_clear_values() {
+    let mut rng = rand::thread_rng();
+
+    for len in 0..=33 {
+        let layers = bitonic_layers(len);
+        for _ in 0..10 {
+            let mut values = (0..len).map(|_| rng.gen_range(0..8u8)).collect::<Vec<_>>();
+            let mut expected = values.clone();
+            expected.sort_unstable();
+
+            for layer in &layers {
+                for &(i, j) in layer {
+                    assert!(i < j && j < len);
+                    if values[i] > values[j] {
+                        values.swap(i, j);
+                    }
+                }
+            }
+            assert_eq!(
+                values, expected,
+                "Invalid sorting network for {len} elements"
+            );
+        }
+    }
+}
+
+fn integer_default_sort<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests_smaller {
+        let len = rng.gen_range(0..=6);
+        let mut clears = (0..len)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        let mut cts = clears.iter().map(|&v| cks.encrypt(v)).collect::<Vec<_>>();
+
+        // Make one ciphertext have carries
+        if let Some(ct) = cts.first_mut() {
+            sks.unchecked_scalar_add_assign(ct, 1);
+            clears[0] = (clears[0] + 1) % modulus;
+        }
+
+        sks.sort_parallelized(&mut cts);
+        clears.sort_unstable();
+
+        assert!(cts.iter().all(|ct| ct.block_carries_are_empty()));
+        let decrypted = cts.iter().map(|ct| cks.decrypt(ct)).collect::<Vec<u64>>();
+        assert_eq!(decrypted, clears);
+    }
+}
+
+fn integer_default_sort_by_key<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests_smaller {
+        let len = rng.gen_range(0..=6);
+        // Values are the initial indices, as keys are unique the permutation is known
+        let mut clear_keys = Vec::with_capacity(len);
+        while clear_keys.len() < len {
+            let key = rng.gen::<u64>() % modulus;
+            if !clear_keys.contains(&key) {
+                clear_keys.push(key);
+            }
+        }
+        let mut keys = clear_keys
+            .iter()
+            .map(|&v| cks.encrypt(v))
+            .collect::<Vec<_>>();
+        let mut values = (0..len as u64).map(|v| cks.encrypt(v)).collect::<Vec<_>>();
+
+        sks.sort_by_key_parallelized(&mut keys, &mut values);
+
+        let mut expected = (0..len as u64).collect::<Vec<_>>();
+        expected.sort_by_key(|&index| clear_keys[index as usize]);
+        clear_keys.sort_unstable();
+
+        let decrypted_keys = keys.iter().map(|ct| cks.decrypt(ct)).collect::<Vec<u64>>();
+        let decrypted_values = values
+            .iter()
+            .map(|ct| cks.decrypt(ct))
+            .collect::<Vec<u64>>();
+        assert_eq!(decrypted_keys, clear_keys);
+        assert_eq!(decrypted_values, expected);
+    }
+}
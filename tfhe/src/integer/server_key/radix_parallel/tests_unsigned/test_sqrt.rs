@@ -0,0 +1,69 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, unsigned_modulus,
+};
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{IntegerKeyKind, RadixClientKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+use super::NB_CTXT;
+
+create_parametrized_test!(integer_default_sqrt_rem);
+
+fn clear_isqrt(value: u64) -> u64 {
+    let mut root = (value as f64).sqrt() as u64;
+    // Fix the rounding errors of the floating point square root
+    while root * root > value {
+        root -= 1;
+    }
+    while (root + 1) * (root + 1) <= value {
+        root += 1;
+    }
+    root
+}
+
+fn integer_default_sqrt_rem<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    // Always test the edge cases
+    let clears = [0, 1, modulus - 1]
+        .into_iter()
+        .chain((0..nb_tests_smaller).map(|_| rng.gen::<u64>() % modulus))
+        .collect::<Vec<_>>();
+
+    for clear in clears {
+        let mut ct = cks.encrypt(clear);
+        // Make the input have carries
+        sks.unchecked_scalar_add_assign(&mut ct, 1);
+        let clear = (clear + 1) % modulus;
+
+        let (root, rem) = sks.sqrt_rem_parallelized(&ct);
+        assert!(root.block_carries_are_empty());
+        assert!(rem.block_carries_are_empty());
+        assert_eq!(root.blocks.len(), NB_CTXT);
+        assert_eq!(rem.blocks.len(), NB_CTXT);
+
+        let expected_root = clear_isqrt(clear);
+        let root: u64 = cks.decrypt(&root);
+        let rem: u64 = cks.decrypt(&rem);
+        assert_eq!(root, expected_root, "Invalid root for {clear}");
+        assert_eq!(
+            rem,
+            clear - expected_root * expected_root,
+            "Invalid remainder for {clear}"
+        );
+    }
+}
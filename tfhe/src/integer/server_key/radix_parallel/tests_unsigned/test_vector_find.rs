@@ -20,6 +20,7 @@ use crate::shortint::parameters::*;
 create_parametrized_test!(integer_unchecked_match_value);
 create_parametrized_test!(integer_unchecked_match_value_or);
 create_parametrized_test!(integer_unchecked_contains);
+create_parametrized_test!(integer_unchecked_count_distinct);
 create_parametrized_test!(integer_unchecked_contains_clear);
 create_parametrized_test!(integer_unchecked_is_in_clears);
 create_parametrized_test!(integer_unchecked_index_in_clears);
@@ -32,6 +33,7 @@ create_parametrized_test!(integer_unchecked_first_index_of_clear);
 create_parametrized_test!(integer_default_match_value);
 create_parametrized_test!(integer_default_match_value_or);
 create_parametrized_test!(integer_default_contains);
+create_parametrized_test!(integer_default_count_distinct);
 create_parametrized_test!(integer_default_contains_clear);
 create_parametrized_test!(integer_default_is_in_clears);
 create_parametrized_test!(integer_default_index_in_clears);
@@ -65,6 +67,14 @@ where
     unchecked_contains_test_case(param, executor);
 }
 
+fn integer_unchecked_count_distinct<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::unchecked_count_distinct_parallelized);
+    unchecked_count_distinct_test_case(param, executor);
+}
+
 fn integer_unchecked_contains_clear<P>(param: P)
 where
     P: Into<PBSParameters>,
@@ -157,6 +167,14 @@ where
     default_contains_test_case(param, executor);
 }
 
+fn integer_default_count_distinct<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::count_distinct_parallelized);
+    default_count_distinct_test_case(param, executor);
+}
+
 fn integer_default_contains_clear<P>(param: P)
 where
     P: Into<PBSParameters>,
@@ -1935,3 +1953,104 @@ where
         assert_eq!(is_in, expected_is_in);
     }
 }
+
+pub(crate) fn unchecked_count_distinct_test_case<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a [RadixCiphertext], RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    // empty collection
+    {
+        let result = executor.execute(&[]);
+        assert!(result.is_trivial());
+        let count: u64 = cks.decrypt(&result);
+        assert_eq!(count, 0);
+    }
+
+    for _ in 0..nb_tests {
+        let num_values = rng.gen_range(1..MAX_VEC_LEN);
+        // Draw the values in a small range so that there are duplicates
+        let value_range = modulus.min(num_values as u64);
+        let clears = (0..num_values)
+            .map(|_| rng.gen_range(0..value_range))
+            .collect::<Vec<_>>();
+
+        let cts = clears
+            .iter()
+            .copied()
+            .map(|value| cks.encrypt(value))
+            .collect::<Vec<_>>();
+
+        let expected_result = clears.iter().collect::<HashSet<_>>().len() as u64;
+        let result = executor.execute(&cts);
+        panic_if_any_block_is_not_clean_or_trivial(&result, &cks);
+
+        let count: u64 = cks.decrypt(&result);
+        assert_eq!(count, expected_result);
+    }
+}
+
+pub(crate) fn default_count_distinct_test_case<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a [RadixCiphertext], RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    sks.set_deterministic_pbs_execution(true);
+
+    let sks = Arc::new(sks);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        let num_values = rng.gen_range(1..MAX_VEC_LEN);
+        // Draw the values in a small range so that there are duplicates
+        let value_range = modulus.min(num_values as u64);
+        let clears = (0..num_values)
+            .map(|_| rng.gen_range(0..value_range))
+            .collect::<Vec<_>>();
+
+        // Add a value to the encryptions so that the ciphertexts have carries
+        let clear_0 = random_non_zero_value(&mut rng, modulus);
+        let cts = clears
+            .iter()
+            .copied()
+            .map(|value| {
+                let mut ct = cks.encrypt((value + modulus - clear_0) % modulus);
+                sks.unchecked_scalar_add_assign(&mut ct, clear_0);
+                ct
+            })
+            .collect::<Vec<_>>();
+
+        let expected_result = clears.iter().collect::<HashSet<_>>().len() as u64;
+        let result = executor.execute(&cts);
+        panic_if_any_block_is_not_clean_or_trivial(&result, &cks);
+
+        let count: u64 = cks.decrypt(&result);
+        assert_eq!(count, expected_result);
+
+        let result2 = executor.execute(&cts);
+        assert_eq!(result, result2, "Failed determinism check");
+    }
+}
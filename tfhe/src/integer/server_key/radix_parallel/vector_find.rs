@@ -52,6 +52,42 @@ impl<Clear> MatchValues<Clear> {
     }
 }
 
+impl<Clear> TryFrom<Vec<(Clear, Clear)>> for MatchValues<Clear>
+where
+    Clear: Eq + Hash,
+{
+    type Error = crate::Error;
+
+    fn try_from(matches: Vec<(Clear, Clear)>) -> Result<Self, Self::Error> {
+        Self::new(matches)
+    }
+}
+
+/// Builds a `MatchValues` from a table of `(input, output)` pairs
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::MatchValues;
+///
+/// let table = [(0u64, 10u64), (1, 20), (4, 10)];
+/// let matches = MatchValues::try_from(table.as_slice()).unwrap();
+///
+/// // Inputs must be unique
+/// let table = [(0u64, 10u64), (0, 20)];
+/// assert!(MatchValues::try_from(table.as_slice()).is_err());
+/// ```
+impl<Clear> TryFrom<&[(Clear, Clear)]> for MatchValues<Clear>
+where
+    Clear: Copy + Eq + Hash,
+{
+    type Error = crate::Error;
+
+    fn try_from(matches: &[(Clear, Clear)]) -> Result<Self, Self::Error> {
+        Self::new(matches.to_vec())
+    }
+}
+
 impl ServerKey {
     /// `match` an input value to an output value
     ///
@@ -328,6 +364,87 @@ impl ServerKey {
         self.unchecked_contains_parallelized(cts, value)
     }
 
+    /// Returns the number of distinct values in the encrypted slice
+    ///
+    /// A value is counted if it is not equal to any of the values before it in the slice, so all
+    /// the `n * (n - 1) / 2` pairs of values are compared: the cost is quadratic in the length of
+    /// the slice. The comparisons are done in parallel.
+    ///
+    /// # Notes
+    ///
+    /// - ciphertexts in the slice must not have any carries
+    /// - The returned result has enough blocks to encrypt the length of the slice
+    pub fn unchecked_count_distinct_parallelized<T>(&self, cts: &[T]) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let num_blocks_result =
+            ((cts.len() | 1).ilog2() + 1).div_ceil(self.message_modulus().0.ilog2()) as usize;
+
+        if cts.len() <= 1 {
+            return self.create_trivial_radix(cts.len() as u64, num_blocks_result);
+        }
+
+        let is_first_occurrence = (1..cts.len())
+            .into_par_iter()
+            .map(|i| {
+                let equalities = cts[..i]
+                    .par_iter()
+                    .map(|previous| self.unchecked_eq_parallelized(&cts[i], previous).0)
+                    .collect::<Vec<_>>();
+                let is_duplicate = BooleanBlock::new_unchecked(
+                    self.is_at_least_one_comparisons_block_true(equalities),
+                );
+                self.boolean_bitnot(&is_duplicate)
+                    .into_radix::<RadixCiphertext>(num_blocks_result, self)
+            })
+            .collect::<Vec<_>>();
+
+        // The first value is always counted
+        let mut terms = is_first_occurrence;
+        terms.push(self.create_trivial_radix(1u64, num_blocks_result));
+
+        self.unchecked_sum_ciphertexts_vec_parallelized(terms)
+            .expect("there is at least one term")
+    }
+
+    /// Returns the number of distinct values in the encrypted slice
+    ///
+    /// See [Self::unchecked_count_distinct_parallelized]
+    pub fn smart_count_distinct_parallelized<T>(&self, cts: &mut [T]) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        cts.par_iter_mut()
+            .filter(|ct| !ct.block_carries_are_empty())
+            .for_each(|ct| self.full_propagate_parallelized(ct));
+
+        self.unchecked_count_distinct_parallelized(cts)
+    }
+
+    /// Returns the number of distinct values in the encrypted slice
+    ///
+    /// See [Self::unchecked_count_distinct_parallelized]
+    pub fn count_distinct_parallelized<T>(&self, cts: &[T]) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_cts;
+
+        let cts = if cts.iter().any(|ct| !ct.block_carries_are_empty()) {
+            tmp_cts = cts.to_vec();
+            tmp_cts
+                .par_iter_mut()
+                .filter(|ct| !ct.block_carries_are_empty())
+                .for_each(|ct| self.full_propagate_parallelized(ct));
+            &tmp_cts
+        } else {
+            cts
+        };
+
+        self.unchecked_count_distinct_parallelized(cts)
+    }
+
     /// Returns an encrypted `true` if the clear `value` is found in the encrypted slice
     pub fn unchecked_contains_clear_parallelized<T, Clear>(
         &self,
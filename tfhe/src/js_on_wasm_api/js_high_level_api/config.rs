@@ -1,9 +1,39 @@
 use crate::high_level_api as hlapi;
+use crate::js_on_wasm_api::js_high_level_api::{catch_panic_result, into_js_error};
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
 pub struct TfheConfig(pub(crate) hlapi::Config);
 
+#[wasm_bindgen]
+impl TfheConfig {
+    #[wasm_bindgen]
+    pub fn safe_serialize(&self, serialized_size_limit: u64) -> Result<Vec<u8>, JsError> {
+        let mut buffer = vec![];
+        catch_panic_result(|| {
+            crate::safe_serialization::SerializationConfig::new(serialized_size_limit)
+                .serialize_into(&self.0, &mut buffer)
+                .map_err(into_js_error)
+        })?;
+
+        Ok(buffer)
+    }
+
+    #[wasm_bindgen]
+    pub fn safe_deserialize(
+        buffer: &[u8],
+        serialized_size_limit: u64,
+    ) -> Result<TfheConfig, JsError> {
+        catch_panic_result(|| {
+            crate::safe_serialization::DeserializationConfig::new(serialized_size_limit)
+                .disable_conformance()
+                .deserialize_from(buffer)
+                .map(Self)
+                .map_err(into_js_error)
+        })
+    }
+}
+
 #[wasm_bindgen]
 pub struct TfheConfigBuilder(pub(crate) hlapi::ConfigBuilder);
 
@@ -2,6 +2,7 @@
 use crate::high_level_api::prelude::*;
 use crate::integer::bigint::{StaticUnsignedBigInt, U1024, U2048, U512};
 use crate::integer::{I256, U256};
+use crate::js_on_wasm_api::js_high_level_api::config::TfheConfig;
 use crate::js_on_wasm_api::js_high_level_api::keys::TfheCompactPublicKey;
 #[cfg(feature = "zk-pok")]
 use crate::js_on_wasm_api::js_high_level_api::zk::{CompactPkePublicParams, ZkComputeLoad};
@@ -105,6 +106,68 @@ impl TryFrom<JsValue> for I256 {
 }
 
 // We use this macro to define wasm wrapper for
+// Conformance parameters of a ciphertext type, which can be created from a config,
+// so that ciphertexts can be checked without having the server key
+macro_rules! create_conformance_params_wrapper_type (
+    (
+        type_name: $type_name:ident,
+        compressed_type_name: $compressed_type_name:ident $(,)?
+    ) => {
+        ::paste::paste! {
+            #[wasm_bindgen]
+            pub struct [<$type_name ConformanceParams>](
+                pub(crate) crate::high_level_api::[<$type_name ConformanceParams>]
+            );
+
+            #[wasm_bindgen]
+            impl [<$type_name ConformanceParams>] {
+                #[wasm_bindgen]
+                pub fn from_config(config: &TfheConfig) -> Result<[<$type_name ConformanceParams>], JsError> {
+                    catch_panic(|| {
+                        Self(crate::high_level_api::[<$type_name ConformanceParams>]::from(
+                            config.0.inner.block_parameters,
+                        ))
+                    })
+                }
+            }
+
+            #[wasm_bindgen]
+            impl $type_name {
+                #[wasm_bindgen]
+                pub fn safe_deserialize_conformant(
+                    buffer: &[u8],
+                    serialized_size_limit: u64,
+                    conformance_params: &[<$type_name ConformanceParams>],
+                ) -> Result<$type_name, JsError> {
+                    catch_panic_result(|| {
+                        crate::safe_serialization::DeserializationConfig::new(serialized_size_limit)
+                            .deserialize_from(buffer, &conformance_params.0)
+                            .map($type_name)
+                            .map_err(into_js_error)
+                    })
+                }
+            }
+
+            #[wasm_bindgen]
+            impl $compressed_type_name {
+                #[wasm_bindgen]
+                pub fn safe_deserialize_conformant(
+                    buffer: &[u8],
+                    serialized_size_limit: u64,
+                    conformance_params: &[<$type_name ConformanceParams>],
+                ) -> Result<$compressed_type_name, JsError> {
+                    catch_panic_result(|| {
+                        crate::safe_serialization::DeserializationConfig::new(serialized_size_limit)
+                            .deserialize_from(buffer, &conformance_params.0)
+                            .map($compressed_type_name)
+                            .map_err(into_js_error)
+                    })
+                }
+            }
+        }
+    }
+);
+
 // FheUint types which maps to a type that is not native
 // to wasm-bindgen such as u128 (rust native) and our U256
 // and requires conversions using TryFrom
@@ -276,6 +339,11 @@ macro_rules! create_wrapper_type_non_native_type (
                 })
             }
         }
+
+        create_conformance_params_wrapper_type!(
+            type_name: $type_name,
+            compressed_type_name: $compressed_type_name,
+        );
     };
 
     (
@@ -517,6 +585,11 @@ macro_rules! create_wrapper_type_that_has_native_type (
                 })
             }
         }
+
+        create_conformance_params_wrapper_type!(
+            type_name: $type_name,
+            compressed_type_name: $compressed_type_name,
+        );
     };
     (
         $(
@@ -1181,6 +1254,17 @@ pub enum FheTypes {
     Int128,
     Int160,
     Int256,
+    Int512,
+    Int1024,
+    Int2048,
+    Uint24,
+    Uint40,
+    Uint48,
+    Uint72,
+    Int24,
+    Int40,
+    Int48,
+    Int72,
 }
 
 impl From<crate::FheTypes> for FheTypes {
@@ -1216,6 +1300,17 @@ impl From<crate::FheTypes> for FheTypes {
             crate::FheTypes::Int128 => Self::Int128,
             crate::FheTypes::Int160 => Self::Int160,
             crate::FheTypes::Int256 => Self::Int256,
+            crate::FheTypes::Int512 => Self::Int512,
+            crate::FheTypes::Int1024 => Self::Int1024,
+            crate::FheTypes::Int2048 => Self::Int2048,
+            crate::FheTypes::Uint24 => Self::Uint24,
+            crate::FheTypes::Uint40 => Self::Uint40,
+            crate::FheTypes::Uint48 => Self::Uint48,
+            crate::FheTypes::Uint72 => Self::Uint72,
+            crate::FheTypes::Int24 => Self::Int24,
+            crate::FheTypes::Int40 => Self::Int40,
+            crate::FheTypes::Int48 => Self::Int48,
+            crate::FheTypes::Int72 => Self::Int72,
         }
     }
 }
@@ -383,6 +383,11 @@ expose_predefined_parameters! {
     PARAM_MESSAGE_4_CARRY_4_COMPACT_PK_PBS_KS,
     // TUniform
     PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+    // 2^-128 failure probability
+    PARAM_MESSAGE_1_CARRY_1_KS_PBS_GAUSSIAN_2M128,
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS_GAUSSIAN_2M128,
     // Aliases to remove eventually
     PARAM_MESSAGE_1_CARRY_0,
     PARAM_MESSAGE_1_CARRY_1,
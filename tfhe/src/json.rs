@@ -0,0 +1,122 @@
+//! Human-readable JSON export of parameters and metadata.
+//!
+//! This is meant to audit what a key or a ciphertext was created with, for example to check the
+//! moduli and failure probability of the parameters of a deployed key, without writing a custom
+//! tool. The output is not meant to be loaded back, the [`safe_serialization`] module must be
+//! used to store objects.
+//!
+//! [`safe_serialization`]: crate::safe_serialization
+
+use crate::conformance::ListSizeConstraint;
+use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::BootstrapKeyConformanceParams;
+use crate::core_crypto::prelude::{
+    GgswCiphertextListConformanceParameters, GlweAutomorphismKeyConformanceParams,
+    GlweCiphertextConformanceParameters, KeyswitchKeyConformanceParams,
+    LweCiphertextListParameters, LweCiphertextParameters, LweCompactPublicKeyEncryptionParameters,
+    MsDecompressionType, MultiBitBootstrapKeyConformanceParams, PackingKeyswitchConformanceParams,
+};
+use crate::safe_serialization::SerializedObjectInfo;
+
+/// Export of an object as indented JSON, for debugging and auditing purposes
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::json::ToJsonPretty;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+///
+/// let json = PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_json_pretty().unwrap();
+/// assert!(json.contains("\"log2_p_fail\""));
+/// ```
+pub trait ToJsonPretty {
+    /// Returns the object as an indented JSON string
+    fn to_json_pretty(&self) -> Result<String, serde_json::Error>;
+}
+
+/// Implements [`ToJsonPretty`] for types implementing `Serialize`
+macro_rules! impl_to_json_pretty_with_serialize {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl $crate::json::ToJsonPretty for $ty {
+                fn to_json_pretty(&self) -> Result<String, serde_json::Error> {
+                    serde_json::to_string_pretty(self)
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use impl_to_json_pretty_with_serialize;
+
+impl_to_json_pretty_with_serialize!(
+    ListSizeConstraint,
+    SerializedObjectInfo,
+    BootstrapKeyConformanceParams,
+    GgswCiphertextListConformanceParameters,
+    GlweAutomorphismKeyConformanceParams,
+    GlweCiphertextConformanceParameters<u64>,
+    KeyswitchKeyConformanceParams<u64>,
+    LweCiphertextListParameters<u64>,
+    LweCiphertextParameters<u64>,
+    LweCompactPublicKeyEncryptionParameters<u64>,
+    MsDecompressionType,
+    MultiBitBootstrapKeyConformanceParams,
+    PackingKeyswitchConformanceParams,
+);
+
+#[cfg(feature = "boolean")]
+impl_to_json_pretty_with_serialize!(crate::boolean::parameters::BooleanParameters);
+
+#[cfg(feature = "shortint")]
+mod shortint {
+    use crate::shortint::key_switching_key::KeySwitchingKeyConformanceParams;
+    use crate::shortint::list_compression::CompressionConformanceParameters;
+    use crate::shortint::parameters::{
+        CiphertextConformanceParams, CiphertextListConformanceParams, ClassicPBSParameters,
+        CompactPublicKeyEncryptionParameters, CompressedCiphertextConformanceParams,
+        CompressionParameters, MultiBitPBSParameters, PBSParameters,
+        ShortintKeySwitchingParameters, ShortintParameterSet, WopbsParameters,
+    };
+    use crate::shortint::server_key::PBSConformanceParameters;
+
+    impl_to_json_pretty_with_serialize!(
+        ClassicPBSParameters,
+        MultiBitPBSParameters,
+        PBSParameters,
+        WopbsParameters,
+        ShortintParameterSet,
+        CompressionParameters,
+        CompactPublicKeyEncryptionParameters,
+        ShortintKeySwitchingParameters,
+        CiphertextConformanceParams,
+        CompressedCiphertextConformanceParams,
+        CiphertextListConformanceParams,
+        PBSConformanceParameters,
+        CompressionConformanceParameters,
+        KeySwitchingKeyConformanceParams,
+    );
+
+    #[cfg(feature = "zk-pok")]
+    impl_to_json_pretty_with_serialize!(
+        crate::shortint::ciphertext::ProvenCompactCiphertextListConformanceParams
+    );
+}
+
+#[cfg(feature = "integer")]
+mod integer {
+    use crate::integer::parameters::{
+        CompactCiphertextListConformanceParams, RadixCiphertextConformanceParams,
+    };
+
+    impl_to_json_pretty_with_serialize!(
+        RadixCiphertextConformanceParams,
+        CompactCiphertextListConformanceParams,
+        crate::Config,
+        crate::FheBoolConformanceParams,
+    );
+
+    #[cfg(feature = "zk-pok")]
+    impl_to_json_pretty_with_serialize!(
+        crate::integer::ciphertext::IntegerProvenCompactCiphertextListConformanceParams
+    );
+}
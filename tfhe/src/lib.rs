@@ -134,6 +134,9 @@ pub mod safe_serialization;
 
 pub mod conformance;
 
+#[cfg(feature = "json")]
+pub mod json;
+
 pub mod named;
 
 pub mod error;
@@ -1,3 +1,12 @@
 pub trait Named {
     const NAME: &'static str;
+
+    /// Oldest version of *TFHE-rs* able to deserialize the current version of this type, in the
+    /// "major.minor" format.
+    ///
+    /// Versioned types are backward compatible, but a version of *TFHE-rs* cannot read a type
+    /// version added after it. Types whose latest version is not readable by all the supported
+    /// versions should set this to the version of *TFHE-rs* that introduced it, so that the safe
+    /// serialization records it and older readers report it instead of a decoding error.
+    const MIN_READER_VERSION: Option<&'static str> = None;
 }
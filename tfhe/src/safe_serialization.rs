@@ -1,19 +1,60 @@
 //! Serialization utilities with some safety checks
 
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::fmt::Display;
+use std::io::Read;
+use std::ops::ControlFlow;
+use std::sync::Arc;
 
 use crate::conformance::ParameterSetConformant;
+use crate::core_crypto::commons::parameters::LweDimension;
 use crate::named::Named;
 use bincode::Options;
+#[cfg(feature = "checksum")]
+use checksum::ChecksumHasher;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use strict::{CollectionLimits, StrictDeserializer};
 use tfhe_versionable::{Unversionize, Versionize};
 
+#[cfg(feature = "zstd")]
+pub use self::zstd::CompressionLevel;
+
+#[cfg(feature = "aead")]
+mod aead;
+#[cfg(feature = "async")]
+mod async_io;
+#[cfg(feature = "cbor")]
+mod cbor;
+#[cfg(feature = "checksum")]
+mod checksum;
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "mmap")]
+mod mmap;
+#[cfg(feature = "postcard")]
+mod postcard;
+mod strict;
+#[cfg(feature = "zstd")]
+mod zstd;
+
 /// This is the global version of the serialization scheme that is used. This should be updated when
 /// the SerializationHeader is updated.
 const SERIALIZATION_VERSION: &str = "0.5";
 
+/// Version of the serialization scheme used when the header is followed by the minimum version
+/// of *TFHE-rs* able to read the object, and then by a list of [`SerializationHeaderExtension`].
+/// Headers of objects that can be read by all the versions are still written with
+/// [`SERIALIZATION_VERSION`].
+///
+/// The minimum version is read before anything else that a reader may not understand, so that
+/// an object written by a newer version of *TFHE-rs* is rejected with a clear error.
+///
+/// This header format was introduced along with the extensions, it has the same version as their
+/// [minimum reader version](SerializationHeaderExtension::min_reader_version).
+const MIN_READER_SERIALIZATION_VERSION: &str = "0.8";
+
 /// This is the version of the versioning scheme used to add backward compatibibility on tfhe-rs
 /// types. Similar to SERIALIZATION_VERSION, this number should be increased when the versioning
 /// scheme is upgraded.
@@ -27,6 +68,46 @@ const CRATE_VERSION: &str = concat!(
     env!("CARGO_PKG_VERSION_MINOR")
 );
 
+/// Parses a version of *TFHE-rs* in the "major.minor" format
+fn parse_crate_version(version: &str) -> Option<(u64, u64)> {
+    let (major, minor) = version.split_once('.')?;
+    Some((major.parse().ok()?, minor.parse().ok()?))
+}
+
+/// Returns the most recent of two versions of *TFHE-rs*
+fn most_recent_crate_version(lhs: Cow<'static, str>, rhs: Cow<'static, str>) -> Cow<'static, str> {
+    if parse_crate_version(&rhs) > parse_crate_version(&lhs) {
+        rhs
+    } else {
+        lhs
+    }
+}
+
+/// Exceeding the limit while reading the header means that the header is too large
+fn header_size_limit_error(err: SafeSerializationError) -> SafeSerializationError {
+    match err {
+        SafeSerializationError::SizeLimitExceeded { size_limit } => {
+            SafeSerializationError::HeaderTooLarge { size_limit }
+        }
+        err => err,
+    }
+}
+
+/// Checks that the current version of *TFHE-rs* is at least `min_reader_version`
+fn check_min_reader_version(min_reader_version: &str) -> Result<(), SafeSerializationError> {
+    let is_supported = parse_crate_version(min_reader_version)
+        .is_some_and(|required| Some(required) <= parse_crate_version(CRATE_VERSION));
+
+    if is_supported {
+        Ok(())
+    } else {
+        Err(SafeSerializationError::ReaderVersionTooOld {
+            required: min_reader_version.to_string(),
+            current: CRATE_VERSION.to_string(),
+        })
+    }
+}
+
 /// Tells if this serialized object is versioned or not
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
 // This type should not be versioned because it is part of a wrapper of versioned messages.
@@ -67,72 +148,210 @@ impl SerializationVersioningMode {
     }
 }
 
-/// `HEADER_LENGTH_LIMIT` is the maximum `SerializationHeader` size which
-/// `DeserializationConfig::deserialize_from` is going to try to read (it returns an error if
-/// it's too big).
-/// It helps prevent an attacker passing a very long header to exhaust memory.
-const HEADER_LENGTH_LIMIT: u64 = 1000;
+/// Compression algorithm applied to the serialized object
+#[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Eq, Debug)]
+// This type should not be versioned because it is part of a wrapper of versioned messages.
+#[cfg_attr(tfhe_lints, allow(tfhe_lints::serialize_without_versionize))]
+enum SerializationCompression {
+    /// The object is compressed with zstd
+    Zstd,
+}
 
-/// Header with global metadata about the serialized object. This help checking that we are not
-/// deserializing data that we can't handle.
-#[derive(Serialize, Deserialize)]
+/// Authenticated encryption applied to the serialized object, along with its nonce
+#[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Eq, Debug)]
 // This type should not be versioned because it is part of a wrapper of versioned messages.
 #[cfg_attr(tfhe_lints, allow(tfhe_lints::serialize_without_versionize))]
-struct SerializationHeader {
-    header_version: Cow<'static, str>,
-    versioning_mode: SerializationVersioningMode,
-    name: Cow<'static, str>,
+enum SerializationEncryption {
+    /// The object is encrypted with XChaCha20-Poly1305
+    XChaCha20Poly1305 { nonce: [u8; 24] },
 }
 
-impl SerializationHeader {
-    /// Creates a new header for a versioned message
-    fn new_versioned<T: Named>() -> Self {
-        Self {
-            header_version: Cow::Borrowed(SERIALIZATION_VERSION),
-            versioning_mode: SerializationVersioningMode::versioned(),
-            name: Cow::Borrowed(T::NAME),
+/// Optional information stored after the [`SerializationHeader`]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+// This type should not be versioned because it is part of a wrapper of versioned messages.
+#[cfg_attr(tfhe_lints, allow(tfhe_lints::serialize_without_versionize))]
+enum SerializationHeaderExtension {
+    /// The serialized object is compressed
+    Compression(SerializationCompression),
+    /// The serialized object is streamed in frames
+    Frames(FrameLayout),
+    /// Checksum of the serialized object
+    Checksum(SerializationChecksum),
+    /// User defined metadata, see [`SerializationConfig::with_metadata`]
+    Metadata(BTreeMap<String, String>),
+    /// The serialized object is encrypted
+    Encryption(SerializationEncryption),
+    /// The header is followed by a collection of objects of the same type, see
+    /// [`SerializationConfig::serialize_slice_into`]
+    Collection,
+    /// Fingerprint of the parameters the object was created with, see
+    /// [`SerializationConfig::with_parameter_fingerprint`]
+    ParameterFingerprint(ParameterFingerprint),
+    /// Length of the payload following the header, which is itself followed by the signature of
+    /// the header and the payload, see [`SerializationConfig::with_signer`]
+    SignedPayloadLength(u64),
+}
+
+impl SerializationHeaderExtension {
+    /// Oldest version of *TFHE-rs* that knows this extension.
+    ///
+    /// Extensions added in later versions must return the version that introduced them, so
+    /// that older readers reject the object instead of failing to decode the extension.
+    fn min_reader_version(&self) -> &'static str {
+        match self {
+            Self::Compression(_)
+            | Self::Frames(_)
+            | Self::Checksum(_)
+            | Self::Metadata(_)
+            | Self::Encryption(_)
+            | Self::Collection
+            | Self::ParameterFingerprint(_)
+            | Self::SignedPayloadLength(_) => "0.8",
         }
     }
+}
+
+/// Fingerprint of the cryptographic parameters or of the key an object is related to.
+///
+/// When attached to a serialized object with [`SerializationConfig::with_parameter_fingerprint`],
+/// it is checked on deserialization by a config created with
+/// [`DeserializationConfig::with_expected_parameter_fingerprint`]. This detects objects created
+/// with other parameters, for example a ciphertext sent to a server whose key was generated for
+/// another parameter set, before they produce meaningless results.
+#[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Eq, Hash, Debug)]
+// This type should not be versioned because it is part of a wrapper of versioned messages.
+#[cfg_attr(tfhe_lints, allow(tfhe_lints::serialize_without_versionize))]
+pub struct ParameterFingerprint([u8; 32]);
+
+impl ParameterFingerprint {
+    /// Creates a fingerprint from raw bytes, for example a key identifier chosen by the
+    /// application
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
 
-    /// Creates a new header for an unversioned message
-    fn new_unversioned<T: Named>() -> Self {
-        Self {
-            header_version: Cow::Borrowed(SERIALIZATION_VERSION),
-            versioning_mode: SerializationVersioningMode::unversioned(),
-            name: Cow::Borrowed(T::NAME),
+    /// Returns the raw bytes of the fingerprint
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl Display for ParameterFingerprint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+/// Algorithm used to compute the integrity checksum of serialized objects, see
+/// [`SerializationConfig::with_checksum`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ChecksumAlgorithm {
+    /// Fast checksum, detecting accidental corruptions
+    Crc32,
+    /// Cryptographic hash, slower than [`ChecksumAlgorithm::Crc32`].
+    ///
+    /// Note that a checksum alone does not protect against deliberate modifications, as it can
+    /// be recomputed by anyone, see [`SerializationConfig::with_signer`] for this use case.
+    Sha256,
+}
+
+/// Checksum of a serialized object, stored in the header
+#[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Eq, Debug)]
+// This type should not be versioned because it is part of a wrapper of versioned messages.
+#[cfg_attr(tfhe_lints, allow(tfhe_lints::serialize_without_versionize))]
+enum SerializationChecksum {
+    Crc32(u32),
+    Sha256([u8; 32]),
+}
+
+impl SerializationChecksum {
+    fn algorithm(&self) -> ChecksumAlgorithm {
+        match self {
+            Self::Crc32(_) => ChecksumAlgorithm::Crc32,
+            Self::Sha256(_) => ChecksumAlgorithm::Sha256,
         }
     }
+}
 
-    /// Checks the validity of the header
-    fn validate<T: Named>(&self) -> Result<(), String> {
-        match &self.versioning_mode {
-            SerializationVersioningMode::Versioned { versioning_version } => {
-                // For the moment there is only one versioning scheme, so another value is
-                // a hard error. But maybe if we upgrade it we will be able to automatically convert
-                // it.
-                if versioning_version != VERSIONING_VERSION {
-                    return Err(format!(
-                    "On deserialization, expected versioning scheme version {VERSIONING_VERSION}, \
-got version {versioning_version}"
-                ));
-                }
-            }
-            SerializationVersioningMode::Unversioned { crate_version } => {
-                if crate_version != CRATE_VERSION {
-                    return Err(format!(
-                "This {} has been saved from TFHE-rs v{crate_version}, without versioning informations. \
-Please use the versioned serialization mode for backward compatibility.",
-                self.name
-            ));
-                }
-            }
+/// Without the `checksum` feature no checksum can be computed or verified, this type has no
+/// values so that objects with a checksum are rejected instead of being read without verifying it.
+#[cfg(not(feature = "checksum"))]
+enum ChecksumHasher {}
+
+#[cfg(not(feature = "checksum"))]
+impl ChecksumHasher {
+    fn new(algorithm: ChecksumAlgorithm) -> Result<Self, String> {
+        Err(format!(
+            "Computing or verifying a {algorithm:?} checksum requires the `checksum` feature"
+        ))
+    }
+
+    fn update(&mut self, _bytes: &[u8]) {
+        match *self {}
+    }
+
+    fn finalize(self) -> SerializationChecksum {
+        match self {}
+    }
+}
+
+/// Writer that forwards the bytes to the inner writer and updates a checksum with them
+struct ChecksumWriter<W> {
+    inner: W,
+    hasher: Option<ChecksumHasher>,
+}
+
+impl<W: std::io::Write> std::io::Write for ChecksumWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        if let Some(hasher) = &mut self.hasher {
+            hasher.update(&buf[..written]);
         }
+        Ok(written)
+    }
 
-        if self.name != T::NAME {
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Reader that updates a checksum with the bytes read from the inner reader
+struct ChecksumReader<R> {
+    inner: R,
+    hasher: ChecksumHasher,
+}
+
+impl<R: std::io::Read> std::io::Read for ChecksumReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.hasher.update(&buf[..read]);
+        Ok(read)
+    }
+}
+
+/// `MAX_FRAME_SIZE` is the maximum size of the frames used by the chunked streaming mode.
+/// It bounds the size of the buffer allocated on deserialization.
+const MAX_FRAME_SIZE: u64 = 1 << 26;
+
+/// Layout of an object streamed in frames, see [`SerializationConfig::with_chunked_streaming`]
+///
+/// Each frame is made of its length, as a little endian `u32`, followed by its content. The
+/// length of a frame is between 1 and `frame_size`, and the object ends with a frame of length 0.
+#[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Eq, Debug)]
+// This type should not be versioned because it is part of a wrapper of versioned messages.
+#[cfg_attr(tfhe_lints, allow(tfhe_lints::serialize_without_versionize))]
+struct FrameLayout {
+    /// Maximum size of the content of a frame
+    frame_size: u64,
+}
+
+impl FrameLayout {
+    /// Checks that the layout read from a header is consistent
+    fn validate(&self) -> Result<(), String> {
+        if self.frame_size == 0 || self.frame_size > MAX_FRAME_SIZE {
             return Err(format!(
-                "On deserialization, expected type {}, got type {}",
-                T::NAME,
-                self.name
+                "Invalid frame size {}, frames must be between 1 and {MAX_FRAME_SIZE} bytes",
+                self.frame_size
             ));
         }
 
@@ -140,356 +359,4546 @@ Please use the versioned serialization mode for backward compatibility.",
     }
 }
 
-/// A configuration used to Serialize *TFHE-rs* objects. This configuration decides
-/// if the object will be versioned and holds the max byte size of the written data.
-#[derive(Clone)]
-pub struct SerializationConfig {
-    versioned: SerializationVersioningMode,
-    serialized_size_limit: u64,
+/// Writer that splits the bytes in frames, see [`FrameLayout`]
+struct FrameWriter<W: std::io::Write> {
+    inner: W,
+    frame: Vec<u8>,
+    frame_size: usize,
 }
 
-impl SerializationConfig {
-    /// Creates a new serialization config. The default configuration will serialize the object
-    /// with versioning information for backward compatibility.
-    /// `serialized_size_limit` is the size limit (in number of byte) of the serialized object
-    /// (excluding the header).
-    pub fn new(serialized_size_limit: u64) -> Self {
+impl<W: std::io::Write> FrameWriter<W> {
+    fn new(inner: W, layout: FrameLayout) -> Self {
+        let frame_size = layout.frame_size as usize;
         Self {
-            versioned: SerializationVersioningMode::versioned(),
-            serialized_size_limit,
+            inner,
+            frame: Vec::with_capacity(frame_size),
+            frame_size,
         }
     }
 
-    /// Creates a new serialization config without any size check.
-    pub fn new_with_unlimited_size() -> Self {
-        Self {
-            versioned: SerializationVersioningMode::versioned(),
-            serialized_size_limit: 0,
+    fn write_frame(&mut self) -> std::io::Result<()> {
+        // The frame size is at most MAX_FRAME_SIZE, so it fits in a u32
+        self.inner
+            .write_all(&(self.frame.len() as u32).to_le_bytes())?;
+        self.inner.write_all(&self.frame)?;
+        self.frame.clear();
+        Ok(())
+    }
+
+    /// Writes the last frame and the end marker
+    fn finish(mut self) -> std::io::Result<W> {
+        if !self.frame.is_empty() {
+            self.write_frame()?;
         }
+        self.write_frame()?;
+        Ok(self.inner)
     }
+}
 
-    /// Disables the size limit for serialized objects
-    pub fn disable_size_limit(self) -> Self {
+impl<W: std::io::Write> std::io::Write for FrameWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = buf.len().min(self.frame_size - self.frame.len());
+        self.frame.extend_from_slice(&buf[..written]);
+        if self.frame.len() == self.frame_size {
+            self.write_frame()?;
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Reader that reads the content of the frames written by a [`FrameWriter`]
+///
+/// The length of each frame is checked when it is read, so that invalid data is rejected without
+/// reading further.
+struct FrameReader<R: std::io::Read> {
+    inner: R,
+    frame_size: u64,
+    /// Number of bytes left in the current frame
+    remaining: u64,
+    /// Whether the end marker was read
+    ended: bool,
+}
+
+impl<R: std::io::Read> FrameReader<R> {
+    fn new(inner: R, layout: FrameLayout) -> Self {
         Self {
-            serialized_size_limit: 0,
-            ..self
+            inner,
+            frame_size: layout.frame_size,
+            remaining: 0,
+            ended: false,
         }
     }
 
-    /// Disable the versioning of serialized objects
-    pub fn disable_versioning(self) -> Self {
+    fn read_frame_length(&mut self) -> std::io::Result<()> {
+        let mut length = [0u8; 4];
+        self.inner.read_exact(&mut length)?;
+        let length = u64::from(u32::from_le_bytes(length));
+
+        if length > self.frame_size {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!(
+                    "Invalid frame of {length} bytes, frames are at most {} bytes",
+                    self.frame_size
+                ),
+            ));
+        }
+
+        self.remaining = length;
+        self.ended = length == 0;
+        Ok(())
+    }
+
+    /// Checks that the object was read up to its end marker
+    fn finish(mut self) -> std::io::Result<()> {
+        if self.remaining == 0 && !self.ended {
+            self.read_frame_length()?;
+        }
+
+        if !self.ended {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "The streamed object is longer than its serialized content",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: std::io::Read> std::io::Read for FrameReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        if self.remaining == 0 && !self.ended {
+            self.read_frame_length()?;
+        }
+
+        if self.ended {
+            return Ok(0);
+        }
+
+        let to_read = buf.len().min(self.remaining as usize);
+        let read = self.inner.read(&mut buf[..to_read])?;
+        if read == 0 {
+            return Err(std::io::ErrorKind::UnexpectedEof.into());
+        }
+        self.remaining -= read as u64;
+        Ok(read)
+    }
+}
+
+/// Writer that forwards the bytes to the inner writer and counts them
+struct CountingWriter<W> {
+    inner: W,
+    count: u64,
+}
+
+impl<W: std::io::Write> std::io::Write for CountingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.count += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Callback receiving the progress of a serialization, see
+/// [`SerializationConfig::with_progress`]. [`DeserializationConfig::with_progress`] borrows a
+/// callback of the same signature.
+///
+/// It is called with the number of bytes written or read so far, and the total number of bytes
+/// if it is known. Returning [`ControlFlow::Break`] cancels the operation.
+pub type ProgressCallback = Arc<dyn Fn(u64, Option<u64>) -> ControlFlow<()> + Send + Sync>;
+
+/// Number of bytes between two calls of the progress callback
+const PROGRESS_REPORT_INTERVAL: u64 = 1 << 20;
+
+/// Progress callback of a config, with the total number of bytes if it is known
+#[derive(Copy, Clone)]
+struct Progress<'a> {
+    callback: &'a (dyn Fn(u64, Option<u64>) -> ControlFlow<()> + Send + Sync),
+    total: Option<u64>,
+}
+
+/// Counts the processed bytes and calls the progress callback every
+/// [`PROGRESS_REPORT_INTERVAL`] bytes
+struct ProgressReporter<'a> {
+    progress: Progress<'a>,
+    done: u64,
+    next_report: u64,
+}
+
+impl<'a> ProgressReporter<'a> {
+    fn new(progress: Progress<'a>) -> Self {
         Self {
-            versioned: SerializationVersioningMode::unversioned(),
-            ..self
+            progress,
+            done: 0,
+            next_report: PROGRESS_REPORT_INTERVAL,
         }
     }
 
-    /// Create a serialization header based on the current config
-    fn create_header<T: Named>(&self) -> SerializationHeader {
-        match self.versioned {
-            SerializationVersioningMode::Versioned { .. } => {
-                SerializationHeader::new_versioned::<T>()
-            }
-            SerializationVersioningMode::Unversioned { .. } => {
-                SerializationHeader::new_unversioned::<T>()
-            }
+    fn advance(&mut self, count: usize) -> std::io::Result<()> {
+        self.done += count as u64;
+        if self.done < self.next_report {
+            return Ok(());
         }
+
+        self.next_report = self.done + PROGRESS_REPORT_INTERVAL;
+        self.report()
     }
 
-    /// Returns the max length of the serialized header
-    fn header_length_limit(&self) -> u64 {
-        if self.serialized_size_limit == 0 {
-            0
-        } else {
-            HEADER_LENGTH_LIMIT
+    fn report(&self) -> std::io::Result<()> {
+        match (self.progress.callback)(self.done, self.progress.total) {
+            ControlFlow::Continue(()) => Ok(()),
+            ControlFlow::Break(()) => Err(std::io::Error::other(
+                "The operation was cancelled by the progress callback",
+            )),
         }
     }
+}
 
-    /// Serializes an object into a [writer](std::io::Write), based on the current config.
-    /// The written bytes can be deserialized using [`DeserializationConfig::deserialize_from`].
-    pub fn serialize_into<T: Serialize + Versionize + Named>(
+/// Writer that forwards the bytes to the inner writer and reports the progress
+struct ProgressWriter<'a, W> {
+    inner: W,
+    reporter: ProgressReporter<'a>,
+}
+
+impl<W: std::io::Write> std::io::Write for ProgressWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.reporter.advance(written)?;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Reader that forwards the bytes of the inner reader and reports the progress
+struct ProgressReader<'a, R> {
+    inner: R,
+    reporter: ProgressReporter<'a>,
+}
+
+impl<R: std::io::Read> std::io::Read for ProgressReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.reporter.advance(read)?;
+        Ok(read)
+    }
+}
+
+/// Wire format used to encode the header and the object.
+///
+/// The same format must be selected in the [`SerializationConfig`] and in the
+/// [`DeserializationConfig`]. Whatever the format, the raw byte sections (compressed objects and
+/// signatures) are encoded as a little endian `u64` length followed by the bytes.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum SerializationFormat {
+    /// Compact binary format used by default, see [bincode](https://crates.io/crates/bincode)
+    #[default]
+    Bincode,
+    /// Concise Binary Object Representation (RFC 8949), that can be read by many languages.
+    ///
+    /// The header and the object are each prefixed with their length as a little endian `u64`.
+    ///
+    /// The collection and nesting limits of a strict config
+    /// ([`DeserializationConfig::new_strict`]) cannot be checked while decoding CBOR, a config
+    /// having such limits fails to deserialize CBOR data instead of ignoring them.
+    #[cfg(feature = "cbor")]
+    Cbor,
+    /// Compact format targeting embedded devices, see
+    /// [postcard](https://crates.io/crates/postcard).
+    ///
+    /// The header and the object are each prefixed with their length as a little endian `u64`.
+    #[cfg(feature = "postcard")]
+    Postcard,
+}
+
+impl SerializationFormat {
+    /// Serializes a value into the writer, failing if it is bigger than `size_limit` bytes
+    /// (0 means no limit)
+    fn serialize_into<T: Serialize + ?Sized>(
         self,
-        object: &T,
+        value: &T,
+        writer: impl std::io::Write,
+        size_limit: u64,
+    ) -> bincode::Result<()> {
+        match self {
+            Self::Bincode => bincode::DefaultOptions::new()
+                .with_fixint_encoding()
+                .with_limit(size_limit)
+                .serialize_into(writer, value),
+            #[cfg(feature = "cbor")]
+            Self::Cbor => cbor::serialize_into(value, writer, size_limit),
+            #[cfg(feature = "postcard")]
+            Self::Postcard => postcard::serialize_into(value, writer, size_limit),
+        }
+    }
+
+    /// Deserializes a value from the reader, failing if it is bigger than `size_limit` bytes
+    /// (0 means no limit)
+    fn deserialize_from<T: DeserializeOwned>(
+        self,
+        reader: impl std::io::Read,
+        size_limit: u64,
+    ) -> Result<T, SafeSerializationError> {
+        match self {
+            Self::Bincode => bincode::DefaultOptions::new()
+                .with_fixint_encoding()
+                .with_limit(size_limit)
+                .deserialize_from(reader)
+                .map_err(|err| SafeSerializationError::from_bincode(err, size_limit)),
+            #[cfg(feature = "cbor")]
+            Self::Cbor => cbor::deserialize_from(reader, size_limit),
+            #[cfg(feature = "postcard")]
+            Self::Postcard => postcard::deserialize_from(reader, size_limit),
+        }
+    }
+
+    /// Deserializes a value like [`Self::deserialize_from`], while checking that its collections
+    /// respect `limits`
+    fn deserialize_with_limits_from<T: DeserializeOwned>(
+        self,
+        reader: impl std::io::Read,
+        size_limit: u64,
+        limits: CollectionLimits,
+    ) -> Result<T, SafeSerializationError> {
+        if limits.is_unlimited() {
+            return self.deserialize_from(reader, size_limit);
+        }
+
+        match self {
+            Self::Bincode => {
+                let options = bincode::DefaultOptions::new()
+                    .with_fixint_encoding()
+                    .with_limit(size_limit);
+                let mut deserializer = bincode::Deserializer::with_reader(reader, options);
+                T::deserialize(StrictDeserializer::new(&mut deserializer, limits))
+                    .map_err(|err| SafeSerializationError::from_bincode(err, size_limit))
+            }
+            #[cfg(feature = "cbor")]
+            Self::Cbor => Err(SafeSerializationError::Message(
+                "Collection and nesting limits are not supported with the CBOR format, use a \
+                config without them (e.g. DeserializationConfig::new_auto_limit) or another format"
+                    .to_string(),
+            )),
+            #[cfg(feature = "postcard")]
+            Self::Postcard => postcard::deserialize_with_limits_from(reader, size_limit, limits),
+        }
+    }
+
+    #[cfg(any(feature = "cbor", feature = "postcard"))]
+    fn write_length_prefixed(
+        bytes: &[u8],
         mut writer: impl std::io::Write,
+        size_limit: u64,
     ) -> bincode::Result<()> {
-        let options = bincode::DefaultOptions::new()
+        let len = bytes.len() as u64;
+        if size_limit != 0 && len > size_limit {
+            return Err(Box::new(bincode::ErrorKind::SizeLimit));
+        }
+
+        writer.write_all(&len.to_le_bytes())?;
+        writer.write_all(bytes)?;
+        Ok(())
+    }
+
+    #[cfg(any(feature = "cbor", feature = "postcard"))]
+    fn read_length_prefixed(
+        mut reader: impl std::io::Read,
+        size_limit: u64,
+    ) -> Result<Vec<u8>, SafeSerializationError> {
+        let mut len_bytes = [0u8; 8];
+        reader.read_exact(&mut len_bytes)?;
+        let len = u64::from_le_bytes(len_bytes);
+        if size_limit != 0 && len > size_limit {
+            return Err(SafeSerializationError::SizeLimitExceeded { size_limit });
+        }
+
+        // The length is not trusted, the buffer is not allocated upfront
+        let mut bytes = Vec::new();
+        reader.take(len).read_to_end(&mut bytes)?;
+        if bytes.len() as u64 != len {
+            return Err(SafeSerializationError::Io(
+                std::io::ErrorKind::UnexpectedEof.into(),
+            ));
+        }
+
+        Ok(bytes)
+    }
+}
+
+/// `HEADER_LENGTH_LIMIT` is the maximum `SerializationHeader` size which
+/// `DeserializationConfig::deserialize_from` is going to try to read (it returns an error if
+/// it's too big).
+/// It helps prevent an attacker passing a very long header to exhaust memory.
+const HEADER_LENGTH_LIMIT: u64 = 1000;
+
+/// Maximum nesting depth of the objects read by a config created with
+/// [`DeserializationConfig::new_strict`], much deeper than any *TFHE-rs* object
+pub const STRICT_NESTING_DEPTH_LIMIT: u32 = 128;
+
+/// `SIGNATURE_LENGTH_LIMIT` is the maximum size of a detached signature which
+/// `DeserializationConfig::deserialize_from` is going to try to read when a verifier is set.
+/// It is large enough for the usual signature schemes, including post-quantum ones.
+const SIGNATURE_LENGTH_LIMIT: u64 = 1 << 14;
+
+/// Version of the layout of the archives written by [`SerializedArchiveBuilder`]
+const ARCHIVE_VERSION: &str = "0.1";
+
+/// Max size of the index of an archive, which is read before any of its entries.
+/// It helps prevent an attacker passing a very long index to exhaust memory.
+const ARCHIVE_INDEX_LENGTH_LIMIT: u64 = 1 << 20;
+
+/// Size reserved for the metadata of an object (moduli, degree, tag, versioning information...)
+/// on top of its raw data, when computing a [`SerializedSizeBound`]
+pub(crate) const OBJECT_METADATA_SIZE_BOUND: u64 = 1 << 10;
+
+/// Recommended `serialized_size_limit` to deserialize a single ciphertext.
+///
+/// This is large enough for any ciphertext of up to 2048 bits with the default parameters. Use
+/// [`recommended_size_limit`] to get a tighter limit for a given parameter set.
+pub const CIPHERTEXT_SIZE_LIMIT: u64 = 1 << 25;
+
+/// Recommended `serialized_size_limit` to deserialize a server key.
+///
+/// This is large enough for a server key generated with the default parameters, including its
+/// compression keys and the key switching key of a compact public key. Use
+/// [`recommended_size_limit`] to get a tighter limit for a given parameter set.
+pub const SERVER_KEY_SIZE_LIMIT: u64 = 1 << 30;
+
+/// Objects whose serialized size can be bounded from the parameter set used to check their
+/// conformance.
+///
+/// This allows to derive the `serialized_size_limit` of a config from a parameter set, see
+/// [`recommended_size_limit`].
+pub trait SerializedSizeBound: ParameterSetConformant {
+    /// Returns an upper bound of the number of bytes of an object conformant with
+    /// `parameter_set`, serialized without its header
+    fn serialized_size_bound(parameter_set: &Self::ParameterSet) -> u64;
+}
+
+/// Returns the recommended `serialized_size_limit` to serialize or deserialize objects of type
+/// `T` that are conformant with `parameter_set`.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::safe_serialization::{recommended_size_limit, CIPHERTEXT_SIZE_LIMIT};
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+/// use tfhe::shortint::Ciphertext;
+///
+/// let conformance_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param();
+/// let size_limit = recommended_size_limit::<Ciphertext>(&conformance_params);
+///
+/// assert!(size_limit < CIPHERTEXT_SIZE_LIMIT);
+/// ```
+pub fn recommended_size_limit<T: SerializedSizeBound>(parameter_set: &T::ParameterSet) -> u64 {
+    T::serialized_size_bound(parameter_set) + HEADER_LENGTH_LIMIT
+}
+
+/// Returns the recommended maximum number of elements of the collections of objects of type `T`
+/// that are conformant with `parameter_set`.
+///
+/// This is a single cap applied to every collection of the object, it is not derived for each
+/// collection from the parameter set. It is the number of 8 bytes integers (the smallest
+/// element of the large collections of *TFHE-rs* objects) that fit in the
+/// [`SerializedSizeBound`] of the whole object, so that no collection can make the
+/// deserialization allocate much more than the size of a conformant object. The exact length of
+/// each collection is checked afterwards by the conformance check. A smaller cap can be set with
+/// [`DeserializationConfig::with_max_collection_length`] when the largest collection of the
+/// object is known.
+pub fn recommended_max_collection_length<T: SerializedSizeBound>(
+    parameter_set: &T::ParameterSet,
+) -> u64 {
+    (T::serialized_size_bound(parameter_set) / 8).max(OBJECT_METADATA_SIZE_BOUND)
+}
+
+/// Returns the recommended `serialized_size_limit` to deserialize a compact ciphertext list
+/// holding `num_blocks` blocks in total, encrypted with a compact public key of dimension
+/// `encryption_lwe_dimension`.
+///
+/// For example a list of 4 `FheUint8` has 16 blocks with 2 bits of message per block.
+pub fn compact_ciphertext_list_size_limit(
+    encryption_lwe_dimension: LweDimension,
+    num_blocks: usize,
+) -> u64 {
+    let num_blocks = num_blocks as u64;
+    let lwe_dimension = encryption_lwe_dimension.0 as u64;
+
+    // Each group of at most `lwe_dimension` bodies shares a single mask
+    let mask_words = num_blocks.div_ceil(lwe_dimension) * lwe_dimension;
+    // The description of each value of the list takes at most 16 bytes
+    let info_size = 16 * num_blocks;
+
+    8 * (mask_words + num_blocks) + info_size + OBJECT_METADATA_SIZE_BOUND + HEADER_LENGTH_LIMIT
+}
+
+/// Error returned when a serialized object cannot be read.
+///
+/// The variants allow to branch on the cause of the failure, the [`Display`] implementation gives
+/// a human readable description of the error.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SafeSerializationError {
+    /// The header of the object is larger than the header size limit
+    HeaderTooLarge { size_limit: u64 },
+    /// The serialized object is not of the requested type
+    TypeMismatch { expected: String, found: String },
+    /// The object was serialized with an incompatible version of the versioning scheme or, for
+    /// unversioned objects, with another version of *TFHE-rs*. Unversioned objects can only be
+    /// read by the version that wrote them, the versioned mode should be used for backward
+    /// compatibility.
+    VersionMismatch { expected: String, found: String },
+    /// The object was serialized by a newer version of *TFHE-rs*, with a format or a type
+    /// version that the current version cannot read
+    ReaderVersionTooOld { required: String, current: String },
+    /// The serialized object is larger than the size limit of the config
+    SizeLimitExceeded { size_limit: u64 },
+    /// The deserialized object is not conformant with the provided parameter set
+    ConformanceFailure { type_name: String },
+    /// The parameter fingerprint of the serialized object is missing or is not the expected one
+    ParameterMismatch {
+        expected: ParameterFingerprint,
+        found: Option<ParameterFingerprint>,
+    },
+    /// The serialized data could not be read
+    Io(std::io::Error),
+    /// The serialized data could not be decoded
+    Bincode(bincode::Error),
+    /// Any other failure, such as an invalid signature or checksum
+    Message(String),
+}
+
+impl SafeSerializationError {
+    /// Converts an error returned by bincode, `size_limit` being the limit that was used to read
+    /// the data
+    fn from_bincode(err: bincode::Error, size_limit: u64) -> Self {
+        match *err {
+            bincode::ErrorKind::SizeLimit => Self::SizeLimitExceeded { size_limit },
+            bincode::ErrorKind::Io(err) => Self::Io(err),
+            kind => Self::Bincode(Box::new(kind)),
+        }
+    }
+}
+
+impl Display for SafeSerializationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::HeaderTooLarge { size_limit } => write!(
+                f,
+                "The serialized header exceeds the size limit of {size_limit} bytes"
+            ),
+            Self::TypeMismatch { expected, found } => write!(
+                f,
+                "On deserialization, expected type {expected}, got type {found}"
+            ),
+            Self::VersionMismatch { expected, found } => write!(
+                f,
+                "On deserialization, expected version {expected}, got version {found}"
+            ),
+            Self::ReaderVersionTooOld { required, current } => write!(
+                f,
+                "The serialized object requires TFHE-rs >= {required}, \
+but it is read by TFHE-rs {current}"
+            ),
+            Self::SizeLimitExceeded { size_limit } => write!(
+                f,
+                "The serialized object exceeds the size limit of {size_limit} bytes"
+            ),
+            Self::ConformanceFailure { type_name } => write!(
+                f,
+                "Deserialized object of type {type_name} not conformant with given parameter set"
+            ),
+            Self::ParameterMismatch {
+                expected,
+                found: Some(found),
+            } => write!(
+                f,
+                "On deserialization, expected parameter fingerprint {expected}, got {found}"
+            ),
+            Self::ParameterMismatch {
+                expected,
+                found: None,
+            } => write!(
+                f,
+                "On deserialization, expected parameter fingerprint {expected}, \
+but the object has no fingerprint"
+            ),
+            Self::Io(err) => write!(f, "Failed to read the serialized data: {err}"),
+            Self::Bincode(err) => write!(f, "Failed to decode the serialized data: {err}"),
+            Self::Message(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+impl std::error::Error for SafeSerializationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Bincode(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for SafeSerializationError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<SafeSerializationError> for crate::Error {
+    fn from(err: SafeSerializationError) -> Self {
+        Self::new(err.to_string())
+    }
+}
+
+/// A signer used to authenticate serialized objects.
+///
+/// When set with [`SerializationConfig::with_signer`], the signer is called on the serialized
+/// header and payload, and the returned detached signature is appended to the serialized data.
+///
+/// This trait does not depend on any signature scheme, it can be implemented for example with an
+/// Ed25519 key from an external crate, or a key held in an HSM.
+pub trait SerializationSigner: Send + Sync {
+    /// Returns the detached signature of `message`
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, String>;
+}
+
+/// A verifier used to check the authenticity of serialized objects.
+///
+/// When set with [`DeserializationConfig::with_verifier`], the detached signature appended by a
+/// [`SerializationSigner`] is read and checked against the serialized header and payload. The
+/// deserialization fails if the signature is missing or invalid.
+pub trait SerializationVerifier: Send + Sync {
+    /// Checks that `signature` is a valid signature of `message`
+    fn verify(&self, message: &[u8], signature: &[u8]) -> Result<(), String>;
+}
+
+/// Reader that keeps a copy of all the bytes read from the inner reader, so that they can be
+/// checked against a signature.
+struct RecordingReader<R> {
+    inner: R,
+    recorded: Vec<u8>,
+}
+
+impl<R: std::io::Read> std::io::Read for RecordingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.recorded.extend_from_slice(&buf[..read]);
+        Ok(read)
+    }
+}
+
+/// Header with global metadata about the serialized object. This help checking that we are not
+/// deserializing data that we can't handle.
+#[derive(Serialize, Deserialize)]
+// This type should not be versioned because it is part of a wrapper of versioned messages.
+#[cfg_attr(tfhe_lints, allow(tfhe_lints::serialize_without_versionize))]
+struct SerializationHeader {
+    header_version: Cow<'static, str>,
+    versioning_mode: SerializationVersioningMode,
+    name: Cow<'static, str>,
+    /// Oldest version of *TFHE-rs* able to read the object, serialized after the header like
+    /// the extensions.
+    #[serde(skip)]
+    min_reader_version: Option<Cow<'static, str>>,
+    /// Extensions are serialized separately after the header, so that headers without
+    /// extensions keep the same format as before.
+    #[serde(skip)]
+    extensions: Vec<SerializationHeaderExtension>,
+}
+
+impl SerializationHeader {
+    /// Creates a new header for a versioned message
+    fn new_versioned<T: Named>() -> Self {
+        // Objects that can be read by all the versions keep the original header format
+        let header_version = if T::MIN_READER_VERSION.is_some() {
+            MIN_READER_SERIALIZATION_VERSION
+        } else {
+            SERIALIZATION_VERSION
+        };
+
+        Self {
+            header_version: Cow::Borrowed(header_version),
+            versioning_mode: SerializationVersioningMode::versioned(),
+            name: Cow::Borrowed(T::NAME),
+            min_reader_version: T::MIN_READER_VERSION.map(Cow::Borrowed),
+            extensions: Vec::new(),
+        }
+    }
+
+    /// Creates a new header for an unversioned message
+    fn new_unversioned<T: Named>() -> Self {
+        Self {
+            header_version: Cow::Borrowed(SERIALIZATION_VERSION),
+            versioning_mode: SerializationVersioningMode::unversioned(),
+            name: Cow::Borrowed(T::NAME),
+            // Unversioned objects can only be read by the version that wrote them, which is
+            // checked with the crate version of the versioning mode
+            min_reader_version: None,
+            extensions: Vec::new(),
+        }
+    }
+
+    /// Adds an extension to the header
+    fn push_extension(&mut self, extension: SerializationHeaderExtension) {
+        let extension_version = Cow::Borrowed(extension.min_reader_version());
+        self.min_reader_version = Some(match self.min_reader_version.take() {
+            Some(version) => most_recent_crate_version(version, extension_version),
+            None => extension_version,
+        });
+        self.header_version = Cow::Borrowed(MIN_READER_SERIALIZATION_VERSION);
+        self.extensions.push(extension);
+    }
+
+    /// Returns the compression algorithm applied to the object, if any
+    fn compression(&self) -> Option<SerializationCompression> {
+        self.extensions
+            .iter()
+            .find_map(|extension| match extension {
+                SerializationHeaderExtension::Compression(compression) => Some(*compression),
+                _ => None,
+            })
+    }
+
+    /// Returns the checksum of the object, if any
+    fn checksum(&self) -> Option<SerializationChecksum> {
+        self.extensions
+            .iter()
+            .find_map(|extension| match extension {
+                SerializationHeaderExtension::Checksum(checksum) => Some(*checksum),
+                _ => None,
+            })
+    }
+
+    /// Returns the layout of the frames of the object, if it is streamed in frames
+    fn frame_layout(&self) -> Option<FrameLayout> {
+        self.extensions
+            .iter()
+            .find_map(|extension| match extension {
+                SerializationHeaderExtension::Frames(layout) => Some(*layout),
+                _ => None,
+            })
+    }
+
+    /// Returns the encryption applied to the object, if any
+    fn encryption(&self) -> Option<SerializationEncryption> {
+        self.extensions
+            .iter()
+            .find_map(|extension| match extension {
+                SerializationHeaderExtension::Encryption(encryption) => Some(*encryption),
+                _ => None,
+            })
+    }
+
+    /// Returns true if the header is followed by a collection of objects
+    fn is_collection(&self) -> bool {
+        self.extensions
+            .iter()
+            .any(|extension| matches!(extension, SerializationHeaderExtension::Collection))
+    }
+
+    /// Returns the fingerprint of the parameters of the object, if any
+    fn parameter_fingerprint(&self) -> Option<ParameterFingerprint> {
+        self.extensions
+            .iter()
+            .find_map(|extension| match extension {
+                SerializationHeaderExtension::ParameterFingerprint(fingerprint) => {
+                    Some(*fingerprint)
+                }
+                _ => None,
+            })
+    }
+
+    /// Returns the length of the signed payload following the header, if the object is signed
+    fn signed_payload_length(&self) -> Option<u64> {
+        self.extensions
+            .iter()
+            .find_map(|extension| match extension {
+                SerializationHeaderExtension::SignedPayloadLength(length) => Some(*length),
+                _ => None,
+            })
+    }
+
+    /// Returns the user defined metadata attached to the object, if any
+    fn metadata(&self) -> Option<&BTreeMap<String, String>> {
+        self.extensions
+            .iter()
+            .find_map(|extension| match extension {
+                SerializationHeaderExtension::Metadata(metadata) => Some(metadata),
+                _ => None,
+            })
+    }
+
+    /// Writes the header, followed by its extensions if there are any
+    fn serialize_into(
+        &self,
+        mut writer: impl std::io::Write,
+        format: SerializationFormat,
+        header_length_limit: u64,
+    ) -> bincode::Result<()> {
+        format.serialize_into(self, &mut writer, header_length_limit)?;
+        if self.header_version == MIN_READER_SERIALIZATION_VERSION {
+            format.serialize_into(&self.min_reader_version, &mut writer, header_length_limit)?;
+            format.serialize_into(&self.extensions, &mut writer, header_length_limit)?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads a header written by [`Self::serialize_into`]
+    fn deserialize_from(
+        mut reader: impl std::io::Read,
+        format: SerializationFormat,
+        header_length_limit: u64,
+    ) -> Result<Self, SafeSerializationError> {
+        Self::deserialize_base_from(&mut reader, format, header_length_limit)?
+            .deserialize_extensions_from(reader, format, header_length_limit)
+    }
+
+    /// Reads the part of the header that is common to all the header versions
+    fn deserialize_base_from(
+        reader: impl std::io::Read,
+        format: SerializationFormat,
+        header_length_limit: u64,
+    ) -> Result<Self, SafeSerializationError> {
+        format
+            .deserialize_from(reader, header_length_limit)
+            .map_err(header_size_limit_error)
+    }
+
+    /// Reads what follows the common part of the header, depending on the header version
+    fn deserialize_extensions_from(
+        mut self,
+        mut reader: impl std::io::Read,
+        format: SerializationFormat,
+        header_length_limit: u64,
+    ) -> Result<Self, SafeSerializationError> {
+        match self.header_version.as_ref() {
+            SERIALIZATION_VERSION => {}
+            MIN_READER_SERIALIZATION_VERSION => {
+                let min_reader_version: Option<String> = format
+                    .deserialize_from(&mut reader, header_length_limit)
+                    .map_err(header_size_limit_error)?;
+
+                // Checked before reading the extensions, which may not be known by this version
+                if let Some(min_reader_version) = &min_reader_version {
+                    check_min_reader_version(min_reader_version)?;
+                }
+                self.min_reader_version = min_reader_version.map(Cow::Owned);
+
+                self.extensions = format
+                    .deserialize_from(&mut reader, header_length_limit)
+                    .map_err(header_size_limit_error)?;
+            }
+            header_version => {
+                return Err(SafeSerializationError::Message(format!(
+                    "Unknown serialization header version {header_version}, the object was \
+probably serialized by a newer version of TFHE-rs (this is TFHE-rs {CRATE_VERSION})"
+                )));
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Checks the validity of the header
+    fn validate<T: Named>(&self) -> Result<(), SafeSerializationError> {
+        match &self.versioning_mode {
+            SerializationVersioningMode::Versioned { versioning_version } => {
+                // For the moment there is only one versioning scheme, so another value is
+                // a hard error. But maybe if we upgrade it we will be able to automatically convert
+                // it.
+                if versioning_version != VERSIONING_VERSION {
+                    return Err(SafeSerializationError::VersionMismatch {
+                        expected: VERSIONING_VERSION.to_string(),
+                        found: versioning_version.to_string(),
+                    });
+                }
+            }
+            SerializationVersioningMode::Unversioned { crate_version } => {
+                if crate_version != CRATE_VERSION {
+                    return Err(SafeSerializationError::VersionMismatch {
+                        expected: CRATE_VERSION.to_string(),
+                        found: crate_version.to_string(),
+                    });
+                }
+            }
+        }
+
+        if self.name != T::NAME {
+            return Err(SafeSerializationError::TypeMismatch {
+                expected: T::NAME.to_string(),
+                found: self.name.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Signs the serialized content and writes the signature
+fn write_signature(
+    signer: &dyn SerializationSigner,
+    signed_content: &[u8],
+    writer: impl std::io::Write,
+) -> bincode::Result<()> {
+    let signature = signer
+        .sign(signed_content)
+        .map_err(|err| Box::new(bincode::ErrorKind::Custom(err)))?;
+
+    bincode::DefaultOptions::new()
+        .with_fixint_encoding()
+        .with_limit(SIGNATURE_LENGTH_LIMIT)
+        .serialize_into(writer, &signature)
+}
+
+/// A configuration used to Serialize *TFHE-rs* objects. This configuration decides
+/// if the object will be versioned and holds the max byte size of the written data.
+#[derive(Clone)]
+pub struct SerializationConfig {
+    versioned: SerializationVersioningMode,
+    serialized_size_limit: u64,
+    format: SerializationFormat,
+    frame_size: Option<u64>,
+    checksum: Option<ChecksumAlgorithm>,
+    signer: Option<Arc<dyn SerializationSigner>>,
+    metadata: BTreeMap<String, String>,
+    parameter_fingerprint: Option<ParameterFingerprint>,
+    progress: Option<ProgressCallback>,
+    #[cfg(feature = "zstd")]
+    compression: Option<CompressionLevel>,
+    #[cfg(feature = "zstd")]
+    compression_segment_size: Option<u64>,
+    #[cfg(feature = "aead")]
+    encryption_key: Option<zeroize::Zeroizing<[u8; 32]>>,
+}
+
+impl SerializationConfig {
+    /// Creates a new serialization config. The default configuration will serialize the object
+    /// with versioning information for backward compatibility.
+    /// `serialized_size_limit` is the size limit (in number of byte) of the serialized object
+    /// (excluding the header).
+    pub fn new(serialized_size_limit: u64) -> Self {
+        Self {
+            versioned: SerializationVersioningMode::versioned(),
+            serialized_size_limit,
+            format: SerializationFormat::Bincode,
+            frame_size: None,
+            checksum: None,
+            signer: None,
+            metadata: BTreeMap::new(),
+            parameter_fingerprint: None,
+            progress: None,
+            #[cfg(feature = "zstd")]
+            compression: None,
+            #[cfg(feature = "zstd")]
+            compression_segment_size: None,
+            #[cfg(feature = "aead")]
+            encryption_key: None,
+        }
+    }
+
+    /// Creates a new serialization config whose size limit is the
+    /// [recommended limit](recommended_size_limit) for objects of type `T` that are conformant
+    /// with `parameter_set`.
+    pub fn new_auto_limit<T: SerializedSizeBound>(parameter_set: &T::ParameterSet) -> Self {
+        Self::new(recommended_size_limit::<T>(parameter_set))
+    }
+
+    /// Creates a new serialization config without any size check.
+    pub fn new_with_unlimited_size() -> Self {
+        Self {
+            versioned: SerializationVersioningMode::versioned(),
+            serialized_size_limit: 0,
+            format: SerializationFormat::Bincode,
+            frame_size: None,
+            checksum: None,
+            signer: None,
+            metadata: BTreeMap::new(),
+            parameter_fingerprint: None,
+            progress: None,
+            #[cfg(feature = "zstd")]
+            compression: None,
+            #[cfg(feature = "zstd")]
+            compression_segment_size: None,
+            #[cfg(feature = "aead")]
+            encryption_key: None,
+        }
+    }
+
+    /// Disables the size limit for serialized objects
+    pub fn disable_size_limit(self) -> Self {
+        Self {
+            serialized_size_limit: 0,
+            ..self
+        }
+    }
+
+    /// Disable the versioning of serialized objects
+    pub fn disable_versioning(self) -> Self {
+        Self {
+            versioned: SerializationVersioningMode::unversioned(),
+            ..self
+        }
+    }
+
+    /// Selects the wire format of the serialized objects, bincode is used by default.
+    ///
+    /// The objects must be deserialized by a config using the same format, see
+    /// [`DeserializationConfig::with_format`].
+    pub fn with_format(self, format: SerializationFormat) -> Self {
+        Self { format, ..self }
+    }
+
+    /// Streams the serialized objects in frames of at most `frame_size` bytes.
+    ///
+    /// The object is written in frames as it is encoded, instead of being encoded in memory. Each
+    /// frame is prefixed with its length, so that on deserialization the frames are read and
+    /// checked one at a time: a frame larger than `frame_size`, recorded in the header, is
+    /// rejected before its content is read, and the size limit is enforced as the frames are
+    /// read.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `frame_size` is 0 or greater than 64 MiB.
+    pub fn with_chunked_streaming(self, frame_size: u64) -> Self {
+        assert!(
+            (1..=MAX_FRAME_SIZE).contains(&frame_size),
+            "The frame size must be between 1 and {MAX_FRAME_SIZE} bytes"
+        );
+        Self {
+            frame_size: Some(frame_size),
+            ..self
+        }
+    }
+
+    /// Signs the serialized objects with the provided signer.
+    ///
+    /// The detached signature covers the header and the serialized object, it is appended after
+    /// them. The signed data can only be deserialized with signature verification by a config
+    /// created with [`DeserializationConfig::with_verifier`]. The length of the serialized object
+    /// is stored in the header, so that the signature can be checked before the object is parsed.
+    ///
+    /// Note that the object is first serialized in memory to be signed.
+    pub fn with_signer(self, signer: Arc<dyn SerializationSigner>) -> Self {
+        Self {
+            signer: Some(signer),
+            ..self
+        }
+    }
+
+    /// Attaches user defined metadata to the header of the serialized objects.
+    ///
+    /// The metadata can hold small information such as a tenant identifier or a key generation
+    /// epoch. It is not part of the object, so it has no impact on its conformance, and it can be
+    /// read without deserializing the object with [`inspect_header`], or along with the object
+    /// with [`DeserializationConfig::deserialize_from_with_metadata`].
+    ///
+    /// The metadata is stored in the header, so it must fit in the header size limit of
+    /// 1000 bytes when a size limit is used.
+    pub fn with_metadata(self, metadata: BTreeMap<String, String>) -> Self {
+        Self { metadata, ..self }
+    }
+
+    /// Attaches the fingerprint of the parameters the serialized objects were created with to
+    /// their header.
+    ///
+    /// The fingerprint is checked on deserialization by a config created with
+    /// [`DeserializationConfig::with_expected_parameter_fingerprint`], see
+    /// [`ParameterFingerprint`].
+    pub fn with_parameter_fingerprint(self, fingerprint: ParameterFingerprint) -> Self {
+        Self {
+            parameter_fingerprint: Some(fingerprint),
+            ..self
+        }
+    }
+
+    /// Calls `callback` with the number of bytes written so far and the total number of bytes,
+    /// so that the progress of the serialization of large objects such as server keys can be
+    /// displayed.
+    ///
+    /// The callback is called every MiB and once the object is written. Returning
+    /// [`ControlFlow::Break`] from the callback cancels the serialization, which then returns an
+    /// error. The progress is reported by [`Self::serialize_into`] and
+    /// [`Self::serialize_slice_into`].
+    ///
+    /// The object is traversed twice, once to compute the total size and once to write it.
+    pub fn with_progress(
+        self,
+        callback: impl Fn(u64, Option<u64>) -> ControlFlow<()> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            progress: Some(Arc::new(callback)),
+            ..self
+        }
+    }
+
+    /// Create a serialization header based on the current config
+    fn create_header<T: Named>(&self) -> SerializationHeader {
+        #[allow(unused_mut)]
+        let mut header = match self.versioned {
+            SerializationVersioningMode::Versioned { .. } => {
+                SerializationHeader::new_versioned::<T>()
+            }
+            SerializationVersioningMode::Unversioned { .. } => {
+                SerializationHeader::new_unversioned::<T>()
+            }
+        };
+
+        #[cfg(feature = "zstd")]
+        if self.compression.is_some() {
+            header.push_extension(SerializationHeaderExtension::Compression(
+                SerializationCompression::Zstd,
+            ));
+        }
+
+        if !self.metadata.is_empty() {
+            header.push_extension(SerializationHeaderExtension::Metadata(
+                self.metadata.clone(),
+            ));
+        }
+
+        if let Some(fingerprint) = self.parameter_fingerprint {
+            header.push_extension(SerializationHeaderExtension::ParameterFingerprint(
+                fingerprint,
+            ));
+        }
+
+        header
+    }
+
+    /// Returns the max length of the serialized header
+    fn header_length_limit(&self) -> u64 {
+        if self.serialized_size_limit == 0 {
+            0
+        } else {
+            HEADER_LENGTH_LIMIT
+        }
+    }
+
+    /// Serializes an object into a [writer](std::io::Write), based on the current config.
+    /// The written bytes can be deserialized using [`DeserializationConfig::deserialize_from`].
+    pub fn serialize_into<T: Serialize + Versionize + Named>(
+        mut self,
+        object: &T,
+        mut writer: impl std::io::Write,
+    ) -> bincode::Result<()> {
+        if let Some(callback) = self.progress.take() {
+            let progress = Progress {
+                callback: &*callback,
+                total: Some(self.serialized_size(object)?),
+            };
+            let mut writer = ProgressWriter {
+                inner: writer,
+                reporter: ProgressReporter::new(progress),
+            };
+            self.serialize_into(object, &mut writer)?;
+            writer.reporter.report()?;
+            return Ok(());
+        }
+
+        if let Some(signer) = &self.signer {
+            let mut signed_content = Vec::new();
+            self.serialize_header_and_object(object, &mut signed_content, None)?;
+
+            writer.write_all(&signed_content)?;
+            return write_signature(signer.as_ref(), &signed_content, writer);
+        }
+
+        self.serialize_header_and_object(object, &mut writer, None)
+    }
+
+    /// Serializes an object like [`Self::serialize_into`], but writes the header and the payload
+    /// into two separate [writers](std::io::Write).
+    ///
+    /// This allows to store the header, which holds the metadata of the object, apart from the
+    /// bulk of the data. The two parts can be deserialized using
+    /// [`DeserializationConfig::deserialize_detached_from`]. The concatenation of the header and
+    /// the payload is exactly what [`Self::serialize_into`] writes, so it can also be deserialized
+    /// with [`DeserializationConfig::deserialize_from`].
+    ///
+    /// With a signer, the signature covers both parts and is written after the payload.
+    pub fn serialize_detached_into<T: Serialize + Versionize + Named>(
+        self,
+        object: &T,
+        mut header_writer: impl std::io::Write,
+        mut payload_writer: impl std::io::Write,
+    ) -> bincode::Result<()> {
+        if let Some(signer) = &self.signer {
+            let mut header = Vec::new();
+            let mut payload = Vec::new();
+            self.serialize_header_and_object(object, &mut header, Some(&mut payload))?;
+
+            header_writer.write_all(&header)?;
+            payload_writer.write_all(&payload)?;
+            header.extend_from_slice(&payload);
+            return write_signature(signer.as_ref(), &header, payload_writer);
+        }
+
+        self.serialize_header_and_object(object, &mut header_writer, Some(&mut payload_writer))
+    }
+
+    /// Serializes a slice of objects of the same type into a [writer](std::io::Write), based on
+    /// the current config.
+    ///
+    /// A single header is written for the whole collection, followed by the number of elements
+    /// and the length of each serialized element, then by the elements themselves. This avoids
+    /// repeating the header for each element. The written bytes can be deserialized using
+    /// [`DeserializationConfig::deserialize_vec_from`].
+    ///
+    /// The size limit of the config applies to each element. The checksum, if any, covers all the
+    /// elements. Compression, encryption and chunked streaming are not supported for collections.
+    pub fn serialize_slice_into<T: Serialize + Versionize + Named>(
+        mut self,
+        objects: &[T],
+        mut writer: impl std::io::Write,
+    ) -> bincode::Result<()> {
+        if let Some(callback) = self.progress.take() {
+            let mut counter = CountingWriter {
+                inner: std::io::sink(),
+                count: 0,
+            };
+            self.clone().serialize_slice_into(objects, &mut counter)?;
+            let progress = Progress {
+                callback: &*callback,
+                total: Some(counter.count),
+            };
+
+            let mut writer = ProgressWriter {
+                inner: writer,
+                reporter: ProgressReporter::new(progress),
+            };
+            self.serialize_slice_into(objects, &mut writer)?;
+            writer.reporter.report()?;
+            return Ok(());
+        }
+
+        if let Some(signer) = &self.signer {
+            let mut signed_content = Vec::new();
+            self.serialize_header_and_slice(objects, &mut signed_content)?;
+
+            writer.write_all(&signed_content)?;
+            return write_signature(signer.as_ref(), &signed_content, writer);
+        }
+
+        self.serialize_header_and_slice(objects, writer)
+    }
+
+    /// Returns the exact number of bytes written by [`Self::serialize_into`] for this object.
+    ///
+    /// The object is serialized without storing the result, so this has the cost of a
+    /// serialization, but the serialized data is not allocated. It can be used to pre-allocate a
+    /// buffer or to reserve storage before the actual serialization.
+    ///
+    /// With a signer, the object is serialized in memory and signed to know the signature length.
+    pub fn serialized_size<T: Serialize + Versionize + Named>(
+        &self,
+        object: &T,
+    ) -> bincode::Result<u64> {
+        let mut counter = CountingWriter {
+            inner: std::io::sink(),
+            count: 0,
+        };
+        let config = Self {
+            progress: None,
+            ..self.clone()
+        };
+        config.serialize_into(object, &mut counter)?;
+        Ok(counter.count)
+    }
+
+    /// Returns the number of bytes of the serialized object alone, without its header, signature
+    /// or compression.
+    ///
+    /// This is the size checked against the `serialized_size_limit` of the
+    /// [`DeserializationConfig`], so it can be used to choose this limit.
+    pub fn serialized_object_size<T: Serialize + Versionize + Named>(
+        &self,
+        object: &T,
+    ) -> bincode::Result<u64> {
+        let mut counter = CountingWriter {
+            inner: std::io::sink(),
+            count: 0,
+        };
+        self.serialize_object(object, &mut counter)?;
+        Ok(counter.count)
+    }
+
+    /// Serializes the header and the object. The payload is written after the header, unless a
+    /// separate writer is provided for it.
+    fn serialize_header_and_object<T: Serialize + Versionize + Named>(
+        &self,
+        object: &T,
+        header_writer: &mut dyn std::io::Write,
+        payload_writer: Option<&mut dyn std::io::Write>,
+    ) -> bincode::Result<()> {
+        let mut header = self.create_header::<T>();
+
+        // With compression, the payload is the compressed object, which is prepared in memory
+        #[cfg(feature = "zstd")]
+        let compressed = self.compress_object(object)?;
+        #[cfg(not(feature = "zstd"))]
+        let compressed: Option<Vec<u8>> = None;
+
+        // With encryption, the payload is the encrypted object, which is also prepared in memory
+        #[cfg(feature = "aead")]
+        let (payload, payload_size_limit) = match &self.encryption_key {
+            Some(key) => {
+                let (encryption, encrypted) = self.encrypt_object(key, object, compressed)?;
+                header.push_extension(SerializationHeaderExtension::Encryption(encryption));
+
+                (
+                    Some(encrypted),
+                    aead::encrypted_size_limit(self.serialized_size_limit),
+                )
+            }
+            None => (compressed, self.serialized_size_limit),
+        };
+        #[cfg(not(feature = "aead"))]
+        let (payload, payload_size_limit) = (compressed, self.serialized_size_limit);
+
+        let write_payload = |writer: &mut dyn std::io::Write| match &payload {
+            Some(payload) => bincode::DefaultOptions::new()
+                .with_fixint_encoding()
+                .with_limit(payload_size_limit)
+                .serialize_into(writer, payload),
+            None => self.serialize_object(object, writer),
+        };
+
+        if let Some(algorithm) = self.checksum {
+            // First pass over the payload to compute its checksum, without storing it
+            let mut first_pass = ChecksumWriter {
+                inner: std::io::sink(),
+                hasher: Some(
+                    ChecksumHasher::new(algorithm)
+                        .map_err(|err| Box::new(bincode::ErrorKind::Custom(err)))?,
+                ),
+            };
+            write_payload(&mut first_pass)?;
+
+            if let Some(hasher) = first_pass.hasher {
+                header.push_extension(SerializationHeaderExtension::Checksum(hasher.finalize()));
+            }
+        }
+        if let Some(frame_size) = self.frame_size {
+            header.push_extension(SerializationHeaderExtension::Frames(FrameLayout {
+                frame_size,
+            }));
+        }
+
+        let frame_layout = header.frame_layout();
+        let write_framed_payload = |writer: &mut dyn std::io::Write| {
+            let Some(layout) = frame_layout else {
+                return write_payload(writer);
+            };
+
+            let mut frame_writer = FrameWriter::new(writer, layout);
+            write_payload(&mut frame_writer)?;
+            frame_writer.finish()?;
+            Ok(())
+        };
+
+        // The length of a signed payload is written in the header, so that the payload can be
+        // read and its signature checked before it is parsed
+        if self.signer.is_some() {
+            let mut signed_payload = Vec::new();
+            write_framed_payload(&mut signed_payload)?;
+            header.push_extension(SerializationHeaderExtension::SignedPayloadLength(
+                signed_payload.len() as u64,
+            ));
+
+            header.serialize_into(&mut *header_writer, self.format, self.header_length_limit())?;
+            payload_writer
+                .unwrap_or(header_writer)
+                .write_all(&signed_payload)?;
+            return Ok(());
+        }
+
+        header.serialize_into(&mut *header_writer, self.format, self.header_length_limit())?;
+        write_framed_payload(payload_writer.unwrap_or(header_writer))
+    }
+
+    /// Serializes the header, the length table and the elements of a collection
+    fn serialize_header_and_slice<T: Serialize + Versionize + Named>(
+        &self,
+        objects: &[T],
+        mut writer: impl std::io::Write,
+    ) -> bincode::Result<()> {
+        #[cfg(feature = "zstd")]
+        let compressed = self.compression.is_some();
+        #[cfg(not(feature = "zstd"))]
+        let compressed = false;
+        #[cfg(feature = "aead")]
+        let encrypted = self.encryption_key.is_some();
+        #[cfg(not(feature = "aead"))]
+        let encrypted = false;
+
+        if compressed || encrypted || self.frame_size.is_some() {
+            return Err(Box::new(bincode::ErrorKind::Custom(
+                "Compression, encryption and chunked streaming are not supported \
+for collections"
+                    .to_string(),
+            )));
+        }
+
+        let mut header = self.create_header::<T>();
+        header.push_extension(SerializationHeaderExtension::Collection);
+
+        // First pass over the elements to compute their sizes and checksum, without storing them
+        let mut first_pass = CountingWriter {
+            inner: ChecksumWriter {
+                inner: std::io::sink(),
+                hasher: self
+                    .checksum
+                    .map(ChecksumHasher::new)
+                    .transpose()
+                    .map_err(|err| Box::new(bincode::ErrorKind::Custom(err)))?,
+            },
+            count: 0,
+        };
+        let element_lengths = objects
+            .iter()
+            .map(|object| {
+                let start = first_pass.count;
+                self.serialize_object(object, &mut first_pass)?;
+                Ok(first_pass.count - start)
+            })
+            .collect::<bincode::Result<Vec<u64>>>()?;
+
+        if let Some(hasher) = first_pass.inner.hasher {
+            header.push_extension(SerializationHeaderExtension::Checksum(hasher.finalize()));
+        }
+        if self.signer.is_some() {
+            let lengths_size = bincode::DefaultOptions::new()
+                .with_fixint_encoding()
+                .serialized_size(&element_lengths)?;
+            header.push_extension(SerializationHeaderExtension::SignedPayloadLength(
+                lengths_size + first_pass.count,
+            ));
+        }
+
+        header.serialize_into(&mut writer, self.format, self.header_length_limit())?;
+        bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .with_limit(self.serialized_size_limit)
+            .serialize_into(&mut writer, &element_lengths)?;
+
+        for object in objects {
+            self.serialize_object(object, &mut writer)?;
+        }
+
+        Ok(())
+    }
+
+    /// Serializes the object alone, without header
+    fn serialize_object<T: Serialize + Versionize + Named>(
+        &self,
+        object: &T,
+        writer: impl std::io::Write,
+    ) -> bincode::Result<()> {
+        match self.versioned {
+            SerializationVersioningMode::Versioned { .. } => {
+                self.format
+                    .serialize_into(&object.versionize(), writer, self.serialized_size_limit)
+            }
+            SerializationVersioningMode::Unversioned { .. } => {
+                self.format
+                    .serialize_into(object, writer, self.serialized_size_limit)
+            }
+        }
+    }
+}
+
+/// Reads the signed payload following a header, whose serialized bytes are `signed_content`, and
+/// checks the signature following the payload before anything is parsed from it.
+///
+/// Returns the signed content, that is the header followed by the payload.
+fn read_verified_content(
+    verifier: &dyn SerializationVerifier,
+    header: &SerializationHeader,
+    mut signed_content: Vec<u8>,
+    mut reader: impl std::io::Read,
+) -> Result<Vec<u8>, SafeSerializationError> {
+    let Some(payload_length) = header.signed_payload_length() else {
+        return Err(SafeSerializationError::Message(format!(
+            "This {} is not signed, but a verifier was provided",
+            header.name
+        )));
+    };
+
+    // The length is not trusted, the payload is not allocated upfront
+    let header_length = signed_content.len();
+    (&mut reader)
+        .take(payload_length)
+        .read_to_end(&mut signed_content)?;
+    if (signed_content.len() - header_length) as u64 != payload_length {
+        return Err(SafeSerializationError::Message(format!(
+            "The signed payload of this {} is truncated",
+            header.name
+        )));
+    }
+
+    verify_signature(verifier, &signed_content, reader)?;
+
+    Ok(signed_content)
+}
+
+/// Reads the signature following the serialized content and checks it with the verifier
+fn verify_signature(
+    verifier: &dyn SerializationVerifier,
+    signed_content: &[u8],
+    reader: impl std::io::Read,
+) -> Result<(), SafeSerializationError> {
+    let signature: Vec<u8> = bincode::DefaultOptions::new()
+        .with_fixint_encoding()
+        .with_limit(SIGNATURE_LENGTH_LIMIT)
+        .deserialize_from(reader)
+        .map_err(|err| {
+            SafeSerializationError::Message(format!(
+                "Failed to read the signature of the object: {err}"
+            ))
+        })?;
+
+    verifier.verify(signed_content, &signature).map_err(|err| {
+        SafeSerializationError::Message(format!("Invalid signature of the object: {err}"))
+    })
+}
+
+/// A configuration used to Serialize *TFHE-rs* objects. This configuration decides
+/// the various sanity checks that will be performed during deserialization.
+///
+/// The config borrows the [verifier](Self::with_verifier), the
+/// [progress callback](Self::with_progress) and the [decryption key](Self::with_decryption_key)
+/// it is given, so that it can be copied to deserialize several objects.
+#[derive(Copy, Clone)]
+pub struct DeserializationConfig<'a> {
+    serialized_size_limit: u64,
+    validate_header: bool,
+    allow_legacy_unframed: bool,
+    require_checksum: bool,
+    format: SerializationFormat,
+    verifier: Option<&'a dyn SerializationVerifier>,
+    expected_parameter_fingerprint: Option<ParameterFingerprint>,
+    progress: Option<Progress<'a>>,
+    collection_limits: CollectionLimits,
+    #[cfg(feature = "aead")]
+    decryption_key: Option<&'a [u8; 32]>,
+}
+
+/// A configuration used to Serialize *TFHE-rs* objects. This is similar to
+/// [`DeserializationConfig`] but it will not require conformance parameters.
+///
+/// This type should be created with [`DeserializationConfig::disable_conformance`]
+#[derive(Copy, Clone)]
+pub struct NonConformantDeserializationConfig<'a> {
+    serialized_size_limit: u64,
+    validate_header: bool,
+    allow_legacy_unframed: bool,
+    require_checksum: bool,
+    format: SerializationFormat,
+    verifier: Option<&'a dyn SerializationVerifier>,
+    expected_parameter_fingerprint: Option<ParameterFingerprint>,
+    progress: Option<Progress<'a>>,
+    collection_limits: CollectionLimits,
+    #[cfg(feature = "aead")]
+    decryption_key: Option<&'a [u8; 32]>,
+}
+
+impl<'a> NonConformantDeserializationConfig<'a> {
+    /// Deserializes an object serialized by [`SerializationConfig::serialize_into`] from a
+    /// [reader](std::io::Read). Performs various sanity checks based on the deserialization config,
+    /// but skips conformance checks.
+    pub fn deserialize_from<T: DeserializeOwned + Unversionize + Named>(
+        self,
+        reader: impl std::io::Read,
+    ) -> Result<T, SafeSerializationError> {
+        self.deserialize_from_with_metadata(reader)
+            .map(|(object, _)| object)
+    }
+
+    /// Deserializes an object like [`Self::deserialize_from`], and returns it along with the
+    /// metadata attached to it by [`SerializationConfig::with_metadata`].
+    ///
+    /// The metadata is empty if none was attached to the object.
+    pub fn deserialize_from_with_metadata<T: DeserializeOwned + Unversionize + Named>(
+        mut self,
+        mut reader: impl std::io::Read,
+    ) -> Result<(T, BTreeMap<String, String>), SafeSerializationError> {
+        if let Some(progress) = self.progress.take() {
+            let mut reader = ProgressReader {
+                inner: reader,
+                reporter: ProgressReporter::new(progress),
+            };
+            let object = self.deserialize_from_with_metadata(&mut reader)?;
+            reader.reporter.report()?;
+            return Ok(object);
+        }
+
+        if let Some(verifier) = self.verifier {
+            let (header, header_bytes) = self.deserialize_signed_header::<T>(&mut reader)?;
+            let header_length = header_bytes.len();
+            let signed_content = read_verified_content(verifier, &header, header_bytes, reader)?;
+
+            let object =
+                self.deserialize_object_with_header(&header, &signed_content[header_length..])?;
+            let metadata = header.metadata().cloned().unwrap_or_default();
+
+            return Ok((object, metadata));
+        }
+
+        self.deserialize_header_and_object(reader)
+    }
+
+    /// Deserializes an object serialized by [`SerializationConfig::serialize_detached_into`],
+    /// given its header and its payload read from two separate [readers](std::io::Read). Performs
+    /// various sanity checks based on the deserialization config, but skips conformance checks.
+    pub fn deserialize_detached_from<T: DeserializeOwned + Unversionize + Named>(
+        self,
+        header_reader: impl std::io::Read,
+        payload_reader: impl std::io::Read,
+    ) -> Result<T, SafeSerializationError> {
+        if let Some(verifier) = self.verifier {
+            let (header, header_bytes) = self.deserialize_signed_header::<T>(header_reader)?;
+            let header_length = header_bytes.len();
+            let signed_content =
+                read_verified_content(verifier, &header, header_bytes, payload_reader)?;
+
+            return self.deserialize_object_with_header(&header, &signed_content[header_length..]);
+        }
+
+        let header = self.deserialize_header::<T>(header_reader)?;
+        self.deserialize_object_with_header(&header, payload_reader)
+    }
+
+    /// Deserializes a collection serialized by [`SerializationConfig::serialize_slice_into`] from
+    /// a [reader](std::io::Read). Performs various sanity checks based on the deserialization
+    /// config, but skips conformance checks.
+    pub fn deserialize_vec_from<T: DeserializeOwned + Unversionize + Named>(
+        mut self,
+        mut reader: impl std::io::Read,
+    ) -> Result<Vec<T>, SafeSerializationError> {
+        if let Some(progress) = self.progress.take() {
+            let mut reader = ProgressReader {
+                inner: reader,
+                reporter: ProgressReporter::new(progress),
+            };
+            let objects = self.deserialize_vec_from(&mut reader)?;
+            reader.reporter.report()?;
+            return Ok(objects);
+        }
+
+        if let Some(verifier) = self.verifier {
+            let (header, header_bytes) = self.deserialize_signed_header::<T>(&mut reader)?;
+            let header_length = header_bytes.len();
+            let signed_content = read_verified_content(verifier, &header, header_bytes, reader)?;
+
+            return self.deserialize_vec_with_header(&header, &signed_content[header_length..]);
+        }
+
+        let header = self.deserialize_header::<T>(&mut reader)?;
+        self.deserialize_vec_with_header(&header, reader)
+    }
+
+    /// Deserializes the length table and the elements of a collection, given its header
+    fn deserialize_vec_with_header<T: DeserializeOwned + Unversionize + Named>(
+        &self,
+        header: &SerializationHeader,
+        mut reader: impl std::io::Read,
+    ) -> Result<Vec<T>, SafeSerializationError> {
+        if !header.is_collection() {
+            return Err(SafeSerializationError::Message(format!(
+                "This is a single serialized {}, use deserialize_from to read it",
+                header.name
+            )));
+        }
+        if header.compression().is_some()
+            || header.encryption().is_some()
+            || header.frame_layout().is_some()
+        {
+            return Err(SafeSerializationError::Message(format!(
+                "Invalid header for a collection of {}, collections cannot be compressed, \
+encrypted or streamed",
+                header.name
+            )));
+        }
+
+        let element_size_limit = self.serialized_size_limit - self.header_length_limit();
+        let element_lengths: Vec<u64> = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .with_limit(element_size_limit)
+            .deserialize_from(&mut reader)
+            .map_err(|err| SafeSerializationError::from_bincode(err, element_size_limit))?;
+
+        self.check_checksum_presence(header)?;
+        let Some(expected_checksum) = header.checksum() else {
+            return self.deserialize_elements(header, &element_lengths, reader);
+        };
+
+        let mut checksum_reader = ChecksumReader {
+            inner: reader,
+            hasher: ChecksumHasher::new(expected_checksum.algorithm())
+                .map_err(SafeSerializationError::Message)?,
+        };
+        let objects = self.deserialize_elements(header, &element_lengths, &mut checksum_reader)?;
+
+        if checksum_reader.hasher.finalize() != expected_checksum {
+            return Err(SafeSerializationError::Message(format!(
+                "The checksum of the serialized collection of {} does not match, the data is \
+corrupted",
+                header.name
+            )));
+        }
+
+        Ok(objects)
+    }
+
+    /// Deserializes the elements of a collection, given their lengths
+    fn deserialize_elements<T: DeserializeOwned + Unversionize + Named>(
+        &self,
+        header: &SerializationHeader,
+        element_lengths: &[u64],
+        mut reader: impl std::io::Read,
+    ) -> Result<Vec<T>, SafeSerializationError> {
+        let element_size_limit = self.serialized_size_limit - self.header_length_limit();
+
+        // The lengths are not trusted, the vec is not allocated upfront
+        let mut objects = Vec::new();
+        for &element_length in element_lengths {
+            if element_size_limit != 0 && element_length > element_size_limit {
+                return Err(SafeSerializationError::SizeLimitExceeded {
+                    size_limit: element_size_limit,
+                });
+            }
+
+            let mut element_reader = (&mut reader).take(element_length);
+            let object = self.deserialize_object(
+                &header.versioning_mode,
+                &mut element_reader,
+                element_size_limit,
+            )?;
+
+            if element_reader.limit() != 0 {
+                return Err(SafeSerializationError::Message(format!(
+                    "A serialized {} is shorter than its length in the collection",
+                    header.name
+                )));
+            }
+
+            objects.push(object);
+        }
+
+        Ok(objects)
+    }
+
+    /// Deserializes the header and the object, and returns the object with its metadata
+    fn deserialize_header_and_object<T: DeserializeOwned + Unversionize + Named>(
+        &self,
+        mut reader: impl std::io::Read,
+    ) -> Result<(T, BTreeMap<String, String>), SafeSerializationError> {
+        if self.allow_legacy_unframed {
+            return self.deserialize_header_and_object_or_legacy(reader);
+        }
+
+        let header = self.deserialize_header::<T>(&mut reader)?;
+        let object = self.deserialize_object_with_header(&header, reader)?;
+        let metadata = header.metadata().cloned().unwrap_or_default();
+
+        Ok((object, metadata))
+    }
+
+    /// Deserializes the header and the object like [`Self::deserialize_header_and_object`], or
+    /// reads the data as a plain bincode object if it does not start with a header
+    fn deserialize_header_and_object_or_legacy<T: DeserializeOwned + Unversionize + Named>(
+        &self,
+        mut reader: impl std::io::Read,
+    ) -> Result<(T, BTreeMap<String, String>), SafeSerializationError> {
+        if self.serialized_size_limit == 0 {
+            return Err(SafeSerializationError::Message(
+                "Reading legacy objects without header requires a size limit".to_string(),
+            ));
+        }
+        self.check_size_limit()?;
+
+        // The bytes read while looking for the header are kept to read the data again if it
+        // has no header
+        let mut recording_reader = RecordingReader {
+            inner: &mut reader,
+            recorded: Vec::new(),
+        };
+
+        let header = match SerializationHeader::deserialize_base_from(
+            &mut recording_reader,
+            self.format,
+            self.header_length_limit(),
+        ) {
+            Ok(header) => header,
+            Err(_) => {
+                self.check_legacy_object_allowed()?;
+
+                let recorded = recording_reader.recorded;
+                let object = SerializationFormat::Bincode.deserialize_with_limits_from(
+                    recorded.as_slice().chain(reader),
+                    self.serialized_size_limit,
+                    self.collection_limits,
+                )?;
+
+                return Ok((object, BTreeMap::new()));
+            }
+        };
+
+        // Once the start of a header has been read, the data is never read as a legacy object,
+        // so the errors in the rest of the header are reported
+        let header = header.deserialize_extensions_from(
+            &mut reader,
+            self.format,
+            self.header_length_limit(),
+        )?;
+        self.check_header::<T>(&header)?;
+
+        let object = self.deserialize_object_with_header(&header, reader)?;
+        let metadata = header.metadata().cloned().unwrap_or_default();
+
+        Ok((object, metadata))
+    }
+
+    /// Checks that an object without header can be accepted by the config. Such objects cannot
+    /// be signed, encrypted, or carry a checksum or a parameter fingerprint.
+    fn check_legacy_object_allowed(&self) -> Result<(), SafeSerializationError> {
+        let mut requirement = None;
+        if self.verifier.is_some() {
+            requirement = Some("a signature");
+        }
+        #[cfg(feature = "aead")]
+        if self.decryption_key.is_some() {
+            requirement = Some("an encryption");
+        }
+        if self.require_checksum {
+            requirement = Some("a checksum");
+        }
+
+        if let Some(requirement) = requirement {
+            return Err(SafeSerializationError::Message(format!(
+                "The data does not start with a valid header, and cannot be read as a legacy \
+object because the deserialization config requires {requirement}"
+            )));
+        }
+
+        // Legacy objects do not have a fingerprint
+        self.check_parameter_fingerprint(None)
+    }
+
+    /// Deserializes the header and checks it if header validation is enabled
+    fn deserialize_header<T: Named>(
+        &self,
+        reader: impl std::io::Read,
+    ) -> Result<SerializationHeader, SafeSerializationError> {
+        self.check_size_limit()?;
+
+        let deserialized_header =
+            SerializationHeader::deserialize_from(reader, self.format, self.header_length_limit())?;
+        self.check_header::<T>(&deserialized_header)?;
+
+        Ok(deserialized_header)
+    }
+
+    /// Deserializes the header of a signed object like [`Self::deserialize_header`], and returns
+    /// it with its serialized bytes, which are covered by the signature
+    fn deserialize_signed_header<T: Named>(
+        &self,
+        reader: impl std::io::Read,
+    ) -> Result<(SerializationHeader, Vec<u8>), SafeSerializationError> {
+        let mut recording_reader = RecordingReader {
+            inner: reader,
+            recorded: Vec::new(),
+        };
+        let header = self.deserialize_header::<T>(&mut recording_reader)?;
+
+        Ok((header, recording_reader.recorded))
+    }
+
+    /// Checks the header if header validation is enabled, and checks its parameter fingerprint
+    fn check_header<T: Named>(
+        &self,
+        header: &SerializationHeader,
+    ) -> Result<(), SafeSerializationError> {
+        if self.validate_header {
+            header.validate::<T>()?;
+        }
+        self.check_parameter_fingerprint(header.parameter_fingerprint())
+    }
+
+    /// Checks that the object has a checksum if the config requires one
+    fn check_checksum_presence(
+        &self,
+        header: &SerializationHeader,
+    ) -> Result<(), SafeSerializationError> {
+        if self.require_checksum && header.checksum().is_none() {
+            return Err(SafeSerializationError::Message(format!(
+                "The serialized {} does not have a checksum, which is required by the \
+deserialization config",
+                header.name
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Checks the parameter fingerprint of an object if a fingerprint is expected
+    fn check_parameter_fingerprint(
+        &self,
+        found: Option<ParameterFingerprint>,
+    ) -> Result<(), SafeSerializationError> {
+        match self.expected_parameter_fingerprint {
+            Some(expected) if found != Some(expected) => {
+                Err(SafeSerializationError::ParameterMismatch { expected, found })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks that the size limit leaves room for the object after its header
+    fn check_size_limit(&self) -> Result<(), SafeSerializationError> {
+        if self.serialized_size_limit != 0 && self.serialized_size_limit <= HEADER_LENGTH_LIMIT {
+            return Err(SafeSerializationError::Message(format!(
+                "The provided size limit is too small, provide a limit of at least \
+{HEADER_LENGTH_LIMIT} bytes"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Deserializes the object described by an already deserialized header, checking its
+    /// checksum if there is one
+    fn deserialize_object_with_header<T: DeserializeOwned + Unversionize + Named>(
+        &self,
+        deserialized_header: &SerializationHeader,
+        reader: impl std::io::Read,
+    ) -> Result<T, SafeSerializationError> {
+        if deserialized_header.is_collection() {
+            return Err(SafeSerializationError::Message(format!(
+                "This is a serialized collection of {}, use deserialize_vec_from to read it",
+                deserialized_header.name
+            )));
+        }
+
+        let object_size_limit = self.serialized_size_limit - self.header_length_limit();
+
+        self.check_checksum_presence(deserialized_header)?;
+        let Some(expected_checksum) = deserialized_header.checksum() else {
+            return self.deserialize_payload(deserialized_header, reader, object_size_limit);
+        };
+
+        let mut checksum_reader = ChecksumReader {
+            inner: reader,
+            hasher: ChecksumHasher::new(expected_checksum.algorithm())
+                .map_err(SafeSerializationError::Message)?,
+        };
+        let object = self
+            .deserialize_payload(deserialized_header, &mut checksum_reader, object_size_limit)
+            .map_err(|err| match err {
+                // Decoding errors are likely due to the corruption that the checksum detects
+                SafeSerializationError::Bincode(_) | SafeSerializationError::Message(_) => {
+                    SafeSerializationError::Message(format!(
+                        "Failed to deserialize an object protected by a checksum, \
+the data may be corrupted: {err}"
+                    ))
+                }
+                err => err,
+            })?;
+
+        if checksum_reader.hasher.finalize() != expected_checksum {
+            return Err(SafeSerializationError::Message(format!(
+                "The checksum of the serialized {} does not match, the data is corrupted",
+                deserialized_header.name
+            )));
+        }
+
+        Ok(object)
+    }
+
+    /// Deserializes the payload following the header, which may be streamed in frames
+    fn deserialize_payload<T: DeserializeOwned + Unversionize + Named>(
+        &self,
+        header: &SerializationHeader,
+        mut reader: impl std::io::Read,
+        object_size_limit: u64,
+    ) -> Result<T, SafeSerializationError> {
+        let Some(layout) = header.frame_layout() else {
+            return self.deserialize_unframed_payload(header, reader, object_size_limit);
+        };
+
+        layout.validate().map_err(SafeSerializationError::Message)?;
+
+        // The frames are read one at a time, without buffering, so that the data following the
+        // object is not consumed. The size limit is enforced while the frames are read.
+        let mut frame_reader = FrameReader::new(&mut reader, layout);
+        let object =
+            self.deserialize_unframed_payload(header, &mut frame_reader, object_size_limit)?;
+        frame_reader.finish().map_err(SafeSerializationError::Io)?;
+
+        Ok(object)
+    }
+
+    /// Deserializes the payload, which may be encrypted
+    fn deserialize_unframed_payload<T: DeserializeOwned + Unversionize + Named>(
+        &self,
+        header: &SerializationHeader,
+        reader: impl std::io::Read,
+        object_size_limit: u64,
+    ) -> Result<T, SafeSerializationError> {
+        match header.encryption() {
+            None => {
+                #[cfg(feature = "aead")]
+                if self.decryption_key.is_some() {
+                    return Err(SafeSerializationError::Message(format!(
+                        "This {} is not encrypted, but a decryption key was provided",
+                        header.name
+                    )));
+                }
+
+                self.deserialize_decrypted_payload(header, reader, object_size_limit)
+            }
+            #[cfg(feature = "aead")]
+            Some(encryption) => {
+                self.deserialize_encrypted_payload(header, encryption, reader, object_size_limit)
+            }
+            #[cfg(not(feature = "aead"))]
+            Some(_) => Err(SafeSerializationError::Message(format!(
+                "This {} is encrypted, \
+please enable the \"aead\" feature of TFHE-rs to deserialize it",
+                header.name
+            ))),
+        }
+    }
+
+    /// Deserializes the decrypted payload, which may be compressed
+    fn deserialize_decrypted_payload<T: DeserializeOwned + Unversionize + Named>(
+        &self,
+        header: &SerializationHeader,
+        reader: impl std::io::Read,
+        object_size_limit: u64,
+    ) -> Result<T, SafeSerializationError> {
+        match header.compression() {
+            None => self.deserialize_object(&header.versioning_mode, reader, object_size_limit),
+            #[cfg(feature = "zstd")]
+            Some(SerializationCompression::Zstd) => {
+                self.deserialize_compressed_payload(header, reader, object_size_limit)
+            }
+            #[cfg(not(feature = "zstd"))]
+            Some(SerializationCompression::Zstd) => Err(SafeSerializationError::Message(format!(
+                "This {} has been compressed with zstd, \
+please enable the \"zstd\" feature of TFHE-rs to deserialize it",
+                header.name
+            ))),
+        }
+    }
+
+    /// Deserializes the object alone, after its header has been read
+    fn deserialize_object<T: DeserializeOwned + Unversionize + Named>(
+        &self,
+        versioning_mode: &SerializationVersioningMode,
+        reader: impl std::io::Read,
+        object_size_limit: u64,
+    ) -> Result<T, SafeSerializationError> {
+        match versioning_mode {
+            SerializationVersioningMode::Versioned { .. } => {
+                let deser_versioned = self.format.deserialize_with_limits_from(
+                    reader,
+                    object_size_limit,
+                    self.collection_limits,
+                )?;
+
+                T::unversionize(deser_versioned)
+                    .map_err(|err| SafeSerializationError::Message(err.to_string()))
+            }
+            SerializationVersioningMode::Unversioned { .. } => self
+                .format
+                .deserialize_with_limits_from(reader, object_size_limit, self.collection_limits),
+        }
+    }
+
+    /// Enables the conformance check on an existing config.
+    pub fn enable_conformance(self) -> DeserializationConfig<'a> {
+        DeserializationConfig {
+            serialized_size_limit: self.serialized_size_limit,
+            validate_header: self.validate_header,
+            allow_legacy_unframed: self.allow_legacy_unframed,
+            require_checksum: self.require_checksum,
+            format: self.format,
+            verifier: self.verifier,
+            expected_parameter_fingerprint: self.expected_parameter_fingerprint,
+            progress: self.progress,
+            collection_limits: self.collection_limits,
+            #[cfg(feature = "aead")]
+            decryption_key: self.decryption_key,
+        }
+    }
+
+    fn header_length_limit(&self) -> u64 {
+        if self.serialized_size_limit == 0 {
+            0
+        } else {
+            HEADER_LENGTH_LIMIT
+        }
+    }
+}
+
+impl<'a> DeserializationConfig<'a> {
+    /// Creates a new deserialization config.
+    ///
+    /// By default, it will check that the serialization version and the name of the
+    /// deserialized type are correct.
+    /// `serialized_size_limit` is the size limit (in number of byte) of the serialized object
+    /// (excluding version and name serialization).
+    ///
+    /// It will also check that the object is conformant with the parameter set given in
+    /// `conformance_params`. Finally, it will check the compatibility of the loaded data with
+    /// the current *TFHE-rs* version.
+    pub fn new(serialized_size_limit: u64) -> Self {
+        Self {
+            serialized_size_limit,
+            validate_header: true,
+            allow_legacy_unframed: false,
+            require_checksum: false,
+            format: SerializationFormat::Bincode,
+            verifier: None,
+            expected_parameter_fingerprint: None,
+            progress: None,
+            collection_limits: CollectionLimits::default(),
+            #[cfg(feature = "aead")]
+            decryption_key: None,
+        }
+    }
+
+    /// Creates a new deserialization config whose size limit is the
+    /// [recommended limit](recommended_size_limit) for objects of type `T` that are conformant
+    /// with `parameter_set`.
+    ///
+    /// This should be preferred to an arbitrary large limit, which defeats the protection against
+    /// memory exhaustion offered by the size limit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::safe_serialization::{DeserializationConfig, SerializationConfig};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::{gen_keys, Ciphertext};
+    ///
+    /// let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let conformance_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param();
+    ///
+    /// let ct = ck.encrypt(3);
+    ///
+    /// let mut buffer = vec![];
+    /// SerializationConfig::new_auto_limit::<Ciphertext>(&conformance_params)
+    ///     .serialize_into(&ct, &mut buffer)
+    ///     .unwrap();
+    ///
+    /// let ct: Ciphertext = DeserializationConfig::new_auto_limit::<Ciphertext>(&conformance_params)
+    ///     .deserialize_from(buffer.as_slice(), &conformance_params)
+    ///     .unwrap();
+    /// assert_eq!(ck.decrypt(&ct), 3);
+    /// ```
+    pub fn new_auto_limit<T: SerializedSizeBound>(parameter_set: &T::ParameterSet) -> Self {
+        Self::new(recommended_size_limit::<T>(parameter_set))
+    }
+
+    /// Creates a new deserialization config in strict mode for objects of type `T` that are
+    /// conformant with `parameter_set`.
+    ///
+    /// On top of the [recommended size limit](recommended_size_limit), the length of each
+    /// collection of the object is checked against the
+    /// [recommended maximum length](recommended_max_collection_length) and the nesting depth of
+    /// the object is limited to [`STRICT_NESTING_DEPTH_LIMIT`]. These checks are done while the
+    /// object is decoded, before memory is allocated for the elements of a collection, so that
+    /// adversarial length fields deep inside a payload cannot be used to pressure the allocator.
+    ///
+    /// The same [maximum length](recommended_max_collection_length) applies to all the
+    /// collections of the object.
+    ///
+    /// Configs with collection limits fail to deserialize data in the CBOR format, see
+    /// [`SerializationFormat::Cbor`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::safe_serialization::{DeserializationConfig, SerializationConfig};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::{gen_keys, Ciphertext};
+    ///
+    /// let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let conformance_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param();
+    ///
+    /// let ct = ck.encrypt(3);
+    ///
+    /// let mut buffer = vec![];
+    /// SerializationConfig::new_auto_limit::<Ciphertext>(&conformance_params)
+    ///     .serialize_into(&ct, &mut buffer)
+    ///     .unwrap();
+    ///
+    /// let ct: Ciphertext = DeserializationConfig::new_strict::<Ciphertext>(&conformance_params)
+    ///     .deserialize_from(buffer.as_slice(), &conformance_params)
+    ///     .unwrap();
+    /// assert_eq!(ck.decrypt(&ct), 3);
+    /// ```
+    pub fn new_strict<T: SerializedSizeBound>(parameter_set: &T::ParameterSet) -> Self {
+        Self::new_auto_limit::<T>(parameter_set)
+            .with_max_collection_length(recommended_max_collection_length::<T>(parameter_set))
+            .with_max_nesting_depth(STRICT_NESTING_DEPTH_LIMIT)
+    }
+
+    /// Creates a new config without any size limit for the deserialized objects.
+    pub fn new_with_unlimited_size() -> Self {
+        Self {
+            serialized_size_limit: 0,
+            validate_header: true,
+            allow_legacy_unframed: false,
+            require_checksum: false,
+            format: SerializationFormat::Bincode,
+            verifier: None,
+            expected_parameter_fingerprint: None,
+            progress: None,
+            collection_limits: CollectionLimits::default(),
+            #[cfg(feature = "aead")]
+            decryption_key: None,
+        }
+    }
+
+    /// Disables the size limit for the serialized objects.
+    pub fn disable_size_limit(self) -> Self {
+        Self {
+            serialized_size_limit: 0,
+            ..self
+        }
+    }
+
+    /// Disables the header validation on the object. This header validations
+    /// checks that the serialized object is the one that is supposed to be loaded
+    /// and is compatible with this version of *TFHE-rs*.
+    pub fn disable_header_validation(self) -> Self {
+        Self {
+            validate_header: false,
+            ..self
+        }
+    }
+
+    /// Selects the wire format of the serialized objects, bincode is used by default.
+    ///
+    /// It must be the format that was used to serialize the objects, see
+    /// [`SerializationConfig::with_format`]. The CBOR format does not support the limits of
+    /// [`Self::new_strict`].
+    pub fn with_format(self, format: SerializationFormat) -> Self {
+        Self { format, ..self }
+    }
+
+    /// Also accepts objects serialized directly with `bincode::serialize`, without the header
+    /// written by [`SerializationConfig::serialize_into`].
+    ///
+    /// When the header cannot be read, the data is read again from its start as a plain bincode
+    /// object. This allows to load data created before adopting safe serialization, without
+    /// having to write a second loader.
+    ///
+    /// Such legacy objects have no version information, so they can only be read by the version
+    /// of *TFHE-rs* that wrote them. As the header cannot be used to check the type of the object,
+    /// this mode requires a size limit. The conformance check and the collection limits still
+    /// apply.
+    ///
+    /// The data is only read as a legacy object if it does not start with a header: an object
+    /// whose header is invalid or was written by a newer version is rejected. Legacy objects
+    /// are also rejected if the config requires a signature, an encryption, a checksum or a
+    /// parameter fingerprint, which they cannot have.
+    pub fn allow_legacy_unframed(self) -> Self {
+        Self {
+            allow_legacy_unframed: true,
+            ..self
+        }
+    }
+
+    /// Requires the serialized objects to carry the given parameter fingerprint.
+    ///
+    /// The objects must have been serialized by a config created with
+    /// [`SerializationConfig::with_parameter_fingerprint`] and the same fingerprint. Objects
+    /// without fingerprint or with another fingerprint are rejected with
+    /// [`SafeSerializationError::ParameterMismatch`] before being deserialized.
+    pub fn with_expected_parameter_fingerprint(self, fingerprint: ParameterFingerprint) -> Self {
+        Self {
+            expected_parameter_fingerprint: Some(fingerprint),
+            ..self
+        }
+    }
+
+    /// Calls `callback` with the number of bytes read so far and the total number of bytes if it
+    /// is known, so that the progress of the deserialization of large objects such as server keys
+    /// can be displayed.
+    ///
+    /// The callback is called every MiB and once the object is read. The total number of bytes is
+    /// only known when reading from a file with `deserialize_from_file`. Returning
+    /// [`ControlFlow::Break`] from the callback cancels the deserialization, which then returns an
+    /// error, so the callback can be used to enforce a timeout. The progress is reported by
+    /// [`Self::deserialize_from`] and [`Self::deserialize_vec_from`].
+    pub fn with_progress(
+        self,
+        callback: &'a (dyn Fn(u64, Option<u64>) -> ControlFlow<()> + Send + Sync),
+    ) -> Self {
+        Self {
+            progress: Some(Progress {
+                callback,
+                total: None,
+            }),
+            ..self
+        }
+    }
+
+    /// Requires the serialized objects to be signed, and checks their signature with the
+    /// provided verifier.
+    ///
+    /// The objects must have been serialized by a config created with
+    /// [`SerializationConfig::with_signer`]. The header and the serialized object are read in
+    /// memory and their signature is checked before the object is decompressed, decrypted or
+    /// parsed. Only the header, whose size is bounded, is parsed before the signature check.
+    pub fn with_verifier(self, verifier: &'a dyn SerializationVerifier) -> Self {
+        Self {
+            verifier: Some(verifier),
+            ..self
+        }
+    }
+
+    /// Rejects the objects having a collection (sequence, map, string or bytes) of more than
+    /// `max_length` elements.
+    ///
+    /// The length is checked as soon as it is decoded, before memory is allocated for the
+    /// elements, see [`Self::new_strict`]. This is not supported with the CBOR format.
+    pub fn with_max_collection_length(self, max_length: u64) -> Self {
+        Self {
+            collection_limits: CollectionLimits {
+                max_collection_length: max_length,
+                ..self.collection_limits
+            },
+            ..self
+        }
+    }
+
+    /// Rejects the objects whose collections, structs and enums are nested more than
+    /// `max_depth` times in each other.
+    ///
+    /// This is not supported with the CBOR format.
+    pub fn with_max_nesting_depth(self, max_depth: u32) -> Self {
+        Self {
+            collection_limits: CollectionLimits {
+                max_nesting_depth: max_depth,
+                ..self.collection_limits
+            },
+            ..self
+        }
+    }
+
+    /// Disables the conformance check on an existing config.
+    pub fn disable_conformance(self) -> NonConformantDeserializationConfig<'a> {
+        NonConformantDeserializationConfig {
+            serialized_size_limit: self.serialized_size_limit,
+            validate_header: self.validate_header,
+            allow_legacy_unframed: self.allow_legacy_unframed,
+            require_checksum: self.require_checksum,
+            format: self.format,
+            verifier: self.verifier,
+            expected_parameter_fingerprint: self.expected_parameter_fingerprint,
+            progress: self.progress,
+            collection_limits: self.collection_limits,
+            #[cfg(feature = "aead")]
+            decryption_key: self.decryption_key,
+        }
+    }
+
+    /// Deserializes an object serialized by [`SerializationConfig::serialize_into`] from a
+    /// [reader](std::io::Read). Performs various sanity checks based on the deserialization config.
+    pub fn deserialize_from<T: DeserializeOwned + Unversionize + Named + ParameterSetConformant>(
+        self,
+        reader: impl std::io::Read,
+        parameter_set: &T::ParameterSet,
+    ) -> Result<T, SafeSerializationError> {
+        self.deserialize_from_with_metadata(reader, parameter_set)
+            .map(|(object, _)| object)
+    }
+
+    /// Deserializes an object like [`Self::deserialize_from`], and returns it along with the
+    /// metadata attached to it by [`SerializationConfig::with_metadata`].
+    ///
+    /// The metadata is empty if none was attached to the object.
+    pub fn deserialize_from_with_metadata<
+        T: DeserializeOwned + Unversionize + Named + ParameterSetConformant,
+    >(
+        self,
+        reader: impl std::io::Read,
+        parameter_set: &T::ParameterSet,
+    ) -> Result<(T, BTreeMap<String, String>), SafeSerializationError> {
+        let (deser, metadata): (T, _) = self
+            .disable_conformance()
+            .deserialize_from_with_metadata(reader)?;
+        if !deser.is_conformant(parameter_set) {
+            return Err(SafeSerializationError::ConformanceFailure {
+                type_name: T::NAME.to_string(),
+            });
+        }
+
+        Ok((deser, metadata))
+    }
+
+    /// Deserializes an object serialized by [`SerializationConfig::serialize_detached_into`],
+    /// given its header and its payload read from two separate [readers](std::io::Read). Performs
+    /// various sanity checks based on the deserialization config.
+    pub fn deserialize_detached_from<
+        T: DeserializeOwned + Unversionize + Named + ParameterSetConformant,
+    >(
+        self,
+        header_reader: impl std::io::Read,
+        payload_reader: impl std::io::Read,
+        parameter_set: &T::ParameterSet,
+    ) -> Result<T, SafeSerializationError> {
+        let deser: T = self
+            .disable_conformance()
+            .deserialize_detached_from(header_reader, payload_reader)?;
+        if !deser.is_conformant(parameter_set) {
+            return Err(SafeSerializationError::ConformanceFailure {
+                type_name: T::NAME.to_string(),
+            });
+        }
+
+        Ok(deser)
+    }
+
+    /// Deserializes a collection serialized by [`SerializationConfig::serialize_slice_into`] from
+    /// a [reader](std::io::Read). Performs various sanity checks based on the deserialization
+    /// config, and checks the conformance of each element.
+    pub fn deserialize_vec_from<
+        T: DeserializeOwned + Unversionize + Named + ParameterSetConformant,
+    >(
+        self,
+        reader: impl std::io::Read,
+        parameter_set: &T::ParameterSet,
+    ) -> Result<Vec<T>, SafeSerializationError> {
+        let deser: Vec<T> = self.disable_conformance().deserialize_vec_from(reader)?;
+        if !deser
+            .iter()
+            .all(|object| object.is_conformant(parameter_set))
+        {
+            return Err(SafeSerializationError::ConformanceFailure {
+                type_name: T::NAME.to_string(),
+            });
+        }
+
+        Ok(deser)
+    }
+}
+
+/// Location of an entry in an archive
+#[derive(Serialize, Deserialize, Clone)]
+// This type should not be versioned because it is part of a wrapper of versioned messages.
+#[cfg_attr(tfhe_lints, allow(tfhe_lints::serialize_without_versionize))]
+struct ArchiveEntry {
+    name: String,
+    type_name: String,
+    offset: u64,
+    length: u64,
+}
+
+/// Index written at the start of an archive, listing its entries
+#[derive(Serialize, Deserialize)]
+// This type should not be versioned because it is part of a wrapper of versioned messages.
+#[cfg_attr(tfhe_lints, allow(tfhe_lints::serialize_without_versionize))]
+struct ArchiveIndex {
+    archive_version: Cow<'static, str>,
+    entries: Vec<ArchiveEntry>,
+}
+
+/// Builds an archive holding multiple named objects in a single stream.
+///
+/// Each entry is serialized independently with the [`SerializationConfig`] of the builder, and
+/// thus has its own header and versioning. An index of the entries is written at the start of the
+/// archive so that a single entry can be extracted with an [`ArchiveReader`] without reading the
+/// others.
+///
+/// The entries are kept in memory until the archive is written.
+///
+/// # Example
+///
+/// ```rust
+/// use std::io::Cursor;
+/// use tfhe::safe_serialization::{
+///     ArchiveReader, DeserializationConfig, SerializationConfig, SerializedArchiveBuilder,
+/// };
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+/// use tfhe::shortint::{gen_keys, Ciphertext};
+///
+/// let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+/// let conformance_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param();
+///
+/// let mut builder = SerializedArchiveBuilder::new(SerializationConfig::new(1 << 20));
+/// builder.add("a", &ck.encrypt(1)).unwrap();
+/// builder.add("b", &ck.encrypt(2)).unwrap();
+///
+/// let mut buffer = vec![];
+/// builder.write_into(&mut buffer).unwrap();
+///
+/// let mut reader = ArchiveReader::new(Cursor::new(buffer)).unwrap();
+/// let b: Ciphertext = reader
+///     .deserialize_entry(
+///         "b",
+///         DeserializationConfig::new(1 << 20),
+///         &conformance_params,
+///     )
+///     .unwrap();
+/// assert_eq!(ck.decrypt(&b), 2);
+/// ```
+pub struct SerializedArchiveBuilder {
+    config: SerializationConfig,
+    entries: Vec<ArchiveEntry>,
+    data: Vec<u8>,
+}
+
+impl SerializedArchiveBuilder {
+    /// Creates an empty archive, whose entries will be serialized with the provided config
+    pub fn new(config: SerializationConfig) -> Self {
+        Self {
+            config,
+            entries: Vec::new(),
+            data: Vec::new(),
+        }
+    }
+
+    /// Serializes an object and adds it to the archive under the given name.
+    ///
+    /// Returns an error if the serialization fails or if an entry with the same name already
+    /// exists.
+    pub fn add<T: Serialize + Versionize + Named>(
+        &mut self,
+        name: impl Into<String>,
+        object: &T,
+    ) -> bincode::Result<&mut Self> {
+        let name = name.into();
+        if self.entries.iter().any(|entry| entry.name == name) {
+            return Err(Box::new(bincode::ErrorKind::Custom(format!(
+                "The archive already has an entry named {name}"
+            ))));
+        }
+
+        let offset = self.data.len() as u64;
+        self.config.clone().serialize_into(object, &mut self.data)?;
+
+        self.entries.push(ArchiveEntry {
+            name,
+            type_name: T::NAME.to_string(),
+            offset,
+            length: self.data.len() as u64 - offset,
+        });
+
+        Ok(self)
+    }
+
+    /// Writes the archive into a [writer](std::io::Write).
+    /// The written bytes can be read using an [`ArchiveReader`].
+    pub fn write_into(&self, mut writer: impl std::io::Write) -> bincode::Result<()> {
+        let index = ArchiveIndex {
+            archive_version: Cow::Borrowed(ARCHIVE_VERSION),
+            entries: self.entries.clone(),
+        };
+
+        bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .with_limit(ARCHIVE_INDEX_LENGTH_LIMIT)
+            .serialize_into(&mut writer, &index)?;
+
+        writer.write_all(&self.data)?;
+        Ok(())
+    }
+}
+
+/// Reads the entries of an archive written by [`SerializedArchiveBuilder::write_into`].
+///
+/// Only the index of the archive is read on creation, the entries are read on demand by seeking
+/// to their position.
+pub struct ArchiveReader<R> {
+    reader: R,
+    entries: Vec<ArchiveEntry>,
+    /// Position of the first entry in the reader
+    data_start: u64,
+}
+
+impl<R: std::io::Read + std::io::Seek> ArchiveReader<R> {
+    /// Reads the index of the archive starting at the current position of the reader
+    pub fn new(mut reader: R) -> Result<Self, SafeSerializationError> {
+        let index: ArchiveIndex = bincode::DefaultOptions::new()
             .with_fixint_encoding()
-            .with_limit(0);
+            .with_limit(ARCHIVE_INDEX_LENGTH_LIMIT)
+            .deserialize_from(&mut reader)
+            .map_err(|err| SafeSerializationError::from_bincode(err, ARCHIVE_INDEX_LENGTH_LIMIT))?;
+
+        if index.archive_version != ARCHIVE_VERSION {
+            return Err(SafeSerializationError::VersionMismatch {
+                expected: ARCHIVE_VERSION.to_string(),
+                found: index.archive_version.to_string(),
+            });
+        }
+
+        let data_start = reader.stream_position()?;
+
+        Ok(Self {
+            reader,
+            entries: index.entries,
+            data_start,
+        })
+    }
+
+    /// Returns the names of the entries in the archive, in the order they were added
+    pub fn entry_names(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|entry| entry.name.as_str())
+    }
+
+    /// Returns the name of the type of an entry, as given by [`Named::NAME`], if the entry exists
+    pub fn entry_type_name(&self, name: &str) -> Option<&str> {
+        self.find_entry(name).map(|entry| entry.type_name.as_str())
+    }
+
+    fn find_entry(&self, name: &str) -> Option<&ArchiveEntry> {
+        self.entries.iter().find(|entry| entry.name == name)
+    }
+
+    /// Returns a [reader](std::io::Read) over the serialized data of an entry.
+    ///
+    /// The returned reader can be given to [`DeserializationConfig::deserialize_from`] or
+    /// [`NonConformantDeserializationConfig::deserialize_from`].
+    pub fn entry_reader(
+        &mut self,
+        name: &str,
+    ) -> Result<std::io::Take<&mut R>, SafeSerializationError> {
+        let entry = self.find_entry(name).ok_or_else(|| {
+            SafeSerializationError::Message(format!("The archive has no entry named {name}"))
+        })?;
+        let (offset, length) = (entry.offset, entry.length);
+
+        let position = self.data_start.checked_add(offset).ok_or_else(|| {
+            SafeSerializationError::Message(format!("Invalid offset {offset} for entry {name}"))
+        })?;
+        self.reader.seek(std::io::SeekFrom::Start(position))?;
+
+        Ok((&mut self.reader).take(length))
+    }
+
+    /// Deserializes an entry of the archive, with conformance checks
+    pub fn deserialize_entry<
+        T: DeserializeOwned + Unversionize + Named + ParameterSetConformant,
+    >(
+        &mut self,
+        name: &str,
+        config: DeserializationConfig<'_>,
+        parameter_set: &T::ParameterSet,
+    ) -> Result<T, SafeSerializationError> {
+        let reader = self.entry_reader(name)?;
+        config.deserialize_from(reader, parameter_set)
+    }
+}
+
+/// Serialize an object with the default configuration (with size limit and versioning).
+/// This is an alias for `SerializationConfig::new(serialized_size_limit).serialize_into`
+pub fn safe_serialize<T: Serialize + Versionize + Named>(
+    object: &T,
+    writer: impl std::io::Write,
+    serialized_size_limit: u64,
+) -> bincode::Result<()> {
+    SerializationConfig::new(serialized_size_limit).serialize_into(object, writer)
+}
+
+/// Serialize an object with the default configuration (with size limit, header check and
+/// versioning). This is an alias for
+/// `DeserializationConfig::new(serialized_size_limit).disable_conformance().deserialize_from`
+pub fn safe_deserialize<T: DeserializeOwned + Unversionize + Named>(
+    reader: impl std::io::Read,
+    serialized_size_limit: u64,
+) -> Result<T, SafeSerializationError> {
+    DeserializationConfig::new(serialized_size_limit)
+        .disable_conformance()
+        .deserialize_from(reader)
+}
+
+/// Serialize an object with the default configuration and conformance checks (with size limit,
+/// header check and versioning). This is an alias for
+/// `DeserializationConfig::new(serialized_size_limit).deserialize_from`
+pub fn safe_deserialize_conformant<
+    T: DeserializeOwned + Unversionize + Named + ParameterSetConformant,
+>(
+    reader: impl std::io::Read,
+    serialized_size_limit: u64,
+    parameter_set: &T::ParameterSet,
+) -> Result<T, SafeSerializationError> {
+    DeserializationConfig::new(serialized_size_limit).deserialize_from(reader, parameter_set)
+}
+
+/// Upgrades an object serialized by any previous version of *TFHE-rs* to the current version.
+///
+/// The object is deserialized, which upgrades it to its latest version through the
+/// backward compatibility mechanism of `tfhe-versionable`, and is serialized again with the
+/// default configuration. The metadata attached to the object is kept. This allows to upgrade
+/// stored objects once, offline, instead of upgrading them each time they are loaded.
+///
+/// `serialized_size_limit` applies to both the old and the new object. Only versioned objects
+/// can be migrated, unversioned data can only be loaded by the version that wrote it.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::safe_serialization::{migrate, safe_deserialize, safe_serialize};
+/// use tfhe::{generate_keys, ConfigBuilder, FheUint8};
+///
+/// let (client_key, _server_key) = generate_keys(ConfigBuilder::default());
+///
+/// // Data that may have been written by an older version
+/// let mut old = vec![];
+/// safe_serialize(&FheUint8::encrypt(42u8, &client_key), &mut old, 1 << 20).unwrap();
+///
+/// let mut new = vec![];
+/// migrate::<FheUint8>(old.as_slice(), &mut new, 1 << 20).unwrap();
+///
+/// let ct: FheUint8 = safe_deserialize(new.as_slice(), 1 << 20).unwrap();
+/// let dec: u8 = ct.decrypt(&client_key);
+/// assert_eq!(dec, 42);
+/// ```
+pub fn migrate<T>(
+    reader: impl std::io::Read,
+    writer: impl std::io::Write,
+    serialized_size_limit: u64,
+) -> Result<(), SafeSerializationError>
+where
+    T: Serialize + DeserializeOwned + Versionize + Unversionize + Named,
+{
+    let (object, metadata): (T, _) = DeserializationConfig::new(serialized_size_limit)
+        .disable_conformance()
+        .deserialize_from_with_metadata(reader)?;
+
+    SerializationConfig::new(serialized_size_limit)
+        .with_metadata(metadata)
+        .serialize_into(&object, writer)
+        .map_err(|err| SafeSerializationError::from_bincode(err, serialized_size_limit))
+}
+
+/// Information on a serialized object, read from its header by [`inspect_header`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
+pub struct SerializedObjectInfo {
+    type_name: String,
+    header_version: String,
+    versioning_version: Option<String>,
+    crate_version: Option<String>,
+    min_reader_version: Option<String>,
+    compressed: bool,
+    encrypted: bool,
+    has_checksum: bool,
+    frame_size: Option<u64>,
+    collection: bool,
+    #[cfg_attr(
+        feature = "json",
+        serde(serialize_with = "json::serialize_fingerprint_as_hex")
+    )]
+    parameter_fingerprint: Option<ParameterFingerprint>,
+    metadata: BTreeMap<String, String>,
+}
+
+impl SerializedObjectInfo {
+    /// Name of the type of the object, as given by [`Named::NAME`]
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    /// Version of the serialization scheme of the header
+    pub fn header_version(&self) -> &str {
+        &self.header_version
+    }
+
+    /// Whether the object was serialized with versioning information
+    pub fn is_versioned(&self) -> bool {
+        self.versioning_version.is_some()
+    }
+
+    /// Version of the versioning scheme, for versioned objects
+    pub fn versioning_version(&self) -> Option<&str> {
+        self.versioning_version.as_deref()
+    }
+
+    /// Version of *TFHE-rs* the object was serialized with, for unversioned objects
+    pub fn crate_version(&self) -> Option<&str> {
+        self.crate_version.as_deref()
+    }
+
+    /// Oldest version of *TFHE-rs* able to read the object, if it was recorded in the header.
+    ///
+    /// Objects without this information can be read by any version supporting their versioning
+    /// scheme. Headers requiring a more recent version than the current one cannot be inspected.
+    pub fn min_reader_version(&self) -> Option<&str> {
+        self.min_reader_version.as_deref()
+    }
+
+    /// Whether the object is compressed, see [`SerializationConfig::with_compression`]
+    pub fn is_compressed(&self) -> bool {
+        self.compressed
+    }
+
+    /// Whether the object is encrypted, see `SerializationConfig::with_encryption`
+    pub fn is_encrypted(&self) -> bool {
+        self.encrypted
+    }
+
+    /// Whether the object is protected by a checksum, see [`SerializationConfig::with_checksum`]
+    pub fn has_checksum(&self) -> bool {
+        self.has_checksum
+    }
+
+    /// Maximum size in bytes of the frames of the object, if it was serialized with
+    /// [`SerializationConfig::with_chunked_streaming`]
+    pub fn frame_size(&self) -> Option<u64> {
+        self.frame_size
+    }
+
+    /// Whether the header is followed by a collection of objects, see
+    /// [`SerializationConfig::serialize_slice_into`]
+    pub fn is_collection(&self) -> bool {
+        self.collection
+    }
+
+    /// Fingerprint of the parameters of the object, see
+    /// [`SerializationConfig::with_parameter_fingerprint`]
+    pub fn parameter_fingerprint(&self) -> Option<ParameterFingerprint> {
+        self.parameter_fingerprint
+    }
+
+    /// User defined metadata attached to the object, see [`SerializationConfig::with_metadata`]
+    pub fn metadata(&self) -> &BTreeMap<String, String> {
+        &self.metadata
+    }
+}
+
+/// Reads the header of an object serialized by [`SerializationConfig::serialize_into`] with the
+/// default [`SerializationFormat`], without deserializing the object itself.
+///
+/// This allows to cheaply check or route serialized data before deserializing it. The reading of
+/// the header is limited in size, but its content is not validated, and nothing guarantees that
+/// the object that follows can be deserialized.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::safe_serialization::{inspect_header, SerializationConfig};
+/// use tfhe::shortint::gen_keys;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+///
+/// let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+/// let ct = ck.encrypt(1);
+///
+/// let mut buffer = vec![];
+/// SerializationConfig::new(1 << 20)
+///     .serialize_into(&ct, &mut buffer)
+///     .unwrap();
+///
+/// let info = inspect_header(buffer.as_slice()).unwrap();
+/// assert_eq!(info.type_name(), "shortint::Ciphertext");
+/// assert!(info.is_versioned());
+/// ```
+pub fn inspect_header(
+    reader: impl std::io::Read,
+) -> Result<SerializedObjectInfo, SafeSerializationError> {
+    inspect_header_with_format(reader, SerializationFormat::default())
+}
+
+/// Reads the header of an object serialized with the given [`SerializationFormat`], see
+/// [`inspect_header`].
+pub fn inspect_header_with_format(
+    reader: impl std::io::Read,
+    format: SerializationFormat,
+) -> Result<SerializedObjectInfo, SafeSerializationError> {
+    let header = SerializationHeader::deserialize_from(reader, format, HEADER_LENGTH_LIMIT)?;
+
+    let (versioning_version, crate_version) = match &header.versioning_mode {
+        SerializationVersioningMode::Versioned { versioning_version } => {
+            (Some(versioning_version.to_string()), None)
+        }
+        SerializationVersioningMode::Unversioned { crate_version } => {
+            (None, Some(crate_version.to_string()))
+        }
+    };
+
+    Ok(SerializedObjectInfo {
+        type_name: header.name.to_string(),
+        header_version: header.header_version.to_string(),
+        versioning_version,
+        crate_version,
+        min_reader_version: header.min_reader_version.as_deref().map(str::to_string),
+        compressed: header.compression().is_some(),
+        encrypted: header.encryption().is_some(),
+        has_checksum: header.checksum().is_some(),
+        frame_size: header.frame_layout().map(|layout| layout.frame_size),
+        collection: header.is_collection(),
+        parameter_fingerprint: header.parameter_fingerprint(),
+        metadata: header.metadata().cloned().unwrap_or_default(),
+    })
+}
+
+#[cfg(all(test, feature = "shortint"))]
+mod test_shortint {
+    use crate::safe_serialization::{
+        DeserializationConfig, SerializationConfig, SerializationSigner, SerializationVerifier,
+        HEADER_LENGTH_LIMIT,
+    };
+    use crate::shortint::parameters::{
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS, PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    };
+    use crate::shortint::{gen_keys, Ciphertext};
+    use std::sync::Arc;
+
+    #[test]
+    fn safe_deserialization_ct() {
+        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+        let msg = 2_u64;
+
+        let ct = ck.encrypt(msg);
+
+        let mut buffer = vec![];
+
+        SerializationConfig::new(1 << 20)
+            .disable_versioning()
+            .serialize_into(&ct, &mut buffer)
+            .unwrap();
+
+        assert!(DeserializationConfig::new(1 << 20)
+            .deserialize_from::<Ciphertext>(
+                buffer.as_slice(),
+                &PARAM_MESSAGE_3_CARRY_3_KS_PBS.to_shortint_conformance_param()
+            )
+            .is_err());
+
+        let ct2 = DeserializationConfig::new(1 << 20)
+            .deserialize_from::<Ciphertext>(
+                buffer.as_slice(),
+                &PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param(),
+            )
+            .unwrap();
+
+        let dec = ck.decrypt(&ct2);
+        assert_eq!(msg, dec);
+    }
+
+    #[test]
+    fn safe_deserialization_ct_versioned() {
+        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+        let msg = 2_u64;
+
+        let ct = ck.encrypt(msg);
+
+        let mut buffer = vec![];
+
+        SerializationConfig::new(1 << 20)
+            .serialize_into(&ct, &mut buffer)
+            .unwrap();
+
+        assert!(DeserializationConfig::new(1 << 20,)
+            .deserialize_from::<Ciphertext>(
+                buffer.as_slice(),
+                &PARAM_MESSAGE_3_CARRY_3_KS_PBS.to_shortint_conformance_param()
+            )
+            .is_err());
+
+        let ct2 = DeserializationConfig::new(1 << 20)
+            .deserialize_from::<Ciphertext>(
+                buffer.as_slice(),
+                &PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param(),
+            )
+            .unwrap();
+
+        let dec = ck.decrypt(&ct2);
+        assert_eq!(msg, dec);
+    }
+
+    /// Toy signature scheme used to test the signing hooks, NOT secure
+    struct ToyKey(u8);
+
+    impl ToyKey {
+        fn signature(&self, message: &[u8]) -> Vec<u8> {
+            let checksum = message
+                .iter()
+                .fold(self.0 as u64, |acc, &byte| acc.rotate_left(5) ^ byte as u64);
+            checksum.to_le_bytes().to_vec()
+        }
+    }
+
+    impl SerializationSigner for ToyKey {
+        fn sign(&self, message: &[u8]) -> Result<Vec<u8>, String> {
+            Ok(self.signature(message))
+        }
+    }
+
+    impl SerializationVerifier for ToyKey {
+        fn verify(&self, message: &[u8], signature: &[u8]) -> Result<(), String> {
+            if self.signature(message) == signature {
+                Ok(())
+            } else {
+                Err("signature mismatch".to_string())
+            }
+        }
+    }
+
+    #[test]
+    fn safe_deserialization_ct_signed() {
+        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let conformance_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param();
+
+        let msg = 2_u64;
+
+        let ct = ck.encrypt(msg);
+
+        let mut buffer = vec![];
+
+        SerializationConfig::new(1 << 20)
+            .with_signer(Arc::new(ToyKey(42)))
+            .serialize_into(&ct, &mut buffer)
+            .unwrap();
+
+        let verifier = ToyKey(42);
+        let config = DeserializationConfig::new(1 << 20).with_verifier(&verifier);
+        let ct2 = config
+            .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params)
+            .unwrap();
+        assert_eq!(msg, ck.decrypt(&ct2));
+
+        // The config is Copy, it can be used again
+        let ct2 = config
+            .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params)
+            .unwrap();
+        assert_eq!(msg, ck.decrypt(&ct2));
+
+        // The signature is detached, so the object can still be read without verification
+        let ct2 = DeserializationConfig::new(1 << 20)
+            .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params)
+            .unwrap();
+        assert_eq!(msg, ck.decrypt(&ct2));
+
+        // Wrong key
+        assert!(DeserializationConfig::new(1 << 20)
+            .with_verifier(&ToyKey(43))
+            .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params)
+            .is_err());
+
+        // Tampered data
+        let mut tampered = buffer.clone();
+        let idx = tampered.len() / 2;
+        tampered[idx] ^= 1;
+        assert!(DeserializationConfig::new(1 << 20)
+            .with_verifier(&ToyKey(42))
+            .deserialize_from::<Ciphertext>(tampered.as_slice(), &conformance_params)
+            .is_err());
+
+        // Truncated payload, detected before the payload is parsed
+        assert!(config
+            .deserialize_from::<Ciphertext>(&buffer[..buffer.len() / 2], &conformance_params)
+            .is_err());
+
+        // Missing signature
+        let mut unsigned = vec![];
+        SerializationConfig::new(1 << 20)
+            .serialize_into(&ct, &mut unsigned)
+            .unwrap();
+        assert!(DeserializationConfig::new(1 << 20)
+            .with_verifier(&ToyKey(42))
+            .deserialize_from::<Ciphertext>(unsigned.as_slice(), &conformance_params)
+            .is_err());
+    }
+
+    #[test]
+    fn safe_deserialization_ct_formats() {
+        use crate::safe_serialization::SerializationFormat;
+
+        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let conformance_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param();
+
+        let msg = 2_u64;
+
+        let ct = ck.encrypt(msg);
+
+        for format in [
+            SerializationFormat::Bincode,
+            #[cfg(feature = "cbor")]
+            SerializationFormat::Cbor,
+            #[cfg(feature = "postcard")]
+            SerializationFormat::Postcard,
+        ] {
+            for versioned in [true, false] {
+                let mut config = SerializationConfig::new(1 << 20).with_format(format);
+                if !versioned {
+                    config = config.disable_versioning();
+                }
+
+                let mut buffer = vec![];
+                config.serialize_into(&ct, &mut buffer).unwrap();
+
+                let ct2 = DeserializationConfig::new(1 << 20)
+                    .with_format(format)
+                    .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params)
+                    .unwrap();
+
+                let dec = ck.decrypt(&ct2);
+                assert_eq!(msg, dec);
+
+                // The size limit is enforced whatever the format
+                assert!(SerializationConfig::new(100)
+                    .with_format(format)
+                    .serialize_into(&ct, &mut vec![])
+                    .is_err());
+                assert!(DeserializationConfig::new(HEADER_LENGTH_LIMIT + 100)
+                    .with_format(format)
+                    .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params)
+                    .is_err());
+
+                // Strict configs are rejected with CBOR rather than being silently relaxed
+                let strict_result =
+                    DeserializationConfig::new_strict::<Ciphertext>(&conformance_params)
+                        .with_format(format)
+                        .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params);
+                #[cfg(feature = "cbor")]
+                if format == SerializationFormat::Cbor {
+                    assert!(strict_result.is_err());
+                    continue;
+                }
+                assert_eq!(ck.decrypt(&strict_result.unwrap()), msg);
+            }
+        }
+    }
+
+    #[test]
+    fn safe_deserialization_ct_chunked() {
+        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let conformance_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param();
+
+        let msg = 2_u64;
+
+        let ct = ck.encrypt(msg);
+
+        for frame_size in [1, 100, 1 << 20] {
+            let mut buffer = vec![];
+            SerializationConfig::new(1 << 20)
+                .with_chunked_streaming(frame_size)
+                .serialize_into(&ct, &mut buffer)
+                .unwrap();
+
+            let ct2 = DeserializationConfig::new(1 << 20)
+                .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params)
+                .unwrap();
+            assert_eq!(msg, ck.decrypt(&ct2));
+
+            // The size limit is enforced while the frames are read
+            assert!(DeserializationConfig::new(HEADER_LENGTH_LIMIT + 100)
+                .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params)
+                .is_err());
+
+            // Truncated data, without the end marker, is detected
+            assert!(DeserializationConfig::new(1 << 20)
+                .deserialize_from::<Ciphertext>(&buffer[..buffer.len() - 1], &conformance_params)
+                .is_err());
+        }
+
+        // A frame larger than the frame size of the header is rejected
+        let frame_size = 100;
+        let mut buffer = vec![];
+        let config = SerializationConfig::new(1 << 20).with_chunked_streaming(frame_size);
+        config.serialize_into(&ct, &mut buffer).unwrap();
+        let object_size = config.serialized_object_size(&ct).unwrap();
+        let framed_size = object_size + 4 * object_size.div_ceil(frame_size) + 4;
+        let first_frame = buffer.len() - framed_size as usize;
+        assert_eq!(
+            buffer[first_frame..first_frame + 4],
+            (frame_size as u32).to_le_bytes()
+        );
+        buffer[first_frame..first_frame + 4]
+            .copy_from_slice(&(frame_size as u32 + 1).to_le_bytes());
+        assert!(DeserializationConfig::new(1 << 20)
+            .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params)
+            .is_err());
+
+        // Chunked streaming can be combined with a signature
+        let mut signed = vec![];
+        SerializationConfig::new(1 << 20)
+            .with_chunked_streaming(100)
+            .with_signer(Arc::new(ToyKey(42)))
+            .serialize_into(&ct, &mut signed)
+            .unwrap();
+        let ct2 = DeserializationConfig::new(1 << 20)
+            .with_verifier(&ToyKey(42))
+            .deserialize_from::<Ciphertext>(signed.as_slice(), &conformance_params)
+            .unwrap();
+        assert_eq!(msg, ck.decrypt(&ct2));
+    }
+
+    #[cfg(feature = "checksum")]
+    #[test]
+    fn safe_deserialization_ct_checksum() {
+        use crate::safe_serialization::ChecksumAlgorithm;
+
+        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let conformance_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param();
+
+        let msg = 2_u64;
+
+        let ct = ck.encrypt(msg);
+
+        for algorithm in [ChecksumAlgorithm::Crc32, ChecksumAlgorithm::Sha256] {
+            for config in [
+                SerializationConfig::new(1 << 20),
+                SerializationConfig::new(1 << 20).disable_versioning(),
+                SerializationConfig::new(1 << 20).with_chunked_streaming(100),
+            ] {
+                let mut buffer = vec![];
+                config
+                    .with_checksum(algorithm)
+                    .serialize_into(&ct, &mut buffer)
+                    .unwrap();
+
+                let ct2 = DeserializationConfig::new(1 << 20)
+                    .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params)
+                    .unwrap();
+                assert_eq!(msg, ck.decrypt(&ct2));
+
+                // A modification of the serialized data is detected
+                let last = buffer.len() - 1;
+                buffer[last] ^= 1;
+                assert!(DeserializationConfig::new(1 << 20)
+                    .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params)
+                    .is_err());
+            }
+        }
+
+        // Objects without checksum can be rejected
+        let mut buffer = vec![];
+        SerializationConfig::new(1 << 20)
+            .serialize_into(&ct, &mut buffer)
+            .unwrap();
+        assert!(DeserializationConfig::new(1 << 20)
+            .require_checksum()
+            .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params)
+            .is_err());
+    }
+
+    #[cfg(feature = "checksum")]
+    #[test]
+    fn safe_deserialization_inspect_header() {
+        use crate::safe_serialization::{inspect_header, ChecksumAlgorithm, CRATE_VERSION};
+
+        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+        let ct = ck.encrypt(2);
+
+        let mut buffer = vec![];
+        SerializationConfig::new(1 << 20)
+            .serialize_into(&ct, &mut buffer)
+            .unwrap();
+
+        let info = inspect_header(buffer.as_slice()).unwrap();
+        assert_eq!(info.type_name(), "shortint::Ciphertext");
+        assert!(info.is_versioned());
+        assert_eq!(info.crate_version(), None);
+        assert!(!info.is_compressed());
+        assert!(!info.has_checksum());
+        assert_eq!(info.frame_size(), None);
+
+        let mut buffer = vec![];
+        SerializationConfig::new(1 << 20)
+            .disable_versioning()
+            .with_checksum(ChecksumAlgorithm::Crc32)
+            .with_chunked_streaming(100)
+            .serialize_into(&ct, &mut buffer)
+            .unwrap();
+
+        let info = inspect_header(buffer.as_slice()).unwrap();
+        assert!(!info.is_versioned());
+        assert_eq!(info.crate_version(), Some(CRATE_VERSION));
+        assert!(info.has_checksum());
+        assert_eq!(info.frame_size(), Some(100));
+
+        // Only the header is needed
+        assert!(inspect_header(&buffer[..buffer.len() / 2]).is_ok());
+        assert!(inspect_header(&buffer[..4]).is_err());
+    }
+
+    #[test]
+    fn safe_serialization_ct_metadata() {
+        use crate::safe_serialization::inspect_header;
+        use std::collections::BTreeMap;
+
+        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let conformance_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param();
+
+        let ct = ck.encrypt(2);
+
+        let metadata = BTreeMap::from([
+            ("tenant".to_string(), "tenant-42".to_string()),
+            ("epoch".to_string(), "7".to_string()),
+        ]);
+
+        let mut buffer = vec![];
+        SerializationConfig::new(1 << 20)
+            .with_metadata(metadata.clone())
+            .serialize_into(&ct, &mut buffer)
+            .unwrap();
+
+        let info = inspect_header(buffer.as_slice()).unwrap();
+        assert_eq!(info.metadata(), &metadata);
+
+        let (ct2, metadata2): (Ciphertext, _) = DeserializationConfig::new(1 << 20)
+            .deserialize_from_with_metadata(buffer.as_slice(), &conformance_params)
+            .unwrap();
+        assert_eq!(metadata2, metadata);
+        assert_eq!(ck.decrypt(&ct2), 2);
+
+        // The metadata does not prevent a regular deserialization
+        let ct3: Ciphertext = DeserializationConfig::new(1 << 20)
+            .deserialize_from(buffer.as_slice(), &conformance_params)
+            .unwrap();
+        assert_eq!(ck.decrypt(&ct3), 2);
+
+        // Without metadata, the header is unchanged
+        let mut buffer = vec![];
+        SerializationConfig::new(1 << 20)
+            .serialize_into(&ct, &mut buffer)
+            .unwrap();
+
+        let info = inspect_header(buffer.as_slice()).unwrap();
+        assert!(info.metadata().is_empty());
+        assert_eq!(info.header_version(), super::SERIALIZATION_VERSION);
+
+        // The metadata must fit in the header
+        let metadata = BTreeMap::from([("large".to_string(), "a".repeat(2000))]);
+        assert!(SerializationConfig::new(1 << 20)
+            .with_metadata(metadata)
+            .serialize_into(&ct, &mut vec![])
+            .is_err());
+    }
+
+    #[test]
+    fn safe_serialization_ct_migration() {
+        use crate::safe_serialization::{inspect_header, migrate, SafeSerializationError};
+        use crate::shortint::noise_squashing::SquashedNoiseCiphertext;
+        use std::collections::BTreeMap;
+
+        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let conformance_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param();
+
+        let ct = ck.encrypt(3);
+        let metadata = BTreeMap::from([("epoch".to_string(), "1".to_string())]);
+
+        let mut old = vec![];
+        SerializationConfig::new(1 << 20)
+            .with_metadata(metadata.clone())
+            .serialize_into(&ct, &mut old)
+            .unwrap();
+
+        let mut new = vec![];
+        migrate::<Ciphertext>(old.as_slice(), &mut new, 1 << 20).unwrap();
+
+        let info = inspect_header(new.as_slice()).unwrap();
+        assert_eq!(info.metadata(), &metadata);
+
+        let ct2: Ciphertext = DeserializationConfig::new(1 << 20)
+            .deserialize_from(new.as_slice(), &conformance_params)
+            .unwrap();
+        assert_eq!(ck.decrypt(&ct2), 3);
+
+        // The type must match
+        assert!(matches!(
+            migrate::<SquashedNoiseCiphertext>(old.as_slice(), &mut vec![], 1 << 20),
+            Err(SafeSerializationError::TypeMismatch { .. })
+        ));
+    }
+
+    #[cfg(feature = "checksum")]
+    #[test]
+    fn safe_deserialization_ct_detached() {
+        use crate::safe_serialization::{inspect_header, ChecksumAlgorithm};
+        use std::collections::BTreeMap;
+
+        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let conformance_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param();
+
+        let msg = 3_u64;
+        let ct = ck.encrypt(msg);
+        let metadata = BTreeMap::from([("owner".to_string(), "alice".to_string())]);
+
+        let config = SerializationConfig::new(1 << 20)
+            .with_checksum(ChecksumAlgorithm::Crc32)
+            .with_metadata(metadata.clone());
+
+        let mut header = vec![];
+        let mut payload = vec![];
+        config
+            .clone()
+            .serialize_detached_into(&ct, &mut header, &mut payload)
+            .unwrap();
+
+        // The header can be inspected on its own
+        let info = inspect_header(header.as_slice()).unwrap();
+        assert_eq!(info.metadata(), &metadata);
+
+        let ct2: Ciphertext = DeserializationConfig::new(1 << 20)
+            .deserialize_detached_from(header.as_slice(), payload.as_slice(), &conformance_params)
+            .unwrap();
+        assert_eq!(msg, ck.decrypt(&ct2));
+
+        // The concatenation of both parts is a regular serialized object
+        let mut buffer = vec![];
+        config.serialize_into(&ct, &mut buffer).unwrap();
+        assert_eq!(buffer, [header.as_slice(), payload.as_slice()].concat());
+
+        // The payload of another object does not match the header
+        let mut other_header = vec![];
+        let mut other_payload = vec![];
+        SerializationConfig::new(1 << 20)
+            .serialize_detached_into(&ck.encrypt(1), &mut other_header, &mut other_payload)
+            .unwrap();
+        assert!(DeserializationConfig::new(1 << 20)
+            .deserialize_detached_from::<Ciphertext>(
+                header.as_slice(),
+                other_payload.as_slice(),
+                &conformance_params
+            )
+            .is_err());
+
+        // The signature covers both parts
+        let mut header = vec![];
+        let mut payload = vec![];
+        SerializationConfig::new(1 << 20)
+            .with_signer(Arc::new(ToyKey(42)))
+            .serialize_detached_into(&ct, &mut header, &mut payload)
+            .unwrap();
+
+        let ct2: Ciphertext = DeserializationConfig::new(1 << 20)
+            .with_verifier(&ToyKey(42))
+            .deserialize_detached_from(header.as_slice(), payload.as_slice(), &conformance_params)
+            .unwrap();
+        assert_eq!(msg, ck.decrypt(&ct2));
+
+        assert!(DeserializationConfig::new(1 << 20)
+            .with_verifier(&ToyKey(42))
+            .deserialize_detached_from::<Ciphertext>(
+                other_header.as_slice(),
+                payload.as_slice(),
+                &conformance_params
+            )
+            .is_err());
+    }
+
+    #[cfg(feature = "checksum")]
+    #[test]
+    fn safe_deserialization_ct_vec() {
+        use crate::safe_serialization::{inspect_header, ChecksumAlgorithm};
+
+        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let conformance_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param();
+
+        let msgs = [0_u64, 1, 2, 3];
+        let cts = msgs.iter().map(|&msg| ck.encrypt(msg)).collect::<Vec<_>>();
+
+        let config = SerializationConfig::new(1 << 20).with_checksum(ChecksumAlgorithm::Sha256);
+
+        let mut buffer = vec![];
+        config
+            .clone()
+            .serialize_slice_into(&cts, &mut buffer)
+            .unwrap();
+
+        let info = inspect_header(buffer.as_slice()).unwrap();
+        assert!(info.is_collection());
+
+        // The header is only written once
+        let mut single_buffer = vec![];
+        config.serialize_into(&cts[0], &mut single_buffer).unwrap();
+        assert!(buffer.len() < cts.len() * single_buffer.len());
+
+        let cts2: Vec<Ciphertext> = DeserializationConfig::new(1 << 20)
+            .deserialize_vec_from(buffer.as_slice(), &conformance_params)
+            .unwrap();
+        let decrypted = cts2.iter().map(|ct| ck.decrypt(ct)).collect::<Vec<_>>();
+        assert_eq!(decrypted, msgs);
+
+        // Each element is checked for conformance
+        let other_conformance_params =
+            PARAM_MESSAGE_3_CARRY_3_KS_PBS.to_shortint_conformance_param();
+        assert!(DeserializationConfig::new(1 << 20)
+            .deserialize_vec_from::<Ciphertext>(buffer.as_slice(), &other_conformance_params)
+            .is_err());
+
+        // A collection and a single object cannot be read in place of each other
+        assert!(DeserializationConfig::new(1 << 20)
+            .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params)
+            .is_err());
+        assert!(DeserializationConfig::new(1 << 20)
+            .deserialize_vec_from::<Ciphertext>(single_buffer.as_slice(), &conformance_params)
+            .is_err());
+
+        // Corrupted elements are detected by the checksum
+        let last = buffer.len() - 1;
+        buffer[last] ^= 1;
+        assert!(DeserializationConfig::new(1 << 20)
+            .deserialize_vec_from::<Ciphertext>(buffer.as_slice(), &conformance_params)
+            .is_err());
+
+        // Signed collection
+        let mut buffer = vec![];
+        SerializationConfig::new(1 << 20)
+            .with_signer(Arc::new(ToyKey(42)))
+            .serialize_slice_into(&cts, &mut buffer)
+            .unwrap();
+
+        let cts2: Vec<Ciphertext> = DeserializationConfig::new(1 << 20)
+            .with_verifier(&ToyKey(42))
+            .deserialize_vec_from(buffer.as_slice(), &conformance_params)
+            .unwrap();
+        assert_eq!(cts2.len(), cts.len());
+
+        // Empty collection
+        let mut buffer = vec![];
+        SerializationConfig::new(1 << 20)
+            .serialize_slice_into::<Ciphertext>(&[], &mut buffer)
+            .unwrap();
+
+        let cts2: Vec<Ciphertext> = DeserializationConfig::new(1 << 20)
+            .deserialize_vec_from(buffer.as_slice(), &conformance_params)
+            .unwrap();
+        assert!(cts2.is_empty());
+    }
+
+    #[test]
+    fn safe_serialization_auto_limit() {
+        use crate::safe_serialization::{
+            recommended_size_limit, SafeSerializationError, SerializedSizeBound,
+            CIPHERTEXT_SIZE_LIMIT, SERVER_KEY_SIZE_LIMIT,
+        };
+        use crate::shortint::ciphertext::MaxDegree;
+        use crate::shortint::{PBSParameters, ServerKey};
+
+        let (ck, sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let conformance_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param();
+
+        let ct = ck.encrypt(3);
+
+        let ct_size = SerializationConfig::new(0)
+            .serialized_object_size(&ct)
+            .unwrap();
+        let ct_bound = Ciphertext::serialized_size_bound(&conformance_params);
+        assert!(ct_size <= ct_bound);
+        assert!(recommended_size_limit::<Ciphertext>(&conformance_params) < CIPHERTEXT_SIZE_LIMIT);
+
+        let sk_params: (PBSParameters, MaxDegree) = (
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS.into(),
+            MaxDegree::from_msg_carry_modulus(
+                PARAM_MESSAGE_2_CARRY_2_KS_PBS.message_modulus,
+                PARAM_MESSAGE_2_CARRY_2_KS_PBS.carry_modulus,
+            ),
+        );
+        let sk_size = SerializationConfig::new(0)
+            .serialized_object_size(&sk)
+            .unwrap();
+        let sk_bound = ServerKey::serialized_size_bound(&sk_params);
+        assert!(sk_size <= sk_bound);
+        assert!(recommended_size_limit::<ServerKey>(&sk_params) < SERVER_KEY_SIZE_LIMIT);
+
+        let mut buffer = vec![];
+        SerializationConfig::new_auto_limit::<Ciphertext>(&conformance_params)
+            .serialize_into(&ct, &mut buffer)
+            .unwrap();
+
+        let ct2: Ciphertext =
+            DeserializationConfig::new_auto_limit::<Ciphertext>(&conformance_params)
+                .deserialize_from(buffer.as_slice(), &conformance_params)
+                .unwrap();
+        assert_eq!(ck.decrypt(&ct2), 3);
+
+        // The limit derived from a smaller parameter set rejects the ciphertext
+        let mut small_params = conformance_params;
+        small_params.ct_params.lwe_dim.0 = 16;
+        assert!(matches!(
+            DeserializationConfig::new_auto_limit::<Ciphertext>(&small_params)
+                .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params),
+            Err(SafeSerializationError::SizeLimitExceeded { .. })
+        ));
+    }
+
+    #[test]
+    fn safe_deserialization_ct_strict() {
+        use crate::safe_serialization::{
+            recommended_max_collection_length, SafeSerializationError, CIPHERTEXT_SIZE_LIMIT,
+        };
+
+        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let conformance_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param();
+        let lwe_size = conformance_params.ct_params.lwe_dim.to_lwe_size().0 as u64;
+
+        assert!(recommended_max_collection_length::<Ciphertext>(&conformance_params) >= lwe_size);
+
+        let ct = ck.encrypt(3);
+
+        for versioned in [true, false] {
+            let config = SerializationConfig::new_auto_limit::<Ciphertext>(&conformance_params);
+            let config = if versioned {
+                config
+            } else {
+                config.disable_versioning()
+            };
+            let mut buffer = vec![];
+            config.serialize_into(&ct, &mut buffer).unwrap();
+
+            let ct2: Ciphertext =
+                DeserializationConfig::new_strict::<Ciphertext>(&conformance_params)
+                    .deserialize_from(buffer.as_slice(), &conformance_params)
+                    .unwrap();
+            assert_eq!(ck.decrypt(&ct2), 3);
+
+            // The ciphertext holds a collection of lwe_size elements
+            let ct2: Ciphertext = DeserializationConfig::new(CIPHERTEXT_SIZE_LIMIT)
+                .with_max_collection_length(lwe_size)
+                .deserialize_from(buffer.as_slice(), &conformance_params)
+                .unwrap();
+            assert_eq!(ck.decrypt(&ct2), 3);
+
+            assert!(matches!(
+                DeserializationConfig::new(CIPHERTEXT_SIZE_LIMIT)
+                    .with_max_collection_length(lwe_size - 1)
+                    .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params),
+                Err(SafeSerializationError::Bincode(_))
+            ));
+
+            assert!(matches!(
+                DeserializationConfig::new(CIPHERTEXT_SIZE_LIMIT)
+                    .with_max_nesting_depth(1)
+                    .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params),
+                Err(SafeSerializationError::Bincode(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn safe_deserialization_ct_legacy_unframed() {
+        use crate::safe_serialization::{SerializationFormat, SerializationHeader};
+        use std::borrow::Cow;
+
+        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let conformance_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param();
+
+        let msg = 2_u64;
+        let ct = ck.encrypt(msg);
+
+        let legacy_buffer = bincode::serialize(&ct).unwrap();
+
+        assert!(DeserializationConfig::new(1 << 20)
+            .deserialize_from::<Ciphertext>(legacy_buffer.as_slice(), &conformance_params)
+            .is_err());
+
+        let ct2: Ciphertext = DeserializationConfig::new(1 << 20)
+            .allow_legacy_unframed()
+            .deserialize_from(legacy_buffer.as_slice(), &conformance_params)
+            .unwrap();
+        assert_eq!(msg, ck.decrypt(&ct2));
+
+        // Data with a header is still accepted
+        let mut buffer = vec![];
+        SerializationConfig::new(1 << 20)
+            .serialize_into(&ct, &mut buffer)
+            .unwrap();
+
+        let ct2: Ciphertext = DeserializationConfig::new(1 << 20)
+            .allow_legacy_unframed()
+            .deserialize_from(buffer.as_slice(), &conformance_params)
+            .unwrap();
+        assert_eq!(msg, ck.decrypt(&ct2));
+
+        // Legacy objects are still checked for conformance and size
+        let other_conformance_params =
+            PARAM_MESSAGE_3_CARRY_3_KS_PBS.to_shortint_conformance_param();
+        assert!(DeserializationConfig::new(1 << 20)
+            .allow_legacy_unframed()
+            .deserialize_from::<Ciphertext>(legacy_buffer.as_slice(), &other_conformance_params)
+            .is_err());
+
+        assert!(DeserializationConfig::new(HEADER_LENGTH_LIMIT + 16)
+            .allow_legacy_unframed()
+            .deserialize_from::<Ciphertext>(legacy_buffer.as_slice(), &conformance_params)
+            .is_err());
+
+        assert!(DeserializationConfig::new_with_unlimited_size()
+            .allow_legacy_unframed()
+            .deserialize_from::<Ciphertext>(legacy_buffer.as_slice(), &conformance_params)
+            .is_err());
+
+        // The collection limits also apply to legacy objects
+        assert!(DeserializationConfig::new(1 << 20)
+            .allow_legacy_unframed()
+            .with_max_collection_length(16)
+            .deserialize_from::<Ciphertext>(legacy_buffer.as_slice(), &conformance_params)
+            .is_err());
+
+        // Legacy objects cannot be signed
+        assert!(DeserializationConfig::new(1 << 20)
+            .allow_legacy_unframed()
+            .with_verifier(&ToyKey(1))
+            .deserialize_from::<Ciphertext>(legacy_buffer.as_slice(), &conformance_params)
+            .is_err());
+
+        #[cfg(feature = "checksum")]
+        assert!(DeserializationConfig::new(1 << 20)
+            .allow_legacy_unframed()
+            .require_checksum()
+            .deserialize_from::<Ciphertext>(legacy_buffer.as_slice(), &conformance_params)
+            .is_err());
+
+        #[cfg(feature = "aead")]
+        assert!(DeserializationConfig::new(1 << 20)
+            .allow_legacy_unframed()
+            .with_decryption_key(&[7; 32])
+            .deserialize_from::<Ciphertext>(legacy_buffer.as_slice(), &conformance_params)
+            .is_err());
+
+        // Data starting with a header is not read as a legacy object, even if the rest of the
+        // header is invalid
+        let mut header = SerializationHeader::new_versioned::<Ciphertext>();
+        header.header_version = Cow::Borrowed("99.0");
+        let mut header_buffer = vec![];
+        header
+            .serialize_into(
+                &mut header_buffer,
+                SerializationFormat::Bincode,
+                HEADER_LENGTH_LIMIT,
+            )
+            .unwrap();
+        header_buffer.extend_from_slice(&legacy_buffer);
+
+        let err = DeserializationConfig::new(1 << 20)
+            .allow_legacy_unframed()
+            .deserialize_from::<Ciphertext>(header_buffer.as_slice(), &conformance_params)
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("newer version of TFHE-rs"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn safe_deserialization_ct_errors() {
+        use crate::safe_serialization::{safe_deserialize, SafeSerializationError, CRATE_VERSION};
+        use crate::shortint::noise_squashing::SquashedNoiseCiphertext;
+
+        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+        let ct = ck.encrypt(1);
+
+        let mut buffer = vec![];
+        SerializationConfig::new(1 << 20)
+            .serialize_into(&ct, &mut buffer)
+            .unwrap();
+
+        let err =
+            safe_deserialize::<SquashedNoiseCiphertext>(buffer.as_slice(), 1 << 20).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                SafeSerializationError::TypeMismatch { expected, found }
+                    if expected == "shortint::SquashedNoiseCiphertext"
+                        && found == "shortint::Ciphertext"
+            ),
+            "{err}"
+        );
+
+        let err = DeserializationConfig::new(1 << 20)
+            .deserialize_from::<Ciphertext>(
+                buffer.as_slice(),
+                &PARAM_MESSAGE_3_CARRY_3_KS_PBS.to_shortint_conformance_param(),
+            )
+            .unwrap_err();
+        assert!(
+            matches!(err, SafeSerializationError::ConformanceFailure { .. }),
+            "{err}"
+        );
+
+        let size_limit = HEADER_LENGTH_LIMIT + 10;
+        let err = safe_deserialize::<Ciphertext>(buffer.as_slice(), size_limit).unwrap_err();
+        assert!(
+            matches!(
+                err,
+                SafeSerializationError::SizeLimitExceeded { size_limit: 10 }
+            ),
+            "{err}"
+        );
+
+        let mut unversioned = vec![];
+        SerializationConfig::new(1 << 20)
+            .disable_versioning()
+            .serialize_into(&ct, &mut unversioned)
+            .unwrap();
+        // Pretend that the data was written by another version of TFHE-rs
+        let version_position = unversioned
+            .windows(CRATE_VERSION.len())
+            .position(|window| window == CRATE_VERSION.as_bytes())
+            .unwrap();
+        unversioned[version_position] = b'9';
+        let err = safe_deserialize::<Ciphertext>(unversioned.as_slice(), 1 << 20).unwrap_err();
+        assert!(
+            matches!(err, SafeSerializationError::VersionMismatch { .. }),
+            "{err}"
+        );
+
+        let err = safe_deserialize::<Ciphertext>(&buffer[..buffer.len() / 2], 1 << 20).unwrap_err();
+        assert!(matches!(err, SafeSerializationError::Io(_)), "{err}");
+    }
+
+    #[cfg(feature = "checksum")]
+    #[test]
+    fn safe_deserialization_min_reader_version() {
+        use crate::safe_serialization::{
+            inspect_header, safe_deserialize, ChecksumAlgorithm, SafeSerializationError,
+            SerializationFormat, SerializationHeader, MIN_READER_SERIALIZATION_VERSION,
+            SERIALIZATION_VERSION,
+        };
+        use std::borrow::Cow;
+
+        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+        let ct = ck.encrypt(1);
+
+        // Objects readable by all versions keep the original header
+        let mut buffer = vec![];
+        SerializationConfig::new(1 << 20)
+            .serialize_into(&ct, &mut buffer)
+            .unwrap();
+        let info = inspect_header(buffer.as_slice()).unwrap();
+        assert_eq!(info.header_version(), SERIALIZATION_VERSION);
+        assert_eq!(info.min_reader_version(), None);
+
+        // Extensions require a version that knows them
+        let mut buffer = vec![];
+        SerializationConfig::new(1 << 20)
+            .with_checksum(ChecksumAlgorithm::Crc32)
+            .serialize_into(&ct, &mut buffer)
+            .unwrap();
+        let info = inspect_header(buffer.as_slice()).unwrap();
+        assert_eq!(info.header_version(), MIN_READER_SERIALIZATION_VERSION);
+        assert_eq!(info.min_reader_version(), Some("0.8"));
+        let ct2: Ciphertext = safe_deserialize(buffer.as_slice(), 1 << 20).unwrap();
+        assert_eq!(ck.decrypt(&ct2), 1);
+
+        // Pretend that the object was written by a future version, with extensions unknown to
+        // this one
+        let mut header = SerializationHeader::new_versioned::<Ciphertext>();
+        header.header_version = Cow::Borrowed(MIN_READER_SERIALIZATION_VERSION);
+        header.min_reader_version = Some(Cow::Borrowed("99.0"));
+        let mut buffer = vec![];
+        header
+            .serialize_into(
+                &mut buffer,
+                SerializationFormat::Bincode,
+                HEADER_LENGTH_LIMIT,
+            )
+            .unwrap();
+        buffer.extend_from_slice(&[0xff; 32]);
+
+        let err = safe_deserialize::<Ciphertext>(buffer.as_slice(), 1 << 20).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                SafeSerializationError::ReaderVersionTooOld { required, .. } if required == "99.0"
+            ),
+            "{err}"
+        );
+        assert!(
+            err.to_string().contains("requires TFHE-rs >= 99.0"),
+            "{err}"
+        );
+        assert!(inspect_header(buffer.as_slice()).is_err());
+
+        // Unknown header versions are also reported
+        header.header_version = Cow::Borrowed("99.0");
+        let mut buffer = vec![];
+        header
+            .serialize_into(
+                &mut buffer,
+                SerializationFormat::Bincode,
+                HEADER_LENGTH_LIMIT,
+            )
+            .unwrap();
+        let err = safe_deserialize::<Ciphertext>(buffer.as_slice(), 1 << 20).unwrap_err();
+        assert!(
+            err.to_string().contains("newer version of TFHE-rs"),
+            "{err}"
+        );
+    }
+
+    #[cfg(feature = "aead")]
+    #[test]
+    fn safe_serialization_ct_encryption() {
+        use crate::safe_serialization::inspect_header;
+
+        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let conformance_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param();
+
+        let ct = ck.encrypt(2);
+
+        let key = [7u8; 32];
+        let other_key = [8u8; 32];
+
+        let mut buffer = vec![];
+        SerializationConfig::new(1 << 20)
+            .with_encryption(&key)
+            .serialize_into(&ct, &mut buffer)
+            .unwrap();
+
+        let info = inspect_header(buffer.as_slice()).unwrap();
+        assert!(info.is_encrypted());
+
+        let ct2: Ciphertext = DeserializationConfig::new(1 << 20)
+            .with_decryption_key(&key)
+            .deserialize_from(buffer.as_slice(), &conformance_params)
+            .unwrap();
+        assert_eq!(ck.decrypt(&ct2), 2);
+
+        // The key is required, and must be the right one
+        assert!(DeserializationConfig::new(1 << 20)
+            .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params)
+            .is_err());
+        assert!(DeserializationConfig::new(1 << 20)
+            .with_decryption_key(&other_key)
+            .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params)
+            .is_err());
+
+        // A modification of the encrypted object is detected
+        let last = buffer.len() - 1;
+        buffer[last] ^= 1;
+        assert!(DeserializationConfig::new(1 << 20)
+            .with_decryption_key(&key)
+            .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params)
+            .is_err());
+
+        // Unencrypted objects are rejected when a key is provided
+        let mut buffer = vec![];
+        SerializationConfig::new(1 << 20)
+            .serialize_into(&ct, &mut buffer)
+            .unwrap();
+        assert!(DeserializationConfig::new(1 << 20)
+            .with_decryption_key(&key)
+            .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params)
+            .is_err());
+    }
+
+    #[test]
+    fn safe_serialization_ct_size() {
+        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let conformance_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param();
+
+        let ct = ck.encrypt(2);
+
+        #[allow(unused_mut)]
+        let mut configs = vec![
+            SerializationConfig::new(1 << 20),
+            SerializationConfig::new(1 << 20).disable_versioning(),
+            SerializationConfig::new(1 << 20).with_chunked_streaming(1000),
+        ];
+        #[cfg(feature = "checksum")]
+        configs.push(
+            SerializationConfig::new(1 << 20)
+                .with_checksum(crate::safe_serialization::ChecksumAlgorithm::Sha256),
+        );
+
+        for config in configs {
+            let mut buffer = vec![];
+            config.clone().serialize_into(&ct, &mut buffer).unwrap();
+
+            assert_eq!(config.serialized_size(&ct).unwrap(), buffer.len() as u64);
+        }
+
+        // The object size is the smallest limit that allows to deserialize the object
+        let config = SerializationConfig::new(1 << 20);
+        let object_size = config.serialized_object_size(&ct).unwrap();
+
+        let mut buffer = vec![];
+        config.serialize_into(&ct, &mut buffer).unwrap();
+
+        assert!(DeserializationConfig::new(object_size)
+            .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params)
+            .is_ok());
+        assert!(DeserializationConfig::new(object_size - 1)
+            .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params)
+            .is_err());
+
+        // The size limit still applies
+        assert!(SerializationConfig::new(object_size - 1)
+            .serialized_size(&ct)
+            .is_err());
+    }
+
+    #[cfg(all(feature = "json", feature = "checksum"))]
+    #[test]
+    fn safe_serialization_ct_info_to_json_pretty() {
+        use crate::json::ToJsonPretty;
+        use crate::named::Named;
+        use crate::safe_serialization::{inspect_header, ParameterFingerprint};
+
+        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let fingerprint = ParameterFingerprint::of(&PARAM_MESSAGE_2_CARRY_2_KS_PBS).unwrap();
+
+        let ct = ck.encrypt(2);
+
+        let mut buffer = vec![];
+        SerializationConfig::new(1 << 20)
+            .with_parameter_fingerprint(fingerprint)
+            .serialize_into(&ct, &mut buffer)
+            .unwrap();
+
+        let json = inspect_header(buffer.as_slice())
+            .unwrap()
+            .to_json_pretty()
+            .unwrap();
+        let info: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(info["type_name"], Ciphertext::NAME);
+        assert_eq!(info["parameter_fingerprint"], fingerprint.to_string());
+
+        let json = PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_json_pretty().unwrap();
+        let params: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            params["log2_p_fail"],
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS.log2_p_fail
+        );
+        assert_eq!(params["message_modulus"], 4);
+    }
+
+    #[cfg(feature = "checksum")]
+    #[test]
+    fn safe_deserialization_ct_parameter_fingerprint() {
+        use crate::safe_serialization::{
+            inspect_header, ParameterFingerprint, SafeSerializationError,
+        };
+
+        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let conformance_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param();
+
+        let fingerprint = ParameterFingerprint::of(&PARAM_MESSAGE_2_CARRY_2_KS_PBS).unwrap();
+        let other_fingerprint = ParameterFingerprint::of(&PARAM_MESSAGE_3_CARRY_3_KS_PBS).unwrap();
+        assert_ne!(fingerprint, other_fingerprint);
+
+        let ct = ck.encrypt(2);
+
+        let mut buffer = vec![];
+        SerializationConfig::new(1 << 20)
+            .with_parameter_fingerprint(fingerprint)
+            .serialize_into(&ct, &mut buffer)
+            .unwrap();
+
+        assert_eq!(
+            inspect_header(buffer.as_slice())
+                .unwrap()
+                .parameter_fingerprint(),
+            Some(fingerprint)
+        );
+
+        let ct2: Ciphertext = DeserializationConfig::new(1 << 20)
+            .with_expected_parameter_fingerprint(fingerprint)
+            .deserialize_from(buffer.as_slice(), &conformance_params)
+            .unwrap();
+        assert_eq!(ck.decrypt(&ct2), 2);
+
+        // The fingerprint is only checked if one is expected
+        let ct2: Ciphertext = DeserializationConfig::new(1 << 20)
+            .deserialize_from(buffer.as_slice(), &conformance_params)
+            .unwrap();
+        assert_eq!(ck.decrypt(&ct2), 2);
+
+        let err = DeserializationConfig::new(1 << 20)
+            .with_expected_parameter_fingerprint(other_fingerprint)
+            .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            SafeSerializationError::ParameterMismatch {
+                found: Some(found),
+                ..
+            } if found == fingerprint
+        ));
+
+        // Objects without fingerprint are rejected when one is expected
+        let mut buffer = vec![];
+        SerializationConfig::new(1 << 20)
+            .serialize_into(&ct, &mut buffer)
+            .unwrap();
+
+        let err = DeserializationConfig::new(1 << 20)
+            .with_expected_parameter_fingerprint(fingerprint)
+            .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            SafeSerializationError::ParameterMismatch { found: None, .. }
+        ));
+    }
+
+    #[test]
+    fn safe_serialization_ct_progress() {
+        use std::ops::ControlFlow;
+        use std::sync::Mutex;
+
+        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let conformance_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param();
+
+        let ct = ck.encrypt(2);
+
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let reports_clone = reports.clone();
+        let mut buffer = vec![];
+        SerializationConfig::new(1 << 20)
+            .with_progress(move |done, total| {
+                reports_clone.lock().unwrap().push((done, total));
+                ControlFlow::Continue(())
+            })
+            .serialize_into(&ct, &mut buffer)
+            .unwrap();
+
+        let len = buffer.len() as u64;
+        assert_eq!(reports.lock().unwrap().last(), Some(&(len, Some(len))));
+
+        let reports = Mutex::new(Vec::new());
+        let ct2: Ciphertext = DeserializationConfig::new(1 << 20)
+            .with_progress(&|done, total| {
+                reports.lock().unwrap().push((done, total));
+                ControlFlow::Continue(())
+            })
+            .deserialize_from(buffer.as_slice(), &conformance_params)
+            .unwrap();
+
+        assert_eq!(ck.decrypt(&ct2), 2);
+        assert_eq!(reports.lock().unwrap().last(), Some(&(len, None)));
+
+        // The callback can cancel the operations
+        assert!(SerializationConfig::new(1 << 20)
+            .with_progress(|_, _| ControlFlow::Break(()))
+            .serialize_into(&ct, &mut vec![])
+            .is_err());
+        assert!(DeserializationConfig::new(1 << 20)
+            .with_progress(&|_, _| ControlFlow::Break(()))
+            .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params)
+            .is_err());
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn safe_deserialization_ct_mmap() {
+        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let conformance_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param();
+
+        let msg = 2_u64;
+
+        let ct = ck.encrypt(msg);
+
+        let path = std::env::temp_dir().join(format!(
+            "tfhe_safe_deserialization_ct_mmap_{}",
+            std::process::id()
+        ));
+
+        let mut buffer = vec![];
+        SerializationConfig::new(1 << 20)
+            .serialize_into(&ct, &mut buffer)
+            .unwrap();
+        std::fs::write(&path, &buffer).unwrap();
+
+        let ct2: Result<Ciphertext, _> =
+            DeserializationConfig::new(1 << 20).deserialize_from_file(&path, &conformance_params);
+
+        let ct3: Result<Ciphertext, _> = DeserializationConfig::new(HEADER_LENGTH_LIMIT + 100)
+            .deserialize_from_file(&path, &conformance_params);
+
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(msg, ck.decrypt(&ct2.unwrap()));
+        assert!(ct3.is_err());
+
+        assert!(DeserializationConfig::new(1 << 20)
+            .deserialize_from_file::<Ciphertext>(&path, &conformance_params)
+            .is_err());
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn safe_deserialization_ct_async() {
+        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let conformance_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param();
+
+        let msg = 2_u64;
+
+        let ct = Arc::new(ck.encrypt(msg));
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        runtime.block_on(async {
+            let buffer = SerializationConfig::new(1 << 20)
+                .serialize_into_async(ct.clone(), Vec::new())
+                .await
+                .unwrap();
+
+            // The async serialization produces the same data as the sync one
+            let mut sync_buffer = vec![];
+            SerializationConfig::new(1 << 20)
+                .serialize_into(ct.as_ref(), &mut sync_buffer)
+                .unwrap();
+            assert_eq!(buffer, sync_buffer);
+
+            let ct2: Ciphertext = DeserializationConfig::new(1 << 20)
+                .deserialize_from_async(std::io::Cursor::new(buffer.clone()), &conformance_params)
+                .await
+                .unwrap();
+            assert_eq!(msg, ck.decrypt(&ct2));
+
+            assert!(DeserializationConfig::new(HEADER_LENGTH_LIMIT + 100)
+                .deserialize_from_async::<Ciphertext, _>(
+                    std::io::Cursor::new(buffer),
+                    &conformance_params
+                )
+                .await
+                .is_err());
+        });
+    }
+
+    #[test]
+    fn safe_deserialization_archive() {
+        use crate::safe_serialization::{ArchiveReader, SerializedArchiveBuilder};
+        use std::io::Cursor;
+
+        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let conformance_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param();
 
-        let header = self.create_header::<T>();
-        options
-            .with_limit(self.header_length_limit())
-            .serialize_into(&mut writer, &header)?;
+        let mut builder = SerializedArchiveBuilder::new(SerializationConfig::new(1 << 20));
+        builder
+            .add("ct_1", &ck.encrypt(1))
+            .unwrap()
+            .add("ct_2", &ck.encrypt(2))
+            .unwrap();
+        assert!(builder.add("ct_1", &ck.encrypt(3)).is_err());
 
-        match self.versioned {
-            SerializationVersioningMode::Versioned { .. } => options
-                .with_limit(self.serialized_size_limit)
-                .serialize_into(&mut writer, &object.versionize())?,
-            SerializationVersioningMode::Unversioned { .. } => options
-                .with_limit(self.serialized_size_limit)
-                .serialize_into(&mut writer, &object)?,
-        };
+        let mut buffer = vec![];
+        builder.write_into(&mut buffer).unwrap();
 
-        Ok(())
+        let mut reader = ArchiveReader::new(Cursor::new(buffer)).unwrap();
+        assert_eq!(reader.entry_names().collect::<Vec<_>>(), ["ct_1", "ct_2"]);
+        assert_eq!(reader.entry_type_name("ct_1"), Some("shortint::Ciphertext"));
+
+        // Entries can be read in any order
+        for (name, msg) in [("ct_2", 2), ("ct_1", 1), ("ct_2", 2)] {
+            let ct: Ciphertext = reader
+                .deserialize_entry(
+                    name,
+                    DeserializationConfig::new(1 << 20),
+                    &conformance_params,
+                )
+                .unwrap();
+            assert_eq!(ck.decrypt(&ct), msg);
+        }
+
+        assert!(reader
+            .deserialize_entry::<Ciphertext>(
+                "ct_3",
+                DeserializationConfig::new(1 << 20),
+                &conformance_params
+            )
+            .is_err());
+
+        // The raw entry can be read with any deserialization config
+        let ct: Ciphertext = DeserializationConfig::new(1 << 20)
+            .disable_conformance()
+            .deserialize_from(reader.entry_reader("ct_1").unwrap())
+            .unwrap();
+        assert_eq!(ck.decrypt(&ct), 1);
     }
-}
 
-/// A configuration used to Serialize *TFHE-rs* objects. This configuration decides
-/// the various sanity checks that will be performed during deserialization.
-#[derive(Copy, Clone)]
-pub struct DeserializationConfig {
-    serialized_size_limit: u64,
-    validate_header: bool,
-}
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn safe_deserialization_ct_compressed() {
+        use crate::safe_serialization::CompressionLevel;
 
-/// A configuration used to Serialize *TFHE-rs* objects. This is similar to
-/// [`DeserializationConfig`] but it will not require conformance parameters.
-///
-/// This type should be created with [`DeserializationConfig::disable_conformance`]
-#[derive(Copy, Clone)]
-pub struct NonConformantDeserializationConfig {
-    serialized_size_limit: u64,
-    validate_header: bool,
-}
+        let (ck, sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let conformance_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param();
 
-impl NonConformantDeserializationConfig {
-    /// Deserializes an object serialized by [`SerializationConfig::serialize_into`] from a
-    /// [reader](std::io::Read). Performs various sanity checks based on the deserialization config,
-    /// but skips conformance checks.
-    pub fn deserialize_from<T: DeserializeOwned + Unversionize + Named>(
-        self,
-        mut reader: impl std::io::Read,
-    ) -> Result<T, String> {
-        if self.serialized_size_limit != 0 && self.serialized_size_limit <= HEADER_LENGTH_LIMIT {
-            return Err(format!(
-                "The provided size limit is too small, provide a limit of at least \
-{HEADER_LENGTH_LIMIT} bytes"
-            ));
-        }
+        let msg = 2_u64;
 
-        let options = bincode::DefaultOptions::new()
-            .with_fixint_encoding()
-            .with_limit(0);
+        // The mask of an encrypted ciphertext is random and does not compress well, use a
+        // trivial one to check that the size is reduced
+        let ct = sk.create_trivial(msg);
 
-        let deserialized_header: SerializationHeader = options
-            .with_limit(self.header_length_limit())
-            .deserialize_from(&mut reader)
-            .map_err(|err| err.to_string())?;
+        let mut uncompressed = vec![];
+        SerializationConfig::new(1 << 20)
+            .serialize_into(&ct, &mut uncompressed)
+            .unwrap();
 
-        if self.validate_header {
-            deserialized_header.validate::<T>()?;
-        }
+        for level in [
+            CompressionLevel::FAST,
+            CompressionLevel::default(),
+            CompressionLevel::BEST,
+        ] {
+            let mut buffer = vec![];
+            SerializationConfig::new(1 << 20)
+                .with_compression(level)
+                .serialize_into(&ct, &mut buffer)
+                .unwrap();
+            assert!(buffer.len() < uncompressed.len());
 
-        match deserialized_header.versioning_mode {
-            SerializationVersioningMode::Versioned { .. } => {
-                let deser_versioned = options
-                    .with_limit(self.serialized_size_limit - self.header_length_limit())
-                    .deserialize_from(&mut reader)
-                    .map_err(|err| err.to_string())?;
+            let ct2 = DeserializationConfig::new(1 << 20)
+                .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params)
+                .unwrap();
 
-                T::unversionize(deser_versioned).map_err(|e| e.to_string())
-            }
-            SerializationVersioningMode::Unversioned { .. } => options
-                .with_limit(self.serialized_size_limit - self.header_length_limit())
-                .deserialize_from(&mut reader)
-                .map_err(|err| err.to_string()),
+            let dec = ck.decrypt(&ct2);
+            assert_eq!(msg, dec);
         }
-    }
 
-    /// Enables the conformance check on an existing config.
-    pub fn enable_conformance(self) -> DeserializationConfig {
-        DeserializationConfig {
-            serialized_size_limit: self.serialized_size_limit,
-            validate_header: self.validate_header,
-        }
-    }
+        // Compression and signature can be combined
+        let mut signed = vec![];
+        SerializationConfig::new(1 << 20)
+            .with_compression(CompressionLevel::default())
+            .with_signer(Arc::new(ToyKey(42)))
+            .serialize_into(&ct, &mut signed)
+            .unwrap();
 
-    fn header_length_limit(&self) -> u64 {
-        if self.serialized_size_limit == 0 {
-            0
-        } else {
-            HEADER_LENGTH_LIMIT
-        }
-    }
-}
+        let ct2 = DeserializationConfig::new(1 << 20)
+            .with_verifier(&ToyKey(42))
+            .deserialize_from::<Ciphertext>(signed.as_slice(), &conformance_params)
+            .unwrap();
+        assert_eq!(msg, ck.decrypt(&ct2));
 
-impl DeserializationConfig {
-    /// Creates a new deserialization config.
-    ///
-    /// By default, it will check that the serialization version and the name of the
-    /// deserialized type are correct.
-    /// `serialized_size_limit` is the size limit (in number of byte) of the serialized object
-    /// (excluding version and name serialization).
-    ///
-    /// It will also check that the object is conformant with the parameter set given in
-    /// `conformance_params`. Finally, it will check the compatibility of the loaded data with
-    /// the current *TFHE-rs* version.
-    pub fn new(serialized_size_limit: u64) -> Self {
-        Self {
-            serialized_size_limit,
-            validate_header: true,
-        }
+        // The size limit also applies to the decompressed object
+        let mut buffer = vec![];
+        SerializationConfig::new_with_unlimited_size()
+            .with_compression(CompressionLevel::default())
+            .serialize_into(&ct, &mut buffer)
+            .unwrap();
+        let small_limit = HEADER_LENGTH_LIMIT + (uncompressed.len() as u64 / 2);
+        assert!(DeserializationConfig::new(small_limit)
+            .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params)
+            .is_err());
     }
 
-    /// Creates a new config without any size limit for the deserialized objects.
-    pub fn new_with_unlimited_size() -> Self {
-        Self {
-            serialized_size_limit: 0,
-            validate_header: true,
-        }
-    }
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn safe_deserialization_ct_parallel_compression() {
+        use crate::safe_serialization::{inspect_header, CompressionLevel};
 
-    /// Disables the size limit for the serialized objects.
-    pub fn disable_size_limit(self) -> Self {
-        Self {
-            serialized_size_limit: 0,
-            ..self
-        }
-    }
+        let (ck, sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let conformance_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param();
 
-    /// Disables the header validation on the object. This header validations
-    /// checks that the serialized object is the one that is supposed to be loaded
-    /// and is compatible with this version of *TFHE-rs*.
-    pub fn disable_header_validation(self) -> Self {
-        Self {
-            validate_header: false,
-            ..self
-        }
-    }
+        let msg = 3_u64;
+        let ct = sk.create_trivial(msg);
 
-    /// Disables the conformance check on an existing config.
-    pub fn disable_conformance(self) -> NonConformantDeserializationConfig {
-        NonConformantDeserializationConfig {
-            serialized_size_limit: self.serialized_size_limit,
-            validate_header: self.validate_header,
-        }
-    }
+        let uncompressed_size = SerializationConfig::new(1 << 20)
+            .serialized_object_size(&ct)
+            .unwrap();
 
-    /// Deserializes an object serialized by [`SerializationConfig::serialize_into`] from a
-    /// [reader](std::io::Read). Performs various sanity checks based on the deserialization config.
-    pub fn deserialize_from<T: DeserializeOwned + Unversionize + Named + ParameterSetConformant>(
-        self,
-        reader: impl std::io::Read,
-        parameter_set: &T::ParameterSet,
-    ) -> Result<T, String> {
-        let deser: T = self.disable_conformance().deserialize_from(reader)?;
-        if !deser.is_conformant(parameter_set) {
-            return Err(format!(
-                "Deserialized object of type {} not conformant with given parameter set",
-                T::NAME
-            ));
+        // Segments smaller than, equal to and larger than the object
+        for segment_size in [1, 64, uncompressed_size, 1 << 20] {
+            let mut buffer = vec![];
+            SerializationConfig::new(1 << 20)
+                .with_parallel_compression(CompressionLevel::default(), segment_size)
+                .serialize_into(&ct, &mut buffer)
+                .unwrap();
+
+            let info = inspect_header(buffer.as_slice()).unwrap();
+            assert!(info.is_compressed());
+
+            let ct2 = DeserializationConfig::new(1 << 20)
+                .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params)
+                .unwrap();
+            assert_eq!(ct2, ct);
+            assert_eq!(msg, ck.decrypt(&ct2));
         }
 
-        Ok(deser)
+        // The size limit also applies to the decompressed object
+        let mut buffer = vec![];
+        SerializationConfig::new_with_unlimited_size()
+            .with_parallel_compression(CompressionLevel::default(), 64)
+            .serialize_into(&ct, &mut buffer)
+            .unwrap();
+        let small_limit = HEADER_LENGTH_LIMIT + (uncompressed_size / 2);
+        assert!(DeserializationConfig::new(small_limit)
+            .deserialize_from::<Ciphertext>(buffer.as_slice(), &conformance_params)
+            .is_err());
     }
 }
 
-/// Serialize an object with the default configuration (with size limit and versioning).
-/// This is an alias for `SerializationConfig::new(serialized_size_limit).serialize_into`
-pub fn safe_serialize<T: Serialize + Versionize + Named>(
-    object: &T,
-    writer: impl std::io::Write,
-    serialized_size_limit: u64,
-) -> bincode::Result<()> {
-    SerializationConfig::new(serialized_size_limit).serialize_into(object, writer)
-}
+#[cfg(all(test, feature = "boolean"))]
+mod test_boolean {
+    use crate::boolean::ciphertext::{Ciphertext, CompressedCiphertext};
+    use crate::boolean::client_key::ClientKey;
+    use crate::boolean::keycache::KEY_CACHE;
+    use crate::boolean::parameters::{
+        BooleanParameters, DEFAULT_PARAMETERS, DEFAULT_PARAMETERS_KS_PBS, TFHE_LIB_PARAMETERS,
+    };
+    use crate::boolean::public_key::{CompressedPublicKey, PublicKey};
+    use crate::boolean::server_key::{CompressedServerKey, ServerKey};
+    use crate::conformance::ParameterSetConformant;
+    use crate::safe_serialization::{
+        recommended_size_limit, safe_deserialize_conformant, safe_serialize, DeserializationConfig,
+        SerializationConfig,
+    };
 
-/// Serialize an object with the default configuration (with size limit, header check and
-/// versioning). This is an alias for
-/// `DeserializationConfig::new(serialized_size_limit).disable_conformance().deserialize_from`
-pub fn safe_deserialize<T: DeserializeOwned + Unversionize + Named>(
-    reader: impl std::io::Read,
-    serialized_size_limit: u64,
-) -> Result<T, String> {
-    DeserializationConfig::new(serialized_size_limit)
-        .disable_conformance()
-        .deserialize_from(reader)
-}
+    #[test]
+    fn safe_deserialization_boolean_ct() {
+        for (params, other_params) in [
+            (DEFAULT_PARAMETERS, TFHE_LIB_PARAMETERS),
+            (DEFAULT_PARAMETERS_KS_PBS, DEFAULT_PARAMETERS),
+        ] {
+            let keys = KEY_CACHE.get_from_param(params);
+            let ck = keys.client_key();
 
-/// Serialize an object with the default configuration and conformance checks (with size limit,
-/// header check and versioning). This is an alias for
-/// `DeserializationConfig::new(serialized_size_limit).deserialize_from`
-pub fn safe_deserialize_conformant<
-    T: DeserializeOwned + Unversionize + Named + ParameterSetConformant,
->(
-    reader: impl std::io::Read,
-    serialized_size_limit: u64,
-    parameter_set: &T::ParameterSet,
-) -> Result<T, String> {
-    DeserializationConfig::new(serialized_size_limit).deserialize_from(reader, parameter_set)
-}
+            for msg in [false, true] {
+                let ct = ck.encrypt(msg);
+                let size_limit = recommended_size_limit::<Ciphertext>(&params);
 
-#[cfg(all(test, feature = "shortint"))]
-mod test_shortint {
-    use crate::safe_serialization::{DeserializationConfig, SerializationConfig};
-    use crate::shortint::parameters::{
-        PARAM_MESSAGE_2_CARRY_2_KS_PBS, PARAM_MESSAGE_3_CARRY_3_KS_PBS,
-    };
-    use crate::shortint::{gen_keys, Ciphertext};
+                let mut buffer = vec![];
+                safe_serialize(&ct, &mut buffer, size_limit).unwrap();
 
-    #[test]
-    fn safe_deserialization_ct() {
-        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+                assert!(safe_deserialize_conformant::<Ciphertext>(
+                    buffer.as_slice(),
+                    size_limit,
+                    &other_params
+                )
+                .is_err());
 
-        let msg = 2_u64;
+                let ct2: Ciphertext =
+                    safe_deserialize_conformant(buffer.as_slice(), size_limit, &params).unwrap();
+                assert_eq!(ck.decrypt(&ct2), msg);
 
-        let ct = ck.encrypt(msg);
+                let compressed = ck.encrypt_compressed(msg);
+                let mut buffer = vec![];
+                safe_serialize(&compressed, &mut buffer, size_limit).unwrap();
+                let compressed2: CompressedCiphertext =
+                    safe_deserialize_conformant(buffer.as_slice(), size_limit, &params).unwrap();
+                assert_eq!(ck.decrypt(&compressed2.decompress()), msg);
+            }
 
-        let mut buffer = vec![];
+            // Trivial ciphertexts have no LWE to check
+            let trivial = Ciphertext::Trivial(true);
+            assert!(trivial.is_conformant(&other_params));
+        }
+    }
 
-        SerializationConfig::new(1 << 20)
-            .disable_versioning()
-            .serialize_into(&ct, &mut buffer)
+    #[test]
+    fn safe_deserialization_boolean_keys() {
+        let params: BooleanParameters = DEFAULT_PARAMETERS;
+        let keys = KEY_CACHE.get_from_param(params);
+        let (ck, sk) = (keys.client_key(), keys.server_key());
+
+        let mut buffer = vec![];
+        SerializationConfig::new_auto_limit::<ServerKey>(&params)
+            .serialize_into(sk, &mut buffer)
             .unwrap();
 
-        assert!(DeserializationConfig::new(1 << 20)
-            .deserialize_from::<Ciphertext>(
-                buffer.as_slice(),
-                &PARAM_MESSAGE_3_CARRY_3_KS_PBS.to_shortint_conformance_param()
-            )
+        assert!(DeserializationConfig::new_auto_limit::<ServerKey>(&params)
+            .deserialize_from::<ServerKey>(buffer.as_slice(), &TFHE_LIB_PARAMETERS)
             .is_err());
 
-        let ct2 = DeserializationConfig::new(1 << 20)
-            .deserialize_from::<Ciphertext>(
-                buffer.as_slice(),
-                &PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param(),
-            )
+        let sk2: ServerKey = DeserializationConfig::new_auto_limit::<ServerKey>(&params)
+            .deserialize_from(buffer.as_slice(), &params)
             .unwrap();
 
-        let dec = ck.decrypt(&ct2);
-        assert_eq!(msg, dec);
-    }
-
-    #[test]
-    fn safe_deserialization_ct_versioned() {
-        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let ct = ck.encrypt(true);
+        assert!(!ck.decrypt(&sk2.not(&ct)));
 
-        let msg = 2_u64;
+        let mut buffer = vec![];
+        safe_serialize(ck, &mut buffer, 1 << 20).unwrap();
 
-        let ct = ck.encrypt(msg);
+        assert!(safe_deserialize_conformant::<ClientKey>(
+            buffer.as_slice(),
+            1 << 20,
+            &TFHE_LIB_PARAMETERS
+        )
+        .is_err());
+        let ck2: ClientKey =
+            safe_deserialize_conformant(buffer.as_slice(), 1 << 20, &params).unwrap();
+        assert_eq!(&ck2, ck);
 
+        let compressed_sk = CompressedServerKey::new(ck);
         let mut buffer = vec![];
+        SerializationConfig::new_auto_limit::<CompressedServerKey>(&params)
+            .serialize_into(&compressed_sk, &mut buffer)
+            .unwrap();
 
-        SerializationConfig::new(1 << 20)
-            .serialize_into(&ct, &mut buffer)
+        assert!(
+            DeserializationConfig::new_auto_limit::<CompressedServerKey>(&params)
+                .deserialize_from::<CompressedServerKey>(buffer.as_slice(), &TFHE_LIB_PARAMETERS)
+                .is_err()
+        );
+
+        let compressed_sk2: CompressedServerKey =
+            DeserializationConfig::new_auto_limit::<CompressedServerKey>(&params)
+                .deserialize_from(buffer.as_slice(), &params)
+                .unwrap();
+        let sk2 = compressed_sk2.decompress();
+        assert!(sk2.is_conformant(&params));
+        assert!(!ck.decrypt(&sk2.not(&ct)));
+    }
+
+    #[test]
+    fn safe_deserialization_boolean_public_keys() {
+        let params: BooleanParameters = DEFAULT_PARAMETERS;
+        let keys = KEY_CACHE.get_from_param(params);
+        let ck = keys.client_key();
+
+        let pk = PublicKey::new(ck);
+        let mut buffer = vec![];
+        SerializationConfig::new_auto_limit::<PublicKey>(&params)
+            .serialize_into(&pk, &mut buffer)
             .unwrap();
 
-        assert!(DeserializationConfig::new(1 << 20,)
-            .deserialize_from::<Ciphertext>(
-                buffer.as_slice(),
-                &PARAM_MESSAGE_3_CARRY_3_KS_PBS.to_shortint_conformance_param()
-            )
+        assert!(DeserializationConfig::new_auto_limit::<PublicKey>(&params)
+            .deserialize_from::<PublicKey>(buffer.as_slice(), &TFHE_LIB_PARAMETERS)
             .is_err());
 
-        let ct2 = DeserializationConfig::new(1 << 20)
-            .deserialize_from::<Ciphertext>(
-                buffer.as_slice(),
-                &PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param(),
-            )
+        let pk2: PublicKey = DeserializationConfig::new_auto_limit::<PublicKey>(&params)
+            .deserialize_from(buffer.as_slice(), &params)
             .unwrap();
+        assert!(ck.decrypt(&pk2.encrypt(true)));
 
-        let dec = ck.decrypt(&ct2);
-        assert_eq!(msg, dec);
+        let compressed_pk = CompressedPublicKey::new(ck);
+        let mut buffer = vec![];
+        SerializationConfig::new_auto_limit::<CompressedPublicKey>(&params)
+            .serialize_into(&compressed_pk, &mut buffer)
+            .unwrap();
+
+        assert!(
+            DeserializationConfig::new_auto_limit::<CompressedPublicKey>(&params)
+                .deserialize_from::<CompressedPublicKey>(buffer.as_slice(), &TFHE_LIB_PARAMETERS)
+                .is_err()
+        );
+
+        let compressed_pk2: CompressedPublicKey =
+            DeserializationConfig::new_auto_limit::<CompressedPublicKey>(&params)
+                .deserialize_from(buffer.as_slice(), &params)
+                .unwrap();
+        assert!(!ck.decrypt(&compressed_pk2.encrypt(false)));
+        assert!(compressed_pk2.decompress().is_conformant(&params));
     }
 }
 
@@ -659,4 +5068,30 @@ mod test_integer {
 
         assert_eq!(&msg[..], &dec);
     }
+
+    #[cfg(feature = "aead")]
+    #[test]
+    fn safe_serialization_client_key_encryption() {
+        use crate::ClientKey;
+
+        let (client_key, _) = generate_keys(ConfigBuilder::default().build());
+        let ct = FheUint8::encrypt(42u8, &client_key);
+
+        let key = [3u8; 32];
+
+        let mut buffer = vec![];
+        SerializationConfig::new(1 << 30)
+            .with_encryption(&key)
+            .serialize_into(&client_key, &mut buffer)
+            .unwrap();
+
+        let client_key2: ClientKey = DeserializationConfig::new(1 << 30)
+            .with_decryption_key(&key)
+            .disable_conformance()
+            .deserialize_from(buffer.as_slice())
+            .unwrap();
+
+        let dec: u8 = ct.decrypt(&client_key2);
+        assert_eq!(dec, 42);
+    }
 }
@@ -0,0 +1,173 @@
+//! Encryption of the serialized objects with XChaCha20-Poly1305, see
+//! [`SerializationConfig::with_encryption`]
+use super::{
+    inspect_header, DeserializationConfig, NonConformantDeserializationConfig,
+    SafeSerializationError, SerializationConfig, SerializationEncryption, SerializationHeader,
+};
+use crate::named::Named;
+use bincode::Options;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tfhe_versionable::{Unversionize, Versionize};
+
+/// `ENCRYPTION_OVERHEAD` is the number of bytes added to an encrypted object: the length prefix of
+/// the encrypted data and the authentication tag.
+const ENCRYPTION_OVERHEAD: u64 = 8 + 16;
+
+/// Returns the size limit of an encrypted object, given the size limit of the object itself
+pub(super) fn encrypted_size_limit(serialized_size_limit: u64) -> u64 {
+    if serialized_size_limit == 0 {
+        0
+    } else {
+        serialized_size_limit + ENCRYPTION_OVERHEAD
+    }
+}
+
+impl SerializationEncryption {
+    /// Encrypts the payload with a random nonce. The type name of the object is authenticated
+    /// along with the payload, so that an encrypted object cannot be loaded as another type.
+    fn encrypt(key: &[u8; 32], type_name: &str, payload: &[u8]) -> Result<(Self, Vec<u8>), String> {
+        use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+        use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+        use rand_core::{OsRng, RngCore};
+
+        // Nonces of XChaCha20 are large enough to be drawn at random without risk of collision
+        let mut nonce = [0u8; 24];
+        OsRng.fill_bytes(&mut nonce);
+
+        let encrypted = XChaCha20Poly1305::new(Key::from_slice(key))
+            .encrypt(
+                XNonce::from_slice(&nonce),
+                Payload {
+                    msg: payload,
+                    aad: type_name.as_bytes(),
+                },
+            )
+            .map_err(|_| "Failed to encrypt the object".to_string())?;
+
+        Ok((Self::XChaCha20Poly1305 { nonce }, encrypted))
+    }
+
+    /// Decrypts and authenticates a payload encrypted by [`Self::encrypt`]
+    fn decrypt(
+        &self,
+        key: &[u8; 32],
+        type_name: &str,
+        encrypted: &[u8],
+    ) -> Result<Vec<u8>, String> {
+        use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+        use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+
+        match self {
+            Self::XChaCha20Poly1305 { nonce } => XChaCha20Poly1305::new(Key::from_slice(key))
+                .decrypt(
+                    XNonce::from_slice(nonce),
+                    Payload {
+                        msg: encrypted,
+                        aad: type_name.as_bytes(),
+                    },
+                )
+                .map_err(|_| {
+                    format!(
+                        "Failed to decrypt the {type_name}, the key is wrong or the data has been \
+modified"
+                    )
+                }),
+        }
+    }
+}
+
+impl SerializationConfig {
+    /// Encrypts the serialized objects with XChaCha20-Poly1305, using the provided 256 bits key.
+    ///
+    /// This protects objects stored at rest, such as client keys, against disclosure and
+    /// modification. A random nonce is drawn for each object and stored in the header along with
+    /// the cipher identifier. The objects can only be deserialized by a config created with
+    /// [`DeserializationConfig::with_decryption_key`] and the same key.
+    ///
+    /// The header is not encrypted, so the type and metadata of the objects can still be read
+    /// with [`inspect_header`]. When combined with compression, the object is compressed before
+    /// being encrypted.
+    ///
+    /// Note that the object is first encrypted in memory.
+    pub fn with_encryption(self, key: &[u8; 32]) -> Self {
+        Self {
+            encryption_key: Some(zeroize::Zeroizing::new(*key)),
+            ..self
+        }
+    }
+
+    /// Encrypts the object, or its compressed form if the config enables compression
+    pub(super) fn encrypt_object<T: Serialize + Versionize + Named>(
+        &self,
+        key: &[u8; 32],
+        object: &T,
+        compressed: Option<Vec<u8>>,
+    ) -> bincode::Result<(SerializationEncryption, Vec<u8>)> {
+        // The plaintext may be a secret key, it is zeroized once encrypted
+        let plaintext = zeroize::Zeroizing::new(match compressed {
+            Some(compressed) => bincode::DefaultOptions::new()
+                .with_fixint_encoding()
+                .with_limit(self.serialized_size_limit)
+                .serialize(&compressed)?,
+            None => {
+                let mut plaintext = Vec::new();
+                self.serialize_object(object, &mut plaintext)?;
+                plaintext
+            }
+        });
+
+        SerializationEncryption::encrypt(key, T::NAME, &plaintext)
+            .map_err(|err| Box::new(bincode::ErrorKind::Custom(err)))
+    }
+}
+
+impl<'a> DeserializationConfig<'a> {
+    /// Requires the serialized objects to be encrypted, and decrypts them with the provided key.
+    ///
+    /// The objects must have been serialized by a config created with
+    /// [`SerializationConfig::with_encryption`] and the same key. Objects that were modified or
+    /// encrypted with another key are rejected.
+    ///
+    /// The key is borrowed by the config, which does not keep a copy of it.
+    pub fn with_decryption_key(self, key: &'a [u8; 32]) -> Self {
+        Self {
+            decryption_key: Some(key),
+            ..self
+        }
+    }
+}
+
+impl NonConformantDeserializationConfig<'_> {
+    /// Decrypts the payload with the key of the config, then deserializes it
+    pub(super) fn deserialize_encrypted_payload<T: DeserializeOwned + Unversionize + Named>(
+        &self,
+        header: &SerializationHeader,
+        encryption: SerializationEncryption,
+        mut reader: impl std::io::Read,
+        object_size_limit: u64,
+    ) -> Result<T, SafeSerializationError> {
+        let Some(key) = self.decryption_key else {
+            return Err(SafeSerializationError::Message(format!(
+                "This {} is encrypted, please provide its key with \
+DeserializationConfig::with_decryption_key",
+                header.name
+            )));
+        };
+
+        let size_limit = encrypted_size_limit(object_size_limit);
+        let encrypted: Vec<u8> = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .with_limit(size_limit)
+            .deserialize_from(&mut reader)
+            .map_err(|err| SafeSerializationError::from_bincode(err, size_limit))?;
+
+        let plaintext = zeroize::Zeroizing::new(
+            encryption
+                .decrypt(key, &header.name, &encrypted)
+                .map_err(SafeSerializationError::Message)?,
+        );
+
+        self.deserialize_decrypted_payload(header, plaintext.as_slice(), object_size_limit)
+    }
+}
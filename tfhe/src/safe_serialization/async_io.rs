@@ -0,0 +1,96 @@
+//! Serialization into async writers and deserialization from async readers, running the
+//! synchronous (de)serialization on the blocking thread pool of the tokio runtime
+use super::{
+    DeserializationConfig, NonConformantDeserializationConfig, SafeSerializationError,
+    SerializationConfig,
+};
+use crate::conformance::ParameterSetConformant;
+use crate::named::Named;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::sync::Arc;
+use tfhe_versionable::{Unversionize, Versionize};
+
+impl SerializationConfig {
+    /// Serializes an object into an [async writer](tokio::io::AsyncWrite), based on the current
+    /// config. The written bytes can be deserialized using
+    /// [`DeserializationConfig::deserialize_from`] or
+    /// [`DeserializationConfig::deserialize_from_async`].
+    ///
+    /// The serialization is run on the blocking thread pool of the tokio runtime, and the data is
+    /// streamed to the writer as it is produced, without blocking the runtime. This must be
+    /// called from within a tokio runtime.
+    ///
+    /// The writer is returned once the object has been written and the writer flushed.
+    pub async fn serialize_into_async<T, W>(self, object: Arc<T>, writer: W) -> bincode::Result<W>
+    where
+        T: Serialize + Versionize + Named + Send + Sync + 'static,
+        W: tokio::io::AsyncWrite + Unpin + Send + 'static,
+    {
+        tokio::task::spawn_blocking(move || {
+            let mut bridge = tokio_util::io::SyncIoBridge::new(writer);
+            self.serialize_into(object.as_ref(), &mut bridge)?;
+            std::io::Write::flush(&mut bridge)?;
+            Ok(bridge.into_inner())
+        })
+        .await
+        .map_err(|err| {
+            Box::new(bincode::ErrorKind::Custom(format!(
+                "The serialization task failed: {err}"
+            )))
+        })?
+    }
+}
+
+impl NonConformantDeserializationConfig<'static> {
+    /// Deserializes an object serialized by [`SerializationConfig::serialize_into`] from an
+    /// [async reader](tokio::io::AsyncRead). Performs various sanity checks based on the
+    /// deserialization config, but skips conformance checks.
+    ///
+    /// The deserialization is run on the blocking thread pool of the tokio runtime, and the data
+    /// is read from the reader as it is needed, without blocking the runtime. This must be called
+    /// from within a tokio runtime. As the config is moved to the thread pool, what it borrows
+    /// must be `'static`.
+    pub async fn deserialize_from_async<T, R>(self, reader: R) -> Result<T, SafeSerializationError>
+    where
+        T: DeserializeOwned + Unversionize + Named + Send + 'static,
+        R: tokio::io::AsyncRead + Unpin + Send + 'static,
+    {
+        tokio::task::spawn_blocking(move || {
+            self.deserialize_from(tokio_util::io::SyncIoBridge::new(reader))
+        })
+        .await
+        .map_err(|err| {
+            SafeSerializationError::Message(format!("The deserialization task failed: {err}"))
+        })?
+    }
+}
+
+impl DeserializationConfig<'static> {
+    /// Deserializes an object serialized by [`SerializationConfig::serialize_into`] from an
+    /// [async reader](tokio::io::AsyncRead). Performs various sanity checks based on the
+    /// deserialization config.
+    ///
+    /// See [`NonConformantDeserializationConfig::deserialize_from_async`].
+    pub async fn deserialize_from_async<T, R>(
+        self,
+        reader: R,
+        parameter_set: &T::ParameterSet,
+    ) -> Result<T, SafeSerializationError>
+    where
+        T: DeserializeOwned + Unversionize + Named + ParameterSetConformant + Send + 'static,
+        R: tokio::io::AsyncRead + Unpin + Send + 'static,
+    {
+        let deser: T = self
+            .disable_conformance()
+            .deserialize_from_async(reader)
+            .await?;
+        if !deser.is_conformant(parameter_set) {
+            return Err(SafeSerializationError::ConformanceFailure {
+                type_name: T::NAME.to_string(),
+            });
+        }
+
+        Ok(deser)
+    }
+}
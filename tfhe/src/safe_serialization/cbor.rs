@@ -0,0 +1,26 @@
+//! Encoding of the header and the object with CBOR, see [`SerializationFormat::Cbor`]
+use super::{SafeSerializationError, SerializationFormat};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Serializes a value into the writer as a length prefixed CBOR item
+pub(super) fn serialize_into<T: Serialize + ?Sized>(
+    value: &T,
+    writer: impl std::io::Write,
+    size_limit: u64,
+) -> bincode::Result<()> {
+    let mut bytes = Vec::new();
+    ciborium::into_writer(value, &mut bytes)
+        .map_err(|err| bincode::ErrorKind::Custom(err.to_string()))?;
+    SerializationFormat::write_length_prefixed(&bytes, writer, size_limit)
+}
+
+/// Deserializes a value written by [`serialize_into`]
+pub(super) fn deserialize_from<T: DeserializeOwned>(
+    reader: impl std::io::Read,
+    size_limit: u64,
+) -> Result<T, SafeSerializationError> {
+    let bytes = SerializationFormat::read_length_prefixed(reader, size_limit)?;
+    ciborium::from_reader(bytes.as_slice())
+        .map_err(|err| SafeSerializationError::Message(err.to_string()))
+}
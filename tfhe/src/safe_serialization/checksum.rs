@@ -0,0 +1,98 @@
+//! Integrity checksums of the serialized objects and fingerprints of their parameters, computed
+//! with CRC32 or SHA-256.
+use super::{
+    ChecksumAlgorithm, DeserializationConfig, ParameterFingerprint, SerializationChecksum,
+    SerializationConfig,
+};
+use bincode::Options;
+use serde::Serialize;
+use sha2::Digest;
+
+impl ParameterFingerprint {
+    /// Computes the fingerprint of a set of parameters, as the SHA-256 hash of their bincode
+    /// serialization.
+    ///
+    /// The parameters can be any serializable value, such as the parameters of a key, or a tuple
+    /// of parameters and a key identifier.
+    pub fn of<P: Serialize + ?Sized>(parameters: &P) -> bincode::Result<Self> {
+        let mut hasher = sha2::Sha256::new();
+        hasher.update(b"tfhe-rs parameter fingerprint");
+        bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .serialize_into(HashWriter(&mut hasher), parameters)?;
+
+        Ok(Self(hasher.finalize().into()))
+    }
+}
+
+/// Writer that feeds the bytes to a SHA-256 hasher
+struct HashWriter<'a>(&'a mut sha2::Sha256);
+
+impl std::io::Write for HashWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Incremental computation of a [`SerializationChecksum`]
+pub(super) enum ChecksumHasher {
+    Crc32(crc32fast::Hasher),
+    Sha256(sha2::Sha256),
+}
+
+impl ChecksumHasher {
+    pub(super) fn new(algorithm: ChecksumAlgorithm) -> Result<Self, String> {
+        Ok(match algorithm {
+            ChecksumAlgorithm::Crc32 => Self::Crc32(crc32fast::Hasher::new()),
+            ChecksumAlgorithm::Sha256 => Self::Sha256(sha2::Sha256::new()),
+        })
+    }
+
+    pub(super) fn update(&mut self, bytes: &[u8]) {
+        match self {
+            Self::Crc32(hasher) => hasher.update(bytes),
+            Self::Sha256(hasher) => hasher.update(bytes),
+        }
+    }
+
+    pub(super) fn finalize(self) -> SerializationChecksum {
+        match self {
+            Self::Crc32(hasher) => SerializationChecksum::Crc32(hasher.finalize()),
+            Self::Sha256(hasher) => SerializationChecksum::Sha256(hasher.finalize().into()),
+        }
+    }
+}
+
+impl SerializationConfig {
+    /// Adds a checksum of the serialized objects to their header.
+    ///
+    /// The checksum is verified by [`DeserializationConfig::deserialize_from`], so that corrupted
+    /// data is rejected instead of being deserialized into wrong values.
+    ///
+    /// The object is traversed twice, once to compute its checksum and once to write it.
+    pub fn with_checksum(self, algorithm: ChecksumAlgorithm) -> Self {
+        Self {
+            checksum: Some(algorithm),
+            ..self
+        }
+    }
+}
+
+impl DeserializationConfig<'_> {
+    /// Requires the serialized objects to have a checksum, see
+    /// [`SerializationConfig::with_checksum`].
+    ///
+    /// The checksum of an object is always verified when it has one, this rejects the objects
+    /// that were serialized without it.
+    pub fn require_checksum(self) -> Self {
+        Self {
+            require_checksum: true,
+            ..self
+        }
+    }
+}
@@ -0,0 +1,14 @@
+//! Export of the information on serialized objects to JSON, see [`SerializedObjectInfo`]
+use super::{ParameterFingerprint, SerializedObjectInfo};
+use serde::Serialize;
+
+/// Exports the fingerprint as an hexadecimal string rather than an array of bytes, to match what
+/// is displayed
+pub(super) fn serialize_fingerprint_as_hex<S: serde::Serializer>(
+    fingerprint: &Option<ParameterFingerprint>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    fingerprint
+        .map(|fingerprint| fingerprint.to_string())
+        .serialize(serializer)
+}
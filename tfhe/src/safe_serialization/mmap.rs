@@ -0,0 +1,70 @@
+//! Deserialization from files mapped in memory
+use super::{
+    DeserializationConfig, NonConformantDeserializationConfig, SafeSerializationError,
+    SerializationConfig,
+};
+use crate::conformance::ParameterSetConformant;
+use crate::named::Named;
+use serde::de::DeserializeOwned;
+use tfhe_versionable::Unversionize;
+
+impl NonConformantDeserializationConfig<'_> {
+    /// Deserializes an object serialized by [`SerializationConfig::serialize_into`] from a file,
+    /// by mapping it in memory. Performs various sanity checks based on the deserialization
+    /// config, but skips conformance checks.
+    ///
+    /// The object is read directly from the mapped file instead of an intermediate buffer, so
+    /// loading a large key does not require twice its size in memory: the mapped pages are backed
+    /// by the file and can be reclaimed by the system at any time. The deserialized object still
+    /// owns its data.
+    ///
+    /// The file must not be modified while it is being deserialized.
+    pub fn deserialize_from_file<T: DeserializeOwned + Unversionize + Named>(
+        mut self,
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<T, SafeSerializationError> {
+        let mmap = map_file(path.as_ref())?;
+        if let Some(progress) = &mut self.progress {
+            progress.total = Some(mmap.len() as u64);
+        }
+        self.deserialize_from(&mmap[..])
+    }
+}
+
+impl DeserializationConfig<'_> {
+    /// Deserializes an object serialized by [`SerializationConfig::serialize_into`] from a file,
+    /// by mapping it in memory. Performs various sanity checks based on the deserialization config.
+    ///
+    /// See [`NonConformantDeserializationConfig::deserialize_from_file`].
+    pub fn deserialize_from_file<
+        T: DeserializeOwned + Unversionize + Named + ParameterSetConformant,
+    >(
+        self,
+        path: impl AsRef<std::path::Path>,
+        parameter_set: &T::ParameterSet,
+    ) -> Result<T, SafeSerializationError> {
+        let deser: T = self.disable_conformance().deserialize_from_file(path)?;
+        if !deser.is_conformant(parameter_set) {
+            return Err(SafeSerializationError::ConformanceFailure {
+                type_name: T::NAME.to_string(),
+            });
+        }
+
+        Ok(deser)
+    }
+}
+
+fn map_file(path: &std::path::Path) -> Result<memmap2::Mmap, SafeSerializationError> {
+    let file = std::fs::File::open(path)?;
+
+    // SAFETY: The mapping is only read during the deserialization, and is dropped right after it.
+    // The file must not be modified in the meantime, which is documented in the public functions.
+    let mmap = unsafe { memmap2::Mmap::map(&file) }?;
+
+    // The file is read once from start to end, this lets the system read ahead and release the
+    // pages that were already read. This is only a hint so a failure is not an error.
+    #[cfg(unix)]
+    let _ = mmap.advise(memmap2::Advice::Sequential);
+
+    Ok(mmap)
+}
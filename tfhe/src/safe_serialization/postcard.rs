@@ -0,0 +1,38 @@
+//! Encoding of the header and the object with postcard, see [`SerializationFormat::Postcard`]
+use super::strict::{CollectionLimits, StrictDeserializer};
+use super::{SafeSerializationError, SerializationFormat};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Serializes a value into the writer as a length prefixed postcard message
+pub(super) fn serialize_into<T: Serialize + ?Sized>(
+    value: &T,
+    writer: impl std::io::Write,
+    size_limit: u64,
+) -> bincode::Result<()> {
+    let bytes =
+        ::postcard::to_stdvec(value).map_err(|err| bincode::ErrorKind::Custom(err.to_string()))?;
+    SerializationFormat::write_length_prefixed(&bytes, writer, size_limit)
+}
+
+/// Deserializes a value written by [`serialize_into`]
+pub(super) fn deserialize_from<T: DeserializeOwned>(
+    reader: impl std::io::Read,
+    size_limit: u64,
+) -> Result<T, SafeSerializationError> {
+    let bytes = SerializationFormat::read_length_prefixed(reader, size_limit)?;
+    ::postcard::from_bytes(&bytes).map_err(|err| SafeSerializationError::Message(err.to_string()))
+}
+
+/// Deserializes a value written by [`serialize_into`], while checking that its collections respect
+/// `limits`
+pub(super) fn deserialize_with_limits_from<T: DeserializeOwned>(
+    reader: impl std::io::Read,
+    size_limit: u64,
+    limits: CollectionLimits,
+) -> Result<T, SafeSerializationError> {
+    let bytes = SerializationFormat::read_length_prefixed(reader, size_limit)?;
+    let mut deserializer = ::postcard::Deserializer::from_bytes(&bytes);
+    T::deserialize(StrictDeserializer::new(&mut deserializer, limits))
+        .map_err(|err| SafeSerializationError::Message(err.to_string()))
+}
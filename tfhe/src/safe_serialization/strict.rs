@@ -0,0 +1,467 @@
+//! A [Deserializer] wrapper that enforces limits on the collections while they are decoded.
+//!
+//! The length of a collection is checked as soon as the format announces it, before the
+//! deserialized type allocates memory for its elements. The elements are also counted, for the
+//! formats that do not announce the length of their collections.
+use serde::de::{
+    DeserializeSeed, Deserializer, EnumAccess, Error, MapAccess, SeqAccess, VariantAccess, Visitor,
+};
+use std::fmt;
+
+/// Limits on the collections of a deserialized object, `u64::MAX` and `u32::MAX` mean no limit
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(super) struct CollectionLimits {
+    /// Maximum number of elements of any collection (sequence, map, string or bytes)
+    pub(super) max_collection_length: u64,
+    /// Maximum number of collections, structs and enums nested in each other
+    pub(super) max_nesting_depth: u32,
+}
+
+impl Default for CollectionLimits {
+    fn default() -> Self {
+        Self {
+            max_collection_length: u64::MAX,
+            max_nesting_depth: u32::MAX,
+        }
+    }
+}
+
+impl CollectionLimits {
+    pub(super) fn is_unlimited(&self) -> bool {
+        *self == Self::default()
+    }
+
+    fn check_length<E: Error>(&self, length: usize) -> Result<(), E> {
+        if length as u64 > self.max_collection_length {
+            return Err(E::custom(format!(
+                "A collection of {length} elements exceeds the limit of {} elements",
+                self.max_collection_length
+            )));
+        }
+        Ok(())
+    }
+
+    fn check_depth<E: Error>(&self, depth: u32) -> Result<(), E> {
+        if depth > self.max_nesting_depth {
+            return Err(E::custom(format!(
+                "The object exceeds the nesting depth limit of {}",
+                self.max_nesting_depth
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Wraps a [Deserializer] so that the collections of the deserialized value respect `limits`
+pub(super) struct StrictDeserializer<D> {
+    inner: D,
+    limits: CollectionLimits,
+    depth: u32,
+}
+
+impl<D> StrictDeserializer<D> {
+    pub(super) fn new(inner: D, limits: CollectionLimits) -> Self {
+        Self {
+            inner,
+            limits,
+            depth: 0,
+        }
+    }
+}
+
+struct StrictVisitor<V> {
+    inner: V,
+    limits: CollectionLimits,
+    depth: u32,
+}
+
+impl<V> StrictVisitor<V> {
+    /// Limits are checked when the collection is entered, the nested values are one level deeper
+    fn enter<E: Error>(&self) -> Result<u32, E> {
+        let depth = self.depth + 1;
+        self.limits.check_depth::<E>(depth)?;
+        Ok(depth)
+    }
+}
+
+struct StrictSeed<S> {
+    inner: S,
+    limits: CollectionLimits,
+    depth: u32,
+}
+
+struct StrictAccess<A> {
+    inner: A,
+    limits: CollectionLimits,
+    depth: u32,
+    count: usize,
+}
+
+impl<A> StrictAccess<A> {
+    fn seed<S>(&self, inner: S) -> StrictSeed<S> {
+        StrictSeed {
+            inner,
+            limits: self.limits,
+            depth: self.depth,
+        }
+    }
+
+    fn count_element<E: Error>(&mut self) -> Result<(), E> {
+        self.count += 1;
+        self.limits.check_length(self.count)
+    }
+}
+
+macro_rules! forward_deserialize {
+    ($($method:ident $(($($arg:ident: $ty:ty),*))?),* $(,)?) => {
+        $(
+            fn $method<V>(self, $($($arg: $ty,)*)? visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'de>,
+            {
+                let visitor = StrictVisitor {
+                    inner: visitor,
+                    limits: self.limits,
+                    depth: self.depth,
+                };
+                self.inner.$method($($($arg,)*)? visitor)
+            }
+        )*
+    };
+}
+
+impl<'de, D> Deserializer<'de> for StrictDeserializer<D>
+where
+    D: Deserializer<'de>,
+{
+    type Error = D::Error;
+
+    forward_deserialize!(
+        deserialize_any,
+        deserialize_bool,
+        deserialize_i8,
+        deserialize_i16,
+        deserialize_i32,
+        deserialize_i64,
+        deserialize_i128,
+        deserialize_u8,
+        deserialize_u16,
+        deserialize_u32,
+        deserialize_u64,
+        deserialize_u128,
+        deserialize_f32,
+        deserialize_f64,
+        deserialize_char,
+        deserialize_str,
+        deserialize_string,
+        deserialize_bytes,
+        deserialize_byte_buf,
+        deserialize_option,
+        deserialize_unit,
+        deserialize_unit_struct(name: &'static str),
+        deserialize_newtype_struct(name: &'static str),
+        deserialize_seq,
+        deserialize_tuple(len: usize),
+        deserialize_tuple_struct(name: &'static str, len: usize),
+        deserialize_map,
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]),
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]),
+        deserialize_identifier,
+        deserialize_ignored_any,
+    );
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method<E>(self, v: $ty) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+impl<'de, V> Visitor<'de> for StrictVisitor<V>
+where
+    V: Visitor<'de>,
+{
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.inner.expecting(formatter)
+    }
+
+    forward_visit!(
+        visit_bool(bool),
+        visit_i8(i8),
+        visit_i16(i16),
+        visit_i32(i32),
+        visit_i64(i64),
+        visit_i128(i128),
+        visit_u8(u8),
+        visit_u16(u16),
+        visit_u32(u32),
+        visit_u64(u64),
+        visit_u128(u128),
+        visit_f32(f32),
+        visit_f64(f64),
+        visit_char(char),
+    );
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        self.limits.check_length::<E>(v.len())?;
+        self.inner.visit_str(v)
+    }
+
+    fn visit_borrowed_str<E: Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        self.limits.check_length::<E>(v.len())?;
+        self.inner.visit_borrowed_str(v)
+    }
+
+    fn visit_string<E: Error>(self, v: String) -> Result<Self::Value, E> {
+        self.limits.check_length::<E>(v.len())?;
+        self.inner.visit_string(v)
+    }
+
+    fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        self.limits.check_length::<E>(v.len())?;
+        self.inner.visit_bytes(v)
+    }
+
+    fn visit_borrowed_bytes<E: Error>(self, v: &'de [u8]) -> Result<Self::Value, E> {
+        self.limits.check_length::<E>(v.len())?;
+        self.inner.visit_borrowed_bytes(v)
+    }
+
+    fn visit_byte_buf<E: Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        self.limits.check_length::<E>(v.len())?;
+        self.inner.visit_byte_buf(v)
+    }
+
+    fn visit_none<E: Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_none()
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let deserializer = StrictDeserializer {
+            inner: deserializer,
+            limits: self.limits,
+            depth: self.depth,
+        };
+        self.inner.visit_some(deserializer)
+    }
+
+    fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_unit()
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let deserializer = StrictDeserializer {
+            inner: deserializer,
+            limits: self.limits,
+            depth: self.depth,
+        };
+        self.inner.visit_newtype_struct(deserializer)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let depth = self.enter::<A::Error>()?;
+        // Checked before the visitor uses the length to allocate
+        if let Some(length) = seq.size_hint() {
+            self.limits.check_length::<A::Error>(length)?;
+        }
+        self.inner.visit_seq(StrictAccess {
+            inner: seq,
+            limits: self.limits,
+            depth,
+            count: 0,
+        })
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let depth = self.enter::<A::Error>()?;
+        if let Some(length) = map.size_hint() {
+            self.limits.check_length::<A::Error>(length)?;
+        }
+        self.inner.visit_map(StrictAccess {
+            inner: map,
+            limits: self.limits,
+            depth,
+            count: 0,
+        })
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let depth = self.enter::<A::Error>()?;
+        self.inner.visit_enum(StrictAccess {
+            inner: data,
+            limits: self.limits,
+            depth,
+            count: 0,
+        })
+    }
+}
+
+impl<'de, S> DeserializeSeed<'de> for StrictSeed<S>
+where
+    S: DeserializeSeed<'de>,
+{
+    type Value = S::Value;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.inner.deserialize(StrictDeserializer {
+            inner: deserializer,
+            limits: self.limits,
+            depth: self.depth,
+        })
+    }
+}
+
+impl<'de, A> SeqAccess<'de> for StrictAccess<A>
+where
+    A: SeqAccess<'de>,
+{
+    type Error = A::Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        let element = self.inner.next_element_seed(self.seed(seed))?;
+        if element.is_some() {
+            self.count_element::<A::Error>()?;
+        }
+        Ok(element)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, A> MapAccess<'de> for StrictAccess<A>
+where
+    A: MapAccess<'de>,
+{
+    type Error = A::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        let key = self.inner.next_key_seed(self.seed(seed))?;
+        if key.is_some() {
+            self.count_element::<A::Error>()?;
+        }
+        Ok(key)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        self.inner.next_value_seed(self.seed(seed))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, A> EnumAccess<'de> for StrictAccess<A>
+where
+    A: EnumAccess<'de>,
+{
+    type Error = A::Error;
+    type Variant = StrictAccess<A::Variant>;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let (value, variant) = self.inner.variant_seed(StrictSeed {
+            inner: seed,
+            limits: self.limits,
+            depth: self.depth,
+        })?;
+        let variant = StrictAccess {
+            inner: variant,
+            limits: self.limits,
+            depth: self.depth,
+            count: 0,
+        };
+        Ok((value, variant))
+    }
+}
+
+impl<'de, A> VariantAccess<'de> for StrictAccess<A>
+where
+    A: VariantAccess<'de>,
+{
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        self.inner.unit_variant()
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        let seed = self.seed(seed);
+        self.inner.newtype_variant_seed(seed)
+    }
+
+    fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let visitor = StrictVisitor {
+            inner: visitor,
+            limits: self.limits,
+            depth: self.depth,
+        };
+        self.inner.tuple_variant(len, visitor)
+    }
+
+    fn struct_variant<V>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let visitor = StrictVisitor {
+            inner: visitor,
+            limits: self.limits,
+            depth: self.depth,
+        };
+        self.inner.struct_variant(fields, visitor)
+    }
+}
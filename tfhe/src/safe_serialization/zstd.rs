@@ -0,0 +1,151 @@
+//! Compression of the serialized objects with zstd, see [`SerializationConfig::with_compression`]
+use super::{
+    DeserializationConfig, NonConformantDeserializationConfig, SafeSerializationError,
+    SerializationConfig, SerializationHeader,
+};
+use crate::named::Named;
+use bincode::Options;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tfhe_versionable::{Unversionize, Versionize};
+
+/// Compression level used by [`SerializationConfig::with_compression`].
+///
+/// Higher levels give smaller outputs but are slower.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct CompressionLevel(i32);
+
+impl CompressionLevel {
+    /// Fastest compression, with the lowest compression ratio
+    pub const FAST: Self = Self(1);
+    /// Best compression ratio, but slow
+    pub const BEST: Self = Self(19);
+
+    /// Creates a compression level from a zstd level, which is clamped to the supported range
+    /// (usually 1 to 22)
+    pub fn new(level: i32) -> Self {
+        let range = ::zstd::compression_level_range();
+        Self(level.clamp(*range.start(), *range.end()))
+    }
+
+    /// Returns the underlying zstd level
+    pub fn level(&self) -> i32 {
+        self.0
+    }
+}
+
+impl Default for CompressionLevel {
+    fn default() -> Self {
+        Self(::zstd::DEFAULT_COMPRESSION_LEVEL)
+    }
+}
+
+/// Compresses `data` in segments of `segment_size` bytes on the rayon thread pool.
+///
+/// Each segment is compressed into an independent zstd frame, and the frames are concatenated in
+/// order. A zstd stream made of several frames is decompressed into the concatenation of their
+/// content, so the result can be read by a regular zstd decoder.
+fn compress_segments(
+    data: &[u8],
+    level: CompressionLevel,
+    segment_size: u64,
+) -> std::io::Result<Vec<u8>> {
+    use rayon::prelude::*;
+
+    if data.is_empty() {
+        return ::zstd::bulk::compress(data, level.level());
+    }
+
+    let segment_size = usize::try_from(segment_size).unwrap_or(usize::MAX);
+    let frames = data
+        .par_chunks(segment_size)
+        .map(|segment| ::zstd::bulk::compress(segment, level.level()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+
+    Ok(frames.concat())
+}
+
+impl SerializationConfig {
+    /// Compresses the serialized objects with zstd.
+    ///
+    /// The compression is flagged in the header, so that
+    /// [`DeserializationConfig::deserialize_from`] transparently decompresses the object. The size
+    /// limit of the config applies both to the object before compression and to the compressed
+    /// data.
+    ///
+    /// Note that the object is first compressed in memory.
+    pub fn with_compression(self, level: CompressionLevel) -> Self {
+        Self {
+            compression: Some(level),
+            ..self
+        }
+    }
+
+    /// Compresses the serialized objects in segments of `segment_size` bytes, in parallel.
+    ///
+    /// The compression of large objects such as server keys is single-threaded and dominates the
+    /// serialization time. With this option, the object is first serialized in memory, then split
+    /// in segments that are compressed concurrently on the rayon thread pool. The compressed
+    /// segments are written in order as consecutive zstd frames, so the data is deserialized by
+    /// [`DeserializationConfig::deserialize_from`] like any compressed object.
+    ///
+    /// This enables compression with the given level, see [`Self::with_compression`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `segment_size` is 0.
+    pub fn with_parallel_compression(self, level: CompressionLevel, segment_size: u64) -> Self {
+        assert!(segment_size > 0, "The segment size must be greater than 0");
+        Self {
+            compression: Some(level),
+            compression_segment_size: Some(segment_size),
+            ..self
+        }
+    }
+
+    /// Compresses the serialized object in memory, if the config enables compression
+    pub(super) fn compress_object<T: Serialize + Versionize + Named>(
+        &self,
+        object: &T,
+    ) -> bincode::Result<Option<Vec<u8>>> {
+        match (self.compression, self.compression_segment_size) {
+            (Some(level), Some(segment_size)) => {
+                // The serialized object may be a secret key, it is zeroized once compressed
+                let mut serialized = zeroize::Zeroizing::new(Vec::new());
+                self.serialize_object(object, &mut *serialized)?;
+                Ok(Some(compress_segments(&serialized, level, segment_size)?))
+            }
+            (Some(level), None) => {
+                let mut encoder = ::zstd::stream::Encoder::new(Vec::new(), level.level())?;
+                self.serialize_object(object, &mut encoder)?;
+                Ok(Some(encoder.finish()?))
+            }
+            (None, _) => Ok(None),
+        }
+    }
+}
+
+impl NonConformantDeserializationConfig<'_> {
+    /// Deserializes a payload compressed with zstd
+    pub(super) fn deserialize_compressed_payload<T: DeserializeOwned + Unversionize + Named>(
+        &self,
+        header: &SerializationHeader,
+        mut reader: impl std::io::Read,
+        object_size_limit: u64,
+    ) -> Result<T, SafeSerializationError> {
+        let compressed: Vec<u8> = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .with_limit(object_size_limit)
+            .deserialize_from(&mut reader)
+            .map_err(|err| SafeSerializationError::from_bincode(err, object_size_limit))?;
+
+        // The size limit is also applied to the decompressed data, so that a small compressed
+        // payload cannot be used to exhaust memory
+        let decoder =
+            ::zstd::stream::Decoder::with_buffer(compressed.as_slice()).map_err(|err| {
+                SafeSerializationError::Message(format!("Failed to decompress the object: {err}"))
+            })?;
+
+        self.deserialize_object(&header.versioning_mode, decoder, object_size_limit)
+    }
+}
@@ -4,6 +4,7 @@ pub mod ciphertext;
 pub mod client_key;
 pub mod key_switching_key;
 pub mod list_compression;
+pub mod noise_squashing;
 pub mod parameters;
 pub mod public_key;
 pub mod server_key;
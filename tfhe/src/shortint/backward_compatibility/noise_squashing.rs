@@ -0,0 +1,20 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::shortint::noise_squashing::{
+    NoiseSquashingKey, NoiseSquashingPrivateKey, SquashedNoiseCiphertext,
+};
+
+#[derive(VersionsDispatch)]
+pub enum NoiseSquashingKeyVersions {
+    V0(NoiseSquashingKey),
+}
+
+#[derive(VersionsDispatch)]
+pub enum NoiseSquashingPrivateKeyVersions {
+    V0(NoiseSquashingPrivateKey),
+}
+
+#[derive(VersionsDispatch)]
+pub enum SquashedNoiseCiphertextVersions {
+    V0(SquashedNoiseCiphertext),
+}
@@ -1,6 +1,7 @@
 pub mod compact_public_key_only;
 pub mod key_switching;
 pub mod list_compression;
+pub mod noise_squashing;
 
 use tfhe_versionable::VersionsDispatch;
 
@@ -0,0 +1,8 @@
+use tfhe_versionable::VersionsDispatch;
+
+use super::parameters::noise_squashing::NoiseSquashingParameters;
+
+#[derive(VersionsDispatch)]
+pub enum NoiseSquashingParametersVersions {
+    V0(NoiseSquashingParameters),
+}
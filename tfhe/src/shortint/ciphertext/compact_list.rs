@@ -58,18 +58,9 @@ impl ParameterSetConformant for CompactCiphertextList {
 }
 
 impl CompactCiphertextList {
-    /// Expand a [`CompactCiphertextList`] to a `Vec` of [`Ciphertext`].
-    ///
-    /// The function takes a [`ShortintCompactCiphertextListCastingMode`] to indicate whether a
-    /// keyswitch should be applied during expansion, and if it does, functions can be applied as
-    /// well during casting, which can be more efficient if a refresh is required during casting.
-    ///
-    /// This is useful when using separate parameters for the public key used to encrypt the
-    /// [`CompactCiphertextList`] allowing to keyswitch to the computation params during expansion.
-    pub fn expand(
-        &self,
-        casting_mode: ShortintCompactCiphertextListCastingMode<'_>,
-    ) -> Result<Vec<Ciphertext>, crate::Error> {
+    /// Expand the compact list into a list of LWE ciphertexts, still encrypted under the key used
+    /// to encrypt the [`CompactCiphertextList`].
+    pub(crate) fn expand_into_lwe_ciphertext_list(&self) -> LweCiphertextListOwned<u64> {
         let mut output_lwe_ciphertext_list = LweCiphertextList::new(
             0u64,
             self.ct_list.lwe_size(),
@@ -91,6 +82,23 @@ impl CompactCiphertextList {
             par_expand_lwe_compact_ciphertext_list(&mut output_lwe_ciphertext_list, &self.ct_list);
         }
 
+        output_lwe_ciphertext_list
+    }
+
+    /// Expand a [`CompactCiphertextList`] to a `Vec` of [`Ciphertext`].
+    ///
+    /// The function takes a [`ShortintCompactCiphertextListCastingMode`] to indicate whether a
+    /// keyswitch should be applied during expansion, and if it does, functions can be applied as
+    /// well during casting, which can be more efficient if a refresh is required during casting.
+    ///
+    /// This is useful when using separate parameters for the public key used to encrypt the
+    /// [`CompactCiphertextList`] allowing to keyswitch to the computation params during expansion.
+    pub fn expand(
+        &self,
+        casting_mode: ShortintCompactCiphertextListCastingMode<'_>,
+    ) -> Result<Vec<Ciphertext>, crate::Error> {
+        let output_lwe_ciphertext_list = self.expand_into_lwe_ciphertext_list();
+
         match (self.expansion_kind, casting_mode) {
             (
                 CompactCiphertextListExpansionKind::RequiresCasting,
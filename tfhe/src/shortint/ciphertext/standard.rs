@@ -3,6 +3,7 @@ use super::super::parameters::CiphertextConformanceParams;
 use super::common::*;
 use crate::conformance::ParameterSetConformant;
 use crate::core_crypto::entities::*;
+use crate::safe_serialization::{SerializedSizeBound, OBJECT_METADATA_SIZE_BOUND};
 use crate::shortint::backward_compatibility::ciphertext::CiphertextVersions;
 use crate::shortint::parameters::{CarryModulus, MessageModulus};
 use serde::{Deserialize, Serialize};
@@ -47,6 +48,14 @@ impl ParameterSetConformant for Ciphertext {
     }
 }
 
+impl SerializedSizeBound for Ciphertext {
+    fn serialized_size_bound(param: &CiphertextConformanceParams) -> u64 {
+        let lwe_size = param.ct_params.lwe_dim.to_lwe_size().0 as u64;
+
+        8 * lwe_size + OBJECT_METADATA_SIZE_BOUND
+    }
+}
+
 // Use destructuring to also have a compile error
 // if ever a new member is added to Ciphertext
 // and is not handled here.
@@ -198,6 +198,7 @@ impl ProvenCompactCiphertextList {
 }
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
 pub struct ProvenCompactCiphertextListConformanceParams {
     pub encryption_lwe_dimension: LweDimension,
     pub message_modulus: MessageModulus,
@@ -19,6 +19,7 @@ use crate::shortint::CarryModulus;
 use secret_encryption_key::SecretEncryptionKeyView;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Debug, Display};
+use zeroize::Zeroize;
 
 /// A structure containing the client key, which must be kept secret.
 ///
@@ -37,6 +38,16 @@ pub struct ClientKey {
     pub parameters: ShortintParameterSet,
 }
 
+/// Overwrites the secret keys with zeros, so that they do not remain in memory.
+///
+/// Wrap the key in [`zeroize::Zeroizing`] to have it zeroized when dropped.
+impl Zeroize for ClientKey {
+    fn zeroize(&mut self) {
+        self.glwe_secret_key.zeroize();
+        self.lwe_secret_key.zeroize();
+    }
+}
+
 impl<'cks> From<&'cks ClientKey> for SecretEncryptionKeyView<'cks> {
     fn from(value: &'cks ClientKey) -> Self {
         Self {
@@ -341,6 +341,10 @@ impl ShortintEngine {
         &mut self,
         server_key: &ServerKey,
     ) -> (BuffersRef<'_>, &mut ComputationBuffers) {
+        assert!(
+            !server_key.is_dry_run(),
+            "A dry run server key can only compute on trivial ciphertexts"
+        );
         (
             self.ciphertext_buffers.as_buffers(
                 server_key
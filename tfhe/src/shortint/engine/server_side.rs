@@ -11,7 +11,7 @@ use crate::shortint::client_key::secret_encryption_key::SecretEncryptionKeyView;
 use crate::shortint::parameters::{EncryptionKeyChoice, ShortintKeySwitchingParameters};
 use crate::shortint::server_key::{ShortintBootstrappingKey, ShortintCompressedBootstrappingKey};
 use crate::shortint::{
-    CiphertextModulus, ClientKey, CompressedServerKey, PBSParameters, ServerKey,
+    CiphertextModulus, ClientKey, CompressedServerKey, PBSOrder, PBSParameters, ServerKey,
 };
 
 impl ShortintEngine {
@@ -94,6 +94,73 @@ impl ShortintEngine {
         }
     }
 
+    /// Creates a server key for dry runs, without key material
+    ///
+    /// The bootstrapping key has an input LWE dimension of zero, which marks the key as a dry run
+    /// key (see [ServerKey::is_dry_run]), and the keyswitching key only has the dimension of the
+    /// ciphertexts the server key works on. No secret key nor randomness is involved.
+    pub(crate) fn new_dry_run_server_key_with_max_degree(
+        &mut self,
+        pbs_params: PBSParameters,
+        max_degree: MaxDegree,
+    ) -> ServerKey {
+        let glwe_size = pbs_params.glwe_dimension().to_glwe_size();
+        let large_lwe_dimension = pbs_params
+            .glwe_dimension()
+            .to_equivalent_lwe_dimension(pbs_params.polynomial_size());
+
+        let bootstrapping_key = match pbs_params {
+            PBSParameters::PBS(pbs_params) => {
+                ShortintBootstrappingKey::Classic(FourierLweBootstrapKey::new(
+                    LweDimension(0),
+                    glwe_size,
+                    pbs_params.polynomial_size,
+                    pbs_params.pbs_base_log,
+                    pbs_params.pbs_level,
+                ))
+            }
+            PBSParameters::MultiBitPBS(pbs_params) => ShortintBootstrappingKey::MultiBit {
+                fourier_bsk: FourierLweMultiBitBootstrapKey::new(
+                    LweDimension(0),
+                    glwe_size,
+                    pbs_params.polynomial_size,
+                    pbs_params.pbs_base_log,
+                    pbs_params.pbs_level,
+                    pbs_params.grouping_factor,
+                ),
+                thread_count: ThreadCount(1),
+                deterministic_execution: pbs_params.deterministic_execution,
+            },
+        };
+
+        // The dimension of the ciphertexts is given by the keyswitching key, the other dimension
+        // is reduced to the minimum so that it does not hold a key
+        let pbs_order: PBSOrder = pbs_params.encryption_key_choice().into();
+        let (input_lwe_dimension, output_lwe_dimension) = match pbs_order {
+            PBSOrder::KeyswitchBootstrap => (large_lwe_dimension, LweDimension(0)),
+            PBSOrder::BootstrapKeyswitch => (LweDimension(1), pbs_params.lwe_dimension()),
+        };
+        let key_switching_key = LweKeyswitchKey::new(
+            0u64,
+            pbs_params.ks_base_log(),
+            pbs_params.ks_level(),
+            input_lwe_dimension,
+            output_lwe_dimension,
+            pbs_params.ciphertext_modulus(),
+        );
+
+        ServerKey {
+            key_switching_key,
+            bootstrapping_key,
+            message_modulus: pbs_params.message_modulus(),
+            carry_modulus: pbs_params.carry_modulus(),
+            max_degree,
+            max_noise_level: pbs_params.max_noise_level(),
+            ciphertext_modulus: pbs_params.ciphertext_modulus(),
+            pbs_order,
+        }
+    }
+
     pub fn new_bootstrapping_key<
         InKeycont: Container<Element = u64>,
         OutKeyCont: Container<Element = u64> + Sync,
@@ -1012,6 +1012,7 @@ impl CompressedKeySwitchingKey {
     }
 }
 
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
 pub struct KeySwitchingKeyConformanceParams {
     pub keyswitch_key_conformance_params: KeyswitchKeyConformanceParams,
     pub cast_rshift: i8,
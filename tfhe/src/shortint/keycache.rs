@@ -88,6 +88,10 @@ named_params_impl!( ShortintParameterSet =>
     PARAM_MESSAGE_7_CARRY_0_KS_PBS_GAUSSIAN_2M64,
     PARAM_MESSAGE_7_CARRY_1_KS_PBS_GAUSSIAN_2M64,
     PARAM_MESSAGE_8_CARRY_0_KS_PBS_GAUSSIAN_2M64,
+    PARAM_MESSAGE_1_CARRY_1_KS_PBS_GAUSSIAN_2M128,
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS_GAUSSIAN_2M128,
     // Small
     PARAM_MESSAGE_1_CARRY_1_PBS_KS,
     PARAM_MESSAGE_2_CARRY_2_PBS_KS,
@@ -105,6 +109,13 @@ named_params_impl!( ShortintParameterSet =>
     PARAM_MULTI_BIT_MESSAGE_1_CARRY_1_GROUP_3_KS_PBS,
     PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_3_KS_PBS,
     PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_3_KS_PBS,
+    // MultiBit 2^-128
+    PARAM_MULTI_BIT_GROUP_2_MESSAGE_1_CARRY_1_KS_PBS_GAUSSIAN_2M128,
+    PARAM_MULTI_BIT_GROUP_2_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128,
+    PARAM_MULTI_BIT_GROUP_2_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128,
+    PARAM_MULTI_BIT_GROUP_3_MESSAGE_1_CARRY_1_KS_PBS_GAUSSIAN_2M128,
+    PARAM_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128,
+    PARAM_MULTI_BIT_GROUP_3_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128,
     // MultiBit Group 2 GPU
     PARAM_GPU_MULTI_BIT_MESSAGE_1_CARRY_1_GROUP_2_KS_PBS,
     PARAM_GPU_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS,
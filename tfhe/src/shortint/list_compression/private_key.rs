@@ -9,6 +9,7 @@ use crate::shortint::engine::ShortintEngine;
 use crate::shortint::parameters::list_compression::CompressionParameters;
 use crate::shortint::{ClassicPBSParameters, EncryptionKeyChoice, PBSParameters};
 use std::fmt::Debug;
+use zeroize::Zeroize;
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize, Versionize)]
 #[versionize(CompressionPrivateKeysVersions)]
@@ -17,6 +18,12 @@ pub struct CompressionPrivateKeys {
     pub params: CompressionParameters,
 }
 
+impl Zeroize for CompressionPrivateKeys {
+    fn zeroize(&mut self) {
+        self.post_packing_ks_key.zeroize();
+    }
+}
+
 impl ClientKey {
     pub fn new_compression_private_key(
         &self,
@@ -109,6 +109,7 @@ impl ClientKey {
     }
 }
 
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
 pub struct CompressionConformanceParameters {
     pub br_level: DecompositionLevelCount,
     pub br_base_log: DecompositionBaseLog,
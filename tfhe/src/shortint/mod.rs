@@ -55,7 +55,10 @@ pub mod key_switching_key;
 #[cfg(any(test, doctest, feature = "internal-keycache"))]
 pub mod keycache;
 pub mod list_compression;
+pub mod noise_squashing;
 pub mod oprf;
+#[cfg(feature = "experimental")]
+pub mod packed_slots;
 pub mod parameters;
 pub mod prelude;
 pub mod public_key;
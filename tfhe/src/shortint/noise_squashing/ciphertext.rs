@@ -0,0 +1,54 @@
+use tfhe_versionable::Versionize;
+
+use crate::core_crypto::prelude::LweCiphertextOwned;
+use crate::shortint::backward_compatibility::noise_squashing::SquashedNoiseCiphertextVersions;
+use crate::shortint::parameters::{CarryModulus, Degree, MessageModulus};
+use serde::{Deserialize, Serialize};
+
+/// A ciphertext whose noise was squashed, encrypted under a 128 bits modulus
+///
+/// It can only be decrypted, no operation is supported on it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Versionize)]
+#[versionize(SquashedNoiseCiphertextVersions)]
+pub struct SquashedNoiseCiphertext {
+    ct: LweCiphertextOwned<u128>,
+    degree: Degree,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+}
+
+impl crate::named::Named for SquashedNoiseCiphertext {
+    const NAME: &'static str = "shortint::SquashedNoiseCiphertext";
+}
+
+impl SquashedNoiseCiphertext {
+    pub(crate) fn new(
+        ct: LweCiphertextOwned<u128>,
+        degree: Degree,
+        message_modulus: MessageModulus,
+        carry_modulus: CarryModulus,
+    ) -> Self {
+        Self {
+            ct,
+            degree,
+            message_modulus,
+            carry_modulus,
+        }
+    }
+
+    pub fn lwe_ciphertext(&self) -> &LweCiphertextOwned<u128> {
+        &self.ct
+    }
+
+    pub fn degree(&self) -> Degree {
+        self.degree
+    }
+
+    pub fn message_modulus(&self) -> MessageModulus {
+        self.message_modulus
+    }
+
+    pub fn carry_modulus(&self) -> CarryModulus {
+        self.carry_modulus
+    }
+}
@@ -0,0 +1,12 @@
+//! Noise squashing converts ciphertexts to a 128 bits modulus where their noise is negligible.
+//!
+//! Threshold decryption protocols need ciphertexts whose noise is small compared to the modulus,
+//! this module provides the keys and the ciphertext type used for that conversion.
+
+mod ciphertext;
+mod private_key;
+mod server_key;
+
+pub use ciphertext::SquashedNoiseCiphertext;
+pub use private_key::NoiseSquashingPrivateKey;
+pub use server_key::NoiseSquashingKey;
@@ -0,0 +1,75 @@
+use tfhe_versionable::Versionize;
+
+use super::SquashedNoiseCiphertext;
+use crate::core_crypto::prelude::{
+    allocate_and_generate_new_binary_glwe_secret_key, decrypt_lwe_ciphertext, GlweSecretKeyOwned,
+};
+use crate::shortint::backward_compatibility::noise_squashing::NoiseSquashingPrivateKeyVersions;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::NoiseSquashingParameters;
+use std::fmt::Debug;
+use zeroize::Zeroize;
+
+/// Secret key able to decrypt a [SquashedNoiseCiphertext]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(NoiseSquashingPrivateKeyVersions)]
+pub struct NoiseSquashingPrivateKey {
+    post_noise_squashing_secret_key: GlweSecretKeyOwned<u128>,
+    params: NoiseSquashingParameters,
+}
+
+impl Zeroize for NoiseSquashingPrivateKey {
+    fn zeroize(&mut self) {
+        self.post_noise_squashing_secret_key.zeroize();
+    }
+}
+
+impl NoiseSquashingPrivateKey {
+    pub fn new(params: NoiseSquashingParameters) -> Self {
+        let post_noise_squashing_secret_key = ShortintEngine::with_thread_local_mut(|engine| {
+            allocate_and_generate_new_binary_glwe_secret_key(
+                params.glwe_dimension,
+                params.polynomial_size,
+                &mut engine.secret_generator,
+            )
+        });
+
+        Self {
+            post_noise_squashing_secret_key,
+            params,
+        }
+    }
+
+    pub fn post_noise_squashing_secret_key(&self) -> &GlweSecretKeyOwned<u128> {
+        &self.post_noise_squashing_secret_key
+    }
+
+    pub fn noise_squashing_parameters(&self) -> NoiseSquashingParameters {
+        self.params
+    }
+
+    /// Decrypts a [SquashedNoiseCiphertext], the result contains both the message and the carry
+    pub fn decrypt_squashed_noise_ciphertext(&self, ct: &SquashedNoiseCiphertext) -> u128 {
+        assert_eq!(
+            ct.lwe_ciphertext().lwe_size().to_lwe_dimension(),
+            self.post_noise_squashing_secret_key
+                .as_lwe_secret_key()
+                .lwe_dimension(),
+            "SquashedNoiseCiphertext was not squashed with a key matching this private key"
+        );
+
+        let plaintext = decrypt_lwe_ciphertext(
+            &self.post_noise_squashing_secret_key.as_lwe_secret_key(),
+            ct.lwe_ciphertext(),
+        );
+
+        let total_modulus = (ct.message_modulus().0 * ct.carry_modulus().0) as u128;
+        // One bit of padding, same encoding as the regular shortint ciphertexts
+        let delta = (1u128 << 127) / total_modulus;
+
+        // Round to the closest multiple of delta
+        let decoded = plaintext.0.wrapping_add(delta / 2) / delta;
+
+        decoded % total_modulus
+    }
+}
@@ -0,0 +1,291 @@
+use tfhe_versionable::Versionize;
+
+use super::{NoiseSquashingPrivateKey, SquashedNoiseCiphertext};
+use crate::core_crypto::prelude::*;
+use crate::shortint::backward_compatibility::noise_squashing::NoiseSquashingKeyVersions;
+use crate::shortint::ciphertext::NoiseLevel;
+use crate::shortint::client_key::ClientKey;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::{CarryModulus, MessageModulus};
+use crate::shortint::server_key::ServerKey;
+use crate::shortint::{Ciphertext, PBSOrder};
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+/// Key used to squash the noise of ciphertexts
+///
+/// It holds a bootstrapping key going from the small LWE secret key of the [ClientKey] to the
+/// 128 bits secret key of the [NoiseSquashingPrivateKey].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
+#[versionize(NoiseSquashingKeyVersions)]
+pub struct NoiseSquashingKey {
+    bootstrapping_key: Fourier128LweBootstrapKeyOwned,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    output_ciphertext_modulus: CiphertextModulus<u128>,
+}
+
+impl NoiseSquashingKey {
+    pub fn new(
+        client_key: &ClientKey,
+        noise_squashing_private_key: &NoiseSquashingPrivateKey,
+    ) -> Self {
+        let params = noise_squashing_private_key.noise_squashing_parameters();
+
+        assert!(
+            client_key
+                .parameters
+                .ciphertext_modulus()
+                .is_native_modulus(),
+            "Noise squashing is only compatible with ciphertexts using the native modulus"
+        );
+        assert!(
+            params.ciphertext_modulus.is_native_modulus(),
+            "Noise squashing is only compatible with a native output modulus"
+        );
+
+        // The binary small key is reinterpreted to encrypt the bootstrapping key on 128 bits
+        let small_lwe_secret_key = client_key.small_lwe_secret_key();
+        let input_lwe_secret_key = LweSecretKey::from_container(
+            small_lwe_secret_key
+                .as_ref()
+                .iter()
+                .map(|&x| x as u128)
+                .collect::<Vec<_>>(),
+        );
+
+        let std_bootstrapping_key = ShortintEngine::with_thread_local_mut(|engine| {
+            par_allocate_and_generate_new_lwe_bootstrap_key(
+                &input_lwe_secret_key,
+                noise_squashing_private_key.post_noise_squashing_secret_key(),
+                params.decomp_base_log,
+                params.decomp_level_count,
+                params.glwe_noise_distribution,
+                params.ciphertext_modulus,
+                &mut engine.encryption_generator,
+            )
+        });
+
+        let mut bootstrapping_key = Fourier128LweBootstrapKey::new(
+            std_bootstrapping_key.input_lwe_dimension(),
+            std_bootstrapping_key.glwe_size(),
+            std_bootstrapping_key.polynomial_size(),
+            std_bootstrapping_key.decomposition_base_log(),
+            std_bootstrapping_key.decomposition_level_count(),
+        );
+
+        convert_standard_lwe_bootstrap_key_to_fourier_128(
+            &std_bootstrapping_key,
+            &mut bootstrapping_key,
+        );
+
+        Self {
+            bootstrapping_key,
+            message_modulus: client_key.parameters.message_modulus(),
+            carry_modulus: client_key.parameters.carry_modulus(),
+            output_ciphertext_modulus: params.ciphertext_modulus,
+        }
+    }
+
+    pub fn bootstrapping_key(&self) -> &Fourier128LweBootstrapKeyOwned {
+        &self.bootstrapping_key
+    }
+
+    pub fn message_modulus(&self) -> MessageModulus {
+        self.message_modulus
+    }
+
+    pub fn carry_modulus(&self) -> CarryModulus {
+        self.carry_modulus
+    }
+
+    pub fn output_ciphertext_modulus(&self) -> CiphertextModulus<u128> {
+        self.output_ciphertext_modulus
+    }
+
+    /// Converts a ciphertext to a [SquashedNoiseCiphertext], keeping both its message and carry
+    ///
+    /// `src_server_key` must be the server key of the client key used to generate this key, it is
+    /// used to bring the ciphertext under the small LWE secret key and to reset its noise if
+    /// needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::noise_squashing::{NoiseSquashingKey, NoiseSquashingPrivateKey};
+    /// use tfhe::shortint::parameters::noise_squashing::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    ///
+    /// let noise_squashing_private_key =
+    ///     NoiseSquashingPrivateKey::new(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let noise_squashing_key = NoiseSquashingKey::new(&cks, &noise_squashing_private_key);
+    ///
+    /// let ct = cks.encrypt(3);
+    /// let ct = sks.unchecked_add(&ct, &ct);
+    ///
+    /// let squashed = noise_squashing_key.squash_ciphertext_noise(&ct, &sks);
+    ///
+    /// let decrypted = noise_squashing_private_key.decrypt_squashed_noise_ciphertext(&squashed);
+    /// assert_eq!(decrypted, 6);
+    /// ```
+    pub fn squash_ciphertext_noise(
+        &self,
+        ct: &Ciphertext,
+        src_server_key: &ServerKey,
+    ) -> SquashedNoiseCiphertext {
+        assert_eq!(
+            ct.message_modulus, self.message_modulus,
+            "Mismatched MessageModulus between Ciphertext {:?} and NoiseSquashingKey {:?}",
+            ct.message_modulus, self.message_modulus,
+        );
+        assert_eq!(
+            ct.carry_modulus, self.carry_modulus,
+            "Mismatched CarryModulus between Ciphertext {:?} and NoiseSquashingKey {:?}",
+            ct.carry_modulus, self.carry_modulus,
+        );
+
+        let total_modulus = self.message_modulus.0 * self.carry_modulus.0;
+        assert!(
+            ct.degree.get() < total_modulus,
+            "The ciphertext degree {:?} does not fit in the message and carry space",
+            ct.degree,
+        );
+
+        let delta = (1u128 << 127) / total_modulus as u128;
+        let output_lwe_size = self.bootstrapping_key.output_lwe_dimension().to_lwe_size();
+
+        if ct.is_trivial() {
+            let value = ct.decrypt_trivial_message_and_carry().unwrap() as u128;
+            let squashed = allocate_and_trivially_encrypt_new_lwe_ciphertext(
+                output_lwe_size,
+                Plaintext(value * delta),
+                self.output_ciphertext_modulus,
+            );
+            return SquashedNoiseCiphertext::new(
+                squashed,
+                ct.degree,
+                ct.message_modulus,
+                ct.carry_modulus,
+            );
+        }
+
+        let cleaned_ct;
+        let ct = if ct.noise_level() > NoiseLevel::NOMINAL {
+            let identity = src_server_key.generate_lookup_table(|x| x);
+            cleaned_ct = src_server_key.apply_lookup_table(ct, &identity);
+            &cleaned_ct
+        } else {
+            ct
+        };
+
+        let after_ks;
+        let input_lwe = match ct.pbs_order {
+            PBSOrder::KeyswitchBootstrap => {
+                let mut buffer = LweCiphertext::new(
+                    0u64,
+                    src_server_key.key_switching_key.output_lwe_size(),
+                    ct.ct.ciphertext_modulus(),
+                );
+                keyswitch_lwe_ciphertext(&src_server_key.key_switching_key, &ct.ct, &mut buffer);
+                after_ks = buffer;
+                &after_ks
+            }
+            PBSOrder::BootstrapKeyswitch => &ct.ct,
+        };
+
+        assert_eq!(
+            input_lwe.lwe_size().to_lwe_dimension(),
+            self.bootstrapping_key.input_lwe_dimension(),
+            "The ciphertext is not encrypted under the key used to generate the NoiseSquashingKey"
+        );
+
+        // Going from the native 64 bits modulus to the native 128 bits one is exact
+        let lifted_lwe = LweCiphertext::from_container(
+            input_lwe
+                .as_ref()
+                .iter()
+                .map(|&x| (x as u128) << 64)
+                .collect::<Vec<_>>(),
+            self.output_ciphertext_modulus,
+        );
+
+        let accumulator = generate_programmable_bootstrap_glwe_lut(
+            self.bootstrapping_key.polynomial_size(),
+            self.bootstrapping_key.glwe_size(),
+            total_modulus,
+            self.output_ciphertext_modulus,
+            delta,
+            |x| x,
+        );
+
+        let mut squashed =
+            LweCiphertext::new(0u128, output_lwe_size, self.output_ciphertext_modulus);
+
+        programmable_bootstrap_f128_lwe_ciphertext(
+            &lifted_lwe,
+            &mut squashed,
+            &accumulator,
+            &self.bootstrapping_key,
+        );
+
+        SquashedNoiseCiphertext::new(squashed, ct.degree, ct.message_modulus, ct.carry_modulus)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::shortint::parameters::noise_squashing::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    use crate::shortint::parameters::{
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, PARAM_MESSAGE_2_CARRY_2_PBS_KS,
+    };
+    use crate::shortint::{gen_keys, ClassicPBSParameters};
+
+    fn test_noise_squashing_(params: ClassicPBSParameters) {
+        let (cks, sks) = gen_keys(params);
+
+        let noise_squashing_private_key = NoiseSquashingPrivateKey::new(
+            NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+        );
+        let noise_squashing_key = NoiseSquashingKey::new(&cks, &noise_squashing_private_key);
+
+        let total_modulus = (params.message_modulus.0 * params.carry_modulus.0) as u64;
+
+        for msg in 0..params.message_modulus.0 as u64 {
+            // Fresh ciphertext
+            let ct = cks.encrypt(msg);
+            let squashed = noise_squashing_key.squash_ciphertext_noise(&ct, &sks);
+            let decrypted =
+                noise_squashing_private_key.decrypt_squashed_noise_ciphertext(&squashed);
+            assert_eq!(decrypted, msg as u128);
+
+            // Ciphertext with a carry and noise above the nominal level
+            let ct = sks.unchecked_add(&ct, &ct);
+            let squashed = noise_squashing_key.squash_ciphertext_noise(&ct, &sks);
+            let decrypted =
+                noise_squashing_private_key.decrypt_squashed_noise_ciphertext(&squashed);
+            assert_eq!(decrypted, (2 * msg % total_modulus) as u128);
+            assert_eq!(squashed.degree(), ct.degree);
+
+            // Trivial ciphertext
+            let ct = sks.create_trivial(msg);
+            let squashed = noise_squashing_key.squash_ciphertext_noise(&ct, &sks);
+            let decrypted =
+                noise_squashing_private_key.decrypt_squashed_noise_ciphertext(&squashed);
+            assert_eq!(decrypted, msg as u128);
+        }
+    }
+
+    #[test]
+    fn test_noise_squashing_ks_pbs() {
+        test_noise_squashing_(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    }
+
+    #[test]
+    fn test_noise_squashing_pbs_ks() {
+        test_noise_squashing_(PARAM_MESSAGE_2_CARRY_2_PBS_KS);
+    }
+}
@@ -0,0 +1,342 @@
+//! Experimental encryption of many messages in a single GLWE ciphertext.
+//!
+//! Each coefficient of the plaintext polynomial of the GLWE ciphertext holds one message (called a
+//! slot), encoded the same way as in a shortint [Ciphertext]. Additions and multiplications by a
+//! clear scalar apply to all the slots at once, which makes the linear parts of a computation
+//! much cheaper, then each slot can be extracted to a regular shortint [Ciphertext] to continue the
+//! computation with PBS based operations.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::gen_keys;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+//!
+//! let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+//!
+//! let mut packed_a = cks.encrypt_packed_slots(&[0, 1, 2, 3]);
+//! let packed_b = cks.encrypt_packed_slots(&[3, 2, 1, 0]);
+//!
+//! sks.unchecked_packed_slots_add_assign(&mut packed_a, &packed_b);
+//! sks.unchecked_packed_slots_scalar_mul_assign(&mut packed_a, 2);
+//!
+//! let ct = sks.extract_packed_slot(&packed_a, 1).unwrap();
+//! assert_eq!(cks.decrypt_message_and_carry(&ct), 6);
+//!
+//! let slots = cks.decrypt_packed_slots(&packed_a);
+//! assert_eq!(slots, vec![2, 2, 2, 2]);
+//! ```
+
+use crate::core_crypto::prelude::{
+    decrypt_glwe_ciphertext, encrypt_glwe_ciphertext, extract_lwe_sample_from_glwe_ciphertext,
+    glwe_ciphertext_add_assign, glwe_ciphertext_cleartext_mul_assign, keyswitch_lwe_ciphertext,
+    Cleartext, GlweCiphertextOwned, LweCiphertext, MonomialDegree, PlaintextCount, PlaintextList,
+};
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::server_key::CiphertextNoiseDegree;
+use crate::shortint::{
+    CarryModulus, CheckError, Ciphertext, ClientKey, MessageModulus, PBSOrder, ServerKey,
+};
+
+/// A GLWE ciphertext encrypting up to `polynomial_size` shortint messages
+///
+/// The degree and noise level are tracked for all the slots at once,
+/// as operations apply to all of them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[must_use]
+pub struct PackedSlotsCiphertext {
+    glwe: GlweCiphertextOwned<u64>,
+    slot_count: usize,
+    degree: Degree,
+    noise_level: NoiseLevel,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+}
+
+impl PackedSlotsCiphertext {
+    /// Returns the number of slots holding a message
+    pub fn slot_count(&self) -> usize {
+        self.slot_count
+    }
+
+    pub fn degree(&self) -> Degree {
+        self.degree
+    }
+
+    pub fn noise_level(&self) -> NoiseLevel {
+        self.noise_level
+    }
+
+    pub fn noise_degree(&self) -> CiphertextNoiseDegree {
+        CiphertextNoiseDegree {
+            noise_level: self.noise_level,
+            degree: self.degree,
+        }
+    }
+
+    pub fn message_modulus(&self) -> MessageModulus {
+        self.message_modulus
+    }
+
+    pub fn carry_modulus(&self) -> CarryModulus {
+        self.carry_modulus
+    }
+}
+
+impl ClientKey {
+    /// Encrypts the messages in the slots of a single GLWE ciphertext
+    ///
+    /// Each message is reduced to the message modulus.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more messages than the polynomial size of the parameters.
+    pub fn encrypt_packed_slots(&self, messages: &[u64]) -> PackedSlotsCiphertext {
+        let polynomial_size = self.parameters.polynomial_size();
+        assert!(
+            messages.len() <= polynomial_size.0,
+            "Cannot pack {} messages, at most {} (the polynomial size) can be packed",
+            messages.len(),
+            polynomial_size.0
+        );
+
+        let message_modulus = self.parameters.message_modulus();
+        let carry_modulus = self.parameters.carry_modulus();
+
+        //The delta is the one defined by the parameters
+        let delta = (1_u64 << 63) / (message_modulus.0 * carry_modulus.0) as u64;
+
+        let mut plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+        for (plaintext, message) in plaintext_list.as_mut().iter_mut().zip(messages.iter()) {
+            *plaintext = (message % message_modulus.0 as u64) * delta;
+        }
+
+        let mut glwe = GlweCiphertextOwned::new(
+            0u64,
+            self.parameters.glwe_dimension().to_glwe_size(),
+            polynomial_size,
+            self.parameters.ciphertext_modulus(),
+        );
+
+        ShortintEngine::with_thread_local_mut(|engine| {
+            encrypt_glwe_ciphertext(
+                &self.glwe_secret_key,
+                &mut glwe,
+                &plaintext_list,
+                self.parameters.glwe_noise_distribution(),
+                &mut engine.encryption_generator,
+            );
+        });
+
+        PackedSlotsCiphertext {
+            glwe,
+            slot_count: messages.len(),
+            degree: Degree::new(message_modulus.0 - 1),
+            noise_level: NoiseLevel::NOMINAL,
+            message_modulus,
+            carry_modulus,
+        }
+    }
+
+    /// Decrypts the messages stored in the slots, each reduced to the message modulus
+    pub fn decrypt_packed_slots(&self, ct: &PackedSlotsCiphertext) -> Vec<u64> {
+        let mut plaintext_list =
+            PlaintextList::new(0u64, PlaintextCount(ct.glwe.polynomial_size().0));
+        decrypt_glwe_ciphertext(&self.glwe_secret_key, &ct.glwe, &mut plaintext_list);
+
+        let delta = (1_u64 << 63) / (ct.message_modulus.0 * ct.carry_modulus.0) as u64;
+
+        //The bit before the message
+        let rounding_bit = delta >> 1;
+
+        plaintext_list.as_ref()[..ct.slot_count]
+            .iter()
+            .map(|&decrypted| {
+                let rounding = (decrypted & rounding_bit) << 1;
+                (decrypted.wrapping_add(rounding) / delta) % ct.message_modulus.0 as u64
+            })
+            .collect()
+    }
+}
+
+impl ServerKey {
+    /// Adds slot-wise the messages of `rhs` to the ones of `lhs`
+    ///
+    /// This leaves the carries of the slots unclean.
+    pub fn unchecked_packed_slots_add_assign(
+        &self,
+        lhs: &mut PackedSlotsCiphertext,
+        rhs: &PackedSlotsCiphertext,
+    ) {
+        glwe_ciphertext_add_assign(&mut lhs.glwe, &rhs.glwe);
+        lhs.slot_count = lhs.slot_count.max(rhs.slot_count);
+        lhs.degree = lhs.degree + rhs.degree;
+        lhs.noise_level = lhs.noise_level + rhs.noise_level;
+    }
+
+    /// Adds slot-wise the messages of `rhs` to the ones of `lhs`
+    ///
+    /// If the carries of the slots would overflow, or the noise would be too high,
+    /// an error is returned and `lhs` is not modified.
+    pub fn checked_packed_slots_add_assign(
+        &self,
+        lhs: &mut PackedSlotsCiphertext,
+        rhs: &PackedSlotsCiphertext,
+    ) -> Result<(), CheckError> {
+        self.is_add_possible(lhs.noise_degree(), rhs.noise_degree())?;
+        self.unchecked_packed_slots_add_assign(lhs, rhs);
+        Ok(())
+    }
+
+    /// Multiplies all the slots by the same clear scalar
+    ///
+    /// This leaves the carries of the slots unclean.
+    pub fn unchecked_packed_slots_scalar_mul_assign(
+        &self,
+        ct: &mut PackedSlotsCiphertext,
+        scalar: u8,
+    ) {
+        glwe_ciphertext_cleartext_mul_assign(&mut ct.glwe, Cleartext(u64::from(scalar)));
+        ct.degree = Degree::new(ct.degree.get() * scalar as usize);
+        ct.noise_level = ct.noise_level * scalar as usize;
+    }
+
+    /// Multiplies all the slots by the same clear scalar
+    ///
+    /// If the carries of the slots would overflow, or the noise would be too high,
+    /// an error is returned and `ct` is not modified.
+    pub fn checked_packed_slots_scalar_mul_assign(
+        &self,
+        ct: &mut PackedSlotsCiphertext,
+        scalar: u8,
+    ) -> Result<(), CheckError> {
+        self.is_scalar_mul_possible(ct.noise_degree(), scalar)?;
+        self.unchecked_packed_slots_scalar_mul_assign(ct, scalar);
+        Ok(())
+    }
+
+    /// Extracts the slot at `index` into a shortint [Ciphertext]
+    ///
+    /// The returned ciphertext has the degree and noise level of the packed ciphertext, its carries
+    /// can then be cleaned using the usual shortint operations.
+    ///
+    /// For parameters using the [PBSOrder::BootstrapKeyswitch] order, a keyswitch is applied
+    /// to get a ciphertext encrypted under the small key.
+    ///
+    /// Returns `None` if the index is out of the slots of the packed ciphertext.
+    pub fn extract_packed_slot(
+        &self,
+        ct: &PackedSlotsCiphertext,
+        index: usize,
+    ) -> Option<Ciphertext> {
+        if index >= ct.slot_count {
+            return None;
+        }
+
+        let lwe_size = ct
+            .glwe
+            .glwe_size()
+            .to_glwe_dimension()
+            .to_equivalent_lwe_dimension(ct.glwe.polynomial_size())
+            .to_lwe_size();
+
+        let mut big_lwe = LweCiphertext::new(0u64, lwe_size, ct.glwe.ciphertext_modulus());
+
+        extract_lwe_sample_from_glwe_ciphertext(&ct.glwe, &mut big_lwe, MonomialDegree(index));
+
+        let lwe = match self.pbs_order {
+            PBSOrder::KeyswitchBootstrap => big_lwe,
+            PBSOrder::BootstrapKeyswitch => {
+                let mut small_lwe = LweCiphertext::new(
+                    0u64,
+                    self.key_switching_key.output_lwe_size(),
+                    self.ciphertext_modulus,
+                );
+                keyswitch_lwe_ciphertext(&self.key_switching_key, &big_lwe, &mut small_lwe);
+                small_lwe
+            }
+        };
+
+        Some(Ciphertext::new(
+            lwe,
+            ct.degree,
+            ct.noise_level,
+            ct.message_modulus,
+            ct.carry_modulus,
+            self.pbs_order,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::shortint::gen_keys;
+    use crate::shortint::parameters::{
+        ClassicPBSParameters, PARAM_MESSAGE_2_CARRY_2_KS_PBS, PARAM_MESSAGE_2_CARRY_2_PBS_KS,
+    };
+    use rand::Rng;
+
+    fn packed_slots_test(params: ClassicPBSParameters) {
+        let (cks, sks) = gen_keys(params);
+        let mut rng = rand::thread_rng();
+
+        let modulus = params.message_modulus.0 as u64;
+        let total_modulus = modulus * params.carry_modulus.0 as u64;
+        let slot_count = params.polynomial_size.0 / 2;
+
+        let clears_a = (0..slot_count)
+            .map(|_| rng.gen_range(0..modulus))
+            .collect::<Vec<_>>();
+        let clears_b = (0..slot_count)
+            .map(|_| rng.gen_range(0..modulus))
+            .collect::<Vec<_>>();
+
+        let mut packed = cks.encrypt_packed_slots(&clears_a);
+        let packed_b = cks.encrypt_packed_slots(&clears_b);
+        assert_eq!(packed.slot_count(), slot_count);
+        assert_eq!(cks.decrypt_packed_slots(&packed), clears_a);
+
+        sks.checked_packed_slots_add_assign(&mut packed, &packed_b)
+            .unwrap();
+        sks.checked_packed_slots_scalar_mul_assign(&mut packed, 2)
+            .unwrap();
+
+        let expected = clears_a
+            .iter()
+            .zip(clears_b.iter())
+            .map(|(a, b)| ((a + b) * 2) % total_modulus)
+            .collect::<Vec<_>>();
+
+        let decrypted = cks.decrypt_packed_slots(&packed);
+        for (decrypted, expected) in decrypted.iter().zip(expected.iter()) {
+            assert_eq!(*decrypted, expected % modulus);
+        }
+
+        for index in [0, 1, slot_count / 2, slot_count - 1] {
+            let ct = sks.extract_packed_slot(&packed, index).unwrap();
+            assert_eq!(ct.degree, packed.degree());
+            assert_eq!(cks.decrypt_message_and_carry(&ct), expected[index]);
+
+            let cleaned = sks.message_extract(&ct);
+            assert_eq!(cks.decrypt(&cleaned), expected[index] % modulus);
+        }
+
+        assert!(sks.extract_packed_slot(&packed, slot_count).is_none());
+
+        // Too many additions for the carries
+        assert!(sks
+            .checked_packed_slots_scalar_mul_assign(&mut packed, 3)
+            .is_err());
+    }
+
+    #[test]
+    fn test_packed_slots_ks_pbs() {
+        packed_slots_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    }
+
+    #[test]
+    fn test_packed_slots_pbs_ks() {
+        packed_slots_test(PARAM_MESSAGE_2_CARRY_2_PBS_KS);
+    }
+}
@@ -1,6 +1,7 @@
 use crate::shortint::parameters::*;
 use crate::shortint::ClassicPBSParameters;
 
+pub mod p_fail_2_minus_128;
 pub mod p_fail_2_minus_64;
 
 pub const ALL_PARAMETER_VEC_GAUSSIAN: [ClassicPBSParameters; 40] = [
@@ -45,3 +46,10 @@ pub const ALL_PARAMETER_VEC_GAUSSIAN: [ClassicPBSParameters; 40] = [
     PARAM_MESSAGE_3_CARRY_3_PBS_KS_GAUSSIAN_2M64,
     PARAM_MESSAGE_4_CARRY_4_PBS_KS_GAUSSIAN_2M64,
 ];
+
+pub const ALL_PARAMETER_VEC_GAUSSIAN_2M128: [ClassicPBSParameters; 4] = [
+    PARAM_MESSAGE_1_CARRY_1_KS_PBS_GAUSSIAN_2M128,
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS_GAUSSIAN_2M128,
+];
@@ -0,0 +1,95 @@
+use crate::core_crypto::prelude::*;
+use crate::shortint::ciphertext::MaxNoiseLevel;
+use crate::shortint::parameters::{CarryModulus, ClassicPBSParameters, MessageModulus};
+// p-fail = 2^-128.994, 2-norm = 3
+pub const PARAM_MESSAGE_1_CARRY_1_KS_PBS_GAUSSIAN_2M128: ClassicPBSParameters =
+    ClassicPBSParameters {
+        lwe_dimension: LweDimension(751),
+        glwe_dimension: GlweDimension(2),
+        polynomial_size: PolynomialSize(1024),
+        lwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+            1.4881320180236506e-05,
+        )),
+        glwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+            2.84526747960189e-15,
+        )),
+        pbs_base_log: DecompositionBaseLog(23),
+        pbs_level: DecompositionLevelCount(1),
+        ks_base_log: DecompositionBaseLog(3),
+        ks_level: DecompositionLevelCount(4),
+        message_modulus: MessageModulus(2),
+        carry_modulus: CarryModulus(2),
+        max_noise_level: MaxNoiseLevel::new(3),
+        log2_p_fail: -128.994,
+        ciphertext_modulus: CiphertextModulus::new_native(),
+        encryption_key_choice: EncryptionKeyChoice::Big,
+    };
+// p-fail = 2^-129.42, 2-norm = 5
+pub const PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128: ClassicPBSParameters =
+    ClassicPBSParameters {
+        lwe_dimension: LweDimension(860),
+        glwe_dimension: GlweDimension(1),
+        polynomial_size: PolynomialSize(4096),
+        lwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+            2.2693228106309527e-06,
+        )),
+        glwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+            2.168404344971009e-19,
+        )),
+        pbs_base_log: DecompositionBaseLog(22),
+        pbs_level: DecompositionLevelCount(1),
+        ks_base_log: DecompositionBaseLog(3),
+        ks_level: DecompositionLevelCount(5),
+        message_modulus: MessageModulus(4),
+        carry_modulus: CarryModulus(4),
+        max_noise_level: MaxNoiseLevel::new(5),
+        log2_p_fail: -129.42,
+        ciphertext_modulus: CiphertextModulus::new_native(),
+        encryption_key_choice: EncryptionKeyChoice::Big,
+    };
+// p-fail = 2^-128.686, 2-norm = 9
+pub const PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128: ClassicPBSParameters =
+    ClassicPBSParameters {
+        lwe_dimension: LweDimension(983),
+        glwe_dimension: GlweDimension(1),
+        polynomial_size: PolynomialSize(16384),
+        lwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+            2.7179911938548117e-07,
+        )),
+        glwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+            2.168404344971009e-19,
+        )),
+        pbs_base_log: DecompositionBaseLog(15),
+        pbs_level: DecompositionLevelCount(2),
+        ks_base_log: DecompositionBaseLog(3),
+        ks_level: DecompositionLevelCount(6),
+        message_modulus: MessageModulus(8),
+        carry_modulus: CarryModulus(8),
+        max_noise_level: MaxNoiseLevel::new(9),
+        log2_p_fail: -128.686,
+        ciphertext_modulus: CiphertextModulus::new_native(),
+        encryption_key_choice: EncryptionKeyChoice::Big,
+    };
+// p-fail = 2^-129.52, 2-norm = 17
+pub const PARAM_MESSAGE_4_CARRY_4_KS_PBS_GAUSSIAN_2M128: ClassicPBSParameters =
+    ClassicPBSParameters {
+        lwe_dimension: LweDimension(1082),
+        glwe_dimension: GlweDimension(1),
+        polynomial_size: PolynomialSize(65536),
+        lwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+            4.925310492031207e-08,
+        )),
+        glwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+            2.168404344971009e-19,
+        )),
+        pbs_base_log: DecompositionBaseLog(11),
+        pbs_level: DecompositionLevelCount(3),
+        ks_base_log: DecompositionBaseLog(2),
+        ks_level: DecompositionLevelCount(11),
+        message_modulus: MessageModulus(16),
+        carry_modulus: CarryModulus(16),
+        max_noise_level: MaxNoiseLevel::new(17),
+        log2_p_fail: -129.52,
+        ciphertext_modulus: CiphertextModulus::new_native(),
+        encryption_key_choice: EncryptionKeyChoice::Big,
+    };
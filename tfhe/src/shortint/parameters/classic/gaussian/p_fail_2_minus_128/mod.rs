@@ -0,0 +1,8 @@
+//! Parameters with a bootstrapping failure probability of at most $2^{-128}$, for applications
+//! that cannot tolerate the $2^{-64}$ of the default parameters.
+//!
+//! The noise distributions are the minimal ones for 132 bits of security for the given
+//! dimensions, as for the $2^{-64}$ parameters, and the failure probabilities are computed with
+//! the formulas of [`noise_formulas`](crate::core_crypto::commons::noise_formulas) for the
+//! keyswitch and the PBS, to which the modulus switching noise is added.
+pub mod ks_pbs;
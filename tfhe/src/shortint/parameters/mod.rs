@@ -28,6 +28,7 @@ pub mod coverage_parameters;
 pub mod key_switching;
 pub mod list_compression;
 pub mod multi_bit;
+pub mod noise_squashing;
 pub mod parameters_wopbs;
 pub mod parameters_wopbs_message_carry;
 pub mod parameters_wopbs_only;
@@ -38,6 +39,7 @@ pub use super::PBSOrder;
 pub use crate::core_crypto::commons::parameters::EncryptionKeyChoice;
 use crate::shortint::ciphertext::MaxDegree;
 pub use crate::shortint::parameters::classic::compact_pk::*;
+pub use crate::shortint::parameters::classic::gaussian::p_fail_2_minus_128::ks_pbs::*;
 pub use crate::shortint::parameters::classic::gaussian::p_fail_2_minus_64::ks_pbs::*;
 pub use crate::shortint::parameters::classic::gaussian::p_fail_2_minus_64::pbs_ks::*;
 pub use crate::shortint::parameters::classic::tuniform::p_fail_2_minus_64::ks_pbs::*;
@@ -50,7 +52,9 @@ pub use compact_public_key_only::{
 #[cfg(tarpaulin)]
 pub use coverage_parameters::*;
 pub use key_switching::ShortintKeySwitchingParameters;
+pub use multi_bit::p_fail_2_minus_128::ks_pbs::*;
 pub use multi_bit::*;
+pub use noise_squashing::NoiseSquashingParameters;
 pub use parameters_wopbs::*;
 
 /// The modulus of the message space. For a given plaintext $p$ we have the message $m$ defined as
@@ -222,6 +226,7 @@ pub enum PBSParameters {
 /// Can be used on a server to check if client inputs are well formed
 /// before running a computation on them
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
 pub struct CiphertextConformanceParams {
     pub ct_params: LweCiphertextParameters<u64>,
     pub message_modulus: MessageModulus,
@@ -235,6 +240,7 @@ pub struct CiphertextConformanceParams {
 /// Can be used on a server to check if client inputs are well formed
 /// before running a computation on them
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
 pub struct CompressedCiphertextConformanceParams {
     pub ct_params: GlweCiphertextConformanceParameters<u64>,
     pub lwe_per_glwe: LweCiphertextCount,
@@ -249,6 +255,7 @@ pub struct CompressedCiphertextConformanceParams {
 /// Can be used on a server to check if client inputs are well formed
 /// before running a computation on them
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
 pub struct CiphertextListConformanceParams {
     pub ct_list_params: LweCiphertextListParameters<u64>,
     pub message_modulus: MessageModulus,
@@ -1,4 +1,5 @@
 //! #Warning experimental
+pub mod p_fail_2_minus_128;
 pub mod p_fail_2_minus_64;
 
 use super::{CiphertextConformanceParams, MultiBitPBSParametersVersions};
@@ -11,6 +12,7 @@ use crate::core_crypto::prelude::{
     LweCiphertextParameters, MsDecompressionType, MultiBitBootstrapKeyConformanceParams,
 };
 use crate::shortint::ciphertext::{Degree, MaxNoiseLevel, NoiseLevel};
+use crate::shortint::parameters::p_fail_2_minus_128::ks_pbs::*;
 use crate::shortint::parameters::p_fail_2_minus_64::ks_pbs::*;
 use crate::shortint::parameters::p_fail_2_minus_64::ks_pbs_gpu::*;
 use crate::shortint::parameters::{
@@ -119,6 +121,15 @@ pub const ALL_MULTI_BIT_PARAMETER_VEC: [MultiBitPBSParameters; 6] = [
     PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_3_KS_PBS,
 ];
 
+pub const ALL_MULTI_BIT_PARAMETER_VEC_2M128: [MultiBitPBSParameters; 6] = [
+    PARAM_MULTI_BIT_GROUP_2_MESSAGE_1_CARRY_1_KS_PBS_GAUSSIAN_2M128,
+    PARAM_MULTI_BIT_GROUP_2_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128,
+    PARAM_MULTI_BIT_GROUP_2_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128,
+    PARAM_MULTI_BIT_GROUP_3_MESSAGE_1_CARRY_1_KS_PBS_GAUSSIAN_2M128,
+    PARAM_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128,
+    PARAM_MULTI_BIT_GROUP_3_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128,
+];
+
 // Convenience aliases
 pub const PARAM_MULTI_BIT_MESSAGE_1_CARRY_1_GROUP_2_KS_PBS: MultiBitPBSParameters =
     PARAM_MULTI_BIT_GROUP_2_MESSAGE_1_CARRY_1_KS_PBS_GAUSSIAN_2M64;
@@ -0,0 +1,7 @@
+//! Multi-bit parameters with a bootstrapping failure probability of at most $2^{-128}$.
+//!
+//! The multi-bit PBS noise is upper bounded with the classic PBS noise of each of the $2^g$
+//! keys of a group, with a margin covering the largest deviation of this bound from the failure
+//! probabilities of the $2^{-64}$ multi-bit parameters. The failure probabilities given here are
+//! thus upper bounds.
+pub mod ks_pbs;
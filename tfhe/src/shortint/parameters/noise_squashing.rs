@@ -0,0 +1,34 @@
+use tfhe_versionable::Versionize;
+
+use crate::core_crypto::prelude::CiphertextModulus as CoreCiphertextModulus;
+use crate::shortint::backward_compatibility::parameters::noise_squashing::NoiseSquashingParametersVersions;
+use crate::shortint::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, DynamicDistribution, GlweDimension,
+    PolynomialSize,
+};
+use std::fmt::Debug;
+
+/// Parameters of the keys used to squash the noise of ciphertexts
+///
+/// The squashed ciphertexts live under a 128 bits modulus, so that their noise is negligible
+/// compared to the message, which is what threshold decryption protocols require.
+#[derive(Copy, Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(NoiseSquashingParametersVersions)]
+pub struct NoiseSquashingParameters {
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub glwe_noise_distribution: DynamicDistribution<u128>,
+    pub decomp_base_log: DecompositionBaseLog,
+    pub decomp_level_count: DecompositionLevelCount,
+    pub ciphertext_modulus: CoreCiphertextModulus<u128>,
+}
+
+pub const NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64: NoiseSquashingParameters =
+    NoiseSquashingParameters {
+        glwe_dimension: GlweDimension(2),
+        polynomial_size: PolynomialSize(2048),
+        glwe_noise_distribution: DynamicDistribution::new_t_uniform(30),
+        decomp_base_log: DecompositionBaseLog(24),
+        decomp_level_count: DecompositionLevelCount(3),
+        ciphertext_modulus: CoreCiphertextModulus::<u128>::new_native(),
+    };
@@ -2,7 +2,7 @@ use crate::conformance::ParameterSetConformant;
 use crate::core_crypto::prelude::{
     allocate_and_generate_new_binary_lwe_secret_key,
     allocate_and_generate_new_seeded_lwe_compact_public_key, generate_lwe_compact_public_key,
-    Container, LweCiphertextCount, LweCompactCiphertextListOwned,
+    Container, ContainerMut, LweCiphertextCount, LweCompactCiphertextListOwned,
     LweCompactPublicKeyEncryptionParameters, LweCompactPublicKeyOwned, LweSecretKey, Plaintext,
     PlaintextList, SeededLweCompactPublicKeyOwned,
 };
@@ -21,6 +21,7 @@ use crate::zk::{CompactPkePublicParams, ZkComputeLoad};
 use crate::Error;
 use serde::{Deserialize, Serialize};
 use tfhe_versionable::Versionize;
+use zeroize::Zeroize;
 
 /// Private key from which a [`CompactPublicKey`] can be built.
 #[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
@@ -30,6 +31,12 @@ pub struct CompactPrivateKey<KeyCont: Container<Element = u64>> {
     parameters: CompactPublicKeyEncryptionParameters,
 }
 
+impl<C: ContainerMut<Element = u64>> Zeroize for CompactPrivateKey<C> {
+    fn zeroize(&mut self) {
+        self.key.zeroize();
+    }
+}
+
 impl<C: Container<Element = u64>> CompactPrivateKey<C> {
     pub fn from_raw_parts(
         key: LweSecretKey<C>,
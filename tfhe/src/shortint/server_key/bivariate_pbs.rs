@@ -231,6 +231,75 @@ impl ServerKey {
         ct_left: &mut Ciphertext,
         ct_right: &mut Ciphertext,
         acc: &BivariateLookupTableOwned,
+    ) {
+        self.clean_for_bivariate_lookup_table_if_needed(ct_left, ct_right, acc);
+
+        self.unchecked_apply_lookup_table_bivariate_assign(ct_left, ct_right, acc);
+    }
+
+    /// Compute a keyswitch and programmable bootstrap on the packing of two ciphertexts.
+    ///
+    /// The degrees of the inputs are used to check whether `ct_left` and `ct_right` can be packed
+    /// together without exceeding the plaintext space. If that is the case, no carry is cleaned,
+    /// otherwise the carries of both inputs are cleaned, in place, before the packing. This does
+    /// not change the values they encrypt.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let msg: u64 = 3;
+    /// let msg2: u64 = 2;
+    /// let mut ct1 = cks.encrypt(msg);
+    /// let mut ct2 = cks.encrypt(msg2);
+    /// let modulus = cks.parameters.message_modulus().0 as u64;
+    ///
+    /// // Fill the carries of the first ciphertext
+    /// sks.unchecked_scalar_add_assign(&mut ct1, 3);
+    ///
+    /// // Generate the lookup table for the function f: x, y -> (x * y * x) mod 4
+    /// let acc = sks.generate_lookup_table_bivariate(|x, y| x * y * x % modulus);
+    /// let ct_res = sks.smart_apply_lookup_table_bivariate(&mut ct1, &mut ct2, &acc);
+    ///
+    /// let msg = (msg + 3) % modulus;
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, (msg * msg2 * msg) % modulus);
+    /// ```
+    pub fn smart_apply_lookup_table_bivariate(
+        &self,
+        ct_left: &mut Ciphertext,
+        ct_right: &mut Ciphertext,
+        acc: &BivariateLookupTableOwned,
+    ) -> Ciphertext {
+        self.clean_for_bivariate_lookup_table_if_needed(ct_left, ct_right, acc);
+
+        self.unchecked_apply_lookup_table_bivariate(ct_left, ct_right, acc)
+    }
+
+    /// Compute a keyswitch and programmable bootstrap on the packing of two ciphertexts.
+    ///
+    /// See [Self::smart_apply_lookup_table_bivariate].
+    pub fn smart_apply_lookup_table_bivariate_assign(
+        &self,
+        ct_left: &mut Ciphertext,
+        ct_right: &mut Ciphertext,
+        acc: &BivariateLookupTableOwned,
+    ) {
+        self.clean_for_bivariate_lookup_table_if_needed(ct_left, ct_right, acc);
+
+        self.unchecked_apply_lookup_table_bivariate_assign(ct_left, ct_right, acc);
+    }
+
+    fn clean_for_bivariate_lookup_table_if_needed(
+        &self,
+        ct_left: &mut Ciphertext,
+        ct_right: &mut Ciphertext,
+        acc: &BivariateLookupTableOwned,
     ) {
         if self
             .is_functional_bivariate_pbs_possible(
@@ -253,8 +322,6 @@ impl ServerKey {
             Some(acc),
         )
         .unwrap();
-
-        self.unchecked_apply_lookup_table_bivariate_assign(ct_left, ct_right, acc);
     }
     /// Generic programmable bootstrap where messages are concatenated into one ciphertext to
     /// evaluate a bivariate function. This is used to apply many binary operations (comparisons,
@@ -0,0 +1,137 @@
+use super::{CheckError, CiphertextNoiseDegree, ManyLookupTableOwned, ServerKey};
+use crate::shortint::ciphertext::{Degree, MaxDegree};
+use crate::shortint::Ciphertext;
+use std::collections::HashMap;
+
+/// Generates many lookup tables sized for the degree of the ciphertexts they are applied to.
+///
+/// A many lookup table packs several functions in a single accumulator, so that they are all
+/// evaluated with a single PBS. The more functions are packed, the smaller the input domain of
+/// each function: for an input ciphertext of degree `d`, at most
+/// `message_modulus * carry_modulus / (d + 1)` functions can be evaluated at once. Applying a many
+/// lookup table to a ciphertext with a greater degree leads to wrong results.
+///
+/// The factory holds the user provided functions, and for a given input degree it generates the
+/// minimal number of many lookup tables, each packing as many functions as safely possible. The
+/// lookup tables are cached by number of packed functions, so that inputs with different degrees
+/// reuse them when possible.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::gen_keys;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+/// use tfhe::shortint::server_key::ManyLutFactory;
+///
+/// // Generate the client key and the server key:
+/// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+///
+/// let f1 = |x: u64| x.pow(2) % 4;
+/// let f2 = |x: u64| x.count_ones() as u64 % 4;
+/// let f3 = |x: u64| (x + 1) % 4;
+/// let functions: &[&dyn Fn(u64) -> u64] = &[&f1, &f2, &f3];
+///
+/// let mut factory = ManyLutFactory::new(&sks, functions);
+///
+/// // A fresh ciphertext has a degree of 3, so at most 16 / 4 = 4 functions fit in a single LUT
+/// let ct = cks.encrypt(3);
+/// assert_eq!(factory.max_function_count(ct.degree), 4);
+///
+/// let results = factory.apply(&ct).unwrap();
+/// for (res, function) in results.iter().zip(functions) {
+///     assert_eq!(cks.decrypt(res), function(3));
+/// }
+///
+/// // With a degree of 6, only 2 functions can be packed, so 2 LUTs are needed
+/// let ct = sks.unchecked_add(&ct, &cks.encrypt(1));
+/// assert_eq!(factory.luts_for(ct.noise_degree()).unwrap().len(), 2);
+/// ```
+pub struct ManyLutFactory<'a> {
+    server_key: &'a ServerKey,
+    functions: &'a [&'a dyn Fn(u64) -> u64],
+    luts: HashMap<usize, Vec<ManyLookupTableOwned>>,
+}
+
+impl<'a> ManyLutFactory<'a> {
+    /// Creates a factory evaluating the given functions with the given server key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no function is provided.
+    pub fn new(server_key: &'a ServerKey, functions: &'a [&'a dyn Fn(u64) -> u64]) -> Self {
+        assert!(
+            !functions.is_empty(),
+            "At least one function is required to generate many lookup tables"
+        );
+
+        Self {
+            server_key,
+            functions,
+            luts: HashMap::new(),
+        }
+    }
+
+    /// Returns the number of functions of the factory
+    pub fn function_count(&self) -> usize {
+        self.functions.len()
+    }
+
+    /// Returns the maximum number of functions that can be evaluated at once on a ciphertext of
+    /// the given degree.
+    ///
+    /// This is 0 if the degree uses the padding bit, in which case no lookup table can be applied.
+    pub fn max_function_count(&self, input_degree: Degree) -> usize {
+        let modulus_sup = self.server_key.message_modulus.0 * self.server_key.carry_modulus.0;
+
+        (modulus_sup / (input_degree.get() + 1)).min(modulus_sup / 2)
+    }
+
+    /// Returns the many lookup tables evaluating all the functions of the factory on a ciphertext
+    /// with the given degree and noise level.
+    ///
+    /// Each lookup table packs as many functions as the input degree allows, in the order of the
+    /// functions, the last one possibly packing fewer functions.
+    ///
+    /// Returns an error if the degree of the input uses the padding bit, or if its noise level is
+    /// too big to be bootstrapped.
+    pub fn luts_for(
+        &mut self,
+        input: CiphertextNoiseDegree,
+    ) -> Result<&[ManyLookupTableOwned], CheckError> {
+        let server_key = self.server_key;
+
+        MaxDegree::from_msg_carry_modulus(server_key.message_modulus, server_key.carry_modulus)
+            .validate(input.degree)?;
+        server_key.max_noise_level.validate(input.noise_level)?;
+
+        let functions_per_lut = self
+            .max_function_count(input.degree)
+            .min(self.functions.len());
+
+        let functions = self.functions;
+        let luts = self.luts.entry(functions_per_lut).or_insert_with(|| {
+            functions
+                .chunks(functions_per_lut)
+                .map(|chunk| server_key.generate_many_lookup_table(chunk))
+                .collect()
+        });
+
+        Ok(luts.as_slice())
+    }
+
+    /// Evaluates all the functions of the factory on the given ciphertext, using the minimal
+    /// number of PBS for its degree.
+    ///
+    /// The results are returned in the order of the functions.
+    ///
+    /// Returns an error under the same conditions as [`Self::luts_for`].
+    pub fn apply(&mut self, ct: &Ciphertext) -> Result<Vec<Ciphertext>, CheckError> {
+        let server_key = self.server_key;
+        let luts = self.luts_for(ct.noise_degree())?;
+
+        Ok(luts
+            .iter()
+            .flat_map(|lut| server_key.apply_many_lookup_table(ct, lut))
+            .collect())
+    }
+}
@@ -7,6 +7,7 @@ mod bitwise_op;
 mod bivariate_pbs;
 mod comp_op;
 mod div_mod;
+mod many_lut;
 mod modulus_switched_compression;
 mod mul;
 mod neg;
@@ -24,6 +25,7 @@ pub use bivariate_pbs::{
     BivariateLookupTableMutView, BivariateLookupTableOwned, BivariateLookupTableView,
 };
 pub use compressed::{CompressedServerKey, ShortintCompressedBootstrappingKey};
+pub use many_lut::ManyLutFactory;
 pub(crate) use scalar_mul::unchecked_scalar_mul_assign;
 
 #[cfg(test)]
@@ -46,6 +48,7 @@ use crate::core_crypto::entities::*;
 use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::BootstrapKeyConformanceParams;
 use crate::core_crypto::fft_impl::fft64::math::fft::Fft;
 use crate::core_crypto::prelude::ComputationBuffers;
+use crate::safe_serialization::{SerializedSizeBound, OBJECT_METADATA_SIZE_BOUND};
 use crate::shortint::ciphertext::{Ciphertext, Degree, MaxDegree, MaxNoiseLevel, NoiseLevel};
 use crate::shortint::client_key::ClientKey;
 use crate::shortint::engine::{
@@ -314,20 +317,51 @@ impl From<ShortintBootstrappingKey>
     }
 }
 
+const DRY_RUN_SERIALIZATION_ERROR: &str = "A dry run server key cannot be serialized";
+
 impl Serialize for ShortintBootstrappingKey {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
+        if self.is_dry_run() {
+            return Err(serde::ser::Error::custom(DRY_RUN_SERIALIZATION_ERROR));
+        }
+
         SerializableShortintBootstrappingKey::from(self).serialize(serializer)
     }
 }
 
+/// Versioned representation of a [`ShortintBootstrappingKey`], which is serialized like the
+/// versioned [`SerializableShortintBootstrappingKey`] but refuses to serialize the key of a dry
+/// run server key
+#[cfg_attr(tfhe_lints, allow(tfhe_lints::serialize_without_versionize))]
+pub struct ShortintBootstrappingKeyVersioned {
+    key: SerializableShortintBootstrappingKeyVersionedOwned,
+    is_dry_run: bool,
+}
+
+impl Serialize for ShortintBootstrappingKeyVersioned {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if self.is_dry_run {
+            return Err(serde::ser::Error::custom(DRY_RUN_SERIALIZATION_ERROR));
+        }
+
+        self.key.serialize(serializer)
+    }
+}
+
 impl Versionize for ShortintBootstrappingKey {
-    type Versioned<'vers> = SerializableShortintBootstrappingKeyVersionedOwned;
+    type Versioned<'vers> = ShortintBootstrappingKeyVersioned;
 
     fn versionize(&self) -> Self::Versioned<'_> {
-        SerializableShortintBootstrappingKey::from(self).versionize_owned()
+        ShortintBootstrappingKeyVersioned {
+            key: SerializableShortintBootstrappingKey::from(self).versionize_owned(),
+            is_dry_run: self.is_dry_run(),
+        }
     }
 }
 
@@ -386,6 +420,12 @@ impl Unversionize for ShortintBootstrappingKey {
 }
 
 impl ShortintBootstrappingKey {
+    /// Returns `true` if this is the empty key of a dry run server key, which has an input LWE
+    /// dimension of zero
+    pub fn is_dry_run(&self) -> bool {
+        self.input_lwe_dimension().0 == 0
+    }
+
     pub fn input_lwe_dimension(&self) -> LweDimension {
         match self {
             Self::Classic(inner) => inner.input_lwe_dimension(),
@@ -621,6 +661,27 @@ impl ServerKey {
         })
     }
 
+    /// Creates a server key for dry runs, with a chosen maximum degree
+    ///
+    /// The key holds no key material, only the dimensions needed to create trivial ciphertexts.
+    /// As the PBS of trivial ciphertexts are evaluated in the clear, the key computes correctly
+    /// on trivial ciphertexts without executing any PBS. Computing a PBS on a non-trivial
+    /// ciphertext panics, and the key can neither be serialized nor pass a conformance check.
+    pub(crate) fn new_dry_run_with_max_degree(
+        parameters: PBSParameters,
+        max_degree: MaxDegree,
+    ) -> Self {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_dry_run_server_key_with_max_degree(parameters, max_degree)
+        })
+    }
+
+    /// Returns `true` if this key was created for dry runs, in which case it can only compute on
+    /// trivial ciphertexts
+    pub fn is_dry_run(&self) -> bool {
+        self.bootstrapping_key.is_dry_run()
+    }
+
     pub fn ciphertext_lwe_dimension(&self) -> LweDimension {
         match self.pbs_order {
             PBSOrder::KeyswitchBootstrap => self.key_switching_key.input_key_lwe_dimension(),
@@ -1704,6 +1765,7 @@ where
 }
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
 pub struct PBSConformanceParameters {
     pub in_lwe_dimension: LweDimension,
     pub out_glwe_dimension: GlweDimension,
@@ -1776,6 +1838,10 @@ impl ParameterSetConformant for ServerKey {
             pbs_order,
         } = self;
 
+        if bootstrapping_key.is_dry_run() {
+            return false;
+        }
+
         let params: PBSConformanceParameters = parameter_set.into();
 
         let pbs_key_ok = bootstrapping_key.is_conformant(&params);
@@ -1800,3 +1866,59 @@ impl ParameterSetConformant for ServerKey {
             && *ciphertext_modulus == parameter_set.ciphertext_modulus()
     }
 }
+
+impl SerializedSizeBound for ServerKey {
+    fn serialized_size_bound((parameter_set, _): &Self::ParameterSet) -> u64 {
+        let big_lwe_dimension = parameter_set
+            .glwe_dimension()
+            .to_equivalent_lwe_dimension(parameter_set.polynomial_size())
+            .0 as u64;
+        let small_lwe_size = parameter_set.lwe_dimension().to_lwe_size().0 as u64;
+
+        // The keyswitching key goes from the big key to the small key whatever the PBS order
+        let ksk_size = 8 * big_lwe_dimension * parameter_set.ks_level().0 as u64 * small_lwe_size;
+
+        let bsk_size = fourier_bootstrap_key_size_bound(
+            parameter_set.lwe_dimension(),
+            match parameter_set {
+                PBSParameters::PBS(_) => None,
+                PBSParameters::MultiBitPBS(multi_bit_params) => {
+                    Some(multi_bit_params.grouping_factor)
+                }
+            },
+            parameter_set.pbs_level(),
+            parameter_set.glwe_dimension().to_glwe_size(),
+            parameter_set.polynomial_size(),
+        );
+
+        ksk_size + bsk_size + OBJECT_METADATA_SIZE_BOUND
+    }
+}
+
+/// Returns an upper bound of the serialized size of a bootstrapping key in the Fourier domain
+pub(crate) fn fourier_bootstrap_key_size_bound(
+    input_lwe_dimension: LweDimension,
+    grouping_factor: Option<LweBskGroupingFactor>,
+    decomp_level_count: DecompositionLevelCount,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+) -> u64 {
+    let input_lwe_dimension = input_lwe_dimension.0 as u64;
+
+    let ggsw_count = match grouping_factor {
+        None => input_lwe_dimension,
+        Some(grouping_factor) => {
+            let grouping_factor = grouping_factor.0 as u64;
+            input_lwe_dimension.div_ceil(grouping_factor) * (1 << grouping_factor)
+        }
+    };
+
+    // Each GGSW has level * glwe_size^2 polynomials, of polynomial_size / 2 complex coefficients
+    // of 16 bytes in the Fourier domain
+    let glwe_size = glwe_size.0 as u64;
+    16 * ggsw_count
+        * decomp_level_count.0 as u64
+        * glwe_size
+        * glwe_size
+        * (polynomial_size.0 as u64 / 2)
+}
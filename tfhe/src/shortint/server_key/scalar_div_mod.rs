@@ -94,6 +94,52 @@ impl ServerKey {
         ct.degree = Degree::new(ct.degree.get() / scalar as usize);
     }
 
+    /// Compute a division of a ciphertext by a scalar.
+    ///
+    /// The division is computed by a PBS which only reads the message part of the input, so this
+    /// is always possible whatever the degree of `ct` and the carries never need to be cleaned
+    /// beforehand.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `scalar == 0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Encrypt one message, and fill its carries
+    /// let mut ct_1 = cks.encrypt(3);
+    /// sks.unchecked_scalar_add_assign(&mut ct_1, 3);
+    ///
+    /// // Compute homomorphically a division
+    /// let ct_res = sks.smart_scalar_div(&mut ct_1, 2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(((3 + 3) % 4) / 2, res);
+    /// ```
+    #[allow(clippy::needless_pass_by_ref_mut)]
+    pub fn smart_scalar_div(&self, ct: &mut Ciphertext, scalar: u8) -> Ciphertext {
+        self.unchecked_scalar_div(ct, scalar)
+    }
+
+    /// Compute a division of a ciphertext by a scalar.
+    ///
+    /// See [Self::smart_scalar_div].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `scalar == 0`.
+    pub fn smart_scalar_div_assign(&self, ct: &mut Ciphertext, scalar: u8) {
+        self.unchecked_scalar_div_assign(ct, scalar);
+    }
+
     /// Alias to [`unchecked_scalar_mod`](`Self::unchecked_scalar_mod`) provided for convenience
     ///
     /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
@@ -182,4 +228,50 @@ impl ServerKey {
         self.apply_lookup_table_assign(ct, &acc);
         ct.degree = Degree::new(modulus as usize - 1);
     }
+
+    /// Compute homomorphically a modular reduction.
+    ///
+    /// The reduction is computed by a PBS which only reads the message part of the input, so this
+    /// is always possible whatever the degree of `ct` and the carries never need to be cleaned
+    /// beforehand.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `modulus == 0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Encrypt one message, and fill its carries
+    /// let mut ct_1 = cks.encrypt(3);
+    /// sks.unchecked_scalar_add_assign(&mut ct_1, 3);
+    ///
+    /// // Compute homomorphically a modular reduction
+    /// let ct_res = sks.smart_scalar_mod(&mut ct_1, 2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(((3 + 3) % 4) % 2, res);
+    /// ```
+    #[allow(clippy::needless_pass_by_ref_mut)]
+    pub fn smart_scalar_mod(&self, ct: &mut Ciphertext, modulus: u8) -> Ciphertext {
+        self.unchecked_scalar_mod(ct, modulus)
+    }
+
+    /// Compute homomorphically a modular reduction.
+    ///
+    /// See [Self::smart_scalar_mod].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `modulus == 0`.
+    pub fn smart_scalar_mod_assign(&self, ct: &mut Ciphertext, modulus: u8) {
+        self.unchecked_scalar_mod_assign(ct, modulus);
+    }
 }
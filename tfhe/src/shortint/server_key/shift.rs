@@ -264,6 +264,89 @@ impl ServerKey {
         ct.degree = Degree::new(ct.degree.get() >> shift);
     }
 
+    /// Compute homomorphically a right shift of the bits.
+    ///
+    /// The shift is computed by a PBS which only reads the message part of the input, so unlike
+    /// the left shift, this is always possible whatever the degree of `ct` and the carries never
+    /// need to be cleaned beforehand.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let msg = 2;
+    ///
+    /// // Encrypt a message, and fill its carries
+    /// let mut ct = cks.encrypt(msg);
+    /// sks.unchecked_scalar_add_assign(&mut ct, 3);
+    /// // |       ct        |
+    /// // | carry | message |
+    /// // |-------|---------|
+    /// // |  0 1  |   0 1   |
+    ///
+    /// // Compute homomorphically a right shift
+    /// let shift: u8 = 1;
+    /// let ct_res = sks.smart_scalar_right_shift(&mut ct, shift);
+    /// // |      ct_res     |
+    /// // | carry | message |
+    /// // |-------|---------|
+    /// // |  0 0  |   0 0   |
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(((msg + 3) % 4) >> shift, dec);
+    /// ```
+    #[allow(clippy::needless_pass_by_ref_mut)]
+    pub fn smart_scalar_right_shift(&self, ct: &mut Ciphertext, shift: u8) -> Ciphertext {
+        self.unchecked_scalar_right_shift(ct, shift)
+    }
+
+    /// Compute homomorphically a right shift of the bits.
+    ///
+    /// The shift is computed by a PBS which only reads the message part of the input, so unlike
+    /// the left shift, this is always possible whatever the degree of `ct` and the carries never
+    /// need to be cleaned beforehand.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let msg = 2;
+    ///
+    /// // Encrypt a message, and fill its carries
+    /// let mut ct = cks.encrypt(msg);
+    /// sks.unchecked_scalar_add_assign(&mut ct, 3);
+    /// // |       ct        |
+    /// // | carry | message |
+    /// // |-------|---------|
+    /// // |  0 1  |   0 1   |
+    ///
+    /// // Compute homomorphically a right shift
+    /// let shift: u8 = 1;
+    /// sks.smart_scalar_right_shift_assign(&mut ct, shift);
+    /// // |       ct        |
+    /// // | carry | message |
+    /// // |-------|---------|
+    /// // |  0 0  |   0 0   |
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct);
+    /// assert_eq!(((msg + 3) % 4) >> shift, dec);
+    /// ```
+    pub fn smart_scalar_right_shift_assign(&self, ct: &mut Ciphertext, shift: u8) {
+        self.unchecked_scalar_right_shift_assign(ct, shift);
+    }
+
     /// Compute homomorphically a left shift of the bits.
     ///
     /// This returns a new ciphertext.
@@ -1,7 +1,7 @@
 use super::{NB_SUB_TEST_SMART, NB_TESTS, NB_TESTS_SMART};
 use crate::shortint::keycache::KEY_CACHE;
 use crate::shortint::parameters::*;
-use crate::shortint::server_key::{LookupTableOwned, ManyLookupTableOwned};
+use crate::shortint::server_key::{LookupTableOwned, ManyLookupTableOwned, ManyLutFactory};
 use rand::Rng;
 
 // Macro to generate tests for all parameter sets
@@ -90,6 +90,7 @@ create_parametrized_test!(shortint_encrypt_decrypt_without_padding);
 create_parametrized_test!(shortint_keyswitch_bootstrap);
 create_parametrized_test!(shortint_keyswitch_programmable_bootstrap);
 create_parametrized_test!(shortint_keyswitch_programmable_bootstrap_many_lut);
+create_parametrized_test!(shortint_many_lut_factory);
 create_parametrized_test!(shortint_carry_extract);
 create_parametrized_test!(shortint_message_extract);
 create_parametrized_test!(shortint_generate_lookup_table);
@@ -111,6 +112,7 @@ create_parametrized_test!(shortint_unchecked_scalar_mul);
 create_parametrized_test!(shortint_smart_scalar_mul);
 create_parametrized_test!(shortint_default_scalar_mul);
 create_parametrized_test!(shortint_unchecked_right_shift);
+create_parametrized_test!(shortint_smart_right_shift);
 create_parametrized_test!(shortint_default_right_shift);
 create_parametrized_test!(shortint_unchecked_left_shift);
 create_parametrized_test!(shortint_default_left_shift);
@@ -370,6 +372,67 @@ where
     }
 }
 
+fn shortint_many_lut_factory<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+
+    let msg_modulus = cks.parameters.message_modulus().0 as u64;
+    let carry_modulus = cks.parameters.carry_modulus().0 as u64;
+    let modulus_sup = msg_modulus * carry_modulus;
+
+    let f1 = |x: u64| x * x % msg_modulus;
+    let f2 = |x: u64| (x.count_ones() as u64) % msg_modulus;
+    let f3 = |x: u64| (x.wrapping_add(1)) % msg_modulus;
+    let f4 = |x: u64| (x * 3) % msg_modulus;
+    let f5 = |x: u64| (x / 2) % msg_modulus;
+
+    let functions: &[&dyn Fn(u64) -> u64] = &[&f1, &f2, &f3, &f4, &f5];
+    let mut factory = ManyLutFactory::new(sks, functions);
+
+    // The degree is an upper bound of the message, any degree between the message and the
+    // padding bit must give correct results
+    for degree in (msg_modulus - 1)..modulus_sup {
+        let clear = rng.gen::<u64>() % msg_modulus;
+        let mut ct = cks.encrypt(clear);
+        ct.degree = Degree::new(degree as usize);
+
+        let max_fn_count = factory.max_function_count(ct.degree);
+        assert!(max_fn_count >= 1);
+        assert!(max_fn_count * (degree as usize + 1) <= modulus_sup as usize);
+
+        let functions_per_lut = max_fn_count.min(functions.len());
+        let luts = factory.luts_for(ct.noise_degree()).unwrap();
+        assert_eq!(luts.len(), functions.len().div_ceil(functions_per_lut));
+        for lut in luts {
+            assert!(lut.input_max_degree.get() >= degree as usize);
+        }
+
+        let results = factory.apply(&ct).unwrap();
+        assert_eq!(results.len(), functions.len());
+        for (fn_idx, (res, function)) in results.iter().zip(functions).enumerate() {
+            let dec = cks.decrypt(res);
+            let function_eval = function(clear);
+
+            assert_eq!(
+                dec, function_eval,
+                "Evaluation of function #{fn_idx} on {clear} with degree {degree} failed, \
+                got {dec}, expected {function_eval}",
+            );
+        }
+    }
+
+    // A degree using the padding bit cannot be bootstrapped
+    let mut ct = cks.encrypt(0);
+    ct.degree = Degree::new(modulus_sup as usize);
+    assert_eq!(factory.max_function_count(ct.degree), 0);
+    assert!(factory.apply(&ct).is_err());
+}
+
 fn shortint_carry_extract<P>(param: P)
 where
     P: Into<PBSParameters>,
@@ -1331,6 +1394,35 @@ where
     }
 }
 
+fn shortint_smart_right_shift<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    for _ in 0..NB_TESTS_SMART {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let shift = rng.gen::<u64>() % 2;
+
+        // Make the ciphertext have carries
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        sks.unchecked_scalar_add_assign(&mut ctxt_0, clear_1 as u8);
+        let clear = (clear_0 + clear_1) % modulus;
+
+        let ct_res = sks.smart_scalar_right_shift(&mut ctxt_0, shift as u8);
+
+        let dec_res = cks.decrypt(&ct_res);
+
+        assert_eq!(clear >> shift, dec_res);
+    }
+}
+
 fn shortint_default_right_shift<P>(param: P)
 where
     P: Into<PBSParameters>,
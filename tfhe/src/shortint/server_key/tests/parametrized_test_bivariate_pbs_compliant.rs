@@ -144,6 +144,8 @@ create_parametrized_test_bivariate_pbs_compliant!(shortint_smart_scalar_greater)
 create_parametrized_test_bivariate_pbs_compliant!(shortint_smart_scalar_greater_or_equal);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_div);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_scalar_div);
+create_parametrized_test_bivariate_pbs_compliant!(shortint_smart_scalar_div_mod);
+create_parametrized_test_bivariate_pbs_compliant!(shortint_smart_apply_lookup_table_bivariate);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_mod);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_mul_lsb);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_mul_msb);
@@ -1242,6 +1244,75 @@ where
     }
 }
 
+fn shortint_smart_scalar_div_mod<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    for _ in 0..NB_TESTS_SMART {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let scalar = (rng.gen::<u64>() % (modulus - 1)) + 1;
+
+        // Make the ciphertext have carries
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        sks.unchecked_scalar_add_assign(&mut ctxt_0, clear_1 as u8);
+        let clear = (clear_0 + clear_1) % modulus;
+
+        let ct_res = sks.smart_scalar_div(&mut ctxt_0, scalar as u8);
+        let dec_res = cks.decrypt(&ct_res);
+        assert_eq!(clear / scalar, dec_res);
+
+        let ct_res = sks.smart_scalar_mod(&mut ctxt_0, scalar as u8);
+        let dec_res = cks.decrypt(&ct_res);
+        assert_eq!(clear % scalar, dec_res);
+    }
+}
+
+fn shortint_smart_apply_lookup_table_bivariate<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    let acc = sks.generate_lookup_table_bivariate(|x, y| (x * y + x) % modulus);
+
+    for _ in 0..NB_TESTS_SMART {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        let mut ctxt_1 = cks.encrypt(clear_1);
+
+        let mut ct_res = sks.smart_apply_lookup_table_bivariate(&mut ctxt_0, &mut ctxt_1, &acc);
+        let mut clear = (clear_0 * clear_1 + clear_0) % modulus;
+
+        for _ in 0..NB_SUB_TEST_SMART {
+            // Make the result have carries so that some calls need to clean them
+            sks.unchecked_add_assign(&mut ct_res, &ctxt_1);
+            clear = (clear + clear_1) % modulus;
+
+            ct_res = sks.smart_apply_lookup_table_bivariate(&mut ct_res, &mut ctxt_1, &acc);
+            clear = (clear * clear_1 + clear) % modulus;
+
+            let dec_res = cks.decrypt(&ct_res);
+
+            assert_eq!(clear, dec_res);
+        }
+    }
+}
+
 fn shortint_unchecked_mod<P>(param: P)
 where
     P: Into<PBSParameters>,
@@ -0,0 +1,75 @@
+//! Tests the memory usage cap of the high level API on real operations.
+//!
+//! The cap is checked against the usage measured by the accounting allocator, which has to be
+//! the global allocator of the test binary, and is global to the process: this is why these
+//! tests run in their own binary, in a single test function.
+#![cfg(feature = "integer")]
+
+use tfhe::prelude::*;
+use tfhe::{
+    check_memory_usage_cap, current_memory_usage, fhe_join, generate_keys, set_memory_usage_cap,
+    set_server_key, try_with_memory_usage_cap, ConfigBuilder, FheUint8, MemoryAccountingAllocator,
+};
+
+#[global_allocator]
+static ALLOCATOR: MemoryAccountingAllocator = MemoryAccountingAllocator::new(std::alloc::System);
+
+#[test]
+fn test_memory_usage_cap_on_operations() {
+    let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    set_server_key(server_key);
+
+    let a = FheUint8::encrypt(12u8, &client_key);
+    let b = FheUint8::encrypt(30u8, &client_key);
+
+    let usage = current_memory_usage();
+    assert!(usage > 0);
+
+    // A cap smaller than what is already used, no operation can be started
+    set_memory_usage_cap(Some(usage / 2));
+    let error = check_memory_usage_cap().unwrap_err();
+    assert_eq!(error.cap(), usage / 2);
+
+    let error = try_with_memory_usage_cap(|| Ok(&a + &b)).unwrap_err();
+    assert!(error.usage() > error.cap());
+
+    // Within `try_with_memory_usage_cap` the cap is checked explicitly between operations
+    set_memory_usage_cap(None);
+    let mut operations_done = 0;
+    let result = try_with_memory_usage_cap(|| {
+        let product = &a * &b;
+        operations_done += 1;
+        set_memory_usage_cap(Some(usage / 2));
+        check_memory_usage_cap()?;
+        let _ = &product + &a;
+        operations_done += 1;
+        Ok(())
+    });
+    assert!(result.is_err());
+    assert_eq!(operations_done, 1);
+
+    // and the operations themselves are not refused, including the ones run on other threads
+    set_memory_usage_cap(None);
+    let (sum, compressed) = try_with_memory_usage_cap(|| {
+        set_memory_usage_cap(Some(usage / 2));
+        Ok(fhe_join(|| &a + &b, || a.compress()))
+    })
+    .unwrap();
+    let decrypted: u8 = sum.decrypt(&client_key);
+    assert_eq!(decrypted, 42);
+    let decrypted: u8 = compressed.decompress().decrypt(&client_key);
+    assert_eq!(decrypted, 12);
+
+    // Outside of `try_with_memory_usage_cap`, a refused operation panics
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| &a + &b));
+    assert!(result.is_err());
+
+    // Operations that only have a CPU implementation are also refused
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| a.compress()));
+    assert!(result.is_err());
+
+    set_memory_usage_cap(None);
+    let sum = &a + &b;
+    let decrypted: u8 = sum.decrypt(&client_key);
+    assert_eq!(decrypted, 42);
+}